@@ -0,0 +1,5 @@
+// eslint-disable-next-line no-debugger
+debugger;
+// eslint-disable-next-line no-console
+console.log(1);
+console.log(2); // oxlint-disable-line
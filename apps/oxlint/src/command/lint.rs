@@ -1,7 +1,7 @@
 use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
-use oxc_linter::{AllowWarnDeny, FixKind, LintPlugins};
+use oxc_linter::{AllowWarnDeny, FixCategory, FixKind, LintPlugins};
 
 use super::{
     expand_glob,
@@ -33,6 +33,12 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
     /// list all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
@@ -68,6 +74,15 @@ pub struct BasicOptions {
     /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
+
+    /// File to keep the `package.json` and `tsconfig.json` files read by the resolver of the
+    /// import plugin in, so that later runs only read the ones which changed
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub resolver_cache: Option<PathBuf>,
+
+    /// Ignore the `.oxlintrc.json` files in the directories of the linted files
+    #[bpaf(switch, hide_usage)]
+    pub disable_nested_config: bool,
 }
 
 // This is formatted according to
@@ -84,6 +99,9 @@ pub struct BasicOptions {
 ///   * `nursery`     - new lints that are still under development.
 ///   * `restriction` - lints which prevent the use of language and library features.
 ///   * `all`         - all the categories listed above except nursery. Does not enable plugins automatically.
+///   Filters can be combined with `&` (and), `|` (or), `!` (not) and parentheses,
+///   and can also select `plugin:<name>` or `fixable` rules.
+///   For example `-D 'correctness & fixable & !plugin:react'`.
 ///
 /// Arguments:
 //  ^ This shows up on the website but not from the cli's `--help`.
@@ -129,6 +147,24 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions.
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// Only apply the fixes of rules in a category: formatting, import-hygiene, style or behavior.
+    /// Can be repeated. Applies safe fixes if no other fix option is used.
+    #[bpaf(
+        long("fix-kind"),
+        argument::<String>("CATEGORY"),
+        parse(parse_fix_category),
+        many,
+        hide_usage
+    )]
+    pub fix_categories: Vec<FixCategory>,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn parse_fix_category(name: String) -> Result<FixCategory, String> {
+    FixCategory::parse_kind(&name).ok_or_else(|| {
+        format!("Invalid fix kind `{name}`, expected one of: {}", FixCategory::NAMES.join(", "))
+    })
 }
 
 impl FixOptions {
@@ -150,11 +186,23 @@ impl FixOptions {
             kind.set(FixKind::Dangerous, true);
         }
 
+        if !self.fix_categories.is_empty() && kind.is_none() {
+            kind.set(FixKind::SafeFix, true);
+        }
+
         kind
     }
 
+    /// The categories of rules whose fixes are applied, all categories if `--fix-kind` is not used.
+    pub fn fix_category(&self) -> FixCategory {
+        if self.fix_categories.is_empty() {
+            return FixCategory::all();
+        }
+        self.fix_categories.iter().fold(FixCategory::empty(), |acc, category| acc | *category)
+    }
+
     pub fn is_enabled(&self) -> bool {
-        self.fix || self.fix_suggestions || self.fix_dangerously
+        self.fix || self.fix_suggestions || self.fix_dangerously || !self.fix_categories.is_empty()
     }
 }
 
@@ -173,12 +221,17 @@ pub struct WarningOptions {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Report directive comments like `// oxlint-disable-line` which do not suppress any problem.
+    /// They are removed by `--fix`.
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
 }
 
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json, unix, checkstyle, github)
+    /// Use a specific output format (default, json, unix, checkstyle, junit, github, sarif)
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
 }
@@ -192,6 +245,11 @@ pub enum OutputFormat {
     Json,
     Unix,
     Checkstyle,
+    /// JUnit XML, with a failed test case per diagnostic
+    Junit,
+    /// SARIF 2.1.0, e.g. for GitHub code scanning
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
 }
 
 impl FromStr for OutputFormat {
@@ -203,12 +261,58 @@ impl FromStr for OutputFormat {
             "default" => Ok(Self::Default),
             "unix" => Ok(Self::Unix),
             "checkstyle" => Ok(Self::Checkstyle),
+            "junit" => Ok(Self::Junit),
             "github" => Ok(Self::Github),
+            "sarif" => Ok(Self::Sarif),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
 }
 
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint the files which changed since they were last linted without problems.
+    /// Files are linted again when the configuration or the version of oxlint changes.
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path of the cache file, `.oxlintcache` by default
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+impl CacheOptions {
+    /// The path of the cache file, if caching is enabled.
+    pub fn cache_path(&self) -> Option<PathBuf> {
+        self.cache
+            .then(|| self.cache_location.clone().unwrap_or_else(|| PathBuf::from(".oxlintcache")))
+    }
+}
+
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Path of a baseline file. Only the problems which are not recorded in it are reported.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record the problems in the baseline file, `.oxlint-baseline.json` by default, instead of
+    /// reporting them
+    #[bpaf(switch, hide_usage)]
+    pub generate_baseline: bool,
+}
+
+impl BaselineOptions {
+    /// The path of the baseline file, if a baseline is used or generated.
+    pub fn baseline_path(&self) -> Option<PathBuf> {
+        match &self.baseline {
+            Some(path) => Some(path.clone()),
+            None => self.generate_baseline.then(|| PathBuf::from(".oxlint-baseline.json")),
+        }
+    }
+}
+
 /// Enable Plugins
 #[allow(clippy::struct_field_names)]
 #[derive(Debug, Default, Clone, Bpaf)]
@@ -285,6 +389,10 @@ pub struct EnablePlugins {
     /// Enable the security plugin and detect security problems
     #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
     pub security_plugin: OverrideToggle,
+
+    /// Enable the regexp plugin and detect regular expression problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub regexp_plugin: OverrideToggle,
 }
 
 /// Enables or disables a boolean option, or leaves it unset.
@@ -361,6 +469,7 @@ impl EnablePlugins {
         self.promise_plugin.inspect(|yes| plugins.set(LintPlugins::PROMISE, yes));
         self.node_plugin.inspect(|yes| plugins.set(LintPlugins::NODE, yes));
         self.security_plugin.inspect(|yes| plugins.set(LintPlugins::SECURITY, yes));
+        self.regexp_plugin.inspect(|yes| plugins.set(LintPlugins::REGEXP, yes));
 
         // Without this, jest plugins adapted to vitest will not be enabled.
         if self.vitest_plugin.is_enabled() && self.jest_plugin.is_not_set() {
@@ -443,7 +552,7 @@ mod warning_options {
 mod lint_options {
     use std::{fs::File, path::PathBuf};
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, FixCategory, FixKind};
 
     use super::{lint_command, LintCommand, OutputFormat};
 
@@ -522,6 +631,26 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_kind() {
+        let options = get_lint_options("--fix-kind formatting --fix-kind=import-hygiene test.js");
+        assert!(options.fix_options.is_enabled());
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+        assert_eq!(
+            options.fix_options.fix_category(),
+            FixCategory::Formatting | FixCategory::ImportHygiene
+        );
+
+        let options = get_lint_options("--fix-suggestions --fix-kind style test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Suggestion);
+
+        let options = get_lint_options("--fix test.js");
+        assert_eq!(options.fix_options.fix_category(), FixCategory::all());
+
+        let args = ["--fix-kind", "whitespace", "test.js"].map(String::from);
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -544,6 +673,18 @@ mod lint_options {
         assert!(options.paths.is_empty());
     }
 
+    #[test]
+    fn format_junit() {
+        let options = get_lint_options("--format junit");
+        assert_eq!(options.output_options.format, OutputFormat::Junit);
+    }
+
+    #[test]
+    fn format_sarif() {
+        let options = get_lint_options("--format sarif");
+        assert_eq!(options.output_options.format, OutputFormat::Sarif);
+    }
+
     #[test]
     fn format_error() {
         let args = "-f asdf".split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -30,6 +30,20 @@ pub struct MiscOptions {
     /// When present, no linting is performed and only config-related options are valid.
     #[bpaf(switch, hide_usage)]
     pub print_config: bool,
+
+    /// Lint the source text read from stdin instead of files.
+    /// With `--fix`, the fixed source text is printed to stdout and diagnostics to stderr.
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// Path of the source text read with `--stdin`, used for its language and configuration
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+
+    /// Keep running, and lint the files again when they are added or changed.
+    /// Combine with `--cache` to skip the unchanged files when restarting.
+    #[bpaf(switch, hide_usage)]
+    pub watch: bool,
 }
 
 #[allow(clippy::ptr_arg)]
@@ -70,6 +84,8 @@ fn expand_glob(paths: Vec<PathBuf>) -> Vec<PathBuf> {
 
 #[cfg(test)]
 mod misc_options {
+    use std::path::PathBuf;
+
     use super::{lint::lint_command, MiscOptions};
 
     fn get_misc_options(arg: &str) -> MiscOptions {
@@ -81,6 +97,8 @@ mod misc_options {
     fn default() {
         let options = get_misc_options(".");
         assert!(options.threads.is_none());
+        assert!(!options.stdin);
+        assert!(!options.watch);
     }
 
     #[test]
@@ -88,4 +106,17 @@ mod misc_options {
         let options = get_misc_options("--threads 4 .");
         assert_eq!(options.threads, Some(4));
     }
+
+    #[test]
+    fn stdin() {
+        let options = get_misc_options("--stdin --stdin-filename src/index.ts");
+        assert!(options.stdin);
+        assert_eq!(options.stdin_filename, Some(PathBuf::from("src/index.ts")));
+    }
+
+    #[test]
+    fn watch() {
+        let options = get_misc_options("--watch src");
+        assert!(options.watch);
+    }
 }
@@ -3,6 +3,7 @@ mod lint;
 mod result;
 mod runner;
 mod walk;
+mod watch;
 
 pub mod cli {
 
@@ -1,10 +1,17 @@
-use std::{env, io::BufWriter, time::Instant};
+use std::{
+    collections::BTreeSet,
+    env,
+    io::{BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    process::Termination,
+    time::Instant,
+};
 
 use ignore::gitignore::Gitignore;
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
-    loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, InvalidFilterKind, LintFilter, LintService,
-    LintServiceOptions, Linter, LinterBuilder, Oxlintrc,
+    loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, InvalidFilterKind, LintBaseline, LintFilter,
+    LintService, LintServiceOptions, Linter, LinterBuilder, Oxlintrc,
 };
 use oxc_span::VALID_EXTENSIONS;
 
@@ -14,6 +21,7 @@ use crate::{
         WarningOptions,
     },
     walk::{Extensions, Walk},
+    watch::Watcher,
 };
 
 pub struct LintRunner {
@@ -43,6 +51,8 @@ impl Runner for LintRunner {
             fix_options,
             enable_plugins,
             output_options,
+            cache_options,
+            baseline_options,
             misc_options,
             ..
         } = self.options;
@@ -51,6 +61,38 @@ impl Runner for LintRunner {
         let provided_path_count = paths.len();
         let now = Instant::now();
 
+        // With `--fix --stdin`, the fixed source text is printed to stdout, so diagnostics are
+        // printed to stderr instead.
+        let fix_stdin = misc_options.stdin && fix_options.fix_kind().is_some();
+        let stdin = if misc_options.stdin {
+            if provided_path_count > 0 {
+                return CliRunResult::InvalidOptions {
+                    message: "PATH cannot be used with --stdin.".to_string(),
+                };
+            }
+            if misc_options.watch {
+                return CliRunResult::InvalidOptions {
+                    message: "--watch cannot be used with --stdin.".to_string(),
+                };
+            }
+            if fix_stdin && !matches!(output_options.format, OutputFormat::Default) {
+                return CliRunResult::InvalidOptions {
+                    message: "--format cannot be used with --fix --stdin, diagnostics are printed to stderr.".to_string(),
+                };
+            }
+            let mut source_text = String::new();
+            if let Err(e) = std::io::stdin().read_to_string(&mut source_text) {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to read from stdin: {e}"),
+                };
+            }
+            let path =
+                misc_options.stdin_filename.clone().unwrap_or_else(|| PathBuf::from("stdin.js"));
+            Some((path, source_text))
+        } else {
+            None
+        };
+
         // The ignore crate whitelists explicit paths, but priority
         // should be given to the ignore file. Many users lint
         // automatically and pass a list of changed files explicitly.
@@ -92,8 +134,17 @@ impl Runner for LintRunner {
             .copied()
             .collect::<Vec<&'static str>>();
 
-        let paths =
-            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
+        let extensions = Extensions(extensions);
+        // Watch the files before linting them, so that changes made while linting are not missed.
+        let watcher = misc_options
+            .watch
+            .then(|| Watcher::new(paths.clone(), ignore_options.clone(), extensions.clone()));
+
+        let paths = if let Some((path, _)) = &stdin {
+            vec![path.clone().into_boxed_path()]
+        } else {
+            Walk::new(&paths, &ignore_options).with_extensions(extensions).paths()
+        };
 
         let number_of_files = paths.len();
 
@@ -102,14 +153,7 @@ impl Runner for LintRunner {
         let mut oxlintrc = if let Some(config_path) = basic_options.config.as_ref() {
             match Oxlintrc::from_file(config_path) {
                 Ok(config) => config,
-                Err(diagnostic) => {
-                    let handler = GraphicalReportHandler::new();
-                    let mut err = String::new();
-                    handler.render_report(&mut err, &diagnostic).unwrap();
-                    return CliRunResult::InvalidOptions {
-                        message: format!("Failed to parse configuration file.\n{err}"),
-                    };
-                }
+                Err(diagnostic) => return Self::config_error(&diagnostic),
             }
         } else {
             Oxlintrc::default()
@@ -117,11 +161,33 @@ impl Runner for LintRunner {
 
         enable_plugins.apply_overrides(&mut oxlintrc.plugins);
 
+        let nested_configs = if basic_options.disable_nested_config {
+            vec![]
+        } else {
+            match Self::find_nested_configs(&paths, &cwd, basic_options.config.as_deref()) {
+                Ok(nested_configs) => nested_configs,
+                Err(diagnostic) => return Self::config_error(&diagnostic),
+            }
+        };
+        let nested_needs_module_resolution = nested_configs
+            .iter()
+            .any(|(_, nested_config)| nested_config.plugins.needs_module_resolution());
+
         let oxlintrc_for_print =
             if misc_options.print_config { Some(oxlintrc.clone()) } else { None };
-        let builder = LinterBuilder::from_oxlintrc(false, oxlintrc)
+        let mut builder = LinterBuilder::from_oxlintrc(false, oxlintrc)
             .with_filters(filter)
-            .with_fix(fix_options.fix_kind());
+            .with_fix(fix_options.fix_kind())
+            .with_fix_category(fix_options.fix_category())
+            .with_report_fixes(matches!(
+                output_options.format,
+                OutputFormat::Json | OutputFormat::Sarif
+            ))
+            .with_report_unused_directives(if warning_options.report_unused_disable_directives {
+                AllowWarnDeny::Warn
+            } else {
+                AllowWarnDeny::Allow
+            });
 
         if let Some(basic_config_file) = oxlintrc_for_print {
             return CliRunResult::PrintConfigResult {
@@ -129,8 +195,31 @@ impl Runner for LintRunner {
             };
         }
 
-        let mut options =
-            LintServiceOptions::new(cwd, paths).with_cross_module(builder.plugins().has_import());
+        for (directory, nested_config) in nested_configs {
+            builder = builder.with_nested_config(directory, nested_config);
+        }
+
+        let mut options = LintServiceOptions::new(cwd, paths).with_cross_module(
+            builder.plugins().needs_module_resolution() || nested_needs_module_resolution,
+        );
+        if let Some(cache) = cache_options.cache_path() {
+            options = options.with_cache(cache);
+        }
+        if let Some(path) = baseline_options.baseline_path() {
+            let baseline = if baseline_options.generate_baseline {
+                LintBaseline::generate(path)
+            } else {
+                match LintBaseline::load(path) {
+                    Ok(baseline) => baseline,
+                    Err(diagnostic) => {
+                        return CliRunResult::InvalidOptions {
+                            message: diagnostic.message.to_string(),
+                        }
+                    }
+                }
+            };
+            options = options.with_baseline(baseline);
+        }
         let linter = builder.build();
 
         let tsconfig = basic_options.tsconfig;
@@ -147,9 +236,43 @@ impl Runner for LintRunner {
             }
         }
 
+        if let Some(path) = basic_options.resolver_cache {
+            options = options.with_resolver_cache(path);
+        }
+
         let lint_service = LintService::new(linter, options);
         let mut diagnostic_service =
             Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
+        if fix_stdin {
+            diagnostic_service.set_stderr_reporter();
+        }
+
+        if let Some((path, source_text)) = stdin {
+            let fixed_source_text =
+                lint_service.run_source_text(&path, &source_text, diagnostic_service.sender());
+            diagnostic_service.run();
+            if fix_stdin {
+                let source_text = fixed_source_text.as_deref().unwrap_or(&source_text);
+                let mut stdout = std::io::stdout();
+                if let Err(e) =
+                    stdout.write_all(source_text.as_bytes()).and_then(|()| stdout.flush())
+                {
+                    return CliRunResult::InvalidOptions {
+                        message: format!("Failed to write to stdout: {e}"),
+                    };
+                }
+            }
+            return CliRunResult::LintResult(LintResult {
+                duration: now.elapsed(),
+                number_of_rules: lint_service.linter().number_of_rules(),
+                number_of_files,
+                number_of_warnings: diagnostic_service.warnings_count(),
+                number_of_errors: diagnostic_service.errors_count(),
+                max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
+                deny_warnings: warning_options.deny_warnings,
+                print_summary: !fix_stdin && matches!(output_options.format, OutputFormat::Default),
+            });
+        }
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
@@ -161,7 +284,7 @@ impl Runner for LintRunner {
         });
         diagnostic_service.run();
 
-        CliRunResult::LintResult(LintResult {
+        let result = LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
             number_of_files,
@@ -170,11 +293,81 @@ impl Runner for LintRunner {
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
             deny_warnings: warning_options.deny_warnings,
             print_summary: matches!(output_options.format, OutputFormat::Default),
-        })
+        };
+
+        let Some(mut watcher) = watcher else {
+            return CliRunResult::LintResult(result);
+        };
+        CliRunResult::LintResult(result).report();
+        loop {
+            let paths = watcher.wait();
+            let now = Instant::now();
+            let mut diagnostic_service =
+                Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
+            rayon::spawn({
+                let tx_error = diagnostic_service.sender().clone();
+                let lint_service = lint_service.clone();
+                let paths = paths.clone();
+                move || {
+                    lint_service.run_paths(&paths, &tx_error);
+                }
+            });
+            diagnostic_service.run();
+
+            CliRunResult::LintResult(LintResult {
+                duration: now.elapsed(),
+                number_of_rules: lint_service.linter().number_of_rules(),
+                number_of_files: paths.len(),
+                number_of_warnings: diagnostic_service.warnings_count(),
+                number_of_errors: diagnostic_service.errors_count(),
+                max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
+                deny_warnings: warning_options.deny_warnings,
+                print_summary: matches!(output_options.format, OutputFormat::Default),
+            })
+            .report();
+        }
     }
 }
 
 impl LintRunner {
+    fn config_error(diagnostic: &OxcDiagnostic) -> CliRunResult {
+        let handler = GraphicalReportHandler::new();
+        let mut err = String::new();
+        handler.render_report(&mut err, diagnostic).unwrap();
+        CliRunResult::InvalidOptions {
+            message: format!("Failed to parse configuration file.\n{err}"),
+        }
+    }
+
+    /// Find the `.oxlintrc.json` files in the directories of `paths` and their parent directories
+    /// within `cwd`, except for the root configuration file passed with `--config`.
+    fn find_nested_configs(
+        paths: &[Box<Path>],
+        cwd: &Path,
+        root_config: Option<&Path>,
+    ) -> Result<Vec<(PathBuf, Oxlintrc)>, OxcDiagnostic> {
+        let root_config = root_config.and_then(|path| path.canonicalize().ok());
+        let mut visited = BTreeSet::new();
+        let mut nested_configs = vec![];
+        for path in paths {
+            for directory in path.ancestors().skip(1) {
+                if directory.is_absolute() && !directory.starts_with(cwd) {
+                    break;
+                }
+                // The parent directories of a visited directory have been visited too.
+                if !visited.insert(directory) {
+                    break;
+                }
+                let config_path = directory.join(".oxlintrc.json");
+                if !config_path.is_file() || config_path.canonicalize().ok() == root_config {
+                    continue;
+                }
+                nested_configs.push((directory.to_path_buf(), Oxlintrc::from_file(&config_path)?));
+            }
+        }
+        Ok(nested_configs)
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
@@ -190,7 +383,9 @@ impl LintRunner {
             OutputFormat::Json => diagnostic_service.set_json_reporter(),
             OutputFormat::Unix => diagnostic_service.set_unix_reporter(),
             OutputFormat::Checkstyle => diagnostic_service.set_checkstyle_reporter(),
+            OutputFormat::Junit => diagnostic_service.set_junit_reporter(),
             OutputFormat::Github => diagnostic_service.set_github_reporter(),
+            OutputFormat::Sarif => diagnostic_service.set_sarif_reporter(),
         }
         diagnostic_service
     }
@@ -226,6 +421,11 @@ impl LintRunner {
                         ),
                     });
                 }
+                Err(InvalidFilterKind::InvalidQuery(filter, reason)) => {
+                    return Err(CliRunResult::InvalidOptions {
+                        message: format!("Failed to {severity} filter {filter}: {reason}."),
+                    });
+                }
             }
         }
 
@@ -269,7 +469,8 @@ mod test {
         let result = test(args);
         assert!(result.number_of_rules > 0);
         assert!(result.number_of_warnings > 0);
-        assert_eq!(result.number_of_errors, 0);
+        // from the `.oxlintrc.json` files in `fixtures/import` and `fixtures/nested_config`
+        assert_eq!(result.number_of_errors, 2);
     }
 
     #[test]
@@ -533,6 +734,24 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn lint_markdown_file() {
+        let args = &["fixtures/markdown/debugger.md"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_html_file() {
+        let args = &["fixtures/html/debugger.html"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn test_tsconfig_option() {
         // passed
@@ -575,6 +794,54 @@ mod test {
         assert_eq!(result.number_of_errors, 1);
     }
 
+    #[test]
+    fn test_resolver_cache_option() {
+        let cache = std::env::temp_dir().join("oxlint_test_resolver_cache.json");
+        let _ = std::fs::remove_file(&cache);
+        let args = &[
+            "-c",
+            "fixtures/import/.oxlintrc.json",
+            "--resolver-cache",
+            cache.to_str().unwrap(),
+            "fixtures/import/test.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 1);
+        assert!(cache.is_file());
+        std::fs::remove_file(cache).unwrap();
+    }
+
+    #[test]
+    fn test_nested_config() {
+        let args = &["fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn test_disable_nested_config() {
+        let args = &["--disable-nested-config", "fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_stdin_with_path() {
+        let message = test_invalid_options(&["--stdin", "fixtures/linter/debugger.js"]);
+        assert_eq!(message, "PATH cannot be used with --stdin.");
+    }
+
+    #[test]
+    fn test_watch_with_stdin() {
+        let message = test_invalid_options(&["--stdin", "--watch"]);
+        assert_eq!(message, "--watch cannot be used with --stdin.");
+    }
+
     #[test]
     fn test_fix() {
         use std::fs;
@@ -597,6 +864,118 @@ mod test {
         fs::write(file, content).unwrap();
     }
 
+    #[test]
+    fn test_fix_kind() {
+        use std::fs;
+        let file = "fixtures/fix_kind/test.js";
+        let args = &["-D", "number-literal-case", "--fix-kind", "formatting", file];
+        let content = fs::read_to_string(file).unwrap();
+        assert_eq!(&content, "debugger;\nconsole.log(0XFF);\n");
+
+        // Only the formatting fix is applied, `no-debugger` may change behavior.
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(fs::read_to_string(file).unwrap(), "debugger;\nconsole.log(0xFF);\n");
+
+        // Write the file back.
+        fs::write(file, content).unwrap();
+    }
+
+    #[test]
+    fn test_report_unused_disable_directives() {
+        use std::fs;
+        let file = "fixtures/report_unused_directives/test.js";
+        let args = &["-A", "all", "-D", "no-debugger", "--report-unused-disable-directives", file];
+        let content = fs::read_to_string(file).unwrap();
+
+        // The `no-console` and `oxlint-disable-line` directives do not suppress any problem.
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+
+        let result = test(&["-A", "all", "-D", "no-debugger", file]);
+        assert_eq!(result.number_of_warnings, 0);
+
+        // The unused directives are removed by `--fix`.
+        let mut fix_args = args.to_vec();
+        fix_args.insert(0, "--fix");
+        let _ = test(&fix_args);
+        assert_eq!(
+            fs::read_to_string(file).unwrap(),
+            "// eslint-disable-next-line no-debugger\ndebugger;\nconsole.log(1);\nconsole.log(2);\n"
+        );
+
+        // Write the file back.
+        fs::write(file, content).unwrap();
+    }
+
+    #[test]
+    fn test_fix_passes() {
+        use std::fs;
+        let file = "fixtures/fix_passes/test.js";
+        let args = &["-A", "all", "-D", "no-useless-spread", "--fix-dangerously", file];
+        let content = fs::read_to_string(file).unwrap();
+        assert_eq!(&content, "console.log([...[...[1, 2, 3]]]);\n");
+
+        // The fixes of the nested spreads overlap, so the inner one is fixed in a second pass.
+        let result = test(args);
+        assert_eq!(result.number_of_errors, 0);
+        assert_eq!(fs::read_to_string(file).unwrap(), "console.log([1, 2, 3]);\n");
+
+        // Write the file back.
+        fs::write(file, content).unwrap();
+    }
+
+    #[test]
+    fn test_cache() {
+        use std::fs;
+        let cache = "fixtures/cache/.oxlintcache";
+        let args = &["--cache", "--cache-location", cache, "fixtures/cache"];
+        let _ = fs::remove_file(cache);
+
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+
+        // Only the file without problems is cached.
+        let content = fs::read_to_string(cache).unwrap();
+        assert!(content.contains("clean.js"));
+        assert!(!content.contains("debugger.js"));
+
+        // The problems of files which are not cached are reported again.
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+
+        fs::remove_file(cache).unwrap();
+    }
+
+    #[test]
+    fn test_baseline() {
+        use std::fs;
+        let baseline = "fixtures/baseline/.oxlint-baseline.json";
+        let _ = fs::remove_file(baseline);
+
+        let result = test(&["--generate-baseline", "--baseline", baseline, "fixtures/baseline"]);
+        assert_eq!(result.number_of_warnings, 0);
+        let content = fs::read_to_string(baseline).unwrap();
+        assert!(content.contains("eslint(no-debugger)"));
+
+        // The problems in the baseline are not reported.
+        let result = test(&["--baseline", baseline, "fixtures/baseline"]);
+        assert_eq!(result.number_of_warnings, 0);
+        // Other problems are.
+        let result = test(&["--baseline", baseline, "fixtures/linter/debugger.js"]);
+        assert_eq!(result.number_of_warnings, 1);
+
+        fs::remove_file(baseline).unwrap();
+    }
+
+    #[test]
+    fn test_baseline_not_found() {
+        let message = test_invalid_options(&["--baseline", "fixtures/baseline/missing.json"]);
+        assert!(message.starts_with("Failed to read the baseline file"));
+    }
+
     #[test]
     fn test_print_config_ban_all_rules() {
         let args = &["-A", "all", "--print-config"];
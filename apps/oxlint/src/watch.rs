@@ -0,0 +1,103 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    cli::IgnoreOptions,
+    walk::{Extensions, Walk},
+};
+
+/// How long to wait between two checks for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Watches the files to lint for changes, for `--watch`.
+///
+/// The modification times of the files are polled, and the paths are walked again on every poll
+/// so that added files are linted too.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    ignore_options: IgnoreOptions,
+    extensions: Extensions,
+    modified: BTreeMap<Box<Path>, SystemTime>,
+}
+
+impl Watcher {
+    /// Watch the files in `paths`, filtered like the files which are linted.
+    pub fn new(paths: Vec<PathBuf>, ignore_options: IgnoreOptions, extensions: Extensions) -> Self {
+        let mut watcher = Self { paths, ignore_options, extensions, modified: BTreeMap::new() };
+        watcher.changed_paths();
+        watcher
+    }
+
+    /// Block until files are added or changed, and return them.
+    pub fn wait(&mut self) -> Vec<Box<Path>> {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let paths = self.changed_paths();
+            if !paths.is_empty() {
+                return paths;
+            }
+        }
+    }
+
+    /// The files which were added or changed since the last call, sorted.
+    fn changed_paths(&mut self) -> Vec<Box<Path>> {
+        let paths = Walk::new(&self.paths, &self.ignore_options)
+            .with_extensions(self.extensions.clone())
+            .paths();
+        let mut modified = BTreeMap::new();
+        let mut changed = vec![];
+        for path in paths {
+            let Ok(time) = fs::metadata(&path).and_then(|metadata| metadata.modified()) else {
+                continue;
+            };
+            if self.modified.get(&path) != Some(&time) {
+                changed.push(path.clone());
+            }
+            modified.insert(path, time);
+        }
+        self.modified = modified;
+        changed.sort_unstable();
+        changed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    use super::Watcher;
+    use crate::{cli::lint_command, walk::Extensions};
+
+    #[test]
+    fn changed_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.js");
+        fs::write(&a, "debugger;").unwrap();
+        fs::write(dir.path().join("b.txt"), "").unwrap();
+
+        let ignore_options = lint_command().run_inner(["."].as_slice()).unwrap().ignore_options;
+        let mut watcher =
+            Watcher::new(vec![dir.path().to_path_buf()], ignore_options, Extensions::default());
+        assert!(watcher.changed_paths().is_empty());
+
+        // Set the modification time explicitly, as it may not change within a short time.
+        let file = fs::File::options().write(true).open(&a).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1)).unwrap();
+        let b = dir.path().join("b.js");
+        fs::write(&b, "").unwrap();
+
+        let changed = watcher.changed_paths();
+        let changed = changed.iter().map(|path| path.to_path_buf()).collect::<Vec<PathBuf>>();
+        assert_eq!(changed, [a, b]);
+        assert!(watcher.changed_paths().is_empty());
+    }
+}
@@ -9,9 +9,10 @@ use oxc_mangler::{MangleOptions, Mangler};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{ScopeTree, SemanticBuilder, SemanticBuilderReturn, SymbolTable};
+use oxc_sourcemap::SourceMap;
 use oxc_span::SourceType;
 use oxc_transformer::{
-    InjectGlobalVariables, InjectGlobalVariablesConfig, ReplaceGlobalDefines,
+    InjectGlobalVariables, InjectGlobalVariablesConfig, JestHoist, ReplaceGlobalDefines,
     ReplaceGlobalDefinesConfig, TransformOptions, Transformer, TransformerReturn,
 };
 
@@ -57,6 +58,13 @@ pub trait CompilerInterface {
         false
     }
 
+    /// Source map of the input source text, e.g. from a previous build step.
+    ///
+    /// The emitted source map is chained onto it, so that it maps back to the original sources.
+    fn input_source_map(&self) -> Option<&SourceMap> {
+        None
+    }
+
     fn parse_options(&self) -> ParseOptions {
         ParseOptions::default()
     }
@@ -69,6 +77,25 @@ pub trait CompilerInterface {
         None
     }
 
+    /// Adjust the transform options for the file at `source_path`, e.g. to only enable React
+    /// Refresh for source files.
+    ///
+    /// Returns the options to use instead of [`CompilerInterface::transform_options`], or `None`
+    /// to use them as they are.
+    fn transform_options_for_path(
+        &self,
+        _source_path: &Path,
+        _options: &TransformOptions,
+    ) -> Option<TransformOptions> {
+        None
+    }
+
+    /// Hoist `jest.mock()` calls above the imports, like `babel-plugin-jest-hoist`, for using the
+    /// compiler as a Jest transformer.
+    fn jest_hoist(&self) -> bool {
+        false
+    }
+
     fn define_options(&self) -> Option<ReplaceGlobalDefinesConfig> {
         None
     }
@@ -157,7 +184,12 @@ pub trait CompilerInterface {
 
         /* Transform */
 
+        let mut quote_reserved_properties = false;
         if let Some(options) = self.transform_options() {
+            let overridden_options = self.transform_options_for_path(source_path, options);
+            let options = overridden_options.as_ref().unwrap_or(options);
+            quote_reserved_properties =
+                options.env.es5.member_expression_literals || options.env.es5.property_literals;
             let mut transformer_return =
                 self.transform(options, &allocator, &mut program, source_path, symbols, scopes);
 
@@ -173,6 +205,16 @@ pub trait CompilerInterface {
             (symbols, scopes) = (transformer_return.symbols, transformer_return.scopes);
         }
 
+        // Only moves statements within their scope, so symbols and scopes stay in sync.
+        if self.jest_hoist() {
+            let ret = JestHoist::new(&allocator).build(symbols, scopes, &mut program);
+            if !ret.errors.is_empty() {
+                self.handle_errors(ret.errors);
+                return;
+            }
+            (symbols, scopes) = (ret.symbols, ret.scopes);
+        }
+
         let inject_options = self.inject_options();
         let define_options = self.define_options();
 
@@ -216,7 +258,9 @@ pub trait CompilerInterface {
 
         /* Codegen */
 
-        if let Some(options) = self.codegen_options() {
+        if let Some(mut options) = self.codegen_options() {
+            // Nodes created after transforming, e.g. by defines, are not quoted by the transformer.
+            options.quote_reserved_properties |= quote_reserved_properties;
             let ret = self.codegen(&program, source_path, mangler, options);
             self.after_codegen(ret);
         }
@@ -288,7 +332,11 @@ pub trait CompilerInterface {
     }
 
     fn mangle(&self, program: &mut Program<'_>, options: MangleOptions) -> Mangler {
-        Mangler::new().with_options(options).build(program)
+        let printed = CodeGenerator::new()
+            .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+            .build(program)
+            .code;
+        Mangler::new().with_options(options).with_frequency_text(printed).build(program)
     }
 
     fn codegen(
@@ -302,6 +350,11 @@ pub trait CompilerInterface {
         if self.enable_sourcemap() {
             options.source_map_path = Some(source_path.to_path_buf());
         }
-        CodeGenerator::new().with_options(options).with_mangler(mangler).build(program)
+        let mut ret =
+            CodeGenerator::new().with_options(options).with_mangler(mangler).build(program);
+        if let Some(input_source_map) = self.input_source_map() {
+            ret.map = ret.map.map(|map| map.remap(input_source_map));
+        }
+        ret
     }
 }
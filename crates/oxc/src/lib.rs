@@ -22,6 +22,14 @@ pub mod ast {
     pub use oxc_ast::*;
 }
 
+pub mod ast_visit {
+    //! Visitors for the AST.
+    //!
+    //! See the [`oxc_ast_visit` module-level documentation](oxc_ast_visit) for more information.
+    #[doc(inline)]
+    pub use oxc_ast_visit::*;
+}
+
 pub mod diagnostics {
     //! Error data types and utilities for handling/reporting them.
     //!
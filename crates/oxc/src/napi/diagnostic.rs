@@ -0,0 +1,106 @@
+use std::{path::Path, sync::Arc};
+
+use napi_derive::napi;
+
+use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic, Severity};
+use oxc_span::SourceType;
+
+/// An error or warning reported while processing a file.
+#[napi(object)]
+pub struct Diagnostic {
+    #[napi(ts_type = "'Error' | 'Warning' | 'Advice'")]
+    pub severity: &'static str,
+    /// The code of the diagnostic, e.g. `TS1109`.
+    pub code: Option<String>,
+    pub message: String,
+    /// The source ranges the diagnostic refers to.
+    pub labels: Vec<DiagnosticLabel>,
+    pub help: Option<String>,
+    /// The diagnostic rendered with a code frame of the source text, as printed by the CLI.
+    ///
+    /// This is not set if the diagnostic does not refer to the source text.
+    pub codeframe: Option<String>,
+}
+
+/// A source range of a {@link Diagnostic}.
+#[napi(object)]
+pub struct DiagnosticLabel {
+    pub message: Option<String>,
+    /// Start offset of the range, in UTF-8 bytes.
+    pub start: u32,
+    /// End offset of the range, in UTF-8 bytes.
+    pub end: u32,
+}
+
+impl Diagnostic {
+    /// Convert the diagnostics reported for `source_text`, rendering their code frames.
+    pub fn from_diagnostics(
+        filename: &Path,
+        source_type: SourceType,
+        source_text: &str,
+        diagnostics: Vec<OxcDiagnostic>,
+    ) -> Vec<Self> {
+        if diagnostics.is_empty() {
+            return vec![];
+        }
+        let lang = match (source_type.is_javascript(), source_type.is_jsx()) {
+            (true, false) => "JavaScript",
+            (true, true) => "JSX",
+            (false, true) => "TypeScript React",
+            (false, false) => {
+                if source_type.is_typescript_definition() {
+                    "TypeScript Declaration"
+                } else {
+                    "TypeScript"
+                }
+            }
+        };
+        let source = Arc::new(
+            NamedSource::new(filename.to_string_lossy(), source_text.to_string())
+                .with_language(lang),
+        );
+
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                let has_labels =
+                    diagnostic.labels.as_ref().is_some_and(|labels| !labels.is_empty());
+                let codeframe = has_labels.then(|| {
+                    let error =
+                        Error::from(diagnostic.clone()).with_source_code(Arc::clone(&source));
+                    format!("{error:?}")
+                });
+                Self { codeframe, ..Self::from(diagnostic) }
+            })
+            .collect()
+    }
+}
+
+impl From<OxcDiagnostic> for Diagnostic {
+    fn from(diagnostic: OxcDiagnostic) -> Self {
+        let severity = match diagnostic.severity {
+            Severity::Error => "Error",
+            Severity::Warning => "Warning",
+            Severity::Advice => "Advice",
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let labels = diagnostic
+            .labels
+            .iter()
+            .flatten()
+            .map(|label| DiagnosticLabel {
+                message: label.label().map(ToString::to_string),
+                start: label.offset() as u32,
+                end: (label.offset() + label.len()) as u32,
+            })
+            .collect();
+        Self {
+            severity,
+            code: diagnostic.code.is_some().then(|| diagnostic.code.to_string()),
+            message: diagnostic.message.to_string(),
+            labels,
+            help: diagnostic.help.as_ref().map(ToString::to_string),
+            codeframe: None,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use napi_derive::napi;
+
+use super::diagnostic::Diagnostic;
+
+/// A syntax feature used by a file.
+#[napi(object)]
+pub struct EsFeatureUsage {
+    /// Name of the feature, e.g. `ES2015ArrowFunctions`.
+    pub feature: String,
+    /// The ES version which introduced the feature, e.g. `es2015`.
+    pub es_version: String,
+    /// Start offset of the code which uses the feature.
+    pub start: u32,
+    /// End offset of the code which uses the feature.
+    pub end: u32,
+}
+
+#[napi(object)]
+pub struct EsFeaturesResult {
+    /// Features used by the file, in source order.
+    pub features: Vec<EsFeatureUsage>,
+    /// The lowest ES version which supports all the features used by the file.
+    pub es_target: String,
+    pub errors: Vec<Diagnostic>,
+}
+
+impl From<&oxc_transformer::ESFeatureUsage> for EsFeatureUsage {
+    fn from(usage: &oxc_transformer::ESFeatureUsage) -> Self {
+        Self {
+            feature: format!("{:?}", usage.feature),
+            es_version: usage.feature.es_target().to_string(),
+            start: usage.span.start,
+            end: usage.span.end,
+        }
+    }
+}
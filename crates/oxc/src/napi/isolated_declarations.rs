@@ -1,12 +1,19 @@
 use napi_derive::napi;
 
-use super::source_map::SourceMap;
+use super::{diagnostic::Diagnostic, source_map::SourceMap};
 
 #[napi(object)]
 pub struct IsolatedDeclarationsResult {
     pub code: String,
     pub map: Option<SourceMap>,
-    pub errors: Vec<String>,
+    pub errors: Vec<Diagnostic>,
+}
+
+/// A source file for batch isolated declarations emit.
+#[napi(object)]
+pub struct IsolatedDeclarationsFile {
+    pub filename: String,
+    pub source_text: String,
 }
 
 #[napi(object)]
@@ -20,6 +27,9 @@ pub struct IsolatedDeclarationsOptions {
     /// See <https://www.typescriptlang.org/tsconfig/#stripInternal>
     pub strip_internal: Option<bool>,
 
+    /// Generate a source map of the declarations.
+    ///
+    /// Default: `false`
     pub sourcemap: Option<bool>,
 }
 
@@ -0,0 +1,15 @@
+use napi_derive::napi;
+
+use super::diagnostic::Diagnostic;
+
+#[napi(object)]
+pub struct MinifyResult {
+    /// The minified code.
+    pub code: String,
+    /// Parse errors.
+    ///
+    /// Oxc's parser recovers from common syntax errors, meaning that
+    /// minified code may still be available even if there are errors in this
+    /// list.
+    pub errors: Vec<Diagnostic>,
+}
@@ -1,3 +1,5 @@
+pub mod diagnostic;
+
 #[cfg(feature = "parser")]
 pub mod parse;
 
@@ -9,3 +11,9 @@ pub mod isolated_declarations;
 
 #[cfg(feature = "transformer")]
 pub mod transform;
+
+#[cfg(feature = "transformer")]
+pub mod es_features;
+
+#[cfg(feature = "minifier")]
+pub mod minify;
@@ -1,5 +1,7 @@
 use napi_derive::napi;
 
+use super::diagnostic::Diagnostic;
+
 /// Babel Parser Options
 ///
 /// <https://github.com/babel/babel/blob/main/packages/babel-parser/typings/babel-parser.d.ts>
@@ -24,7 +26,7 @@ pub struct ParseResult {
     #[napi(ts_type = "import(\"@oxc-project/types\").Program")]
     pub program: String,
     pub comments: Vec<Comment>,
-    pub errors: Vec<String>,
+    pub errors: Vec<Diagnostic>,
 }
 
 #[napi(object)]
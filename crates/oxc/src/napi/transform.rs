@@ -10,7 +10,10 @@ use rustc_hash::FxHashMap;
 
 use oxc_transformer::{JsxRuntime, RewriteExtensionsMode};
 
-use super::{isolated_declarations::IsolatedDeclarationsOptions, source_map::SourceMap};
+use super::{
+    diagnostic::Diagnostic, isolated_declarations::IsolatedDeclarationsOptions,
+    source_map::SourceMap,
+};
 
 #[derive(Default)]
 #[napi(object)]
@@ -45,7 +48,35 @@ pub struct TransformResult {
     /// Oxc's parser recovers from common syntax errors, meaning that
     /// transformed code may still be available even if there are errors in this
     /// list.
-    pub errors: Vec<String>,
+    pub errors: Vec<Diagnostic>,
+
+    /// Time spent in each transform plugin.
+    ///
+    /// This will be set if {@link TransformOptions#profile} is `true`.
+    pub profile: Option<Vec<PluginProfile>>,
+}
+
+/// Time spent in a transform plugin.
+#[napi(object)]
+pub struct PluginProfile {
+    /// Name of the plugin, e.g. `typescript` or `es2020`.
+    pub name: String,
+
+    /// Total time spent in the plugin, in milliseconds.
+    pub duration: f64,
+
+    /// Number of visitor methods of the plugin which were called.
+    pub calls: u32,
+}
+
+impl From<oxc_transformer::PluginProfile> for PluginProfile {
+    fn from(profile: oxc_transformer::PluginProfile) -> Self {
+        Self {
+            name: profile.name.to_string(),
+            duration: profile.duration.as_secs_f64() * 1000.0,
+            calls: profile.calls,
+        }
+    }
 }
 
 /// Options for transforming a JavaScript or TypeScript file.
@@ -65,6 +96,17 @@ pub struct TransformOptions {
     /// options.
     pub cwd: Option<String>,
 
+    /// An expression which evaluates to the file name of the module, e.g.
+    /// `import.meta.url` or `__filename`, emitted instead of the path of the
+    /// file by transforms which reference it, such as JSX development mode.
+    ///
+    /// Keeps the output independent of the machine it is built on.
+    pub filename_expression: Option<String>,
+
+    /// An expression which evaluates to the id of the module, e.g.
+    /// `module.id`, used to prefix the components registered by React Refresh.
+    pub module_id_expression: Option<String>,
+
     /// Enable source map generation.
     ///
     /// When `true`, the `sourceMap` field of transform result objects will be populated.
@@ -87,17 +129,64 @@ pub struct TransformOptions {
     /// Inject Plugin
     #[napi(ts_type = "Record<string, string | [string, string]>")]
     pub inject: Option<FxHashMap<String, Either<String, Vec<String>>>>,
+
+    /// Hoist `jest.mock()` calls above the imports, like
+    /// `babel-plugin-jest-hoist`, for using Oxc as a Jest transformer.
+    ///
+    /// @default false
+    pub jest_hoist: Option<bool>,
+
+    /// Keep the `name` of classes which a transform would otherwise change,
+    /// e.g. an anonymous class initializing a class field, by defining it with
+    /// `Object.defineProperty`.
+    ///
+    /// @default false
+    pub keep_class_names: Option<bool>,
+
+    /// Measure the time spent in each transform plugin, returned in
+    /// {@link TransformResult#profile}.
+    ///
+    /// @default false
+    pub profile: Option<bool>,
+
+    /// Options for the files matching some glob patterns, e.g. a different JSX
+    /// import source for a subdirectory.
+    ///
+    /// Each matching override replaces the options it sets. Later overrides
+    /// take precedence over earlier ones.
+    pub overrides: Option<Vec<TransformOptionsOverride>>,
+}
+
+/// Options for the files matching {@link TransformOptionsOverride#files}.
+#[napi(object)]
+pub struct TransformOptionsOverride {
+    /// Glob patterns of the files to apply the options to, e.g. `src/components/**`.
+    ///
+    /// Patterns are matched against the file name relative to
+    /// {@link TransformOptions#cwd}, or the file name as given when it is
+    /// not inside `cwd`.
+    pub files: Vec<String>,
+
+    /// Replaces {@link TransformOptions#typescript}.
+    pub typescript: Option<TypeScriptOptions>,
+
+    /// Replaces {@link TransformOptions#jsx}.
+    pub jsx: Option<JsxOptions>,
 }
 
 impl From<TransformOptions> for oxc_transformer::TransformOptions {
     fn from(options: TransformOptions) -> Self {
         Self {
             cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
+            filename_expression: options.filename_expression,
+            module_id_expression: options.module_id_expression,
             typescript: options
                 .typescript
                 .map(oxc_transformer::TypeScriptOptions::from)
                 .unwrap_or_default(),
             jsx: options.jsx.map(Into::into).unwrap_or_default(),
+            keep_class_names: options.keep_class_names.unwrap_or_default(),
+            profile: options.profile.unwrap_or_default(),
             ..Self::default()
         }
     }
@@ -111,6 +200,11 @@ pub struct TypeScriptOptions {
     pub only_remove_type_imports: Option<bool>,
     pub allow_namespaces: Option<bool>,
     pub allow_declare_fields: Option<bool>,
+    /// Emit enums in the compact form of `tsc`, `(function (E) { ... })(E || (E = {}))`,
+    /// instead of the form of Babel.
+    ///
+    /// @default false
+    pub compact_enums: Option<bool>,
     /// Also generate a `.d.ts` declaration file for TypeScript files.
     ///
     /// The source file must be compliant with all
@@ -143,6 +237,7 @@ impl From<TypeScriptOptions> for oxc_transformer::TypeScriptOptions {
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
             optimize_const_enums: false,
+            compact_enums: options.compact_enums.unwrap_or(ops.compact_enums),
             rewrite_import_extensions: options.rewrite_import_extensions.and_then(|value| {
                 match value {
                     Either::A(v) => {
@@ -2201,6 +2201,21 @@ pub struct ImportDeclaration<'a> {
     /// `import type { foo } from 'bar'`
     #[ts]
     pub import_kind: ImportOrExportKind,
+    /// `import source x from 'y'` or `import defer * as ns from 'y'`
+    pub phase: Option<ImportPhase>,
+}
+
+/// Phase of an [`ImportDeclaration`], from the stage 3 proposals
+/// [Source Phase Imports](https://github.com/tc39/proposal-source-phase-imports) and
+/// [Deferring Module Evaluation](https://github.com/tc39/proposal-defer-import-eval).
+#[ast]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ESTree)]
+pub enum ImportPhase {
+    /// `import source x from 'y'`
+    Source = 0,
+    /// `import defer * as ns from 'y'`
+    Defer = 1,
 }
 
 #[ast(visit)]
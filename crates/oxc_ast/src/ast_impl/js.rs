@@ -19,6 +19,18 @@ impl<'a> Program<'a> {
     pub fn is_strict(&self) -> bool {
         self.source_type.is_strict() || self.directives.iter().any(Directive::is_use_strict)
     }
+
+    /// Comments before the node at `span` which are attached to it, see
+    /// [`leading_comments`](crate::leading_comments).
+    pub fn leading_comments(&self, span: Span) -> &[Comment] {
+        crate::leading_comments(&self.comments, span)
+    }
+
+    /// Comments after the node at `span` until the end of the line, see
+    /// [`trailing_comments`](crate::trailing_comments).
+    pub fn trailing_comments(&self, span: Span) -> &[Comment] {
+        crate::trailing_comments(&self.comments, self.source_text, span)
+    }
 }
 
 impl<'a> Expression<'a> {
@@ -1422,6 +1434,16 @@ impl<'a> ImportDeclarationSpecifier<'a> {
     }
 }
 
+impl ImportPhase {
+    #[allow(missing_docs)]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Source => "source",
+            Self::Defer => "defer",
+        }
+    }
+}
+
 impl<'a> ImportAttributeKey<'a> {
     #[allow(missing_docs)]
     pub fn as_atom(&self) -> Atom<'a> {
@@ -709,6 +709,10 @@ const _: () = {
     assert!(offset_of!(ImportDeclaration, source) == 40usize);
     assert!(offset_of!(ImportDeclaration, with_clause) == 64usize);
     assert!(offset_of!(ImportDeclaration, import_kind) == 72usize);
+    assert!(offset_of!(ImportDeclaration, phase) == 73usize);
+
+    assert!(size_of::<ImportPhase>() == 1usize);
+    assert!(align_of::<ImportPhase>() == 1usize);
 
     assert!(size_of::<ImportDeclarationSpecifier>() == 16usize);
     assert!(align_of::<ImportDeclarationSpecifier>() == 8usize);
@@ -2265,6 +2269,10 @@ const _: () = {
     assert!(offset_of!(ImportDeclaration, source) == 24usize);
     assert!(offset_of!(ImportDeclaration, with_clause) == 40usize);
     assert!(offset_of!(ImportDeclaration, import_kind) == 44usize);
+    assert!(offset_of!(ImportDeclaration, phase) == 45usize);
+
+    assert!(size_of::<ImportPhase>() == 1usize);
+    assert!(align_of::<ImportPhase>() == 1usize);
 
     assert!(size_of::<ImportDeclarationSpecifier>() == 8usize);
     assert!(align_of::<ImportDeclarationSpecifier>() == 4usize);
@@ -6573,6 +6573,7 @@ impl<'a> AstBuilder<'a> {
     /// - source
     /// - with_clause: Some(vec![]) for empty assertion
     /// - import_kind: `import type { foo } from 'bar'`
+    /// - phase: `import source x from 'y'` or `import defer * as ns from 'y'`
     #[inline]
     pub fn module_declaration_import_declaration<T1>(
         self,
@@ -6581,6 +6582,7 @@ impl<'a> AstBuilder<'a> {
         source: StringLiteral<'a>,
         with_clause: T1,
         import_kind: ImportOrExportKind,
+        phase: Option<ImportPhase>,
     ) -> ModuleDeclaration<'a>
     where
         T1: IntoIn<'a, Option<Box<'a, WithClause<'a>>>>,
@@ -6591,6 +6593,7 @@ impl<'a> AstBuilder<'a> {
             source,
             with_clause,
             import_kind,
+            phase,
         )))
     }
 
@@ -6858,6 +6861,7 @@ impl<'a> AstBuilder<'a> {
     /// - source
     /// - with_clause: Some(vec![]) for empty assertion
     /// - import_kind: `import type { foo } from 'bar'`
+    /// - phase: `import source x from 'y'` or `import defer * as ns from 'y'`
     #[inline]
     pub fn import_declaration<T1>(
         self,
@@ -6866,6 +6870,7 @@ impl<'a> AstBuilder<'a> {
         source: StringLiteral<'a>,
         with_clause: T1,
         import_kind: ImportOrExportKind,
+        phase: Option<ImportPhase>,
     ) -> ImportDeclaration<'a>
     where
         T1: IntoIn<'a, Option<Box<'a, WithClause<'a>>>>,
@@ -6876,6 +6881,7 @@ impl<'a> AstBuilder<'a> {
             source,
             with_clause: with_clause.into_in(self.allocator),
             import_kind,
+            phase,
         }
     }
 
@@ -6889,6 +6895,7 @@ impl<'a> AstBuilder<'a> {
     /// - source
     /// - with_clause: Some(vec![]) for empty assertion
     /// - import_kind: `import type { foo } from 'bar'`
+    /// - phase: `import source x from 'y'` or `import defer * as ns from 'y'`
     #[inline]
     pub fn alloc_import_declaration<T1>(
         self,
@@ -6897,12 +6904,13 @@ impl<'a> AstBuilder<'a> {
         source: StringLiteral<'a>,
         with_clause: T1,
         import_kind: ImportOrExportKind,
+        phase: Option<ImportPhase>,
     ) -> Box<'a, ImportDeclaration<'a>>
     where
         T1: IntoIn<'a, Option<Box<'a, WithClause<'a>>>>,
     {
         Box::new_in(
-            self.import_declaration(span, specifiers, source, with_clause, import_kind),
+            self.import_declaration(span, specifiers, source, with_clause, import_kind, phase),
             self.allocator,
         )
     }
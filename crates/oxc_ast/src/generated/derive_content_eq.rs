@@ -2243,6 +2243,13 @@ impl<'a> ContentEq for ImportDeclaration<'a> {
             && ContentEq::content_eq(&self.source, &other.source)
             && ContentEq::content_eq(&self.with_clause, &other.with_clause)
             && ContentEq::content_eq(&self.import_kind, &other.import_kind)
+            && ContentEq::content_eq(&self.phase, &other.phase)
+    }
+}
+
+impl ContentEq for ImportPhase {
+    fn content_eq(&self, other: &Self) -> bool {
+        self == other
     }
 }
 
@@ -1234,6 +1234,13 @@ impl<'a> ContentHash for ImportDeclaration<'a> {
         ContentHash::content_hash(&self.source, state);
         ContentHash::content_hash(&self.with_clause, state);
         ContentHash::content_hash(&self.import_kind, state);
+        ContentHash::content_hash(&self.phase, state);
+    }
+}
+
+impl ContentHash for ImportPhase {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        ContentHash::content_hash(&discriminant(self), state);
     }
 }
 
@@ -1701,10 +1701,20 @@ impl<'a> Serialize for ImportDeclaration<'a> {
         map.serialize_entry("source", &self.source)?;
         map.serialize_entry("withClause", &self.with_clause)?;
         map.serialize_entry("importKind", &self.import_kind)?;
+        map.serialize_entry("phase", &self.phase)?;
         map.end()
     }
 }
 
+impl Serialize for ImportPhase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            ImportPhase::Source => serializer.serialize_unit_variant("ImportPhase", 0u32, "source"),
+            ImportPhase::Defer => serializer.serialize_unit_variant("ImportPhase", 1u32, "defer"),
+        }
+    }
+}
+
 impl<'a> Serialize for ImportDeclarationSpecifier<'a> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match self {
@@ -37,6 +37,8 @@
 //! [typescript-eslint]: <https://github.com/typescript-eslint/typescript-eslint/tree/main/packages/ast-spec>
 //! [ECMAScript spec]: <https://tc39.es/ecma262/>
 //! [tsc]: <https://github.com/microsoft/TypeScript>
+//! [`Visit`]: <https://docs.rs/oxc_ast_visit/latest/oxc_ast_visit/trait.Visit.html>
+//! [`VisitMut`]: <https://docs.rs/oxc_ast_visit/latest/oxc_ast_visit/trait.VisitMut.html>
 //! [`Traverse`]: <https://github.com/oxc-project/oxc/tree/main/crates/oxc_traverse>
 
 // TODO: I'm not sure if it is a but or intentional but clippy needs this allowed both on this
@@ -68,25 +70,22 @@ mod generated {
     pub mod derive_get_span;
     pub mod derive_get_span_mut;
     pub mod get_id;
-    pub mod visit;
-    pub mod visit_mut;
-}
-
-pub mod visit {
-    #![allow(missing_docs)]
-    pub use crate::generated::{visit::*, visit_mut::*};
 }
 
 pub use generated::{ast_builder, ast_kind};
 pub use num_bigint::BigUint;
+pub use oxc_ast_macros::match_ast;
+#[cfg(feature = "serialize")]
+pub use serialize::ESTreeOptions;
 
 pub use crate::{
     ast::comment::{Comment, CommentKind, CommentPosition},
     ast_builder::AstBuilder,
     ast_builder_impl::NONE,
     ast_kind::{AstKind, AstType},
-    trivia::{comments_range, has_comments_between, CommentsRange},
-    visit::{Visit, VisitMut},
+    trivia::{
+        comments_range, has_comments_between, leading_comments, trailing_comments, CommentsRange,
+    },
 };
 
 // After experimenting with two types of boxed enum variants:
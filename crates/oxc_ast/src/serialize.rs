@@ -128,6 +128,18 @@ impl<'a> Program<'a> {
         String::from_utf8(ser.into_inner()).unwrap()
     }
 
+    /// Serialize to ESTree compatible JSON, with the positions of nodes described by `options`.
+    ///
+    /// # Panics
+    pub fn to_estree_json(&self, options: ESTreeOptions) -> String {
+        let json = self.to_json();
+        if !options.ranges && !options.locations && !options.utf16 {
+            return json;
+        }
+        let positions = SourcePositions::new(self.source_text, options);
+        positions.rewrite_spans(&json)
+    }
+
     /// # Panics
     pub fn serializer(&self) -> serde_json::Serializer<std::vec::Vec<u8>, EcmaFormatter> {
         let buf = std::vec::Vec::new();
@@ -137,6 +149,145 @@ impl<'a> Program<'a> {
     }
 }
 
+/// Options for [`Program::to_estree_json`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ESTreeOptions {
+    /// Add `range: [start, end]` to every node, as emitted by `espree` and `typescript-estree`.
+    pub ranges: bool,
+    /// Add `loc: { start: { line, column }, end: { line, column } }` to every node.
+    /// Lines are 1-based and columns are 0-based.
+    pub locations: bool,
+    /// Report offsets and columns in UTF-16 code units, which is how JavaScript indexes strings,
+    /// instead of UTF-8 bytes.
+    pub utf16: bool,
+}
+
+/// Converts the UTF-8 byte offsets of spans into the positions requested by [`ESTreeOptions`].
+struct SourcePositions {
+    options: ESTreeOptions,
+    /// UTF-16 offset of every UTF-8 byte offset, `None` if the conversion is not needed.
+    utf16_offsets: Option<std::vec::Vec<u32>>,
+    /// Offsets of the start of every line, in the requested unit.
+    line_starts: std::vec::Vec<u32>,
+}
+
+impl SourcePositions {
+    fn new(source_text: &str, options: ESTreeOptions) -> Self {
+        let utf16_offsets = (options.utf16 && !source_text.is_ascii()).then(|| {
+            let mut offsets = std::vec::Vec::with_capacity(source_text.len() + 1);
+            let mut utf16_offset = 0;
+            for c in source_text.chars() {
+                offsets.extend(std::iter::repeat(utf16_offset).take(c.len_utf8()));
+                #[allow(clippy::cast_possible_truncation)]
+                let len = c.len_utf16() as u32;
+                utf16_offset += len;
+            }
+            offsets.push(utf16_offset);
+            offsets
+        });
+
+        let mut positions = Self { options, utf16_offsets, line_starts: vec![0] };
+        if options.locations {
+            let mut chars = source_text.char_indices().peekable();
+            while let Some((offset, c)) = chars.next() {
+                let is_line_break = match c {
+                    '\r' => !matches!(chars.peek(), Some((_, '\n'))),
+                    '\n' | '\u{2028}' | '\u{2029}' => true,
+                    _ => false,
+                };
+                if is_line_break {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let line_start = positions.offset((offset + c.len_utf8()) as u32);
+                    positions.line_starts.push(line_start);
+                }
+            }
+        }
+        positions
+    }
+
+    /// Converts a UTF-8 byte offset into the requested unit.
+    fn offset(&self, offset: u32) -> u32 {
+        match &self.utf16_offsets {
+            Some(offsets) => offsets.get(offset as usize).copied().unwrap_or(offset),
+            None => offset,
+        }
+    }
+
+    /// 1-based line and 0-based column of a converted offset.
+    fn line_column(&self, offset: u32) -> (usize, u32) {
+        let line = self.line_starts.partition_point(|line_start| *line_start <= offset);
+        (line, offset - self.line_starts[line - 1])
+    }
+
+    /// Rewrites every `"start":N,"end":M` pair of a node in the compact JSON produced by
+    /// [`Program::to_json`].
+    fn rewrite_spans(&self, json: &str) -> String {
+        const START: &str = "\"start\":";
+        const END: &str = ",\"end\":";
+
+        let mut output = String::with_capacity(json.len() * 2);
+        let mut rest = json;
+        let mut in_string = false;
+        let mut escaped = false;
+        while let Some(c) = rest.chars().next() {
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == '"' {
+                    in_string = false;
+                }
+            } else if c == '"' {
+                if output.ends_with(['{', ',']) {
+                    if let Some((start, end, remaining)) = Self::parse_span(rest, START, END) {
+                        self.write_span(&mut output, start, end);
+                        rest = remaining;
+                        continue;
+                    }
+                }
+                in_string = true;
+            }
+            output.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        output
+    }
+
+    fn parse_span<'s>(
+        json: &'s str,
+        start_key: &str,
+        end_key: &str,
+    ) -> Option<(u32, u32, &'s str)> {
+        fn parse_number(json: &str) -> Option<(u32, &str)> {
+            let len = json.bytes().take_while(u8::is_ascii_digit).count();
+            let number = json[..len].parse().ok()?;
+            Some((number, &json[len..]))
+        }
+        let (start, json) = parse_number(json.strip_prefix(start_key)?)?;
+        let (end, json) = parse_number(json.strip_prefix(end_key)?)?;
+        Some((start, end, json))
+    }
+
+    fn write_span(&self, output: &mut String, start: u32, end: u32) {
+        use std::fmt::Write;
+        let (start, end) = (self.offset(start), self.offset(end));
+        write!(output, "\"start\":{start},\"end\":{end}").unwrap();
+        if self.options.ranges {
+            write!(output, ",\"range\":[{start},{end}]").unwrap();
+        }
+        if self.options.locations {
+            let (start_line, start_column) = self.line_column(start);
+            let (end_line, end_column) = self.line_column(end);
+            write!(
+                output,
+                ",\"loc\":{{\"start\":{{\"line\":{start_line},\"column\":{start_column}}},\"end\":{{\"line\":{end_line},\"column\":{end_column}}}}}"
+            )
+            .unwrap();
+        }
+    }
+}
+
 impl Serialize for RegExpFlags {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -298,3 +449,26 @@ impl<'a> Serialize for JSXMemberExpressionObject<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rewrite_spans() {
+        let source_text = "a;\n\"é\";\r\nb";
+        let json = r#"{"type":"Program","start":0,"end":11,"body":[{"type":"StringLiteral","start":3,"end":7,"value":"\"start\":3,\"end\":7"},{"type":"Identifier","start":10,"end":11}]}"#;
+
+        let options = ESTreeOptions { ranges: true, ..ESTreeOptions::default() };
+        assert_eq!(
+            SourcePositions::new(source_text, options).rewrite_spans(json),
+            r#"{"type":"Program","start":0,"end":11,"range":[0,11],"body":[{"type":"StringLiteral","start":3,"end":7,"range":[3,7],"value":"\"start\":3,\"end\":7"},{"type":"Identifier","start":10,"end":11,"range":[10,11]}]}"#
+        );
+
+        let options = ESTreeOptions { locations: true, utf16: true, ..ESTreeOptions::default() };
+        assert_eq!(
+            SourcePositions::new(source_text, options).rewrite_spans(json),
+            r#"{"type":"Program","start":0,"end":10,"loc":{"start":{"line":1,"column":0},"end":{"line":3,"column":1}},"body":[{"type":"StringLiteral","start":3,"end":6,"loc":{"start":{"line":2,"column":0},"end":{"line":2,"column":3}},"value":"\"start\":3,\"end\":7"},{"type":"Identifier","start":9,"end":10,"loc":{"start":{"line":3,"column":0},"end":{"line":3,"column":1}}}]}"#
+        );
+    }
+}
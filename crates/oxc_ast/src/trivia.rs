@@ -21,6 +21,49 @@ pub fn has_comments_between(comments: &[Comment], span: Span) -> bool {
     comments_range(comments, span.start..span.end).count() > 0
 }
 
+/// Leading comments of the AST node at `span`, i.e. the comments between the previous token and
+/// the node, which are attached to the first token of the node.
+///
+/// The comments are attached to the outermost node starting at `span.start`, e.g. the comments
+/// before `a.b()` are leading comments of both the call expression and `a`.
+pub fn leading_comments(comments: &[Comment], span: Span) -> &[Comment] {
+    let end = comments.partition_point(|comment| comment.span.start < span.start);
+    let start = comments[..end]
+        .iter()
+        .rposition(|comment| !comment.is_leading() || comment.attached_to != span.start)
+        .map_or(0, |i| i + 1);
+    &comments[start..end]
+}
+
+/// Trailing comments of the AST node at `span`, i.e. the comments after the node until the end
+/// of the line.
+///
+/// Only whitespace and commas may be between the node and the comments, so
+/// `foo(a, // about a` is a trailing comment of `a`.
+pub fn trailing_comments<'c>(
+    comments: &'c [Comment],
+    source_text: &str,
+    span: Span,
+) -> &'c [Comment] {
+    let start = comments.partition_point(|comment| comment.span.start < span.end);
+    let mut prev_end = span.end;
+    let mut end = start;
+    for comment in &comments[start..] {
+        let gap = &source_text[prev_end as usize..comment.span.start as usize];
+        let is_attached = if end == start {
+            gap.chars().all(|c| c.is_whitespace() || c == ',')
+        } else {
+            gap.chars().all(char::is_whitespace)
+        };
+        if !comment.is_trailing() || !is_attached {
+            break;
+        }
+        prev_end = comment.span.end;
+        end += 1;
+    }
+    &comments[start..end]
+}
+
 /// Double-ended iterator over a range of comments, by starting position.
 pub struct CommentsRange<'c> {
     comments: &'c [Comment],
@@ -115,4 +158,38 @@ mod test {
         assert_eq!(comments_range(&comments, ..18).count(), full_len.saturating_sub(1));
         assert_eq!(comments_range(&comments, ..=18).count(), full_len);
     }
+
+    #[test]
+    fn test_leading_and_trailing_comments() {
+        let source_text = "a; // t1\n/* l1 */ // l2\nb(c, /* t2 */ // t3\nd);";
+        let comment = |text: &str, kind, position, attached_to| {
+            let start = u32::try_from(source_text.find(text).unwrap()).unwrap();
+            let end = start + u32::try_from(text.len()).unwrap();
+            Comment { position, attached_to, ..Comment::new(start, end, kind) }
+        };
+        let comments = vec![
+            comment("// t1", CommentKind::Line, CommentPosition::Trailing, 0),
+            comment("/* l1 */", CommentKind::Block, CommentPosition::Leading, 24),
+            comment("// l2", CommentKind::Line, CommentPosition::Leading, 24),
+            comment("/* t2 */", CommentKind::Block, CommentPosition::Trailing, 0),
+            comment("// t3", CommentKind::Line, CommentPosition::Trailing, 0),
+        ];
+        let text = |comments: &[Comment]| {
+            comments.iter().map(|comment| comment.span.source_text(source_text)).collect::<Vec<_>>()
+        };
+
+        // `a;`
+        assert!(leading_comments(&comments, Span::new(0, 2)).is_empty());
+        assert_eq!(text(trailing_comments(&comments, source_text, Span::new(0, 2))), ["// t1"]);
+        // `b(c, d);`
+        let stmt = Span::new(24, u32::try_from(source_text.len()).unwrap());
+        assert_eq!(text(leading_comments(&comments, stmt)), ["/* l1 */", "// l2"]);
+        assert!(trailing_comments(&comments, source_text, stmt).is_empty());
+        // `c`
+        assert!(leading_comments(&comments, Span::new(26, 27)).is_empty());
+        assert_eq!(
+            text(trailing_comments(&comments, source_text, Span::new(26, 27))),
+            ["/* t2 */", "// t3"]
+        );
+    }
 }
@@ -2,6 +2,7 @@ use proc_macro::TokenStream;
 use syn::{parse_macro_input, Item};
 
 mod ast;
+mod match_ast;
 
 /// This attribute serves two purposes.
 /// First, it is a marker for our `ast_tools` to detect AST types.
@@ -92,3 +93,45 @@ pub fn ast(_args: TokenStream, input: TokenStream) -> TokenStream {
 pub fn ast_derive(_input: TokenStream) -> TokenStream {
     TokenStream::new()
 }
+
+/// Match an `&Expression` against a pattern of nested expression shapes.
+///
+/// ```ignore
+/// // `Object.assign(...)`
+/// if let Some(arguments) = match_ast!(expr, call(member(ident("Object"), "assign"), arguments)) {
+///     // ...
+/// }
+/// ```
+///
+/// Patterns:
+///
+/// * `_` matches any expression.
+/// * `name` matches any expression and binds it to `name`, and `name @ pattern` binds an
+///   expression matching `pattern`.
+/// * `ident(name)` matches an identifier reference.
+/// * `member(object, name)` matches a member expression with a static property name, e.g.
+///   `object.name` or `object["name"]`.
+/// * `call(callee, arguments)` and `new(callee, arguments)` match a call or new expression.
+///   `arguments` is `..` to match any arguments, a binding for the arguments, or a list of
+///   patterns like `[_, name, ..]`, which spread arguments never match. It can be omitted to
+///   match any arguments.
+/// * `string(value)` matches a string literal.
+/// * `this()` matches `this`.
+///
+/// Names and values are `_`, one or more alternatives like `"log" | "warn"`, a binding of the
+/// `&str`, or a binding of alternatives like `name @ "log" | "warn"`.
+///
+/// Parentheses and TypeScript type expressions like `as` are skipped before matching an
+/// expression, like [`Expression::get_inner_expression`] does.
+///
+/// Evaluates to `bool` without bindings, to `Option<T>` with one binding, and to an `Option` of a
+/// tuple of the bindings in order otherwise.
+///
+/// Must be used in a crate depending on `oxc_ast`, which re-exports this macro.
+///
+/// [`Expression::get_inner_expression`]: https://docs.rs/oxc_ast/latest/oxc_ast/ast/enum.Expression.html#method.get_inner_expression
+#[proc_macro]
+pub fn match_ast(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as match_ast::MatchAst);
+    TokenStream::from(match_ast::match_ast(&input))
+}
@@ -0,0 +1,362 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    bracketed, parenthesized,
+    parse::{Parse, ParseStream},
+    Expr, Ident, LitStr, Result, Token,
+};
+
+/// `match_ast!(scrutinee, pattern)`
+pub struct MatchAst {
+    scrutinee: Expr,
+    pattern: Pattern,
+}
+
+/// A pattern matched against an `Expression`.
+enum Pattern {
+    /// `_`
+    Wildcard,
+    /// `name` or `name @ pattern`
+    Bind(Ident, Option<Box<Pattern>>),
+    /// `ident(name)`
+    Ident(NamePattern),
+    /// `member(object, name)`
+    Member(Box<Pattern>, NamePattern),
+    /// `call(callee, arguments)`
+    Call(Box<Pattern>, ArgumentsPattern),
+    /// `new(callee, arguments)`
+    New(Box<Pattern>, ArgumentsPattern),
+    /// `string(value)`
+    String(NamePattern),
+    /// `this()`
+    This,
+}
+
+/// A pattern matched against a `&str`, e.g. the name of an identifier.
+struct NamePattern {
+    binding: Option<Ident>,
+    /// Alternatives of the name, any name if empty.
+    names: Vec<LitStr>,
+}
+
+/// A pattern matched against the arguments of a call.
+enum ArgumentsPattern {
+    /// `..`
+    Any,
+    /// `arguments`
+    Bind(Ident),
+    /// `[pattern, ...]` or `[pattern, ..., ..]`
+    List(Vec<Pattern>, /* rest */ bool),
+}
+
+impl Parse for MatchAst {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let scrutinee = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let pattern = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { scrutinee, pattern })
+    }
+}
+
+impl Parse for Pattern {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![_]) {
+            input.parse::<Token![_]>()?;
+            return Ok(Self::Wildcard);
+        }
+        let name = input.parse::<Ident>()?;
+        if input.peek(Token![@]) {
+            input.parse::<Token![@]>()?;
+            return Ok(Self::Bind(name, Some(Box::new(input.parse()?))));
+        }
+        if !input.peek(syn::token::Paren) {
+            return Ok(Self::Bind(name, None));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        let pattern = match name.to_string().as_str() {
+            "ident" => Self::Ident(content.parse()?),
+            "string" => Self::String(content.parse()?),
+            "this" => Self::This,
+            "member" => {
+                let object = content.parse()?;
+                content.parse::<Token![,]>()?;
+                Self::Member(Box::new(object), content.parse()?)
+            }
+            "call" | "new" => {
+                let callee = content.parse()?;
+                let arguments = if content.is_empty() {
+                    ArgumentsPattern::Any
+                } else {
+                    content.parse::<Token![,]>()?;
+                    content.parse()?
+                };
+                if name == "call" {
+                    Self::Call(Box::new(callee), arguments)
+                } else {
+                    Self::New(Box::new(callee), arguments)
+                }
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "expected one of `ident`, `member`, `call`, `new`, `string` or `this`",
+                ))
+            }
+        };
+        if !content.is_empty() {
+            return Err(content.error("unexpected tokens in pattern"));
+        }
+        Ok(pattern)
+    }
+}
+
+impl Parse for NamePattern {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![_]) {
+            input.parse::<Token![_]>()?;
+            return Ok(Self { binding: None, names: vec![] });
+        }
+        let binding = if input.peek(Ident) {
+            let binding = input.parse()?;
+            if !input.peek(Token![@]) {
+                return Ok(Self { binding: Some(binding), names: vec![] });
+            }
+            input.parse::<Token![@]>()?;
+            Some(binding)
+        } else {
+            None
+        };
+        let mut names = vec![input.parse()?];
+        while input.peek(Token![|]) {
+            input.parse::<Token![|]>()?;
+            names.push(input.parse()?);
+        }
+        Ok(Self { binding, names })
+    }
+}
+
+impl Parse for ArgumentsPattern {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![..]) {
+            input.parse::<Token![..]>()?;
+            return Ok(Self::Any);
+        }
+        if input.peek(Ident) {
+            return Ok(Self::Bind(input.parse()?));
+        }
+        let content;
+        bracketed!(content in input);
+        let mut patterns = vec![];
+        let mut rest = false;
+        while !content.is_empty() {
+            if content.peek(Token![..]) {
+                content.parse::<Token![..]>()?;
+                rest = true;
+                if !content.is_empty() {
+                    return Err(content.error("`..` must be the last argument pattern"));
+                }
+                break;
+            }
+            patterns.push(content.parse()?);
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+        Ok(Self::List(patterns, rest))
+    }
+}
+
+pub fn match_ast(input: &MatchAst) -> TokenStream {
+    let mut generator = Generator { statements: vec![], bindings: vec![], count: 0 };
+    let node = generator.node();
+    generator.pattern(&input.pattern, &node);
+
+    let Generator { statements, bindings, .. } = generator;
+    let (success, failure) = match bindings.as_slice() {
+        [] => (quote!(true), quote!(false)),
+        [binding] => (quote!(Some(#binding)), quote!(None)),
+        bindings => (quote!(Some((#(#bindings),*))), quote!(None)),
+    };
+    let can_fail = statements.iter().any(|statement| !matches!(statement, Statement::Let(_)));
+    let statements = statements
+        .into_iter()
+        .map(|statement| match statement {
+            Statement::Let(statement) => statement,
+            Statement::Check(condition) => {
+                quote!(if !(#condition) { break '__match_ast #failure; })
+            }
+            Statement::LetElse(pattern, value) => {
+                quote!(let #pattern = #value else { break '__match_ast #failure; };)
+            }
+        })
+        .collect::<Vec<_>>();
+    let scrutinee = &input.scrutinee;
+    let body = if can_fail {
+        quote! {
+            '__match_ast: {
+                #(#statements)*
+                #success
+            }
+        }
+    } else {
+        quote! {
+            #(#statements)*
+            #success
+        }
+    };
+
+    quote! {
+        {
+            #[allow(unused_variables)]
+            let #node: &::oxc_ast::ast::Expression<'_> = #scrutinee;
+            #body
+        }
+    }
+}
+
+enum Statement {
+    /// A statement which always succeeds.
+    Let(TokenStream),
+    /// The match fails if the condition is `false`.
+    Check(TokenStream),
+    /// `let pattern = value`, the match fails if `pattern` is refuted.
+    LetElse(TokenStream, TokenStream),
+}
+
+struct Generator {
+    statements: Vec<Statement>,
+    bindings: Vec<Ident>,
+    count: usize,
+}
+
+impl Generator {
+    fn node(&mut self) -> Ident {
+        self.count += 1;
+        format_ident!("__match_ast_{}", self.count)
+    }
+
+    /// Match `node`, an `&Expression`, against `pattern`.
+    fn pattern(&mut self, pattern: &Pattern, node: &Ident) {
+        let expression = quote!(::oxc_ast::ast::Expression);
+        match pattern {
+            Pattern::Wildcard => {}
+            Pattern::Bind(binding, pattern) => {
+                self.statements.push(Statement::Let(quote!(let #binding = #node;)));
+                self.bindings.push(binding.clone());
+                if let Some(pattern) = pattern {
+                    self.pattern(pattern, node);
+                }
+            }
+            Pattern::Ident(name) => {
+                let ident = self.node();
+                self.statements.push(Statement::LetElse(
+                    quote!(#expression::Identifier(#ident)),
+                    quote!(#node.get_inner_expression()),
+                ));
+                self.name(name, &quote!(#ident.name.as_str()));
+            }
+            Pattern::String(value) => {
+                let literal = self.node();
+                self.statements.push(Statement::LetElse(
+                    quote!(#expression::StringLiteral(#literal)),
+                    quote!(#node.get_inner_expression()),
+                ));
+                self.name(value, &quote!(#literal.value.as_str()));
+            }
+            Pattern::This => {
+                self.statements.push(Statement::Check(quote!(
+                    ::core::matches!(#node.get_inner_expression(), #expression::ThisExpression(_))
+                )));
+            }
+            Pattern::Member(object, name) => {
+                let member = self.node();
+                self.statements.push(Statement::LetElse(
+                    quote!(Some(#member)),
+                    quote!(#node.get_inner_expression().as_member_expression()),
+                ));
+                if !matches!(**object, Pattern::Wildcard) {
+                    let object_node = self.node();
+                    self.statements
+                        .push(Statement::Let(quote!(let #object_node = #member.object();)));
+                    self.pattern(object, &object_node);
+                }
+                if name.binding.is_some() || !name.names.is_empty() {
+                    let property = self.node();
+                    self.statements.push(Statement::LetElse(
+                        quote!(Some(#property)),
+                        quote!(#member.static_property_name()),
+                    ));
+                    self.name(name, &quote!(#property));
+                }
+            }
+            Pattern::Call(callee, arguments) | Pattern::New(callee, arguments) => {
+                let variant = if matches!(pattern, Pattern::Call(..)) {
+                    quote!(CallExpression)
+                } else {
+                    quote!(NewExpression)
+                };
+                let call = self.node();
+                self.statements.push(Statement::LetElse(
+                    quote!(#expression::#variant(#call)),
+                    quote!(#node.get_inner_expression()),
+                ));
+                if !matches!(**callee, Pattern::Wildcard) {
+                    let callee_node = self.node();
+                    self.statements.push(Statement::Let(quote!(let #callee_node = &#call.callee;)));
+                    self.pattern(callee, &callee_node);
+                }
+                self.arguments(arguments, &call);
+            }
+        }
+    }
+
+    /// Match `value`, a `&str`, against `pattern`.
+    fn name(&mut self, pattern: &NamePattern, value: &TokenStream) {
+        let NamePattern { binding, names } = pattern;
+        if !names.is_empty() {
+            self.statements.push(Statement::Check(quote!(::core::matches!(#value, #(#names)|*))));
+        }
+        if let Some(binding) = binding {
+            self.statements.push(Statement::Let(quote!(let #binding: &str = #value;)));
+            self.bindings.push(binding.clone());
+        }
+    }
+
+    /// Match the arguments of `call`, a call or new expression, against `pattern`.
+    fn arguments(&mut self, pattern: &ArgumentsPattern, call: &Ident) {
+        match pattern {
+            ArgumentsPattern::Any => {}
+            ArgumentsPattern::Bind(binding) => {
+                self.statements.push(Statement::Let(quote!(let #binding = &#call.arguments;)));
+                self.bindings.push(binding.clone());
+            }
+            ArgumentsPattern::List(patterns, rest) => {
+                let len = patterns.len();
+                self.statements.push(Statement::Check(if *rest {
+                    quote!(#call.arguments.len() >= #len)
+                } else {
+                    quote!(#call.arguments.len() == #len)
+                }));
+                for (i, pattern) in patterns.iter().enumerate() {
+                    // Spread arguments never match.
+                    if matches!(pattern, Pattern::Wildcard) {
+                        self.statements.push(Statement::Check(
+                            quote!(#call.arguments[#i].as_expression().is_some()),
+                        ));
+                        continue;
+                    }
+                    let argument = self.node();
+                    self.statements.push(Statement::LetElse(
+                        quote!(Some(#argument)),
+                        quote!(#call.arguments[#i].as_expression()),
+                    ));
+                    self.pattern(pattern, &argument);
+                }
+            }
+        }
+    }
+}
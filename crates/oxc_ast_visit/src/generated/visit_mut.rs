@@ -21,8 +21,8 @@ use std::cell::Cell;
 use oxc_allocator::Vec;
 use oxc_syntax::scope::{ScopeFlags, ScopeId};
 
-use crate::ast::*;
-use crate::ast_kind::AstType;
+use oxc_ast::ast::*;
+use oxc_ast::AstType;
 
 use walk_mut::*;
 
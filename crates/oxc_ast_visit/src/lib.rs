@@ -0,0 +1,39 @@
+//! # Oxc AST Visitors
+//!
+//! Visitors for the AST nodes of [`oxc_ast`], generated from the AST definitions by the
+//! `ast_tools` task, so they are kept in sync with every AST change:
+//!
+//! * [`Visit`] visits the AST immutably, [`walk`] holds the default traversal of each node.
+//! * [`VisitMut`] visits the AST mutably, [`walk_mut`] holds the default traversal of each node.
+//!
+//! Override the `visit_*` methods of the nodes of interest, and call the matching `walk_*`
+//! function to continue visiting their children. Fields are visited in the order they are
+//! defined in the AST types.
+//!
+//! ```ignore
+//! use oxc_ast::ast::Function;
+//! use oxc_ast_visit::{walk, Visit};
+//! use oxc_syntax::scope::ScopeFlags;
+//!
+//! #[derive(Default)]
+//! struct CountFunctions(usize);
+//!
+//! impl<'a> Visit<'a> for CountFunctions {
+//!     fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+//!         self.0 += 1;
+//!         walk::walk_function(self, func, flags);
+//!     }
+//! }
+//! ```
+
+#![allow(clippy::self_named_module_files)]
+
+mod generated {
+    pub mod visit;
+    pub mod visit_mut;
+}
+
+pub use generated::{
+    visit::{walk, Visit},
+    visit_mut::{walk_mut, VisitMut},
+};
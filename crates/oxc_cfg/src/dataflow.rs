@@ -0,0 +1,194 @@
+//! A generic dataflow analysis framework over the [`ControlFlowGraph`].
+//!
+//! An analysis describes how its state flows through a basic block with
+//! [`DataflowAnalysis::transfer`], and how the states of control flow paths are merged with
+//! [`DataflowAnalysis::join`]. [`ControlFlowGraph::solve_dataflow`] computes the state at the
+//! entry and exit of every basic block, iterating until a fixed point is reached.
+
+use std::collections::VecDeque;
+
+use petgraph::{visit::EdgeRef, Direction};
+
+use crate::{BlockNodeId, ControlFlowGraph, EdgeType};
+
+/// The direction in which the state of a [`DataflowAnalysis`] flows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalysisDirection {
+    /// From the predecessors of a block to its successors, e.g. reaching definitions.
+    Forward,
+    /// From the successors of a block to its predecessors, e.g. liveness.
+    Backward,
+}
+
+pub trait DataflowAnalysis {
+    /// The state of the analysis at a program point.
+    type Domain: Clone + Eq;
+
+    const DIRECTION: AnalysisDirection;
+
+    /// The initial state of every block, which must be the identity of [`Self::join`].
+    fn bottom(&self) -> Self::Domain;
+
+    /// The state flowing into the blocks at the boundary of the graph: blocks without
+    /// predecessors for forward analyses, and blocks without successors for backward analyses.
+    fn boundary(&self) -> Self::Domain;
+
+    /// Merge the state of another control flow path into `state`.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain);
+
+    /// Apply the effects of `block` to `state`, from its entry to its exit for forward analyses,
+    /// and from its exit to its entry for backward analyses.
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain);
+
+    /// Whether the state flows along edges of `kind`.
+    ///
+    /// By default, state does not flow into unreachable code or into nested functions.
+    fn follows_edge(&self, kind: &EdgeType) -> bool {
+        !matches!(kind, EdgeType::Unreachable | EdgeType::NewFunction)
+    }
+}
+
+/// The states computed by [`ControlFlowGraph::solve_dataflow`].
+#[derive(Debug)]
+pub struct DataflowResults<D> {
+    entry: Vec<D>,
+    exit: Vec<D>,
+}
+
+impl<D> DataflowResults<D> {
+    /// The state at the entry of `block`.
+    pub fn entry(&self, block: BlockNodeId) -> &D {
+        &self.entry[block.index()]
+    }
+
+    /// The state at the exit of `block`.
+    pub fn exit(&self, block: BlockNodeId) -> &D {
+        &self.exit[block.index()]
+    }
+}
+
+impl ControlFlowGraph {
+    /// Run `analysis` on the graph until a fixed point is reached.
+    pub fn solve_dataflow<A: DataflowAnalysis>(&self, analysis: &A) -> DataflowResults<A::Domain> {
+        let graph = &self.graph;
+        let block_count = graph.node_count();
+        let (incoming, outgoing) = match A::DIRECTION {
+            AnalysisDirection::Forward => (Direction::Incoming, Direction::Outgoing),
+            AnalysisDirection::Backward => (Direction::Outgoing, Direction::Incoming),
+        };
+
+        // `input` is the state before a block is transferred, i.e. the entry of forward analyses
+        // and the exit of backward analyses.
+        let mut input = vec![analysis.bottom(); block_count];
+        let mut output = vec![analysis.bottom(); block_count];
+
+        let mut worklist = graph.node_indices().collect::<VecDeque<_>>();
+        if A::DIRECTION == AnalysisDirection::Backward {
+            // Visiting blocks in reverse converges faster.
+            worklist.make_contiguous().reverse();
+        }
+        let mut queued = vec![true; block_count];
+
+        while let Some(block) = worklist.pop_front() {
+            queued[block.index()] = false;
+
+            let mut state = analysis.bottom();
+            let mut has_incoming = false;
+            for edge in graph.edges_directed(block, incoming) {
+                if !analysis.follows_edge(edge.weight()) {
+                    continue;
+                }
+                let other = match A::DIRECTION {
+                    AnalysisDirection::Forward => edge.source(),
+                    AnalysisDirection::Backward => edge.target(),
+                };
+                analysis.join(&mut state, &output[other.index()]);
+                has_incoming = true;
+            }
+            if !has_incoming {
+                state = analysis.boundary();
+            }
+            input[block.index()] = state.clone();
+
+            analysis.transfer(block, &mut state);
+            if state == output[block.index()] {
+                continue;
+            }
+            output[block.index()] = state;
+
+            for edge in graph.edges_directed(block, outgoing) {
+                if !analysis.follows_edge(edge.weight()) {
+                    continue;
+                }
+                let next = match A::DIRECTION {
+                    AnalysisDirection::Forward => edge.target(),
+                    AnalysisDirection::Backward => edge.source(),
+                };
+                if !queued[next.index()] {
+                    queued[next.index()] = true;
+                    worklist.push_back(next);
+                }
+            }
+        }
+
+        match A::DIRECTION {
+            AnalysisDirection::Forward => DataflowResults { entry: input, exit: output },
+            AnalysisDirection::Backward => DataflowResults { entry: output, exit: input },
+        }
+    }
+}
+
+/// A fixed size set of indices, the usual domain of dataflow analyses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Create an empty set of indices less than `len`.
+    pub fn new_empty(len: usize) -> Self {
+        Self { words: vec![0; len.div_ceil(64)] }
+    }
+
+    /// Create a set of all the indices less than `len`.
+    pub fn new_filled(len: usize) -> Self {
+        let mut set = Self { words: vec![u64::MAX; len.div_ceil(64)] };
+        if len % 64 != 0 {
+            if let Some(last) = set.words.last_mut() {
+                *last = (1 << (len % 64)) - 1;
+            }
+        }
+        set
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    pub fn insert(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        self.words[index / 64] &= !(1 << (index % 64));
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    pub fn intersect_with(&mut self, other: &Self) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+    }
+
+    /// Iterate over the indices in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            (0..64).filter(move |bit| word & (1 << bit) != 0).map(move |bit| i * 64 + bit)
+        })
+    }
+}
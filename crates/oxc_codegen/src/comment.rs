@@ -3,13 +3,21 @@ use rustc_hash::FxHashMap;
 use oxc_ast::{Comment, CommentKind};
 use oxc_syntax::identifier::is_line_terminator;
 
-use crate::{Codegen, LegalComment};
+use crate::{Codegen, CommentMode, LegalComment};
 
 pub(crate) type CommentsMap = FxHashMap</* attached_to */ u32, Vec<Comment>>;
 
 impl<'a> Codegen<'a> {
+    /// Only comments that can be printed in [crate::CodegenOptions::comments] mode are kept, so that
+    /// the others do not affect the layout either.
     pub(crate) fn build_comments(&mut self, comments: &[Comment]) {
         for comment in comments {
+            if !self.options.comments.is_all()
+                && !self.is_annotation_comment(comment)
+                && !(self.options.print_legal_comments() && comment.is_legal(self.source_text))
+            {
+                continue;
+            }
             self.comments.entry(comment.attached_to).or_default().push(*comment);
         }
     }
@@ -89,7 +97,7 @@ impl<'a> Codegen<'a> {
             }
             if comment.is_legal(self.source_text) {
                 match &self.options.legal_comments {
-                    LegalComment::None if self.options.comments => {
+                    LegalComment::None if self.options.comments != CommentMode::None => {
                         leading_comments.push(comment);
                         continue;
                     }
@@ -8,6 +8,8 @@ bitflags! {
         const FORBID_IN   = 1 << 0;
         const FORBID_CALL = 1 << 1;
         const TYPESCRIPT  = 1 << 2;
+        /// The statement is the body of `if`, `for`, `while`, a label, etc.
+        const STATEMENT_BODY = 1 << 3;
     }
 }
 
@@ -22,6 +24,11 @@ impl Context {
         self.contains(Self::FORBID_CALL)
     }
 
+    #[inline]
+    pub fn statement_body(self) -> bool {
+        self.contains(Self::STATEMENT_BODY)
+    }
+
     #[inline]
     #[must_use]
     pub fn with_typescript(mut self) -> Self {
@@ -41,6 +48,12 @@ impl Context {
         self.and(Self::FORBID_CALL, include)
     }
 
+    #[inline]
+    #[must_use]
+    pub fn and_statement_body(self, include: bool) -> Self {
+        self.and(Self::STATEMENT_BODY, include)
+    }
+
     #[inline]
     fn and(self, flag: Self, set: bool) -> Self {
         if set {
@@ -4,8 +4,8 @@ use cow_utils::CowUtils;
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
 use oxc_syntax::{
-    identifier::{LS, PS},
-    operator::UnaryOperator,
+    identifier::{is_line_terminator, is_white_space_single_line, LS, PS},
+    operator::{BinaryOperator, UnaryOperator},
     precedence::{GetPrecedence, Precedence},
 };
 
@@ -41,6 +41,7 @@ impl<'a> Gen for Program<'a> {
         if let Some(hashbang) = &self.hashbang {
             hashbang.print(p, ctx);
         }
+        p.print_banner();
         for directive in &self.directives {
             directive.print(p, ctx);
         }
@@ -61,7 +62,8 @@ impl<'a> Gen for Hashbang<'a> {
 
 impl<'a> Gen for Directive<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_statement_source_mapping();
+        p.add_source_mapping(self.span);
         p.print_indent();
         // A Use Strict Directive may not contain an EscapeSequence or LineContinuation.
         // So here should print original `directive` value, the `expression` value is escaped str.
@@ -77,6 +79,9 @@ impl<'a> Gen for Directive<'a> {
 impl<'a> Gen for Statement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_statement_comments(self.span().start);
+        p.add_statement_source_mapping();
+        // Only the statement itself is the body, not the statements nested in it.
+        let (body_ctx, ctx) = (ctx, ctx.and_statement_body(false));
         match self {
             Self::BlockStatement(stmt) => stmt.print(p, ctx),
             Self::BreakStatement(stmt) => stmt.print(p, ctx),
@@ -84,7 +89,7 @@ impl<'a> Gen for Statement<'a> {
             Self::DebuggerStatement(stmt) => stmt.print(p, ctx),
             Self::DoWhileStatement(stmt) => stmt.print(p, ctx),
             Self::EmptyStatement(stmt) => stmt.print(p, ctx),
-            Self::ExpressionStatement(stmt) => stmt.print(p, ctx),
+            Self::ExpressionStatement(stmt) => stmt.print(p, body_ctx),
             Self::ForInStatement(stmt) => stmt.print(p, ctx),
             Self::ForOfStatement(stmt) => stmt.print(p, ctx),
             Self::ForStatement(stmt) => stmt.print(p, ctx),
@@ -149,9 +154,17 @@ impl<'a> Gen for Statement<'a> {
 }
 
 impl<'a> Gen for ExpressionStatement<'a> {
-    fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+    fn gen(&self, p: &mut Codegen, ctx: Context) {
+        p.add_source_mapping(self.span);
         p.print_indent();
+        // A `;` here would end the body of `if (a) ;(b)()`, so bodies are left as they are.
+        if p.options.omit_semicolons
+            && !p.options.minify
+            && !ctx.statement_body()
+            && starts_with_asi_hazard(&self.expression)
+        {
+            p.print_semicolon();
+        }
         p.start_of_stmt = p.code_len();
         p.print_expression(&self.expression);
         if self.expression.is_specific_id("let") {
@@ -162,9 +175,120 @@ impl<'a> Gen for ExpressionStatement<'a> {
     }
 }
 
+/// Whether a statement starting with `expr` would continue the previous line when the previous
+/// statement has no semicolon, e.g. `(a || b).c()` or `[a, b].forEach(f)`.
+///
+/// Errs on the side of `true`, as an extra `;` is harmless.
+fn starts_with_asi_hazard(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::ArrayExpression(_)
+        | Expression::ObjectExpression(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ClassExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::JSXElement(_)
+        | Expression::JSXFragment(_)
+        | Expression::TSTypeAssertion(_) => true,
+        Expression::UnaryExpression(e) => {
+            matches!(e.operator, UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation)
+        }
+        Expression::UpdateExpression(e) => e.prefix || assignment_target_hazard(&e.argument),
+        Expression::AssignmentExpression(e) => {
+            e.left.as_simple_assignment_target().map_or(true, assignment_target_hazard)
+        }
+        Expression::ParenthesizedExpression(e) => starts_with_asi_hazard(&e.expression),
+        Expression::CallExpression(e) => callee_hazard(&e.callee),
+        Expression::TaggedTemplateExpression(e) => callee_hazard(&e.tag),
+        Expression::ChainExpression(e) => match &e.expression {
+            ChainElement::CallExpression(call) => callee_hazard(&call.callee),
+            element => element.as_member_expression().map_or(true, |e| callee_hazard(e.object())),
+        },
+        match_member_expression!(Expression) => callee_hazard(expr.to_member_expression().object()),
+        Expression::TSAsExpression(e) => callee_hazard(&e.expression),
+        Expression::TSSatisfiesExpression(e) => callee_hazard(&e.expression),
+        Expression::TSNonNullExpression(e) => callee_hazard(&e.expression),
+        Expression::TSInstantiationExpression(e) => callee_hazard(&e.expression),
+        Expression::BinaryExpression(e) => match &e.left {
+            Expression::BinaryExpression(left)
+                if left.operator == e.operator && e.operator != BinaryOperator::Exponential =>
+            {
+                starts_with_asi_hazard(&e.left)
+            }
+            left => operand_hazard(left, e.precedence()),
+        },
+        Expression::LogicalExpression(e) => match &e.left {
+            Expression::LogicalExpression(left) if left.operator == e.operator => {
+                starts_with_asi_hazard(&e.left)
+            }
+            left => operand_hazard(left, e.precedence()),
+        },
+        Expression::ConditionalExpression(e) => operand_hazard(&e.test, Precedence::Conditional),
+        Expression::SequenceExpression(e) => match e.expressions.first() {
+            Some(Expression::SequenceExpression(_)) | None => true,
+            Some(first) => starts_with_asi_hazard(first),
+        },
+        _ => false,
+    }
+}
+
+fn assignment_target_hazard(target: &SimpleAssignmentTarget<'_>) -> bool {
+    match target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(_) => false,
+        target => target.as_member_expression().map_or(true, |e| callee_hazard(e.object())),
+    }
+}
+
+/// The callee of a call or the object of a member expression is parenthesized unless it is a
+/// primary, call or member expression.
+fn callee_hazard(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::ParenthesizedExpression(e) => callee_hazard(&e.expression),
+        Expression::Identifier(_)
+        | Expression::ThisExpression(_)
+        | Expression::Super(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::MetaProperty(_)
+        | Expression::ImportExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::ArrayExpression(_)
+        | match_member_expression!(Expression) => starts_with_asi_hazard(expr),
+        _ => true,
+    }
+}
+
+/// The left operand of an operator with `precedence` is parenthesized unless it binds tighter.
+fn operand_hazard(expr: &Expression<'_>, precedence: Precedence) -> bool {
+    match expr {
+        Expression::ParenthesizedExpression(e) => operand_hazard(&e.expression, precedence),
+        Expression::BinaryExpression(_) | Expression::LogicalExpression(_) => {
+            expr.precedence() <= precedence || starts_with_asi_hazard(expr)
+        }
+        Expression::UnaryExpression(_) | Expression::AwaitExpression(_) => {
+            precedence == Precedence::Exponentiation || starts_with_asi_hazard(expr)
+        }
+        Expression::SequenceExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::ConditionalExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::PrivateInExpression(_)
+        | Expression::TSAsExpression(_)
+        | Expression::TSSatisfiesExpression(_) => true,
+        _ => starts_with_asi_hazard(expr),
+    }
+}
+
 impl<'a> Gen for IfStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         print_if(self, p, ctx);
     }
@@ -189,10 +313,10 @@ fn print_if(if_stmt: &IfStatement<'_>, p: &mut Codegen, ctx: Context) {
         }
         stmt if wrap_to_avoid_ambiguous_else(stmt) => {
             p.print_soft_space();
-            p.print_block_start(stmt.span().start);
+            p.print_block_start(stmt.span());
             stmt.print(p, ctx);
             p.needs_semicolon = false;
-            p.print_block_end(stmt.span().end);
+            p.print_block_end(stmt.span());
             if if_stmt.alternate.is_some() {
                 p.print_soft_space();
             } else {
@@ -253,7 +377,7 @@ impl<'a> Gen for BlockStatement<'a> {
 
 impl<'a> Gen for ForStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("for");
         p.print_soft_space();
@@ -284,7 +408,7 @@ impl<'a> Gen for ForStatement<'a> {
 
 impl<'a> Gen for ForInStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("for");
         p.print_soft_space();
@@ -302,7 +426,7 @@ impl<'a> Gen for ForInStatement<'a> {
 
 impl<'a> Gen for ForOfStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("for");
         if self.r#await {
@@ -348,7 +472,7 @@ impl<'a> Gen for ForStatementLeft<'a> {
 
 impl<'a> Gen for WhileStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("while");
         p.print_soft_space();
@@ -361,7 +485,7 @@ impl<'a> Gen for WhileStatement<'a> {
 
 impl<'a> Gen for DoWhileStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("do ");
         if let Statement::BlockStatement(block) = &self.body {
@@ -386,7 +510,7 @@ impl<'a> Gen for DoWhileStatement<'a> {
 
 impl Gen for EmptyStatement {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_semicolon();
         p.print_soft_newline();
@@ -395,7 +519,7 @@ impl Gen for EmptyStatement {
 
 impl<'a> Gen for ContinueStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("continue");
         if let Some(label) = &self.label {
@@ -408,7 +532,7 @@ impl<'a> Gen for ContinueStatement<'a> {
 
 impl<'a> Gen for BreakStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("break");
         if let Some(label) = &self.label {
@@ -421,7 +545,7 @@ impl<'a> Gen for BreakStatement<'a> {
 
 impl<'a> Gen for SwitchStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("switch");
         p.print_soft_space();
@@ -431,7 +555,7 @@ impl<'a> Gen for SwitchStatement<'a> {
         p.print_soft_space();
         p.print_curly_braces(self.span, self.cases.is_empty(), |p| {
             for case in &self.cases {
-                p.add_source_mapping(case.span.start);
+                p.add_source_mapping(case.span);
                 case.print(p, ctx);
             }
         });
@@ -470,7 +594,7 @@ impl<'a> Gen for SwitchCase<'a> {
 
 impl<'a> Gen for ReturnStatement<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_space_before_identifier();
         p.print_str("return");
@@ -485,7 +609,7 @@ impl<'a> Gen for ReturnStatement<'a> {
 impl<'a> Gen for LabeledStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         if !p.options.minify && (p.indent > 0 || p.print_next_indent_as_space) {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_indent();
         }
         p.print_space_before_identifier();
@@ -497,7 +621,7 @@ impl<'a> Gen for LabeledStatement<'a> {
 
 impl<'a> Gen for TryStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_space_before_identifier();
         p.print_str("try");
@@ -527,7 +651,7 @@ impl<'a> Gen for TryStatement<'a> {
 
 impl<'a> Gen for ThrowStatement<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("throw ");
         p.print_expression(&self.argument);
@@ -537,7 +661,7 @@ impl<'a> Gen for ThrowStatement<'a> {
 
 impl<'a> Gen for WithStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("with");
         p.print_ascii_byte(b'(');
@@ -549,7 +673,7 @@ impl<'a> Gen for WithStatement<'a> {
 
 impl Gen for DebuggerStatement {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("debugger");
         p.print_semicolon_after_statement();
@@ -558,7 +682,7 @@ impl Gen for DebuggerStatement {
 
 impl<'a> Gen for VariableDeclaration<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if self.declare {
             p.print_str("declare ");
         }
@@ -617,7 +741,7 @@ impl<'a> Gen for Function<'a> {
         p.print_annotation_comments(self.span.start);
         p.wrap(wrap, |p| {
             p.print_space_before_identifier();
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             if self.declare {
                 p.print_str("declare ");
             }
@@ -698,7 +822,7 @@ impl<'a> Gen for FormalParameters<'a> {
         if let Some(rest) = &self.rest {
             if !self.items.is_empty() {
                 p.print_comma();
-                p.print_soft_space();
+                p.print_list_space();
             }
             rest.print(p, ctx);
         }
@@ -707,12 +831,16 @@ impl<'a> Gen for FormalParameters<'a> {
 
 impl<'a> Gen for ImportDeclaration<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("import ");
         if self.import_kind.is_type() {
             p.print_str("type ");
         }
+        if let Some(phase) = self.phase {
+            p.print_str(phase.as_str());
+            p.print_hard_space();
+        }
         if let Some(specifiers) = &self.specifiers {
             if specifiers.is_empty() {
                 p.print_str("{}");
@@ -795,19 +923,19 @@ impl<'a> Gen for ImportDeclaration<'a> {
             p.print_hard_space();
             with_clause.print(p, ctx);
         }
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
         p.print_semicolon_after_statement();
     }
 }
 
 impl<'a> Gen for WithClause<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         self.attributes_keyword.print(p, ctx);
         p.print_soft_space();
-        p.print_block_start(self.span.start);
+        p.print_block_start(self.span);
         p.print_sequence(&self.with_entries, ctx);
-        p.print_block_end(self.span.end);
+        p.print_block_end(self.span);
     }
 }
 
@@ -827,7 +955,7 @@ impl<'a> Gen for ImportAttribute<'a> {
 
 impl<'a> Gen for ExportNamedDeclaration<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
 
         if p.options.print_annotation_comments() {
@@ -950,7 +1078,7 @@ impl<'a> Gen for ModuleExportName<'a> {
 
 impl<'a> Gen for ExportAllDeclaration<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("export ");
         if self.export_kind.is_type() {
@@ -975,7 +1103,7 @@ impl<'a> Gen for ExportAllDeclaration<'a> {
 
 impl<'a> Gen for ExportDefaultDeclaration<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("export default ");
         self.declaration.print(p, ctx);
@@ -1010,7 +1138,9 @@ impl<'a> GenExpr for Expression<'a> {
             Self::NumericLiteral(lit) => lit.print_expr(p, precedence, ctx),
             Self::BigIntLiteral(lit) => lit.print(p, ctx),
             Self::RegExpLiteral(lit) => lit.print(p, ctx),
-            Self::StringLiteral(lit) => lit.print(p, ctx),
+            Self::StringLiteral(lit) => {
+                print_string_literal(lit, /* allow_backtick */ true, p);
+            }
             Self::Identifier(ident) => ident.print(p, ctx),
             Self::ThisExpression(expr) => expr.print(p, ctx),
             match_member_expression!(Self) => {
@@ -1031,7 +1161,7 @@ impl<'a> GenExpr for Expression<'a> {
             Self::AssignmentExpression(expr) => expr.print_expr(p, precedence, ctx),
             Self::SequenceExpression(expr) => expr.print_expr(p, precedence, ctx),
             Self::ImportExpression(expr) => expr.print_expr(p, precedence, ctx),
-            Self::TemplateLiteral(literal) => literal.print(p, ctx),
+            Self::TemplateLiteral(literal) => print_template_literal(literal, p, ctx),
             Self::TaggedTemplateExpression(expr) => expr.print(p, ctx),
             Self::Super(sup) => sup.print(p, ctx),
             Self::AwaitExpression(expr) => expr.print_expr(p, precedence, ctx),
@@ -1068,7 +1198,7 @@ impl<'a> Gen for IdentifierReference<'a> {
 
 impl<'a> Gen for IdentifierName<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(self.name.as_str());
     }
 }
@@ -1090,7 +1220,7 @@ impl<'a> Gen for LabelIdentifier<'a> {
 
 impl Gen for BooleanLiteral {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_space_before_identifier();
         p.print_str(self.as_str());
     }
@@ -1099,14 +1229,14 @@ impl Gen for BooleanLiteral {
 impl Gen for NullLiteral {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str("null");
     }
 }
 
 impl<'a> GenExpr for NumericLiteral<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         let value = self.value;
         if ctx.contains(Context::TYPESCRIPT) {
             p.print_str(self.raw);
@@ -1150,14 +1280,14 @@ impl<'a> Gen for BigIntLiteral<'a> {
             p.print_space_before_operator(Operator::Unary(UnaryOperator::UnaryNegation));
         }
         p.print_space_before_identifier();
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(self.raw.as_str());
     }
 }
 
 impl<'a> Gen for RegExpLiteral<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         let last = p.last_byte();
         let pattern_text = self.regex.pattern.source_text(p.source_text);
         // Avoid forming a single-line comment or "</script" sequence
@@ -1201,6 +1331,10 @@ fn print_unquoted_str(s: &str, quote: u8, p: &mut Codegen) {
             '\u{c}' => {
                 p.print_str("\\f");
             }
+            // Line breaks are allowed in template literals.
+            '\n' if quote == b'`' => {
+                p.print_hard_newline();
+            }
             '\n' => {
                 p.print_str("\\n");
             }
@@ -1235,7 +1369,7 @@ fn print_unquoted_str(s: &str, quote: u8, p: &mut Codegen) {
                 }
             }
             '$' => {
-                if chars.peek().is_some_and(|&next| next == '{') {
+                if quote == b'`' && chars.peek().is_some_and(|&next| next == '{') {
                     p.print_str("\\$");
                 } else {
                     p.print_str("$");
@@ -1246,7 +1380,9 @@ fn print_unquoted_str(s: &str, quote: u8, p: &mut Codegen) {
             // <https://github.com/tc39/proposal-json-superset>
             LS => p.print_str("\\u2028"),
             PS => p.print_str("\\u2029"),
-            '\u{a0}' => {
+            // Other characters are printed as they are, which is shorter than any escape, except
+            // for the control characters above which are kept escaped for readability.
+            '\u{a0}' if !p.options.minify => {
                 p.print_str("\\xA0");
             }
             _ => {
@@ -1258,17 +1394,24 @@ fn print_unquoted_str(s: &str, quote: u8, p: &mut Codegen) {
 
 impl<'a> Gen for StringLiteral<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
-        let s = self.value.as_str();
-        p.wrap_quote(|p, quote| {
-            print_unquoted_str(s, quote, p);
-        });
+        print_string_literal(self, /* allow_backtick */ false, p);
     }
 }
 
+/// Property names, module specifiers and types cannot be template literals, so a string literal
+/// is only printed with backticks in expression position.
+fn print_string_literal(lit: &StringLiteral<'_>, allow_backtick: bool, p: &mut Codegen) {
+    p.add_source_mapping(lit.span);
+    let s = lit.value.as_str();
+    let quote = p.quote_for(s, allow_backtick);
+    p.print_ascii_byte(quote);
+    print_unquoted_str(s, quote, p);
+    p.print_ascii_byte(quote);
+}
+
 impl Gen for ThisExpression {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_space_before_identifier();
         p.print_str("this");
     }
@@ -1303,6 +1446,16 @@ impl<'a> GenExpr for ComputedMemberExpression<'a> {
 impl<'a> GenExpr for StaticMemberExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, _precedence: Precedence, ctx: Context) {
         self.object.print_expr(p, Precedence::Postfix, ctx.intersection(Context::FORBID_CALL));
+        if p.is_quoted_property_name(&self.property.name) {
+            // `a.default` -> `a["default"]`
+            if self.optional {
+                p.print_str("?.");
+            }
+            p.print_ascii_byte(b'[');
+            print_quoted_property_name(&self.property, p);
+            p.print_ascii_byte(b']');
+            return;
+        }
         if self.optional {
             p.print_ascii_byte(b'?');
         } else if p.need_space_before_dot == p.code_len() {
@@ -1314,6 +1467,16 @@ impl<'a> GenExpr for StaticMemberExpression<'a> {
     }
 }
 
+/// Print a property name as a string, see [crate::CodegenOptions::quote_reserved_properties].
+///
+/// Reserved words contain no characters which need escaping.
+fn print_quoted_property_name(name: &IdentifierName<'_>, p: &mut Codegen) {
+    p.add_source_mapping(name.span);
+    p.print_ascii_byte(p.quote);
+    p.print_str(name.name.as_str());
+    p.print_ascii_byte(p.quote);
+}
+
 impl<'a> GenExpr for PrivateFieldExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, _precedence: Precedence, ctx: Context) {
         self.object.print_expr(p, Precedence::Prefix, ctx.intersection(Context::FORBID_CALL));
@@ -1338,7 +1501,7 @@ impl<'a> GenExpr for CallExpression<'a> {
             if is_export_default {
                 p.start_of_default_export = p.code_len();
             }
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             self.callee.print_expr(p, Precedence::Postfix, Context::empty());
             if self.optional {
                 p.print_str("?.");
@@ -1363,7 +1526,7 @@ impl<'a> GenExpr for CallExpression<'a> {
                 p.print_list(&self.arguments, ctx);
             }
             p.print_ascii_byte(b')');
-            p.add_source_mapping(self.span.end);
+            p.add_source_mapping_end(self.span);
         });
     }
 }
@@ -1393,7 +1556,7 @@ impl<'a> Gen for ArrayExpressionElement<'a> {
 
 impl<'a> Gen for SpreadElement<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ellipsis();
         self.argument.print_expr(p, Precedence::Comma, Context::empty());
     }
@@ -1402,7 +1565,7 @@ impl<'a> Gen for SpreadElement<'a> {
 impl<'a> Gen for ArrayExpression<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         let is_multi_line = self.elements.len() > 2;
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ascii_byte(b'[');
         if is_multi_line {
             p.indent();
@@ -1415,7 +1578,7 @@ impl<'a> Gen for ArrayExpression<'a> {
                 p.print_soft_newline();
                 p.print_indent();
             } else if i != 0 {
-                p.print_soft_space();
+                p.print_list_space();
             }
             item.print(p, ctx);
             if i == self.elements.len() - 1 && matches!(item, ArrayExpressionElement::Elision(_)) {
@@ -1427,7 +1590,7 @@ impl<'a> Gen for ArrayExpression<'a> {
             p.dedent();
             p.print_indent();
         }
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
         p.print_ascii_byte(b']');
     }
 }
@@ -1439,7 +1602,7 @@ impl<'a> GenExpr for ObjectExpression<'a> {
         let is_multi_line = len > 1;
         let wrap = p.start_of_stmt == n || p.start_of_arrow_expr == n;
         p.wrap(wrap, |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_ascii_byte(b'{');
             if is_multi_line {
                 p.indent();
@@ -1463,7 +1626,7 @@ impl<'a> GenExpr for ObjectExpression<'a> {
             } else if len > 0 {
                 p.print_soft_space();
             }
-            p.add_source_mapping(self.span.end);
+            p.add_source_mapping_end(self.span);
             p.print_ascii_byte(b'}');
         });
     }
@@ -1481,16 +1644,16 @@ impl<'a> Gen for ObjectPropertyKind<'a> {
 impl<'a> Gen for ObjectProperty<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         if let Expression::FunctionExpression(func) = &self.value {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             let is_accessor = match &self.kind {
                 PropertyKind::Init => false,
                 PropertyKind::Get => {
-                    p.add_source_mapping(self.span.start);
+                    p.add_source_mapping(self.span);
                     p.print_str("get ");
                     true
                 }
                 PropertyKind::Set => {
-                    p.add_source_mapping(self.span.start);
+                    p.add_source_mapping(self.span);
                     p.print_str("set ");
                     true
                 }
@@ -1528,7 +1691,10 @@ impl<'a> Gen for ObjectProperty<'a> {
             if key.name == "__proto__" {
                 shorthand = self.shorthand;
             } else if let Expression::Identifier(ident) = self.value.without_parentheses() {
-                if key.name == p.get_identifier_reference_name(ident) {
+                // `{ let }` is printed as `{ "let": let }` when the key is quoted.
+                if key.name == p.get_identifier_reference_name(ident)
+                    && !p.is_quoted_property_name(&key.name)
+                {
                     shorthand = true;
                 }
             }
@@ -1566,11 +1732,15 @@ impl<'a> Gen for ObjectProperty<'a> {
 impl<'a> Gen for PropertyKey<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
+            Self::StaticIdentifier(ident) if p.is_quoted_property_name(&ident.name) => {
+                print_quoted_property_name(ident, p);
+            }
             Self::StaticIdentifier(ident) => ident.print(p, ctx),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
-            match_expression!(Self) => {
-                self.to_expression().print_expr(p, Precedence::Comma, Context::empty());
-            }
+            match_expression!(Self) => match self.to_expression() {
+                Expression::StringLiteral(lit) => lit.print(p, ctx),
+                expr => expr.print_expr(p, Precedence::Comma, Context::empty()),
+            },
         }
     }
 }
@@ -1580,7 +1750,7 @@ impl<'a> GenExpr for ArrowFunctionExpression<'a> {
         p.wrap(precedence >= Precedence::Assign, |p| {
             p.print_annotation_comments(self.span.start);
             if self.r#async {
-                p.add_source_mapping(self.span.start);
+                p.add_source_mapping(self.span);
                 p.print_str("async");
             }
 
@@ -1591,7 +1761,7 @@ impl<'a> GenExpr for ArrowFunctionExpression<'a> {
             if let Some(type_parameters) = &self.type_parameters {
                 type_parameters.print(p, ctx);
             }
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_ascii_byte(b'(');
             self.params.print(p, ctx);
             p.print_ascii_byte(b')');
@@ -1618,7 +1788,7 @@ impl<'a> GenExpr for ArrowFunctionExpression<'a> {
 impl<'a> GenExpr for YieldExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, _ctx: Context) {
         p.wrap(precedence >= Precedence::Assign, |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_space_before_identifier();
             p.print_str("yield");
             if self.delegate {
@@ -1640,7 +1810,7 @@ impl<'a> GenExpr for UpdateExpression<'a> {
         let operator = self.operator.as_str();
         p.wrap(precedence >= self.precedence(), |p| {
             if self.prefix {
-                p.add_source_mapping(self.span.start);
+                p.add_source_mapping(self.span);
                 p.print_space_before_operator(self.operator.into());
                 p.print_str(operator);
                 p.prev_op = Some(self.operator.into());
@@ -1807,7 +1977,7 @@ impl<'a> Gen for AssignmentTargetPattern<'a> {
 
 impl<'a> Gen for ArrayAssignmentTarget<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ascii_byte(b'[');
         for (index, item) in self.elements.iter().enumerate() {
             if index != 0 {
@@ -1822,31 +1992,31 @@ impl<'a> Gen for ArrayAssignmentTarget<'a> {
             if !self.elements.is_empty() {
                 p.print_comma();
             }
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             target.print(p, ctx);
         }
         if self.trailing_comma.is_some() {
             p.print_comma();
         }
         p.print_ascii_byte(b']');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
 impl<'a> Gen for ObjectAssignmentTarget<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ascii_byte(b'{');
         p.print_list(&self.properties, ctx);
         if let Some(target) = &self.rest {
             if !self.properties.is_empty() {
                 p.print_comma();
             }
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             target.print(p, ctx);
         }
         p.print_ascii_byte(b'}');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
@@ -1944,7 +2114,7 @@ impl<'a> GenExpr for ImportExpression<'a> {
             || self.arguments.first().is_some_and(|argument| p.has_comment(argument.span().start));
 
         p.wrap(wrap, |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_str("import(");
             if has_comment {
                 p.indent();
@@ -1984,7 +2154,7 @@ impl<'a> Gen for TemplateLiteral<'a> {
         let mut expressions = self.expressions.iter();
 
         for quasi in &self.quasis {
-            p.add_source_mapping(quasi.span.start);
+            p.add_source_mapping(quasi.span);
             p.print_str(quasi.value.raw.as_str());
 
             if let Some(expr) = expressions.next() {
@@ -1998,9 +2168,27 @@ impl<'a> Gen for TemplateLiteral<'a> {
     }
 }
 
+/// When minifying, a template literal without substitutions is printed like a string literal,
+/// with whichever quote needs the fewest escapes, e.g. `` `a"b` `` as `'a"b'`.
+///
+/// Not at the start of a statement, where a string literal could become a directive.
+fn print_template_literal(lit: &TemplateLiteral<'_>, p: &mut Codegen, ctx: Context) {
+    if p.options.minify && lit.expressions.is_empty() && p.start_of_stmt != p.code_len() {
+        if let Some(cooked) = lit.quasis.first().and_then(|quasi| quasi.value.cooked.as_ref()) {
+            p.add_source_mapping(lit.span);
+            let quote = p.quote_for(cooked, /* allow_backtick */ true);
+            p.print_ascii_byte(quote);
+            print_unquoted_str(cooked, quote, p);
+            p.print_ascii_byte(quote);
+            return;
+        }
+    }
+    lit.print(p, ctx);
+}
+
 impl<'a> Gen for TaggedTemplateExpression<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         self.tag.print_expr(p, Precedence::Postfix, Context::empty());
         if let Some(type_parameters) = &self.type_parameters {
             type_parameters.print(p, ctx);
@@ -2011,7 +2199,7 @@ impl<'a> Gen for TaggedTemplateExpression<'a> {
 
 impl Gen for Super {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str("super");
     }
 }
@@ -2019,7 +2207,7 @@ impl Gen for Super {
 impl<'a> GenExpr for AwaitExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
         p.wrap(precedence >= self.precedence(), |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_str("await ");
             self.argument.print_expr(p, Precedence::Exponentiation, ctx);
         });
@@ -2046,7 +2234,7 @@ impl<'a> GenExpr for NewExpression<'a> {
         p.wrap(wrap, |p| {
             p.print_annotation_comments(self.span.start);
             p.print_space_before_identifier();
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_str("new ");
             self.callee.print_expr(p, Precedence::New, Context::FORBID_CALL);
             p.print_ascii_byte(b'(');
@@ -2132,7 +2320,7 @@ impl<'a> GenExpr for TSTypeAssertion<'a> {
 
 impl<'a> Gen for MetaProperty<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         self.meta.print(p, ctx);
         p.print_ascii_byte(b'.');
         self.property.print(p, ctx);
@@ -2141,7 +2329,7 @@ impl<'a> Gen for MetaProperty<'a> {
 
 impl<'a> Gen for Class<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         let n = p.code_len();
         let wrap = self.is_expression() && (p.start_of_stmt == n || p.start_of_default_export == n);
         p.wrap(wrap, |p| {
@@ -2207,15 +2395,15 @@ impl<'a> Gen for ClassElement<'a> {
             }
             Self::PropertyDefinition(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_member();
             }
             Self::AccessorProperty(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_member();
             }
             Self::TSIndexSignature(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_member();
             }
         }
     }
@@ -2292,7 +2480,9 @@ impl Gen for JSXEmptyExpression {
 impl<'a> Gen for JSXExpression<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
-            match_expression!(Self) => p.print_expression(self.to_expression()),
+            match_expression!(Self) => {
+                self.to_expression().print_expr(p, Precedence::Comma, Context::empty());
+            }
             Self::EmptyExpression(expr) => expr.print(p, ctx),
         }
     }
@@ -2341,9 +2531,12 @@ impl<'a> Gen for JSXAttributeItem<'a> {
 
 impl<'a> Gen for JSXOpeningElement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ascii_byte(b'<');
         self.name.print(p, ctx);
+        if let Some(type_parameters) = &self.type_parameters {
+            type_parameters.print(p, ctx);
+        }
         for attr in &self.attributes {
             match attr {
                 JSXAttributeItem::Attribute(_) => {
@@ -2365,7 +2558,7 @@ impl<'a> Gen for JSXOpeningElement<'a> {
 
 impl<'a> Gen for JSXClosingElement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str("</");
         self.name.print(p, ctx);
         p.print_ascii_byte(b'>');
@@ -2386,29 +2579,68 @@ impl<'a> Gen for JSXElement<'a> {
 
 impl Gen for JSXOpeningFragment {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str("<>");
     }
 }
 
 impl Gen for JSXClosingFragment {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str("</>");
     }
 }
 
 impl<'a> Gen for JSXText<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
-        p.print_str(self.value.as_str());
+        p.add_source_mapping(self.span);
+        if p.options.minify && !p.options.preserve_jsx_whitespace {
+            print_collapsed_jsx_text(self.value.as_str(), p);
+        } else {
+            p.print_str(self.value.as_str());
+        }
+    }
+}
+
+/// Print JSX text with its whitespace collapsed the way JSX transforms interpret it: the first
+/// line is trimmed at its end, the last line at its start and the other lines at both ends, then
+/// the lines which are not empty are joined with a single space.
+///
+/// Text on a single line is printed as is.
+fn print_collapsed_jsx_text(text: &str, p: &mut Codegen) {
+    let mut printed = false;
+    let mut print_line = |line: &str, p: &mut Codegen| {
+        if printed {
+            p.print_ascii_byte(b' ');
+        }
+        p.print_str(line);
+        printed = true;
+    };
+    let mut first_non_whitespace = Some(0);
+    let mut last_non_whitespace = None;
+    for (index, c) in text.char_indices() {
+        if is_line_terminator(c) {
+            if let (Some(first), Some(last)) = (first_non_whitespace, last_non_whitespace) {
+                print_line(&text[first..last], p);
+            }
+            first_non_whitespace = None;
+        } else if !is_white_space_single_line(c) {
+            last_non_whitespace = Some(index + c.len_utf8());
+            if first_non_whitespace.is_none() {
+                first_non_whitespace = Some(index);
+            }
+        }
+    }
+    if let Some(first) = first_non_whitespace {
+        print_line(&text[first..], p);
     }
 }
 
 impl<'a> Gen for JSXSpreadChild<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.print_str("...");
-        p.print_expression(&self.expression);
+        p.print_str("{...");
+        self.expression.print_expr(p, Precedence::Comma, Context::empty());
+        p.print_ascii_byte(b'}');
     }
 }
 
@@ -2417,7 +2649,7 @@ impl<'a> Gen for JSXChild<'a> {
         match self {
             Self::Fragment(fragment) => fragment.print(p, ctx),
             Self::Element(el) => el.print(p, ctx),
-            Self::Spread(spread) => p.print_expression(&spread.expression),
+            Self::Spread(spread) => spread.print(p, ctx),
             Self::ExpressionContainer(expr_container) => expr_container.print(p, ctx),
             Self::Text(text) => text.print(p, ctx),
         }
@@ -2436,7 +2668,7 @@ impl<'a> Gen for JSXFragment<'a> {
 
 impl<'a> Gen for StaticBlock<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str("static");
         p.print_soft_space();
         p.print_curly_braces(self.span, self.body.is_empty(), |p| {
@@ -2451,7 +2683,7 @@ impl<'a> Gen for StaticBlock<'a> {
 
 impl<'a> Gen for MethodDefinition<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         for decorator in &self.decorators {
             decorator.print(p, ctx);
             p.print_hard_space();
@@ -2518,7 +2750,7 @@ impl<'a> Gen for MethodDefinition<'a> {
 
 impl<'a> Gen for PropertyDefinition<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         for decorator in &self.decorators {
             decorator.print(p, ctx);
             p.print_hard_space();
@@ -2565,7 +2797,7 @@ impl<'a> Gen for PropertyDefinition<'a> {
 
 impl<'a> Gen for AccessorProperty<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         for decorator in &self.decorators {
             decorator.print(p, ctx);
             p.print_hard_space();
@@ -2640,7 +2872,7 @@ impl<'a> Gen for BindingPatternKind<'a> {
 
 impl<'a> Gen for ObjectPattern<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ascii_byte(b'{');
         if !self.is_empty() {
             p.print_soft_space();
@@ -2656,13 +2888,13 @@ impl<'a> Gen for ObjectPattern<'a> {
             p.print_soft_space();
         }
         p.print_ascii_byte(b'}');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
 impl<'a> Gen for BindingProperty<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if self.computed {
             p.print_ascii_byte(b'[');
         }
@@ -2704,7 +2936,7 @@ impl<'a> Gen for BindingProperty<'a> {
 
 impl<'a> Gen for BindingRestElement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ellipsis();
         self.argument.print(p, ctx);
     }
@@ -2712,7 +2944,7 @@ impl<'a> Gen for BindingRestElement<'a> {
 
 impl<'a> Gen for ArrayPattern<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ascii_byte(b'[');
         for (index, item) in self.elements.iter().enumerate() {
             if index != 0 {
@@ -2731,7 +2963,7 @@ impl<'a> Gen for ArrayPattern<'a> {
             rest.print(p, ctx);
         }
         p.print_ascii_byte(b']');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
@@ -2763,7 +2995,7 @@ impl<'a> Gen for Decorator<'a> {
             }
         }
 
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ascii_byte(b'@');
         let wrap = need_wrap(&self.expression);
         p.wrap(wrap, |p| {
@@ -22,6 +22,7 @@ use oxc_mangler::Mangler;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::{
     identifier::{is_identifier_part, is_identifier_part_ascii},
+    keyword::is_es3_reserved_word,
     operator::{BinaryOperator, UnaryOperator, UpdateOperator},
     precedence::Precedence,
 };
@@ -33,7 +34,10 @@ use crate::{
 pub use crate::{
     context::Context,
     gen::{Gen, GenExpr},
-    options::{CodegenOptions, LegalComment},
+    options::{
+        CodegenOptions, CommentMode, IndentChar, LegalComment, SourceMapDetail, SourceMapSources,
+        SourcesContent,
+    },
 };
 
 /// Code generator without whitespace removal.
@@ -124,6 +128,9 @@ pub struct Codegen<'a> {
     start_of_annotation_comment: Option<u32>,
 
     sourcemap_builder: Option<SourcemapBuilder>,
+    /// Whether the next mapping is the first one of a statement.
+    /// Only used with [`SourceMapDetail::Statement`].
+    start_of_sourcemap_stmt: bool,
 }
 
 impl<'a> Default for Codegen<'a> {
@@ -172,6 +179,7 @@ impl<'a> Codegen<'a> {
             start_of_annotation_comment: None,
             legal_comments: vec![],
             sourcemap_builder: None,
+            start_of_sourcemap_stmt: false,
         }
     }
 
@@ -202,12 +210,25 @@ impl<'a> Codegen<'a> {
             self.build_comments(&program.comments);
         }
         if let Some(path) = &self.options.source_map_path {
-            self.sourcemap_builder = Some(SourcemapBuilder::new(path, program.source_text));
+            let source = self.options.source_map_sources.rewrite(path);
+            self.sourcemap_builder = Some(SourcemapBuilder::new(&source, program.source_text));
         }
         program.print(&mut self, Context::default());
         self.try_print_eof_legal_comments();
+        self.print_footer();
         let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self.sourcemap_builder.map(|builder| {
+            let mut map = builder.into_sourcemap();
+            if let Some(source_root) = &self.options.source_root {
+                map.set_source_root(source_root);
+            }
+            if let Some(path) = &self.options.source_map_path {
+                if !self.options.sources_content.includes(path) {
+                    map.remove_source_contents();
+                }
+            }
+            map
+        });
         CodegenReturn { code, map, legal_comments: self.legal_comments }
     }
 
@@ -344,14 +365,79 @@ impl<'a> Codegen<'a> {
             self.print_next_indent_as_space = false;
             return;
         }
-        // SAFETY: this iterator only yields tabs, which are always valid ASCII characters.
+        self.print_indent_of_level(self.indent as usize);
+    }
+
+    #[inline]
+    fn print_indent_of_level(&mut self, level: usize) {
+        let byte = self.options.indent_char.as_byte();
+        let len = level * self.options.indent_width;
+        // SAFETY: this iterator only yields tabs or spaces, which are valid ASCII characters.
         unsafe {
-            self.code.print_bytes_unchecked(std::iter::repeat(b'\t').take(self.indent as usize));
+            self.code.print_bytes_unchecked(std::iter::repeat(byte).take(len));
+        }
+    }
+
+    /// Length of the line being printed, in characters.
+    fn current_line_len(&self) -> usize {
+        let bytes = self.code.as_bytes();
+        let start = bytes.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+        // Count the bytes that are not UTF-8 continuation bytes.
+        bytes[start..].iter().filter(|&&b| b & 0xC0 != 0x80).count()
+    }
+
+    /// Print the space between two items of a list, after the comma.
+    ///
+    /// Breaks the line instead if it is longer than [CodegenOptions::line_width], and indents the
+    /// next item by one more level.
+    fn print_list_space(&mut self) {
+        if self.options.minify {
+            return;
+        }
+        if self.options.line_width.is_some_and(|width| self.current_line_len() > width) {
+            self.print_hard_newline();
+            self.print_indent_of_level(self.indent as usize + 1);
+        } else {
+            self.print_hard_space();
+        }
+    }
+
+    fn print_banner(&mut self) {
+        if let Some(banner) = self.options.banner.clone() {
+            self.print_str(&banner);
+            if !banner.ends_with('\n') {
+                self.print_hard_newline();
+            }
+        }
+    }
+
+    fn print_footer(&mut self) {
+        if let Some(footer) = self.options.footer.clone() {
+            if self.last_byte().is_some_and(|b| b != b'\n') {
+                self.print_hard_newline();
+            }
+            self.print_str(&footer);
+            if !footer.ends_with('\n') {
+                self.print_hard_newline();
+            }
         }
     }
 
     #[inline]
     fn print_semicolon_after_statement(&mut self) {
+        if self.options.minify {
+            self.needs_semicolon = true;
+        } else if self.options.omit_semicolons {
+            self.print_hard_newline();
+        } else {
+            self.print_str(";\n");
+        }
+    }
+
+    /// Class fields keep their semicolon even with [CodegenOptions::omit_semicolons], since a
+    /// field followed by a computed member or a generator method would otherwise be ambiguous.
+    #[inline]
+    fn print_semicolon_after_class_member(&mut self) {
         if self.options.minify {
             self.needs_semicolon = true;
         } else {
@@ -390,7 +476,7 @@ impl<'a> Codegen<'a> {
     }
 
     fn print_curly_braces<F: FnOnce(&mut Self)>(&mut self, span: Span, single_line: bool, op: F) {
-        self.add_source_mapping(span.start);
+        self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
         if !single_line {
             self.print_soft_newline();
@@ -401,21 +487,21 @@ impl<'a> Codegen<'a> {
             self.dedent();
             self.print_indent();
         }
-        self.add_source_mapping(span.end);
+        self.add_source_mapping_end(span);
         self.print_ascii_byte(b'}');
     }
 
-    fn print_block_start(&mut self, position: u32) {
-        self.add_source_mapping(position);
+    fn print_block_start(&mut self, span: Span) {
+        self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
         self.print_soft_newline();
         self.indent();
     }
 
-    fn print_block_end(&mut self, position: u32) {
+    fn print_block_end(&mut self, span: Span) {
         self.dedent();
         self.print_indent();
-        self.add_source_mapping(position);
+        self.add_source_mapping_end(span);
         self.print_ascii_byte(b'}');
     }
 
@@ -435,7 +521,7 @@ impl<'a> Codegen<'a> {
                     self.print_hard_space();
                 }
                 self.print_next_indent_as_space = true;
-                stmt.print(self, ctx);
+                stmt.print(self, ctx.and_statement_body(true));
             }
         }
     }
@@ -467,7 +553,7 @@ impl<'a> Codegen<'a> {
         for (index, item) in items.iter().enumerate() {
             if index != 0 {
                 self.print_comma();
-                self.print_soft_space();
+                self.print_list_space();
             }
             item.print(self, ctx);
         }
@@ -493,12 +579,18 @@ impl<'a> Codegen<'a> {
         for (index, item) in items.iter().enumerate() {
             if index != 0 {
                 self.print_comma();
-                self.print_soft_space();
+                self.print_list_space();
             }
             item.print_expr(self, precedence, ctx);
         }
     }
 
+    /// Whether the property name `name` is printed as a string, see
+    /// [CodegenOptions::quote_reserved_properties].
+    fn is_quoted_property_name(&self, name: &str) -> bool {
+        self.options.quote_reserved_properties && is_es3_reserved_word(name)
+    }
+
     fn get_identifier_reference_name(&self, reference: &IdentifierReference<'a>) -> &'a str {
         if let Some(mangler) = &self.mangler {
             if let Some(reference_id) = reference.reference_id.get() {
@@ -632,6 +724,46 @@ impl<'a> Codegen<'a> {
         }
     }
 
+    /// The quote to print the string `s` with.
+    ///
+    /// When minifying, this is the quote that needs the fewest escapes, which can be a backtick if
+    /// `allow_backtick` and [CodegenOptions::template_literals] are set. See also
+    /// [CodegenOptions::avoid_escaped_quotes].
+    fn quote_for(&self, s: &str, allow_backtick: bool) -> u8 {
+        let minify = self.options.minify;
+        if !minify && !self.options.avoid_escaped_quotes {
+            return self.quote;
+        }
+        let (mut single_cost, mut double_cost, mut backtick_cost) = (0, 0, 0);
+        let mut bytes = s.bytes().peekable();
+        while let Some(b) = bytes.next() {
+            match b {
+                b'\n' => {
+                    single_cost += 1;
+                    double_cost += 1;
+                }
+                b'\'' => single_cost += 1,
+                b'"' => double_cost += 1,
+                b'`' => backtick_cost += 1,
+                b'$' if bytes.peek() == Some(&b'{') => backtick_cost += 1,
+                _ => {}
+            }
+        }
+        let alternate = if self.quote == b'\'' { b'"' } else { b'\'' };
+        let candidates =
+            if minify && allow_backtick && self.options.template_literals { 3 } else { 2 };
+        // Ties are resolved in favor of the preferred quote.
+        [self.quote, alternate, b'`']
+            .into_iter()
+            .take(candidates)
+            .min_by_key(|quote| match quote {
+                b'\'' => single_cost,
+                b'"' => double_cost,
+                _ => backtick_cost,
+            })
+            .unwrap_or(self.quote)
+    }
+
     #[inline]
     fn wrap_quote<F: FnMut(&mut Self, u8)>(&mut self, mut f: F) {
         self.print_ascii_byte(self.quote);
@@ -639,13 +771,47 @@ impl<'a> Codegen<'a> {
         self.print_ascii_byte(self.quote);
     }
 
-    fn add_source_mapping(&mut self, position: u32) {
+    /// Mark the start of a statement. With [`SourceMapDetail::Statement`], only the first
+    /// mapping after this is added, which is the first token of the statement.
+    fn add_statement_source_mapping(&mut self) {
+        self.start_of_sourcemap_stmt = true;
+    }
+
+    /// Whether a mapping should be added at the current position.
+    fn should_add_source_mapping(&mut self) -> bool {
+        if self.sourcemap_builder.is_none() {
+            return false;
+        }
+        if self.options.sourcemap_detail.is_statement() {
+            return std::mem::take(&mut self.start_of_sourcemap_stmt);
+        }
+        true
+    }
+
+    /// Nodes created by transforms or the minifier have no span; mapping them would point at the
+    /// start of the original file, so they inherit the mapping of the preceding token instead.
+    fn add_source_mapping(&mut self, span: Span) {
+        if span.is_unspanned() || !self.should_add_source_mapping() {
+            return;
+        }
         if let Some(sourcemap_builder) = self.sourcemap_builder.as_mut() {
-            sourcemap_builder.add_source_mapping(self.code.as_bytes(), position, None);
+            sourcemap_builder.add_source_mapping(self.code.as_bytes(), span.start, None);
+        }
+    }
+
+    fn add_source_mapping_end(&mut self, span: Span) {
+        if span.is_unspanned() || self.options.sourcemap_detail.is_statement() {
+            return;
+        }
+        if let Some(sourcemap_builder) = self.sourcemap_builder.as_mut() {
+            sourcemap_builder.add_source_mapping(self.code.as_bytes(), span.end, None);
         }
     }
 
     fn add_source_mapping_for_name(&mut self, span: Span, name: &str) {
+        if span.is_unspanned() || !self.should_add_source_mapping() {
+            return;
+        }
         if let Some(sourcemap_builder) = self.sourcemap_builder.as_mut() {
             sourcemap_builder.add_source_mapping_for_name(self.code.as_bytes(), span, name);
         }
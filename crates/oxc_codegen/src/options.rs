@@ -1,4 +1,8 @@
-use std::path::PathBuf;
+use std::{
+    fmt,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
 
 /// Legal comment
 ///
@@ -35,6 +39,183 @@ impl LegalComment {
     }
 }
 
+/// Which comments to print.
+///
+/// Annotation comments, e.g. `/* #__PURE__ */` and `/* #__NO_SIDE_EFFECTS__ */`, are printed in
+/// every mode, as removing them changes how bundlers and minifiers treat the code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum CommentMode {
+    /// Print annotation comments only.
+    None,
+    /// Print legal comments (`/*! ... */`, `@license` and `@preserve`) and annotation comments.
+    Legal,
+    /// Print all comments (default).
+    #[default]
+    All,
+}
+
+impl CommentMode {
+    /// Is all mode.
+    pub fn is_all(self) -> bool {
+        self == Self::All
+    }
+}
+
+/// Character used for indentation.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum IndentChar {
+    /// Indent with tabs (default).
+    #[default]
+    Tab,
+    /// Indent with spaces.
+    Space,
+}
+
+impl IndentChar {
+    pub(crate) fn as_byte(self) -> u8 {
+        match self {
+            Self::Tab => b'\t',
+            Self::Space => b' ',
+        }
+    }
+}
+
+/// Granularity of the mappings in the source map.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum SourceMapDetail {
+    /// Map the start of every statement only.
+    ///
+    /// Much smaller and faster to generate, and still good enough to locate lines, e.g. for
+    /// stack traces in a dev server.
+    Statement,
+    /// Map every token, including names (default).
+    #[default]
+    Token,
+}
+
+impl SourceMapDetail {
+    /// Is statement mode.
+    pub fn is_statement(self) -> bool {
+        self == Self::Statement
+    }
+}
+
+/// How the source path is written to `sources` of the source map.
+#[derive(Clone, Default)]
+pub enum SourceMapSources {
+    /// Use [`CodegenOptions::source_map_path`] as is (default).
+    #[default]
+    Path,
+    /// Absolute path, resolved against the current working directory.
+    Absolute,
+    /// Path relative to the given directory, e.g. the output directory of the chunk.
+    RelativeTo(PathBuf),
+    /// Rewrite the path with a custom function.
+    Custom(Arc<dyn Fn(&Path) -> String + Send + Sync>),
+}
+
+impl fmt::Debug for SourceMapSources {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path => f.write_str("Path"),
+            Self::Absolute => f.write_str("Absolute"),
+            Self::RelativeTo(dir) => f.debug_tuple("RelativeTo").field(dir).finish(),
+            Self::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+impl SourceMapSources {
+    /// Get the path written to `sources` for `path`.
+    pub fn rewrite(&self, path: &Path) -> String {
+        match self {
+            Self::Path => path.to_string_lossy().into_owned(),
+            Self::Absolute => absolute_path(path)
+                .unwrap_or_else(|| path.to_path_buf())
+                .to_string_lossy()
+                .into_owned(),
+            Self::RelativeTo(dir) => relative_path(dir, path),
+            Self::Custom(rewrite) => rewrite(path),
+        }
+    }
+}
+
+/// Compute `path` relative to `dir`, separated by `/`.
+///
+/// Relative paths are resolved against the current working directory first. `path` is returned
+/// as is if that fails, and as an absolute path if it has no common ancestor with `dir`, e.g. on
+/// another drive on Windows.
+fn relative_path(dir: &Path, path: &Path) -> String {
+    let (Some(dir), Some(path)) = (absolute_path(dir), absolute_path(path)) else {
+        return path.to_string_lossy().into_owned();
+    };
+    let dir = dir.components().collect::<Vec<_>>();
+    let path_components = path.components().collect::<Vec<_>>();
+    let common = dir.iter().zip(&path_components).take_while(|(a, b)| a == b).count();
+    if common == 0 {
+        return path.to_string_lossy().into_owned();
+    }
+    let parents = std::iter::repeat("..").take(dir.len() - common).map(ToString::to_string);
+    let rest =
+        path_components[common..].iter().map(|c| c.as_os_str().to_string_lossy().into_owned());
+    parents.chain(rest).collect::<Vec<_>>().join("/")
+}
+
+/// Resolve `path` against the current working directory, removing `.` and `..` components.
+///
+/// Returns `None` if `path` is relative and the current working directory is unavailable.
+fn absolute_path(path: &Path) -> Option<PathBuf> {
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().ok()?.join(path)
+    };
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    Some(normalized)
+}
+
+/// Whether to embed the original source code in `sourcesContent` of the source map.
+#[derive(Clone, Default)]
+pub enum SourcesContent {
+    /// Embed the source code (default).
+    #[default]
+    All,
+    /// Do not emit `sourcesContent`.
+    None,
+    /// Embed the source code only if the function returns `true` for the source path.
+    Filter(Arc<dyn Fn(&Path) -> bool + Send + Sync>),
+}
+
+impl fmt::Debug for SourcesContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => f.write_str("All"),
+            Self::None => f.write_str("None"),
+            Self::Filter(_) => f.write_str("Filter"),
+        }
+    }
+}
+
+impl SourcesContent {
+    /// Whether to embed the source code of `path`.
+    pub fn includes(&self, path: &Path) -> bool {
+        match self {
+            Self::All => true,
+            Self::None => false,
+            Self::Filter(filter) => filter(path),
+        }
+    }
+}
+
 /// Codegen Options.
 #[derive(Debug, Clone)]
 pub struct CodegenOptions {
@@ -43,49 +224,158 @@ pub struct CodegenOptions {
     /// Default is `false`.
     pub single_quote: bool,
 
+    /// Use the other quote for strings that contain more of the preferred quote than of the
+    /// other one, so that fewer quotes need to be escaped, e.g. `"it's"` with `single_quote`.
+    ///
+    /// Default is `false`.
+    pub avoid_escaped_quotes: bool,
+
+    /// Omit semicolons at the end of statements, inserting one before statements that would
+    /// otherwise continue the previous line, e.g. `;[a, b].forEach(f)`.
+    ///
+    /// Semicolons after class fields and directives are always printed.
+    /// Has no effect when `minify` is true.
+    ///
+    /// Default is `false`.
+    pub omit_semicolons: bool,
+
+    /// Character used for indentation.
+    ///
+    /// Has no effect when `minify` is true.
+    ///
+    /// Default is [IndentChar::Tab].
+    pub indent_char: IndentChar,
+
+    /// Number of [CodegenOptions::indent_char] printed per level of indentation.
+    ///
+    /// Default is `1`.
+    pub indent_width: usize,
+
+    /// Soft limit on the length of a line, in characters.
+    ///
+    /// Once a line is longer than this, the next item of a list, e.g. an argument, a parameter or
+    /// an array element, is printed on a new line with one more level of indentation. Lines are
+    /// not broken anywhere else, so they can still end up longer than this.
+    /// Has no effect when `minify` is true.
+    ///
+    /// Default is `None`.
+    pub line_width: Option<usize>,
+
+    /// Quote property names which are reserved words in ES3, for engines predating ES5, e.g.
+    /// IE 8.
+    ///
+    /// `a.default` is printed as `a["default"]` and `{ default: 1 }` as `{ "default": 1 }`. This is
+    /// also done by the transformer for such targets, but nodes created after transforming, e.g.
+    /// by replacing global defines, are only covered by printing them this way.
+    ///
+    /// Default is `false`.
+    pub quote_reserved_properties: bool,
+
     /// Remove whitespace.
     ///
+    /// Strings are printed with whichever quote needs the fewest escapes, including backticks
+    /// where a template literal is allowed and [CodegenOptions::template_literals] is set.
+    ///
     /// Default is `false`.
     pub minify: bool,
 
-    /// Print all comments?
+    /// Allow printing strings as template literals when minifying.
+    ///
+    /// Disable for engines predating ES2015, which do not support template literals.
     ///
     /// Default is `true`.
-    pub comments: bool,
+    pub template_literals: bool,
 
-    /// Print annotation comments, e.g. `/* #__PURE__ */` and `/* #__NO_SIDE_EFFECTS__ */`.
+    /// Print the text children of JSX elements exactly as they were parsed.
     ///
-    /// Only takes into effect when `comments` is false.
+    /// When `minify` is true, the whitespace of JSX text is otherwise collapsed the way JSX
+    /// transforms interpret it: whitespace around line breaks is removed, lines are joined with a
+    /// single space, and text of only whitespace spanning multiple lines is removed. Enable this
+    /// when the printed JSX is compiled by a tool which is sensitive to whitespace.
     ///
     /// Default is `false`.
-    pub annotation_comments: bool,
+    pub preserve_jsx_whitespace: bool,
 
-    /// Print legal comments.
+    /// Which comments to print.
     ///
-    /// Only takes into effect when `comments` is false.
+    /// No comments are printed when `minify` is true.
+    ///
+    /// Default is [CommentMode::All].
+    pub comments: CommentMode,
+
+    /// Where to print legal comments.
+    ///
+    /// [LegalComment::None] prints them in place unless `comments` is [CommentMode::None].
     ///
     /// <https://esbuild.github.io/api/#legal-comments>
     ///
     /// Default is [LegalComment::None].
     pub legal_comments: LegalComment,
 
+    /// Text to print at the start of the output, e.g. a license header or `"use client";`.
+    ///
+    /// It is printed after the hashbang, and followed by a line break if it does not end with one.
+    ///
+    /// Default is `None`.
+    pub banner: Option<String>,
+
+    /// Text to print at the end of the output, e.g. `//# sourceURL=index.js`.
+    ///
+    /// It is printed on a line of its own, after legal comments moved to the end of the file.
+    ///
+    /// Default is `None`.
+    pub footer: Option<String>,
+
     /// Override the source map path. This affects the `sourceMappingURL`
     /// comment at the end of the generated code.
     ///
     /// By default, the source map path is the same as the input source code
     /// (with a `.map` extension).
     pub source_map_path: Option<PathBuf>,
+
+    /// The `sourceRoot` of the source map.
+    ///
+    /// Default is `None`.
+    pub source_root: Option<String>,
+
+    /// How [`CodegenOptions::source_map_path`] is written to `sources` of the source map.
+    ///
+    /// Default is [SourceMapSources::Path].
+    pub source_map_sources: SourceMapSources,
+
+    /// Whether to embed the source code in `sourcesContent` of the source map.
+    ///
+    /// Default is [SourcesContent::All].
+    pub sources_content: SourcesContent,
+
+    /// Granularity of the mappings in the source map.
+    ///
+    /// Default is [SourceMapDetail::Token].
+    pub sourcemap_detail: SourceMapDetail,
 }
 
 impl Default for CodegenOptions {
     fn default() -> Self {
         Self {
             single_quote: false,
+            avoid_escaped_quotes: false,
+            omit_semicolons: false,
+            indent_char: IndentChar::default(),
+            indent_width: 1,
+            line_width: None,
+            quote_reserved_properties: false,
             minify: false,
-            comments: true,
-            annotation_comments: false,
+            template_literals: true,
+            preserve_jsx_whitespace: false,
+            comments: CommentMode::default(),
             legal_comments: LegalComment::default(),
+            banner: None,
+            footer: None,
             source_map_path: None,
+            source_root: None,
+            source_map_sources: SourceMapSources::default(),
+            sources_content: SourcesContent::default(),
+            sourcemap_detail: SourceMapDetail::default(),
         }
     }
 }
@@ -93,10 +383,30 @@ impl Default for CodegenOptions {
 impl CodegenOptions {
     pub(crate) fn print_comments(&self) -> bool {
         !self.minify
-            && (self.comments || self.annotation_comments || self.legal_comments.is_inline())
     }
 
     pub(crate) fn print_annotation_comments(&self) -> bool {
-        !self.minify && (self.comments || self.annotation_comments)
+        !self.minify
+    }
+
+    /// Whether legal comments are printed, either in place or moved elsewhere.
+    pub(crate) fn print_legal_comments(&self) -> bool {
+        self.comments != CommentMode::None || !self.legal_comments.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::relative_path;
+
+    #[test]
+    fn relative() {
+        let test = |dir: &str, path: &str| relative_path(Path::new(dir), Path::new(path));
+        assert_eq!(test("/project/dist", "/project/src/index.js"), "../src/index.js");
+        assert_eq!(test("/project", "/project/src/index.js"), "src/index.js");
+        assert_eq!(test("dist/chunks", "src/index.js"), "../../src/index.js");
+        assert_eq!(test("./dist", "./dist/index.js"), "index.js");
     }
 }
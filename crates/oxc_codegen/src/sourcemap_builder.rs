@@ -1,4 +1,4 @@
-use std::{path::Path, sync::Arc};
+use std::sync::Arc;
 
 use nonmax::NonMaxU32;
 use oxc_index::{Idx, IndexVec};
@@ -74,11 +74,10 @@ pub struct SourcemapBuilder {
 }
 
 impl SourcemapBuilder {
-    pub fn new(path: &Path, source_text: &str) -> Self {
+    pub fn new(source: &str, source_text: &str) -> Self {
         let mut sourcemap_builder = oxc_sourcemap::SourceMapBuilder::default();
         let line_offset_tables = Self::generate_line_offset_tables(source_text);
-        let source_id =
-            sourcemap_builder.set_source_and_content(path.to_string_lossy().as_ref(), source_text);
+        let source_id = sourcemap_builder.set_source_and_content(source, source_text);
         Self {
             source_id,
             original_source: Arc::from(source_text),
@@ -388,7 +387,7 @@ mod test {
     }
 
     fn assert_mapping(source: &str, mappings: &[(u32, u32, u32)]) {
-        let mut builder = SourcemapBuilder::new(Path::new("x.js"), source);
+        let mut builder = SourcemapBuilder::new("x.js", source);
         for (position, expected_line, expected_col) in mappings.iter().copied() {
             let (line, col) = builder.search_original_line_and_column(position);
             assert_eq!(
@@ -402,7 +401,7 @@ mod test {
     #[test]
     fn add_source_mapping() {
         fn create_mappings(source: &str, line: u32, column: u32) {
-            let mut builder = SourcemapBuilder::new(Path::new("x.js"), source);
+            let mut builder = SourcemapBuilder::new("x.js", source);
             let output: Vec<u8> = source.as_bytes().into();
             for (i, _ch) in source.char_indices() {
                 #[allow(clippy::cast_possible_truncation)]
@@ -438,7 +437,7 @@ mod test {
     #[test]
     fn add_source_mapping_for_name() {
         let output = "ac".as_bytes();
-        let mut builder = SourcemapBuilder::new(Path::new("x.js"), "ab");
+        let mut builder = SourcemapBuilder::new("x.js", "ab");
         builder.add_source_mapping_for_name(output, Span::new(0, 1), "a");
         builder.add_source_mapping_for_name(output, Span::new(1, 2), "c");
         let sm = builder.into_sourcemap();
@@ -457,7 +456,7 @@ mod test {
     #[test]
     fn add_source_mapping_for_unordered_position() {
         let output = "".as_bytes();
-        let mut builder = SourcemapBuilder::new(Path::new("x.js"), "ab");
+        let mut builder = SourcemapBuilder::new("x.js", "ab");
         builder.add_source_mapping(output, 1, None);
         builder.add_source_mapping(output, 0, None);
         let sm = builder.into_sourcemap();
@@ -1,4 +1,4 @@
-use oxc_codegen::{CodegenOptions, LegalComment};
+use oxc_codegen::{CodegenOptions, CommentMode, LegalComment};
 
 use crate::{codegen_options, snapshot, snapshot_options};
 
@@ -49,3 +49,25 @@ fn legal_external_comment() {
     assert_eq!(ret.legal_comments[0].content_span().source_text(code), " @license ");
     assert_eq!(ret.legal_comments[1].content_span().source_text(code), " @preserve ");
 }
+
+#[test]
+fn comment_modes() {
+    let code = "/* @license */\n/** doc */\nconst p = /*#__PURE__*/ Promise.resolve();\n";
+    let test = |comments: CommentMode, expected: &str| {
+        let options = CodegenOptions { comments, ..Default::default() };
+        assert_eq!(codegen_options(code, &options).code, expected, "{comments:?}");
+    };
+    test(CommentMode::All, code);
+    test(CommentMode::Legal, "/* @license */\nconst p = /*#__PURE__*/ Promise.resolve();\n");
+    test(CommentMode::None, "const p = /*#__PURE__*/ Promise.resolve();\n");
+
+    let options = CodegenOptions {
+        comments: CommentMode::None,
+        legal_comments: LegalComment::Eof,
+        ..Default::default()
+    };
+    assert_eq!(
+        codegen_options(code, &options).code,
+        "const p = /*#__PURE__*/ Promise.resolve();\n/* @license */\n"
+    );
+}
@@ -0,0 +1,160 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, NONE};
+use oxc_codegen::{
+    CodeGenerator, CodegenOptions, SourceMapDetail, SourceMapSources, SourcesContent,
+};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, SPAN};
+
+use crate::codegen_options;
+
+fn sourcemap(options: CodegenOptions) -> oxc_sourcemap::SourceMap {
+    sourcemap_of("/project/src/index.js", options)
+}
+
+fn sourcemap_of(path: &str, options: CodegenOptions) -> oxc_sourcemap::SourceMap {
+    let options = CodegenOptions { source_map_path: Some(PathBuf::from(path)), ..options };
+    codegen_options("let x = 1;", &options).map.unwrap()
+}
+
+#[test]
+fn source_root() {
+    let map = sourcemap(CodegenOptions::default());
+    assert_eq!(map.get_source_root(), None);
+
+    let map = sourcemap(CodegenOptions {
+        source_root: Some("webpack://".to_string()),
+        ..CodegenOptions::default()
+    });
+    assert_eq!(map.get_source_root(), Some("webpack://"));
+}
+
+#[test]
+fn sources() {
+    let map = sourcemap(CodegenOptions::default());
+    assert_eq!(map.get_source(0), Some("/project/src/index.js"));
+
+    let map = sourcemap(CodegenOptions {
+        source_map_sources: SourceMapSources::RelativeTo(PathBuf::from("/project/dist/assets")),
+        ..CodegenOptions::default()
+    });
+    assert_eq!(map.get_source(0), Some("../../src/index.js"));
+
+    let map = sourcemap(CodegenOptions {
+        source_map_sources: SourceMapSources::RelativeTo(PathBuf::from(
+            "/project/dist/../dist/./assets",
+        )),
+        ..CodegenOptions::default()
+    });
+    assert_eq!(map.get_source(0), Some("../../src/index.js"));
+
+    // A relative directory is resolved against the current working directory.
+    let map = sourcemap(CodegenOptions {
+        source_map_sources: SourceMapSources::RelativeTo(PathBuf::from("dist")),
+        ..CodegenOptions::default()
+    });
+    let depth = std::env::current_dir().unwrap().join("dist").components().count() - 1;
+    let expected = format!("{}project/src/index.js", "../".repeat(depth));
+    assert_eq!(map.get_source(0), Some(expected.as_str()));
+
+    let map = sourcemap(CodegenOptions {
+        source_map_sources: SourceMapSources::Custom(Arc::new(|path: &Path| {
+            format!("app:///{}", path.file_name().unwrap().to_string_lossy())
+        })),
+        ..CodegenOptions::default()
+    });
+    assert_eq!(map.get_source(0), Some("app:///index.js"));
+}
+
+#[test]
+fn sources_content() {
+    let map = sourcemap(CodegenOptions::default());
+    assert_eq!(map.get_source_content(0), Some("let x = 1;"));
+
+    let map = sourcemap(CodegenOptions {
+        sources_content: SourcesContent::None,
+        ..CodegenOptions::default()
+    });
+    assert_eq!(map.get_source_content(0), None);
+
+    let options = || CodegenOptions {
+        sources_content: SourcesContent::Filter(Arc::new(|path: &Path| {
+            !path.components().any(|c| c.as_os_str() == "node_modules")
+        })),
+        ..CodegenOptions::default()
+    };
+    let map = sourcemap(options());
+    assert_eq!(map.get_source_content(0), Some("let x = 1;"));
+    let map = sourcemap_of("/project/node_modules/foo/index.js", options());
+    assert_eq!(map.get_source(0), Some("/project/node_modules/foo/index.js"));
+    assert_eq!(map.get_source_content(0), None);
+}
+
+#[test]
+fn unspanned_nodes() {
+    // Nodes inserted by the minifier have no position in the original source and must not be
+    // mapped to its first line.
+    let allocator = Allocator::default();
+    let ast = AstBuilder::new(&allocator);
+    let mut program = Parser::new(&allocator, "\nfoo();", SourceType::mjs()).parse().program;
+    let callee = ast.expression_identifier_reference(SPAN, "bar");
+    let call = ast.expression_call(SPAN, callee, NONE, ast.vec(), false);
+    program.body.insert(0, ast.statement_expression(SPAN, call));
+
+    let options = CodegenOptions {
+        source_map_path: Some(PathBuf::from("index.js")),
+        ..CodegenOptions::default()
+    };
+    let ret = CodeGenerator::new().with_options(options).build(&program);
+    assert_eq!(ret.code, "bar();\nfoo();\n");
+    let map = ret.map.unwrap();
+    assert!(map.get_tokens().count() > 0);
+    assert!(map.get_tokens().all(|token| token.get_src_line() == 1 && token.get_dst_line() == 1));
+}
+
+#[test]
+fn banner_and_footer() {
+    let options = CodegenOptions {
+        source_map_path: Some(PathBuf::from("index.js")),
+        banner: Some("/*! license */\n\"use client\";".to_string()),
+        footer: Some("//# sourceURL=index.js".to_string()),
+        ..CodegenOptions::default()
+    };
+    let ret = codegen_options("foo();", &options);
+    assert_eq!(ret.code, "/*! license */\n\"use client\";\nfoo();\n//# sourceURL=index.js\n");
+    let map = ret.map.unwrap();
+    assert!(map.get_tokens().count() > 0);
+    assert!(map.get_tokens().all(|token| token.get_src_line() == 0 && token.get_dst_line() == 2));
+}
+
+#[test]
+fn sourcemap_detail() {
+    let source_text = "const foo = bar(1, 2);\nif (foo) {\n  baz(foo);\n}\n";
+    let map = |sourcemap_detail| {
+        let options = CodegenOptions {
+            source_map_path: Some(PathBuf::from("index.js")),
+            sourcemap_detail,
+            ..CodegenOptions::default()
+        };
+        codegen_options(source_text, &options).map.unwrap()
+    };
+
+    let token = map(SourceMapDetail::Token);
+    let statement = map(SourceMapDetail::Statement);
+    assert!(token.get_tokens().count() > statement.get_tokens().count());
+
+    // One mapping at the start of each statement, without names.
+    let mappings = statement
+        .get_tokens()
+        .map(|token| {
+            assert_eq!(token.get_name_id(), None);
+            (token.get_dst_line(), token.get_dst_col(), token.get_src_line(), token.get_src_col())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(mappings, vec![(0, 0, 0, 0), (1, 0, 1, 0), (2, 0, 2, 2)]);
+}
@@ -1,6 +1,6 @@
 use oxc_allocator::Allocator;
 use oxc_codegen::{CodeGenerator, CodegenOptions};
-use oxc_parser::Parser;
+use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 
 pub fn test(source_text: &str, expected: &str) {
@@ -11,6 +11,22 @@ pub fn test(source_text: &str, expected: &str) {
     assert_eq!(result, expected, "\nfor source: {source_text:?}");
 }
 
+pub fn test_with_parse_options(source_text: &str, expected: &str, options: ParseOptions) {
+    let source_type = SourceType::jsx();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+    let result = CodeGenerator::new().build(&ret.program).code;
+    assert_eq!(result, expected, "\nfor source: {source_text:?}");
+}
+
+pub fn test_tsx(source_text: &str, expected: &str) {
+    let source_type = SourceType::tsx();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let result = CodeGenerator::new().build(&ret.program).code;
+    assert_eq!(result, expected, "\nfor source: {source_text:?}");
+}
+
 pub fn test_without_source(source_text: &str, expected: &str) {
     let source_type = SourceType::jsx();
     let allocator = Allocator::default();
@@ -29,3 +45,11 @@ pub fn test_minify(source_text: &str, expected: &str) {
         .code;
     assert_eq!(result, expected, "\nfor minify source: {source_text}");
 }
+
+pub fn test_options(source_text: &str, expected: &str, options: CodegenOptions) {
+    let source_type = SourceType::jsx();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let result = CodeGenerator::new().with_options(options).build(&ret.program).code;
+    assert_eq!(result, expected, "\nfor source: {source_text:?}");
+}
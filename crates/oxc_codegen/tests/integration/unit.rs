@@ -1,4 +1,9 @@
-use crate::tester::{test, test_minify, test_without_source};
+use oxc_codegen::{CodegenOptions, IndentChar};
+use oxc_parser::ParseOptions;
+
+use crate::tester::{
+    test, test_minify, test_options, test_tsx, test_with_parse_options, test_without_source,
+};
 
 #[test]
 fn module_decl() {
@@ -8,6 +13,18 @@ fn module_decl() {
     test("export * from './foo.js' with {}", "export * from \"./foo.js\" with {\n};\n");
 }
 
+#[test]
+fn import_phases() {
+    let options = ParseOptions { experimental_import_phases: true, ..ParseOptions::default() };
+    test_with_parse_options("import source x from 'y'", "import source x from \"y\";\n", options);
+    test_with_parse_options(
+        "import defer * as ns from 'y'",
+        "import defer * as ns from \"y\";\n",
+        options,
+    );
+    test_with_parse_options("import source from 'y'", "import source from \"y\";\n", options);
+}
+
 #[test]
 fn expr() {
     test("new (foo()).bar();", "new (foo()).bar();\n");
@@ -64,6 +81,19 @@ fn shorthand() {
     test("var obj = { __proto__: __proto__, };", "var obj = { __proto__: __proto__ };\n");
 }
 
+#[test]
+fn quote_reserved_properties() {
+    let options = CodegenOptions { quote_reserved_properties: true, ..CodegenOptions::default() };
+    test_options("a.default", "a[\"default\"];\n", options.clone());
+    test_options("a?.class.b", "a?.[\"class\"].b;\n", options.clone());
+    test_options("0..char = 1", "0[\"char\"] = 1;\n", options.clone());
+    test_options("({ default: 1, b: 2 })", "({\n\t\"default\": 1,\n\tb: 2\n});\n", options.clone());
+    test_options("({ let })", "({ \"let\": let });\n", options.clone());
+    test_options("({ if() {} })", "({ \"if\"() {} });\n", options.clone());
+    test_options("a.default", "a['default'];\n", CodegenOptions { single_quote: true, ..options });
+    test_options("a.default", "a.default;\n", CodegenOptions::default());
+}
+
 #[test]
 fn unicode_escape() {
     test("console.log('你好');", "console.log(\"你好\");\n");
@@ -294,3 +324,149 @@ fn in_expr_in_sequence_in_for_loop_init() {
         "for ((\"hidden\" in a) && (m = a.hidden), r = 0; s > r; r++) {}\n",
     );
 }
+
+#[test]
+fn avoid_escaped_quotes() {
+    let double = CodegenOptions { avoid_escaped_quotes: true, ..CodegenOptions::default() };
+    let single = CodegenOptions { single_quote: true, ..double.clone() };
+    // Strings at the start of a statement would be directives.
+    test_options("x = 'it\\'s'", "x = \"it's\";\n", double.clone());
+    test_options("x = 'say \"hi\"'", "x = 'say \"hi\"';\n", double.clone());
+    test_options("x = 'a\"b\\'c'", "x = \"a\\\"b'c\";\n", double);
+    test_options("x = 'say \"hi\"'", "x = 'say \"hi\"';\n", single.clone());
+    test_options("x = 'it\\'s'", "x = \"it's\";\n", single.clone());
+    test_options("x = 'a\"b\\'c'", "x = 'a\"b\\'c';\n", single);
+}
+
+#[test]
+fn omit_semicolons() {
+    let options = CodegenOptions { omit_semicolons: true, ..CodegenOptions::default() };
+    let test = |source_text: &str, expected: &str| {
+        test_options(source_text, expected, options.clone());
+    };
+    test("let a = b; c.d(); e = f", "let a = b\nc.d()\ne = f\n");
+    test("a; (b || c).d()", "a\n;(b || c).d()\n");
+    test("a; [b, c].forEach(d)", "a\n;[b, c].forEach(d)\n");
+    test("a; `b`.length", "a\n;`b`.length\n");
+    test("a; -b", "a\n;-b\n");
+    test("a; [b] = c", "a\n;[b] = c\n");
+    test("a; b + c", "a\nb + c\n");
+    test("a; (b + c) * d", "a\n;(b + c) * d\n");
+    test("if (a) [b] = c", "if (a) [b] = c\n");
+    test("'use strict'; a", "\"use strict\";\na\n");
+    test("class A { a = 1; [b] = 2 }", "class A {\n\ta = 1;\n\t[b] = 2;\n}\n");
+    test_options("a; (b)()", "a;b();", CodegenOptions { minify: true, ..options });
+}
+
+#[test]
+fn minify_quotes() {
+    test_minify("x = 'a\"b'", "x='a\"b';");
+    test_minify("x = 'a\"b\\'c'", "x=`a\"b'c`;");
+    test_minify("x = 'a\"b\\'c\\n'", "x=`a\"b'c\n`;");
+    test_minify("x = '${a}\\n'", "x=\"${a}\\n\";");
+    test_minify("x = '`${a}\\n'", "x=\"`${a}\\n\";");
+    test_minify("x = { 'a\"b\\'c\\n': 1 }", "x={\"a\\\"b'c\\n\":1};");
+    test_minify("import x from 'a\"b\\'c\\n'", "import x from \"a\\\"b'c\\n\";");
+    test_minify("x = '\\xA0'", "x=\"\u{a0}\";");
+    test_minify("x = '\\u00e9\\u{1F600}'", "x=\"\u{e9}\u{1F600}\";");
+    // Template literals without substitutions are printed like strings.
+    test_minify("x = `a`", "x=\"a\";");
+    test_minify("x = `a\"b`", "x='a\"b';");
+    test_minify("x = `a${b}`", "x=`a${b}`;");
+    // A string at the start of a statement could be a directive.
+    test_minify("`a`", "`a`;");
+
+    let options =
+        CodegenOptions { minify: true, template_literals: false, ..CodegenOptions::default() };
+    test_options("x = 'a\"b\\'c'", "x=\"a\\\"b'c\";", options.clone());
+    test_options("x = 'a\"b\\'c\\n'", "x=\"a\\\"b'c\\n\";", options);
+}
+
+#[test]
+fn banner_and_footer() {
+    let options = CodegenOptions {
+        banner: Some("/* banner */".to_string()),
+        footer: Some("/* footer */\n".to_string()),
+        ..CodegenOptions::default()
+    };
+    test_options(
+        "#!/usr/bin/env node\na",
+        "#!/usr/bin/env node\n/* banner */\na;\n/* footer */\n",
+        options.clone(),
+    );
+    test_options(
+        "a",
+        "a;\n/* footer */\n",
+        CodegenOptions { minify: true, banner: None, ..options },
+    );
+}
+
+#[test]
+fn jsx() {
+    test("<a>{...b}</a>", "<a>{...b}</a>;\n");
+    test("<a>{(b, c)}{...(d, e)}</a>", "<a>{(b, c)}{...(d, e)}</a>;\n");
+    test("<a b={(c, d)}>{}</a>", "<a b={(c, d)}>{}</a>;\n");
+    test("<this.a {...b} c:d='e' />", "<this.a {...b} c:d=\"e\" />;\n");
+    test_minify("<a>{...b}</a>", "<a>{...b}</a>;");
+    test_minify("<a> x <b/> y </a>", "<a> x <b/> y </a>;");
+    test_minify("<a>\n  x\n  y  \n</a>", "<a>x y</a>;");
+    test_minify("<a>\n  <b />\n  {c}\n</a>", "<a><b/>{c}</a>;");
+    test_minify("<a>x  \n\n  y</a>", "<a>x y</a>;");
+    test_options(
+        "<a>\n  x\n  y  \n</a>",
+        "<a>\n  x\n  y  \n</a>;",
+        CodegenOptions { minify: true, preserve_jsx_whitespace: true, ..CodegenOptions::default() },
+    );
+    test_options(
+        "a; <b />",
+        "a\n;<b />\n",
+        CodegenOptions { omit_semicolons: true, ..CodegenOptions::default() },
+    );
+
+    test_tsx("<Foo<string> bar />", "<Foo<string> bar />;\n");
+    test_tsx("<a b={c as any}>{d!}</a>", "<a b={c as any}>{d!}</a>;\n");
+}
+
+#[test]
+fn indentation() {
+    let options = CodegenOptions {
+        indent_char: IndentChar::Space,
+        indent_width: 2,
+        ..CodegenOptions::default()
+    };
+    test_options(
+        "class Foo { bar() { if (a) { b() } } }",
+        "class Foo {\n  bar() {\n    if (a) {\n      b();\n    }\n  }\n}\n",
+        options.clone(),
+    );
+    test_options(
+        "class Foo { bar() {} }",
+        "class Foo {\n\t\tbar() {}\n}\n",
+        CodegenOptions { indent_char: IndentChar::Tab, ..options.clone() },
+    );
+    test_options(
+        "class Foo { bar() {} }",
+        "class Foo{bar(){}}",
+        CodegenOptions { minify: true, ..options },
+    );
+}
+
+#[test]
+fn line_width() {
+    let options = CodegenOptions { line_width: Some(20), ..CodegenOptions::default() };
+    test_options(
+        "foo(aaaaaaaa, bbbbbbbb, cccccccc, dddddddd)",
+        "foo(aaaaaaaa, bbbbbbbb,\n\tcccccccc, dddddddd);\n",
+        options.clone(),
+    );
+    test_options(
+        "class Foo { bar(aaaaaaaa, bbbbbbbb, ...cccccccc) {} }",
+        "class Foo {\n\tbar(aaaaaaaa, bbbbbbbb,\n\t\t...cccccccc) {}\n}\n",
+        options.clone(),
+    );
+    test_options(
+        "foo(aaaaaaaa, bbbbbbbb, cccccccc, dddddddd)",
+        "foo(aaaaaaaa,bbbbbbbb,cccccccc,dddddddd);",
+        CodegenOptions { minify: true, ..options },
+    );
+}
@@ -0,0 +1,25 @@
+use oxc_diagnostics::OxcDiagnostic;
+
+pub fn missing_export(specifier: &str, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Module \"{specifier}\" does not export \"{name}\""))
+}
+
+pub fn circular_reexport(specifier: &str, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Export \"{name}\" of module \"{specifier}\" is a circular re-export"
+    ))
+}
+
+pub fn external_reexport(specifier: &str, name: &str, source: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Export \"{name}\" of module \"{specifier}\" is re-exported from external module \"{source}\""
+    ))
+    .with_help("Only the entry module may re-export from modules outside the concatenation.")
+}
+
+pub fn external_star_reexport(specifier: &str, source: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Module \"{specifier}\" re-exports everything from external module \"{source}\""
+    ))
+    .with_help("Only the entry module may re-export from modules outside the concatenation.")
+}
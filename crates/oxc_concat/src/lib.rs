@@ -0,0 +1,669 @@
+//! Scope-hoisting module concatenation.
+//!
+//! **Experimental.** [`Concatenator`] merges a set of ES modules into a single module in which
+//! the top-level bindings of every module share one scope, in the style of Rollup:
+//!
+//! * modules are emitted in dependency order, starting from the modules that import nothing;
+//! * top-level bindings that collide between modules are renamed to `name$1`, `name$2`, ...;
+//! * imports between concatenated modules are removed, and references to imported bindings are
+//!   rewritten to the binding they resolve to, which keeps live bindings live;
+//! * `import * as ns` of a concatenated module is backed by a frozen namespace object;
+//! * imports of modules outside the concatenation, and the exports of the entry module, are kept.
+//!
+//! Modules are expected to be plain JavaScript, i.e. the output of the transformer. Deconflicted
+//! names are readable rather than short; run the mangler on the result to shorten them.
+
+mod diagnostics;
+mod renamer;
+
+use std::{fmt::Write, path::Path, sync::Arc};
+
+use oxc_allocator::{Allocator, Vec as ArenaVec};
+use oxc_ast::{ast::*, AstBuilder, NONE};
+use oxc_ast_visit::VisitMut;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_semantic::{ModuleRecord, ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_span::{CompactStr, SourceType, SPAN};
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    module_record::{ExportExportName, ExportImportName, ExportLocalName, ImportImportName},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::renamer::Renamer;
+
+/// A module to concatenate.
+pub struct ConcatModule<'a> {
+    /// The specifier other modules use to import this module.
+    ///
+    /// Import sources are compared with it verbatim, so they must be resolved by the caller
+    /// beforehand.
+    pub specifier: String,
+    pub program: Program<'a>,
+}
+
+pub struct ConcatReturn<'a> {
+    pub program: Program<'a>,
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// A binding that an import or export resolves to.
+#[derive(Debug, Clone, Copy)]
+enum Binding {
+    /// A top-level binding declared by a module.
+    Symbol(usize, SymbolId),
+    /// The binding synthesized for `export default <expression>`.
+    Default(usize),
+    /// The binding synthesized for the namespace object of a module.
+    Namespace(usize),
+}
+
+struct ModuleInfo {
+    specifier: String,
+    record: Arc<ModuleRecord>,
+    symbols: SymbolTable,
+    scopes: ScopeTree,
+    /// Concatenated modules requested by this module, in source order.
+    dependencies: Vec<usize>,
+    /// Names of bindings declared in nested scopes, which would shadow a hoisted binding of the
+    /// same name.
+    nested_names: FxHashSet<CompactStr>,
+    /// Final names of top-level bindings, including bindings imported from concatenated modules.
+    names: FxHashMap<SymbolId, CompactStr>,
+    default_name: Option<CompactStr>,
+    namespace_name: Option<CompactStr>,
+}
+
+pub struct Concatenator<'a> {
+    allocator: &'a Allocator,
+    ast: AstBuilder<'a>,
+    index: FxHashMap<String, usize>,
+    infos: Vec<ModuleInfo>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Concatenator<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self {
+            allocator,
+            ast: AstBuilder::new(allocator),
+            index: FxHashMap::default(),
+            infos: vec![],
+            errors: vec![],
+        }
+    }
+
+    /// Concatenate `modules` into a single program.
+    ///
+    /// The last module is the entry: its exports become the exports of the returned program, and
+    /// modules that it does not (transitively) import are left out.
+    pub fn build(mut self, modules: Vec<ConcatModule<'a>>) -> ConcatReturn<'a> {
+        let Some(entry) = modules.len().checked_sub(1) else {
+            let program = self.ast.program(
+                SPAN,
+                SourceType::mjs(),
+                "",
+                self.ast.vec(),
+                None,
+                self.ast.vec(),
+                self.ast.vec(),
+            );
+            return ConcatReturn { program, errors: self.errors };
+        };
+
+        self.index =
+            modules.iter().enumerate().map(|(i, module)| (module.specifier.clone(), i)).collect();
+        let mut programs = vec![];
+        for module in modules {
+            let info = self.analyze(module.specifier, &module.program);
+            self.infos.push(info);
+            programs.push(Some(module.program));
+        }
+
+        let mut order = vec![];
+        self.sort(entry, &mut vec![false; self.infos.len()], &mut order);
+        self.assign_names(&order);
+        self.link_imports(&order);
+
+        let source_type = programs[entry].as_ref().map_or_else(SourceType::mjs, |p| p.source_type);
+        let hashbang = programs[entry].as_mut().and_then(|program| program.hashbang.take());
+        let mut body = self.ast.vec();
+        for &module in &order {
+            let Some(mut program) = programs[module].take() else { continue };
+            let info = &self.infos[module];
+            Renamer::new(self.ast, program.source_text, &info.symbols, &info.names)
+                .visit_program(&mut program);
+            self.rewrite_body(module, module == entry, program.body, &mut body);
+            if let Some(name) = info.namespace_name.clone() {
+                let namespace = self.namespace_object(module);
+                body.push(self.var_declaration(&name, namespace));
+            }
+        }
+        if let Some(export) = self.entry_exports(entry) {
+            body.push(export);
+        }
+
+        let program =
+            self.ast.program(SPAN, source_type, "", self.ast.vec(), hashbang, self.ast.vec(), body);
+        ConcatReturn { program, errors: self.errors }
+    }
+
+    fn analyze(&self, specifier: String, program: &Program<'a>) -> ModuleInfo {
+        let builder = SemanticBuilder::new().build_module_record(Path::new(&specifier), program);
+        let record = builder.module_record();
+        let (symbols, scopes) = builder.build(program).semantic.into_symbol_table_and_scope_tree();
+
+        let root_scope_id = scopes.root_scope_id();
+        let nested_names = symbols
+            .symbol_ids()
+            .filter(|&symbol_id| symbols.get_scope_id(symbol_id) != root_scope_id)
+            .map(|symbol_id| CompactStr::from(symbols.get_name(symbol_id)))
+            .collect();
+
+        let mut dependencies = vec![];
+        for stmt in &program.body {
+            let source = match stmt {
+                Statement::ImportDeclaration(decl) => &decl.source,
+                Statement::ExportAllDeclaration(decl) => &decl.source,
+                Statement::ExportNamedDeclaration(decl) => match &decl.source {
+                    Some(source) => source,
+                    None => continue,
+                },
+                _ => continue,
+            };
+            if let Some(&dependency) = self.index.get(source.value.as_str()) {
+                dependencies.push(dependency);
+            }
+        }
+
+        ModuleInfo {
+            specifier,
+            record,
+            symbols,
+            scopes,
+            dependencies,
+            nested_names,
+            names: FxHashMap::default(),
+            default_name: None,
+            namespace_name: None,
+        }
+    }
+
+    /// Depth-first post-order: every module is placed after the modules it imports, except for
+    /// the back edge of an import cycle.
+    fn sort(&self, module: usize, visited: &mut [bool], order: &mut Vec<usize>) {
+        if visited[module] {
+            return;
+        }
+        visited[module] = true;
+        for &dependency in &self.infos[module].dependencies {
+            self.sort(dependency, visited, order);
+        }
+        order.push(module);
+    }
+
+    fn assign_names(&mut self, order: &[usize]) {
+        let mut needs_namespace = vec![false; self.infos.len()];
+        for &module in order {
+            let record = &self.infos[module].record;
+            let namespace_imports = record
+                .import_entries
+                .iter()
+                .filter(|entry| entry.import_name.is_namespace_object())
+                .map(|entry| entry.module_request.name());
+            let namespace_reexports = record
+                .indirect_export_entries
+                .iter()
+                .filter(|entry| entry.import_name.is_all())
+                .filter_map(|entry| entry.module_request.as_ref().map(|request| request.name()));
+            for request in namespace_imports.chain(namespace_reexports) {
+                if let Some(&dependency) = self.index.get(request.as_str()) {
+                    needs_namespace[dependency] = true;
+                }
+            }
+        }
+
+        let globals = self
+            .infos
+            .iter()
+            .flat_map(|info| info.scopes.root_unresolved_references().keys().cloned())
+            .collect::<FxHashSet<_>>();
+        let mut taken = FxHashSet::default();
+
+        for &module in order {
+            let info = &self.infos[module];
+            let root_scope_id = info.scopes.root_scope_id();
+            let mut names = FxHashMap::default();
+            for symbol_id in info.symbols.symbol_ids() {
+                if info.symbols.get_scope_id(symbol_id) != root_scope_id {
+                    continue;
+                }
+                let name = info.symbols.get_name(symbol_id);
+                if self.is_internal_import(module, name) {
+                    continue;
+                }
+                let name = self.deconflict(name, Some(module), &globals, &taken);
+                taken.insert(name.clone());
+                names.insert(symbol_id, name);
+            }
+
+            let stem = Self::stem(&info.specifier);
+            let has_default_expression = info.record.local_export_entries.iter().any(|entry| {
+                entry.export_name.is_default()
+                    && !matches!(entry.local_name, ExportLocalName::Name(_))
+            });
+            let default_name = has_default_expression.then(|| {
+                let name = self.deconflict(&format!("{stem}_default"), None, &globals, &taken);
+                taken.insert(name.clone());
+                name
+            });
+            let namespace_name = needs_namespace[module].then(|| {
+                let name = self.deconflict(&stem, None, &globals, &taken);
+                taken.insert(name.clone());
+                name
+            });
+
+            let info = &mut self.infos[module];
+            info.names = names;
+            info.default_name = default_name;
+            info.namespace_name = namespace_name;
+        }
+    }
+
+    /// Returns `name` if it is free, otherwise the first free `name$N`.
+    ///
+    /// A name is free if no other top-level binding has claimed it, it is not a global used by
+    /// any module, and no module declares it in a nested scope, where it would shadow the hoisted
+    /// binding. A module's own nested bindings cannot shadow its own unrenamed top-level binding
+    /// any more than they did before concatenation, so `module` is exempt for `name` itself.
+    fn deconflict(
+        &self,
+        name: &str,
+        module: Option<usize>,
+        globals: &FxHashSet<CompactStr>,
+        taken: &FxHashSet<CompactStr>,
+    ) -> CompactStr {
+        let is_free =
+            |candidate: &str, module: Option<usize>| {
+                !taken.contains(candidate)
+                    && !globals.contains(candidate)
+                    && self.infos.iter().enumerate().all(|(i, info)| {
+                        Some(i) == module || !info.nested_names.contains(candidate)
+                    })
+            };
+        if is_free(name, module) {
+            return CompactStr::from(name);
+        }
+        let mut n = 1;
+        loop {
+            let candidate = format!("{name}${n}");
+            if is_free(&candidate, None) {
+                return CompactStr::from(candidate);
+            }
+            n += 1;
+        }
+    }
+
+    /// Derive a binding name from a module specifier, e.g. `./utils/math.js` -> `math`.
+    fn stem(specifier: &str) -> String {
+        let file_name = specifier.rsplit(['/', '\\']).next().unwrap_or(specifier);
+        let stem = file_name.split('.').next().unwrap_or(file_name);
+        let mut name = stem
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '$' { c } else { '_' })
+            .collect::<String>();
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$') {
+            name.insert(0, '_');
+        }
+        name
+    }
+
+    fn is_internal_import(&self, module: usize, local_name: &str) -> bool {
+        self.infos[module].record.import_entries.iter().any(|entry| {
+            entry.local_name.name() == local_name
+                && self.index.contains_key(entry.module_request.name().as_str())
+        })
+    }
+
+    fn link_imports(&mut self, order: &[usize]) {
+        for &module in order {
+            let record = Arc::clone(&self.infos[module].record);
+            for entry in &record.import_entries {
+                let Some(&dependency) = self.index.get(entry.module_request.name().as_str()) else {
+                    continue;
+                };
+                let info = &self.infos[module];
+                let Some(symbol_id) =
+                    info.scopes.get_binding(info.scopes.root_scope_id(), entry.local_name.name())
+                else {
+                    continue;
+                };
+                let binding = match &entry.import_name {
+                    ImportImportName::Name(name) => {
+                        self.resolve_export(dependency, name.name(), &mut vec![])
+                    }
+                    ImportImportName::Default(_) => {
+                        self.resolve_export(dependency, "default", &mut vec![])
+                    }
+                    ImportImportName::NamespaceObject => Ok(Binding::Namespace(dependency)),
+                };
+                match binding {
+                    Ok(binding) => {
+                        let name = self.binding_name(binding);
+                        self.infos[module].names.insert(symbol_id, name);
+                    }
+                    Err(error) => self.errors.push(error),
+                }
+            }
+        }
+    }
+
+    fn resolve_export(
+        &self,
+        module: usize,
+        name: &str,
+        stack: &mut Vec<(usize, CompactStr)>,
+    ) -> Result<Binding, OxcDiagnostic> {
+        if stack.iter().any(|(m, n)| *m == module && n == name) {
+            return Err(diagnostics::circular_reexport(&self.infos[module].specifier, name));
+        }
+        stack.push((module, CompactStr::from(name)));
+        let binding = self.find_export(module, name, stack);
+        stack.pop();
+        binding
+    }
+
+    fn find_export(
+        &self,
+        module: usize,
+        name: &str,
+        stack: &mut Vec<(usize, CompactStr)>,
+    ) -> Result<Binding, OxcDiagnostic> {
+        let info = &self.infos[module];
+        let record = &info.record;
+
+        if let Some(entry) = record
+            .local_export_entries
+            .iter()
+            .find(|entry| export_name(&entry.export_name) == Some(name))
+        {
+            return match &entry.local_name {
+                ExportLocalName::Name(local) => self.resolve_local(module, local.name(), stack),
+                ExportLocalName::Default(_) | ExportLocalName::Null => Ok(Binding::Default(module)),
+            };
+        }
+
+        if let Some(entry) = record
+            .indirect_export_entries
+            .iter()
+            .find(|entry| export_name(&entry.export_name) == Some(name))
+        {
+            let request =
+                entry.module_request.as_ref().map_or("", |request| request.name().as_str());
+            let Some(&dependency) = self.index.get(request) else {
+                return Err(diagnostics::external_reexport(&info.specifier, name, request));
+            };
+            return match &entry.import_name {
+                ExportImportName::Name(import) => {
+                    // `import d from "mod"; export { d }` is recorded with the local name of the
+                    // default import as its import name.
+                    let is_default_import = record.import_entries.iter().any(|import_entry| {
+                        import_entry.import_name.is_default()
+                            && import_entry.local_name.span() == import.span()
+                    });
+                    let import_name =
+                        if is_default_import { "default" } else { import.name().as_str() };
+                    self.resolve_export(dependency, import_name, stack)
+                }
+                _ => Ok(Binding::Namespace(dependency)),
+            };
+        }
+
+        if name != "default" {
+            for entry in &record.star_export_entries {
+                let Some(request) = &entry.module_request else { continue };
+                let Some(&dependency) = self.index.get(request.name().as_str()) else { continue };
+                if let Ok(binding) = self.resolve_export(dependency, name, stack) {
+                    return Ok(binding);
+                }
+            }
+        }
+
+        Err(diagnostics::missing_export(&info.specifier, name))
+    }
+
+    fn resolve_local(
+        &self,
+        module: usize,
+        local_name: &str,
+        stack: &mut Vec<(usize, CompactStr)>,
+    ) -> Result<Binding, OxcDiagnostic> {
+        let info = &self.infos[module];
+        let import =
+            info.record.import_entries.iter().find(|entry| entry.local_name.name() == local_name);
+        if let Some(import) = import {
+            if let Some(&dependency) = self.index.get(import.module_request.name().as_str()) {
+                return match &import.import_name {
+                    ImportImportName::Name(name) => {
+                        self.resolve_export(dependency, name.name(), stack)
+                    }
+                    ImportImportName::Default(_) => {
+                        self.resolve_export(dependency, "default", stack)
+                    }
+                    ImportImportName::NamespaceObject => Ok(Binding::Namespace(dependency)),
+                };
+            }
+        }
+        info.scopes
+            .get_binding(info.scopes.root_scope_id(), local_name)
+            .map(|symbol_id| Binding::Symbol(module, symbol_id))
+            .ok_or_else(|| diagnostics::missing_export(&info.specifier, local_name))
+    }
+
+    fn binding_name(&self, binding: Binding) -> CompactStr {
+        let name = match binding {
+            Binding::Symbol(module, symbol_id) => self.infos[module].names.get(&symbol_id),
+            Binding::Default(module) => self.infos[module].default_name.as_ref(),
+            Binding::Namespace(module) => self.infos[module].namespace_name.as_ref(),
+        };
+        name.cloned().unwrap_or_else(|| CompactStr::new(""))
+    }
+
+    /// Names exported by `module`, including names re-exported from external modules if
+    /// `include_external` is set.
+    fn exported_names(
+        &mut self,
+        module: usize,
+        include_external: bool,
+        visited: &mut FxHashSet<usize>,
+    ) -> Vec<CompactStr> {
+        if !visited.insert(module) {
+            return vec![];
+        }
+        let record = Arc::clone(&self.infos[module].record);
+        let mut names = record
+            .local_export_entries
+            .iter()
+            .filter_map(|entry| export_name(&entry.export_name))
+            .map(CompactStr::from)
+            .collect::<Vec<_>>();
+        for entry in &record.indirect_export_entries {
+            let is_internal = entry
+                .module_request
+                .as_ref()
+                .is_some_and(|request| self.index.contains_key(request.name().as_str()));
+            if let Some(name) = export_name(&entry.export_name) {
+                if is_internal || include_external {
+                    names.push(CompactStr::from(name));
+                }
+            }
+        }
+        for entry in &record.star_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            let Some(&dependency) = self.index.get(request.name().as_str()) else {
+                if include_external {
+                    self.errors.push(diagnostics::external_star_reexport(
+                        &self.infos[module].specifier,
+                        request.name(),
+                    ));
+                }
+                continue;
+            };
+            for name in self.exported_names(dependency, true, visited) {
+                if name != "default" && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// `var ns = Object.freeze({ __proto__: null, get a() { return a; }, ... })`
+    fn namespace_object(&mut self, module: usize) -> Expression<'a> {
+        let mut source_text = String::from("Object.freeze({ __proto__: null");
+        for name in self.exported_names(module, true, &mut FxHashSet::default()) {
+            match self.resolve_export(module, &name, &mut vec![]) {
+                Ok(binding) => {
+                    let key = name.replace('\\', "\\\\").replace('"', "\\\"");
+                    let value = self.binding_name(binding);
+                    write!(source_text, ", get \"{key}\"() {{ return {value}; }}").unwrap();
+                }
+                Err(error) => self.errors.push(error),
+            }
+        }
+        source_text.push_str(" })");
+        self.parse_expression(&source_text)
+    }
+
+    fn parse_expression(&self, source_text: &str) -> Expression<'a> {
+        let source_text = self.allocator.alloc_str(source_text);
+        // Unwrapping here, the source text is generated from valid identifiers and string literals.
+        Parser::new(self.allocator, source_text, SourceType::mjs()).parse_expression().unwrap()
+    }
+
+    /// `export { a, b as c }` for the exports of the entry module, except re-exports of external
+    /// modules, which are kept as is.
+    fn entry_exports(&mut self, entry: usize) -> Option<Statement<'a>> {
+        let mut specifiers = self.ast.vec();
+        for name in self.exported_names(entry, false, &mut FxHashSet::default()) {
+            let binding = match self.resolve_export(entry, &name, &mut vec![]) {
+                Ok(binding) => binding,
+                Err(error) => {
+                    self.errors.push(error);
+                    continue;
+                }
+            };
+            let local = self.binding_name(binding);
+            let local = self.ast.module_export_name_identifier_reference(SPAN, local.as_str());
+            let exported = if is_identifier_name(&name) {
+                self.ast.module_export_name_identifier_name(SPAN, name.as_str())
+            } else {
+                self.ast.module_export_name_string_literal(SPAN, name.as_str())
+            };
+            specifiers.push(self.ast.export_specifier(
+                SPAN,
+                local,
+                exported,
+                ImportOrExportKind::Value,
+            ));
+        }
+        if specifiers.is_empty() {
+            return None;
+        }
+        Some(Statement::from(self.ast.module_declaration_export_named_declaration(
+            SPAN,
+            None,
+            specifiers,
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        )))
+    }
+
+    /// Strip the module syntax that concatenation makes redundant from `stmts`, and append the
+    /// result to `body`.
+    fn rewrite_body(
+        &self,
+        module: usize,
+        is_entry: bool,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+    ) {
+        for stmt in stmts {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    if !self.index.contains_key(decl.source.value.as_str()) {
+                        body.push(Statement::ImportDeclaration(decl));
+                    }
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    if is_entry && !self.index.contains_key(decl.source.value.as_str()) {
+                        body.push(Statement::ExportAllDeclaration(decl));
+                    }
+                }
+                Statement::ExportNamedDeclaration(mut decl) => {
+                    if let Some(declaration) = decl.declaration.take() {
+                        body.push(Statement::from(declaration));
+                    } else if is_entry
+                        && decl
+                            .source
+                            .as_ref()
+                            .is_some_and(|source| !self.index.contains_key(source.value.as_str()))
+                    {
+                        body.push(Statement::ExportNamedDeclaration(decl));
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    if let Some(stmt) = self.default_declaration(module, decl.unbox()) {
+                        body.push(stmt);
+                    }
+                }
+                stmt => body.push(stmt),
+            }
+        }
+    }
+
+    fn default_declaration(
+        &self,
+        module: usize,
+        decl: ExportDefaultDeclaration<'a>,
+    ) -> Option<Statement<'a>> {
+        let default_name = self.infos[module].default_name.as_ref();
+        match decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                if func.id.is_none() {
+                    func.id = Some(self.ast.binding_identifier(SPAN, default_name?.as_str()));
+                }
+                Some(Statement::FunctionDeclaration(func))
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                if class.id.is_none() {
+                    class.id = Some(self.ast.binding_identifier(SPAN, default_name?.as_str()));
+                }
+                Some(Statement::ClassDeclaration(class))
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => None,
+            kind => Some(self.var_declaration(default_name?, kind.into_expression())),
+        }
+    }
+
+    fn var_declaration(&self, name: &str, init: Expression<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let id = self.ast.binding_pattern(
+            self.ast.binding_pattern_kind_binding_identifier(SPAN, name),
+            NONE,
+            false,
+        );
+        let declarator = self.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        Statement::from(self.ast.declaration_variable(SPAN, kind, self.ast.vec1(declarator), false))
+    }
+}
+
+fn export_name(name: &ExportExportName) -> Option<&str> {
+    match name {
+        ExportExportName::Name(name) => Some(name.name().as_str()),
+        ExportExportName::Default(_) => Some("default"),
+        ExportExportName::Null => None,
+    }
+}
@@ -0,0 +1,93 @@
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_ast_visit::{walk_mut, VisitMut};
+use oxc_semantic::{SymbolId, SymbolTable};
+use oxc_span::CompactStr;
+use rustc_hash::FxHashMap;
+
+/// Rewrites the names of top-level bindings, and of every reference to them, in a single module.
+///
+/// References to imported bindings are rewritten to the final name of the binding they resolve
+/// to in the module that declares it.
+pub struct Renamer<'a, 'n> {
+    ast: AstBuilder<'a>,
+    source_text: &'a str,
+    symbols: &'n SymbolTable,
+    names: &'n FxHashMap<SymbolId, CompactStr>,
+}
+
+impl<'a, 'n> Renamer<'a, 'n> {
+    pub fn new(
+        ast: AstBuilder<'a>,
+        source_text: &'a str,
+        symbols: &'n SymbolTable,
+        names: &'n FxHashMap<SymbolId, CompactStr>,
+    ) -> Self {
+        Self { ast, source_text, symbols, names }
+    }
+
+    fn reference_name(&self, ident: &IdentifierReference<'a>) -> Option<&'n CompactStr> {
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = self.symbols.get_reference(reference_id).symbol_id()?;
+        self.names.get(&symbol_id)
+    }
+}
+
+impl<'a, 'n> VisitMut<'a> for Renamer<'a, 'n> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(name) = ident.symbol_id.get().and_then(|symbol_id| self.names.get(&symbol_id)) {
+            ident.name = self.ast.atom(name);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(name) = self.reference_name(ident) {
+            ident.name = self.ast.atom(name);
+        }
+    }
+
+    /// The concatenated program has no source text of its own, so parsed patterns are detached
+    /// from the source text of their module.
+    fn visit_reg_exp_literal(&mut self, lit: &mut RegExpLiteral<'a>) {
+        if let RegExpPattern::Pattern(pattern) = &lit.regex.pattern {
+            if !pattern.span.is_unspanned() {
+                let raw = pattern.span.source_text(self.source_text);
+                lit.regex.pattern = RegExpPattern::Raw(raw);
+            }
+        }
+    }
+
+    /// `({ a } = obj)` becomes `({ a: a$1 } = obj)`.
+    ///
+    /// Codegen prints the other shorthand forms (`{ a }` and `const { a } = obj`) in long form
+    /// once the key and the value no longer agree, but the identifier of a shorthand assignment
+    /// target doubles as the property key, so it has to be split explicitly.
+    fn visit_assignment_target_property(&mut self, prop: &mut AssignmentTargetProperty<'a>) {
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) = prop {
+            if let Some(name) = self.reference_name(&ident.binding) {
+                let span = ident.span;
+                let key = self
+                    .ast
+                    .property_key_identifier_name(ident.binding.span, ident.binding.name.clone());
+                let target =
+                    AssignmentTarget::from(self.ast.simple_assignment_target_identifier_reference(
+                        ident.binding.span,
+                        name.as_str(),
+                    ));
+                let binding = match ident.init.take() {
+                    Some(mut init) => {
+                        self.visit_expression(&mut init);
+                        self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                            span, target, init,
+                        )
+                    }
+                    None => AssignmentTargetMaybeDefault::from(target),
+                };
+                *prop = self.ast.assignment_target_property_assignment_target_property_property(
+                    span, key, binding,
+                );
+                return;
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, prop);
+    }
+}
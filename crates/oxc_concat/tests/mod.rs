@@ -0,0 +1,113 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::CodeGenerator;
+use oxc_concat::{ConcatModule, Concatenator};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn concat(modules: &[(&str, &str)]) -> (String, usize) {
+    let allocator = Allocator::default();
+    let modules = modules
+        .iter()
+        .map(|(specifier, source_text)| {
+            let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+            ConcatModule { specifier: (*specifier).to_string(), program }
+        })
+        .collect();
+    let ret = Concatenator::new(&allocator).build(modules);
+    (CodeGenerator::new().build(&ret.program).code, ret.errors.len())
+}
+
+fn codegen(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    CodeGenerator::new().build(&program).code
+}
+
+fn test(modules: &[(&str, &str)], expected: &str) {
+    let (code, errors) = concat(modules);
+    assert_eq!(errors, 0, "{modules:?} should concatenate without errors");
+    assert_eq!(code, codegen(expected), "for {modules:?}");
+}
+
+#[test]
+fn imports() {
+    test(
+        &[
+            ("./a.js", "export const a = 1; export function f() { return a; }"),
+            ("./main.js", "import { a, f } from './a.js'; console.log(a, f());"),
+        ],
+        "const a = 1; function f() { return a; } console.log(a, f());",
+    );
+}
+
+#[test]
+fn collisions() {
+    test(
+        &[
+            ("./a.js", "const x = 1; export function getX() { return x; }"),
+            (
+                "./main.js",
+                "import { getX } from './a.js'; const x = 2; export { x }; console.log(getX(), x);",
+            ),
+        ],
+        "const x = 1; function getX() { return x; } const x$1 = 2; console.log(getX(), x$1); export { x$1 as x };",
+    );
+
+    // `count` is a global in `main.js`, so the hoisted binding must not take its name.
+    test(
+        &[
+            ("./a.js", "export let count = 0; export function reset(obj) { ({ count } = obj); }"),
+            ("./main.js", "import { reset } from './a.js'; reset(count);"),
+        ],
+        "let count$1 = 0; function reset(obj) { ({ count: count$1 } = obj); } reset(count);",
+    );
+}
+
+#[test]
+fn default_and_namespace() {
+    test(
+        &[
+            ("./math.js", "export default function (a, b) { return a + b; } export const pi = 3.14;"),
+            (
+                "./main.js",
+                "import add, * as math from './math.js'; const pi = math.pi; export const obj = { pi, add }; export default add(1, 2);",
+            ),
+        ],
+        r#"
+function math_default(a, b) { return a + b; }
+const pi = 3.14;
+var math = Object.freeze({ __proto__: null, get "default"() { return math_default; }, get "pi"() { return pi; } });
+const pi$1 = math.pi;
+const obj = { pi: pi$1, add: math_default };
+var main_default = math_default(1, 2);
+export { obj, main_default as default };
+"#,
+    );
+}
+
+#[test]
+fn external_modules() {
+    test(
+        &[
+            ("./a.js", "import { x } from 'ext'; export const y = x;"),
+            (
+                "./main.js",
+                "import { x } from 'other'; import { y } from './a.js'; export * from 'ext2'; console.log(x, y);",
+            ),
+        ],
+        "import { x } from 'ext'; const y = x; import { x as x$1 } from 'other'; export * from 'ext2'; console.log(x$1, y);",
+    );
+}
+
+#[test]
+fn errors() {
+    let (_, errors) =
+        concat(&[("./a.js", "export const a = 1;"), ("./main.js", "import { b } from './a.js';")]);
+    assert_eq!(errors, 1);
+
+    let (_, errors) = concat(&[
+        ("./a.js", "export { x } from 'ext';"),
+        ("./main.js", "import { x } from './a.js'; x;"),
+    ]);
+    assert_eq!(errors, 1);
+}
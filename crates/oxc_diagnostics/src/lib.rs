@@ -65,7 +65,7 @@ pub type Severity = miette::Severity;
 pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
 
 use miette::{Diagnostic, SourceCode};
-pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource};
+pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource, SourceSpan};
 
 /// Describes an error or warning that occurred.
 ///
@@ -122,6 +122,25 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    pub fix: Option<OxcFix>,
+}
+
+/// A replacement of source code which fixes a diagnostic, e.g. the fix of a lint rule.
+///
+/// Fixes are not rendered by the graphical reporter, but are included by machine-readable
+/// reporters as suggestions.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OxcFix {
+    /// The replaced source code.
+    pub span: SourceSpan,
+    /// The replacement, empty to delete `span`.
+    pub content: Cow<'static, str>,
+}
+
+impl OxcFix {
+    pub fn new<S: Into<SourceSpan>, T: Into<Cow<'static, str>>>(span: S, content: T) -> Self {
+        Self { span: span.into(), content: content.into() }
+    }
 }
 
 impl fmt::Display for OxcDiagnostic {
@@ -178,6 +197,7 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                fix: None,
             }),
         }
     }
@@ -192,6 +212,7 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                fix: None,
             }),
         }
     }
@@ -332,10 +353,99 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Suggest a replacement of source code which fixes this diagnostic.
+    pub fn with_fix(mut self, fix: OxcFix) -> Self {
+        self.inner.fix = Some(fix);
+        self
+    }
+
+    /// Move the labels and the fix of this diagnostic `offset` bytes forward.
+    ///
+    /// Use this when the diagnostic was reported for source code which is embedded in a larger
+    /// source text at `offset`, e.g. a `<script>` block of a Vue file, before adding the larger
+    /// source text with [`OxcDiagnostic::with_source_code`].
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        if offset == 0 {
+            return self;
+        }
+        let move_span =
+            |span: SourceSpan| SourceSpan::new((span.offset() + offset).into(), span.len());
+        if let Some(labels) = &mut self.inner.labels {
+            for label in labels.iter_mut() {
+                let span = move_span(*label.inner());
+                let text = label.label().map(ToString::to_string);
+                *label = if label.primary() {
+                    LabeledSpan::new_primary_with_span(text, span)
+                } else {
+                    LabeledSpan::new_with_span(text, span)
+                };
+            }
+        }
+        if let Some(fix) = &mut self.inner.fix {
+            fix.span = move_span(fix.span);
+        }
+        self
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::new(WithSourceCode { diagnostic: self, source_code: Box::new(code) })
+    }
+
+    /// The [`OxcDiagnostic`] an [`Error`] was created from with
+    /// [`OxcDiagnostic::with_source_code`], e.g. to get its [fix](OxcDiagnostic::with_fix).
+    pub fn from_error(error: &Error) -> Option<&Self> {
+        error.downcast_ref::<WithSourceCode>().map(|error| &error.diagnostic)
+    }
+}
+
+/// An [`OxcDiagnostic`] with the source code its labels point into.
+///
+/// Unlike [`Error::with_source_code`], this keeps the diagnostic accessible with
+/// [`OxcDiagnostic::from_error`].
+struct WithSourceCode {
+    diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode + Send + Sync>,
+}
+
+impl fmt::Debug for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.diagnostic, f)
+    }
+}
+
+impl fmt::Display for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for WithSourceCode {}
+
+impl Diagnostic for WithSourceCode {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
     }
 }
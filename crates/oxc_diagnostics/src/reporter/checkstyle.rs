@@ -24,12 +24,8 @@ impl DiagnosticReporter for CheckstyleReporter {
 
 #[allow(clippy::print_stdout)]
 fn format_checkstyle(diagnostics: &[Error]) {
-    let infos = diagnostics.iter().map(Info::new).collect::<Vec<_>>();
-    let mut grouped: FxHashMap<String, Vec<Info>> = FxHashMap::default();
-    for info in infos {
-        grouped.entry(info.filename.clone()).or_default().push(info);
-    }
-    let messages = grouped.into_values().map(|infos| {
+    let grouped = group_by_filename(diagnostics);
+    let messages = grouped.into_iter().map(|infos| {
          let messages = infos
              .iter()
              .fold(String::new(), |mut acc, info| {
@@ -52,8 +48,23 @@ fn format_checkstyle(diagnostics: &[Error]) {
     );
 }
 
+/// The [`Info`]s of `diagnostics` grouped by file, in the order the files were first reported, so
+/// that the output is stable.
+pub(super) fn group_by_filename(diagnostics: &[Error]) -> Vec<Vec<Info>> {
+    let mut indices: FxHashMap<String, usize> = FxHashMap::default();
+    let mut grouped: Vec<Vec<Info>> = vec![];
+    for info in diagnostics.iter().map(Info::new) {
+        let index = *indices.entry(info.filename.clone()).or_insert_with(|| {
+            grouped.push(vec![]);
+            grouped.len() - 1
+        });
+        grouped[index].push(info);
+    }
+    grouped
+}
+
 /// <https://github.com/tafia/quick-xml/blob/6e34a730853fe295d68dc28460153f08a5a12955/src/escapei.rs#L84-L86>
-fn xml_escape(raw: &str) -> Cow<str> {
+pub(super) fn xml_escape(raw: &str) -> Cow<str> {
     xml_escape_impl(raw, |ch| matches!(ch, b'<' | b'>' | b'&' | b'\'' | b'\"'))
 }
 
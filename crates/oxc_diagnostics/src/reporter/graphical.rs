@@ -1,4 +1,4 @@
-use std::io::{BufWriter, ErrorKind, Stdout, Write};
+use std::io::{BufWriter, ErrorKind, Write};
 
 use super::{writer, DiagnosticReporter};
 use crate::{Error, GraphicalReportHandler};
@@ -8,12 +8,22 @@ use crate::{Error, GraphicalReportHandler};
 /// See [`GraphicalReportHandler`] for how to configure colors, context lines, etc.
 pub struct GraphicalReporter {
     handler: GraphicalReportHandler,
-    writer: BufWriter<Stdout>,
+    writer: Box<dyn Write>,
 }
 
 impl Default for GraphicalReporter {
     fn default() -> Self {
-        Self { handler: GraphicalReportHandler::new(), writer: writer() }
+        Self { handler: GraphicalReportHandler::new(), writer: Box::new(writer()) }
+    }
+}
+
+impl GraphicalReporter {
+    /// Pretty-print diagnostics to stderr instead of stdout.
+    pub(crate) fn stderr() -> Self {
+        Self {
+            handler: GraphicalReportHandler::new(),
+            writer: Box::new(BufWriter::new(std::io::stderr())),
+        }
     }
 }
 
@@ -1,10 +1,15 @@
-use miette::JSONReportHandler;
+use miette::{SourceCode, SourceSpan};
+use serde_json::{json, Value};
 
 use super::DiagnosticReporter;
-use crate::Error;
+use crate::{Error, OxcDiagnostic, Severity};
 
 /// Renders reports as a JSON array of objects.
 ///
+/// Each object has the fields `message`, `code`, `severity`, `url`, `help`, `filename`, `labels`
+/// and `fix`, where `fix` is the suggested replacement of source code or `null`. Spans are
+/// objects of a byte `offset` and `length`, with the one-based `line` and `column` of the offset.
+///
 /// Note that, due to syntactic restrictions of JSON arrays, this reporter waits until all
 /// diagnostics have been reported before writing them to the output stream.
 #[derive(Default)]
@@ -16,7 +21,7 @@ impl DiagnosticReporter for JsonReporter {
     // NOTE: this output does not conform to eslint json format yet
     // https://eslint.org/docs/latest/use/formatters/#json
     fn finish(&mut self) {
-        format_json(&mut self.diagnostics);
+        format_json(&self.diagnostics);
     }
 
     fn render_diagnostics(&mut self, _s: &[u8]) {}
@@ -29,16 +34,52 @@ impl DiagnosticReporter for JsonReporter {
 
 /// <https://github.com/fregante/eslint-formatters/tree/main/packages/eslint-formatter-json>
 #[allow(clippy::print_stdout)]
-fn format_json(diagnostics: &mut Vec<Error>) {
-    let handler = JSONReportHandler::new();
+fn format_json(diagnostics: &[Error]) {
     let messages = diagnostics
-        .drain(..)
-        .map(|error| {
-            let mut output = String::from("\t");
-            handler.render_report(&mut output, error.as_ref()).unwrap();
-            output
-        })
+        .iter()
+        .map(|diagnostic| format!("\t{}", to_json(diagnostic)))
         .collect::<Vec<_>>()
         .join(",\n");
     println!("[\n{messages}\n]");
 }
+
+fn to_json(diagnostic: &Error) -> Value {
+    let source = diagnostic.source_code();
+    let severity = match diagnostic.severity() {
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Advice) => "advice",
+        Some(Severity::Error) | None => "error",
+    };
+    let filename = diagnostic
+        .labels()
+        .and_then(|mut labels| labels.next())
+        .and_then(|label| source?.read_span(label.inner(), 0, 0).ok())
+        .and_then(|span_content| span_content.name().map(ToString::to_string));
+    let labels = diagnostic.labels().map_or_else(Vec::new, |labels| {
+        labels
+            .map(|label| json!({ "label": label.label(), "span": span(source, *label.inner()) }))
+            .collect()
+    });
+    let fix = OxcDiagnostic::from_error(diagnostic)
+        .and_then(|d| d.fix.as_ref())
+        .map(|fix| json!({ "span": span(source, fix.span), "content": fix.content }));
+    json!({
+        "message": diagnostic.to_string(),
+        "code": diagnostic.code().map(|code| code.to_string()),
+        "severity": severity,
+        "url": diagnostic.url().map(|url| url.to_string()),
+        "help": diagnostic.help().map(|help| help.to_string()),
+        "filename": filename.unwrap_or_default(),
+        "labels": labels,
+        "fix": fix,
+    })
+}
+
+fn span(source: Option<&dyn SourceCode>, span: SourceSpan) -> Value {
+    let mut value = json!({ "offset": span.offset(), "length": span.len() });
+    if let Some(span_content) = source.and_then(|source| source.read_span(&span, 0, 0).ok()) {
+        value["line"] = json!(span_content.line() + 1);
+        value["column"] = json!(span_content.column() + 1);
+    }
+    value
+}
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use super::{
+    checkstyle::{group_by_filename, xml_escape},
+    DiagnosticReporter, Info,
+};
+use crate::{Error, Severity};
+
+/// Renders reports as JUnit XML, with a test suite per file and a failed test case per
+/// diagnostic.
+#[derive(Default)]
+pub struct JunitReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for JunitReporter {
+    fn finish(&mut self) {
+        format_junit(&self.diagnostics);
+    }
+
+    fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+/// <https://github.com/eslint/eslint/blob/v8.57.0/lib/cli-engine/formatters/junit.js>
+#[allow(clippy::print_stdout)]
+fn format_junit(diagnostics: &[Error]) {
+    let test_suites = group_by_filename(diagnostics)
+        .into_iter()
+        .map(|infos| {
+            let filename = xml_escape(&infos[0].filename).into_owned();
+            let classname = Path::new(&infos[0].filename).with_extension("");
+            let classname = xml_escape(&classname.to_string_lossy()).into_owned();
+            let test_cases = infos
+                .iter()
+                .map(|info| {
+                    let Info { line, column, message, severity, rule_id, .. } = info;
+                    let severity = match severity {
+                        Severity::Error => "Error",
+                        _ => "Warning",
+                    };
+                    let rule_id = rule_id.as_deref().unwrap_or("");
+                    let message = xml_escape(message);
+                    format!(
+                        r#"<testcase time="0" name="org.oxlint.{rule_id}" classname="{classname}"><failure message="{message}"><![CDATA[line {line}, col {column}, {severity} - {message} ({rule_id})]]></failure></testcase>"#,
+                        rule_id = xml_escape(rule_id),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let total = infos.len();
+            format!(
+                r#"<testsuite package="org.oxlint" time="0" tests="{total}" errors="{total}" name="{filename}">
+{test_cases}
+</testsuite>"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    println!(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+    println!("<testsuites>\n{test_suites}\n</testsuites>");
+}
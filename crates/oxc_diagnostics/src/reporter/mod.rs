@@ -4,13 +4,15 @@ mod checkstyle;
 mod github;
 mod graphical;
 mod json;
+mod junit;
+mod sarif;
 mod unix;
 
 use std::io::{BufWriter, Stdout};
 
 pub use self::{
     checkstyle::CheckstyleReporter, github::GithubReporter, graphical::GraphicalReporter,
-    json::JsonReporter, unix::UnixReporter,
+    json::JsonReporter, junit::JunitReporter, sarif::SarifReporter, unix::UnixReporter,
 };
 use crate::{Error, Severity};
 
@@ -0,0 +1,124 @@
+use cow_utils::CowUtils;
+use miette::{SourceCode, SourceSpan};
+use rustc_hash::FxHashMap;
+use serde_json::{json, Value};
+
+use super::{DiagnosticReporter, Info};
+use crate::{Error, OxcDiagnostic, Severity};
+
+/// Renders reports as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// log, e.g. for GitHub code scanning.
+///
+/// Like [`JsonReporter`](super::JsonReporter), this reporter waits until all diagnostics have
+/// been reported before writing them to the output stream.
+#[derive(Default)]
+pub struct SarifReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self) {
+        format_sarif(&self.diagnostics);
+    }
+
+    fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[allow(clippy::print_stdout)]
+fn format_sarif(diagnostics: &[Error]) {
+    let mut rules = vec![];
+    let mut rule_indices = FxHashMap::default();
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let Info { filename, message, severity, rule_id, .. } = Info::new(diagnostic);
+            let level = match severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Advice => "note",
+            };
+            let uri = filename.cow_replace('\\', "/").into_owned();
+            let source = diagnostic.source_code();
+            let location_region = diagnostic
+                .labels()
+                .and_then(|mut labels| labels.next())
+                .and_then(|label| region(source?, *label.inner()));
+
+            let mut result = json!({
+                "level": level,
+                "message": { "text": message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": uri },
+                        "region": location_region,
+                    },
+                }],
+            });
+            if let Some(rule_id) = rule_id {
+                let rule_index = *rule_indices.entry(rule_id.clone()).or_insert_with(|| {
+                    let mut rule = json!({ "id": rule_id });
+                    if let Some(url) = diagnostic.url() {
+                        rule["helpUri"] = json!(url.to_string());
+                    }
+                    rules.push(rule);
+                    rules.len() - 1
+                });
+                result["ruleId"] = json!(rule_id);
+                result["ruleIndex"] = json!(rule_index);
+            }
+            let fix = OxcDiagnostic::from_error(diagnostic).and_then(|d| d.fix.as_ref());
+            if let Some((fix, deleted_region)) =
+                fix.and_then(|fix| Some((fix, region(source?, fix.span)?)))
+            {
+                let description = diagnostic.help().map_or_else(
+                    || "Apply the fix of this problem".to_string(),
+                    |help| help.to_string(),
+                );
+                result["fixes"] = json!([{
+                    "description": { "text": description },
+                    "artifactChanges": [{
+                        "artifactLocation": { "uri": uri },
+                        "replacements": [{
+                            "deletedRegion": deleted_region,
+                            "insertedContent": { "text": fix.content },
+                        }],
+                    }],
+                }]);
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+
+    let log = json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "oxlint",
+                    "informationUri": "https://oxc.rs",
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+    println!("{log:#}");
+}
+
+/// One-based start and end lines and columns of `span`.
+fn region(source: &dyn SourceCode, span: SourceSpan) -> Option<Value> {
+    let start = source.read_span(&span, 0, 0).ok()?;
+    let end = source.read_span(&(span.offset() + span.len()).into(), 0, 0).ok()?;
+    Some(json!({
+        "startLine": start.line() + 1,
+        "startColumn": start.column() + 1,
+        "endLine": end.line() + 1,
+        "endColumn": end.column() + 1,
+    }))
+}
@@ -7,7 +7,7 @@ use std::{
 use crate::{
     reporter::{
         CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonReporter,
-        UnixReporter,
+        JunitReporter, SarifReporter, UnixReporter,
     },
     Error, NamedSource, OxcDiagnostic, Severity,
 };
@@ -110,6 +110,23 @@ impl DiagnosticService {
         self.reporter = Box::<CheckstyleReporter>::default();
     }
 
+    /// Configure this service to format reports as JUnit XML, with a failed test case per
+    /// diagnostic.
+    pub fn set_junit_reporter(&mut self) {
+        self.reporter = Box::<JunitReporter>::default();
+    }
+
+    /// Configure this service to format reports as a [SARIF](https://sarifweb.azurewebsites.net)
+    /// log, including the fixes attached to diagnostics.
+    pub fn set_sarif_reporter(&mut self) {
+        self.reporter = Box::<SarifReporter>::default();
+    }
+
+    /// Configure this service to pretty-print reports to stderr, leaving stdout to other output.
+    pub fn set_stderr_reporter(&mut self) {
+        self.reporter = Box::new(GraphicalReporter::stderr());
+    }
+
     /// Configure this service to formats reports using [GitHub Actions
     /// annotations](https://docs.github.com/en/actions/reference/workflow-commands-for-github-actions#setting-an-error-message).
     pub fn set_github_reporter(&mut self) {
@@ -69,7 +69,8 @@ impl<'a> PropName for PropertyDefinition<'a> {
 #[cfg(test)]
 mod test {
     use oxc_allocator::Allocator;
-    use oxc_ast::{ast::ObjectExpression, Visit};
+    use oxc_ast::ast::ObjectExpression;
+    use oxc_ast_visit::Visit;
     use oxc_parser::Parser;
     use oxc_span::SourceType;
 
@@ -27,7 +27,8 @@ impl<'a, 'b> CheckForStateChange<'a, 'b> for Expression<'a> {
             | Self::MetaProperty(_)
             | Self::ThisExpression(_)
             | Self::ClassExpression(_)
-            | Self::FunctionExpression(_) => false,
+            | Self::FunctionExpression(_)
+            | Self::ArrowFunctionExpression(_) => false,
             Self::TemplateLiteral(template) => template
                 .expressions
                 .iter()
@@ -4,8 +4,8 @@ use oxc_allocator::Box;
 use oxc_allocator::CloneIn;
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
-use oxc_ast::visit::walk_mut::walk_ts_signatures;
-use oxc_ast::{Visit, VisitMut};
+use oxc_ast_visit::walk_mut::walk_ts_signatures;
+use oxc_ast_visit::{Visit, VisitMut};
 use oxc_ecmascript::BoundNames;
 use oxc_span::{GetSpan, SPAN};
 use oxc_syntax::scope::ScopeFlags;
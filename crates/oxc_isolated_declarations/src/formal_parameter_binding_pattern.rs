@@ -1,6 +1,5 @@
-use oxc_ast::{
-    ast::BindingPatternKind, visit::walk_mut::walk_binding_pattern_kind, AstBuilder, VisitMut,
-};
+use oxc_ast::{ast::BindingPatternKind, AstBuilder};
+use oxc_ast_visit::{walk_mut::walk_binding_pattern_kind, VisitMut};
 
 pub struct FormalParameterBindingPattern<'a> {
     ast: AstBuilder<'a>,
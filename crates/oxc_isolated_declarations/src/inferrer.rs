@@ -16,7 +16,11 @@ impl<'a> IsolatedDeclarations<'a> {
         expr.operator.is_arithmetic() && expr.argument.is_number_literal()
     }
 
-    pub(crate) fn infer_type_from_expression(&self, expr: &Expression<'a>) -> Option<TSType<'a>> {
+    /// Infer the type of `expr` as it is emitted in declaration files, without type checking.
+    ///
+    /// Returns `None` if the type cannot be inferred from the expression alone. Errors are
+    /// reported as in [`IsolatedDeclarations::build`], e.g. for array literals.
+    pub fn infer_type_from_expression(&self, expr: &Expression<'a>) -> Option<TSType<'a>> {
         match expr {
             Expression::BooleanLiteral(_) => Some(self.ast.ts_type_boolean_keyword(SPAN)),
             Expression::NullLiteral(_) => Some(self.ast.ts_type_null_keyword(SPAN)),
@@ -106,7 +110,9 @@ impl<'a> IsolatedDeclarations<'a> {
         }
     }
 
-    pub(crate) fn infer_function_return_type(
+    /// Infer the return type of `function` from its `return` statements, or copy its return
+    /// type annotation.
+    pub fn infer_function_return_type(
         &self,
         function: &Function<'a>,
     ) -> Option<Box<'a, TSTypeAnnotation<'a>>> {
@@ -125,7 +131,8 @@ impl<'a> IsolatedDeclarations<'a> {
         })
     }
 
-    pub(crate) fn infer_arrow_function_return_type(
+    /// Infer the return type of `function` from its body, or copy its return type annotation.
+    pub fn infer_arrow_function_return_type(
         &self,
         function: &ArrowFunctionExpression<'a>,
     ) -> Option<Box<'a, TSTypeAnnotation<'a>>> {
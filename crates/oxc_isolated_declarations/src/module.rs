@@ -140,6 +140,7 @@ impl<'a> IsolatedDeclarations<'a> {
                 // SAFETY: `ast.copy` is unsound! We need to fix.
                 unsafe { self.ast.copy(&decl.with_clause) },
                 decl.import_kind,
+                decl.phase,
             ))
         }
     }
@@ -5,8 +5,9 @@ use oxc_ast::{
         ArrowFunctionExpression, BindingIdentifier, Expression, Function, FunctionBody,
         ReturnStatement, TSType, TSTypeAliasDeclaration, TSTypeName, TSTypeQueryExprName,
     },
-    AstBuilder, Visit,
+    AstBuilder,
 };
+use oxc_ast_visit::Visit;
 use oxc_span::{Atom, GetSpan, SPAN};
 use oxc_syntax::scope::{ScopeFlags, ScopeId};
 
@@ -4,7 +4,7 @@ use bitflags::bitflags;
 use rustc_hash::FxHashMap;
 
 use oxc_ast::ast::*;
-use oxc_ast::{visit::walk::*, Visit};
+use oxc_ast_visit::{walk::*, Visit};
 use oxc_span::Atom;
 use oxc_syntax::scope::{ScopeFlags, ScopeId};
 
@@ -0,0 +1,212 @@
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::linter::{DiagnosticReport, FixedContent};
+
+/// Fixes all auto-fixable problems of a document, editors can run it on save, e.g. with
+/// `"editor.codeActionsOnSave": { "source.fixAll.oxc": "explicit" }` in VS Code.
+pub const SOURCE_FIX_ALL_OXC: CodeActionKind = CodeActionKind::new("source.fixAll.oxc");
+
+/// The code actions of the document at `uri`, for `textDocument/codeAction`: a quick fix for
+/// each fixable problem in `range`, and a [`SOURCE_FIX_ALL_OXC`] action.
+///
+/// `only` are the kinds of code actions requested by the client, all kinds if it is `None`.
+pub fn code_actions(
+    uri: &Url,
+    reports: &[DiagnosticReport],
+    range: Range,
+    only: Option<&[CodeActionKind]>,
+) -> Vec<CodeActionOrCommand> {
+    let mut actions = vec![];
+    if is_requested(&CodeActionKind::QUICKFIX, only) {
+        actions.extend(
+            reports
+                .iter()
+                .filter(|report| overlaps(report.diagnostic.range, range))
+                .filter_map(|report| quick_fix(uri, report))
+                .map(CodeActionOrCommand::CodeAction),
+        );
+    }
+    if is_requested(&SOURCE_FIX_ALL_OXC, only) {
+        actions.extend(fix_all(uri, reports).map(CodeActionOrCommand::CodeAction));
+    }
+    actions
+}
+
+fn quick_fix(uri: &Url, report: &DiagnosticReport) -> Option<CodeAction> {
+    let fixed_content = report.fixed_content.as_ref()?;
+    let title = report
+        .diagnostic
+        .message
+        .split(':')
+        .next()
+        .map_or_else(|| "Fix this problem".into(), |s| format!("Fix this {s} problem"));
+    Some(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(true),
+        edit: Some(workspace_edit(uri, vec![text_edit(fixed_content)])),
+        disabled: None,
+        data: None,
+        diagnostics: Some(vec![report.diagnostic.clone()]),
+        command: None,
+    })
+}
+
+fn fix_all(uri: &Url, reports: &[DiagnosticReport]) -> Option<CodeAction> {
+    let mut fixes =
+        reports.iter().filter_map(|report| report.fixed_content.as_ref()).collect::<Vec<_>>();
+    fixes.sort_by_key(|fix| (fix.range.start, fix.range.end));
+
+    // The edits of a workspace edit must not overlap, the remaining fixes are applied by running
+    // the action again.
+    let mut edits: Vec<TextEdit> = vec![];
+    for fix in fixes {
+        let overlapping = edits.last().is_some_and(|last| {
+            fix.range.start < last.range.end || fix.range.start == last.range.start
+        });
+        if !overlapping {
+            edits.push(text_edit(fix));
+        }
+    }
+    if edits.is_empty() {
+        return None;
+    }
+
+    Some(CodeAction {
+        title: "Fix all auto-fixable problems".into(),
+        kind: Some(SOURCE_FIX_ALL_OXC),
+        is_preferred: None,
+        edit: Some(workspace_edit(uri, edits)),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    })
+}
+
+/// Code action kinds are hierarchical, e.g. requesting `source.fixAll` includes
+/// `source.fixAll.oxc`.
+fn is_requested(kind: &CodeActionKind, only: Option<&[CodeActionKind]>) -> bool {
+    only.map_or(true, |only| {
+        only.iter().any(|requested| {
+            kind.as_str()
+                .strip_prefix(requested.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+    })
+}
+
+fn overlaps(first: Range, other: Range) -> bool {
+    first.start <= other.end && other.start <= first.end
+}
+
+fn text_edit(fixed_content: &FixedContent) -> TextEdit {
+    TextEdit { range: fixed_content.range, new_text: fixed_content.code.clone() }
+}
+
+fn workspace_edit(uri: &Url, edits: Vec<TextEdit>) -> WorkspaceEdit {
+    WorkspaceEdit {
+        #[expect(clippy::disallowed_types)]
+        changes: Some(std::collections::HashMap::from([(uri.clone(), edits)])),
+        ..WorkspaceEdit::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tower_lsp::lsp_types::{
+        CodeActionKind, CodeActionOrCommand, Diagnostic, Position, Range, TextEdit, Url,
+    };
+
+    use super::{code_actions, fix_all, is_requested, SOURCE_FIX_ALL_OXC};
+    use crate::linter::{DiagnosticReport, FixedContent};
+
+    fn range(start: u32, end: u32) -> Range {
+        Range::new(Position::new(0, start), Position::new(0, end))
+    }
+
+    fn report(start: u32, end: u32, code: &str) -> DiagnosticReport {
+        DiagnosticReport {
+            diagnostic: Diagnostic::new_simple(range(start, end), "eslint(no-debugger): x".into()),
+            fixed_content: Some(FixedContent { code: code.into(), range: range(start, end) }),
+        }
+    }
+
+    fn uri() -> Url {
+        Url::parse("file:///a.js").unwrap()
+    }
+
+    fn edits(reports: &[DiagnosticReport]) -> Vec<TextEdit> {
+        let action = fix_all(&uri(), reports).unwrap();
+        action.edit.unwrap().changes.unwrap().remove(&uri()).unwrap()
+    }
+
+    #[test]
+    fn fix_all_edits() {
+        let reports = [report(5, 6, "b"), report(0, 3, "a")];
+        assert_eq!(
+            edits(&reports),
+            [
+                TextEdit { range: range(0, 3), new_text: "a".into() },
+                TextEdit { range: range(5, 6), new_text: "b".into() },
+            ]
+        );
+
+        let mut unfixable = report(0, 1, "");
+        unfixable.fixed_content = None;
+        assert!(fix_all(&uri(), &[unfixable]).is_none());
+    }
+
+    #[test]
+    fn fix_all_overlapping_edits() {
+        // Overlapping fixes are left for the next run, the first one is applied.
+        let reports = [report(0, 3, "a"), report(2, 5, "b"), report(3, 4, "c")];
+        assert_eq!(
+            edits(&reports),
+            [
+                TextEdit { range: range(0, 3), new_text: "a".into() },
+                TextEdit { range: range(3, 4), new_text: "c".into() },
+            ]
+        );
+
+        // Insertions at the same position would be applied in any order.
+        let reports = [report(1, 1, "a"), report(1, 1, "b")];
+        assert_eq!(edits(&reports), [TextEdit { range: range(1, 1), new_text: "a".into() }]);
+    }
+
+    #[test]
+    fn requested_kinds() {
+        assert!(is_requested(&CodeActionKind::QUICKFIX, None));
+        assert!(is_requested(&SOURCE_FIX_ALL_OXC, None));
+        assert!(is_requested(&CodeActionKind::QUICKFIX, Some(&[CodeActionKind::QUICKFIX])));
+        assert!(is_requested(&SOURCE_FIX_ALL_OXC, Some(&[CodeActionKind::SOURCE_FIX_ALL])));
+        assert!(is_requested(&SOURCE_FIX_ALL_OXC, Some(&[CodeActionKind::SOURCE])));
+        assert!(is_requested(&SOURCE_FIX_ALL_OXC, Some(&[SOURCE_FIX_ALL_OXC])));
+
+        assert!(!is_requested(&SOURCE_FIX_ALL_OXC, Some(&[])));
+        assert!(!is_requested(&SOURCE_FIX_ALL_OXC, Some(&[CodeActionKind::QUICKFIX])));
+        assert!(!is_requested(&CodeActionKind::QUICKFIX, Some(&[CodeActionKind::SOURCE])));
+        // Kinds are matched by their dot separated parts.
+        assert!(!is_requested(&SOURCE_FIX_ALL_OXC, Some(&[CodeActionKind::new("source.fix")])));
+    }
+
+    #[test]
+    fn requested_actions() {
+        let reports = [report(0, 3, "a"), report(5, 6, "b")];
+        let kinds = |only: Option<&[CodeActionKind]>| {
+            code_actions(&uri(), &reports, range(0, 1), only)
+                .into_iter()
+                .map(|action| {
+                    let CodeActionOrCommand::CodeAction(action) = action else { unreachable!() };
+                    action.kind.unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+        // Quick fixes are only for the problems in the range.
+        assert_eq!(kinds(None), [CodeActionKind::QUICKFIX, SOURCE_FIX_ALL_OXC]);
+        assert_eq!(kinds(Some(&[CodeActionKind::QUICKFIX])), [CodeActionKind::QUICKFIX]);
+        assert_eq!(kinds(Some(&[CodeActionKind::SOURCE_FIX_ALL])), [SOURCE_FIX_ALL_OXC]);
+    }
+}
@@ -0,0 +1,288 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrowFunctionExpression, BindingPatternKind, Expression, FormalParameters, Function, TSType,
+    TSTypeAnnotation, VariableDeclarator,
+};
+use oxc_ast_visit::{walk, Visit};
+use oxc_codegen::{Codegen, Context, Gen};
+use oxc_isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions};
+use oxc_linter::loader::{JavaScriptSource, Loader};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::ScopeFlags;
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range, TextEdit};
+
+use crate::{linter::offset_to_position, rename::position_to_offset};
+
+/// Longer types are truncated, like in the inlay hints of the TypeScript language service.
+const MAX_HINT_LENGTH: usize = 30;
+
+/// The inlay hints in `range`, for `textDocument/inlayHint`.
+///
+/// Shows the types of variables and the return types of functions which have no type
+/// annotation, when they can be inferred like in isolated declarations, i.e. without a type
+/// checker. Other types are not shown.
+///
+/// Only TypeScript sources have hints, as type annotations cannot be inserted into JavaScript.
+pub fn inlay_hints(path: &Path, source_text: &str, range: Range) -> Vec<InlayHint> {
+    if !Loader::can_load(path) {
+        return vec![];
+    }
+    let (Some(range_start), Some(range_end)) =
+        (position_to_offset(range.start, source_text), position_to_offset(range.end, source_text))
+    else {
+        return vec![];
+    };
+    let Ok(sources) = Loader.load_str(path, source_text) else {
+        return vec![];
+    };
+
+    let mut hints = vec![];
+    for source in sources {
+        let JavaScriptSource { source_text: javascript_source_text, source_type, start, .. } =
+            source;
+        if !source_type.is_typescript() {
+            continue;
+        }
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, javascript_source_text, source_type)
+            .with_options(ParseOptions {
+                allow_return_outside_function: true,
+                ..ParseOptions::default()
+            })
+            .parse();
+        if !ret.errors.is_empty() {
+            continue;
+        }
+
+        let mut collector = TypeHintCollector {
+            isolated_declarations: IsolatedDeclarations::new(
+                &allocator,
+                IsolatedDeclarationsOptions::default(),
+            ),
+            source_text: javascript_source_text,
+            hints: vec![],
+        };
+        collector.visit_program(&ret.program);
+
+        for (offset, ty) in collector.hints {
+            let offset = offset + start;
+            if offset < range_start || range_end < offset {
+                continue;
+            }
+            let Some(position) = offset_to_position(offset as usize, source_text) else {
+                continue;
+            };
+            let label = format!(": {ty}");
+            let (label, text_edits) = if label.chars().count() > MAX_HINT_LENGTH {
+                (truncate(&label), None)
+            } else {
+                // Double clicking a hint inserts it.
+                let edit =
+                    TextEdit { range: Range::new(position, position), new_text: label.clone() };
+                (label, Some(vec![edit]))
+            };
+            hints.push(InlayHint {
+                position,
+                label: InlayHintLabel::String(label),
+                kind: Some(InlayHintKind::TYPE),
+                text_edits,
+                tooltip: None,
+                padding_left: None,
+                padding_right: None,
+                data: None,
+            });
+        }
+    }
+    hints
+}
+
+/// Collects the offsets of the missing type annotations, and their inferred types.
+struct TypeHintCollector<'a, 's> {
+    isolated_declarations: IsolatedDeclarations<'a>,
+    source_text: &'s str,
+    hints: Vec<(u32, String)>,
+}
+
+impl<'a> Visit<'a> for TypeHintCollector<'a, '_> {
+    fn visit_variable_declarator(&mut self, decl: &VariableDeclarator<'a>) {
+        if let (BindingPatternKind::BindingIdentifier(ident), None, Some(init)) =
+            (&decl.id.kind, &decl.id.type_annotation, &decl.init)
+        {
+            if !is_obvious_type(init, decl.kind.is_const()) {
+                if let Some(ty) = self.isolated_declarations.infer_type_from_expression(init) {
+                    self.add_hint(ident.span.end, &ty);
+                }
+            }
+        }
+        walk::walk_variable_declarator(self, decl);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if func.return_type.is_none() {
+            let return_type = self.isolated_declarations.infer_function_return_type(func);
+            self.add_return_type_hint(&func.params, return_type.as_deref());
+        }
+        walk::walk_function(self, func, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, func: &ArrowFunctionExpression<'a>) {
+        if func.return_type.is_none() {
+            let return_type = self.isolated_declarations.infer_arrow_function_return_type(func);
+            self.add_return_type_hint(&func.params, return_type.as_deref());
+        }
+        walk::walk_arrow_function_expression(self, func);
+    }
+}
+
+impl TypeHintCollector<'_, '_> {
+    fn add_return_type_hint(
+        &mut self,
+        params: &FormalParameters,
+        return_type: Option<&TSTypeAnnotation>,
+    ) {
+        let Some(return_type) = return_type else {
+            return;
+        };
+        // The return type of `x => x` cannot be annotated without adding parentheses.
+        if !params.span.source_text(self.source_text).ends_with(')') {
+            return;
+        }
+        self.add_hint(params.span.end, &return_type.type_annotation);
+    }
+
+    fn add_hint(&mut self, offset: u32, ty: &TSType) {
+        // Types which cannot be inferred, e.g. of array literals, are `unknown`.
+        if matches!(ty, TSType::TSUnknownKeyword(_)) {
+            return;
+        }
+        let mut codegen = Codegen::new();
+        ty.print(&mut codegen, Context::empty());
+        // Object types are printed on multiple lines.
+        let ty = codegen.into_source_text().split_whitespace().collect::<Vec<_>>().join(" ");
+        self.hints.push((offset, ty));
+    }
+}
+
+/// Whether the type of a variable initialized to `init` is obvious from the source, e.g. the
+/// literal type `1` of `const x = 1`, or the type of `x as Foo`.
+fn is_obvious_type(init: &Expression, is_const: bool) -> bool {
+    match init.without_parentheses() {
+        // The signature of functions is already in the source, their return type has a hint.
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => true,
+        Expression::TSAsExpression(expr) => !expr.type_annotation.is_const_type_reference(),
+        Expression::TSTypeAssertion(_) => true,
+        Expression::TemplateLiteral(lit) => is_const && lit.is_no_substitution_template(),
+        Expression::UnaryExpression(expr) => is_const && expr.argument.is_number_literal(),
+        expr => is_const && expr.is_literal(),
+    }
+}
+
+fn truncate(label: &str) -> String {
+    let mut truncated = label.chars().take(MAX_HINT_LENGTH - 3).collect::<String>();
+    truncated.push_str("...");
+    truncated
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+    use tower_lsp::lsp_types::{InlayHint, InlayHintLabel, Position, Range};
+
+    use super::{inlay_hints, is_obvious_type, truncate, MAX_HINT_LENGTH};
+
+    fn hints(path: &str, source_text: &str) -> Vec<(Position, String)> {
+        let line_count = u32::try_from(source_text.lines().count()).unwrap();
+        let range = Range::new(Position::new(0, 0), Position::new(line_count, 0));
+        inlay_hints(Path::new(path), source_text, range)
+            .into_iter()
+            .map(|InlayHint { position, label, .. }| {
+                let InlayHintLabel::String(label) = label else { unreachable!() };
+                (position, label)
+            })
+            .collect()
+    }
+
+    fn is_obvious(source_text: &str, is_const: bool) -> bool {
+        let allocator = Allocator::default();
+        let expr =
+            Parser::new(&allocator, source_text, SourceType::ts()).parse_expression().unwrap();
+        is_obvious_type(&expr, is_const)
+    }
+
+    #[test]
+    fn obvious_types() {
+        assert!(is_obvious("1", true));
+        assert!(is_obvious("-1", true));
+        assert!(is_obvious("'a'", true));
+        assert!(is_obvious("`a`", true));
+        assert!(is_obvious("(1)", true));
+        assert!(is_obvious("x as Foo", false));
+        assert!(is_obvious("<Foo>x", false));
+        assert!(is_obvious("() => 1", false));
+        assert!(is_obvious("function () {}", false));
+
+        // `let x = 1` is a `number`, not `1`.
+        assert!(!is_obvious("1", false));
+        assert!(!is_obvious("`a${b}`", true));
+        assert!(!is_obvious("-x", true));
+        assert!(!is_obvious("x as const", true));
+        assert!(!is_obvious("{ a: 1 }", true));
+    }
+
+    #[test]
+    fn variable_hints() {
+        assert_eq!(hints("a.ts", "let x = 1;"), [(Position::new(0, 5), ": number".into())]);
+        assert_eq!(hints("a.ts", "const x = 1; let y: number = 1;"), []);
+        assert_eq!(hints("a.ts", "const x = [1];"), []);
+    }
+
+    #[test]
+    fn return_type_hints() {
+        assert_eq!(
+            hints("a.ts", "function f() { return 1 }"),
+            [(Position::new(0, 12), ": number".into())]
+        );
+        assert_eq!(
+            hints("a.ts", "const f = (x) => 1;"),
+            [(Position::new(0, 13), ": number".into())]
+        );
+        // `x => 1` needs parentheses for a return type.
+        assert_eq!(hints("a.ts", "const f = x => 1;"), []);
+    }
+
+    #[test]
+    fn javascript() {
+        assert_eq!(hints("a.js", "let x = 1; function f() { return 1 }"), []);
+        assert_eq!(
+            hints("a.vue", "<script>let x = 1;</script><script lang=\"ts\">let y = 1;</script>"),
+            [(Position::new(0, 50), ": number".into())]
+        );
+    }
+
+    #[test]
+    fn truncated_hints() {
+        let label = format!(": {}", "a".repeat(MAX_HINT_LENGTH));
+        let truncated = truncate(&label);
+        assert_eq!(truncated.chars().count(), MAX_HINT_LENGTH);
+        assert!(truncated.starts_with(": aaa"));
+        assert!(truncated.ends_with("..."));
+
+        let source_text = format!("let x = {{ {}: 1 }};", "a".repeat(MAX_HINT_LENGTH));
+        let hints = inlay_hints(
+            Path::new("a.ts"),
+            &source_text,
+            Range::new(Position::new(0, 0), Position::new(1, 0)),
+        );
+        assert_eq!(hints.len(), 1);
+        // Truncated hints cannot be inserted.
+        assert!(hints[0].text_edits.is_none());
+        let InlayHintLabel::String(label) = &hints[0].label else { unreachable!() };
+        assert!(label.ends_with("..."));
+    }
+}
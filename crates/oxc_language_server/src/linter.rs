@@ -323,7 +323,7 @@ impl IsolatedLintHandler {
         Some((path.to_path_buf(), diagnostics))
     }
 
-    fn should_lint_path(path: &Path) -> bool {
+    pub fn should_lint_path(path: &Path) -> bool {
         static WANTED_EXTENSIONS: OnceLock<FxHashSet<&'static str>> = OnceLock::new();
         let wanted_exts = WANTED_EXTENSIONS.get_or_init(|| {
             VALID_EXTENSIONS.iter().chain(LINT_PARTIAL_LOADER_EXT.iter()).copied().collect()
@@ -357,7 +357,7 @@ impl IsolatedLintHandler {
 }
 
 #[allow(clippy::cast_possible_truncation)]
-fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
+pub fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
     let rope = Rope::from_str(source_text);
     let line = rope.try_byte_to_line(offset).ok()?;
     let first_char_of_line = rope.try_line_to_char(line).ok()?;
@@ -1,6 +1,16 @@
+mod code_actions;
+mod inlay_hints;
 mod linter;
+mod rename;
 
-use std::{fmt::Debug, path::PathBuf, str::FromStr};
+use std::{
+    fmt::Debug,
+    fs, mem,
+    path::PathBuf,
+    str::FromStr,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::SystemTime,
+};
 
 use dashmap::DashMap;
 use futures::future::join_all;
@@ -8,32 +18,85 @@ use globset::Glob;
 use ignore::gitignore::Gitignore;
 use log::{debug, error, info};
 use oxc_linter::{FixKind, LinterBuilder, Oxlintrc};
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, OnceCell, RwLock, SetError};
 use tower_lsp::{
     jsonrpc::{Error, ErrorCode, Result},
     lsp_types::{
-        CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
-        CodeActionProviderCapability, CodeActionResponse, ConfigurationItem, Diagnostic,
-        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-        DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
-        InitializedParams, OneOf, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-        TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
-        WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+        notification::Notification, request::WorkspaceDiagnosticRefresh, CodeActionKind,
+        CodeActionOptions, CodeActionParams, CodeActionProviderCapability, CodeActionResponse,
+        ConfigurationItem, Diagnostic, DiagnosticOptions, DiagnosticServerCapabilities,
+        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+        DidChangeWatchedFilesRegistrationOptions, DidCloseTextDocumentParams,
+        DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentDiagnosticParams,
+        DocumentDiagnosticReport, DocumentDiagnosticReportResult, FileSystemWatcher,
+        FullDocumentDiagnosticReport, GlobPattern, InitializeParams, InitializeResult,
+        InitializedParams, InlayHint, InlayHintParams, OneOf, PrepareRenameResponse, ProgressToken,
+        Registration, RelatedFullDocumentDiagnosticReport,
+        RelatedUnchangedDocumentDiagnosticReport, RenameOptions, RenameParams, ServerCapabilities,
+        ServerInfo, TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind,
+        UnchangedDocumentDiagnosticReport, Url, WorkDoneProgressOptions, WorkspaceDiagnosticParams,
+        WorkspaceDiagnosticReport, WorkspaceDiagnosticReportPartialResult,
+        WorkspaceDiagnosticReportResult, WorkspaceDocumentDiagnosticReport, WorkspaceEdit,
+        WorkspaceFoldersServerCapabilities, WorkspaceFullDocumentDiagnosticReport,
+        WorkspaceServerCapabilities, WorkspaceUnchangedDocumentDiagnosticReport,
     },
     Client, LanguageServer, LspService, Server,
 };
 
-use crate::linter::{DiagnosticReport, ServerLinter};
+use crate::linter::{DiagnosticReport, IsolatedLintHandler, ServerLinter};
+
+/// Number of files reported at once by `workspace_diagnostic`, if the client accepts partial
+/// results.
+const WORKSPACE_DIAGNOSTIC_BATCH_SIZE: usize = 50;
 
 struct Backend {
     client: Client,
     root_uri: OnceCell<Option<Url>>,
     server_linter: RwLock<ServerLinter>,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
+    /// Result id of the diagnostics in `diagnostics_report_map`, for pull diagnostics.
+    diagnostics_result_ids: DashMap<String, String>,
+    next_result_id: AtomicU64,
+    /// Files linted by `workspace_diagnostic` which are not open. Their diagnostics are not kept,
+    /// only what is needed to skip linting them again while they are unchanged.
+    closed_files: DashMap<String, ClosedFile>,
+    /// Text of open documents, which may not be written to disk yet.
+    documents: DashMap<String, String>,
+    /// The client pulls diagnostics with `textDocument/diagnostic` instead of having them
+    /// published.
+    pull_diagnostics: AtomicBool,
+    /// The client watches the config files for the server, see `initialized`.
+    watch_config_files: AtomicBool,
     options: Mutex<Options>,
     gitignore_glob: Mutex<Vec<Gitignore>>,
 }
+
+/// A file linted by `workspace_diagnostic` while it was not open.
+#[derive(Debug, Clone)]
+struct ClosedFile {
+    /// Modification time of the file when it was linted.
+    modified: SystemTime,
+    result_id: String,
+    has_diagnostics: bool,
+}
+
+/// `$/progress` notification with the partial result of `workspace_diagnostic`, see
+/// <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#partialResults>
+enum WorkspaceDiagnosticProgress {}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WorkspaceDiagnosticProgressParams {
+    token: ProgressToken,
+    value: WorkspaceDiagnosticReportPartialResult,
+}
+
+impl Notification for WorkspaceDiagnosticProgress {
+    type Params = WorkspaceDiagnosticProgressParams;
+    const METHOD: &'static str = "$/progress";
+}
+
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, PartialOrd, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 enum Run {
@@ -88,6 +151,20 @@ impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
         self.init(params.root_uri)?;
         self.init_ignore_glob().await;
+        let pull_diagnostics = params
+            .capabilities
+            .text_document
+            .as_ref()
+            .is_some_and(|capabilities| capabilities.diagnostic.is_some());
+        self.pull_diagnostics.store(pull_diagnostics, Ordering::Relaxed);
+        let watch_config_files = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|capabilities| capabilities.did_change_watched_files.as_ref())
+            .and_then(|capabilities| capabilities.dynamic_registration)
+            .unwrap_or(false);
+        self.watch_config_files.store(watch_config_files, Ordering::Relaxed);
         let options = params.initialization_options.and_then(|mut value| {
             let settings = value.get_mut("settings")?.take();
             serde_json::from_value::<Options>(settings).ok()
@@ -115,13 +192,33 @@ impl LanguageServer for Backend {
                 }),
                 code_action_provider: Some(CodeActionProviderCapability::Options(
                     CodeActionOptions {
-                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::QUICKFIX,
+                            code_actions::SOURCE_FIX_ALL_OXC,
+                        ]),
                         work_done_progress_options: WorkDoneProgressOptions {
                             work_done_progress: None,
                         },
                         resolve_provider: None,
                     },
                 )),
+                diagnostic_provider: pull_diagnostics.then(|| {
+                    DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                        identifier: Some("oxc".into()),
+                        inter_file_dependencies: false,
+                        workspace_diagnostics: true,
+                        work_done_progress_options: WorkDoneProgressOptions {
+                            work_done_progress: None,
+                        },
+                    })
+                }),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions {
+                        work_done_progress: None,
+                    },
+                })),
+                inlay_hint_provider: Some(OneOf::Left(true)),
                 ..ServerCapabilities::default()
             },
         })
@@ -151,6 +248,24 @@ impl LanguageServer for Backend {
             };
 
         debug!("{:?}", &changed_options.get_lint_level());
+        let (config_path_changed, was_disabled) = {
+            let options = self.options.lock().await;
+            (
+                options.config_path != changed_options.config_path,
+                options.get_lint_level() == SyntheticRunLevel::Disable,
+            )
+        };
+        if config_path_changed {
+            *self.options.lock().await = changed_options.clone();
+            self.init_linter_config().await;
+        }
+        if changed_options.get_lint_level() == SyntheticRunLevel::Disable && self.pull_diagnostics()
+        {
+            *self.options.lock().await = changed_options;
+            // the client pulls the now empty diagnostics again
+            self.refresh_diagnostics().await;
+            return;
+        }
         if changed_options.get_lint_level() == SyntheticRunLevel::Disable {
             // clear all exists diagnostics when linter is disabled
             let opened_files = self.diagnostics_report_map.iter().map(|k| k.key().to_string());
@@ -170,10 +285,33 @@ impl LanguageServer for Backend {
             self.publish_all_diagnostics(&cleared_diagnostics).await;
         }
         *self.options.lock().await = changed_options;
+        if config_path_changed || was_disabled {
+            self.revalidate_documents().await;
+        }
     }
 
     async fn initialized(&self, _params: InitializedParams) {
         debug!("oxc initialized.");
+        if self.watch_config_files.load(Ordering::Relaxed) {
+            self.register_config_watcher().await;
+        }
+    }
+
+    /// Reload the linter when its config file changed, and lint the open documents again.
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let Some(config_path) = self.config_path().await else {
+            return;
+        };
+        let config_changed = params
+            .changes
+            .iter()
+            .any(|change| change.uri.to_file_path().is_ok_and(|path| path == config_path));
+        if !config_changed {
+            return;
+        }
+        debug!("config changed: {config_path:?}");
+        self.init_linter_config().await;
+        self.revalidate_documents().await;
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -197,6 +335,9 @@ impl LanguageServer for Backend {
     /// When the document changed, it may not be written to disk, so we should
     /// get the file context from the language client
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        if let Some(change) = params.content_changes.first() {
+            self.documents.insert(params.text_document.uri.to_string(), change.text.clone());
+        }
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -216,6 +357,8 @@ impl LanguageServer for Backend {
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        self.documents
+            .insert(params.text_document.uri.to_string(), params.text_document.text.clone());
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level <= SyntheticRunLevel::Disable {
             return;
@@ -230,48 +373,140 @@ impl LanguageServer for Backend {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri.to_string();
         self.diagnostics_report_map.remove(&uri);
+        self.diagnostics_result_ids.remove(&uri);
+        self.documents.remove(&uri);
     }
 
-    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+    /// Pull diagnostics, see
+    /// <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocument_diagnostic>
+    ///
+    /// The diagnostics of an open document are kept up to date by `did_open`, `did_change` and
+    /// `did_save`, so this only lints documents that have not been linted yet.
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
         let uri = params.text_document.uri;
+        let run_level = { self.options.lock().await.get_lint_level() };
+        if run_level <= SyntheticRunLevel::Disable || self.is_ignored(&uri).await {
+            return Ok(full_diagnostic_report(None, vec![]));
+        }
 
-        if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
-                .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
-            {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
-                        || "Fix this problem".into(),
-                        |s| format!("Fix this {s} problem"),
-                    );
-
-                let fixed_content = report.fixed_content.clone().unwrap();
-
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
-                    title,
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
-                    edit: Some(WorkspaceEdit {
-                        #[expect(clippy::disallowed_types)]
-                        changes: Some(std::collections::HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
-                        ..WorkspaceEdit::default()
-                    }),
-                    disabled: None,
-                    data: None,
-                    diagnostics: None,
-                    command: None,
-                })]));
+        if !self.diagnostics_report_map.contains_key(&uri.to_string()) {
+            self.handle_file_update(uri.clone(), None, None).await;
+        }
+
+        let key = uri.to_string();
+        let result_id = self.diagnostics_result_ids.get(&key).map(|id| id.value().clone());
+        Ok(document_diagnostic_report(result_id, params.previous_result_id.as_deref(), || {
+            self.diagnostics_report_map.get(&key).map_or_else(Vec::new, |reports| {
+                reports.iter().map(|report| report.diagnostic.clone()).collect()
+            })
+        }))
+    }
+
+    /// Pull the diagnostics of all the files in the workspace, see
+    /// <https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#workspace_diagnostic>
+    ///
+    /// Files which are not open are only linted again when their modification time changed. The
+    /// reports are sent in batches as `$/progress` notifications if the client accepts partial
+    /// results.
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> Result<WorkspaceDiagnosticReportResult> {
+        let run_level = { self.options.lock().await.get_lint_level() };
+        if run_level <= SyntheticRunLevel::Disable {
+            return Ok(WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport {
+                items: vec![],
+            }));
+        }
+
+        let previous_result_ids = params
+            .previous_result_ids
+            .into_iter()
+            .map(|previous| (previous.uri.to_string(), previous.value))
+            .collect::<FxHashMap<_, _>>();
+        let partial_result_token = params.partial_result_params.partial_result_token;
+        let mut items = vec![];
+        for uri in self.workspace_files().await {
+            if self.is_ignored(&uri).await {
+                continue;
+            }
+            let key = uri.to_string();
+            let previous_result_id = previous_result_ids.get(&key).map(String::as_str);
+            let report = if self.documents.contains_key(&key) {
+                self.open_document_report(uri, previous_result_id).await
+            } else {
+                self.closed_file_report(uri, previous_result_id).await
+            };
+            items.extend(report);
+
+            if items.len() >= WORKSPACE_DIAGNOSTIC_BATCH_SIZE {
+                if let Some(token) = &partial_result_token {
+                    self.send_workspace_diagnostics(token.clone(), mem::take(&mut items)).await;
+                }
             }
         }
+        // The final result is empty when the items are sent as partial results.
+        if let Some(token) = partial_result_token {
+            if !items.is_empty() {
+                self.send_workspace_diagnostics(token, mem::take(&mut items)).await;
+            }
+        }
+        Ok(WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport { items }))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        let Some(reports) = self.diagnostics_report_map.get(&uri.to_string()) else {
+            return Ok(None);
+        };
+        let actions = code_actions::code_actions(
+            &uri,
+            &reports,
+            params.range,
+            params.context.only.as_deref(),
+        );
+        Ok((!actions.is_empty()).then_some(actions))
+    }
 
-        Ok(None)
+    /// Only symbols local to the file can be renamed, the client shows the error of the request
+    /// for other symbols.
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let Some((path, source_text)) = self.document(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        let range = rename::prepare_rename(&path, &source_text, params.position)?;
+        Ok(range.map(PrepareRenameResponse::Range))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = params.text_document_position.text_document.uri;
+        let Some((path, source_text)) = self.document(&uri) else {
+            return Ok(None);
+        };
+        let edits = rename::rename(
+            &path,
+            &source_text,
+            params.text_document_position.position,
+            &params.new_name,
+        )?;
+        Ok(edits.map(|edits| WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(uri, edits)])),
+            ..WorkspaceEdit::default()
+        }))
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let Some((path, source_text)) = self.document(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(Some(inlay_hints::inlay_hints(&path, &source_text, params.range)))
     }
 }
 
@@ -339,44 +574,250 @@ impl Backend {
         .await;
     }
 
-    async fn init_linter_config(&self) {
+    /// The path of the config file, which may not exist.
+    async fn config_path(&self) -> Option<PathBuf> {
         let Some(Some(uri)) = self.root_uri.get() else {
-            return;
+            return None;
         };
-        let Ok(root_path) = uri.to_file_path() else {
+        let root_path = uri.to_file_path().ok()?;
+        let config_path = self.options.lock().await.get_config_path()?;
+        Some(root_path.join(config_path))
+    }
+
+    async fn init_linter_config(&self) {
+        let Some(config_path) = self.config_path().await else {
             return;
         };
-        let mut config_path = None;
-        let config = root_path.join(self.options.lock().await.get_config_path().unwrap());
-        if config.exists() {
-            config_path = Some(config);
-        }
-        if let Some(config_path) = config_path {
-            let mut linter = self.server_linter.write().await;
-            *linter = ServerLinter::new_with_linter(
-                LinterBuilder::from_oxlintrc(
-                    true,
-                    Oxlintrc::from_file(&config_path)
-                        .expect("should have initialized linter with new options"),
-                )
-                .with_fix(FixKind::SafeFix)
-                .build(),
-            );
+        let linter = if config_path.exists() {
+            match Oxlintrc::from_file(&config_path) {
+                Ok(oxlintrc) => ServerLinter::new_with_linter(
+                    LinterBuilder::from_oxlintrc(true, oxlintrc).with_fix(FixKind::SafeFix).build(),
+                ),
+                Err(err) => {
+                    // e.g. while the config is being edited, keep the previous linter
+                    error!("Can't load config {config_path:?}: {err}");
+                    return;
+                }
+            }
+        } else {
+            ServerLinter::new()
+        };
+        *self.server_linter.write().await = linter;
+    }
+
+    /// Ask the client to notify the server about changes of the config files, see
+    /// `did_change_watched_files`.
+    async fn register_config_watcher(&self) {
+        let config_path = { self.options.lock().await.config_path.clone() };
+        let watchers = [
+            "**/.eslintr{c,c.json}".to_string(),
+            "**/.oxlint{.json,rc.json,rc}".to_string(),
+            "**/oxlint{.json,rc.json}".to_string(),
+            format!("**/{config_path}"),
+        ]
+        .into_iter()
+        .map(|pattern| FileSystemWatcher { glob_pattern: GlobPattern::String(pattern), kind: None })
+        .collect();
+        let register_options =
+            serde_json::to_value(DidChangeWatchedFilesRegistrationOptions { watchers }).ok();
+        let registration = Registration {
+            id: "oxc-watch-config".into(),
+            method: "workspace/didChangeWatchedFiles".into(),
+            register_options,
+        };
+        if let Err(err) = self.client.register_capability(vec![registration]).await {
+            error!("Can't watch config files: {err}");
         }
     }
 
+    /// Lint the open documents again, e.g. after the config changed.
+    async fn revalidate_documents(&self) {
+        let run_level = { self.options.lock().await.get_lint_level() };
+        if run_level <= SyntheticRunLevel::Disable {
+            return;
+        }
+        if self.pull_diagnostics() {
+            // `diagnostic` lints the documents which are not in the map
+            self.diagnostics_report_map.clear();
+            self.closed_files.clear();
+            self.refresh_diagnostics().await;
+            return;
+        }
+        let documents = self
+            .documents
+            .iter()
+            .map(|document| (document.key().clone(), document.value().clone()))
+            .collect::<Vec<_>>();
+        for (uri, source_text) in documents {
+            let Ok(uri) = Url::from_str(&uri) else {
+                continue;
+            };
+            if self.is_ignored(&uri).await {
+                continue;
+            }
+            self.handle_file_update(uri, Some(source_text), None).await;
+        }
+    }
+
+    /// Ask the client to pull the diagnostics again.
+    async fn refresh_diagnostics(&self) {
+        if let Err(err) = self.client.send_request::<WorkspaceDiagnosticRefresh>(()).await {
+            error!("Can't refresh diagnostics: {err}");
+        }
+    }
+
+    /// The workspace diagnostic report of an open document, linting it if it is not linted yet.
+    async fn open_document_report(
+        &self,
+        uri: Url,
+        previous_result_id: Option<&str>,
+    ) -> Option<WorkspaceDocumentDiagnosticReport> {
+        let key = uri.to_string();
+        if !self.diagnostics_report_map.contains_key(&key) {
+            let (_, source_text) = self.document(&uri)?;
+            self.handle_file_update(uri.clone(), Some(source_text), None).await;
+        }
+        let result_id = self.diagnostics_result_ids.get(&key).map(|id| id.value().clone());
+        workspace_document_diagnostic_report(uri, result_id, previous_result_id, || {
+            self.diagnostics_report_map.get(&key).map_or_else(Vec::new, |reports| {
+                reports.iter().map(|report| report.diagnostic.clone()).collect()
+            })
+        })
+    }
+
+    /// The workspace diagnostic report of a file which is not open. It is linted again only if it
+    /// was modified since it was last linted, or if the client needs its diagnostics again.
+    async fn closed_file_report(
+        &self,
+        uri: Url,
+        previous_result_id: Option<&str>,
+    ) -> Option<WorkspaceDocumentDiagnosticReport> {
+        let key = uri.to_string();
+        let path = uri.to_file_path().ok()?;
+        let modified = fs::metadata(&path).and_then(|metadata| metadata.modified()).ok()?;
+        let unchanged_file = self
+            .closed_files
+            .get(&key)
+            .map(|file| file.value().clone())
+            .filter(|file| file.modified == modified);
+        if let Some(file) = &unchanged_file {
+            if previous_result_id == Some(file.result_id.as_str()) {
+                return workspace_document_diagnostic_report(
+                    uri,
+                    Some(file.result_id.clone()),
+                    previous_result_id,
+                    Vec::new,
+                );
+            }
+            // The client has no diagnostics to clear for this file.
+            if previous_result_id.is_none() && !file.has_diagnostics {
+                return None;
+            }
+        }
+
+        let source_text = fs::read_to_string(&path).ok()?;
+        let diagnostics = self
+            .server_linter
+            .read()
+            .await
+            .run_single(&uri, Some(source_text))?
+            .into_iter()
+            .map(|report| report.diagnostic)
+            .collect::<Vec<_>>();
+        let result_id = unchanged_file.map_or_else(
+            || self.next_result_id.fetch_add(1, Ordering::Relaxed).to_string(),
+            |file| file.result_id,
+        );
+        self.closed_files.insert(
+            key,
+            ClosedFile {
+                modified,
+                result_id: result_id.clone(),
+                has_diagnostics: !diagnostics.is_empty(),
+            },
+        );
+        workspace_document_diagnostic_report(uri, Some(result_id), previous_result_id, || {
+            diagnostics
+        })
+    }
+
+    async fn send_workspace_diagnostics(
+        &self,
+        token: ProgressToken,
+        items: Vec<WorkspaceDocumentDiagnosticReport>,
+    ) {
+        self.client
+            .send_notification::<WorkspaceDiagnosticProgress>(WorkspaceDiagnosticProgressParams {
+                token,
+                value: WorkspaceDiagnosticReportPartialResult { items },
+            })
+            .await;
+    }
+
+    /// The files in the workspace which can be linted, skipping the files ignored by
+    /// `.gitignore`.
+    async fn workspace_files(&self) -> Vec<Url> {
+        let Some(Some(root_uri)) = self.root_uri.get() else {
+            return vec![];
+        };
+        let Ok(root_path) = root_uri.to_file_path() else {
+            return vec![];
+        };
+        // Walking a large workspace would block the other requests.
+        tokio::task::spawn_blocking(move || {
+            ignore::WalkBuilder::new(root_path)
+                .build()
+                .flatten()
+                .filter(|entry| {
+                    entry.file_type().is_some_and(|file_type| file_type.is_file())
+                        && IsolatedLintHandler::should_lint_path(entry.path())
+                })
+                .filter_map(|entry| Url::from_file_path(entry.path()).ok())
+                .collect::<Vec<_>>()
+        })
+        .await
+        .unwrap_or_default()
+    }
+
+    /// The path and text of the document at `uri`, read from disk if it is not open.
+    fn document(&self, uri: &Url) -> Option<(PathBuf, String)> {
+        let path = uri.to_file_path().ok()?;
+        let source_text = match self.documents.get(&uri.to_string()) {
+            Some(text) => text.value().clone(),
+            None => fs::read_to_string(&path).ok()?,
+        };
+        Some((path, source_text))
+    }
+
+    fn pull_diagnostics(&self) -> bool {
+        self.pull_diagnostics.load(Ordering::Relaxed)
+    }
+
     async fn handle_file_update(&self, uri: Url, content: Option<String>, version: Option<i32>) {
         if let Some(Some(_root_uri)) = self.root_uri.get() {
             if let Some(diagnostics) = self.server_linter.read().await.run_single(&uri, content) {
-                self.client
-                    .publish_diagnostics(
-                        uri.clone(),
-                        diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
-                        version,
-                    )
-                    .await;
+                if !self.pull_diagnostics() {
+                    self.client
+                        .publish_diagnostics(
+                            uri.clone(),
+                            diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                            version,
+                        )
+                        .await;
+                }
 
-                self.diagnostics_report_map.insert(uri.to_string(), diagnostics);
+                let key = uri.to_string();
+                let unchanged = self.diagnostics_report_map.get(&key).is_some_and(|reports| {
+                    reports
+                        .iter()
+                        .map(|r| &r.diagnostic)
+                        .eq(diagnostics.iter().map(|r| &r.diagnostic))
+                });
+                if !unchanged || !self.diagnostics_result_ids.contains_key(&key) {
+                    let result_id = self.next_result_id.fetch_add(1, Ordering::Relaxed);
+                    self.diagnostics_result_ids.insert(key.clone(), result_id.to_string());
+                }
+                self.diagnostics_report_map.insert(key, diagnostics);
             }
         }
     }
@@ -410,6 +851,74 @@ impl Backend {
     }
 }
 
+/// An unchanged report if the client already has the diagnostics of `result_id`, i.e. it sent
+/// the same `previous_result_id`, otherwise a full report of `items`.
+fn document_diagnostic_report(
+    result_id: Option<String>,
+    previous_result_id: Option<&str>,
+    items: impl FnOnce() -> Vec<Diagnostic>,
+) -> DocumentDiagnosticReportResult {
+    match result_id {
+        Some(result_id) if previous_result_id == Some(result_id.as_str()) => {
+            DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Unchanged(
+                RelatedUnchangedDocumentDiagnosticReport {
+                    related_documents: None,
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                },
+            ))
+        }
+        result_id => full_diagnostic_report(result_id, items()),
+    }
+}
+
+/// Like [`document_diagnostic_report`], for `workspace/diagnostic`. Files without diagnostics are
+/// not reported if the client has no diagnostics of them.
+fn workspace_document_diagnostic_report(
+    uri: Url,
+    result_id: Option<String>,
+    previous_result_id: Option<&str>,
+    items: impl FnOnce() -> Vec<Diagnostic>,
+) -> Option<WorkspaceDocumentDiagnosticReport> {
+    match result_id {
+        Some(result_id) if previous_result_id == Some(result_id.as_str()) => {
+            Some(WorkspaceDocumentDiagnosticReport::Unchanged(
+                WorkspaceUnchangedDocumentDiagnosticReport {
+                    uri,
+                    version: None,
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                },
+            ))
+        }
+        result_id => {
+            let items = items();
+            if items.is_empty() && previous_result_id.is_none() {
+                return None;
+            }
+            Some(WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+                uri,
+                version: None,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport { result_id, items },
+            }))
+        }
+    }
+}
+
+fn full_diagnostic_report(
+    result_id: Option<String>,
+    items: Vec<Diagnostic>,
+) -> DocumentDiagnosticReportResult {
+    DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+        RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport { result_id, items },
+        },
+    ))
+}
+
 #[tokio::main]
 async fn main() {
     env_logger::init();
@@ -425,6 +934,12 @@ async fn main() {
         root_uri: OnceCell::new(),
         server_linter: RwLock::new(server_linter),
         diagnostics_report_map,
+        diagnostics_result_ids: DashMap::new(),
+        next_result_id: AtomicU64::new(0),
+        closed_files: DashMap::new(),
+        documents: DashMap::new(),
+        pull_diagnostics: AtomicBool::new(false),
+        watch_config_files: AtomicBool::new(false),
         options: Mutex::new(Options::default()),
         gitignore_glob: Mutex::new(vec![]),
     })
@@ -432,3 +947,91 @@ async fn main() {
 
     Server::new(stdin, stdout, socket).serve(service).await;
 }
+
+#[cfg(test)]
+mod test {
+    use tower_lsp::lsp_types::{
+        Diagnostic, DocumentDiagnosticReport, DocumentDiagnosticReportResult, Range, Url,
+        WorkspaceDocumentDiagnosticReport,
+    };
+
+    use super::{document_diagnostic_report, workspace_document_diagnostic_report};
+
+    fn diagnostics() -> Vec<Diagnostic> {
+        vec![Diagnostic::new_simple(Range::default(), "problem".into())]
+    }
+
+    #[test]
+    fn unchanged_report() {
+        let report = document_diagnostic_report(Some("1".into()), Some("1"), || {
+            panic!("the diagnostics of an unchanged report are not collected")
+        });
+        let DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Unchanged(report)) =
+            report
+        else {
+            panic!("expected an unchanged report, got {report:?}");
+        };
+        assert_eq!(report.unchanged_document_diagnostic_report.result_id, "1");
+    }
+
+    #[test]
+    fn full_report() {
+        for (result_id, previous_result_id) in
+            [(Some("2"), Some("1")), (Some("1"), None), (None, None), (None, Some("1"))]
+        {
+            let report = document_diagnostic_report(
+                result_id.map(Into::into),
+                previous_result_id,
+                diagnostics,
+            );
+            let DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(report)) =
+                report
+            else {
+                panic!("expected a full report, got {report:?}");
+            };
+            let report = report.full_document_diagnostic_report;
+            assert_eq!(report.result_id.as_deref(), result_id);
+            assert_eq!(report.items, diagnostics());
+        }
+    }
+
+    #[test]
+    fn workspace_reports() {
+        let uri = Url::parse("file:///a.js").unwrap();
+
+        let report =
+            workspace_document_diagnostic_report(uri.clone(), Some("1".into()), Some("1"), || {
+                panic!("the diagnostics of an unchanged report are not collected")
+            });
+        assert!(matches!(report, Some(WorkspaceDocumentDiagnosticReport::Unchanged(_))));
+
+        let report = workspace_document_diagnostic_report(
+            uri.clone(),
+            Some("2".into()),
+            Some("1"),
+            diagnostics,
+        );
+        let Some(WorkspaceDocumentDiagnosticReport::Full(report)) = report else {
+            panic!("expected a full report, got {report:?}");
+        };
+        assert_eq!(report.full_document_diagnostic_report.result_id.as_deref(), Some("2"));
+        assert_eq!(report.full_document_diagnostic_report.items, diagnostics());
+
+        // The diagnostics the client has are cleared.
+        let report = workspace_document_diagnostic_report(
+            uri.clone(),
+            Some("2".into()),
+            Some("1"),
+            Vec::new,
+        );
+        let Some(WorkspaceDocumentDiagnosticReport::Full(report)) = report else {
+            panic!("expected a full report, got {report:?}");
+        };
+        assert!(report.full_document_diagnostic_report.items.is_empty());
+
+        // Files without diagnostics are not reported to clients which have none of them.
+        assert!(
+            workspace_document_diagnostic_report(uri, Some("1".into()), None, Vec::new).is_none()
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_linter::loader::{JavaScriptSource, Loader};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::{RenameError, Semantic, SemanticBuilder, SymbolRename};
+use oxc_span::Span;
+use ropey::Rope;
+use tower_lsp::{
+    jsonrpc::{Error, Result},
+    lsp_types::{Position, Range, TextEdit},
+};
+
+use crate::linter::offset_to_position;
+
+/// The range of the identifier to rename at `position`, for `textDocument/prepareRename`.
+///
+/// # Errors
+/// If the symbol at `position` is visible to other files.
+pub fn prepare_rename(path: &Path, source_text: &str, position: Position) -> Result<Option<Range>> {
+    with_symbol_at(path, source_text, position, |semantic, start, offset| {
+        let Some((symbol_id, span)) = SymbolRename::symbol_at(semantic, offset) else {
+            return Ok(None);
+        };
+        if SymbolRename::is_cross_file(semantic, symbol_id) {
+            return Err(rename_error(&RenameError::CrossFile));
+        }
+        Ok(span_to_range(span, start, source_text))
+    })
+}
+
+/// The edits renaming the symbol at `position` to `new_name`, for `textDocument/rename`.
+///
+/// # Errors
+/// If the symbol cannot be renamed, see [`RenameError`].
+pub fn rename(
+    path: &Path,
+    source_text: &str,
+    position: Position,
+    new_name: &str,
+) -> Result<Option<Vec<TextEdit>>> {
+    with_symbol_at(path, source_text, position, |semantic, start, offset| {
+        let Some((symbol_id, _)) = SymbolRename::symbol_at(semantic, offset) else {
+            return Ok(None);
+        };
+        let rename =
+            SymbolRename::new(semantic, symbol_id, new_name).map_err(|err| rename_error(&err))?;
+        let edits = rename
+            .edits
+            .into_iter()
+            .filter_map(|edit| {
+                let range = span_to_range(edit.span, start, source_text)?;
+                Some(TextEdit { range, new_text: edit.new_text })
+            })
+            .collect();
+        Ok(Some(edits))
+    })
+}
+
+/// Build the semantic of the JavaScript source containing `position`, and call `f` with it, the
+/// start offset of the source in the file and the offset of `position` in the source.
+#[allow(clippy::cast_possible_truncation)]
+fn with_symbol_at<T>(
+    path: &Path,
+    source_text: &str,
+    position: Position,
+    f: impl FnOnce(&Semantic<'_>, u32, u32) -> Result<Option<T>>,
+) -> Result<Option<T>> {
+    if !Loader::can_load(path) {
+        return Ok(None);
+    }
+    let Some(offset) = position_to_offset(position, source_text) else {
+        return Ok(None);
+    };
+    let Ok(sources) = Loader.load_str(path, source_text) else {
+        return Ok(None);
+    };
+    // Embedded sources, e.g. `<script>` blocks of `.vue` files, are renamed independently.
+    let Some(JavaScriptSource { source_text: javascript_source_text, source_type, start, .. }) =
+        sources.into_iter().find(|source| {
+            source.start <= offset && offset <= source.start + source.source_text.len() as u32
+        })
+    else {
+        return Ok(None);
+    };
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, javascript_source_text, source_type)
+        .with_options(ParseOptions {
+            allow_return_outside_function: true,
+            ..ParseOptions::default()
+        })
+        .parse();
+    if !ret.errors.is_empty() {
+        return Ok(None);
+    }
+    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+    f(&semantic, start, offset - start)
+}
+
+fn rename_error(err: &RenameError) -> Error {
+    let message = match err {
+        RenameError::InvalidName => "The new name is not a valid identifier.",
+        RenameError::CrossFile => {
+            "Renaming symbols which are imported, exported or global is not supported yet."
+        }
+        RenameError::Conflict { .. } => "The new name conflicts with another binding.",
+    };
+    Error::invalid_params(message)
+}
+
+fn span_to_range(span: Span, start: u32, source_text: &str) -> Option<Range> {
+    Some(Range {
+        start: offset_to_position((span.start + start) as usize, source_text)?,
+        end: offset_to_position((span.end + start) as usize, source_text)?,
+    })
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn position_to_offset(position: Position, source_text: &str) -> Option<u32> {
+    let rope = Rope::from_str(source_text);
+    // `offset_to_position` uses char columns, so do the same here.
+    let offset = rope.try_line_to_char(position.line as usize).ok()? + position.character as usize;
+    rope.try_char_to_byte(offset).ok().map(|offset| offset as u32)
+}
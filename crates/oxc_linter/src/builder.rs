@@ -1,6 +1,8 @@
 use std::{
     cell::{Ref, RefCell},
     fmt,
+    path::PathBuf,
+    sync::Arc,
 };
 
 use oxc_diagnostics::{Error, OxcDiagnostic};
@@ -8,10 +10,16 @@ use oxc_span::CompactStr;
 use rustc_hash::FxHashSet;
 
 use crate::{
-    config::{ESLintRule, LintPlugins, OxlintRules},
+    config::{
+        ESLintRule, InvalidRuleOptions, LintPlugins, OxlintCategories, OxlintFilters,
+        OxlintOverrides, OxlintRules,
+    },
+    custom_rule::custom_rules,
+    external_plugin::{ExternalPluginRules, ExternalRule},
     rules::RULES,
-    AllowWarnDeny, FixKind, FrameworkFlags, LintConfig, LintFilter, LintFilterKind, LintOptions,
-    Linter, Oxlintrc, RuleCategory, RuleEnum, RuleWithSeverity,
+    AllowWarnDeny, ConfigOverride, ExternalPlugin, FixCategory, FixKind, FrameworkFlags,
+    LintConfig, LintFilter, LintFilterKind, LintOptions, Linter, NestedConfig, Oxlintrc,
+    RuleCategory, RuleEnum, RuleWithSeverity,
 };
 
 #[must_use = "You dropped your builder without building a Linter! Did you mean to call .build()?"]
@@ -20,6 +28,15 @@ pub struct LinterBuilder {
     options: LintOptions,
     config: LintConfig,
     cache: RulesCache,
+    external_plugins: Vec<Arc<dyn ExternalPlugin>>,
+    /// Configured rules which are not built in, resolved against `external_plugins` when building.
+    unknown_rules: Vec<ESLintRule>,
+    /// Configurations of nested `.oxlintrc.json` files and the directories they apply to,
+    /// resolved when building.
+    nested_configs: Vec<(PathBuf, Oxlintrc)>,
+    /// Overrides of the rules and configuration of the files matching globs, resolved when
+    /// building.
+    overrides: OxlintOverrides,
 }
 
 impl Default for LinterBuilder {
@@ -39,7 +56,16 @@ impl LinterBuilder {
         let rules = FxHashSet::default();
         let cache = RulesCache::new(config.plugins);
 
-        Self { rules, options, config, cache }
+        Self {
+            rules,
+            options,
+            config,
+            cache,
+            external_plugins: vec![],
+            unknown_rules: vec![],
+            nested_configs: vec![],
+            overrides: OxlintOverrides::default(),
+        }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -53,11 +79,17 @@ impl LinterBuilder {
         Self {
             rules: RULES
                 .iter()
-                .map(|rule| RuleWithSeverity { rule: rule.clone(), severity: AllowWarnDeny::Warn })
+                .cloned()
+                .chain(custom_rules())
+                .map(|rule| RuleWithSeverity { rule, severity: AllowWarnDeny::Warn })
                 .collect(),
             options,
             config,
             cache,
+            external_plugins: vec![],
+            unknown_rules: vec![],
+            nested_configs: vec![],
+            overrides: OxlintOverrides::default(),
         }
     }
 
@@ -83,40 +115,91 @@ impl LinterBuilder {
     /// match any recognized rules.
     pub fn from_oxlintrc(start_empty: bool, oxlintrc: Oxlintrc) -> Self {
         // TODO: monorepo config merging, plugin-based extends, etc.
-        let Oxlintrc { plugins, settings, env, globals, categories, rules: mut oxlintrc_rules } =
-            oxlintrc;
-
-        let config = LintConfig { plugins, settings, env, globals };
+        let Oxlintrc {
+            extends: _,
+            plugins,
+            settings,
+            env,
+            globals,
+            categories,
+            filters,
+            rules: oxlintrc_rules,
+            parser_overrides,
+            overrides,
+        } = oxlintrc;
+
+        let config = LintConfig { plugins, settings, env, globals, parser_overrides };
         let options = LintOptions::default();
         let rules =
             if start_empty { FxHashSet::default() } else { Self::warn_correctness(plugins) };
         let cache = RulesCache::new(config.plugins);
-        let mut builder = Self { rules, options, config, cache };
+        let builder = Self {
+            rules,
+            options,
+            config,
+            cache,
+            external_plugins: vec![],
+            unknown_rules: vec![],
+            nested_configs: vec![],
+            overrides,
+        };
 
+        builder.with_oxlintrc_rules(&categories, &filters, oxlintrc_rules)
+    }
+
+    /// Apply the categories, filters and rules of an [`Oxlintrc`] on top of the configured rules.
+    fn with_oxlintrc_rules(
+        mut self,
+        categories: &OxlintCategories,
+        filters: &OxlintFilters,
+        mut oxlintrc_rules: OxlintRules,
+    ) -> Self {
         if !categories.is_empty() {
-            builder = builder.with_filters(categories.filters());
+            self = self.with_filters(categories.filters());
         }
 
-        {
-            let all_rules = builder.cache.borrow();
-            oxlintrc_rules.override_rules(&mut builder.rules, all_rules.as_slice());
+        if !filters.is_empty() {
+            self = self.with_filters(filters.filters());
         }
 
-        #[expect(clippy::print_stderr)]
-        if !oxlintrc_rules.unknown_rules.is_empty() {
-            let rules = oxlintrc_rules
-                .unknown_rules
-                .iter()
-                .map(|r| r.full_name())
-                .collect::<Vec<_>>()
-                .join("\n");
-            let error = Error::from(OxcDiagnostic::warn(format!(
-                "The following rules do not match the currently supported rules:\n{rules}"
-            )));
-            eprintln!("{error:?}");
+        {
+            let all_rules = self.cache.borrow();
+            oxlintrc_rules.override_rules(&mut self.rules, all_rules.as_slice());
         }
 
-        builder
+        Self::warn_invalid_rule_options(&oxlintrc_rules.invalid_options);
+        self.unknown_rules.append(&mut oxlintrc_rules.unknown_rules);
+
+        self
+    }
+
+    /// Lint the files in `directory` and its subdirectories with a nested `oxlintrc`, merged on
+    /// top of the configuration of the closest parent directory which has a nested configuration,
+    /// or of this builder.
+    ///
+    /// The rules configured in `oxlintrc` replace the parent's, as do its categories and filters.
+    /// Its plugins are enabled in addition to the parent's, and its `env` and `globals` are merged
+    /// key by key. Settings, parser overrides and overrides are only read from the root
+    /// configuration, and rules of external plugins are not supported in nested configurations.
+    ///
+    /// `directory` must be in the same form, relative or absolute, as the paths which are linted.
+    pub fn with_nested_config(mut self, directory: PathBuf, oxlintrc: Oxlintrc) -> Self {
+        self.nested_configs.push((directory, oxlintrc));
+        self
+    }
+
+    /// Add a plugin of rules which are not built in. Rules of the plugin configured in the
+    /// [`Oxlintrc`] this builder was created from are enabled when building the [`Linter`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let linter = LinterBuilder::from_oxlintrc(true, oxlintrc)
+    ///     .with_external_plugin(Arc::new(MyPlugin))
+    ///     .build();
+    /// ```
+    pub fn with_external_plugin(mut self, plugin: Arc<dyn ExternalPlugin>) -> Self {
+        self.external_plugins.push(plugin);
+        self
     }
 
     #[inline]
@@ -137,6 +220,30 @@ impl LinterBuilder {
         self
     }
 
+    /// Only apply the fixes of rules in these categories. Defaults to all categories.
+    #[inline]
+    pub fn with_fix_category(mut self, category: FixCategory) -> Self {
+        self.options.fix_category = category;
+        self
+    }
+
+    /// Attach the fixes which are not applied to their diagnostics, so that reporters can
+    /// suggest them. See [`OxcDiagnostic::with_fix`](oxc_diagnostics::OxcDiagnostic::with_fix).
+    #[inline]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.options.report_fixes = yes;
+        self
+    }
+
+    /// Report disable directives, like `// eslint-disable-next-line no-debugger`, which do not
+    /// suppress any diagnostic. Directives are not reported if `severity` is
+    /// [`AllowWarnDeny::Allow`].
+    #[inline]
+    pub fn with_report_unused_directives(mut self, severity: AllowWarnDeny) -> Self {
+        self.options.report_unused_directives = severity.is_warn_deny().then_some(severity);
+        self
+    }
+
     /// Configure what linter plugins are enabled.
     ///
     /// Turning on a plugin will not automatically enable any of its rules. You must do this
@@ -202,6 +309,7 @@ impl LinterBuilder {
                         self.upsert_where(severity, |r| r.name() == name_or_category);
                     }
                 }
+                LintFilterKind::Query(query) => self.upsert_where(severity, |r| query.matches(r)),
             },
             AllowWarnDeny::Allow => match filter {
                 LintFilterKind::Category(category) => {
@@ -217,6 +325,9 @@ impl LinterBuilder {
                         self.rules.retain(|rule| rule.name() != name_or_category);
                     }
                 }
+                LintFilterKind::Query(query) => {
+                    self.rules.retain(|rule| !query.matches(&rule.rule));
+                }
             },
         }
 
@@ -244,10 +355,12 @@ impl LinterBuilder {
     }
 
     #[must_use]
-    pub fn build(self) -> Linter {
+    pub fn build(mut self) -> Linter {
         // When a plugin gets disabled before build(), rules for that plugin aren't removed until
         // with_filters() gets called. If the user never calls it, those now-undesired rules need
         // to be taken out.
+        let nested_configs = self.resolve_nested_configs();
+        let overrides = self.resolve_overrides();
         let plugins = self.plugins();
         let mut rules = if self.cache.is_stale() {
             self.rules.into_iter().filter(|r| plugins.contains(r.plugin_name().into())).collect()
@@ -255,20 +368,194 @@ impl LinterBuilder {
             self.rules.into_iter().collect::<Vec<_>>()
         };
         rules.sort_unstable_by_key(|r| r.id());
-        Linter::new(rules, self.options, self.config)
+        let external_plugins =
+            Self::resolve_external_rules(self.external_plugins, self.unknown_rules);
+        Linter::new(rules, self.options, self.config, nested_configs, overrides, external_plugins)
+    }
+
+    /// Resolve the rules of each override into the rules it enables or configures and the rules
+    /// it turns off, to be applied on top of the rules of the files it matches.
+    fn resolve_overrides(&mut self) -> Vec<ConfigOverride> {
+        let overrides = std::mem::take(&mut self.overrides);
+        if overrides.is_empty() {
+            return vec![];
+        }
+
+        let all_rules = self.cache.borrow();
+        let mut unknown_rules = vec![];
+        let mut invalid_options = vec![];
+        let overrides = overrides
+            .into_iter()
+            .map(|config_override| {
+                let globs = config_override.globs().clone();
+                let mut oxlintrc_rules = config_override.rules;
+
+                // Turning off a rule removes it from the rules it is applied to, so apply the
+                // rules to all rules to find the ones which are turned off.
+                let mut disabled_rules = all_rules
+                    .iter()
+                    .map(|rule| RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Warn))
+                    .collect::<FxHashSet<_>>();
+                oxlintrc_rules.clone().override_rules(&mut disabled_rules, all_rules.as_slice());
+                let disabled_rules = all_rules
+                    .iter()
+                    .filter(|rule| !disabled_rules.contains(*rule))
+                    .map(RuleEnum::id)
+                    .collect();
+
+                let mut rules = FxHashSet::default();
+                oxlintrc_rules.override_rules(&mut rules, all_rules.as_slice());
+                unknown_rules.append(&mut oxlintrc_rules.unknown_rules);
+                invalid_options.append(&mut oxlintrc_rules.invalid_options);
+
+                ConfigOverride {
+                    globs,
+                    rules: rules.into_iter().collect(),
+                    disabled_rules,
+                    env: config_override.env,
+                    globals: config_override.globals,
+                }
+            })
+            .collect();
+
+        Self::warn_unknown_rules(&unknown_rules);
+        Self::warn_invalid_rule_options(&invalid_options);
+        overrides
+    }
+
+    /// Resolve the nested configurations, each on top of the one of its closest parent directory.
+    fn resolve_nested_configs(&mut self) -> Vec<NestedConfig> {
+        let mut nested_configs = std::mem::take(&mut self.nested_configs);
+        // Resolve parent directories before their subdirectories.
+        nested_configs.sort_by_key(|(directory, _)| directory.components().count());
+
+        let mut resolved: Vec<(PathBuf, FxHashSet<RuleWithSeverity>, LintConfig)> = vec![];
+        let mut unknown_rules = vec![];
+        for (directory, oxlintrc) in nested_configs {
+            let (rules, mut config) = resolved
+                .iter()
+                .rev()
+                .find(|(parent, _, _)| directory.starts_with(parent))
+                .map_or_else(
+                    || (self.rules.clone(), self.config.clone()),
+                    |(_, rules, config)| (rules.clone(), config.clone()),
+                );
+
+            let Oxlintrc {
+                plugins, categories, filters, rules: oxlintrc_rules, env, globals, ..
+            } = oxlintrc;
+            config.plugins |= plugins;
+            config.env.extend(env);
+            config.globals.extend(globals);
+            let cache = RulesCache::new(config.plugins);
+            let mut builder = Self {
+                rules,
+                options: self.options,
+                config,
+                cache,
+                external_plugins: vec![],
+                unknown_rules: vec![],
+                nested_configs: vec![],
+                overrides: OxlintOverrides::default(),
+            }
+            .with_oxlintrc_rules(&categories, &filters, oxlintrc_rules);
+            unknown_rules.append(&mut builder.unknown_rules);
+
+            let plugins = builder.plugins();
+            builder.rules.retain(|rule| plugins.contains(rule.plugin_name().into()));
+            resolved.push((directory, builder.rules, builder.config));
+        }
+
+        Self::warn_unknown_rules(&unknown_rules);
+
+        resolved
+            .into_iter()
+            .map(|(directory, rules, config)| {
+                let mut rules = rules.into_iter().collect::<Vec<_>>();
+                rules.sort_unstable_by_key(|r| r.id());
+                NestedConfig { directory, rules, config: Arc::new(config) }
+            })
+            .collect()
+    }
+
+    /// Match configured rules which are not built in with the rules of external plugins, warning
+    /// about the rules which match neither.
+    fn resolve_external_rules(
+        plugins: Vec<Arc<dyn ExternalPlugin>>,
+        mut unknown_rules: Vec<ESLintRule>,
+    ) -> Vec<ExternalPluginRules> {
+        let mut external_plugins = vec![];
+        for plugin in plugins {
+            let mut rules = vec![];
+            unknown_rules.retain(|rule| {
+                let full_name = rule.full_name();
+                let Some(rule_name) = full_name
+                    .strip_prefix(plugin.name())
+                    .and_then(|rule_name| rule_name.strip_prefix('/'))
+                    .filter(|rule_name| plugin.has_rule(rule_name))
+                else {
+                    return true;
+                };
+                if rule.severity.is_warn_deny() {
+                    let options = match rule.config.clone() {
+                        Some(serde_json::Value::Array(options)) => options,
+                        Some(option) => vec![option],
+                        None => vec![],
+                    };
+                    rules.push(ExternalRule {
+                        name: rule_name.to_string(),
+                        options,
+                        severity: rule.severity,
+                    });
+                }
+                false
+            });
+            if !rules.is_empty() {
+                external_plugins.push(ExternalPluginRules { plugin, rules });
+            }
+        }
+
+        Self::warn_unknown_rules(&unknown_rules);
+
+        external_plugins
+    }
+
+    fn warn_unknown_rules(unknown_rules: &[ESLintRule]) {
+        #[expect(clippy::print_stderr)]
+        if !unknown_rules.is_empty() {
+            let rules =
+                unknown_rules.iter().map(ESLintRule::full_name).collect::<Vec<_>>().join("\n");
+            let error = Error::from(OxcDiagnostic::warn(format!(
+                "The following rules do not match the currently supported rules:\n{rules}"
+            )));
+            eprintln!("{error:?}");
+        }
+    }
+
+    fn warn_invalid_rule_options(invalid_options: &[InvalidRuleOptions]) {
+        #[expect(clippy::print_stderr)]
+        for InvalidRuleOptions { rule_name, errors } in invalid_options {
+            let error = Error::from(
+                OxcDiagnostic::warn(format!("Invalid options for rule `{rule_name}`"))
+                    .with_help(errors.join("\n")),
+            );
+            eprintln!("{error:?}");
+        }
     }
 
     /// Warn for all correctness rules in the given set of plugins.
     fn warn_correctness(plugins: LintPlugins) -> FxHashSet<RuleWithSeverity> {
         RULES
             .iter()
+            .cloned()
+            .chain(custom_rules())
             .filter(|rule| {
                 // NOTE: this logic means there's no way to disable ESLint
                 // correctness rules. I think that's fine for now.
                 rule.category() == RuleCategory::Correctness
                     && plugins.contains(LintPlugins::from(rule.plugin_name()))
             })
-            .map(|rule| RuleWithSeverity { rule: rule.clone(), severity: AllowWarnDeny::Warn })
+            .map(|rule| RuleWithSeverity { rule, severity: AllowWarnDeny::Warn })
             .collect()
     }
 
@@ -422,12 +709,13 @@ impl RulesCache {
         );
 
         let mut all_rules: Vec<_> = if self.plugins.is_all() {
-            RULES.clone()
+            RULES.iter().cloned().chain(custom_rules()).collect()
         } else {
             RULES
                 .iter()
-                .filter(|rule| self.plugins.contains(LintPlugins::from(rule.plugin_name())))
                 .cloned()
+                .chain(custom_rules())
+                .filter(|rule| self.plugins.contains(LintPlugins::from(rule.plugin_name())))
                 .collect()
         };
         all_rules.sort_unstable(); // TODO: do we need to sort? is is already sorted?
@@ -561,6 +849,35 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_filter_query() {
+        let builder = LinterBuilder::empty().with_filters([LintFilter::new(
+            AllowWarnDeny::Deny,
+            "correctness & fixable & !plugin:typescript",
+        )
+        .unwrap()]);
+        assert!(!builder.rules.is_empty());
+        for rule in &builder.rules {
+            let plugin = rule.plugin_name();
+            let name = rule.name();
+            assert_eq!(rule.severity, AllowWarnDeny::Deny);
+            assert_eq!(rule.category(), RuleCategory::Correctness, "{plugin}/{name}");
+            assert!(rule.fix().has_fix(), "{plugin}/{name} should be fixable");
+            assert_ne!(LintPlugins::from(plugin), LintPlugins::TYPESCRIPT, "{plugin}/{name}");
+        }
+
+        // allowing a query removes exactly the rules it matches
+        let builder = LinterBuilder::default().with_filters([LintFilter::new(
+            AllowWarnDeny::Allow,
+            "plugin:typescript",
+        )
+        .unwrap()]);
+        assert!(!builder.rules.is_empty());
+        for rule in &builder.rules {
+            assert_ne!(LintPlugins::from(rule.plugin_name()), LintPlugins::TYPESCRIPT);
+        }
+    }
+
     #[test]
     fn test_rules_after_plugin_added() {
         let builder = LinterBuilder::default();
@@ -683,4 +1000,114 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_config_filters() {
+        // `allow` filters are applied last, regardless of where they appear in the config
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"
+        {
+            "filters": {
+                "plugin:typescript": "allow",
+                "correctness | fixable": "warn",
+                "correctness & fixable": "deny"
+            },
+            "rules": {
+                "@typescript-eslint/ban-types": "error"
+            }
+        }
+        "#,
+        )
+        .unwrap();
+        let builder = LinterBuilder::from_oxlintrc(true, oxlintrc);
+        assert!(!builder.rules.is_empty());
+        for rule in &builder.rules {
+            let name = rule.name();
+            let plugin = rule.plugin_name();
+            if LintPlugins::from(plugin) == LintPlugins::TYPESCRIPT {
+                // individual rules still take precedence over filters
+                assert_eq!(name, "ban-types", "{plugin}/{name} should be allowed");
+                continue;
+            }
+            let expected = if rule.category() == RuleCategory::Correctness && rule.fix().has_fix() {
+                AllowWarnDeny::Deny
+            } else {
+                AllowWarnDeny::Warn
+            };
+            assert_eq!(rule.severity, expected, "{plugin}/{name}");
+        }
+
+        let invalid =
+            serde_json::from_str::<Oxlintrc>(r#"{ "filters": { "plugin:foo": "warn" } }"#);
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_nested_config() {
+        use std::path::Path;
+
+        let root: Oxlintrc =
+            serde_json::from_str(r#"{ "rules": { "no-debugger": "warn" } }"#).unwrap();
+        let nested: Oxlintrc =
+            serde_json::from_str(r#"{ "rules": { "no-debugger": "error", "eqeqeq": "warn" } }"#)
+                .unwrap();
+        let deeper: Oxlintrc =
+            serde_json::from_str(r#"{ "rules": { "no-debugger": "off" } }"#).unwrap();
+        let linter = LinterBuilder::from_oxlintrc(true, root)
+            .with_nested_config(PathBuf::from("a/b"), deeper)
+            .with_nested_config(PathBuf::from("a"), nested)
+            .build();
+        let severity = |path: &str, name: &str| {
+            let (rules, _) = linter.resolve(Path::new(path));
+            rules.iter().find(|rule| rule.name() == name).map(|rule| rule.severity)
+        };
+
+        assert_eq!(severity("index.js", "no-debugger"), Some(AllowWarnDeny::Warn));
+        assert_eq!(severity("index.js", "eqeqeq"), None);
+        assert_eq!(severity("ab/index.js", "no-debugger"), Some(AllowWarnDeny::Warn));
+        assert_eq!(severity("a/index.js", "no-debugger"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity("a/index.js", "eqeqeq"), Some(AllowWarnDeny::Warn));
+        // the closest configuration wins, and inherits the rules it does not configure
+        assert_eq!(severity("a/b/c/index.js", "no-debugger"), None);
+        assert_eq!(severity("a/b/c/index.js", "eqeqeq"), Some(AllowWarnDeny::Warn));
+    }
+
+    #[test]
+    fn test_overrides() {
+        use std::path::Path;
+
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"{
+                "rules": { "no-debugger": "warn", "no-console": "error" },
+                "overrides": [
+                    { "files": ["*.test.js"], "rules": { "no-console": "off", "eqeqeq": "warn" } },
+                    { "files": ["scripts/**"], "rules": { "no-debugger": "error" }, "env": { "node": true } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let linter = LinterBuilder::from_oxlintrc(true, oxlintrc).build();
+        let resolve = |path: &str| {
+            let path = Path::new(path);
+            let (rules, config) = linter.resolve(path);
+            linter
+                .apply_overrides(path, rules, config)
+                .unwrap_or_else(|| (rules.to_vec(), Arc::clone(config)))
+        };
+        let severity = |path: &str, name: &str| {
+            let (rules, _) = resolve(path);
+            rules.iter().find(|rule| rule.name() == name).map(|rule| rule.severity)
+        };
+
+        assert_eq!(severity("index.js", "no-console"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity("index.js", "eqeqeq"), None);
+        assert_eq!(severity("index.test.js", "no-console"), None);
+        assert_eq!(severity("index.test.js", "eqeqeq"), Some(AllowWarnDeny::Warn));
+        assert_eq!(severity("index.test.js", "no-debugger"), Some(AllowWarnDeny::Warn));
+        // later overrides apply on top of earlier ones
+        assert_eq!(severity("scripts/build.test.js", "no-console"), None);
+        assert_eq!(severity("scripts/build.test.js", "no-debugger"), Some(AllowWarnDeny::Deny));
+        assert!(resolve("scripts/build.js").1.env.contains("node"));
+        assert!(!resolve("index.js").1.env.contains("node"));
+    }
 }
@@ -27,6 +27,11 @@ impl OxlintEnv {
         // Filter out false values
         self.0.iter().filter_map(|(k, v)| (*v).then_some(k.as_str()))
     }
+
+    /// Enable or disable the environments of `other`, leaving the others alone.
+    pub(crate) fn extend(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
 }
 
 impl FromIterator<String> for OxlintEnv {
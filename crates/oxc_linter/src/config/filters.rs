@@ -0,0 +1,94 @@
+use std::{borrow::Cow, ops::Deref};
+
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind};
+
+/// Configure groups of rules with filter expressions.
+///
+/// Keys are the same filters accepted by `-A`/`-W`/`-D` on the command line.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(try_from = "FxHashMap<String, AllowWarnDeny>")]
+pub struct OxlintFilters(FxHashMap<String, AllowWarnDeny>);
+
+impl Deref for OxlintFilters {
+    type Target = FxHashMap<String, AllowWarnDeny>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<FxHashMap<String, AllowWarnDeny>> for OxlintFilters {
+    type Error = InvalidFilterKind;
+
+    fn try_from(filters: FxHashMap<String, AllowWarnDeny>) -> Result<Self, Self::Error> {
+        for filter in filters.keys() {
+            LintFilterKind::try_from(filter.clone())?;
+        }
+        Ok(Self(filters))
+    }
+}
+
+impl OxlintFilters {
+    /// Filters are applied in a fixed order so that the result does not depend on the order of
+    /// keys in the config file: first `warn`, then `deny`, then `allow`. This means `allow` always
+    /// wins, which makes it possible to subtract a group of rules from a broader selection.
+    pub fn filters(&self) -> impl Iterator<Item = LintFilter> + '_ {
+        [AllowWarnDeny::Warn, AllowWarnDeny::Deny, AllowWarnDeny::Allow].into_iter().flat_map(
+            move |severity| {
+                self.iter()
+                    .filter(move |(_, s)| **s == severity)
+                    .map(move |(filter, _)| LintFilter::new(severity, filter.clone()).unwrap())
+            },
+        )
+    }
+}
+
+impl JsonSchema for OxlintFilters {
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("OxlintFilters")
+    }
+
+    fn schema_name() -> String {
+        "OxlintFilters".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut schema = gen.subschema_for::<FxHashMap<String, AllowWarnDeny>>().into_object();
+
+        {
+            let metadata = schema.metadata();
+            metadata.title = Some("Rule Filters".to_string());
+
+            metadata.description = Some(
+                r#"
+Configure groups of rules with filter expressions.
+
+Keys accept the same filters as `-A`/`-W`/`-D` on the command line: rule names, categories, `plugin:<name>`, `fixable`, and combinations of these using `&` (and), `|` (or), `!` (not) and parentheses.
+
+Filters are applied after `categories` and before `rules`, in a fixed order: `warn` filters first, then `deny`, then `allow`. An `allow` filter therefore always removes the rules it matches.
+
+# Example
+```json
+{
+    "$schema": "./node_modules/oxlint/configuration_schema.json",
+    "filters": {
+        "correctness & fixable & !plugin:react": "deny"
+    }
+}
+```
+"#
+                .trim()
+                .to_string(),
+            );
+
+            metadata.examples =
+                vec![serde_json::json!({ "correctness & fixable & !plugin:react": "deny" })];
+        }
+
+        schema.into()
+    }
+}
@@ -1,22 +1,32 @@
 mod categories;
 mod env;
+mod filters;
 mod globals;
+mod overrides;
 mod oxlintrc;
+mod parser_overrides;
 mod plugins;
+mod rule_options;
 mod rules;
 mod settings;
 
+pub(crate) use self::{
+    categories::OxlintCategories, filters::OxlintFilters, rule_options::validate_rule_options,
+};
 pub use self::{
     env::OxlintEnv,
     globals::OxlintGlobals,
+    overrides::OxlintOverrides,
     oxlintrc::Oxlintrc,
+    parser_overrides::OxlintParserOverrides,
     plugins::LintPlugins,
+    rule_options::InvalidRuleOptions,
     rules::ESLintRule,
     rules::OxlintRules,
     settings::{jsdoc::JSDocPluginSettings, OxlintSettings},
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct LintConfig {
     pub(crate) plugins: LintPlugins,
     pub(crate) settings: OxlintSettings,
@@ -24,6 +34,7 @@ pub(crate) struct LintConfig {
     pub(crate) env: OxlintEnv,
     /// Enabled or disabled specific global variables.
     pub(crate) globals: OxlintGlobals,
+    pub(crate) parser_overrides: OxlintParserOverrides,
 }
 
 impl From<Oxlintrc> for LintConfig {
@@ -33,6 +44,7 @@ impl From<Oxlintrc> for LintConfig {
             settings: config.settings,
             env: config.env,
             globals: config.globals,
+            parser_overrides: config.parser_overrides,
         }
     }
 }
@@ -0,0 +1,148 @@
+use std::{borrow::Cow, path::Path};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{env::OxlintEnv, globals::OxlintGlobals, rules::OxlintRules};
+
+/// Configure the files matching a glob differently than the rest of the files, e.g. to change
+/// the severity of rules in tests, generated code or scripts.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(try_from = "Vec<OxlintOverride>")]
+pub struct OxlintOverrides(Vec<OxlintOverride>);
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OxlintOverride {
+    /// Globs of the files to override, relative to the current working directory.
+    pub files: Vec<String>,
+    /// Rules to enable, disable or configure in the files, on top of the configured rules.
+    #[serde(default)]
+    pub rules: OxlintRules,
+    /// Environments to enable or disable in the files.
+    pub env: Option<OxlintEnv>,
+    /// Global variables to enable or disable in the files.
+    pub globals: Option<OxlintGlobals>,
+    #[serde(skip)]
+    globs: GlobSet,
+}
+
+impl TryFrom<Vec<OxlintOverride>> for OxlintOverrides {
+    type Error = globset::Error;
+
+    fn try_from(mut overrides: Vec<OxlintOverride>) -> Result<Self, Self::Error> {
+        for config_override in &mut overrides {
+            let mut builder = GlobSetBuilder::new();
+            for glob in &config_override.files {
+                builder.add(Glob::new(glob)?);
+            }
+            config_override.globs = builder.build()?;
+        }
+        Ok(Self(overrides))
+    }
+}
+
+impl OxlintOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &OxlintOverride> + '_ {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for OxlintOverrides {
+    type Item = OxlintOverride;
+    type IntoIter = std::vec::IntoIter<OxlintOverride>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl OxlintOverride {
+    /// Whether `path`, relative to the current working directory, is overridden.
+    pub fn is_match(&self, path: &Path) -> bool {
+        self.globs.is_match(path)
+    }
+
+    pub(crate) fn globs(&self) -> &GlobSet {
+        &self.globs
+    }
+}
+
+impl JsonSchema for OxlintOverrides {
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("OxlintOverrides")
+    }
+
+    fn schema_name() -> String {
+        "OxlintOverrides".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut schema = gen.subschema_for::<Vec<OxlintOverride>>().into_object();
+
+        {
+            let metadata = schema.metadata();
+            metadata.title = Some("Overrides".to_string());
+
+            metadata.description = Some(
+                r#"
+Configure the files matching a glob differently than the rest of the files, e.g. to change the severity of rules in tests, generated code or scripts.
+
+The rules, environments and globals of an override are applied on top of the configuration of the files. Overrides are applied in order, so later overrides win.
+
+# Example
+```json
+{
+    "$schema": "./node_modules/oxlint/configuration_schema.json",
+    "overrides": [
+        { "files": ["*.test.ts", "*.spec.ts"], "rules": { "no-console": "off" }, "env": { "jest": true } },
+        { "files": ["scripts/**"], "rules": { "no-process-exit": "off" } }
+    ]
+}
+```
+"#
+                .trim()
+                .to_string(),
+            );
+
+            metadata.examples = vec![
+                serde_json::json!([{ "files": ["*.test.ts"], "rules": { "no-console": "off" } }]),
+            ];
+        }
+
+        schema.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::OxlintOverrides;
+
+    #[test]
+    fn test_is_match() {
+        let overrides: OxlintOverrides = serde_json::from_value(json!([
+            { "files": ["*.test.ts"], "rules": { "no-console": "off" } },
+            { "files": ["scripts/**"], "env": { "node": true } },
+        ]))
+        .unwrap();
+
+        let matches = |path: &str| overrides.iter().filter(|o| o.is_match(Path::new(path))).count();
+        assert_eq!(matches("src/index.test.ts"), 1);
+        assert_eq!(matches("scripts/build.test.ts"), 2);
+        assert_eq!(matches("src/index.ts"), 0);
+    }
+
+    #[test]
+    fn test_invalid_glob() {
+        let result = serde_json::from_value::<OxlintOverrides>(json!([{ "files": ["[a"] }]));
+        assert!(result.is_err());
+    }
+}
@@ -1,11 +1,17 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{ArrayExpressionElement, Expression, ObjectPropertyKind};
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_parser::{JsonMode, Parser};
+use oxc_resolver::{Resolution, ResolveOptions, Resolver};
+use oxc_span::SourceType;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    categories::OxlintCategories, env::OxlintEnv, globals::OxlintGlobals, plugins::LintPlugins,
+    categories::OxlintCategories, env::OxlintEnv, filters::OxlintFilters, globals::OxlintGlobals,
+    overrides::OxlintOverrides, parser_overrides::OxlintParserOverrides, plugins::LintPlugins,
     rules::OxlintRules, settings::OxlintSettings,
 };
 
@@ -49,8 +55,20 @@ use crate::utils::read_to_string;
 #[serde(default)]
 #[non_exhaustive]
 pub struct Oxlintrc {
+    /// Configuration files which this configuration extends, merged in order before it.
+    ///
+    /// Each entry is either a path relative to this configuration file, like `./base.json`, or
+    /// the name of a package whose entry point is a configuration file, like
+    /// `@company/oxlint-config`, which is resolved from the directory of this configuration file.
+    ///
+    /// Rules, environments, globals, categories, filters and settings of this configuration
+    /// override those of the configurations it extends, plugins are added to theirs, and overrides
+    /// and parser overrides are appended to theirs.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
     pub plugins: LintPlugins,
     pub categories: OxlintCategories,
+    pub filters: OxlintFilters,
     /// Example
     ///
     /// `.oxlintrc.json`
@@ -74,50 +92,287 @@ pub struct Oxlintrc {
     pub env: OxlintEnv,
     /// Enabled or disabled specific global variables.
     pub globals: OxlintGlobals,
+    #[serde(rename = "parserOverrides", skip_serializing_if = "OxlintParserOverrides::is_empty")]
+    pub parser_overrides: OxlintParserOverrides,
+    #[serde(skip_serializing_if = "OxlintOverrides::is_empty")]
+    pub overrides: OxlintOverrides,
 }
 
 impl Oxlintrc {
     /// # Errors
     ///
     /// * Parse Failure
+    /// * Failure to resolve or parse a configuration in `extends`
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
-        let mut string = read_to_string(path).map_err(|e| {
-            OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
-        })?;
+        let json = read_json(path, &mut vec![])?;
 
-        // jsonc support
-        json_strip_comments::strip(&mut string).map_err(|err| {
-            OxcDiagnostic::error(format!("Failed to parse jsonc file {path:?}: {err:?}"))
+        let config = Self::deserialize(&json).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
         })?;
 
-        let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
+        Ok(config)
+    }
+
+    /// Parse the configuration in `json`, like the contents of a configuration file, which may
+    /// contain comments and trailing commas.
+    ///
+    /// # Errors
+    ///
+    /// * Parse Failure
+    /// * `extends` is used, as it can only be resolved relative to a configuration file
+    pub fn from_json(json: &str) -> Result<Self, OxcDiagnostic> {
+        let allocator = Allocator::default();
+        let expr = Parser::new(&allocator, json, SourceType::default())
+            .parse_json(JsonMode::Jsonc)
+            .map_err(|errors| {
+                let err = errors.first().map_or_else(String::new, |error| {
+                    let offset = error
+                        .labels
+                        .as_ref()
+                        .and_then(|labels| labels.first())
+                        .map_or(0, LabeledSpan::offset);
+                    let (line, column) = line_column(json, offset);
+                    format!("{error} at line {line} column {column}")
+                });
+                OxcDiagnostic::error(format!("Failed to parse config.\n{err}"))
+            })?;
+        let json = json_value(&expr);
+        if json.get("extends").is_some() {
+            return Err(OxcDiagnostic::error(
+                "Failed to parse config, `extends` is only supported in configuration files.",
+            ));
+        }
+
+        Self::deserialize(&json).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
+        })
+    }
+}
+
+/// Read the configuration file at `path` as JSON, with the configurations it extends merged in.
+///
+/// `extending` are the configuration files which are being read because they extend `path`.
+fn read_json(
+    path: &Path,
+    extending: &mut Vec<PathBuf>,
+) -> Result<serde_json::Value, OxcDiagnostic> {
+    let string = read_to_string(path).map_err(|e| {
+        OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
+    })?;
+
+    let allocator = Allocator::default();
+    let json = Parser::new(&allocator, &string, SourceType::default())
+        .parse_json(JsonMode::Jsonc)
+        .map_err(|errors| {
+            let err = errors.first().map_or_else(String::new, |error| {
+                let offset = error
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.first())
+                    .map_or(0, LabeledSpan::offset);
+                let (line, column) = line_column(&string, offset);
+                format!("{error} at line {line} column {column}")
+            });
             let guess = mime_guess::from_path(path);
             let err = match guess.first() {
                 // syntax error
-                Some(mime) if mime.subtype() == "json" => err.to_string(),
+                Some(mime) if mime.subtype() == "json" => err,
                 Some(_) => "Only json configuration is supported".to_string(),
-                None => {
-                    format!(
-                        "{err}, if the configuration is not a json file, please use json instead."
-                    )
-                }
+                None => format!(
+                    "{err}, if the configuration is not a json file, {}",
+                    "please use json instead."
+                ),
             };
             OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
         })?;
+    let json = json_value(&json);
 
-        let config = Self::deserialize(&json).map_err(|err| {
-            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
-        })?;
+    let extends = match json.get("extends") {
+        Some(serde_json::Value::String(specifier)) => vec![specifier.clone()],
+        Some(serde_json::Value::Array(specifiers)) => specifiers
+            .iter()
+            .filter_map(|specifier| specifier.as_str().map(ToString::to_string))
+            .collect(),
+        _ => return Ok(json),
+    };
+    if extends.is_empty() {
+        return Ok(json);
+    }
 
-        Ok(config)
+    let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if extending.contains(&canonical_path) {
+        return Err(OxcDiagnostic::error(format!(
+            "Failed to parse config {path:?}, it extends itself."
+        )));
+    }
+    extending.push(canonical_path.clone());
+    let mut merged = serde_json::Value::Object(serde_json::Map::new());
+    for specifier in &extends {
+        let extended_path = resolve_extends(&canonical_path, specifier)?;
+        merge_config(&mut merged, read_json(&extended_path, extending)?);
+    }
+    extending.pop();
+    merge_config(&mut merged, json);
+    Ok(merged)
+}
+
+/// Resolve `specifier`, an entry of the `extends` of the configuration file at `path`.
+fn resolve_extends(path: &Path, specifier: &str) -> Result<PathBuf, OxcDiagnostic> {
+    let directory = path.parent().unwrap_or(path);
+    let resolver = Resolver::new(ResolveOptions {
+        extensions: vec![".json".into()],
+        condition_names: vec!["default".into()],
+        ..ResolveOptions::default()
+    });
+    resolver.resolve(directory, specifier).map(Resolution::into_path_buf).map_err(|err| {
+        OxcDiagnostic::error(format!(
+            "Failed to resolve {specifier:?} extended by config {path:?}: {err}"
+        ))
+    })
+}
+
+/// Merge `config` into `base`, a configuration which `config` extends.
+fn merge_config(base: &mut serde_json::Value, config: serde_json::Value) {
+    let (Some(base), serde_json::Value::Object(config)) = (base.as_object_mut(), config) else {
+        return;
+    };
+    for (key, value) in config {
+        match (key.as_str(), base.get_mut(&key), value) {
+            ("extends", _, _) => {}
+            (
+                "plugins" | "parserOverrides" | "overrides",
+                Some(serde_json::Value::Array(base_values)),
+                serde_json::Value::Array(values),
+            ) => {
+                for value in values {
+                    if key != "plugins" || !base_values.contains(&value) {
+                        base_values.push(value);
+                    }
+                }
+            }
+            ("settings", Some(base_value), value) => merge_objects(base_value, value),
+            (
+                "rules" | "env" | "globals" | "categories" | "filters",
+                Some(serde_json::Value::Object(base_values)),
+                serde_json::Value::Object(values),
+            ) => base_values.extend(values),
+            (_, _, value) => {
+                base.insert(key, value);
+            }
+        }
     }
 }
 
+/// Merge `value` into `base` recursively, with the values of `value` taking precedence.
+fn merge_objects(base: &mut serde_json::Value, value: serde_json::Value) {
+    match (base, value) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(values)) => {
+            for (key, value) in values {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_objects(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, value) => *base = value,
+    }
+}
+
+/// Convert a value parsed by [`Parser::parse_json`] to a [`serde_json::Value`].
+fn json_value(expr: &Expression<'_>) -> serde_json::Value {
+    match expr {
+        Expression::BooleanLiteral(lit) => serde_json::Value::Bool(lit.value),
+        Expression::StringLiteral(lit) => serde_json::Value::String(lit.value.to_string()),
+        Expression::NumericLiteral(lit) => json_number(lit.value),
+        Expression::UnaryExpression(unary) => match &unary.argument {
+            Expression::NumericLiteral(lit) => json_number(-lit.value),
+            _ => serde_json::Value::Null,
+        },
+        Expression::ArrayExpression(array) => array
+            .elements
+            .iter()
+            .filter_map(ArrayExpressionElement::as_expression)
+            .map(json_value)
+            .collect(),
+        Expression::ObjectExpression(object) => object
+            .properties
+            .iter()
+            .filter_map(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    Some((property.key.static_name()?.into_owned(), json_value(&property.value)))
+                }
+                ObjectPropertyKind::SpreadProperty(_) => None,
+            })
+            .collect(),
+        _ => serde_json::Value::Null,
+    }
+}
+
+/// Integers are kept as integers, so that they can be deserialized as such.
+#[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+fn json_number(value: f64) -> serde_json::Value {
+    if value.fract() == 0.0 && value.abs() < 2f64.powi(53) {
+        serde_json::Value::from(value as i64)
+    } else {
+        serde_json::Number::from_f64(value)
+            .map_or(serde_json::Value::Null, serde_json::Value::Number)
+    }
+}
+
+/// 1-based line and column of `offset` in `source_text`.
+fn line_column(source_text: &str, offset: usize) -> (usize, usize) {
+    let before = &source_text[..offset.min(source_text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
 #[cfg(test)]
 mod test {
-    use super::*;
+    use std::env;
+
     use serde_json::json;
 
+    use super::*;
+    use crate::AllowWarnDeny;
+
+    #[test]
+    fn test_json_value() {
+        let source = r#"{
+            // comment
+            "rules": { "eqeqeq": ["error", "always"], "no-console": 2, },
+            "settings": { "a": -1.5, "b": null, "c": true },
+        }"#;
+        let allocator = Allocator::default();
+        let expr = Parser::new(&allocator, source, SourceType::default())
+            .parse_json(JsonMode::Jsonc)
+            .unwrap();
+        assert_eq!(
+            json_value(&expr),
+            json!({
+                "rules": { "eqeqeq": ["error", "always"], "no-console": 2 },
+                "settings": { "a": -1.5, "b": null, "c": true },
+            })
+        );
+        assert_eq!(line_column(source, source.find("\"rules\"").unwrap()), (3, 13));
+    }
+
+    #[test]
+    fn test_oxlintrc_from_json() {
+        let config = Oxlintrc::from_json(
+            r#"{
+                // comment
+                "rules": { "eqeqeq": "error", },
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.rules.rules.len(), 1);
+
+        assert!(Oxlintrc::from_json("{").is_err());
+        assert!(Oxlintrc::from_json(r#"{ "extends": ["./base.json"] }"#).is_err());
+    }
+
     #[test]
     fn test_oxlintrc_de_empty() {
         let config: Oxlintrc = serde_json::from_value(json!({})).unwrap();
@@ -156,4 +411,29 @@ mod test {
         let config: Oxlintrc = serde_json::from_str(r#"{ "plugins": ["import"] }"#).unwrap();
         assert_eq!(config.plugins, LintPlugins::default().union(LintPlugins::IMPORT));
     }
+
+    #[test]
+    fn test_oxlintrc_extends() {
+        let fixtures = env::current_dir().unwrap().join("fixtures/extends");
+        let config = Oxlintrc::from_file(&fixtures.join(".oxlintrc.json")).unwrap();
+        assert!(config.extends.is_empty());
+        assert_eq!(config.plugins, LintPlugins::default().union(LintPlugins::IMPORT));
+        assert!(config.env.contains("browser"));
+        assert!(config.globals.is_enabled("base"));
+        assert!(config.globals.is_enabled("local"));
+
+        let severity = |name: &str| {
+            config.rules.rules.iter().find(|rule| rule.rule_name == name).map(|rule| rule.severity)
+        };
+        assert_eq!(severity("no-console"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity("no-debugger"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity("eqeqeq"), Some(AllowWarnDeny::Warn));
+    }
+
+    #[test]
+    fn test_oxlintrc_extends_circular() {
+        let fixtures = env::current_dir().unwrap().join("fixtures/extends");
+        assert!(Oxlintrc::from_file(&fixtures.join("circular.json")).is_err());
+        assert!(Oxlintrc::from_file(&fixtures.join("missing.json")).is_err());
+    }
 }
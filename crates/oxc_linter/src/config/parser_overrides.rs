@@ -0,0 +1,174 @@
+use std::{borrow::Cow, path::Path};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_span::SourceType;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parse the files matching a glob as a different source type than inferred from their
+/// extension.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(try_from = "Vec<OxlintParserOverride>")]
+pub struct OxlintParserOverrides(Vec<OxlintParserOverride>);
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OxlintParserOverride {
+    /// Globs of the files to override, relative to the current working directory.
+    pub files: Vec<String>,
+    /// Parse the files as a script, a module, or detect it from the use of `import`/`export`.
+    pub source_type: Option<ParserSourceType>,
+    /// Parse the files as JavaScript, TypeScript or TypeScript declarations, with or without JSX.
+    pub lang: Option<ParserLang>,
+    #[serde(skip)]
+    globs: GlobSet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ParserSourceType {
+    Script,
+    Module,
+    Unambiguous,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ParserLang {
+    Js,
+    Jsx,
+    Ts,
+    Tsx,
+    Dts,
+}
+
+impl TryFrom<Vec<OxlintParserOverride>> for OxlintParserOverrides {
+    type Error = globset::Error;
+
+    fn try_from(mut overrides: Vec<OxlintParserOverride>) -> Result<Self, Self::Error> {
+        for parser_override in &mut overrides {
+            let mut builder = GlobSetBuilder::new();
+            for glob in &parser_override.files {
+                builder.add(Glob::new(glob)?);
+            }
+            parser_override.globs = builder.build()?;
+        }
+        Ok(Self(overrides))
+    }
+}
+
+impl OxlintParserOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Apply the overrides which match `path` to `source_type`. Overrides are applied in order,
+    /// so later overrides win.
+    pub fn source_type(&self, path: &Path, source_type: SourceType) -> SourceType {
+        self.0
+            .iter()
+            .filter(|parser_override| parser_override.globs.is_match(path))
+            .fold(source_type, |source_type, parser_override| parser_override.apply(source_type))
+    }
+}
+
+impl OxlintParserOverride {
+    fn apply(&self, source_type: SourceType) -> SourceType {
+        let source_type = match self.source_type {
+            Some(ParserSourceType::Script) => source_type.with_script(true),
+            Some(ParserSourceType::Module) => source_type.with_module(true),
+            Some(ParserSourceType::Unambiguous) => source_type.with_unambiguous(true),
+            None => source_type,
+        };
+        match self.lang {
+            Some(ParserLang::Js) => source_type.with_javascript(true).with_standard(true),
+            Some(ParserLang::Jsx) => source_type.with_javascript(true).with_jsx(true),
+            Some(ParserLang::Ts) => source_type.with_typescript(true).with_standard(true),
+            Some(ParserLang::Tsx) => source_type.with_typescript(true).with_jsx(true),
+            Some(ParserLang::Dts) => {
+                source_type.with_typescript_definition(true).with_standard(true)
+            }
+            None => source_type,
+        }
+    }
+}
+
+impl JsonSchema for OxlintParserOverrides {
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("OxlintParserOverrides")
+    }
+
+    fn schema_name() -> String {
+        "OxlintParserOverrides".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut schema = gen.subschema_for::<Vec<OxlintParserOverride>>().into_object();
+
+        {
+            let metadata = schema.metadata();
+            metadata.title = Some("Parser Overrides".to_string());
+
+            metadata.description = Some(
+                r#"
+Parse the files matching a glob as a different source type than inferred from their extension.
+
+Overrides are applied in order, so later overrides win. Decorators are always parsed, so they do not need an override.
+
+# Example
+```json
+{
+    "$schema": "./node_modules/oxlint/configuration_schema.json",
+    "parserOverrides": [
+        { "files": ["*.js"], "sourceType": "script" },
+        { "files": ["src/esm/**"], "sourceType": "module" },
+        { "files": ["types/**"], "lang": "dts" }
+    ]
+}
+```
+"#
+                .trim()
+                .to_string(),
+            );
+
+            metadata.examples =
+                vec![serde_json::json!([{ "files": ["*.js"], "sourceType": "script" }])];
+        }
+
+        schema.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_span::SourceType;
+    use serde_json::json;
+
+    use super::OxlintParserOverrides;
+
+    #[test]
+    fn test_source_type() {
+        let overrides: OxlintParserOverrides = serde_json::from_value(json!([
+            { "files": ["*.js"], "sourceType": "script" },
+            { "files": ["src/legacy/**"], "lang": "ts" },
+        ]))
+        .unwrap();
+
+        let source_type = |path: &str| {
+            let path = Path::new(path);
+            overrides.source_type(path, SourceType::from_path(path).unwrap())
+        };
+        assert_eq!(source_type("lib/index.js"), SourceType::jsx().with_script(true));
+        assert_eq!(source_type("src/legacy/index.js"), SourceType::ts().with_script(true));
+        assert_eq!(source_type("src/legacy/index.mjs"), SourceType::ts());
+        assert_eq!(source_type("src/index.ts"), SourceType::ts());
+    }
+
+    #[test]
+    fn test_invalid_glob() {
+        let result = serde_json::from_value::<OxlintParserOverrides>(json!([{ "files": ["[a"] }]));
+        assert!(result.is_err());
+    }
+}
@@ -40,6 +40,8 @@ bitflags! {
         const NODE = 1 << 12;
         /// Custom security rules made by the Oxc team
         const SECURITY = 1 << 13;
+        /// `eslint-plugin-regexp`
+        const REGEXP = 1 << 14;
     }
 }
 impl Default for LintPlugins {
@@ -66,6 +68,7 @@ impl From<LintPluginOptions> for LintPlugins {
         plugins.set(LintPlugins::PROMISE, options.promise);
         plugins.set(LintPlugins::NODE, options.node);
         plugins.set(LintPlugins::SECURITY, options.security);
+        plugins.set(LintPlugins::REGEXP, options.regexp);
         plugins
     }
 }
@@ -94,6 +97,13 @@ impl LintPlugins {
     pub fn has_import(self) -> bool {
         self.contains(LintPlugins::IMPORT)
     }
+
+    /// Returns `true` if any enabled plugin needs the imported modules to be resolved, i.e. the
+    /// import or node plugins.
+    #[inline]
+    pub fn needs_module_resolution(self) -> bool {
+        self.intersects(LintPlugins::IMPORT.union(LintPlugins::NODE))
+    }
 }
 
 impl From<&str> for LintPlugins {
@@ -116,6 +126,7 @@ impl From<&str> for LintPlugins {
             "promise" => LintPlugins::PROMISE,
             "node" => LintPlugins::NODE,
             "security" | "oxc-security" => LintPlugins::SECURITY,
+            "regexp" => LintPlugins::REGEXP,
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             _ => LintPlugins::empty(),
@@ -140,6 +151,7 @@ impl From<LintPlugins> for &'static str {
             LintPlugins::PROMISE => "promise",
             LintPlugins::NODE => "node",
             LintPlugins::SECURITY => "security",
+            LintPlugins::REGEXP => "regexp",
             _ => "",
         }
     }
@@ -230,6 +242,7 @@ pub struct LintPluginOptions {
     pub promise: bool,
     pub node: bool,
     pub security: bool,
+    pub regexp: bool,
 }
 
 impl Default for LintPluginOptions {
@@ -249,6 +262,7 @@ impl Default for LintPluginOptions {
             promise: false,
             node: false,
             security: false,
+            regexp: false,
         }
     }
 }
@@ -272,6 +286,7 @@ impl LintPluginOptions {
             promise: false,
             node: false,
             security: false,
+            regexp: false,
         }
     }
 
@@ -293,6 +308,7 @@ impl LintPluginOptions {
             promise: true,
             node: true,
             security: true,
+            regexp: true,
         }
     }
 }
@@ -317,6 +333,7 @@ impl<S: AsRef<str>> FromIterator<(S, bool)> for LintPluginOptions {
                 LintPlugins::PROMISE => options.promise = enabled,
                 LintPlugins::NODE => options.node = enabled,
                 LintPlugins::SECURITY => options.security = enabled,
+                LintPlugins::REGEXP => options.regexp = enabled,
                 _ => {} // ignored
             }
         }
@@ -350,6 +367,7 @@ mod test {
                 && self.promise == other.promise
                 && self.node == other.node
                 && self.security == other.security
+                && self.regexp == other.regexp
         }
     }
 
@@ -390,6 +408,7 @@ mod test {
             promise: false,
             node: false,
             security: false,
+            regexp: false,
         };
         assert_eq!(plugins, expected);
     }
@@ -0,0 +1,373 @@
+//! Validation of the options of configured rules against the JSON schema of their options.
+
+use itertools::Itertools as _;
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SchemaObject, SingleOrVec},
+};
+use serde_json::Value;
+
+use crate::RuleEnum;
+
+/// The problems with the options of a configured rule, e.g. unknown options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidRuleOptions {
+    /// The full name of the rule, e.g. `max-lines` or `react/jsx-no-useless-fragment`.
+    pub rule_name: String,
+    pub errors: Vec<String>,
+}
+
+/// Checks the options of `rule`, i.e. the values after the severity in
+/// `["error", { "max": 300 }]`, against the schema of the rule's options.
+///
+/// Rules without a schema accept any options.
+pub(crate) fn validate_rule_options(rule: &RuleEnum, config: &Value) -> Vec<String> {
+    let mut gen = SchemaGenerator::default();
+    let Some(schema) = rule.schema(&mut gen) else {
+        return vec![];
+    };
+    let options = match config {
+        Value::Array(options) => options.as_slice(),
+        Value::Null => &[],
+        option => std::slice::from_ref(option),
+    };
+    let validator = Validator { gen: &gen };
+    let mut errors = vec![];
+    for option in options {
+        validator.validate(&schema, option, "", &mut errors);
+    }
+    errors
+}
+
+/// Create `rule` from its configuration, recording the problems with its options.
+pub(crate) fn read_rule_json(
+    rule: &RuleEnum,
+    config: Value,
+    invalid_options: &mut Vec<InvalidRuleOptions>,
+) -> RuleEnum {
+    let errors = validate_rule_options(rule, &config);
+    if !errors.is_empty() {
+        let rule_name = if rule.plugin_name() == "eslint" {
+            rule.name().to_string()
+        } else {
+            format!("{}/{}", rule.plugin_name(), rule.name())
+        };
+        invalid_options.push(InvalidRuleOptions { rule_name, errors });
+    }
+    rule.read_json(config)
+}
+
+struct Validator<'s> {
+    /// Resolves the `$ref`s of the schemas of structs and enums.
+    gen: &'s SchemaGenerator,
+}
+
+impl Validator<'_> {
+    /// Resolves `schema` if it is a reference to a definition.
+    fn resolve<'a>(&'a self, schema: &'a Schema) -> Option<&'a SchemaObject> {
+        let Schema::Object(object) = schema else {
+            return None;
+        };
+        match &object.reference {
+            Some(reference) => {
+                let name = reference.strip_prefix(&self.gen.settings().definitions_path)?;
+                self.resolve(self.gen.definitions().get(name)?)
+            }
+            None => Some(object),
+        }
+    }
+
+    /// Records the problems of `value` at `path`, e.g. `paths[0].name`, in `errors`.
+    fn validate(&self, schema: &Schema, value: &Value, path: &str, errors: &mut Vec<String>) {
+        let Some(schema) = self.resolve(schema) else {
+            return;
+        };
+
+        if let Some(subschemas) = &schema.subschemas {
+            for subschema in subschemas.all_of.iter().flatten() {
+                self.validate(subschema, value, path, errors);
+            }
+            let alternatives = subschemas.any_of.iter().chain(&subschemas.one_of).flatten();
+            self.validate_alternatives(alternatives, value, path, errors);
+        }
+
+        if let Some(instance_type) = &schema.instance_type {
+            if !matches_type(instance_type, value) {
+                let expected = instance_types(instance_type).map(type_name).join(" or ");
+                errors
+                    .push(invalid(path, &format!("expected {expected}, found {}", type_of(value))));
+                return;
+            }
+        }
+
+        if let Some(values) = &schema.enum_values {
+            if !values.contains(value) {
+                let expected = values.iter().map(|value| format!("`{value}`")).join(", ");
+                errors.push(invalid(path, &format!("expected one of {expected}, found `{value}`")));
+                return;
+            }
+        }
+
+        match value {
+            Value::Number(number) => {
+                let (Some(validation), Some(number)) = (&schema.number, number.as_f64()) else {
+                    return;
+                };
+                if let Some(minimum) = validation.minimum.filter(|minimum| number < *minimum) {
+                    errors.push(invalid(path, &format!("expected at least {minimum}")));
+                }
+                if let Some(maximum) = validation.maximum.filter(|maximum| number > *maximum) {
+                    errors.push(invalid(path, &format!("expected at most {maximum}")));
+                }
+            }
+            Value::Array(items) => {
+                let Some(items_schema) =
+                    schema.array.as_ref().and_then(|array| array.items.as_ref())
+                else {
+                    return;
+                };
+                for (i, item) in items.iter().enumerate() {
+                    let item_schema: &Schema = match items_schema {
+                        SingleOrVec::Single(schema) => schema,
+                        SingleOrVec::Vec(schemas) => {
+                            let Some(schema) = schemas.get(i) else { continue };
+                            schema
+                        }
+                    };
+                    self.validate(item_schema, item, &format!("{path}[{i}]"), errors);
+                }
+            }
+            Value::Object(properties) => {
+                let Some(validation) = &schema.object else {
+                    return;
+                };
+                for required in &validation.required {
+                    if !properties.contains_key(required) {
+                        errors.push(format!("missing option `{}`", join(path, required)));
+                    }
+                }
+                for (key, value) in properties {
+                    let property_path = join(path, key);
+                    // Unlike JSON schema, properties which are not declared are not allowed unless
+                    // the schema of additional properties is given, e.g. for maps.
+                    let property_schema = validation.properties.get(key).or(validation
+                        .additional_properties
+                        .as_deref()
+                        .filter(|schema| !matches!(schema, Schema::Bool(false))));
+                    match property_schema {
+                        Some(property_schema) => {
+                            self.validate(property_schema, value, &property_path, errors);
+                        }
+                        None => errors.push(format!("unknown option `{property_path}`")),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Validates a value which must match one of `alternatives`, e.g. the variants of an untagged
+    /// enum. When it matches none, the problems of the closest alternative are recorded.
+    fn validate_alternatives<'a, I>(
+        &self,
+        alternatives: I,
+        value: &Value,
+        path: &str,
+        errors: &mut Vec<String>,
+    ) where
+        I: Iterator<Item = &'a Schema> + Clone,
+    {
+        if alternatives.clone().next().is_none() {
+            return;
+        }
+        let mut closest: Option<Vec<String>> = None;
+        for alternative in alternatives.clone() {
+            if !self.resolve(alternative).is_some_and(|schema| self.accepts_type(schema, value)) {
+                continue;
+            }
+            let mut alternative_errors = vec![];
+            self.validate(alternative, value, path, &mut alternative_errors);
+            if alternative_errors.is_empty() {
+                return;
+            }
+            if closest.as_ref().map_or(true, |closest| alternative_errors.len() < closest.len()) {
+                closest = Some(alternative_errors);
+            }
+        }
+        match closest {
+            Some(closest) => errors.extend(closest),
+            None => {
+                let mut expected = vec![];
+                for schema in alternatives.filter_map(|alternative| self.resolve(alternative)) {
+                    self.collect_type_names(schema, &mut expected);
+                }
+                let expected = expected.into_iter().unique().join(" or ");
+                errors
+                    .push(invalid(path, &format!("expected {expected}, found {}", type_of(value))));
+            }
+        }
+    }
+
+    /// Collects the names of the types of `schema`, or of its alternatives.
+    fn collect_type_names(&self, schema: &SchemaObject, names: &mut Vec<&'static str>) {
+        if let Some(instance_type) = &schema.instance_type {
+            names.extend(instance_types(instance_type).map(type_name));
+        } else if let Some(subschemas) = &schema.subschemas {
+            let alternatives = subschemas.any_of.iter().chain(&subschemas.one_of).flatten();
+            for schema in alternatives.filter_map(|alternative| self.resolve(alternative)) {
+                self.collect_type_names(schema, names);
+            }
+        }
+    }
+
+    /// Whether the type of `value` is one of the types of `schema`, or of one of its alternatives.
+    fn accepts_type(&self, schema: &SchemaObject, value: &Value) -> bool {
+        if let Some(instance_type) = &schema.instance_type {
+            return matches_type(instance_type, value);
+        }
+        let Some(subschemas) = &schema.subschemas else {
+            return true;
+        };
+        let alternatives =
+            subschemas.any_of.iter().chain(&subschemas.one_of).flatten().collect::<Vec<_>>();
+        alternatives.is_empty()
+            || alternatives.iter().any(|alternative| {
+                self.resolve(alternative).map_or(true, |schema| self.accepts_type(schema, value))
+            })
+    }
+}
+
+fn matches_type(instance_type: &SingleOrVec<InstanceType>, value: &Value) -> bool {
+    instance_types(instance_type).any(|instance_type| match instance_type {
+        InstanceType::Null => value.is_null(),
+        InstanceType::Boolean => value.is_boolean(),
+        InstanceType::Object => value.is_object(),
+        InstanceType::Array => value.is_array(),
+        InstanceType::Number => value.is_number(),
+        InstanceType::String => value.is_string(),
+        InstanceType::Integer => value.is_i64() || value.is_u64(),
+    })
+}
+
+fn instance_types(
+    instance_type: &SingleOrVec<InstanceType>,
+) -> impl Iterator<Item = &InstanceType> + Clone + '_ {
+    let instance_types: &[InstanceType] = match instance_type {
+        SingleOrVec::Single(instance_type) => std::slice::from_ref(instance_type.as_ref()),
+        SingleOrVec::Vec(instance_types) => instance_types,
+    };
+    instance_types.iter()
+}
+
+fn type_name(instance_type: &InstanceType) -> &'static str {
+    match instance_type {
+        InstanceType::Null => "null",
+        InstanceType::Boolean => "boolean",
+        InstanceType::Object => "object",
+        InstanceType::Array => "array",
+        InstanceType::Number => "number",
+        InstanceType::String => "string",
+        InstanceType::Integer => "integer",
+    }
+}
+
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn invalid(path: &str, message: &str) -> String {
+    if path.is_empty() {
+        message.to_string()
+    } else {
+        format!("invalid option `{path}`: {message}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::validate_rule_options;
+    use crate::rules::RULES;
+
+    fn validate(rule_name: &str, config: &serde_json::Value) -> Vec<String> {
+        let rule = RULES.iter().find(|rule| rule.name() == rule_name).unwrap();
+        validate_rule_options(rule, config)
+    }
+
+    #[test]
+    fn test_valid_options() {
+        assert!(validate("max-lines", &json!([300])).is_empty());
+        assert!(validate("max-lines", &json!([{ "max": 300, "skipComments": true }])).is_empty());
+        assert!(validate(
+            "no-restricted-imports",
+            &json!(["fs", { "name": "lodash", "importNames": ["cloneDeep"] }])
+        )
+        .is_empty());
+        assert!(validate(
+            "no-restricted-imports",
+            &json!([{ "paths": ["fs"], "patterns": ["lodash/*", { "group": ["@/*"] }] }])
+        )
+        .is_empty());
+        // Rules without a schema accept any options.
+        assert!(validate("no-debugger", &json!([{ "foo": 1 }])).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_options() {
+        assert_eq!(
+            validate("max-lines", &json!([{ "max": 300, "skipComment": true }])),
+            vec!["unknown option `skipComment`"]
+        );
+        assert_eq!(
+            validate("no-restricted-imports", &json!([{ "paths": ["fs"], "pattern": ["a/*"] }])),
+            vec!["unknown option `pattern`"]
+        );
+        assert_eq!(
+            validate(
+                "no-restricted-imports",
+                &json!([{ "paths": [{ "name": "fs", "importName": ["readFile"] }] }])
+            ),
+            vec!["unknown option `paths[0].importName`"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert_eq!(
+            validate("max-lines", &json!([{ "max": "300" }])),
+            vec!["invalid option `max`: expected integer, found string"]
+        );
+        assert_eq!(
+            validate("max-lines", &json!(["300"])),
+            vec!["expected integer or object, found string"]
+        );
+        assert_eq!(
+            validate("max-lines", &json!([{ "max": -1 }])),
+            vec!["invalid option `max`: expected at least 0"]
+        );
+        assert_eq!(
+            validate("no-restricted-imports", &json!([1])),
+            vec!["expected string or object, found number"]
+        );
+        assert_eq!(
+            validate("no-restricted-imports", &json!([{ "message": "Don't." }])),
+            vec!["missing option `name`"]
+        );
+    }
+}
@@ -9,9 +9,10 @@ use serde::{
     Deserialize, Serialize,
 };
 
+use super::rule_options::{read_rule_json, InvalidRuleOptions};
 use crate::{
     rules::{RuleEnum, RULES},
-    utils::is_jest_rule_adapted_to_vitest,
+    utils::{is_jest_rule_adapted_to_vitest, is_unicorn_rule_adapted_to_node},
     AllowWarnDeny, RuleWithSeverity,
 };
 
@@ -29,11 +30,13 @@ pub struct OxlintRules {
     pub(crate) rules: Vec<ESLintRule>,
     /// List of rules that didn't match any known rules
     pub unknown_rules: Vec<ESLintRule>,
+    /// Rules configured with options which don't match the schema of their options
+    pub invalid_options: Vec<InvalidRuleOptions>,
 }
 
 impl OxlintRules {
     pub fn new(rules: Vec<ESLintRule>) -> Self {
-        Self { rules, unknown_rules: Vec::new() }
+        Self { rules, unknown_rules: Vec::new(), invalid_options: Vec::new() }
     }
 
     /// Returns `true` if there are no rules.
@@ -89,7 +92,7 @@ impl OxlintRules {
                                 .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
                             {
                                 let config = rule_config.config.clone().unwrap_or_default();
-                                let rule = rule.read_json(config);
+                                let rule = read_rule_json(rule, config, &mut self.invalid_options);
                                 rules_to_replace.push(RuleWithSeverity::new(rule, severity));
                             } else {
                                 self.unknown_rules.push(ESLintRule {
@@ -115,7 +118,7 @@ impl OxlintRules {
                                 .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
                             {
                                 let config = rule_config.config.clone().unwrap_or_default();
-                                let rule = rule.read_json(config);
+                                let rule = read_rule_json(rule, config, &mut self.invalid_options);
                                 rules_to_remove.push(RuleWithSeverity::new(rule, severity));
                             } else {
                                 self.unknown_rules.push(ESLintRule {
@@ -138,14 +141,16 @@ impl OxlintRules {
                         let config = rule_config.config.clone().unwrap_or_default();
 
                         if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                            let rule_with_config =
+                                read_rule_json(rule, config, &mut self.invalid_options);
                             rules_to_replace
-                                .push(RuleWithSeverity::new(rule.read_json(config), rule.severity));
+                                .push(RuleWithSeverity::new(rule_with_config, rule.severity));
                         }
                         // If the given rule is not found in the rule list (for example, if all rules are disabled),
                         // then look it up in the entire rules list and add it.
                         else if let Some(rule) = all_rules.iter().find(|r| r.name() == *name) {
                             rules_to_replace.push(RuleWithSeverity::new(
-                                rule.read_json(config),
+                                read_rule_json(rule, config, &mut self.invalid_options),
                                 rule_config.severity,
                             ));
                         }
@@ -174,6 +179,9 @@ fn transform_rule_and_plugin_name<'a>(
     if plugin_name == "vitest" && is_jest_rule_adapted_to_vitest(rule_name) {
         return (rule_name, "jest");
     }
+    if plugin_name == "node" && is_unicorn_rule_adapted_to_node(rule_name) {
+        return (rule_name, "unicorn");
+    }
 
     (rule_name, plugin_name)
 }
@@ -262,7 +270,7 @@ impl<'de> Deserialize<'de> for OxlintRules {
                     rules.push(ESLintRule { plugin_name, rule_name, severity, config });
                 }
 
-                Ok(OxlintRules { rules, unknown_rules: Vec::new() })
+                Ok(OxlintRules::new(rules))
             }
         }
 
@@ -286,6 +294,8 @@ fn parse_rule_key(name: &str) -> (String, String) {
         "@typescript-eslint" => ("typescript", rule_name),
         "jsx-a11y" => ("jsx_a11y", rule_name),
         "react-perf" => ("react_perf", rule_name),
+        // e.g. "n/no-deprecated-api" from `eslint-plugin-n`
+        "n" => ("node", rule_name),
         // e.g. "@next/next/google-font-display"
         "@next" => ("nextjs", rule_name.trim_start_matches("next/")),
         // For backwards compatibility, react hook rules reside in the react plugin.
@@ -364,7 +374,7 @@ mod test {
     use serde::Deserialize;
     use serde_json::{json, Value};
 
-    use super::{OxlintRules, RuleSet};
+    use super::{InvalidRuleOptions, OxlintRules, RuleSet};
 
     #[test]
     fn test_parse_rules() {
@@ -430,6 +440,28 @@ mod test {
         assert!(r.config.is_none());
     }
 
+    #[test]
+    fn test_invalid_rule_options() {
+        let config = json!({
+            "max-lines": ["error", { "max": 300, "skipComment": true }],
+            "react/no-unknown-property": ["warn", { "ignore": ["css"] }],
+            "no-console": ["error", { "foo": "bar" }],
+        });
+        let mut rules = OxlintRules::deserialize(&config).unwrap();
+        let mut rule_set = RuleSet::default();
+
+        rules.override_rules(&mut rule_set, &RULES);
+
+        assert_eq!(rule_set.len(), 3);
+        assert_eq!(
+            rules.invalid_options,
+            vec![InvalidRuleOptions {
+                rule_name: "max-lines".to_string(),
+                errors: vec!["unknown option `skipComment`".to_string()],
+            }]
+        );
+    }
+
     #[test]
     fn test_parse_rules_default() {
         let rules = OxlintRules::default();
@@ -487,6 +519,23 @@ mod test {
         assert!(rules.is_empty());
     }
 
+    #[test]
+    fn test_override_node_rule_adapted_from_unicorn() {
+        let mut rules = RuleSet::default();
+        let configs =
+            [json!({ "node/no-process-exit": "error" }), json!({ "n/no-process-exit": "error" })];
+
+        for config in configs {
+            rules.clear();
+            r#override(&mut rules, &config);
+
+            assert_eq!(rules.len(), 1, "{config:?}");
+            let rule = rules.iter().next().unwrap();
+            assert_eq!(rule.name(), "no-process-exit", "{config:?}");
+            assert_eq!(rule.plugin_name(), "unicorn", "{config:?}");
+        }
+    }
+
     #[test]
     fn test_override_plugin_prefix_duplicates() {
         let configs = [
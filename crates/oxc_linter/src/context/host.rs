@@ -1,14 +1,18 @@
+use oxc_diagnostics::{OxcDiagnostic, OxcFix};
 use oxc_semantic::Semantic;
-use oxc_span::SourceType;
+use oxc_span::{SourceType, Span};
 use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
 use crate::{
     config::{LintConfig, LintPlugins},
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{FixKind, Message},
+    external_plugin::{ExternalReport, ExternalRule},
+    fixer::{Fix, FixCategory, FixKind, Message},
     frameworks,
     options::LintOptions,
-    utils, FrameworkFlags, RuleWithSeverity,
+    package_json::PackageJson,
+    rules::RuleEnum,
+    utils, AllowWarnDeny, FrameworkFlags, RuleWithSeverity,
 };
 
 use super::{plugin_name_to_prefix, LintContext};
@@ -50,6 +54,14 @@ pub(crate) struct ContextHost<'a> {
     /// Set via the `--fix`, `--fix-suggestions`, and `--fix-dangerously` CLI
     /// flags.
     pub(super) fix: FixKind,
+    /// Categories of rules whose fixes are applied. Defaults to all categories.
+    ///
+    /// Set via the `--fix-kind` CLI flag.
+    pub(super) fix_category: FixCategory,
+    /// Whether fixes which are not applied are attached to their diagnostics.
+    ///
+    /// Set by output formats which include fixes, e.g. `--format json`.
+    pub(super) report_fixes: bool,
     /// Path to the file being linted.
     pub(super) file_path: Box<Path>,
     /// Global linter configuration, such as globals to include and the target
@@ -59,6 +71,9 @@ pub(crate) struct ContextHost<'a> {
     pub(super) frameworks: FrameworkFlags,
     /// A list of all available linter plugins.
     pub(super) plugins: LintPlugins,
+    /// The closest `package.json` file of the file being linted, if the import plugin is
+    /// enabled.
+    pub(super) package_json: Option<Arc<PackageJson>>,
 }
 
 impl<'a> ContextHost<'a> {
@@ -90,10 +105,13 @@ impl<'a> ContextHost<'a> {
             disable_directives,
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
             fix: options.fix,
+            fix_category: options.fix_category,
+            report_fixes: options.report_fixes,
             file_path,
             config,
             frameworks: options.framework_hints,
             plugins,
+            package_json: None,
         }
         .sniff_for_frameworks()
     }
@@ -113,6 +131,13 @@ impl<'a> ContextHost<'a> {
         self
     }
 
+    /// Set the closest `package.json` file of the file being linted.
+    #[inline]
+    pub(crate) fn with_package_json(mut self, package_json: Option<Arc<PackageJson>>) -> Self {
+        self.package_json = package_json;
+        self
+    }
+
     /// Shared reference to the [`Semantic`] analysis of the file.
     #[inline]
     pub fn semantic(&self) -> &Semantic<'a> {
@@ -142,6 +167,77 @@ impl<'a> ContextHost<'a> {
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
+    /// Add a problem reported by a rule of an external plugin, like
+    /// [`LintContext`] does for built-in rules.
+    ///
+    /// The fixes of external rules are treated as safe fixes which may affect
+    /// program behavior.
+    pub(crate) fn report_external(
+        &self,
+        plugin_name: &str,
+        rule: &ExternalRule,
+        report: ExternalReport,
+    ) {
+        let span = Span::new(report.range[0], report.range[1]);
+        let full_name = format!("{plugin_name}/{}", rule.name);
+        if self.disable_directives.contains(&full_name, span) {
+            return;
+        }
+
+        let mut error = OxcDiagnostic::warn(report.message)
+            .with_label(span)
+            .with_error_code(plugin_name.to_string(), rule.name.clone())
+            .with_severity(rule.severity.into());
+        if let Some(help) = report.help {
+            error = error.with_help(help);
+        }
+        let mut fix = None;
+        if let Some(report_fix) = report.fix {
+            let span = Span::new(report_fix.range[0], report_fix.range[1]);
+            if self.fix.can_apply(FixKind::SafeFix)
+                && self.fix_category.contains(FixCategory::Behavior)
+            {
+                fix = Some(Fix::new(report_fix.text, span));
+            } else if self.report_fixes {
+                error = error.with_fix(OxcFix::new(span, report_fix.text));
+            }
+        }
+
+        self.push_diagnostic(Message::new(error, fix));
+    }
+
+    /// Report the disable directives which did not suppress any diagnostic of the rules run so
+    /// far. Comments whose directives are all unused are removed by safe fixes.
+    pub(crate) fn report_unused_directives(&self, severity: AllowWarnDeny) {
+        let source_text = self.semantic.source_text();
+        for unused in self.disable_directives.unused_comments() {
+            let message = if unused.rules.is_empty() {
+                "Unused disable directive (no problems were reported).".to_string()
+            } else {
+                let rules = unused.rules.iter().map(|rule| format!("'{rule}'")).collect::<Vec<_>>();
+                let rules = rules.join(", ");
+                format!("Unused disable directive (no problems were reported from {rules}).")
+            };
+            let mut error =
+                OxcDiagnostic::warn(message).with_label(unused.span).with_severity(severity.into());
+            let mut fix = None;
+            if unused.is_unused {
+                error = error.with_help("Remove the directive comment.");
+                let span = removal_span(source_text, unused.span);
+                if self.fix.can_apply(FixKind::SafeFix)
+                    && self.fix_category.contains(FixCategory::Formatting)
+                {
+                    fix = Some(Fix::delete(span));
+                } else if self.report_fixes {
+                    error = error.with_fix(OxcFix::new(span, ""));
+                }
+            } else {
+                error = error.with_help("Remove the unused rules from the directive comment.");
+            }
+            self.push_diagnostic(Message::new(error, fix));
+        }
+    }
+
     /// Take ownership of all diagnostics collected during linting.
     pub fn take_diagnostics(&self) -> Vec<Message<'a>> {
         // NOTE: diagnostics are only ever borrowed here and in push_diagnostic.
@@ -163,6 +259,8 @@ impl<'a> ContextHost<'a> {
             current_plugin_prefix: plugin_name_to_prefix(plugin_name),
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: rule.rule.fix(),
+            current_rule_fix_category: rule.rule.fix_category(),
+            current_rule_is_custom: matches!(rule.rule, RuleEnum::Custom(_)),
             severity: rule.severity.into(),
         }
     }
@@ -177,6 +275,8 @@ impl<'a> ContextHost<'a> {
             current_plugin_prefix: "eslint",
             #[cfg(debug_assertions)]
             current_rule_fix_capabilities: crate::rule::RuleFixMeta::None,
+            current_rule_fix_category: FixCategory::Behavior,
+            current_rule_is_custom: false,
             severity: oxc_diagnostics::Severity::Warning,
         }
     }
@@ -227,6 +327,29 @@ impl<'a> ContextHost<'a> {
     }
 }
 
+/// The span to delete to remove the comment at `span`, with the whitespace separating it from
+/// the code on its line and, if the comment is on a line of its own, the line break after it.
+#[allow(clippy::cast_possible_truncation)] // for `as u32`
+fn removal_span(source_text: &str, span: Span) -> Span {
+    let before = &source_text[..span.start as usize];
+    let after = &source_text[span.end as usize..];
+    let line_start = before.trim_end_matches([' ', '\t']).len();
+    let rest = after.trim_start_matches([' ', '\t']);
+    let is_line_start = line_start == 0 || before[..line_start].ends_with('\n');
+    let is_line_end = rest.is_empty() || rest.starts_with('\n') || rest.starts_with("\r\n");
+    let trailing_whitespace = (after.len() - rest.len()) as u32;
+    match (is_line_start, is_line_end) {
+        (true, true) => {
+            let line_break = if rest.starts_with("\r\n") { 2 } else { u32::from(!rest.is_empty()) };
+            Span::new(line_start as u32, span.end + trailing_whitespace + line_break)
+        }
+        // `/* eslint-disable */ code`
+        (true, false) => Span::new(span.start, span.end + trailing_whitespace),
+        // `code // eslint-disable-line`
+        (false, _) => Span::new(line_start as u32, span.end),
+    }
+}
+
 impl<'a> From<ContextHost<'a>> for Vec<Message<'a>> {
     fn from(ctx_host: ContextHost<'a>) -> Self {
         ctx_host.diagnostics.into_inner()
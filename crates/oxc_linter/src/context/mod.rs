@@ -4,7 +4,7 @@ mod host;
 use std::{ops::Deref, path::Path, rc::Rc};
 
 use oxc_cfg::ControlFlowGraph;
-use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_diagnostics::{OxcDiagnostic, OxcFix, Severity};
 use oxc_semantic::Semantic;
 use oxc_span::{GetSpan, Span};
 
@@ -12,9 +12,9 @@ use oxc_span::{GetSpan, Span};
 use crate::rule::RuleFixMeta;
 use crate::{
     disable_directives::DisableDirectives,
-    fixer::{FixKind, Message, RuleFix, RuleFixer},
+    fixer::{FixCategory, FixKind, Message, RuleFix, RuleFixer},
     javascript_globals::GLOBALS,
-    AllowWarnDeny, FrameworkFlags, OxlintEnv, OxlintGlobals, OxlintSettings,
+    AllowWarnDeny, FrameworkFlags, OxlintEnv, OxlintGlobals, OxlintSettings, PackageJson,
 };
 
 pub(crate) use host::ContextHost;
@@ -43,6 +43,11 @@ pub struct LintContext<'a> {
     /// - Rule is fully auto-fixable [`RuleFixMeta::Fixable`]
     #[cfg(debug_assertions)]
     current_rule_fix_capabilities: RuleFixMeta,
+    /// Category of the changes made by the fixes of the current rule. See [`FixCategory`].
+    current_rule_fix_category: FixCategory,
+    /// Whether the current rule is a [`CustomRule`](crate::CustomRule), which has no page on the
+    /// website.
+    current_rule_is_custom: bool,
     /// Current rule severity. Allows for user severity overrides, e.g.
     /// ```json
     /// // .oxlintrc.json
@@ -131,6 +136,13 @@ impl<'a> LintContext<'a> {
         &self.parent.file_path
     }
 
+    /// The closest `package.json` file of the file being linted, found in its directory or one of
+    /// its ancestors. Only available when the import plugin is enabled.
+    #[inline]
+    pub fn package_json(&self) -> Option<&PackageJson> {
+        self.parent.package_json.as_deref()
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -179,15 +191,16 @@ impl<'a> LintContext<'a> {
         if self.parent.disable_directives.contains(self.current_rule_name, message.span()) {
             return;
         }
-        message.error = message
-            .error
-            .with_error_code(self.current_plugin_prefix, self.current_rule_name)
-            .with_url(format!(
+        message.error =
+            message.error.with_error_code(self.current_plugin_prefix, self.current_rule_name);
+        if !self.current_rule_is_custom {
+            message.error = message.error.with_url(format!(
                 "{}/{}/{}.html",
                 Self::WEBSITE_BASE_URL,
                 self.current_plugin_name,
                 self.current_rule_name
             ));
+        }
         if message.error.severity != self.severity {
             message.error = message.error.with_severity(self.severity);
         }
@@ -305,9 +318,17 @@ impl<'a> LintContext<'a> {
             (Some(message), None) => diagnostic.with_help(message.to_owned()),
             _ => diagnostic,
         };
-        if self.parent.fix.can_apply(rule_fix.kind()) && !rule_fix.is_empty() {
+        if rule_fix.is_empty() {
+            self.diagnostic(diagnostic);
+        } else if self.parent.fix.can_apply(rule_fix.kind())
+            && self.parent.fix_category.contains(self.current_rule_fix_category)
+        {
             let fix = rule_fix.into_fix(self.source_text());
             self.add_diagnostic(Message::new(diagnostic, Some(fix)));
+        } else if self.parent.report_fixes {
+            let fix = rule_fix.into_fix(self.source_text());
+            let fix = OxcFix::new(fix.span, fix.content.into_owned());
+            self.diagnostic(diagnostic.with_fix(fix));
         } else {
             self.diagnostic(diagnostic);
         }
@@ -347,4 +368,5 @@ const PLUGIN_PREFIXES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "vitest" => "eslint-plugin-vitest",
     "node" => "eslint-plugin-node",
     "security" => "oxc-security",
+    "regexp" => "eslint-plugin-regexp",
 };
@@ -0,0 +1,217 @@
+use std::{
+    fmt,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use oxc_semantic::SymbolId;
+use schemars::{gen::SchemaGenerator, schema::Schema};
+
+use crate::{
+    context::LintContext,
+    rules::{RuleEnum, RULES},
+    AstNode, FixCategory, RuleCategory, RuleFixMeta,
+};
+
+/// A lint rule which is not part of this crate, compiled into a custom linter binary.
+///
+/// Custom rules behave like built-in rules: they are configured with their plugin name as prefix,
+/// e.g. `"my-company/no-foo": "error"`, are enabled by filters like `-D my-company/no-foo` or
+/// `-W correctness`, and report diagnostics and fixes through [`LintContext`].
+///
+/// Register a rule with [`register_custom_rule`] before building a [`Linter`].
+///
+/// [`Linter`]: crate::Linter
+pub trait CustomRule: fmt::Debug + Send + Sync + 'static {
+    /// Name of the plugin the rule belongs to, e.g. `my-company`.
+    fn plugin_name(&self) -> &'static str;
+
+    /// Kebab-cased name of the rule, e.g. `no-foo`.
+    fn name(&self) -> &'static str;
+
+    fn category(&self) -> RuleCategory;
+
+    /// What kind of auto-fixing can this rule do?
+    fn fix(&self) -> RuleFixMeta {
+        RuleFixMeta::None
+    }
+
+    /// What do the fixes of this rule change? See [`FixCategory`].
+    fn fix_category(&self) -> FixCategory {
+        FixCategory::Behavior
+    }
+
+    fn documentation(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// JSON schema of each of the options of the rule, e.g. `{ "allow": ["bar"] }`, used for
+    /// editor completions and to report invalid options.
+    #[expect(unused_variables)]
+    fn schema(&self, gen: &mut SchemaGenerator) -> Option<Schema> {
+        None
+    }
+
+    /// Create the rule from its eslint json configuration, e.g. `[{ "allow": ["bar"] }]`.
+    fn from_configuration(&self, value: serde_json::Value) -> Box<dyn CustomRule>;
+
+    /// Visit each AST Node
+    #[expect(unused_variables)]
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {}
+
+    /// Visit each symbol
+    #[expect(unused_variables)]
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {}
+
+    /// Run only once. Useful for inspecting scopes and trivias etc.
+    #[expect(unused_variables)]
+    fn run_once(&self, ctx: &LintContext) {}
+}
+
+/// A registered [`CustomRule`], kept behind an [`Arc`] so that [`RuleEnum`] stays small.
+#[derive(Debug, Clone)]
+pub struct CustomRuleEntry(Arc<CustomRuleInner>);
+
+#[derive(Debug)]
+struct CustomRuleInner {
+    /// Follows the ids of the built-in rules.
+    id: usize,
+    rule: Box<dyn CustomRule>,
+}
+
+impl CustomRuleEntry {
+    pub fn id(&self) -> usize {
+        self.0.id
+    }
+
+    pub fn rule(&self) -> &dyn CustomRule {
+        &*self.0.rule
+    }
+
+    pub fn read_json(&self, value: serde_json::Value) -> Self {
+        let rule = self.0.rule.from_configuration(value);
+        Self(Arc::new(CustomRuleInner { id: self.0.id, rule }))
+    }
+}
+
+static CUSTOM_RULES: RwLock<Vec<RuleEnum>> = RwLock::new(Vec::new());
+
+/// Register a rule to be known to every [`Linter`] built afterwards.
+///
+/// # Example
+/// ```ignore
+/// fn main() {
+///     oxc_linter::register_custom_rule(Box::new(NoFoo::default()));
+///     // run the linter as usual
+/// }
+/// ```
+///
+/// # Panics
+/// If a rule with the same plugin and name is already registered, built in or not.
+///
+/// [`Linter`]: crate::Linter
+pub fn register_custom_rule(rule: Box<dyn CustomRule>) {
+    let mut custom_rules = CUSTOM_RULES.write().unwrap_or_else(PoisonError::into_inner);
+    let (plugin_name, name) = (rule.plugin_name(), rule.name());
+    assert!(
+        !RULES
+            .iter()
+            .chain(custom_rules.iter())
+            .any(|r| r.plugin_name() == plugin_name && r.name() == name),
+        "Rule `{plugin_name}/{name}` is already registered."
+    );
+    let id = RULES.len() + custom_rules.len();
+    custom_rules.push(RuleEnum::Custom(CustomRuleEntry(Arc::new(CustomRuleInner { id, rule }))));
+}
+
+/// All registered custom rules, in registration order.
+pub(crate) fn custom_rules() -> Vec<RuleEnum> {
+    CUSTOM_RULES.read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::{register_custom_rule, CustomRule};
+    use crate::{
+        context::LintContext, AstNode, FixKind, Fixer, LinterBuilder, Oxlintrc, RuleCategory,
+        RuleFixMeta,
+    };
+
+    /// Disallows references to `foo`, or to the configured name.
+    #[derive(Debug)]
+    struct NoFoo {
+        name: String,
+    }
+
+    impl CustomRule for NoFoo {
+        fn plugin_name(&self) -> &'static str {
+            "my-company"
+        }
+
+        fn name(&self) -> &'static str {
+            "no-foo"
+        }
+
+        fn category(&self) -> RuleCategory {
+            RuleCategory::Restriction
+        }
+
+        fn fix(&self) -> RuleFixMeta {
+            RuleFixMeta::Fixable(FixKind::Fix)
+        }
+
+        fn from_configuration(&self, value: serde_json::Value) -> Box<dyn CustomRule> {
+            let name = value.get(0).and_then(|v| v.get("name")).and_then(serde_json::Value::as_str);
+            Box::new(Self { name: name.unwrap_or("foo").to_string() })
+        }
+
+        fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+            let AstKind::IdentifierReference(ident) = node.kind() else { return };
+            if ident.name.as_str() == self.name {
+                ctx.diagnostic_with_fix(
+                    OxcDiagnostic::warn(format!("Do not use `{}`.", self.name))
+                        .with_label(ident.span),
+                    |fixer| fixer.replace(ident.span, "bar"),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_rule() {
+        register_custom_rule(Box::new(NoFoo { name: "foo".to_string() }));
+
+        let oxlintrc = Oxlintrc::deserialize(json!({
+            "rules": { "my-company/no-foo": ["error", { "name": "baz" }] }
+        }))
+        .unwrap();
+        let linter =
+            LinterBuilder::from_oxlintrc(true, oxlintrc).with_fix(FixKind::SafeFix).build();
+        assert_eq!(linter.number_of_rules(), 1);
+
+        let allocator = Allocator::default();
+        let source_text = "foo(); baz();";
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&program).semantic;
+        let messages = linter.run(Path::new("test.js"), Rc::new(semantic));
+
+        assert_eq!(messages.len(), 1);
+        let diagnostic = &messages[0].error;
+        assert_eq!(diagnostic.to_string(), "Do not use `baz`.");
+        assert_eq!(diagnostic.code.to_string(), "my-company(no-foo)");
+        assert_eq!(diagnostic.severity, oxc_diagnostics::Severity::Error);
+
+        let fixed = Fixer::new(source_text, messages).fix();
+        assert_eq!(fixed.fixed_code, "foo(); bar();");
+    }
+}
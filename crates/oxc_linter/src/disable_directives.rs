@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use oxc_ast::Comment;
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
@@ -9,6 +11,36 @@ enum DisabledRule<'a> {
     Single(&'a str),
 }
 
+impl DisabledRule<'_> {
+    fn matches(self, rule_name: &str) -> bool {
+        match self {
+            Self::All => true,
+            // Our rule name currently does not contain the prefix.
+            // For example, this will match `@typescript-eslint/no-var-requires` given
+            // our rule_name is `no-var-requires`.
+            Self::Single(name) => name.contains(rule_name),
+        }
+    }
+}
+
+/// A rule disabled over an interval, by the directive at `directive` in
+/// [`DisableDirectives::directives`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct DisabledInterval<'a> {
+    rule: DisabledRule<'a>,
+    directive: usize,
+}
+
+/// A rule, or all rules, disabled by a comment.
+#[derive(Debug)]
+struct DisableDirective<'a> {
+    /// Span of the comment, with its delimiters
+    comment_span: Span,
+    rule: DisabledRule<'a>,
+    /// Whether the directive suppressed a diagnostic
+    used: Cell<bool>,
+}
+
 /// A comment which disables one or more specific rules
 #[derive(Debug)]
 pub struct DisableRuleComment<'a> {
@@ -18,9 +50,22 @@ pub struct DisableRuleComment<'a> {
     pub rules: Vec<&'a str>,
 }
 
+/// A comment with directives which did not suppress any diagnostic.
+#[derive(Debug)]
+pub struct UnusedDisableComment<'a> {
+    /// Span of the comment, with its delimiters
+    pub span: Span,
+    /// Unused rules disabled by the comment, empty if the comment disables all rules
+    pub rules: Vec<&'a str>,
+    /// Whether none of the rules disabled by the comment are used, so the comment can be removed
+    pub is_unused: bool,
+}
+
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
+    intervals: Lapper<u32, DisabledInterval<'a>>,
+    /// All the directives, in the order of their comments
+    directives: Box<[DisableDirective<'a>]>,
     /// Spans of comments that disable all rules
     disable_all_comments: Box<[Span]>,
     /// All comments that disable one or more specific rules
@@ -28,14 +73,16 @@ pub struct DisableDirectives<'a> {
 }
 
 impl<'a> DisableDirectives<'a> {
-    pub fn contains(&self, rule_name: &'static str, span: Span) -> bool {
-        self.intervals.find(span.start, span.end).any(|interval| {
-            interval.val == DisabledRule::All
-                // Our rule name currently does not contain the prefix.
-                // For example, this will match `@typescript-eslint/no-var-requires` given
-                // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+    /// Whether `rule_name` is disabled at `span`. The directives disabling it are marked as used.
+    pub fn contains(&self, rule_name: &str, span: Span) -> bool {
+        let mut contains = false;
+        for interval in self.intervals.find(span.start, span.end) {
+            if interval.val.rule.matches(rule_name) {
+                self.directives[interval.val.directive].used.set(true);
+                contains = true;
+            }
+        }
+        contains
     }
 
     pub fn disable_all_comments(&self) -> &[Span] {
@@ -45,15 +92,38 @@ impl<'a> DisableDirectives<'a> {
     pub fn disable_rule_comments(&self) -> &[DisableRuleComment<'a>] {
         &self.disable_rule_comments
     }
+
+    /// The comments with directives which did not suppress any diagnostic so far.
+    pub fn unused_comments(&self) -> Vec<UnusedDisableComment<'a>> {
+        self.directives
+            .chunk_by(|a, b| a.comment_span == b.comment_span)
+            .filter_map(|directives| {
+                let unused = directives.iter().filter(|directive| !directive.used.get());
+                let count = unused.clone().count();
+                (count > 0).then(|| UnusedDisableComment {
+                    span: directives[0].comment_span,
+                    rules: unused
+                        .filter_map(|directive| match directive.rule {
+                            DisabledRule::All => None,
+                            DisabledRule::Single(rule) => Some(rule),
+                        })
+                        .collect(),
+                    is_unused: count == directives.len(),
+                })
+            })
+            .collect()
+    }
 }
 
 pub struct DisableDirectivesBuilder<'a> {
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable` or `oxlint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable` or `oxlint-disable` rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    intervals: Lapper<u32, DisabledInterval<'a>>,
+    /// All the directives, in the order of their comments
+    directives: Vec<DisableDirective<'a>>,
+    /// Start and directive of `eslint-disable` or `oxlint-disable`
+    disable_all_start: Option<(u32, usize)>,
+    /// Start and directive of `eslint-disable` or `oxlint-disable` rule_name`
+    disable_start_map: FxHashMap<&'a str, (u32, usize)>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -64,6 +134,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
     pub fn new() -> Self {
         Self {
             intervals: Lapper::new(vec![]),
+            directives: vec![],
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
             disable_all_comments: vec![],
@@ -75,13 +146,21 @@ impl<'a> DisableDirectivesBuilder<'a> {
         self.build_impl(source_text, comments);
         DisableDirectives {
             intervals: self.intervals,
+            directives: self.directives.into_boxed_slice(),
             disable_all_comments: self.disable_all_comments.into_boxed_slice(),
             disable_rule_comments: self.disable_rule_comments.into_boxed_slice(),
         }
     }
 
-    fn add_interval(&mut self, start: u32, stop: u32, val: DisabledRule<'a>) {
-        self.intervals.insert(Interval { start, stop, val });
+    /// Add a directive of the comment at `comment_span`, returning its index.
+    fn add_directive(&mut self, comment_span: Span, rule: DisabledRule<'a>) -> usize {
+        self.directives.push(DisableDirective { comment_span, rule, used: Cell::new(false) });
+        self.directives.len() - 1
+    }
+
+    fn add_interval(&mut self, start: u32, stop: u32, directive: usize) {
+        let rule = self.directives[directive].rule;
+        self.intervals.insert(Interval { start, stop, val: DisabledInterval { rule, directive } });
     }
 
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
@@ -92,6 +171,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
         // Wrongly ordered matching pairs are not taken into consideration.
         for comment in comments {
             let span = comment.content_span();
+            let comment_span = comment.span;
             let text = span.source_text(source_text);
             let text = text.trim_start();
 
@@ -100,8 +180,9 @@ impl<'a> DisableDirectivesBuilder<'a> {
             {
                 // `eslint-disable`
                 if text.trim().is_empty() {
+                    let directive = self.add_directive(comment_span, DisabledRule::All);
                     if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
+                        self.disable_all_start = Some((span.end, directive));
                     }
                     self.disable_all_comments.push(span);
                     continue;
@@ -114,13 +195,16 @@ impl<'a> DisableDirectivesBuilder<'a> {
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        let directive = self.add_directive(comment_span, DisabledRule::All);
+                        self.add_interval(span.end, stop, directive);
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
+                            let directive =
+                                self.add_directive(comment_span, DisabledRule::Single(rule_name));
+                            self.add_interval(span.end, stop, directive);
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -138,13 +222,16 @@ impl<'a> DisableDirectivesBuilder<'a> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        let directive = self.add_directive(comment_span, DisabledRule::All);
+                        self.add_interval(start, stop, directive);
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
+                            let directive =
+                                self.add_directive(comment_span, DisabledRule::Single(rule_name));
+                            self.add_interval(start, stop, directive);
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -157,7 +244,9 @@ impl<'a> DisableDirectivesBuilder<'a> {
                     // `eslint-disable rule-name1, rule-name2`
                     let mut rules = vec![];
                     Self::get_rule_names(text, |rule_name| {
-                        self.disable_start_map.entry(rule_name).or_insert(span.end);
+                        let directive =
+                            self.add_directive(comment_span, DisabledRule::Single(rule_name));
+                        self.disable_start_map.entry(rule_name).or_insert((span.end, directive));
                         rules.push(rule_name);
                     });
                     self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -170,14 +259,14 @@ impl<'a> DisableDirectivesBuilder<'a> {
             {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some((start, directive)) = self.disable_all_start.take() {
+                        self.add_interval(start, span.start, directive);
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                        if let Some((start, directive)) = self.disable_start_map.remove(rule_name) {
+                            self.add_interval(start, span.start, directive);
                         }
                     });
                 }
@@ -186,14 +275,14 @@ impl<'a> DisableDirectivesBuilder<'a> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some((start, directive)) = self.disable_all_start {
+            self.add_interval(start, source_len, directive);
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (_, (start, directive)) in disable_start_map {
+            self.add_interval(start, source_len, directive);
         }
     }
 
@@ -602,3 +691,40 @@ semi*/
         Tester::new("no-debugger", pass, fail).intentionally_allow_no_fix_tests().test();
     }
 }
+
+#[test]
+fn test_unused_comments() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source_text = "
+        // eslint-disable-next-line no-debugger, no-console
+        debugger;
+        debugger; // eslint-disable-line
+        /* eslint-disable no-alert */
+    ";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let directives = DisableDirectivesBuilder::new().build(source_text, &ret.program.comments);
+
+    let debugger = |line: usize| {
+        let start = source_text.match_indices("debugger;").nth(line).unwrap().0;
+        let start = u32::try_from(start).unwrap();
+        Span::new(start, start + 9)
+    };
+    assert!(directives.contains("no-debugger", debugger(0)));
+
+    let unused = directives.unused_comments();
+    assert_eq!(unused.len(), 3);
+    assert_eq!(unused[0].rules, vec!["no-console"]);
+    assert!(!unused[0].is_unused);
+    assert!(unused[1].rules.is_empty());
+    assert!(unused[1].is_unused);
+    assert_eq!(unused[2].span.source_text(source_text), "/* eslint-disable no-alert */");
+    assert!(unused[2].is_unused);
+
+    // Reporting a diagnostic in the line marks `eslint-disable-line` as used.
+    assert!(directives.contains("no-debugger", debugger(1)));
+    assert_eq!(directives.unused_comments().len(), 2);
+}
@@ -0,0 +1,207 @@
+use std::{fmt, path::Path, sync::Arc};
+
+use oxc_ast::{AstKind, ESTreeOptions};
+use serde::Deserialize;
+
+use crate::{context::ContextHost, AllowWarnDeny};
+
+/// A plugin of rules which are not built into the linter, e.g. a host which runs ESLint plugins
+/// in a JavaScript or WASM runtime.
+///
+/// The rules of a plugin are configured like built-in rules, prefixed with the name of the
+/// plugin, e.g. `"my-plugin/no-foo": "error"`. Register a plugin with
+/// [`LinterBuilder::with_external_plugin`].
+///
+/// [`LinterBuilder::with_external_plugin`]: crate::LinterBuilder::with_external_plugin
+pub trait ExternalPlugin: Send + Sync {
+    /// Name of the plugin, e.g. `my-plugin` or `@scope/my-plugin`.
+    fn name(&self) -> &str;
+
+    /// Whether the plugin has a rule, e.g. `no-foo`.
+    fn has_rule(&self, rule_name: &str) -> bool;
+
+    /// Run the enabled `rules` of the plugin on a file, returning the problems they report.
+    fn lint(&self, file: &ExternalFile<'_>, rules: &[ExternalRule]) -> Vec<ExternalReport>;
+}
+
+/// A file to lint with an [`ExternalPlugin`].
+pub struct ExternalFile<'a> {
+    pub path: &'a Path,
+    pub source_text: &'a str,
+    /// The AST as ESTree JSON, with a `range` and a `loc` on every node. Ranges are byte offsets
+    /// into `source_text`.
+    pub program: &'a str,
+}
+
+/// A configured rule of an [`ExternalPlugin`].
+#[derive(Debug, Clone)]
+pub struct ExternalRule {
+    /// Name of the rule without the plugin prefix, e.g. `no-foo`.
+    pub name: String,
+    /// Options of the rule, like `context.options` in ESLint.
+    ///
+    /// e.g. `[{ "allow": ["bar"] }]` for `"my-plugin/no-foo": ["error", { "allow": ["bar"] }]`.
+    pub options: Vec<serde_json::Value>,
+    pub severity: AllowWarnDeny,
+}
+
+/// A problem reported by an [`ExternalRule`].
+///
+/// Shaped like the descriptor of `context.report` in ESLint, so that it can be deserialized from
+/// the JSON of a JavaScript host.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalReport {
+    /// Name of the rule, with or without the plugin prefix.
+    pub rule_id: String,
+    pub message: String,
+    /// Byte offsets of the start and end of the problem.
+    pub range: [u32; 2],
+    #[serde(default)]
+    pub help: Option<String>,
+    #[serde(default)]
+    pub fix: Option<ExternalFix>,
+}
+
+/// A fix of an [`ExternalReport`], replacing `range` with `text`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExternalFix {
+    pub range: [u32; 2],
+    pub text: String,
+}
+
+/// The configured rules of an [`ExternalPlugin`].
+#[derive(Clone)]
+pub(crate) struct ExternalPluginRules {
+    pub plugin: Arc<dyn ExternalPlugin>,
+    pub rules: Vec<ExternalRule>,
+}
+
+impl fmt::Debug for ExternalPluginRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalPluginRules")
+            .field("plugin", &self.plugin.name())
+            .field("rules", &self.rules)
+            .finish()
+    }
+}
+
+impl ExternalPluginRules {
+    /// The rule of a report, accepting ESLint's `plugin/rule` ids.
+    fn find_rule(&self, rule_id: &str) -> Option<&ExternalRule> {
+        let name = rule_id
+            .strip_prefix(self.plugin.name())
+            .and_then(|name| name.strip_prefix('/'))
+            .unwrap_or(rule_id);
+        self.rules.iter().find(|rule| rule.name == name)
+    }
+}
+
+/// Run the rules of external plugins on the file of `ctx_host`, serializing its AST once for all
+/// of them.
+pub(crate) fn run_external_plugins(plugins: &[ExternalPluginRules], ctx_host: &ContextHost<'_>) {
+    let semantic = ctx_host.semantic();
+    let Some(AstKind::Program(program)) = semantic.nodes().root_node().map(|node| node.kind())
+    else {
+        return;
+    };
+    let program =
+        program.to_estree_json(ESTreeOptions { ranges: true, locations: true, utf16: false });
+    let file = ExternalFile {
+        path: ctx_host.file_path(),
+        source_text: semantic.source_text(),
+        program: &program,
+    };
+
+    for plugin_rules in plugins {
+        for report in plugin_rules.plugin.lint(&file, &plugin_rules.rules) {
+            if let Some(rule) = plugin_rules.find_rule(&report.rule_id) {
+                ctx_host.report_external(plugin_rules.plugin.name(), rule, report);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::{ExternalFile, ExternalPlugin, ExternalReport, ExternalRule};
+    use crate::{FixKind, Fixer, LinterBuilder, Oxlintrc};
+
+    /// Reports every `debugger` statement, like `no-debugger`.
+    struct TestPlugin;
+
+    impl ExternalPlugin for TestPlugin {
+        fn name(&self) -> &str {
+            "test"
+        }
+
+        fn has_rule(&self, rule_name: &str) -> bool {
+            rule_name == "no-debugger"
+        }
+
+        fn lint(&self, file: &ExternalFile<'_>, rules: &[ExternalRule]) -> Vec<ExternalReport> {
+            assert_eq!(rules.len(), 1);
+            assert_eq!(rules[0].options, vec![json!({ "fix": true })]);
+
+            let program: serde_json::Value = serde_json::from_str(file.program).unwrap();
+            let body = program["body"].as_array().unwrap();
+            body.iter()
+                .filter(|stmt| stmt["type"] == "DebuggerStatement")
+                .map(|stmt| {
+                    let range = &stmt["range"];
+                    let report = json!({
+                        "ruleId": "test/no-debugger",
+                        "message": "Unexpected debugger statement",
+                        "range": range,
+                        "fix": { "range": range, "text": "" },
+                    });
+                    ExternalReport::deserialize(report).unwrap()
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_external_plugin() {
+        let oxlintrc = Oxlintrc::deserialize(json!({
+            "rules": {
+                "test/no-debugger": ["error", { "fix": true }],
+                "test/no-console": "error",
+            }
+        }))
+        .unwrap();
+        let linter = LinterBuilder::from_oxlintrc(true, oxlintrc)
+            .with_external_plugin(Arc::new(TestPlugin))
+            .with_fix(FixKind::SafeFix)
+            .build();
+        assert_eq!(linter.number_of_rules(), 1);
+
+        let allocator = Allocator::default();
+        let source_text =
+            "foo();\ndebugger;\n// eslint-disable-next-line test/no-debugger\ndebugger;\n";
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&program).semantic;
+        let messages = linter.run(Path::new("test.js"), Rc::new(semantic));
+
+        assert_eq!(messages.len(), 1);
+        let diagnostic = &messages[0].error;
+        assert_eq!(diagnostic.to_string(), "Unexpected debugger statement");
+        assert_eq!(diagnostic.code.to_string(), "test(no-debugger)");
+        assert_eq!(diagnostic.severity, oxc_diagnostics::Severity::Error);
+
+        let fixed = Fixer::new(source_text, messages).fix();
+        assert_eq!(
+            fixed.fixed_code,
+            "foo();\n\n// eslint-disable-next-line test/no-debugger\ndebugger;\n"
+        );
+    }
+}
@@ -114,6 +114,64 @@ impl FixKind {
     }
 }
 
+bitflags! {
+    /// Categories of the changes made by the fixes of a lint rule.
+    ///
+    /// Each rule declares one category in `declare_oxc_lint`. A set of
+    /// categories is used by the `LintService` to only apply the fixes of some
+    /// rules, e.g. so that bots can commit low-risk fixes and leave the others
+    /// to humans.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct FixCategory: u8 {
+        /// Only changes how code is written, e.g. whitespace, escapes or the
+        /// case of number literals.
+        const Formatting = 1 << 0;
+        /// Only changes import and export declarations.
+        const ImportHygiene = 1 << 1;
+        /// Rewrites code into an equivalent form, e.g. `Array<T>` into `T[]`.
+        const Style = 1 << 2;
+        /// May affect program behavior. This is the category of rules which do
+        /// not declare one.
+        const Behavior = 1 << 3;
+    }
+}
+
+/// All categories are applied by default.
+impl Default for FixCategory {
+    #[inline]
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl FixCategory {
+    /// Names of the categories, as used by `--fix-kind`.
+    pub const NAMES: [&'static str; 4] = ["formatting", "import-hygiene", "style", "behavior"];
+
+    /// Get a category by its name, e.g. `import-hygiene`.
+    pub fn parse_kind(name: &str) -> Option<Self> {
+        match name {
+            "formatting" => Some(Self::Formatting),
+            "import-hygiene" => Some(Self::ImportHygiene),
+            "style" => Some(Self::Style),
+            "behavior" => Some(Self::Behavior),
+            _ => None,
+        }
+    }
+
+    /// # Panics
+    /// If this [`FixCategory`] is not a single category.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Formatting => "formatting",
+            Self::ImportHygiene => "import-hygiene",
+            Self::Style => "style",
+            Self::Behavior => "behavior",
+            _ => panic!("{self:?} is not a single fix category"),
+        }
+    }
+}
+
 // TODO: rename
 #[derive(Debug, Default)]
 #[must_use = "Fixes must be used. If you don't need a fix, use `LintContext::diagnostic`, or create an empty fix using `RuleFixer::noop`."]
@@ -2,9 +2,9 @@ mod fix;
 
 use std::borrow::Cow;
 
-pub use fix::{CompositeFix, Fix, FixKind, RuleFix};
+pub use fix::{CompositeFix, Fix, FixCategory, FixKind, RuleFix};
 use oxc_codegen::{CodeGenerator, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, OxcFix};
 use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
@@ -252,7 +252,13 @@ impl<'a> Message<'a> {
 impl From<Message<'_>> for OxcDiagnostic {
     #[inline]
     fn from(message: Message) -> Self {
-        message.error
+        // The fix was not applied, e.g. because it conflicts with another fix.
+        match message.fix {
+            Some(fix) if !fix.span.is_unspanned() || !fix.content.is_empty() => {
+                message.error.with_fix(OxcFix::new(fix.span, fix.content.into_owned()))
+            }
+            _ => message.error,
+        }
     }
 }
 
@@ -1,3 +1,9 @@
+//! The linter behind `oxlint`.
+//!
+//! To embed it in another tool, build a [`Linter`] with a [`LinterBuilder`], e.g. from a
+//! configuration parsed by [`Oxlintrc::from_json`], and lint source text or files with a
+//! [`LintService`], which returns the diagnostics and fixes as a [`LintResult`].
+
 #![allow(clippy::self_named_module_files)] // for rules.rs
 
 #[cfg(test)]
@@ -7,12 +13,15 @@ mod ast_util;
 mod builder;
 mod config;
 mod context;
+mod custom_rule;
 mod disable_directives;
+mod external_plugin;
 mod fixer;
 mod frameworks;
 mod globals;
 mod javascript_globals;
 mod options;
+mod package_json;
 mod rule;
 mod rules;
 mod service;
@@ -21,27 +30,41 @@ mod utils;
 pub mod loader;
 pub mod table;
 
-use std::{io::Write, path::Path, rc::Rc, sync::Arc};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
 
 use config::LintConfig;
 use context::ContextHost;
+use globset::GlobSet;
 use options::LintOptions;
 use oxc_semantic::{AstNode, Semantic};
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use sha1::{Digest, Sha1};
 use utils::iter_possible_jest_call_node;
 
 pub use crate::{
     builder::{LinterBuilder, LinterBuilderError},
     config::{ESLintRule, LintPlugins, Oxlintrc},
     context::LintContext,
-    fixer::FixKind,
+    custom_rule::{register_custom_rule, CustomRule},
+    external_plugin::{ExternalFile, ExternalFix, ExternalPlugin, ExternalReport, ExternalRule},
+    fixer::{FixCategory, FixKind},
     frameworks::FrameworkFlags,
-    options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
+    options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind, RuleQuery},
+    package_json::PackageJson,
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
-    service::{LintService, LintServiceOptions},
+    service::{LintBaseline, LintResult, LintService, LintServiceOptions, ModuleResolver},
 };
 use crate::{
     config::{OxlintEnv, OxlintGlobals, OxlintSettings},
+    external_plugin::{run_external_plugins, ExternalPluginRules},
     fixer::{Fixer, Message},
+    package_json::PackageJsonCache,
     rules::RuleEnum,
     table::RuleTable,
 };
@@ -60,6 +83,35 @@ pub struct Linter {
     rules: Vec<RuleWithSeverity>,
     options: LintOptions,
     config: Arc<LintConfig>,
+    /// Configurations of nested `.oxlintrc.json` files, deepest directories first.
+    nested_configs: Vec<NestedConfig>,
+    /// Overrides of the files matching globs, in the order they are applied.
+    overrides: Vec<ConfigOverride>,
+    external_plugins: Vec<ExternalPluginRules>,
+    /// The `package.json` files of the linted files, for the import plugin.
+    package_jsons: PackageJsonCache,
+}
+
+/// The rules and configuration to lint the files in `directory` and its subdirectories with,
+/// resolved from a nested configuration file and those of its parent directories.
+#[derive(Debug)]
+pub(crate) struct NestedConfig {
+    pub(crate) directory: PathBuf,
+    pub(crate) rules: Vec<RuleWithSeverity>,
+    pub(crate) config: Arc<LintConfig>,
+}
+
+/// An override of the rules and configuration of the files matching `globs`, applied on top of
+/// the rules and configuration they are linted with.
+#[derive(Debug)]
+pub(crate) struct ConfigOverride {
+    pub(crate) globs: GlobSet,
+    /// Rules enabled or configured by the override, replacing the rules they are applied to.
+    pub(crate) rules: Vec<RuleWithSeverity>,
+    /// IDs of the rules turned off by the override.
+    pub(crate) disabled_rules: FxHashSet<usize>,
+    pub(crate) env: Option<OxlintEnv>,
+    pub(crate) globals: Option<OxlintGlobals>,
 }
 
 impl Default for Linter {
@@ -73,8 +125,21 @@ impl Linter {
         rules: Vec<RuleWithSeverity>,
         options: LintOptions,
         config: LintConfig,
+        mut nested_configs: Vec<NestedConfig>,
+        overrides: Vec<ConfigOverride>,
+        external_plugins: Vec<ExternalPluginRules>,
     ) -> Self {
-        Self { rules, options, config: Arc::new(config) }
+        nested_configs
+            .sort_by_key(|nested| std::cmp::Reverse(nested.directory.components().count()));
+        Self {
+            rules,
+            options,
+            config: Arc::new(config),
+            nested_configs,
+            overrides,
+            external_plugins,
+            package_jsons: PackageJsonCache::default(),
+        }
     }
 
     #[cfg(test)]
@@ -100,12 +165,46 @@ impl Linter {
         self
     }
 
+    /// Only apply the fixes of rules in these categories. Defaults to all categories.
+    #[must_use]
+    pub fn with_fix_category(mut self, category: FixCategory) -> Self {
+        self.options.fix_category = category;
+        self
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
 
+    /// The source type to parse `path` with, `path` being relative to the current working
+    /// directory. See [`OxlintParserOverrides`].
+    ///
+    /// [`OxlintParserOverrides`]: crate::config::OxlintParserOverrides
+    pub(crate) fn source_type(&self, path: &Path, source_type: SourceType) -> SourceType {
+        self.config.parser_overrides.source_type(path, source_type)
+    }
+
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
+            + self.external_plugins.iter().map(|plugin| plugin.rules.len()).sum::<usize>()
+    }
+
+    /// Hash of the rules and configurations files are linted with, identifying the results of a
+    /// [`LintService`] cache. Fix options are not included, as they do not change which files
+    /// are linted without diagnostics.
+    pub(crate) fn config_hash(&self) -> String {
+        let mut hasher = Sha1::new();
+        hasher.update(format!(
+            "{:?}{:?}{:?}{:?}{:?}{:?}{:?}",
+            self.rules,
+            self.config,
+            self.nested_configs,
+            self.overrides,
+            self.external_plugins,
+            self.options.framework_hints,
+            self.options.report_unused_directives,
+        ));
+        format!("{:x}", hasher.finalize())
     }
 
     #[cfg(test)]
@@ -113,20 +212,86 @@ impl Linter {
         &self.rules
     }
 
+    /// The rules and configuration to lint `path` with: those of the closest nested
+    /// configuration whose directory contains `path`, or the root ones.
+    fn resolve(&self, path: &Path) -> (&[RuleWithSeverity], &Arc<LintConfig>) {
+        self.nested_configs
+            .iter()
+            .find(|nested| path.starts_with(&nested.directory))
+            .map_or((self.rules.as_slice(), &self.config), |nested| {
+                (nested.rules.as_slice(), &nested.config)
+            })
+    }
+
+    /// Apply the overrides matching `relative_path` to `rules` and `config`, returning `None` if
+    /// no override matches.
+    fn apply_overrides(
+        &self,
+        relative_path: &Path,
+        rules: &[RuleWithSeverity],
+        config: &LintConfig,
+    ) -> Option<(Vec<RuleWithSeverity>, Arc<LintConfig>)> {
+        let mut overrides = self
+            .overrides
+            .iter()
+            .filter(|config_override| config_override.globs.is_match(relative_path))
+            .peekable();
+        overrides.peek()?;
+
+        let mut rules = rules.to_vec();
+        let mut config = config.clone();
+        for config_override in overrides {
+            rules.retain(|rule| {
+                !config_override.disabled_rules.contains(&rule.id())
+                    && !config_override.rules.contains(rule)
+            });
+            rules.extend(config_override.rules.iter().cloned());
+            if let Some(env) = &config_override.env {
+                config.env.extend(env.clone());
+            }
+            if let Some(globals) = &config_override.globals {
+                config.globals.extend(globals.clone());
+            }
+        }
+        rules.sort_unstable_by_key(|r| r.id());
+        Some((rules, Arc::new(config)))
+    }
+
     pub fn run<'a>(&self, path: &Path, semantic: Rc<Semantic<'a>>) -> Vec<Message<'a>> {
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, self.options, Arc::clone(&self.config)));
+        self.run_relative(path, path, semantic)
+    }
 
-        let rules = self
-            .rules
+    /// Lint `path` like [`Linter::run`], matching the globs of overrides against `relative_path`,
+    /// the path relative to the current working directory.
+    pub(crate) fn run_relative<'a>(
+        &self,
+        path: &Path,
+        relative_path: &Path,
+        semantic: Rc<Semantic<'a>>,
+    ) -> Vec<Message<'a>> {
+        let (rules, config) = self.resolve(path);
+        let overridden = self.apply_overrides(relative_path, rules, config);
+        let (rules, config) = overridden
+            .as_ref()
+            .map_or((rules, config), |(rules, config)| (rules.as_slice(), config));
+        let package_json = config
+            .plugins
+            .has_import()
+            .then(|| path.parent().and_then(|directory| self.package_jsons.find(directory)))
+            .flatten();
+        let ctx_host = Rc::new(
+            ContextHost::new(path, semantic, self.options, Arc::clone(config))
+                .with_package_json(package_json),
+        );
+
+        let rules = rules
             .iter()
             .filter(|rule| rule.should_run(&ctx_host))
             .map(|rule| (rule, Rc::clone(&ctx_host).spawn(rule)));
 
         let semantic = ctx_host.semantic();
 
-        let should_run_on_jest_node =
-            self.config.plugins.has_test() && ctx_host.frameworks().is_test();
+        let should_run_on_jest_node = config.plugins.has_test() && ctx_host.frameworks().is_test();
 
         // IMPORTANT: We have two branches here for performance reasons:
         //
@@ -193,6 +358,14 @@ impl Linter {
             }
         }
 
+        if !self.external_plugins.is_empty() {
+            run_external_plugins(&self.external_plugins, &ctx_host);
+        }
+
+        if let Some(severity) = self.options.report_unused_directives {
+            ctx_host.report_unused_directives(severity);
+        }
+
         ctx_host.take_diagnostics()
     }
 
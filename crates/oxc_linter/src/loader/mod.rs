@@ -98,6 +98,8 @@ mod test {
             "foo.astro",
             "foo.svelte",
             "foo.vue",
+            "foo.md",
+            "foo.html",
         ];
 
         for path in paths {
@@ -0,0 +1,152 @@
+use memchr::memmem::Finder;
+use oxc_span::SourceType;
+
+use super::{find_script_closing_angle, SCRIPT_END, SCRIPT_START};
+use crate::loader::JavaScriptSource;
+
+/// `type` attributes of `<script>` tags which contain JavaScript.
+/// <https://html.spec.whatwg.org/multipage/scripting.html#javascript-mime-type>
+const JAVASCRIPT_TYPES: &[&str] = &[
+    "",
+    "module",
+    "text/javascript",
+    "application/javascript",
+    "text/ecmascript",
+    "application/ecmascript",
+];
+
+pub struct HtmlPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> HtmlPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    /// Each `<script>` tag which contains JavaScript is linted, as a module for
+    /// `<script type="module">`, and as a script otherwise. Tags of other types, e.g.
+    /// `<script type="application/json">`, are not.
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let script_start_finder = Finder::new(SCRIPT_START);
+        let script_end_finder = Finder::new(SCRIPT_END);
+
+        let mut results = vec![];
+        let mut pointer = 0;
+
+        loop {
+            // find opening "<script"
+            let Some(offset) = script_start_finder.find(self.source_text[pointer..].as_bytes())
+            else {
+                break;
+            };
+            pointer += offset + SCRIPT_START.len();
+
+            // find closing ">"
+            let Some(offset) = find_script_closing_angle(self.source_text, pointer) else {
+                break;
+            };
+            let attributes = &self.source_text[pointer..pointer + offset];
+            pointer += offset + 1;
+            let js_start = pointer;
+
+            // find "</script>"
+            let Some(offset) = script_end_finder.find(self.source_text[pointer..].as_bytes())
+            else {
+                break;
+            };
+            let js_end = pointer + offset;
+            pointer += offset + SCRIPT_END.len();
+
+            let Some(source_type) = Self::source_type(attributes) else {
+                continue;
+            };
+            // NOTE: loader checked that source_text.len() is less than u32::MAX
+            #[allow(clippy::cast_possible_truncation)]
+            results.push(JavaScriptSource::partial(
+                &self.source_text[js_start..js_end],
+                source_type,
+                js_start as u32,
+            ));
+        }
+        results
+    }
+
+    /// The source type of a `<script>` tag with `attributes`, or `None` if it does not contain
+    /// JavaScript.
+    fn source_type(attributes: &str) -> Option<SourceType> {
+        let Some(script_type) = Self::type_attribute(attributes) else {
+            return Some(SourceType::cjs());
+        };
+        let script_type = script_type.trim().to_ascii_lowercase();
+        if !JAVASCRIPT_TYPES.contains(&script_type.as_str()) {
+            return None;
+        }
+        Some(if script_type == "module" { SourceType::mjs() } else { SourceType::cjs() })
+    }
+
+    /// The value of the `type` attribute in `attributes`, e.g. `module` for ` type="module"`.
+    fn type_attribute(attributes: &str) -> Option<&str> {
+        let mut rest = attributes;
+        while let Some(offset) = rest.find("type") {
+            let is_attribute_start =
+                rest[..offset].chars().next_back().map_or(true, char::is_whitespace);
+            rest = &rest[offset + "type".len()..];
+            if !is_attribute_start {
+                continue;
+            }
+            let Some(value) = rest.trim_start().strip_prefix('=') else {
+                continue;
+            };
+            let value = value.trim_start();
+            return Some(match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let value = &value[1..];
+                    &value[..value.find(quote).unwrap_or(value.len())]
+                }
+                _ => &value[..value.find(char::is_whitespace).unwrap_or(value.len())],
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HtmlPartialLoader, JavaScriptSource};
+
+    fn parse_html(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        HtmlPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let source_text = r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <script src="a.js"></script>
+            <script type="importmap">{ "imports": {} }</script>
+            <script type="module">import a from "a";</script>
+          </head>
+          <body>
+            <script>console.log("hi");</script>
+            <script data-type="text/template" type='text/javascript'>debugger;</script>
+          </body>
+        </html>
+        "#;
+
+        let sources = parse_html(source_text);
+        assert_eq!(sources.len(), 4);
+        assert_eq!(sources[0].source_text, "");
+        assert_eq!(sources[1].source_text, r#"import a from "a";"#);
+        assert!(sources[1].source_type.is_module());
+        assert_eq!(sources[2].source_text, r#"console.log("hi");"#);
+        assert!(sources[2].source_type.is_script());
+        assert_eq!(sources[3].source_text, "debugger;");
+        for source in sources {
+            let start = source.start as usize;
+            assert_eq!(&source_text[start..start + source.source_text.len()], source.source_text);
+        }
+    }
+}
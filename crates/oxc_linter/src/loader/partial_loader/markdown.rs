@@ -0,0 +1,165 @@
+use oxc_span::SourceType;
+
+use crate::loader::JavaScriptSource;
+
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+/// An opening code fence, e.g. ```` ```js ````.
+struct Fence {
+    /// `` ` `` or `~`.
+    marker: u8,
+    len: usize,
+    source_type: Option<SourceType>,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    /// Each fenced code block of a JavaScript or TypeScript language, e.g. ```` ```ts ````, is
+    /// linted. Code blocks of other languages, and indented code blocks, are not.
+    /// <https://spec.commonmark.org/0.31.2/#fenced-code-blocks>
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        // The opening fence of the current code block, and where its content starts.
+        let mut open: Option<(Fence, usize)> = None;
+        let mut pointer = 0;
+
+        while pointer < self.source_text.len() {
+            let line_start = pointer;
+            let line_end = self.source_text[pointer..]
+                .find('\n')
+                .map_or(self.source_text.len(), |offset| pointer + offset);
+            pointer = (line_end + 1).min(self.source_text.len());
+            let line = &self.source_text[line_start..line_end];
+
+            match &open {
+                None => {
+                    open = Self::parse_opening_fence(line).map(|fence| (fence, pointer));
+                }
+                Some((fence, js_start)) => {
+                    if Self::is_closing_fence(line, fence) {
+                        results.extend(Self::source(
+                            self.source_text,
+                            fence,
+                            *js_start,
+                            line_start,
+                        ));
+                        open = None;
+                    }
+                }
+            }
+        }
+
+        // A code block which is not closed ends at the end of the document.
+        if let Some((fence, js_start)) = &open {
+            results.extend(Self::source(
+                self.source_text,
+                fence,
+                *js_start,
+                self.source_text.len(),
+            ));
+        }
+        results
+    }
+
+    fn source(
+        source_text: &'a str,
+        fence: &Fence,
+        js_start: usize,
+        js_end: usize,
+    ) -> Option<JavaScriptSource<'a>> {
+        let source_type = fence.source_type?;
+        // NOTE: loader checked that source_text.len() is less than u32::MAX
+        #[allow(clippy::cast_possible_truncation)]
+        Some(JavaScriptSource::partial(
+            &source_text[js_start..js_end],
+            source_type,
+            js_start as u32,
+        ))
+    }
+
+    fn parse_opening_fence(line: &str) -> Option<Fence> {
+        let line = line.trim_start();
+        let marker = *line.as_bytes().first()?;
+        if marker != b'`' && marker != b'~' {
+            return None;
+        }
+        let len = line.bytes().take_while(|c| *c == marker).count();
+        if len < 3 {
+            return None;
+        }
+        let info = &line[len..];
+        // The info string of a backtick fence cannot contain backticks, e.g. ```` ```js` ````
+        // is inline code.
+        if marker == b'`' && info.contains('`') {
+            return None;
+        }
+        let lang = info.split_whitespace().next().unwrap_or_default();
+        // e.g. ```` ```js title="example.js" ```` or ```` ```{js} ````
+        let lang = lang.trim_start_matches('{').trim_end_matches('}');
+        Some(Fence { marker, len, source_type: Self::source_type(lang) })
+    }
+
+    fn is_closing_fence(line: &str, fence: &Fence) -> bool {
+        let line = line.trim();
+        line.len() >= fence.len && line.bytes().all(|c| c == fence.marker)
+    }
+
+    fn source_type(lang: &str) -> Option<SourceType> {
+        let source_type = match lang.to_ascii_lowercase().as_str() {
+            "js" | "javascript" | "mjs" | "es6" => SourceType::mjs(),
+            "cjs" => SourceType::cjs(),
+            "jsx" => SourceType::jsx(),
+            "ts" | "typescript" | "mts" | "cts" => SourceType::ts(),
+            "tsx" => SourceType::tsx(),
+            _ => return None,
+        };
+        Some(source_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, MarkdownPartialLoader};
+
+    fn parse_markdown(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_markdown() {
+        let source_text = "# Title\n\n```js\nconsole.log(\"hi\");\n```\n\n```sh\nnpm install\n```\n\n~~~tsx title=\"a.tsx\"\nlet a: number = <div />;\n~~~\n";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "console.log(\"hi\");\n");
+        assert!(!sources[0].source_type.is_typescript());
+        assert_eq!(sources[1].source_text, "let a: number = <div />;\n");
+        assert!(sources[1].source_type.is_typescript());
+        assert!(sources[1].source_type.is_jsx());
+        for source in sources {
+            let start = source.start as usize;
+            assert_eq!(&source_text[start..start + source.source_text.len()], source.source_text);
+        }
+    }
+
+    #[test]
+    fn test_parse_markdown_nested_fences() {
+        let source_text = "````md\n```js\nnot linted\n```\n````\n\n  ```ts\n  let a = 1;\n  ```";
+
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "  let a = 1;\n");
+    }
+
+    #[test]
+    fn test_parse_markdown_unclosed_fence() {
+        let sources = parse_markdown("```js\nlet a = 1;\n");
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "let a = 1;\n");
+    }
+}
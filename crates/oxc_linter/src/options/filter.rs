@@ -1,15 +1,17 @@
 use crate::RuleCategory;
 
-use super::AllowWarnDeny;
+use super::{AllowWarnDeny, RuleQuery};
 use crate::LintPlugins;
 use std::{borrow::Cow, fmt};
 
 /// Enables, disables, and sets the severity of lint rules.
 ///
-/// Filters come in 3 forms:
+/// Filters come in 4 forms:
 /// 1. Filter by rule name and/or plugin: `no-const-assign`, `eslint/no-const-assign`
 /// 2. Filter an entire category: `correctness`
-/// 3. Some unknow filter. This is a fallback used when parsing a filter string,
+/// 3. A [`RuleQuery`] combining categories, plugins and fixability with set operations:
+///    `correctness & fixable & !plugin:react`
+/// 4. Some unknow filter. This is a fallback used when parsing a filter string,
 ///    and is interpreted uniquely by the linter.
 #[derive(Debug, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -85,20 +87,23 @@ pub enum LintFilterKind {
     Rule(LintPlugins, Cow<'static, str>),
     /// e.g. `correctness`
     Category(RuleCategory),
-    // TODO: plugin + category? e.g `-A react:correctness`
+    /// e.g. `correctness & fixable & !plugin:react`
+    Query(RuleQuery),
 }
 
 impl LintFilterKind {
     /// # Errors
     ///
-    /// If `filter` is an empty string, or is a `<plugin>/<rule>` filter but is missing either the
-    /// plugin or the rule.
+    /// If `filter` is an empty string, is a `<plugin>/<rule>` filter but is missing either the
+    /// plugin or the rule, or is a malformed [`RuleQuery`].
     pub fn parse(filter: Cow<'static, str>) -> Result<Self, InvalidFilterKind> {
         if filter.is_empty() {
             return Err(InvalidFilterKind::Empty);
         }
 
-        if filter.contains('/') {
+        if RuleQuery::is_query(&filter) {
+            RuleQuery::parse(filter).map(LintFilterKind::Query)
+        } else if filter.contains('/') {
             // this is an unfortunate amount of code duplication, but it needs to be done for
             // `filter` to live long enough to avoid a String allocation for &'static str
             let (plugin, rule) = match filter {
@@ -190,6 +195,8 @@ pub enum InvalidFilterKind {
     Empty,
     PluginMissing(Cow<'static, str>),
     RuleMissing(Cow<'static, str>),
+    /// A [`RuleQuery`] that could not be parsed. Contains the query and the reason it is invalid.
+    InvalidQuery(Cow<'static, str>, Cow<'static, str>),
 }
 
 impl fmt::Display for InvalidFilterKind {
@@ -208,6 +215,9 @@ impl fmt::Display for InvalidFilterKind {
                     "Filter '{filter}' must match <plugin>/<rule> but is missing a rule name."
                 )
             }
+            Self::InvalidQuery(filter, reason) => {
+                write!(f, "Filter '{filter}' is not a valid rule query: {reason}.")
+            }
         }
     }
 }
@@ -259,6 +269,15 @@ mod test {
             // misc
             ("not-a-valid-filter", LintFilterKind::Generic("not-a-valid-filter".into())),
             ("all", LintFilterKind::Generic("all".into())),
+            // queries
+            ("fixable", LintFilterKind::Query(RuleQuery::Fixable)),
+            ("plugin:react", LintFilterKind::Query(RuleQuery::Plugin(LintPlugins::REACT))),
+            (
+                "!nursery",
+                LintFilterKind::Query(RuleQuery::Not(Box::new(RuleQuery::Category(
+                    RuleCategory::Nursery,
+                )))),
+            ),
         ];
 
         for (input, expected) in test_cases {
@@ -269,7 +288,8 @@ mod test {
 
     #[test]
     fn test_parse_invalid() {
-        let test_cases = vec!["/rules-of-hooks", "import/", "", "/", "//"];
+        let test_cases =
+            vec!["/rules-of-hooks", "import/", "", "/", "//", "plugin:foo", "correctness &"];
 
         for input in test_cases {
             let actual = LintFilterKind::parse(Cow::Borrowed(input));
@@ -1,15 +1,24 @@
 mod allow_warn_deny;
 mod filter;
+mod query;
 
 pub use allow_warn_deny::AllowWarnDeny;
 pub use filter::{InvalidFilterKind, LintFilter, LintFilterKind};
+pub use query::RuleQuery;
 
-use crate::{fixer::FixKind, FrameworkFlags};
+use crate::{
+    fixer::{FixCategory, FixKind},
+    FrameworkFlags,
+};
 
 /// Subset of options used directly by the linter.
 #[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(test, derive(PartialEq))]
 pub(crate) struct LintOptions {
     pub fix: FixKind,
+    pub fix_category: FixCategory,
+    pub report_fixes: bool,
     pub framework_hints: FrameworkFlags,
+    /// Severity of the reports of unused disable directives, not reported if `None`.
+    pub report_unused_directives: Option<AllowWarnDeny>,
 }
@@ -0,0 +1,340 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{LintPlugins, RuleCategory, RuleEnum};
+
+use super::InvalidFilterKind;
+
+/// A set expression over lint rules.
+///
+/// Queries let users select rules by category, plugin, and fixability, and combine those
+/// selections with set operations instead of enumerating rule names one by one.
+///
+/// Operands:
+/// - `all`: every rule except nursery rules
+/// - `correctness`, `style`, ...: every rule in a category
+/// - `plugin:<name>`: every rule provided by a plugin, e.g. `plugin:react`
+/// - `fixable`: every rule that provides an auto-fix or a suggestion
+/// - `<rule>` or `<plugin>/<rule>`: a single rule
+///
+/// Operators, from tightest to loosest binding:
+/// - `!a`: rules not matched by `a`
+/// - `a & b`: rules matched by both `a` and `b`
+/// - `a | b`: rules matched by either `a` or `b`
+///
+/// Parentheses may be used for grouping and whitespace is ignored. For example,
+/// `correctness & fixable & !plugin:react` selects all fixable correctness rules that are not
+/// provided by the React plugin.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleQuery {
+    /// `all`
+    All,
+    /// e.g. `correctness`
+    Category(RuleCategory),
+    /// e.g. `plugin:react`
+    Plugin(LintPlugins),
+    /// `fixable`
+    Fixable,
+    /// e.g. `no-console` or `eslint/no-console`
+    Rule(Option<LintPlugins>, String),
+    Not(Box<RuleQuery>),
+    And(Box<RuleQuery>, Box<RuleQuery>),
+    Or(Box<RuleQuery>, Box<RuleQuery>),
+}
+
+impl RuleQuery {
+    /// Characters that can only appear in a query, never in a plain filter.
+    pub(super) const OPERATORS: [char; 5] = ['&', '|', '!', '(', ')'];
+
+    /// Returns `true` if `filter` should be parsed as a [`RuleQuery`] instead of a plain
+    /// rule/category filter.
+    pub(super) fn is_query(filter: &str) -> bool {
+        filter.contains(Self::OPERATORS) || filter.starts_with("plugin:") || filter == "fixable"
+    }
+
+    /// # Errors
+    ///
+    /// If `query` is not a well-formed expression, or references an unknown plugin.
+    pub fn parse(query: Cow<'static, str>) -> Result<Self, InvalidFilterKind> {
+        let result = Parser::new(&query).and_then(|mut parser| {
+            let expr = parser.parse_or()?;
+            match parser.peek() {
+                None => Ok(expr),
+                Some(token) => Err(format!("unexpected `{token}`")),
+            }
+        });
+        result.map_err(|reason| InvalidFilterKind::InvalidQuery(query, reason.into()))
+    }
+
+    /// Returns `true` if `rule` is a member of the set of rules described by this query.
+    pub fn matches(&self, rule: &RuleEnum) -> bool {
+        match self {
+            Self::All => rule.category() != RuleCategory::Nursery,
+            Self::Category(category) => rule.category() == *category,
+            Self::Plugin(plugin) => LintPlugins::from(rule.plugin_name()) == *plugin,
+            Self::Fixable => rule.fix().has_fix(),
+            Self::Rule(plugin, name) => {
+                rule.name() == name
+                    && plugin.map_or(true, |plugin| LintPlugins::from(rule.plugin_name()) == plugin)
+            }
+            Self::Not(query) => !query.matches(rule),
+            Self::And(left, right) => left.matches(rule) && right.matches(rule),
+            Self::Or(left, right) => left.matches(rule) || right.matches(rule),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'s> {
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+    Term(&'s str),
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::And => "&".fmt(f),
+            Self::Or => "|".fmt(f),
+            Self::Not => "!".fmt(f),
+            Self::LParen => "(".fmt(f),
+            Self::RParen => ")".fmt(f),
+            Self::Term(term) => term.fmt(f),
+        }
+    }
+}
+
+/// Recursive descent parser for [`RuleQuery`].
+///
+/// ```text
+/// or   := and ( '|' and )*
+/// and  := not ( '&' not )*
+/// not  := '!' not | atom
+/// atom := '(' or ')' | term
+/// ```
+struct Parser<'s> {
+    tokens: Vec<Token<'s>>,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn new(source: &'s str) -> Result<Self, String> {
+        let mut tokens = vec![];
+        let mut rest = source.trim_start();
+        while let Some(c) = rest.chars().next() {
+            let (token, len) = match c {
+                '&' => (Token::And, 1),
+                '|' => (Token::Or, 1),
+                '!' => (Token::Not, 1),
+                '(' => (Token::LParen, 1),
+                ')' => (Token::RParen, 1),
+                _ => {
+                    let len = rest
+                        .find(|c: char| c.is_whitespace() || RuleQuery::OPERATORS.contains(&c))
+                        .unwrap_or(rest.len());
+                    (Token::Term(&rest[..len]), len)
+                }
+            };
+            tokens.push(token);
+            rest = rest[len..].trim_start();
+        }
+        if tokens.is_empty() {
+            return Err("query is empty".into());
+        }
+        Ok(Self { tokens, pos: 0 })
+    }
+
+    fn peek(&self) -> Option<Token<'s>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, token: Token<'s>) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<RuleQuery, String> {
+        let mut left = self.parse_and()?;
+        while self.eat(Token::Or) {
+            let right = self.parse_and()?;
+            left = RuleQuery::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<RuleQuery, String> {
+        let mut left = self.parse_not()?;
+        while self.eat(Token::And) {
+            let right = self.parse_not()?;
+            left = RuleQuery::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> Result<RuleQuery, String> {
+        if self.eat(Token::Not) {
+            return Ok(RuleQuery::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_atom()
+    }
+
+    fn parse_atom(&mut self) -> Result<RuleQuery, String> {
+        let Some(token) = self.peek() else {
+            return Err("unexpected end of query".into());
+        };
+        self.pos += 1;
+        match token {
+            Token::LParen => {
+                let expr = self.parse_or()?;
+                if !self.eat(Token::RParen) {
+                    return Err("missing closing `)`".into());
+                }
+                Ok(expr)
+            }
+            Token::Term(term) => Self::parse_term(term),
+            token => Err(format!("unexpected `{token}`")),
+        }
+    }
+
+    fn parse_term(term: &str) -> Result<RuleQuery, String> {
+        if term == "all" {
+            return Ok(RuleQuery::All);
+        }
+        if term == "fixable" {
+            return Ok(RuleQuery::Fixable);
+        }
+        if let Some(plugin) = term.strip_prefix("plugin:") {
+            return Self::parse_plugin(plugin).map(RuleQuery::Plugin);
+        }
+        if let Ok(category) = RuleCategory::try_from(term) {
+            return Ok(RuleQuery::Category(category));
+        }
+        match term.split_once('/') {
+            Some((plugin, rule)) => {
+                if rule.is_empty() {
+                    return Err(format!("`{term}` is missing a rule name"));
+                }
+                let plugin = Self::parse_plugin(plugin)?;
+                Ok(RuleQuery::Rule(Some(plugin), rule.to_string()))
+            }
+            None => Ok(RuleQuery::Rule(None, term.to_string())),
+        }
+    }
+
+    fn parse_plugin(name: &str) -> Result<LintPlugins, String> {
+        if name.is_empty() {
+            return Err("plugin name is missing".into());
+        }
+        let plugin = LintPlugins::from(name);
+        // `LintPlugins::from` maps unknown plugins to the same (empty) set as ESLint
+        if plugin.is_empty() && name != "eslint" {
+            return Err(format!("unknown plugin `{name}`"));
+        }
+        Ok(plugin)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rules::RULES;
+
+    fn parse(query: &'static str) -> RuleQuery {
+        RuleQuery::parse(Cow::Borrowed(query)).unwrap()
+    }
+
+    fn not(query: RuleQuery) -> RuleQuery {
+        RuleQuery::Not(Box::new(query))
+    }
+
+    fn and(left: RuleQuery, right: RuleQuery) -> RuleQuery {
+        RuleQuery::And(Box::new(left), Box::new(right))
+    }
+
+    fn or(left: RuleQuery, right: RuleQuery) -> RuleQuery {
+        RuleQuery::Or(Box::new(left), Box::new(right))
+    }
+
+    #[test]
+    fn test_parse() {
+        let correctness = RuleQuery::Category(RuleCategory::Correctness);
+        let react = RuleQuery::Plugin(LintPlugins::REACT);
+
+        assert_eq!(parse("fixable"), RuleQuery::Fixable);
+        assert_eq!(parse("plugin:react"), react);
+        assert_eq!(parse("plugin:eslint"), RuleQuery::Plugin(LintPlugins::ESLINT));
+        assert_eq!(parse("!all"), not(RuleQuery::All));
+        assert_eq!(
+            parse("correctness & fixable & !plugin:react"),
+            and(and(correctness.clone(), RuleQuery::Fixable), not(react.clone()))
+        );
+        // `&` binds tighter than `|`
+        assert_eq!(
+            parse("correctness|fixable&plugin:react"),
+            or(correctness.clone(), and(RuleQuery::Fixable, react.clone()))
+        );
+        assert_eq!(
+            parse("(correctness | fixable) & plugin:react"),
+            and(or(correctness.clone(), RuleQuery::Fixable), react.clone())
+        );
+        assert_eq!(
+            parse("!!no-console | eslint/eqeqeq"),
+            or(
+                not(not(RuleQuery::Rule(None, "no-console".into()))),
+                RuleQuery::Rule(Some(LintPlugins::ESLINT), "eqeqeq".into())
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let test_cases = [
+            "",
+            "  ",
+            "correctness &",
+            "& correctness",
+            "correctness fixable",
+            "(correctness",
+            "correctness)",
+            "()",
+            "!",
+            "plugin:",
+            "plugin:not-a-plugin",
+            "not-a-plugin/no-console & fixable",
+            "react/ & fixable",
+        ];
+
+        for input in test_cases {
+            let actual = RuleQuery::parse(Cow::Borrowed(input));
+            assert!(
+                matches!(actual, Err(InvalidFilterKind::InvalidQuery(..))),
+                "input '{input}' produced '{actual:?}' but it should have errored",
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let rules = RULES.as_slice();
+        let query = parse("correctness & fixable & !plugin:react");
+
+        let matched = rules.iter().filter(|rule| query.matches(rule)).collect::<Vec<_>>();
+        assert!(!matched.is_empty());
+        for rule in &matched {
+            assert_eq!(rule.category(), RuleCategory::Correctness);
+            assert!(rule.fix().has_fix());
+            assert_ne!(LintPlugins::from(rule.plugin_name()), LintPlugins::REACT);
+        }
+
+        let eqeqeq = parse("eslint/eqeqeq");
+        assert_eq!(rules.iter().filter(|rule| eqeqeq.matches(rule)).count(), 1);
+        let eqeqeq = parse("react/eqeqeq");
+        assert_eq!(rules.iter().filter(|rule| eqeqeq.matches(rule)).count(), 0);
+    }
+}
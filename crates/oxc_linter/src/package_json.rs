@@ -0,0 +1,80 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde::Deserialize;
+
+use crate::utils::read_to_string;
+
+/// The dependencies declared by a `package.json` file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PackageJson {
+    /// Path of the `package.json` file.
+    #[serde(skip)]
+    pub path: PathBuf,
+    pub dependencies: FxHashMap<String, serde_json::Value>,
+    pub dev_dependencies: FxHashMap<String, serde_json::Value>,
+    pub optional_dependencies: FxHashMap<String, serde_json::Value>,
+    pub peer_dependencies: FxHashMap<String, serde_json::Value>,
+    #[serde(alias = "bundleDependencies")]
+    pub bundled_dependencies: Vec<String>,
+}
+
+impl PackageJson {
+    /// Read the `package.json` file at `path`, or `None` if it does not exist or is not valid.
+    fn read(path: PathBuf) -> Option<Self> {
+        let json = read_to_string(&path).ok()?;
+        let package_json = serde_json::from_str::<Self>(&json).ok()?;
+        Some(Self { path, ..package_json })
+    }
+}
+
+/// The `package.json` files found while linting, shared by all linted files so that every file
+/// is only read once.
+#[derive(Debug, Default)]
+pub struct PackageJsonCache {
+    /// The closest `package.json` file of directories, `None` if there is none.
+    directories: DashMap<Box<Path>, Option<Arc<PackageJson>>, FxBuildHasher>,
+}
+
+impl PackageJsonCache {
+    /// The closest `package.json` file in `directory` or one of its ancestors.
+    pub fn find(&self, directory: &Path) -> Option<Arc<PackageJson>> {
+        if let Some(package_json) = self.directories.get(directory) {
+            return package_json.value().clone();
+        }
+        let package_json = PackageJson::read(directory.join("package.json"))
+            .map(Arc::new)
+            .or_else(|| directory.parent().and_then(|parent| self.find(parent)));
+        self.directories.insert(directory.into(), package_json.clone());
+        package_json
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::PackageJsonCache;
+
+    #[test]
+    fn test_find_package_json() {
+        let cache = PackageJsonCache::default();
+        let directory = env::current_dir().unwrap().join("fixtures/import/deep");
+        let package_json = cache.find(&directory).unwrap();
+        assert_eq!(
+            package_json.path,
+            env::current_dir().unwrap().join("fixtures/import/package.json")
+        );
+        assert!(package_json.dependencies.contains_key("jquery"));
+        assert!(package_json.dev_dependencies.contains_key("glob"));
+        assert_eq!(package_json.bundled_dependencies, ["@generated/foo"]);
+
+        // The closest `package.json` is cached for the directories in between.
+        assert!(cache.directories.contains_key(directory.as_path()));
+    }
+}
@@ -6,13 +6,13 @@ use std::{
 };
 
 use oxc_semantic::SymbolId;
-use schemars::JsonSchema;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     context::{ContextHost, LintContext},
     utils::PossibleJestNode,
-    AllowWarnDeny, AstNode, FixKind, RuleEnum,
+    AllowWarnDeny, AstNode, FixCategory, FixKind, RuleEnum,
 };
 
 pub trait Rule: Sized + Default + fmt::Debug {
@@ -70,9 +70,19 @@ pub trait RuleMeta {
     /// What kind of auto-fixing can this rule do?
     const FIX: RuleFixMeta = RuleFixMeta::None;
 
+    /// What do the fixes of this rule change? See [`FixCategory`].
+    const FIX_CATEGORY: FixCategory = FixCategory::Behavior;
+
     fn documentation() -> Option<&'static str> {
         None
     }
+
+    /// JSON schema of each of the options of this rule, i.e. of the values after the severity in
+    /// `["error", { "max": 300 }]`. Used to report unknown and invalid options.
+    #[expect(unused_variables)]
+    fn schema(gen: &mut SchemaGenerator) -> Option<Schema> {
+        None
+    }
 }
 
 /// Rule categories defined by rust-clippy
@@ -7,7 +7,6 @@
 
 /// <https://github.com/import-js/eslint-plugin-import>
 mod import {
-    // pub mod no_deprecated;
     // pub mod no_unused_modules;
     pub mod default;
     pub mod export;
@@ -19,11 +18,14 @@ mod import {
     pub mod no_commonjs;
     pub mod no_cycle;
     pub mod no_default_export;
+    pub mod no_deprecated;
     pub mod no_duplicates;
     pub mod no_dynamic_require;
+    pub mod no_extraneous_dependencies;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
     pub mod no_self_import;
+    pub mod no_unresolved;
     pub mod no_webpack_loader_syntax;
     pub mod unambiguous;
 }
@@ -101,6 +103,8 @@ mod eslint {
     pub mod no_redeclare;
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
+    pub mod no_restricted_imports;
+    pub mod no_restricted_syntax;
     pub mod no_return_assign;
     pub mod no_script_url;
     pub mod no_self_assign;
@@ -239,6 +243,7 @@ mod jest {
 mod react {
     pub mod button_has_type;
     pub mod checked_requires_onchange_or_readonly;
+    pub mod exhaustive_deps;
     pub mod iframe_missing_sandbox;
     pub mod jsx_boolean_value;
     pub mod jsx_curly_brace_presence;
@@ -426,6 +431,10 @@ mod oxc {
 
 mod security {
     pub mod api_keys;
+    pub mod detect_child_process;
+    pub mod detect_eval_with_expression;
+    pub mod detect_non_literal_fs_filename;
+    pub mod detect_unsafe_regex;
 }
 
 mod nextjs {
@@ -501,12 +510,21 @@ mod vitest {
 }
 
 mod node {
+    pub mod no_deprecated_api;
     pub mod no_exports_assign;
+    pub mod no_missing_import;
     pub mod no_new_require;
+    pub mod prefer_global;
+}
+
+mod regexp {
+    pub mod no_dupe_characters_in_character_class;
+    pub mod no_empty_alternative;
+    pub mod prefer_d;
+    pub mod prefer_w;
 }
 
 oxc_macros::declare_all_lint_rules! {
-    // import::no_deprecated,
     // import::no_unused_modules,
     eslint::array_callback_return,
     eslint::constructor_super,
@@ -580,6 +598,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_redeclare,
     eslint::no_regex_spaces,
     eslint::no_restricted_globals,
+    eslint::no_restricted_imports,
+    eslint::no_restricted_syntax,
     eslint::no_return_assign,
     eslint::no_script_url,
     eslint::no_self_assign,
@@ -632,11 +652,14 @@ oxc_macros::declare_all_lint_rules! {
     import::no_commonjs,
     import::no_cycle,
     import::no_default_export,
+    import::no_deprecated,
     import::no_duplicates,
     import::no_dynamic_require,
+    import::no_extraneous_dependencies,
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
+    import::no_unresolved,
     import::no_webpack_loader_syntax,
     import::unambiguous,
     jest::consistent_test_it,
@@ -752,8 +775,11 @@ oxc_macros::declare_all_lint_rules! {
     nextjs::no_title_in_document_head,
     nextjs::no_typos,
     nextjs::no_unwanted_polyfillio,
+    node::no_deprecated_api,
     node::no_exports_assign,
+    node::no_missing_import,
     node::no_new_require,
+    node::prefer_global,
     oxc::approx_constant,
     oxc::bad_array_method_on_arguments,
     oxc::bad_bitwise_operator,
@@ -790,6 +816,7 @@ oxc_macros::declare_all_lint_rules! {
     promise::valid_params,
     react::button_has_type,
     react::checked_requires_onchange_or_readonly,
+    react::exhaustive_deps,
     react::iframe_missing_sandbox,
     react::jsx_boolean_value,
     react::jsx_curly_brace_presence,
@@ -822,7 +849,15 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_array_as_prop,
     react_perf::jsx_no_new_function_as_prop,
     react_perf::jsx_no_new_object_as_prop,
+    regexp::no_dupe_characters_in_character_class,
+    regexp::no_empty_alternative,
+    regexp::prefer_d,
+    regexp::prefer_w,
     security::api_keys,
+    security::detect_child_process,
+    security::detect_eval_with_expression,
+    security::detect_non_literal_fs_filename,
+    security::detect_unsafe_regex,
     tree_shaking::no_side_effects_in_initialization,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
@@ -1,6 +1,8 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{context::LintContext, rule::Rule};
@@ -14,13 +16,25 @@ fn max_lines_diagnostic(count: usize, max: usize, span: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct MaxLines(Box<MaxLinesConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
 pub struct MaxLinesConfig {
+    /// The maximum number of lines in a file.
     max: usize,
+    /// Ignore lines containing only whitespace.
     skip_blank_lines: bool,
+    /// Ignore lines containing only comments.
     skip_comments: bool,
 }
 
+/// Either the maximum number of lines, or an object of options.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum MaxLinesOption {
+    Max(usize),
+    Config(MaxLinesConfig),
+}
+
 impl std::ops::Deref for MaxLines {
     type Target = MaxLinesConfig;
 
@@ -46,35 +60,28 @@ declare_oxc_lint!(
     /// is not an objective maximum number of lines considered acceptable in a
     /// file, most people would agree it should not be in the thousands.
     /// Recommendations usually range from 100 to 500 lines.
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// "max-lines": ["error", { "max": 300, "skipBlankLines": false, "skipComments": false }]
+    /// ```
+    ///
+    /// The maximum can also be given as a number, e.g. `["error", 300]`.
     MaxLines,
-    pedantic
+    pedantic,
+    config = MaxLinesOption
 );
 
 impl Rule for MaxLines {
     fn from_configuration(value: Value) -> Self {
-        let config = value.get(0);
-        if let Some(max) = config
-            .and_then(Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .and_then(|v| usize::try_from(v).ok())
-        {
-            Self(Box::new(MaxLinesConfig { max, skip_comments: false, skip_blank_lines: false }))
-        } else {
-            let max = config
-                .and_then(|config| config.get("max"))
-                .and_then(Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(300, |v| usize::try_from(v).unwrap_or(300));
-            let skip_comments = config
-                .and_then(|config| config.get("skipComments"))
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
-            let skip_blank_lines = config
-                .and_then(|config| config.get("skipBlankLines"))
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
-
-            Self(Box::new(MaxLinesConfig { max, skip_blank_lines, skip_comments }))
+        let option = value.get(0).and_then(|option| serde_json::from_value(option.clone()).ok());
+        match option {
+            Some(MaxLinesOption::Max(max)) => {
+                Self(Box::new(MaxLinesConfig { max, ..MaxLinesConfig::default() }))
+            }
+            Some(MaxLinesOption::Config(config)) => Self(Box::new(config)),
+            None => Self::default(),
         }
     }
 
@@ -5,6 +5,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_regular_expression::{ConstructorParser, Options};
 use oxc_span::Span;
 use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
@@ -51,9 +52,10 @@ declare_oxc_lint!(
     /// ```
     NoInvalidRegexp,
     correctness,
+    config = NoInvalidRegexpConfig,
 );
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Default, JsonSchema)]
 struct NoInvalidRegexpConfig {
     #[serde(default, rename = "allowConstructorFlags")]
     /// Case-sensitive array of flags.
@@ -0,0 +1,517 @@
+use globset::{GlobBuilder, GlobMatcher};
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn with_message(diagnostic: String, message: Option<&CompactStr>) -> OxcDiagnostic {
+    match message {
+        Some(message) => OxcDiagnostic::warn(format!("{diagnostic} {message}")),
+        None => OxcDiagnostic::warn(diagnostic),
+    }
+}
+
+fn restricted_path(source: &str, message: Option<&CompactStr>, span: Span) -> OxcDiagnostic {
+    with_message(format!("'{source}' import is restricted from being used."), message)
+        .with_label(span)
+}
+
+fn restricted_import_name(
+    import_name: &str,
+    source: &str,
+    message: Option<&CompactStr>,
+    span: Span,
+) -> OxcDiagnostic {
+    with_message(format!("'{import_name}' import from '{source}' is restricted."), message)
+        .with_label(span)
+}
+
+fn restricted_everything(
+    import_names: &[CompactStr],
+    source: &str,
+    message: Option<&CompactStr>,
+    span: Span,
+) -> OxcDiagnostic {
+    let import_names = import_names.join(", ");
+    with_message(
+        format!("* import is invalid because '{import_names}' from '{source}' is restricted."),
+        message,
+    )
+    .with_label(span)
+}
+
+fn restricted_pattern(source: &str, message: Option<&CompactStr>, span: Span) -> OxcDiagnostic {
+    with_message(format!("'{source}' import is restricted from being used by a pattern."), message)
+        .with_label(span)
+}
+
+fn restricted_pattern_import_name(
+    import_name: &str,
+    source: &str,
+    message: Option<&CompactStr>,
+    span: Span,
+) -> OxcDiagnostic {
+    with_message(
+        format!(
+            "'{import_name}' import from '{source}' is restricted from being used by a pattern."
+        ),
+        message,
+    )
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImports(Box<NoRestrictedImportsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImportsConfig {
+    paths: Vec<RestrictedPath>,
+    patterns: Vec<RestrictedPattern>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPath {
+    name: CompactStr,
+    message: Option<CompactStr>,
+    /// Only these names are restricted, instead of the whole module.
+    import_names: Option<Vec<CompactStr>>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPattern {
+    /// `.gitignore` style patterns, in order. Later patterns override earlier ones, and patterns
+    /// starting with `!` allow the sources matched by earlier patterns.
+    group: Vec<(GlobMatcher, bool)>,
+    message: Option<CompactStr>,
+    import_names: Option<Vec<CompactStr>>,
+}
+
+/// An option of the rule: a restricted module, or an object of restricted modules and patterns.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum NoRestrictedImportsOption {
+    Path(RestrictedPathOption),
+    PathsAndPatterns(PathsAndPatternsOption),
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct PathsAndPatternsOption {
+    #[serde(default)]
+    paths: Vec<RestrictedPathOption>,
+    #[serde(default)]
+    patterns: Vec<RestrictedPatternOption>,
+}
+
+/// `"fs"` or `{ "name": "lodash", "importNames": ["cloneDeep"], "message": "..." }`
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum RestrictedPathOption {
+    Name(String),
+    Object(RestrictedPathObject),
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct RestrictedPathObject {
+    name: String,
+    message: Option<String>,
+    import_names: Option<Vec<String>>,
+}
+
+/// `"lodash/*"` or `{ "group": ["lodash/*"], "caseSensitive": true, "message": "..." }`
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum RestrictedPatternOption {
+    Pattern(String),
+    Object(RestrictedPatternObject),
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct RestrictedPatternObject {
+    group: Vec<String>,
+    #[serde(default)]
+    case_sensitive: bool,
+    message: Option<String>,
+    import_names: Option<Vec<String>>,
+}
+
+impl std::ops::Deref for NoRestrictedImports {
+    type Target = NoRestrictedImportsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified modules when loaded by `import`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some imports might not make sense in a particular environment, e.g. Node.js' `fs` module
+    /// in code running in the browser. Other modules might be deprecated, or have a better
+    /// alternative which should be used instead.
+    ///
+    /// ### Options
+    ///
+    /// Modules can be restricted by name, optionally restricting only some of their exports:
+    ///
+    /// ```json
+    /// "no-restricted-imports": ["error", "fs", {
+    ///   "name": "lodash",
+    ///   "importNames": ["cloneDeep"],
+    ///   "message": "Use structuredClone instead."
+    /// }]
+    /// ```
+    ///
+    /// Or by `.gitignore` style patterns:
+    ///
+    /// ```json
+    /// "no-restricted-imports": ["error", {
+    ///   "paths": ["fs"],
+    ///   "patterns": ["lodash/*", {
+    ///     "group": ["@/internal/*", "!@/internal/public"],
+    ///     "message": "Use the public API instead."
+    ///   }]
+    /// }]
+    /// ```
+    ///
+    /// Patterns are case insensitive, unless `caseSensitive` is set to `true`.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the options above:
+    /// ```js
+    /// import fs from 'fs';
+    /// import { cloneDeep } from 'lodash';
+    /// export * from 'lodash/pick';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the options above:
+    /// ```js
+    /// import { debounce } from 'lodash';
+    /// import { api } from '@/internal/public';
+    /// ```
+    NoRestrictedImports,
+    restriction,
+    config = NoRestrictedImportsOption
+);
+
+impl Rule for NoRestrictedImports {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = NoRestrictedImportsConfig::default();
+        let Value::Array(options) = value else { return Self(Box::new(config)) };
+
+        for option in options {
+            match serde_json::from_value(option) {
+                Ok(NoRestrictedImportsOption::Path(path)) => config.paths.push(path.into()),
+                Ok(NoRestrictedImportsOption::PathsAndPatterns(option)) => {
+                    config.paths.extend(option.paths.into_iter().map(RestrictedPath::from));
+                    config.patterns.extend(RestrictedPattern::from_options(option.patterns));
+                }
+                Err(_) => {}
+            }
+        }
+
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ImportDeclaration(decl) => {
+                let names = decl.specifiers.iter().flatten().map(|specifier| match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        (ImportedName::Name(specifier.imported.name().as_str()), specifier.span)
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (ImportedName::Name("default"), specifier.span)
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                        (ImportedName::Everything, specifier.span)
+                    }
+                });
+                self.check(&decl.source, decl.span, &names, ctx);
+            }
+            AstKind::ExportNamedDeclaration(decl) => {
+                let Some(source) = &decl.source else { return };
+                let names = decl.specifiers.iter().map(|specifier| {
+                    (ImportedName::Name(specifier.local.name().as_str()), specifier.span)
+                });
+                self.check(source, decl.span, &names, ctx);
+            }
+            AstKind::ExportAllDeclaration(decl) => {
+                let names = std::iter::once((ImportedName::Everything, decl.span));
+                self.check(&decl.source, decl.span, &names, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ImportedName<'a> {
+    Name(&'a str),
+    /// `import * as ns` and `export * from`
+    Everything,
+}
+
+impl NoRestrictedImports {
+    fn check<'a, I>(&self, source: &StringLiteral<'a>, span: Span, names: &I, ctx: &LintContext<'a>)
+    where
+        I: Iterator<Item = (ImportedName<'a>, Span)> + Clone,
+    {
+        let source = source.value.as_str();
+
+        for path in self.paths.iter().filter(|path| path.name == source) {
+            let Some(import_names) = &path.import_names else {
+                ctx.diagnostic(restricted_path(source, path.message.as_ref(), span));
+                continue;
+            };
+            for (name, span) in names.clone() {
+                match name {
+                    ImportedName::Name(name) if import_names.iter().any(|n| n == name) => {
+                        ctx.diagnostic(restricted_import_name(
+                            name,
+                            source,
+                            path.message.as_ref(),
+                            span,
+                        ));
+                    }
+                    ImportedName::Everything => ctx.diagnostic(restricted_everything(
+                        import_names,
+                        source,
+                        path.message.as_ref(),
+                        span,
+                    )),
+                    ImportedName::Name(_) => {}
+                }
+            }
+        }
+
+        for pattern in self.patterns.iter().filter(|pattern| pattern.is_match(source)) {
+            let Some(import_names) = &pattern.import_names else {
+                ctx.diagnostic(restricted_pattern(source, pattern.message.as_ref(), span));
+                continue;
+            };
+            for (name, span) in names.clone() {
+                match name {
+                    ImportedName::Name(name) if import_names.iter().any(|n| n == name) => {
+                        ctx.diagnostic(restricted_pattern_import_name(
+                            name,
+                            source,
+                            pattern.message.as_ref(),
+                            span,
+                        ));
+                    }
+                    ImportedName::Everything => ctx.diagnostic(restricted_everything(
+                        import_names,
+                        source,
+                        pattern.message.as_ref(),
+                        span,
+                    )),
+                    ImportedName::Name(_) => {}
+                }
+            }
+        }
+    }
+}
+
+fn compact_strs(names: Option<Vec<String>>) -> Option<Vec<CompactStr>> {
+    names.map(|names| names.into_iter().map(CompactStr::from).collect())
+}
+
+impl From<RestrictedPathOption> for RestrictedPath {
+    fn from(option: RestrictedPathOption) -> Self {
+        match option {
+            // "no-restricted-imports": ["error", "fs"]
+            RestrictedPathOption::Name(name) => {
+                Self { name: CompactStr::from(name), message: None, import_names: None }
+            }
+            // "no-restricted-imports": ["error", { "name": "fs", "message": "..." }]
+            RestrictedPathOption::Object(RestrictedPathObject { name, message, import_names }) => {
+                Self {
+                    name: CompactStr::from(name),
+                    message: message.map(CompactStr::from),
+                    import_names: compact_strs(import_names),
+                }
+            }
+        }
+    }
+}
+
+impl RestrictedPattern {
+    /// Like ESLint, the string patterns form a single group, so a negated string pattern allows
+    /// the sources matched by the string patterns before it.
+    fn from_options(options: Vec<RestrictedPatternOption>) -> Vec<Self> {
+        let mut patterns = vec![];
+        let mut groups = vec![];
+        for option in options {
+            match option {
+                RestrictedPatternOption::Pattern(pattern) => patterns.push(pattern),
+                RestrictedPatternOption::Object(RestrictedPatternObject {
+                    group,
+                    case_sensitive,
+                    message,
+                    import_names,
+                }) => groups.extend(Self::new(
+                    &group,
+                    case_sensitive,
+                    message.map(CompactStr::from),
+                    compact_strs(import_names),
+                )),
+            }
+        }
+        Self::new(&patterns, false, None, None).into_iter().chain(groups).collect()
+    }
+
+    fn new(
+        patterns: &[String],
+        case_sensitive: bool,
+        message: Option<CompactStr>,
+        import_names: Option<Vec<CompactStr>>,
+    ) -> Option<Self> {
+        let mut group = vec![];
+        for pattern in patterns {
+            let (pattern, negated) = match pattern.strip_prefix('!') {
+                Some(pattern) => (pattern, true),
+                None => (pattern.as_str(), false),
+            };
+            let pattern = pattern.trim_end_matches('/');
+            // Like `.gitignore`, patterns without a slash match at any depth, and patterns
+            // matching a directory also match everything inside it.
+            let pattern = match pattern.strip_prefix('/') {
+                Some(pattern) => pattern.to_string(),
+                None if !pattern.contains('/') => format!("**/{pattern}"),
+                None => pattern.to_string(),
+            };
+            for pattern in [pattern.clone(), format!("{pattern}/**")] {
+                let Ok(glob) = GlobBuilder::new(&pattern)
+                    .literal_separator(true)
+                    .case_insensitive(!case_sensitive)
+                    .build()
+                else {
+                    continue;
+                };
+                group.push((glob.compile_matcher(), negated));
+            }
+        }
+        (!group.is_empty()).then_some(Self { group, message, import_names })
+    }
+
+    fn is_match(&self, source: &str) -> bool {
+        self.group
+            .iter()
+            .rev()
+            .find(|(glob, _)| glob.is_match(source))
+            .is_some_and(|(_, negated)| !negated)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import os from 'os';", None),
+        ("import os from 'os';", Some(serde_json::json!(["osx"]))),
+        ("import fs from 'fs';", Some(serde_json::json!(["crypto"]))),
+        ("import 'foo';", Some(serde_json::json!(["bar"]))),
+        ("export { foo } from 'foo';", Some(serde_json::json!(["bar"]))),
+        ("export * from 'foo';", Some(serde_json::json!(["bar"]))),
+        ("import withPaths from 'foo/bar';", Some(serde_json::json!([{ "paths": ["foo"] }]))),
+        (
+            "import withPatterns from 'foo/bar';",
+            Some(serde_json::json!([{ "patterns": ["foo/c*"] }])),
+        ),
+        (
+            "import withNegation from 'foo/bar';",
+            Some(serde_json::json!([{ "patterns": ["foo/*", "!foo/bar"] }])),
+        ),
+        (
+            "import { AllowedObject } from 'foo';",
+            Some(serde_json::json!([{ "name": "foo", "importNames": ["DisallowedObject"] }])),
+        ),
+        (
+            "import DisallowedObject from 'foo';",
+            Some(serde_json::json!([{ "name": "foo", "importNames": ["DisallowedObject"] }])),
+        ),
+        (
+            "export { AllowedObject } from 'foo';",
+            Some(serde_json::json!([{ "name": "foo", "importNames": ["DisallowedObject"] }])),
+        ),
+        (
+            "import { AllowedObject } from 'foo/bar';",
+            Some(serde_json::json!([{
+                "patterns": [{ "group": ["foo/*"], "importNames": ["DisallowedObject"] }]
+            }])),
+        ),
+        (
+            "import withCase from 'FOO/bar';",
+            Some(serde_json::json!([{
+                "patterns": [{ "group": ["foo/*"], "caseSensitive": true }]
+            }])),
+        ),
+        ("const fs = require('fs');", Some(serde_json::json!(["fs"]))),
+    ];
+
+    let fail = vec![
+        ("import fs from 'fs';", Some(serde_json::json!(["fs"]))),
+        ("import 'fs';", Some(serde_json::json!(["fs"]))),
+        ("export { readFile } from 'fs';", Some(serde_json::json!(["fs"]))),
+        ("export * from 'fs';", Some(serde_json::json!(["fs"]))),
+        ("import fs from 'fs';", Some(serde_json::json!([{ "paths": ["fs"] }]))),
+        (
+            "import fs from 'fs';",
+            Some(serde_json::json!([{ "name": "fs", "message": "Use the fetch API instead." }])),
+        ),
+        (
+            "import pick from 'lodash/pick';",
+            Some(serde_json::json!([{ "patterns": ["lodash/*"] }])),
+        ),
+        ("import withCase from 'FOO/bar';", Some(serde_json::json!([{ "patterns": ["foo/*"] }]))),
+        (
+            "import { api } from '@/internal/api';",
+            Some(serde_json::json!([{
+                "patterns": [{
+                    "group": ["@/internal/*", "!@/internal/public"],
+                    "message": "Use the public API instead."
+                }]
+            }])),
+        ),
+        (
+            "import { cloneDeep } from 'lodash';",
+            Some(serde_json::json!([{ "name": "lodash", "importNames": ["cloneDeep"] }])),
+        ),
+        (
+            "import cloneDeep from 'lodash';",
+            Some(serde_json::json!([{ "name": "lodash", "importNames": ["default"] }])),
+        ),
+        (
+            "export { cloneDeep } from 'lodash';",
+            Some(serde_json::json!([{ "name": "lodash", "importNames": ["cloneDeep"] }])),
+        ),
+        (
+            "import * as lodash from 'lodash';",
+            Some(serde_json::json!([{ "name": "lodash", "importNames": ["cloneDeep"] }])),
+        ),
+        (
+            "import { merge } from 'lodash/fp';",
+            Some(serde_json::json!([{
+                "patterns": [{ "group": ["lodash/*"], "importNames": ["merge"] }]
+            }])),
+        ),
+    ];
+
+    Tester::new(NoRestrictedImports::NAME, pass, fail).test_and_snapshot();
+}
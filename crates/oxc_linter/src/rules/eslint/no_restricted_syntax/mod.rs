@@ -0,0 +1,190 @@
+mod selector;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use self::selector::Selector;
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_restricted_syntax(selector: &str, message: Option<&CompactStr>, span: Span) -> OxcDiagnostic {
+    match message {
+        Some(message) => OxcDiagnostic::warn(message.to_string()),
+        None => OxcDiagnostic::warn(format!("Using '{selector}' is not allowed.")),
+    }
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restricted: Vec<RestrictedSyntax>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    /// The source text of the selector, reported when no message is given.
+    source: CompactStr,
+    selector: Selector,
+    message: Option<CompactStr>,
+}
+
+impl std::ops::Deref for NoRestrictedSyntax {
+    type Target = NoRestrictedSyntaxConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified syntax, described by selectors matching AST nodes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has a lot of language features, and not everyone likes all of them. Some
+    /// projects want to disallow specific features, e.g. `with` statements or `for-in` loops,
+    /// or enforce bespoke conventions, e.g. forbidding calls to a deprecated internal function.
+    ///
+    /// ### Options
+    ///
+    /// Selectors follow the [esquery](https://github.com/estools/esquery) syntax and match the
+    /// [ESTree](https://github.com/estree/estree) node types, so selectors written for ESLint
+    /// can be reused. A selector can be given as a string, or as an object with a custom
+    /// message:
+    ///
+    /// ```json
+    /// "no-restricted-syntax": ["error", "WithStatement", {
+    ///   "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]",
+    ///   "message": "setTimeout must always be invoked with two arguments."
+    /// }]
+    /// ```
+    ///
+    /// The following subset of esquery is supported:
+    /// * node types, e.g. `CallExpression`, and the wildcard `*`
+    /// * attributes, e.g. `[name]`, `[callee.name="eval"]`, `[value=/^foo/i]` and
+    ///   `[arguments.length>2]`
+    /// * the descendant (`A B`) and child (`A > B`) combinators
+    /// * `:not(...)`, `:matches(...)`, `:is(...)` and `:has(...)`
+    /// * the node classes `:statement`, `:declaration`, `:expression`, `:function` and `:pattern`
+    /// * comma separated lists of selectors
+    ///
+    /// Selectors using sibling combinators or positional pseudo classes such as `:first-child`
+    /// are ignored.
+    ///
+    /// ### Example
+    ///
+    /// Examples of **incorrect** code for this rule with the options above:
+    /// ```js
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    ///
+    /// setTimeout(callback);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the options above:
+    /// ```js
+    /// me.dontMess();
+    ///
+    /// setTimeout(callback, 0);
+    /// ```
+    NoRestrictedSyntax,
+    restriction,
+);
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        let restricted = value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|option| {
+                let (source, message) = match option {
+                    // "no-restricted-syntax": ["error", "WithStatement"]
+                    Value::String(selector) => (selector.as_str(), None),
+                    // "no-restricted-syntax": ["error", { "selector": "WithStatement", "message": "..." }]
+                    Value::Object(obj) => (
+                        obj.get("selector")?.as_str()?,
+                        obj.get("message").and_then(Value::as_str).map(CompactStr::from),
+                    ),
+                    _ => return None,
+                };
+                let selector = Selector::parse(source).ok()?;
+                Some(RestrictedSyntax { source: CompactStr::from(source), selector, message })
+            })
+            .collect();
+
+        Self(Box::new(NoRestrictedSyntaxConfig { restricted }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        for restricted in &self.restricted {
+            if restricted.selector.matches(node, ctx.nodes()) {
+                ctx.diagnostic(no_restricted_syntax(
+                    &restricted.source,
+                    restricted.message.as_ref(),
+                    node.kind().span(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("debugger;", None),
+        ("debugger;", Some(serde_json::json!(["VariableDeclaration"]))),
+        ("foo(a, b);", Some(serde_json::json!(["CallExpression[arguments.length>2]"]))),
+        ("evil('x');", Some(serde_json::json!(["CallExpression[callee.name='eval']"]))),
+        ("const x = 'world';", Some(serde_json::json!(["Literal[value=/^hel/]"]))),
+        ("foo;", Some(serde_json::json!(["MemberExpression > Identifier[name='foo']"]))),
+        (
+            "function foo() { return 1; }",
+            Some(serde_json::json!([":function:has(ThisExpression)"])),
+        ),
+        ("function foo() {}", Some(serde_json::json!(["FunctionDeclaration[async=true]"]))),
+        (
+            "class A { get foo() {} }",
+            Some(serde_json::json!(["ClassDeclaration MethodDefinition[kind='method']"])),
+        ),
+        ("(foo);", Some(serde_json::json!(["ParenthesizedExpression"]))),
+        ("foo(bar);", Some(serde_json::json!(["Identifier ~ Identifier"]))),
+    ];
+
+    let fail = vec![
+        ("debugger;", Some(serde_json::json!(["DebuggerStatement"]))),
+        ("var foo = 41;", Some(serde_json::json!(["VariableDeclaration"]))),
+        (
+            "eval('x');",
+            Some(serde_json::json!([{
+                "selector": "CallExpression[callee.name='eval']",
+                "message": "eval is evil."
+            }])),
+        ),
+        ("foo(a, b, c);", Some(serde_json::json!(["CallExpression[arguments.length>2]"]))),
+        ("const x = 'hello';", Some(serde_json::json!(["Literal[value=/^hel/]"]))),
+        ("foo.bar.baz;", Some(serde_json::json!(["MemberExpression > Identifier[name='foo']"]))),
+        (
+            "function foo() { return this; }",
+            Some(serde_json::json!([":function:has(ThisExpression)"])),
+        ),
+        ("for (const x of xs) {}", Some(serde_json::json!(["ForInStatement, ForOfStatement"]))),
+        ("for (k in o) {}", Some(serde_json::json!(["ForInStatement, ForOfStatement"]))),
+        ("async function foo() {}", Some(serde_json::json!(["FunctionDeclaration[async=true]"]))),
+        (
+            "class A { foo() {} }",
+            Some(serde_json::json!(["ClassDeclaration MethodDefinition[kind='method']"])),
+        ),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,1009 @@
+//! A subset of [esquery](https://github.com/estools/esquery) selectors, matched against the oxc
+//! AST as if it was an ESTree AST.
+//!
+//! Supported selectors are:
+//! * node types, e.g. `CallExpression`, and the wildcard `*`
+//! * attributes, e.g. `[name]`, `[callee.name="eval"]`, `[value=/^foo/i]`, `[arguments.length>2]`
+//! * the descendant (`A B`) and child (`A > B`) combinators
+//! * `:not(...)`, `:matches(...)`, `:is(...)` and `:has(...)`
+//! * the node classes `:statement`, `:declaration`, `:expression`, `:function` and `:pattern`
+//! * comma separated lists of selectors
+//!
+//! Sibling combinators and positional pseudo classes such as `:first-child` are not supported.
+
+use std::{cmp::Ordering, iter::Peekable, str::CharIndices};
+
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, BindingPatternKind, Expression, FunctionType,
+        ImportDeclarationSpecifier, MemberExpression, MethodDefinitionKind, ObjectPropertyKind,
+        PropertyKey, PropertyKind, SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_index::Idx;
+use oxc_semantic::{AstNode, AstNodes};
+use regex::{Regex, RegexBuilder};
+
+#[derive(Debug, Clone)]
+pub enum Selector {
+    /// `*`
+    Wildcard,
+    /// `CallExpression`
+    Type(String),
+    /// `[callee.name="eval"]`
+    Attribute(Attribute),
+    /// `:statement`
+    Class(NodeClass),
+    /// `:not(A, B)`
+    Not(Vec<Selector>),
+    /// `:matches(A, B)`, `:is(A, B)` and `A, B`
+    Matches(Vec<Selector>),
+    /// `:has(A)`
+    Has(Box<Selector>),
+    /// `A[b]:not(c)`
+    Compound(Vec<Selector>),
+    /// `A B`
+    Descendant(Box<Selector>, Box<Selector>),
+    /// `A > B`
+    Child(Box<Selector>, Box<Selector>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    path: Vec<String>,
+    test: Option<(Operator, Literal)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+#[derive(Debug, Clone)]
+enum Literal {
+    String(String),
+    Number(f64),
+    Regex(Regex),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum NodeClass {
+    Statement,
+    Declaration,
+    Expression,
+    Function,
+    Pattern,
+}
+
+impl Selector {
+    /// Parse a selector, returning a description of the error if it is invalid or unsupported.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut parser = Parser { source, chars: source.char_indices().peekable() };
+        parser.skip_whitespace();
+        let selector = parser.parse_selectors()?;
+        match parser.chars.next() {
+            None => Ok(selector),
+            Some((_, c)) => Err(format!("Unexpected character '{c}'")),
+        }
+    }
+
+    /// Whether `node` matches the selector.
+    ///
+    /// Nodes which only exist in the oxc AST, e.g. [`AstKind::Argument`], never match.
+    pub fn matches<'a>(&self, node: &AstNode<'a>, nodes: &AstNodes<'a>) -> bool {
+        estree_type(node.kind()).is_some() && self.matches_node(node, nodes)
+    }
+
+    fn matches_node<'a>(&self, node: &AstNode<'a>, nodes: &AstNodes<'a>) -> bool {
+        match self {
+            Self::Wildcard => true,
+            Self::Type(name) => {
+                estree_type(node.kind()).is_some_and(|ty| ty.eq_ignore_ascii_case(name))
+            }
+            Self::Attribute(attribute) => attribute.matches(node.kind()),
+            Self::Class(class) => estree_type(node.kind()).is_some_and(|ty| class.matches(ty)),
+            Self::Not(selectors) => !selectors.iter().any(|s| s.matches_node(node, nodes)),
+            Self::Matches(selectors) => selectors.iter().any(|s| s.matches_node(node, nodes)),
+            Self::Has(selector) => nodes
+                .iter()
+                .skip(node.id().index() + 1)
+                // Nodes are added in pre-order, so the descendants of a node directly follow it.
+                .take_while(|descendant| nodes.parent_id(descendant.id()) >= Some(node.id()))
+                .any(|descendant| selector.matches(descendant, nodes)),
+            Self::Compound(selectors) => selectors.iter().all(|s| s.matches_node(node, nodes)),
+            Self::Descendant(ancestor, selector) => {
+                selector.matches_node(node, nodes)
+                    && estree_ancestors(node, nodes)
+                        .any(|ancestor_node| ancestor.matches_node(ancestor_node, nodes))
+            }
+            Self::Child(parent, selector) => {
+                selector.matches_node(node, nodes)
+                    && estree_ancestors(node, nodes)
+                        .next()
+                        .is_some_and(|parent_node| parent.matches_node(parent_node, nodes))
+            }
+        }
+    }
+}
+
+/// The ancestors of `node` which exist in ESTree, from its parent to the root.
+fn estree_ancestors<'s, 'a>(
+    node: &AstNode<'a>,
+    nodes: &'s AstNodes<'a>,
+) -> impl Iterator<Item = &'s AstNode<'a>> + 's {
+    nodes.ancestors(node.id()).skip(1).filter(|node| estree_type(node.kind()).is_some())
+}
+
+impl NodeClass {
+    fn matches(self, ty: &str) -> bool {
+        match self {
+            Self::Statement => ty.ends_with("Statement") || ty.ends_with("Declaration"),
+            Self::Declaration => ty.ends_with("Declaration"),
+            Self::Expression => {
+                ty.ends_with("Expression")
+                    || matches!(ty, "Identifier" | "Literal" | "MetaProperty")
+            }
+            Self::Function => {
+                matches!(
+                    ty,
+                    "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+                )
+            }
+            Self::Pattern => {
+                ty.ends_with("Pattern") || Self::Expression.matches(ty) || ty == "RestElement"
+            }
+        }
+    }
+}
+
+impl Attribute {
+    fn matches(&self, kind: AstKind) -> bool {
+        let value = resolve(kind, &self.path);
+        let Some((operator, literal)) = &self.test else {
+            return value.is_some_and(|value| !matches!(value, Value::Null));
+        };
+        let value = value.as_ref();
+        match literal {
+            Literal::Regex(regex) => {
+                let is_match = matches!(value, Some(Value::String(s)) if regex.is_match(s));
+                is_match == (*operator == Operator::Equal)
+            }
+            Literal::String(expected) => {
+                let is_equal = value.and_then(Value::to_js_string).is_some_and(|s| s == *expected);
+                match operator {
+                    Operator::Equal => is_equal,
+                    Operator::NotEqual => !is_equal,
+                    _ => false,
+                }
+            }
+            Literal::Number(expected) => {
+                let Some(actual) = value.and_then(Value::to_number) else {
+                    return *operator == Operator::NotEqual;
+                };
+                let ordering = actual.partial_cmp(expected);
+                match operator {
+                    Operator::Equal => ordering == Some(Ordering::Equal),
+                    Operator::NotEqual => ordering != Some(Ordering::Equal),
+                    Operator::Less => ordering == Some(Ordering::Less),
+                    Operator::LessEqual => {
+                        matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+                    }
+                    Operator::Greater => ordering == Some(Ordering::Greater),
+                    Operator::GreaterEqual => {
+                        matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An ESTree property value of a node.
+#[derive(Debug)]
+enum Value<'a> {
+    Node(AstKind<'a>),
+    List(Vec<Value<'a>>),
+    String(&'a str),
+    Number(f64),
+    Boolean(bool),
+    Null,
+}
+
+impl<'a> Value<'a> {
+    fn to_js_string(&self) -> Option<String> {
+        match self {
+            Self::String(s) => Some((*s).to_string()),
+            // `1.0` is displayed as `1`, like in JavaScript.
+            Self::Number(n) => Some(n.to_string()),
+            Self::Boolean(b) => Some(b.to_string()),
+            Self::Null => Some("null".to_string()),
+            Self::Node(_) | Self::List(_) => None,
+        }
+    }
+
+    fn to_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn expression(expr: &'a Expression<'a>) -> Self {
+        Self::Node(AstKind::from_expression(expr.without_parentheses()))
+    }
+
+    fn optional_expression(expr: Option<&'a Expression<'a>>) -> Self {
+        expr.map_or(Self::Null, Self::expression)
+    }
+
+    fn argument(argument: &'a Argument<'a>) -> Self {
+        match argument {
+            Argument::SpreadElement(spread) => Self::Node(AstKind::SpreadElement(spread)),
+            _ => argument.as_expression().map_or(Self::Null, Self::expression),
+        }
+    }
+
+    fn binding_pattern(kind: &'a BindingPatternKind<'a>) -> Self {
+        Self::Node(match kind {
+            BindingPatternKind::BindingIdentifier(ident) => AstKind::BindingIdentifier(ident),
+            BindingPatternKind::ObjectPattern(pattern) => AstKind::ObjectPattern(pattern),
+            BindingPatternKind::ArrayPattern(pattern) => AstKind::ArrayPattern(pattern),
+            BindingPatternKind::AssignmentPattern(pattern) => AstKind::AssignmentPattern(pattern),
+        })
+    }
+
+    fn simple_assignment_target(target: &'a SimpleAssignmentTarget<'a>) -> Self {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                Self::Node(AstKind::IdentifierReference(ident))
+            }
+            _ => target
+                .as_member_expression()
+                .map_or(Self::Null, |member| Self::Node(AstKind::MemberExpression(member))),
+        }
+    }
+
+    fn property_key(key: &'a PropertyKey<'a>) -> Self {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => Self::Node(AstKind::IdentifierName(ident)),
+            PropertyKey::PrivateIdentifier(ident) => Self::Node(AstKind::PrivateIdentifier(ident)),
+            _ => key.as_expression().map_or(Self::Null, Self::expression),
+        }
+    }
+}
+
+fn resolve<'a>(kind: AstKind<'a>, path: &[String]) -> Option<Value<'a>> {
+    let mut value = Value::Node(kind);
+    for segment in path {
+        value = match value {
+            Value::Node(kind) => field(kind, segment)?,
+            Value::List(items) if segment == "length" => {
+                Value::Number(f64::from(u32::try_from(items.len()).ok()?))
+            }
+            Value::List(items) => items.into_iter().nth(segment.parse().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+/// The value of the ESTree property `name` of `kind`.
+fn field<'a>(kind: AstKind<'a>, name: &str) -> Option<Value<'a>> {
+    let value = match (kind, name) {
+        (_, "type") => Value::String(estree_type(kind)?),
+
+        (AstKind::IdentifierName(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::IdentifierReference(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::BindingIdentifier(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::LabelIdentifier(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::PrivateIdentifier(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::JSXIdentifier(ident), "name") => Value::String(ident.name.as_str()),
+
+        (AstKind::StringLiteral(lit), "value") => Value::String(lit.value.as_str()),
+        (AstKind::NumericLiteral(lit), "value") => Value::Number(lit.value),
+        (AstKind::BooleanLiteral(lit), "value") => Value::Boolean(lit.value),
+        (AstKind::NullLiteral(_), "value") => Value::Null,
+
+        (AstKind::BinaryExpression(expr), "operator") => Value::String(expr.operator.as_str()),
+        (AstKind::LogicalExpression(expr), "operator") => Value::String(expr.operator.as_str()),
+        (AstKind::UnaryExpression(expr), "operator") => Value::String(expr.operator.as_str()),
+        (AstKind::UpdateExpression(expr), "operator") => Value::String(expr.operator.as_str()),
+        (AstKind::AssignmentExpression(expr), "operator") => Value::String(expr.operator.as_str()),
+        (AstKind::UnaryExpression(_), "prefix") => Value::Boolean(true),
+        (AstKind::UpdateExpression(expr), "prefix") => Value::Boolean(expr.prefix),
+
+        (AstKind::BinaryExpression(expr), "left") => Value::expression(&expr.left),
+        (AstKind::BinaryExpression(expr), "right") => Value::expression(&expr.right),
+        (AstKind::LogicalExpression(expr), "left") => Value::expression(&expr.left),
+        (AstKind::LogicalExpression(expr), "right") => Value::expression(&expr.right),
+        (AstKind::AssignmentExpression(expr), "left") => expr
+            .left
+            .as_simple_assignment_target()
+            .map_or(Value::Null, Value::simple_assignment_target),
+        (AstKind::AssignmentExpression(expr), "right") => Value::expression(&expr.right),
+
+        (AstKind::UnaryExpression(expr), "argument") => Value::expression(&expr.argument),
+        (AstKind::UpdateExpression(expr), "argument") => {
+            Value::simple_assignment_target(&expr.argument)
+        }
+        (AstKind::AwaitExpression(expr), "argument") => Value::expression(&expr.argument),
+        (AstKind::SpreadElement(expr), "argument") => Value::expression(&expr.argument),
+        (AstKind::YieldExpression(expr), "argument") => {
+            Value::optional_expression(expr.argument.as_ref())
+        }
+        (AstKind::ReturnStatement(stmt), "argument") => {
+            Value::optional_expression(stmt.argument.as_ref())
+        }
+        (AstKind::ThrowStatement(stmt), "argument") => Value::expression(&stmt.argument),
+        (AstKind::YieldExpression(expr), "delegate") => Value::Boolean(expr.delegate),
+
+        (AstKind::CallExpression(call), "callee") => Value::expression(&call.callee),
+        (AstKind::CallExpression(call), "arguments") => {
+            Value::List(call.arguments.iter().map(Value::argument).collect())
+        }
+        (AstKind::CallExpression(call), "optional") => Value::Boolean(call.optional),
+        (AstKind::NewExpression(new), "callee") => Value::expression(&new.callee),
+        (AstKind::NewExpression(new), "arguments") => {
+            Value::List(new.arguments.iter().map(Value::argument).collect())
+        }
+        (AstKind::ImportExpression(import), "source") => Value::expression(&import.source),
+        (AstKind::TaggedTemplateExpression(expr), "tag") => Value::expression(&expr.tag),
+
+        (AstKind::MemberExpression(member), "object") => Value::expression(member.object()),
+        (AstKind::MemberExpression(member), "property") => match member {
+            MemberExpression::ComputedMemberExpression(expr) => Value::expression(&expr.expression),
+            MemberExpression::StaticMemberExpression(expr) => {
+                Value::Node(AstKind::IdentifierName(&expr.property))
+            }
+            MemberExpression::PrivateFieldExpression(expr) => {
+                Value::Node(AstKind::PrivateIdentifier(&expr.field))
+            }
+        },
+        (AstKind::MemberExpression(member), "computed") => Value::Boolean(member.is_computed()),
+        (AstKind::MemberExpression(member), "optional") => Value::Boolean(member.optional()),
+        (AstKind::MetaProperty(meta), "meta") => Value::Node(AstKind::IdentifierName(&meta.meta)),
+        (AstKind::MetaProperty(meta), "property") => {
+            Value::Node(AstKind::IdentifierName(&meta.property))
+        }
+
+        (AstKind::ConditionalExpression(expr), "test") => Value::expression(&expr.test),
+        (AstKind::ConditionalExpression(expr), "consequent") => Value::expression(&expr.consequent),
+        (AstKind::ConditionalExpression(expr), "alternate") => Value::expression(&expr.alternate),
+        (AstKind::IfStatement(stmt), "test") => Value::expression(&stmt.test),
+        (AstKind::WhileStatement(stmt), "test") => Value::expression(&stmt.test),
+        (AstKind::DoWhileStatement(stmt), "test") => Value::expression(&stmt.test),
+        (AstKind::ForStatement(stmt), "test") => Value::optional_expression(stmt.test.as_ref()),
+        (AstKind::ExpressionStatement(stmt), "expression") => Value::expression(&stmt.expression),
+
+        (AstKind::ArrayExpression(array), "elements") => Value::List(
+            array
+                .elements
+                .iter()
+                .map(|element| match element {
+                    ArrayExpressionElement::SpreadElement(spread) => {
+                        Value::Node(AstKind::SpreadElement(spread))
+                    }
+                    ArrayExpressionElement::Elision(_) => Value::Null,
+                    _ => element.as_expression().map_or(Value::Null, Value::expression),
+                })
+                .collect(),
+        ),
+        (AstKind::ObjectExpression(object), "properties") => Value::List(
+            object
+                .properties
+                .iter()
+                .map(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        Value::Node(AstKind::ObjectProperty(property))
+                    }
+                    ObjectPropertyKind::SpreadProperty(spread) => {
+                        Value::Node(AstKind::SpreadElement(spread))
+                    }
+                })
+                .collect(),
+        ),
+        (AstKind::SequenceExpression(expr), "expressions") => {
+            Value::List(expr.expressions.iter().map(Value::expression).collect())
+        }
+        (AstKind::TemplateLiteral(lit), "expressions") => {
+            Value::List(lit.expressions.iter().map(Value::expression).collect())
+        }
+
+        (AstKind::ObjectProperty(property), "key") => Value::property_key(&property.key),
+        (AstKind::ObjectProperty(property), "value") => Value::expression(&property.value),
+        (AstKind::ObjectProperty(property), "kind") => Value::String(match property.kind {
+            PropertyKind::Init => "init",
+            PropertyKind::Get => "get",
+            PropertyKind::Set => "set",
+        }),
+        (AstKind::ObjectProperty(property), "computed") => Value::Boolean(property.computed),
+        (AstKind::ObjectProperty(property), "method") => Value::Boolean(property.method),
+        (AstKind::ObjectProperty(property), "shorthand") => Value::Boolean(property.shorthand),
+        (AstKind::MethodDefinition(method), "key") => Value::property_key(&method.key),
+        (AstKind::MethodDefinition(method), "value") => {
+            Value::Node(AstKind::Function(&method.value))
+        }
+        (AstKind::MethodDefinition(method), "kind") => Value::String(match method.kind {
+            MethodDefinitionKind::Constructor => "constructor",
+            MethodDefinitionKind::Method => "method",
+            MethodDefinitionKind::Get => "get",
+            MethodDefinitionKind::Set => "set",
+        }),
+        (AstKind::MethodDefinition(method), "computed") => Value::Boolean(method.computed),
+        (AstKind::MethodDefinition(method), "static") => Value::Boolean(method.r#static),
+        (AstKind::PropertyDefinition(property), "key") => Value::property_key(&property.key),
+        (AstKind::PropertyDefinition(property), "value") => {
+            Value::optional_expression(property.value.as_ref())
+        }
+        (AstKind::PropertyDefinition(property), "computed") => Value::Boolean(property.computed),
+        (AstKind::PropertyDefinition(property), "static") => Value::Boolean(property.r#static),
+
+        (AstKind::Function(func), "id") => {
+            func.id.as_ref().map_or(Value::Null, |id| Value::Node(AstKind::BindingIdentifier(id)))
+        }
+        (AstKind::Function(func), "async") => Value::Boolean(func.r#async),
+        (AstKind::Function(func), "generator") => Value::Boolean(func.generator),
+        (AstKind::Function(func), "params") => Value::List(
+            func.params
+                .items
+                .iter()
+                .map(|param| Value::binding_pattern(&param.pattern.kind))
+                .collect(),
+        ),
+        (AstKind::ArrowFunctionExpression(arrow), "async") => Value::Boolean(arrow.r#async),
+        (AstKind::ArrowFunctionExpression(_), "generator") => Value::Boolean(false),
+        (AstKind::ArrowFunctionExpression(arrow), "params") => Value::List(
+            arrow
+                .params
+                .items
+                .iter()
+                .map(|param| Value::binding_pattern(&param.pattern.kind))
+                .collect(),
+        ),
+        (AstKind::Class(class), "id") => {
+            class.id.as_ref().map_or(Value::Null, |id| Value::Node(AstKind::BindingIdentifier(id)))
+        }
+        (AstKind::Class(class), "superClass") => {
+            Value::optional_expression(class.super_class.as_ref())
+        }
+
+        (AstKind::VariableDeclaration(decl), "kind") => Value::String(decl.kind.as_str()),
+        (AstKind::VariableDeclaration(decl), "declarations") => Value::List(
+            decl.declarations
+                .iter()
+                .map(|declarator| Value::Node(AstKind::VariableDeclarator(declarator)))
+                .collect(),
+        ),
+        (AstKind::VariableDeclarator(declarator), "id") => {
+            Value::binding_pattern(&declarator.id.kind)
+        }
+        (AstKind::VariableDeclarator(declarator), "init") => {
+            Value::optional_expression(declarator.init.as_ref())
+        }
+
+        (AstKind::ImportDeclaration(decl), "source") => {
+            Value::Node(AstKind::StringLiteral(&decl.source))
+        }
+        (AstKind::ImportDeclaration(decl), "specifiers") => Value::List(
+            decl.specifiers
+                .iter()
+                .flatten()
+                .map(|specifier| {
+                    Value::Node(match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            AstKind::ImportSpecifier(specifier)
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                            AstKind::ImportDefaultSpecifier(specifier)
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                            AstKind::ImportNamespaceSpecifier(specifier)
+                        }
+                    })
+                })
+                .collect(),
+        ),
+        (AstKind::ExportNamedDeclaration(decl), "source") => decl
+            .source
+            .as_ref()
+            .map_or(Value::Null, |source| Value::Node(AstKind::StringLiteral(source))),
+        (AstKind::ExportAllDeclaration(decl), "source") => {
+            Value::Node(AstKind::StringLiteral(&decl.source))
+        }
+
+        (AstKind::BreakStatement(stmt), "label") => stmt
+            .label
+            .as_ref()
+            .map_or(Value::Null, |label| Value::Node(AstKind::LabelIdentifier(label))),
+        (AstKind::ContinueStatement(stmt), "label") => stmt
+            .label
+            .as_ref()
+            .map_or(Value::Null, |label| Value::Node(AstKind::LabelIdentifier(label))),
+        (AstKind::LabeledStatement(stmt), "label") => {
+            Value::Node(AstKind::LabelIdentifier(&stmt.label))
+        }
+
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// The ESTree type of `kind`, or [`None`] if it only exists in the oxc AST.
+fn estree_type(kind: AstKind) -> Option<&'static str> {
+    let ty = match kind {
+        AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::NumericLiteral(_)
+        | AstKind::BigIntLiteral(_)
+        | AstKind::RegExpLiteral(_)
+        | AstKind::StringLiteral(_) => "Literal",
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_) => "Identifier",
+        AstKind::ArrayExpressionElement(_)
+        | AstKind::Elision(_)
+        | AstKind::PropertyKey(_)
+        | AstKind::Argument(_)
+        | AstKind::AssignmentTarget(_)
+        | AstKind::SimpleAssignmentTarget(_)
+        | AstKind::AssignmentTargetPattern(_)
+        | AstKind::ParenthesizedExpression(_)
+        | AstKind::Hashbang(_)
+        | AstKind::ForStatementInit(_)
+        | AstKind::CatchParameter(_)
+        | AstKind::FormalParameters(_)
+        | AstKind::FormalParameter(_)
+        | AstKind::ModuleDeclaration(_)
+        | AstKind::TSTypeName(_)
+        | AstKind::TSModuleReference(_)
+        | AstKind::JSXElementName(_)
+        | AstKind::JSXMemberExpressionObject(_) => return None,
+        AstKind::ArrayAssignmentTarget(_) => "ArrayPattern",
+        AstKind::ObjectAssignmentTarget(_) => "ObjectPattern",
+        AstKind::AssignmentTargetWithDefault(_) => "AssignmentPattern",
+        AstKind::ObjectProperty(_) => "Property",
+        AstKind::BindingRestElement(_) => "RestElement",
+        AstKind::Directive(_) => "ExpressionStatement",
+        AstKind::FunctionBody(_) => "BlockStatement",
+        AstKind::Function(func) => match func.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+            FunctionType::TSEmptyBodyFunctionExpression => "TSEmptyBodyFunctionExpression",
+        },
+        AstKind::Class(class) if class.is_declaration() => "ClassDeclaration",
+        AstKind::Class(_) => "ClassExpression",
+        AstKind::JSXAttributeItem(item) => item.as_attribute().map(|_| "JSXAttribute")?,
+        AstKind::Program(_) => "Program",
+        AstKind::ThisExpression(_) => "ThisExpression",
+        AstKind::ArrayExpression(_) => "ArrayExpression",
+        AstKind::ObjectExpression(_) => "ObjectExpression",
+        AstKind::TemplateLiteral(_) => "TemplateLiteral",
+        AstKind::TaggedTemplateExpression(_) => "TaggedTemplateExpression",
+        AstKind::MemberExpression(_) => "MemberExpression",
+        AstKind::CallExpression(_) => "CallExpression",
+        AstKind::NewExpression(_) => "NewExpression",
+        AstKind::MetaProperty(_) => "MetaProperty",
+        AstKind::SpreadElement(_) => "SpreadElement",
+        AstKind::UpdateExpression(_) => "UpdateExpression",
+        AstKind::UnaryExpression(_) => "UnaryExpression",
+        AstKind::BinaryExpression(_) => "BinaryExpression",
+        AstKind::PrivateInExpression(_) => "PrivateInExpression",
+        AstKind::LogicalExpression(_) => "LogicalExpression",
+        AstKind::ConditionalExpression(_) => "ConditionalExpression",
+        AstKind::AssignmentExpression(_) => "AssignmentExpression",
+        AstKind::SequenceExpression(_) => "SequenceExpression",
+        AstKind::Super(_) => "Super",
+        AstKind::AwaitExpression(_) => "AwaitExpression",
+        AstKind::ChainExpression(_) => "ChainExpression",
+        AstKind::BlockStatement(_) => "BlockStatement",
+        AstKind::VariableDeclaration(_) => "VariableDeclaration",
+        AstKind::VariableDeclarator(_) => "VariableDeclarator",
+        AstKind::EmptyStatement(_) => "EmptyStatement",
+        AstKind::ExpressionStatement(_) => "ExpressionStatement",
+        AstKind::IfStatement(_) => "IfStatement",
+        AstKind::DoWhileStatement(_) => "DoWhileStatement",
+        AstKind::WhileStatement(_) => "WhileStatement",
+        AstKind::ForStatement(_) => "ForStatement",
+        AstKind::ForInStatement(_) => "ForInStatement",
+        AstKind::ForOfStatement(_) => "ForOfStatement",
+        AstKind::ContinueStatement(_) => "ContinueStatement",
+        AstKind::BreakStatement(_) => "BreakStatement",
+        AstKind::ReturnStatement(_) => "ReturnStatement",
+        AstKind::WithStatement(_) => "WithStatement",
+        AstKind::SwitchStatement(_) => "SwitchStatement",
+        AstKind::SwitchCase(_) => "SwitchCase",
+        AstKind::LabeledStatement(_) => "LabeledStatement",
+        AstKind::ThrowStatement(_) => "ThrowStatement",
+        AstKind::TryStatement(_) => "TryStatement",
+        AstKind::CatchClause(_) => "CatchClause",
+        AstKind::DebuggerStatement(_) => "DebuggerStatement",
+        AstKind::AssignmentPattern(_) => "AssignmentPattern",
+        AstKind::ObjectPattern(_) => "ObjectPattern",
+        AstKind::ArrayPattern(_) => "ArrayPattern",
+        AstKind::ArrowFunctionExpression(_) => "ArrowFunctionExpression",
+        AstKind::YieldExpression(_) => "YieldExpression",
+        AstKind::ClassBody(_) => "ClassBody",
+        AstKind::MethodDefinition(_) => "MethodDefinition",
+        AstKind::PropertyDefinition(_) => "PropertyDefinition",
+        AstKind::PrivateIdentifier(_) => "PrivateIdentifier",
+        AstKind::StaticBlock(_) => "StaticBlock",
+        AstKind::ImportExpression(_) => "ImportExpression",
+        AstKind::ImportDeclaration(_) => "ImportDeclaration",
+        AstKind::ImportSpecifier(_) => "ImportSpecifier",
+        AstKind::ImportDefaultSpecifier(_) => "ImportDefaultSpecifier",
+        AstKind::ImportNamespaceSpecifier(_) => "ImportNamespaceSpecifier",
+        AstKind::ExportNamedDeclaration(_) => "ExportNamedDeclaration",
+        AstKind::ExportDefaultDeclaration(_) => "ExportDefaultDeclaration",
+        AstKind::ExportAllDeclaration(_) => "ExportAllDeclaration",
+        AstKind::ExportSpecifier(_) => "ExportSpecifier",
+        AstKind::TSThisParameter(_) => "TSThisParameter",
+        AstKind::TSEnumDeclaration(_) => "TSEnumDeclaration",
+        AstKind::TSEnumMember(_) => "TSEnumMember",
+        AstKind::TSTypeAnnotation(_) => "TSTypeAnnotation",
+        AstKind::TSLiteralType(_) => "TSLiteralType",
+        AstKind::TSConditionalType(_) => "TSConditionalType",
+        AstKind::TSUnionType(_) => "TSUnionType",
+        AstKind::TSIntersectionType(_) => "TSIntersectionType",
+        AstKind::TSParenthesizedType(_) => "TSParenthesizedType",
+        AstKind::TSIndexedAccessType(_) => "TSIndexedAccessType",
+        AstKind::TSNamedTupleMember(_) => "TSNamedTupleMember",
+        AstKind::TSAnyKeyword(_) => "TSAnyKeyword",
+        AstKind::TSStringKeyword(_) => "TSStringKeyword",
+        AstKind::TSBooleanKeyword(_) => "TSBooleanKeyword",
+        AstKind::TSNumberKeyword(_) => "TSNumberKeyword",
+        AstKind::TSNeverKeyword(_) => "TSNeverKeyword",
+        AstKind::TSIntrinsicKeyword(_) => "TSIntrinsicKeyword",
+        AstKind::TSUnknownKeyword(_) => "TSUnknownKeyword",
+        AstKind::TSNullKeyword(_) => "TSNullKeyword",
+        AstKind::TSUndefinedKeyword(_) => "TSUndefinedKeyword",
+        AstKind::TSVoidKeyword(_) => "TSVoidKeyword",
+        AstKind::TSSymbolKeyword(_) => "TSSymbolKeyword",
+        AstKind::TSThisType(_) => "TSThisType",
+        AstKind::TSObjectKeyword(_) => "TSObjectKeyword",
+        AstKind::TSBigIntKeyword(_) => "TSBigIntKeyword",
+        AstKind::TSTypeReference(_) => "TSTypeReference",
+        AstKind::TSQualifiedName(_) => "TSQualifiedName",
+        AstKind::TSTypeParameterInstantiation(_) => "TSTypeParameterInstantiation",
+        AstKind::TSTypeParameter(_) => "TSTypeParameter",
+        AstKind::TSTypeParameterDeclaration(_) => "TSTypeParameterDeclaration",
+        AstKind::TSTypeAliasDeclaration(_) => "TSTypeAliasDeclaration",
+        AstKind::TSClassImplements(_) => "TSClassImplements",
+        AstKind::TSInterfaceDeclaration(_) => "TSInterfaceDeclaration",
+        AstKind::TSPropertySignature(_) => "TSPropertySignature",
+        AstKind::TSMethodSignature(_) => "TSMethodSignature",
+        AstKind::TSConstructSignatureDeclaration(_) => "TSConstructSignatureDeclaration",
+        AstKind::TSInterfaceHeritage(_) => "TSInterfaceHeritage",
+        AstKind::TSModuleDeclaration(_) => "TSModuleDeclaration",
+        AstKind::TSModuleBlock(_) => "TSModuleBlock",
+        AstKind::TSTypeLiteral(_) => "TSTypeLiteral",
+        AstKind::TSInferType(_) => "TSInferType",
+        AstKind::TSTypeQuery(_) => "TSTypeQuery",
+        AstKind::TSImportType(_) => "TSImportType",
+        AstKind::TSMappedType(_) => "TSMappedType",
+        AstKind::TSTemplateLiteralType(_) => "TSTemplateLiteralType",
+        AstKind::TSAsExpression(_) => "TSAsExpression",
+        AstKind::TSSatisfiesExpression(_) => "TSSatisfiesExpression",
+        AstKind::TSTypeAssertion(_) => "TSTypeAssertion",
+        AstKind::TSImportEqualsDeclaration(_) => "TSImportEqualsDeclaration",
+        AstKind::TSExternalModuleReference(_) => "TSExternalModuleReference",
+        AstKind::TSNonNullExpression(_) => "TSNonNullExpression",
+        AstKind::Decorator(_) => "Decorator",
+        AstKind::TSExportAssignment(_) => "TSExportAssignment",
+        AstKind::TSInstantiationExpression(_) => "TSInstantiationExpression",
+        AstKind::JSXElement(_) => "JSXElement",
+        AstKind::JSXOpeningElement(_) => "JSXOpeningElement",
+        AstKind::JSXClosingElement(_) => "JSXClosingElement",
+        AstKind::JSXFragment(_) => "JSXFragment",
+        AstKind::JSXNamespacedName(_) => "JSXNamespacedName",
+        AstKind::JSXMemberExpression(_) => "JSXMemberExpression",
+        AstKind::JSXExpressionContainer(_) => "JSXExpressionContainer",
+        AstKind::JSXSpreadAttribute(_) => "JSXSpreadAttribute",
+        AstKind::JSXIdentifier(_) => "JSXIdentifier",
+        AstKind::JSXText(_) => "JSXText",
+    };
+    Some(ty)
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    chars: Peekable<CharIndices<'s>>,
+}
+
+impl<'s> Parser<'s> {
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let is_expected = self.peek() == Some(expected);
+        if is_expected {
+            self.chars.next();
+        }
+        is_expected
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            match self.peek() {
+                Some(c) => Err(format!("Expected '{expected}', found '{c}'")),
+                None => Err(format!("Expected '{expected}'")),
+            }
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let mut skipped = false;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.chars.next();
+            skipped = true;
+        }
+        skipped
+    }
+
+    /// `A, B`
+    fn parse_selectors(&mut self) -> Result<Selector, String> {
+        let mut selectors = vec![self.parse_complex()?];
+        while self.eat(',') {
+            self.skip_whitespace();
+            selectors.push(self.parse_complex()?);
+        }
+        Ok(if selectors.len() == 1 { selectors.remove(0) } else { Selector::Matches(selectors) })
+    }
+
+    /// `A > B C`
+    fn parse_complex(&mut self) -> Result<Selector, String> {
+        let mut selector = self.parse_compound()?;
+        loop {
+            let has_whitespace = self.skip_whitespace();
+            match self.peek() {
+                Some('>') => {
+                    self.chars.next();
+                    self.skip_whitespace();
+                    let child = self.parse_compound()?;
+                    selector = Selector::Child(Box::new(selector), Box::new(child));
+                }
+                Some(c @ ('~' | '+')) => {
+                    return Err(format!("The '{c}' combinator is not supported"));
+                }
+                Some(',' | ')') | None => return Ok(selector),
+                Some(_) if has_whitespace => {
+                    let descendant = self.parse_compound()?;
+                    selector = Selector::Descendant(Box::new(selector), Box::new(descendant));
+                }
+                Some(c) => return Err(format!("Unexpected character '{c}'")),
+            }
+        }
+    }
+
+    /// `A[b]:not(c)`
+    fn parse_compound(&mut self) -> Result<Selector, String> {
+        let mut selectors = vec![];
+        loop {
+            match self.peek() {
+                Some('*') => {
+                    self.chars.next();
+                    selectors.push(Selector::Wildcard);
+                }
+                Some('[') => selectors.push(self.parse_attribute()?),
+                Some(':') => selectors.push(self.parse_pseudo_class()?),
+                Some(c) if is_identifier_char(c) => {
+                    selectors.push(Selector::Type(self.parse_identifier()?.to_string()));
+                }
+                _ => break,
+            }
+        }
+        match selectors.len() {
+            0 => match self.peek() {
+                Some(c) => Err(format!("Unexpected character '{c}'")),
+                None => Err("Expected a selector".to_string()),
+            },
+            1 => Ok(selectors.remove(0)),
+            _ => Ok(Selector::Compound(selectors)),
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Result<&'s str, String> {
+        let start = self.chars.peek().map_or(self.source.len(), |&(i, _)| i);
+        while self.peek().is_some_and(is_identifier_char) {
+            self.chars.next();
+        }
+        let end = self.chars.peek().map_or(self.source.len(), |&(i, _)| i);
+        if start == end {
+            return Err("Expected an identifier".to_string());
+        }
+        Ok(&self.source[start..end])
+    }
+
+    /// `[a.b="c"]`
+    fn parse_attribute(&mut self) -> Result<Selector, String> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let mut path = vec![self.parse_identifier()?.to_string()];
+        while self.eat('.') {
+            path.push(self.parse_identifier()?.to_string());
+        }
+        self.skip_whitespace();
+        if self.eat(']') {
+            return Ok(Selector::Attribute(Attribute { path, test: None }));
+        }
+
+        let operator = match self.chars.next().map(|(_, c)| c) {
+            Some('=') => Operator::Equal,
+            Some('!') => {
+                self.expect('=')?;
+                Operator::NotEqual
+            }
+            Some('<') if self.eat('=') => Operator::LessEqual,
+            Some('<') => Operator::Less,
+            Some('>') if self.eat('=') => Operator::GreaterEqual,
+            Some('>') => Operator::Greater,
+            Some(c) => return Err(format!("Unexpected character '{c}' in attribute")),
+            None => return Err("Expected ']'".to_string()),
+        };
+        self.skip_whitespace();
+        let literal = self.parse_literal()?;
+        if !matches!(literal, Literal::Number(_))
+            && !matches!(operator, Operator::Equal | Operator::NotEqual)
+        {
+            return Err("Only numbers can be compared with '<' and '>'".to_string());
+        }
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Selector::Attribute(Attribute { path, test: Some((operator, literal)) }))
+    }
+
+    fn parse_literal(&mut self) -> Result<Literal, String> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.chars.next();
+                let mut value = String::new();
+                loop {
+                    match self.chars.next().map(|(_, c)| c) {
+                        Some(c) if c == quote => return Ok(Literal::String(value)),
+                        Some('\\') => match self.chars.next() {
+                            Some((_, c)) => value.push(c),
+                            None => return Err("Unterminated string".to_string()),
+                        },
+                        Some(c) => value.push(c),
+                        None => return Err("Unterminated string".to_string()),
+                    }
+                }
+            }
+            Some('/') => {
+                self.chars.next();
+                let mut pattern = String::new();
+                loop {
+                    match self.chars.next().map(|(_, c)| c) {
+                        Some('/') => break,
+                        Some('\\') => {
+                            pattern.push('\\');
+                            match self.chars.next() {
+                                Some((_, c)) => pattern.push(c),
+                                None => return Err("Unterminated regular expression".to_string()),
+                            }
+                        }
+                        Some(c) => pattern.push(c),
+                        None => return Err("Unterminated regular expression".to_string()),
+                    }
+                }
+                let mut builder = RegexBuilder::new(&pattern);
+                while let Some(flag) = self.peek().filter(char::is_ascii_alphabetic) {
+                    self.chars.next();
+                    match flag {
+                        'i' => builder.case_insensitive(true),
+                        'm' => builder.multi_line(true),
+                        's' => builder.dot_matches_new_line(true),
+                        'u' => builder.unicode(true),
+                        _ => return Err(format!("Unsupported regular expression flag '{flag}'")),
+                    };
+                }
+                builder.build().map(Literal::Regex).map_err(|err| err.to_string())
+            }
+            Some(_) => {
+                let start = self.chars.peek().map_or(self.source.len(), |&(i, _)| i);
+                while self.peek().is_some_and(|c| is_identifier_char(c) || c == '.') {
+                    self.chars.next();
+                }
+                let end = self.chars.peek().map_or(self.source.len(), |&(i, _)| i);
+                let value = &self.source[start..end];
+                if value.is_empty() {
+                    return Err("Expected a value".to_string());
+                }
+                Ok(match value.parse::<f64>() {
+                    Ok(number) => Literal::Number(number),
+                    Err(_) => Literal::String(value.to_string()),
+                })
+            }
+            None => Err("Expected a value".to_string()),
+        }
+    }
+
+    /// `:not(a)` and `:statement`
+    fn parse_pseudo_class(&mut self) -> Result<Selector, String> {
+        self.expect(':')?;
+        let name = self.parse_identifier()?;
+        let class = match name {
+            "statement" => NodeClass::Statement,
+            "declaration" => NodeClass::Declaration,
+            "expression" => NodeClass::Expression,
+            "function" => NodeClass::Function,
+            "pattern" => NodeClass::Pattern,
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let selector = self.parse_selectors()?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                let selectors = match selector {
+                    Selector::Matches(selectors) => selectors,
+                    selector => vec![selector],
+                };
+                return Ok(match name {
+                    "not" => Selector::Not(selectors),
+                    "has" => Selector::Has(Box::new(Selector::Matches(selectors))),
+                    _ => Selector::Matches(selectors),
+                });
+            }
+            _ => return Err(format!("The ':{name}' pseudo class is not supported")),
+        };
+        Ok(Selector::Class(class))
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '-')
+}
+
+#[cfg(test)]
+mod test {
+    use super::Selector;
+
+    #[test]
+    fn parse() {
+        let valid = [
+            "CallExpression",
+            "*",
+            "Identifier[name='foo']",
+            "CallExpression[callee.name=\"eval\"]",
+            "Literal[value=/^foo/i]",
+            "CallExpression[arguments.length>=2]",
+            "FunctionDeclaration > Identifier",
+            "ClassDeclaration MethodDefinition",
+            "ForInStatement, ForOfStatement",
+            "CallExpression:not([callee.name=foo], [optional=true])",
+            ":matches(WithStatement, DebuggerStatement)",
+            ":function:has(ThisExpression)",
+            "  Program  >  :statement  ",
+        ];
+        for selector in valid {
+            assert!(Selector::parse(selector).is_ok(), "{selector} should be valid");
+        }
+
+        let invalid = [
+            "",
+            "A ~ B",
+            "A + B",
+            ":first-child",
+            "[name=",
+            "[name='foo]",
+            "[name<'foo']",
+            "Literal[value=/foo/g]",
+            "A >",
+            "A,",
+            ":not(A",
+        ];
+        for selector in invalid {
+            assert!(Selector::parse(selector).is_err(), "{selector} should be invalid");
+        }
+    }
+}
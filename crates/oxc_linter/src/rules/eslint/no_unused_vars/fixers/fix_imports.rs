@@ -1,7 +1,8 @@
 use oxc_ast::ast::ImportDeclaration;
+use oxc_semantic::import_specifier_removal_span;
 use oxc_span::GetSpan;
 
-use super::{count_whitespace_or_commas, NoUnusedVars, Symbol};
+use super::{NoUnusedVars, Symbol};
 use crate::fixer::{RuleFix, RuleFixer};
 
 impl NoUnusedVars {
@@ -22,15 +23,10 @@ impl NoUnusedVars {
             "Found an unused variable in an ImportDeclaration with no specifiers. This should be impossible."
         );
 
-        if specifiers.len() == 1 {
-            return fixer.delete(import).dangerously();
-        }
-        let span = specifiers
-            .iter()
-            .find(|specifier| symbol == specifier)
-            .map_or_else(|| symbol.span(), GetSpan::span);
-        let text_after = fixer.source_text()[(span.end as usize)..].chars();
-        let span = span.expand_right(count_whitespace_or_commas(text_after));
+        let Some(index) = specifiers.iter().position(|specifier| symbol == specifier) else {
+            return fixer.delete_range(symbol.span()).dangerously();
+        };
+        let span = import_specifier_removal_span(fixer.source_text(), import, index);
 
         fixer.delete_range(span).dangerously()
     }
@@ -1,8 +1,8 @@
 use oxc_ast::{
     ast::{ArrowFunctionExpression, AwaitExpression, ForOfStatement, Function, PropertyKey},
-    visit::walk::walk_for_of_statement,
-    AstKind, Visit,
+    AstKind,
 };
+use oxc_ast_visit::{walk::walk_for_of_statement, Visit};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ScopeFlags;
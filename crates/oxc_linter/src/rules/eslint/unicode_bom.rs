@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// ```
     UnicodeBom,
     restriction,
-    fix
+    fix,
+    formatting
 );
 
 impl Rule for UnicodeBom {
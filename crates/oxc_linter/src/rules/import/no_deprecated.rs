@@ -1,16 +1,31 @@
-use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use std::{path::PathBuf, sync::Arc};
+
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-// use oxc_span::{CompactStr, Span};
+use oxc_semantic::{AstNode, ModuleRecord};
+use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_syntax::module_record::{ExportExportName, ExportImportName, ImportImportName};
+
+use crate::{context::LintContext, rule::Rule};
 
-use crate::{
-    context::{ContextHost, LintContext},
-    rule::Rule,
-};
+fn no_deprecated_diagnostic(span: Span, name: &str, message: &str) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!("{name:?} is deprecated.")).with_label(span);
+    if message.is_empty() {
+        diagnostic
+    } else {
+        diagnostic.with_help(message.to_string())
+    }
+}
 
-// #[derive(Debug, Error, Diagnostic)]
-// #[error("")]
-// #[diagnostic(severity(warning), help(""))]
-// struct NoDeprecatedDiagnostic(CompactStr, #[label] pub Span);
+fn deprecated_module_diagnostic(span: Span, source: &str, message: &str) -> OxcDiagnostic {
+    let diagnostic =
+        OxcDiagnostic::warn(format!("Module {source:?} is deprecated.")).with_label(span);
+    if message.is_empty() {
+        diagnostic
+    } else {
+        diagnostic.with_help(message.to_string())
+    }
+}
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-deprecated.md>
 #[derive(Debug, Default, Clone)]
@@ -20,25 +35,202 @@ declare_oxc_lint!(
     /// ### What it does
     ///
     /// Reports use of a deprecated name, as indicated by a JSDoc block with
-    /// a @deprecated tag or TomDoc Deprecated: comment.
+    /// a `@deprecated` tag, and imports of a module whose JSDoc block has both a
+    /// `@module` and a `@deprecated` tag.
+    ///
+    /// Imported names are followed through re-exports and namespaces, e.g.
+    /// `ns.deprecatedName` of `import * as ns from './mod'` is reported.
+    /// TomDoc `Deprecated:` comments are not supported.
     ///
     /// ### Why is this bad?
     ///
+    /// Deprecated APIs are going to be removed or replaced, and the deprecation
+    /// message usually tells what to use instead.
+    ///
     /// ### Examples
     ///
+    /// Given
+    /// ```javascript
+    /// // ./answer.js
+    /// /** @deprecated use `answer` instead */
+    /// export const ANSWER = 42;
+    /// export const answer = 42;
+    /// ```
+    ///
     /// Examples of **incorrect** code for this rule:
     /// ```javascript
+    /// import { ANSWER } from './answer';
+    /// console.log(ANSWER);
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
+    /// import { answer } from './answer';
+    /// console.log(answer);
     /// ```
     NoDeprecated,
     nursery
 );
 
 impl Rule for NoDeprecated {
-    fn run_once(&self, _ctx: &LintContext<'_>) {}
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+
+        let mut requested_modules = module_record
+            .requested_modules
+            .iter()
+            .flat_map(|(source, requests)| {
+                requests
+                    .iter()
+                    .filter(|request| request.is_import())
+                    .map(move |request| (request.span(), source))
+            })
+            .collect::<Vec<_>>();
+        requested_modules.sort_unstable_by_key(|(span, _)| span.start);
+        for (span, source) in requested_modules {
+            let Some(module) = module_record.loaded_modules.get(source) else {
+                continue;
+            };
+            if let Some(message) = &module.deprecated {
+                ctx.diagnostic(deprecated_module_diagnostic(span, source, message));
+            }
+        }
+
+        for entry in &module_record.import_entries {
+            let Some(module) = module_record
+                .loaded_modules
+                .get(entry.module_request.name())
+                .map(|module| Arc::clone(module.value()))
+            else {
+                continue;
+            };
+            let export = match &entry.import_name {
+                ImportImportName::Name(name) => resolve_export(&module, name.name(), &mut vec![]),
+                ImportImportName::Default(_) => resolve_export(&module, "default", &mut vec![]),
+                ImportImportName::NamespaceObject => Some(Export::Namespace(module)),
+            };
+            let Some(export) = export else {
+                continue;
+            };
+
+            let local_name = entry.local_name.name();
+            if let Export::Deprecated(message) = &export {
+                ctx.diagnostic(no_deprecated_diagnostic(
+                    entry.local_name.span(),
+                    local_name,
+                    message,
+                ));
+            }
+            let Some(symbol_id) = ctx.scopes().get_root_binding(local_name) else {
+                continue;
+            };
+            for reference in ctx.symbols().get_resolved_references(symbol_id) {
+                let node = ctx.nodes().get_node(reference.node_id());
+                match &export {
+                    Export::Deprecated(message) => {
+                        ctx.diagnostic(no_deprecated_diagnostic(
+                            node.kind().span(),
+                            local_name,
+                            message,
+                        ));
+                    }
+                    Export::Namespace(module) => check_namespace_member(node, module, ctx),
+                }
+            }
+        }
+    }
+}
+
+/// An export of a module which is checked by this rule.
+enum Export {
+    /// A deprecated export, with its deprecation message.
+    Deprecated(CompactStr),
+    /// The namespace object of a module, whose members can be deprecated.
+    Namespace(Arc<ModuleRecord>),
+}
+
+/// Find what `export_name` of `module` refers to, following re-exports.
+///
+/// [`None`] if the export is neither deprecated nor a namespace, or if it can not be found.
+fn resolve_export(
+    module: &ModuleRecord,
+    export_name: &str,
+    visited: &mut Vec<(PathBuf, CompactStr)>,
+) -> Option<Export> {
+    let path = &module.resolved_absolute_path;
+    if visited.iter().any(|(p, name)| p == path && name == export_name) {
+        return None;
+    }
+    visited.push((path.clone(), CompactStr::from(export_name)));
+
+    if let Some(message) = module.deprecated_exports.get(export_name) {
+        return Some(Export::Deprecated(message.clone()));
+    }
+
+    let loaded_module =
+        |source: &str| module.loaded_modules.get(source).map(|module| Arc::clone(module.value()));
+    let export_name_matches = |name: &ExportExportName| match name {
+        ExportExportName::Name(name) => name.name() == export_name,
+        ExportExportName::Default(_) => export_name == "default",
+        ExportExportName::Null => false,
+    };
+
+    // `import * as ns from './mod'; export { ns }`
+    if let Some(entry) =
+        module.local_export_entries.iter().find(|entry| export_name_matches(&entry.export_name))
+    {
+        let local_name = entry.local_name.name()?;
+        let import_entry = module.import_entries.iter().find(|import_entry| {
+            import_entry.import_name.is_namespace_object()
+                && import_entry.local_name.name() == local_name
+        })?;
+        return loaded_module(import_entry.module_request.name()).map(Export::Namespace);
+    }
+
+    if let Some(entry) =
+        module.indirect_export_entries.iter().find(|entry| export_name_matches(&entry.export_name))
+    {
+        let imported = loaded_module(entry.module_request.as_ref()?.name())?;
+        return match &entry.import_name {
+            // `export * as ns from './mod'`
+            ExportImportName::All => Some(Export::Namespace(imported)),
+            // `export { name } from './mod'`
+            ExportImportName::Name(name) => resolve_export(&imported, name.name(), visited),
+            ExportImportName::AllButDefault | ExportImportName::Null => None,
+        };
+    }
+
+    if export_name == "default" {
+        return None;
+    }
+    module.star_export_entries.iter().find_map(|entry| {
+        let imported = loaded_module(entry.module_request.as_ref()?.name())?;
+        resolve_export(&imported, export_name, visited)
+    })
+}
+
+/// Check the member of the namespace object of `module` accessed by `node`, e.g. `ns.name`.
+fn check_namespace_member(node: &AstNode, module: &ModuleRecord, ctx: &LintContext<'_>) {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return;
+    };
+    let Some(member) = parent.kind().as_member_expression() else {
+        return;
+    };
+    if member.object().span() != node.kind().span() {
+        return;
+    }
+    let Some((span, name)) = member.static_property_info() else {
+        return;
+    };
+    match resolve_export(module, name, &mut vec![]) {
+        Some(Export::Deprecated(message)) => {
+            ctx.diagnostic(no_deprecated_diagnostic(span, name, &message));
+        }
+        // `ns.deep.name`
+        Some(Export::Namespace(module)) => check_namespace_member(parent, &module, ctx),
+        None => {}
+    }
 }
 
 #[test]
@@ -50,7 +242,8 @@ fn test() {
         r"import bar from './bar'",
         r"import { fine } from './deprecated'",
         r"import { _undocumented } from './deprecated'",
-        r"import { fn } from './deprecated'",
+        // TomDoc is not supported, see the commented out cases below.
+        // r"import { fn } from './deprecated'",
         r"import { fine } from './tomdoc-deprecated'",
         r"import { _undocumented } from './tomdoc-deprecated'",
         r"import * as depd from './deprecated'",
@@ -84,29 +277,29 @@ fn test() {
 
     let fail = vec![
         // r#"import './malformed.js'"#,
-        // r#"import { fn } from './deprecated'"#,
-        // r#"import TerribleClass from './deprecated'"#,
-        // r#"import { MY_TERRIBLE_ACTION } from './deprecated'"#,
-        // r#"import { fn } from './deprecated'"#,
+        r#"import { fn } from './deprecated'"#,
+        r#"import TerribleClass from './deprecated'"#,
+        r#"import { MY_TERRIBLE_ACTION } from './deprecated'"#,
+        // TomDoc, with `settings: { 'import/docstyle': ['tomdoc'] }`
         // r#"import { fn } from './tomdoc-deprecated'"#,
         // r#"import TerribleClass from './tomdoc-deprecated'"#,
         // r#"import { MY_TERRIBLE_ACTION } from './tomdoc-deprecated'"#,
-        // r#"import { MY_TERRIBLE_ACTION } from './deprecated'; function shadow(MY_TERRIBLE_ACTION) { console.log(MY_TERRIBLE_ACTION); }"#,
-        // r#"import { MY_TERRIBLE_ACTION, fine } from './deprecated'; console.log(fine)"#,
-        // r#"import { MY_TERRIBLE_ACTION } from './deprecated'; console.log(MY_TERRIBLE_ACTION)"#,
-        // r#"import { MY_TERRIBLE_ACTION } from './deprecated'; console.log(someOther.MY_TERRIBLE_ACTION)"#,
-        // r#"import { MY_TERRIBLE_ACTION } from './deprecated'; console.log(MY_TERRIBLE_ACTION.whatever())"#,
-        // r#"import { MY_TERRIBLE_ACTION } from './deprecated'; console.log(MY_TERRIBLE_ACTION(this, is, the, worst))"#,
-        // r#"import Thing from './deprecated-file'"#,
-        // r#"import Thing from './deprecated-file'; console.log(other.Thing)"#,
-        // r#"import * as depd from './deprecated'; console.log(depd.MY_TERRIBLE_ACTION)"#,
-        // r#"import * as deep from './deep-deprecated'; console.log(deep.deepDep.MY_TERRIBLE_ACTION)"#,
-        // r#"import { deepDep } from './deep-deprecated'; console.log(deepDep.MY_TERRIBLE_ACTION)"#,
-        // r#"import { deepDep } from './deep-deprecated'; function x(deepNDep) { console.log(deepDep.MY_TERRIBLE_ACTION) }"#,
-        // // hoisting
-        // r#"console.log(MY_TERRIBLE_ACTION); import { MY_TERRIBLE_ACTION } from "./deprecated""#,
-        // // TypeScript
-        // r#"import { foo } from "./ts-deprecated.ts"; console.log(foo())"#,
+        r#"import { MY_TERRIBLE_ACTION } from './deprecated'; function shadow(MY_TERRIBLE_ACTION) { console.log(MY_TERRIBLE_ACTION); }"#,
+        r#"import { MY_TERRIBLE_ACTION, fine } from './deprecated'; console.log(fine)"#,
+        r#"import { MY_TERRIBLE_ACTION } from './deprecated'; console.log(MY_TERRIBLE_ACTION)"#,
+        r#"import { MY_TERRIBLE_ACTION } from './deprecated'; console.log(someOther.MY_TERRIBLE_ACTION)"#,
+        r#"import { MY_TERRIBLE_ACTION } from './deprecated'; console.log(MY_TERRIBLE_ACTION.whatever())"#,
+        r#"import { MY_TERRIBLE_ACTION } from './deprecated'; console.log(MY_TERRIBLE_ACTION(this, is, the, worst))"#,
+        r#"import Thing from './deprecated-file'"#,
+        r#"import Thing from './deprecated-file'; console.log(other.Thing)"#,
+        r#"import * as depd from './deprecated'; console.log(depd.MY_TERRIBLE_ACTION)"#,
+        r#"import * as deep from './deep-deprecated'; console.log(deep.deepDep.MY_TERRIBLE_ACTION)"#,
+        r#"import { deepDep } from './deep-deprecated'; console.log(deepDep.MY_TERRIBLE_ACTION)"#,
+        r#"import { deepDep } from './deep-deprecated'; function x(deepNDep) { console.log(deepDep.MY_TERRIBLE_ACTION) }"#,
+        // hoisting
+        r#"console.log(MY_TERRIBLE_ACTION); import { MY_TERRIBLE_ACTION } from "./deprecated""#,
+        // TypeScript
+        r#"import { foo } from "./ts-deprecated.ts"; console.log(foo())"#,
     ];
 
     Tester::new(NoDeprecated::NAME, pass, fail)
@@ -0,0 +1,312 @@
+use std::{
+    ffi::OsStr,
+    path::{Component, Path},
+};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_required_module, node_builtin_module_name},
+    AstNode, PackageJson,
+};
+
+fn missing_diagnostic(span: Span, package_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{package_name}' should be listed in the project's dependencies. Run 'npm i -S {package_name}' to add it"
+    ))
+    .with_label(span)
+}
+
+fn dev_dependency_diagnostic(span: Span, package_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{package_name}' should be listed in the project's dependencies, not devDependencies."
+    ))
+    .with_label(span)
+}
+
+fn optional_dependency_diagnostic(span: Span, package_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{package_name}' should be listed in the project's dependencies, not optionalDependencies."
+    ))
+    .with_label(span)
+}
+
+/// Whether the packages of a kind of dependencies can be imported, in all files or only in the
+/// files matching globs.
+#[derive(Debug, Clone)]
+enum AllowDependencies {
+    Always(bool),
+    Files(GlobSet),
+}
+
+impl Default for AllowDependencies {
+    fn default() -> Self {
+        Self::Always(true)
+    }
+}
+
+impl AllowDependencies {
+    fn from_configuration(value: Option<&serde_json::Value>) -> Self {
+        match value {
+            Some(serde_json::Value::Bool(allow)) => Self::Always(*allow),
+            Some(serde_json::Value::Array(globs)) => {
+                let mut builder = GlobSetBuilder::new();
+                for glob in globs.iter().filter_map(serde_json::Value::as_str) {
+                    if let Ok(glob) = Glob::new(glob) {
+                        builder.add(glob);
+                    }
+                }
+                builder.build().map_or_else(|_| Self::default(), Self::Files)
+            }
+            _ => Self::default(),
+        }
+    }
+
+    fn allows(&self, path: &Path) -> bool {
+        match self {
+            Self::Always(allow) => *allow,
+            Self::Files(globs) => globs.is_match(path),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependencies(Box<NoExtraneousDependenciesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependenciesConfig {
+    dev_dependencies: AllowDependencies,
+    optional_dependencies: AllowDependencies,
+    peer_dependencies: AllowDependencies,
+    bundled_dependencies: AllowDependencies,
+    /// Check type only imports too.
+    include_types: bool,
+}
+
+impl std::ops::Deref for NoExtraneousDependencies {
+    type Target = NoExtraneousDependenciesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids importing packages which are not declared in the `dependencies`,
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundledDependencies` of
+    /// the closest `package.json` file.
+    ///
+    /// `import` and `export` declarations and `require` calls are checked. Type only imports are
+    /// not checked unless `includeTypes` is enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A package which is not declared in `package.json` may only be installed by accident, e.g.
+    /// as a dependency of another package, and be missing when the project is installed elsewhere.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, with `{ "devDependencies": false }`:
+    /// ```js
+    /// import _ from "package-which-is-not-declared";
+    /// import test from "ava"; // declared in `devDependencies`
+    /// const debug = require("package-which-is-not-declared");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import _ from "lodash"; // declared in `dependencies`
+    /// import fs from "node:fs";
+    /// import foo from "./foo";
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `devDependencies`, `optionalDependencies` and `peerDependencies`: whether packages of
+    /// these kinds can be imported, `true` by default. Can also be an array of globs, matched
+    /// against the path of the file relative to the current working directory, to only allow
+    /// them in some files, e.g. `{ "devDependencies": ["**/*.test.js"] }`.
+    ///
+    /// `bundledDependencies`: whether bundled packages can be imported, `true` by default.
+    ///
+    /// `includeTypes`: check type only imports too, `false` by default.
+    NoExtraneousDependencies,
+    suspicious
+);
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let option = |name: &str| {
+            AllowDependencies::from_configuration(config.and_then(|config| config.get(name)))
+        };
+        Self(Box::new(NoExtraneousDependenciesConfig {
+            dev_dependencies: option("devDependencies"),
+            optional_dependencies: option("optionalDependencies"),
+            peer_dependencies: option("peerDependencies"),
+            bundled_dependencies: option("bundledDependencies"),
+            include_types: config
+                .and_then(|config| config.get("includeTypes"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(package_json) = ctx.package_json() else {
+            return;
+        };
+        let module_record = ctx.module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            // Modules which resolve to a file outside of `node_modules`, e.g. with `tsconfig.json`
+            // paths, are not packages.
+            if module_record.loaded_modules.get(specifier).is_some_and(|module| {
+                !module.value().resolved_absolute_path.components().any(|component| {
+                    matches!(component, Component::Normal(name) if name == OsStr::new("node_modules"))
+                })
+            }) {
+                continue;
+            }
+            for requested_module in requested_modules {
+                if requested_module.is_type() && !self.include_types {
+                    continue;
+                }
+                self.check(ctx, package_json, specifier, requested_module.span());
+            }
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Some(package_json) = ctx.package_json() else {
+            return;
+        };
+        let Some(specifier) = get_required_module(call_expr, ctx) else {
+            return;
+        };
+        self.check(ctx, package_json, specifier, call_expr.arguments[0].span());
+    }
+}
+
+impl NoExtraneousDependencies {
+    fn check(
+        &self,
+        ctx: &LintContext<'_>,
+        package_json: &PackageJson,
+        specifier: &str,
+        span: Span,
+    ) {
+        let Some(package_name) = package_name(specifier) else {
+            return;
+        };
+        if package_json.dependencies.contains_key(package_name) {
+            return;
+        }
+
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let path = ctx.file_path().strip_prefix(&cwd).unwrap_or(ctx.file_path());
+        let is_dev_dependency = package_json.dev_dependencies.contains_key(package_name);
+        let is_optional_dependency = package_json.optional_dependencies.contains_key(package_name);
+        if (is_dev_dependency && self.dev_dependencies.allows(path))
+            || (is_optional_dependency && self.optional_dependencies.allows(path))
+            || (package_json.peer_dependencies.contains_key(package_name)
+                && self.peer_dependencies.allows(path))
+            || (package_json.bundled_dependencies.iter().any(|name| name == package_name)
+                && self.bundled_dependencies.allows(path))
+        {
+            return;
+        }
+
+        if is_dev_dependency {
+            ctx.diagnostic(dev_dependency_diagnostic(span, package_name));
+        } else if is_optional_dependency {
+            ctx.diagnostic(optional_dependency_diagnostic(span, package_name));
+        } else {
+            ctx.diagnostic(missing_diagnostic(span, package_name));
+        }
+    }
+}
+
+/// The name of the package imported by `specifier`, e.g. `@scope/foo` for `@scope/foo/bar`, or
+/// `None` if `specifier` does not import a package.
+fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with('.')
+        || specifier.starts_with('/')
+        || specifier.contains(':')
+        || node_builtin_module_name(specifier).is_some()
+    {
+        return None;
+    }
+    let end = if specifier.starts_with('@') {
+        let scope_end = specifier.find('/')?;
+        specifier[scope_end + 1..].find('/').map_or(specifier.len(), |i| scope_end + 1 + i)
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+    Some(&specifier[..end]).filter(|name| !name.is_empty())
+}
+
+#[test]
+fn test_package_name() {
+    assert_eq!(package_name("foo"), Some("foo"));
+    assert_eq!(package_name("foo/bar"), Some("foo"));
+    assert_eq!(package_name("@scope/foo"), Some("@scope/foo"));
+    assert_eq!(package_name("@scope/foo/bar"), Some("@scope/foo"));
+    assert_eq!(package_name("@scope"), None);
+    assert_eq!(package_name("./foo"), None);
+    assert_eq!(package_name("/foo"), None);
+    assert_eq!(package_name("fs"), None);
+    assert_eq!(package_name("node:test"), None);
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import $ from 'jquery'", None),
+        ("import cond from 'lodash.cond'", None),
+        ("import { map } from 'rxjs/operators'", None),
+        ("import pkg from '@org/package'", None),
+        ("import foo from '@generated/foo'", None),
+        ("import glob from 'glob'", None),
+        ("import isArray from 'lodash.isarray'", None),
+        ("import eslint from 'eslint'", Some(json!([{ "devDependencies": false }]))),
+        ("import glob from 'glob'", Some(json!([{ "devDependencies": ["**/*.ts"] }]))),
+        ("import fs from 'fs'", None),
+        ("import test from 'node:test'", None),
+        ("import foo from './bar'", None),
+        ("import type { Foo } from 'not-a-dependency'", None),
+        ("const $ = require('jquery')", None),
+        ("const foo = require('./bar')", None),
+        ("const foo = require(name)", None),
+    ];
+
+    let fail = vec![
+        ("import foo from 'not-a-dependency'", None),
+        ("import pkg from '@org/not-a-dependency'", None),
+        ("export * from 'not-a-dependency'", None),
+        ("const foo = require('not-a-dependency')", None),
+        ("import glob from 'glob'", Some(json!([{ "devDependencies": false }]))),
+        ("import glob from 'glob'", Some(json!([{ "devDependencies": ["**/*.test.ts"] }]))),
+        ("import isArray from 'lodash.isarray'", Some(json!([{ "optionalDependencies": false }]))),
+        ("import type { Foo } from 'not-a-dependency'", Some(json!([{ "includeTypes": true }]))),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,142 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, utils::node_builtin_module_name};
+
+fn no_unresolved_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unable to resolve path to module '{specifier}'."))
+        .with_help("Check the path of the module, or install the package which provides it.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolvedConfig {
+    /// Specifiers matching any of these patterns are not reported.
+    ignore: Vec<Regex>,
+}
+
+impl std::ops::Deref for NoUnresolved {
+    type Target = NoUnresolvedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures that imported modules can be resolved to a module on the local filesystem.
+    ///
+    /// Modules are resolved like Node.js and TypeScript do, honoring `tsconfig.json` paths.
+    /// Node.js builtin modules, e.g. `fs` or `node:fs`, and type only imports are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a file or package which does not exist fails at runtime, or when bundling.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import foo from "./missing-file";
+    /// import bar from "package-which-is-not-installed";
+    /// export { baz } from "./missing-file";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from "node:fs";
+    /// import foo from "./foo";
+    /// export { baz } from "./foo";
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignore`: an array of regular expressions, specifiers matching any of them are not
+    /// reported, e.g. `{ "ignore": ["\\.img$"] }`.
+    ///
+    /// The `commonjs` and `amd` options of `eslint-plugin-import` are not supported, `require`
+    /// calls are not checked.
+    NoUnresolved,
+    correctness
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore = value
+            .get(0)
+            .and_then(|config| config.get("ignore"))
+            .and_then(serde_json::Value::as_array)
+            .map(|patterns| {
+                patterns
+                    .iter()
+                    .filter_map(serde_json::Value::as_str)
+                    .filter_map(|pattern| Regex::new(pattern).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self(Box::new(NoUnresolvedConfig { ignore }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            if !module_record.unresolved_modules.contains(specifier)
+                || node_builtin_module_name(specifier).is_some()
+                || self.ignore.iter().any(|pattern| pattern.is_match(specifier))
+            {
+                continue;
+            }
+            for requested_module in requested_modules {
+                if requested_module.is_type() {
+                    continue;
+                }
+                ctx.diagnostic(no_unresolved_diagnostic(requested_module.span(), specifier));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import foo from './bar'", None),
+        ("import foo from './bar.js'", None),
+        ("import { foo } from './bar'", None),
+        ("import * as foo from './bar'", None),
+        ("import foo from './data.json'", None),
+        ("import fs from 'fs'", None),
+        ("import fs from 'node:fs'", None),
+        ("import pad from 'left-pad'", None),
+        ("export * from './bar'", None),
+        ("export { foo } from './bar'", None),
+        ("import type { Foo } from './missing'", None),
+        ("const foo = require('./missing')", None),
+        ("import('./missing')", None),
+        ("import foo from './missing.png'", Some(json!([{ "ignore": ["\\.png$"] }]))),
+    ];
+
+    let fail = vec![
+        ("import foo from './missing'", None),
+        ("import './missing.js'", None),
+        ("import { foo } from 'this-package-does-not-exist'", None),
+        ("export * from './missing'", None),
+        ("export { foo } from './missing'", None),
+        ("import foo from './missing.png'", Some(json!([{ "ignore": ["\\.svg$"] }]))),
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
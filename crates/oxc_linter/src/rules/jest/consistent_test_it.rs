@@ -169,7 +169,8 @@ declare_oxc_lint!(
     /// }
     ConsistentTestIt,
     style,
-    fix
+    fix,
+    style
 );
 
 impl Rule for ConsistentTestIt {
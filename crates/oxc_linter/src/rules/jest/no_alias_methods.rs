@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// ```
     NoAliasMethods,
     style,
-    fix
+    fix,
+    style
 );
 
 impl Rule for NoAliasMethods {
@@ -54,7 +54,8 @@ declare_oxc_lint!(
     /// ```
     NoTestPrefixes,
     style,
-    fix
+    fix,
+    style
 );
 
 impl Rule for NoTestPrefixes {
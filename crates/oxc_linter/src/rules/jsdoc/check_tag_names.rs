@@ -2,6 +2,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
@@ -45,10 +46,11 @@ declare_oxc_lint!(
     /// /** @param */
     /// ```
     CheckTagNames,
-    correctness
+    correctness,
+    config = CheckTagnamesConfig
 );
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 struct CheckTagnamesConfig {
     #[serde(default, rename = "definedTags")]
     defined_tags: Vec<String>,
@@ -1,6 +1,7 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
@@ -46,10 +47,11 @@ declare_oxc_lint!(
     /// function quux (foo) {}
     /// ```
     NoDefaults,
-    correctness
+    correctness,
+    config = NoDefaultsConfig
 );
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 struct NoDefaultsConfig {
     #[serde(default, rename = "noOptionalParamNames")]
     no_optional_param_names: bool,
@@ -0,0 +1,321 @@
+use std::fmt;
+
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext, rule::Rule, utils::for_each_node_builtin_module_access, AstNode,
+};
+
+fn no_deprecated_api_diagnostic(span: Span, api: &DeprecatedApi) -> OxcDiagnostic {
+    let name = match api.member {
+        Some(member) => format!("`{}.{member}`", api.object),
+        None if api.is_module => format!("The `{}` module", api.object),
+        None => format!("`{}()`", api.object),
+    };
+    let diagnostic =
+        OxcDiagnostic::warn(format!("{name} was deprecated since v{}", api.since)).with_label(span);
+    match api.replacement {
+        Some(replacement) => diagnostic.with_help(format!("Use {replacement} instead")),
+        None => diagnostic,
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecatedApi(Box<NoDeprecatedApiConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecatedApiConfig {
+    /// The Node.js version the code runs on, APIs deprecated in later versions are allowed.
+    version: Option<NodeVersion>,
+}
+
+impl std::ops::Deref for NoDeprecatedApi {
+    type Target = NoDeprecatedApiConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow deprecated Node.js APIs, e.g. `new Buffer()`, `fs.exists()` or the `domain`
+    /// module.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated APIs may be removed in a future version of Node.js, and often have known
+    /// problems which their replacements solve.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const buffer = new Buffer(10);
+    ///
+    /// import fs from "node:fs";
+    /// fs.exists("./file", (exists) => {});
+    ///
+    /// const { isArray } = require("util");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const buffer = Buffer.alloc(10);
+    ///
+    /// import fs from "node:fs";
+    /// fs.access("./file", (err) => {});
+    ///
+    /// const { isArray } = Array;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `version` is the Node.js version the code runs on. APIs which were deprecated after that
+    /// version are allowed. By default, all deprecated APIs are reported.
+    ///
+    /// ```json
+    /// {
+    ///   "node/no-deprecated-api": ["error", { "version": ">=14.0.0" }]
+    /// }
+    /// ```
+    NoDeprecatedApi,
+    correctness
+);
+
+impl Rule for NoDeprecatedApi {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoDeprecatedApiConfig {
+            version: value
+                .get(0)
+                .and_then(|v| v.get("version"))
+                .and_then(serde_json::Value::as_str)
+                .and_then(NodeVersion::parse),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `Buffer()`, `new Buffer()`
+            AstKind::CallExpression(call_expr) => {
+                self.check_global_call(&call_expr.callee, call_expr.span, ctx);
+            }
+            AstKind::NewExpression(new_expr) => {
+                self.check_global_call(&new_expr.callee, new_expr.span, ctx);
+            }
+            // `process.binding`
+            AstKind::MemberExpression(member_expr) => {
+                let Expression::Identifier(ident) = member_expr.object() else {
+                    return;
+                };
+                let Some(member) = member_expr.static_property_name() else {
+                    return;
+                };
+                if !ctx.semantic().is_reference_to_global_variable(ident) {
+                    return;
+                }
+                if let Some(api) = self.find(DEPRECATED_GLOBALS, &ident.name, Some(member)) {
+                    ctx.diagnostic(no_deprecated_api_diagnostic(member_expr.span(), api));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        for_each_node_builtin_module_access(ctx, |module, member, span| {
+            if let Some(api) = self.find(DEPRECATED_MODULE_APIS, module, member) {
+                ctx.diagnostic(no_deprecated_api_diagnostic(span, api));
+            }
+        });
+    }
+}
+
+impl NoDeprecatedApi {
+    fn check_global_call(&self, callee: &Expression, span: Span, ctx: &LintContext) {
+        let Expression::Identifier(ident) = callee.without_parentheses() else {
+            return;
+        };
+        if !ctx.semantic().is_reference_to_global_variable(ident) {
+            return;
+        }
+        if let Some(api) = self.find(DEPRECATED_GLOBALS, &ident.name, None) {
+            ctx.diagnostic(no_deprecated_api_diagnostic(span, api));
+        }
+    }
+
+    fn find<'t>(
+        &self,
+        table: &'t [DeprecatedApi],
+        object: &str,
+        member: Option<&str>,
+    ) -> Option<&'t DeprecatedApi> {
+        table.iter().find(|api| {
+            api.object == object
+                && api.member == member
+                && self.version.map_or(true, |version| api.since <= version)
+        })
+    }
+}
+
+/// A Node.js version, e.g. `16.0.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct NodeVersion(u32, u32, u32);
+
+impl NodeVersion {
+    /// Parse a version, ignoring a leading range operator as in `>=16.0.0`.
+    /// Missing minor and patch versions are zero.
+    fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches(['>', '=', '^', '~', 'v']);
+        let mut parts = version.split('.').map(str::parse::<u32>);
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().transpose().ok()?.unwrap_or(0);
+        let patch = parts.next().transpose().ok()?.unwrap_or(0);
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self(major, minor, patch))
+    }
+}
+
+impl fmt::Display for NodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+#[derive(Debug)]
+struct DeprecatedApi {
+    /// Name of the module, or of the global variable.
+    object: &'static str,
+    /// `None` if the module or global itself is deprecated.
+    member: Option<&'static str>,
+    is_module: bool,
+    since: NodeVersion,
+    replacement: Option<&'static str>,
+}
+
+const fn module(
+    object: &'static str,
+    member: Option<&'static str>,
+    since: NodeVersion,
+    replacement: Option<&'static str>,
+) -> DeprecatedApi {
+    DeprecatedApi { object, member, is_module: true, since, replacement }
+}
+
+const fn global(
+    object: &'static str,
+    member: Option<&'static str>,
+    since: NodeVersion,
+    replacement: Option<&'static str>,
+) -> DeprecatedApi {
+    DeprecatedApi { object, member, is_module: false, since, replacement }
+}
+
+/// See <https://nodejs.org/api/deprecations.html>.
+#[rustfmt::skip]
+const DEPRECATED_MODULE_APIS: &[DeprecatedApi] = &[
+    module("_linklist", None, NodeVersion(5, 0, 0), None),
+    module("_stream_wrap", None, NodeVersion(12, 0, 0), None),
+    module("buffer", Some("SlowBuffer"), NodeVersion(6, 0, 0), Some("`Buffer.allocUnsafeSlow()`")),
+    module("constants", None, NodeVersion(6, 3, 0), Some("the `constants` property of each module")),
+    module("crypto", Some("Credentials"), NodeVersion(0, 12, 0), Some("`tls.SecureContext`")),
+    module("crypto", Some("createCipher"), NodeVersion(10, 0, 0), Some("`crypto.createCipheriv()`")),
+    module("crypto", Some("createCredentials"), NodeVersion(0, 12, 0), Some("`tls.createSecureContext()`")),
+    module("crypto", Some("createDecipher"), NodeVersion(10, 0, 0), Some("`crypto.createDecipheriv()`")),
+    module("crypto", Some("pseudoRandomBytes"), NodeVersion(11, 0, 0), Some("`crypto.randomBytes()`")),
+    module("domain", None, NodeVersion(4, 0, 0), None),
+    module("events", Some("listenerCount"), NodeVersion(3, 2, 0), Some("`emitter.listenerCount()`")),
+    module("fs", Some("SyncWriteStream"), NodeVersion(8, 0, 0), None),
+    module("fs", Some("exists"), NodeVersion(4, 0, 0), Some("`fs.stat()` or `fs.access()`")),
+    module("module", Some("createRequireFromPath"), NodeVersion(12, 2, 0), Some("`module.createRequire()`")),
+    module("os", Some("getNetworkInterfaces"), NodeVersion(0, 6, 0), Some("`os.networkInterfaces()`")),
+    module("os", Some("tmpDir"), NodeVersion(7, 0, 0), Some("`os.tmpdir()`")),
+    module("path", Some("_makeLong"), NodeVersion(9, 0, 0), Some("`path.toNamespacedPath()`")),
+    module("punycode", None, NodeVersion(7, 0, 0), None),
+    module("sys", None, NodeVersion(1, 0, 0), Some("the `util` module")),
+    module("timers", Some("enroll"), NodeVersion(10, 0, 0), Some("`setTimeout()`")),
+    module("timers", Some("unenroll"), NodeVersion(10, 0, 0), Some("`clearTimeout()`")),
+    module("tls", Some("SecurePair"), NodeVersion(6, 0, 0), Some("`tls.TLSSocket`")),
+    module("tls", Some("createSecurePair"), NodeVersion(6, 0, 0), Some("`tls.TLSSocket`")),
+    module("url", Some("parse"), NodeVersion(11, 0, 0), Some("the `URL` class")),
+    module("url", Some("resolve"), NodeVersion(11, 0, 0), Some("the `URL` class")),
+    module("util", Some("_extend"), NodeVersion(6, 0, 0), Some("`Object.assign()`")),
+    module("util", Some("debug"), NodeVersion(0, 12, 0), Some("`console.error()`")),
+    module("util", Some("error"), NodeVersion(0, 12, 0), Some("`console.error()`")),
+    module("util", Some("isArray"), NodeVersion(4, 0, 0), Some("`Array.isArray()`")),
+    module("util", Some("isBoolean"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isBuffer"), NodeVersion(4, 0, 0), Some("`Buffer.isBuffer()`")),
+    module("util", Some("isDate"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isError"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isFunction"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isNull"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isNullOrUndefined"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isNumber"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isObject"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isPrimitive"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isRegExp"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isString"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isSymbol"), NodeVersion(4, 0, 0), None),
+    module("util", Some("isUndefined"), NodeVersion(4, 0, 0), None),
+    module("util", Some("log"), NodeVersion(6, 0, 0), None),
+    module("util", Some("print"), NodeVersion(0, 12, 0), Some("`console.log()`")),
+    module("util", Some("pump"), NodeVersion(0, 10, 0), Some("`stream.Readable.prototype.pipe()`")),
+    module("util", Some("puts"), NodeVersion(0, 12, 0), Some("`console.log()`")),
+];
+
+#[rustfmt::skip]
+const DEPRECATED_GLOBALS: &[DeprecatedApi] = &[
+    global("Buffer", None, NodeVersion(6, 0, 0), Some("`Buffer.alloc()` or `Buffer.from()`")),
+    global("process", Some("EventEmitter"), NodeVersion(0, 6, 0), Some("the `events` module")),
+    global("process", Some("assert"), NodeVersion(10, 0, 0), Some("the `assert` module")),
+    global("process", Some("binding"), NodeVersion(10, 9, 0), None),
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("Buffer.alloc(10)", None),
+        ("Buffer.from('foo')", None),
+        ("let Buffer = class {}; new Buffer(10)", None),
+        ("process.exit()", None),
+        ("import fs from 'fs'; fs.access('./file', () => {})", None),
+        ("import { readFile } from 'node:fs'", None),
+        ("import { exists } from './fs'", None),
+        ("const fs = require('fs'); fs.stat('./file')", None),
+        ("const { isArray } = Array", None),
+        ("const util = require('util'); util.inspect(util)", None),
+        ("const fs = require('./fs'); fs.exists('./file')", None),
+        ("fs.exists('./file')", None),
+        // Deprecated after the configured version
+        ("new Buffer(10)", Some(json!([{ "version": "4.0.0" }]))),
+        ("require('url').parse(url)", Some(json!([{ "version": ">=10.0.0" }]))),
+        ("import { isArray } from 'util'", Some(json!([{ "version": "0.12" }]))),
+    ];
+
+    let fail = vec![
+        ("new Buffer(10)", None),
+        ("Buffer(10)", None),
+        ("process.binding('fs')", None),
+        ("import fs from 'fs'; fs.exists('./file', () => {})", None),
+        ("import * as fs from 'node:fs'; fs.exists('./file', () => {})", None),
+        ("import { exists } from 'fs'", None),
+        ("const fs = require('fs'); fs.exists('./file', () => {})", None),
+        ("const { isArray } = require('util')", None),
+        ("require('url').parse(url)", None),
+        ("import domain from 'domain'", None),
+        ("const punycode = require('punycode')", None),
+        ("require('os').tmpDir()", Some(json!([{ "version": ">=8.0.0" }]))),
+        ("import { createCipher } from 'node:crypto'", Some(json!([{ "version": "16" }]))),
+    ];
+
+    Tester::new(NoDeprecatedApi::NAME, pass, fail).test_and_snapshot();
+}
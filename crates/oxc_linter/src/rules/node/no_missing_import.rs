@@ -0,0 +1,99 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::node_builtin_module_name};
+
+fn no_missing_import_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("\"{specifier}\" is not found"))
+        .with_help("Check the path of the module, or install the package which provides it")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMissingImport;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `import` and `export` declarations for modules which cannot be resolved.
+    ///
+    /// Modules are resolved the same way as the import plugin does, honoring `tsconfig.json`
+    /// paths. Node.js builtin modules, e.g. `fs` or `node:fs`, are always allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a file or package which does not exist fails at runtime, often only when the
+    /// importing module is loaded for the first time.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import foo from "./missing-file";
+    /// import bar from "package-which-is-not-installed";
+    /// export * from "./missing-file";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from "node:fs";
+    /// import foo from "./foo";
+    /// export * from "./foo";
+    /// ```
+    NoMissingImport,
+    correctness
+);
+
+impl Rule for NoMissingImport {
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            if !module_record.unresolved_modules.contains(specifier)
+                || node_builtin_module_name(specifier).is_some()
+            {
+                continue;
+            }
+            for requested_module in requested_modules {
+                // Type only imports are removed by the compiler.
+                if requested_module.is_type() {
+                    continue;
+                }
+                ctx.diagnostic(no_missing_import_diagnostic(requested_module.span(), specifier));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import foo from './bar'",
+        "import foo from './bar.js'",
+        "import { foo } from './bar'",
+        "import data from './data.json'",
+        "import fs from 'fs'",
+        "import fs from 'node:fs'",
+        "import { readFile } from 'fs/promises'",
+        "import test from 'node:test'",
+        "export * from './bar'",
+        "export { foo } from './bar'",
+        "const foo = require('./missing')",
+        "import('./missing')",
+    ];
+
+    let fail = vec![
+        "import foo from './missing'",
+        "import './missing.js'",
+        "import foo from 'this-package-does-not-exist'",
+        "export * from './missing'",
+        "export { foo } from './missing'",
+    ];
+
+    Tester::new(NoMissingImport::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_node_plugin(true)
+        .test_and_snapshot();
+}
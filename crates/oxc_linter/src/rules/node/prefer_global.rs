@@ -0,0 +1,204 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext, rule::Rule, utils::for_each_node_builtin_module_access, AstNode,
+};
+
+fn prefer_global_diagnostic(span: Span, global: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected import of `{global}`"))
+        .with_help(format!("Use the global variable `{global}` instead"))
+        .with_label(span)
+}
+
+fn prefer_module_diagnostic(span: Span, global: &str, module: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected use of the global variable `{global}`"))
+        .with_help(format!("Import `{global}` from the `{module}` module instead"))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobal(Box<PreferGlobalConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferGlobalConfig {
+    /// Globals which must be imported from their modules, configured with `"never"`.
+    never: Vec<&'static str>,
+}
+
+impl std::ops::Deref for PreferGlobal {
+    type Target = PreferGlobalConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using either the global variables or the imports of the Node.js modules which
+    /// provide them: `Buffer`, `console`, `process`, `TextDecoder`, `TextEncoder`, `URL` and
+    /// `URLSearchParams`.
+    ///
+    /// This rule combines the `prefer-global/*` rules of `eslint-plugin-n`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The globals and the module exports are the same values, mixing both styles in a code
+    /// base is inconsistent.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const { Buffer } = require("buffer");
+    /// import { URL } from "node:url";
+    /// const process = require("process");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const buffer = Buffer.from("foo");
+    /// const url = new URL("https://example.com");
+    /// process.exit(0);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// Each global is configured with `"always"` (the default) to prefer the global variable,
+    /// or `"never"` to prefer the import. The keys are `buffer`, `console`, `process`,
+    /// `textDecoder`, `textEncoder`, `url` and `urlSearchParams`.
+    ///
+    /// ```json
+    /// {
+    ///   "node/prefer-global": ["error", { "buffer": "never", "process": "never" }]
+    /// }
+    /// ```
+    PreferGlobal,
+    style
+);
+
+impl Rule for PreferGlobal {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let never = NODE_GLOBALS
+            .iter()
+            .filter(|global| {
+                config.get(global.option).and_then(serde_json::Value::as_str) == Some("never")
+            })
+            .map(|global| global.name)
+            .collect();
+        Self(Box::new(PreferGlobalConfig { never }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if self.never.is_empty() {
+            return;
+        }
+        let AstKind::IdentifierReference(ident) = node.kind() else {
+            return;
+        };
+        if !self.never.iter().any(|name| *name == ident.name.as_str())
+            || !ctx.semantic().is_reference_to_global_variable(ident)
+        {
+            return;
+        }
+        if let Some(global) = NODE_GLOBALS.iter().find(|global| global.name == ident.name.as_str())
+        {
+            ctx.diagnostic(prefer_module_diagnostic(ident.span, global.name, global.module));
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        for_each_node_builtin_module_access(ctx, |module, member, span| {
+            let Some(global) = NODE_GLOBALS
+                .iter()
+                .find(|global| global.module == module && global.member == member)
+            else {
+                return;
+            };
+            if !self.never.contains(&global.name) {
+                ctx.diagnostic(prefer_global_diagnostic(span, global.name));
+            }
+        });
+    }
+}
+
+struct NodeGlobal {
+    /// Key of the configuration option.
+    option: &'static str,
+    name: &'static str,
+    /// Module providing the global.
+    module: &'static str,
+    /// Export of [`Self::module`] which is the global, `None` if the module itself is.
+    member: Option<&'static str>,
+}
+
+const NODE_GLOBALS: &[NodeGlobal] = &[
+    NodeGlobal { option: "buffer", name: "Buffer", module: "buffer", member: Some("Buffer") },
+    NodeGlobal { option: "console", name: "console", module: "console", member: None },
+    NodeGlobal { option: "process", name: "process", module: "process", member: None },
+    NodeGlobal {
+        option: "textDecoder",
+        name: "TextDecoder",
+        module: "util",
+        member: Some("TextDecoder"),
+    },
+    NodeGlobal {
+        option: "textEncoder",
+        name: "TextEncoder",
+        module: "util",
+        member: Some("TextEncoder"),
+    },
+    NodeGlobal { option: "url", name: "URL", module: "url", member: Some("URL") },
+    NodeGlobal {
+        option: "urlSearchParams",
+        name: "URLSearchParams",
+        module: "url",
+        member: Some("URLSearchParams"),
+    },
+];
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("const buffer = Buffer.from('foo')", None),
+        ("const url = new URL('https://example.com')", None),
+        ("process.exit(0)", None),
+        ("console.log('foo')", None),
+        ("const { inspect } = require('util')", None),
+        ("import { fileURLToPath } from 'node:url'", None),
+        ("const { Buffer } = require('./buffer')", None),
+        ("const { Buffer } = require('buffer')", Some(json!([{ "buffer": "never" }]))),
+        ("import process from 'process'", Some(json!([{ "process": "never" }]))),
+        (
+            "const Buffer = require('buffer').Buffer; Buffer.from('foo')",
+            Some(json!([{ "buffer": "never" }])),
+        ),
+        ("URL.createObjectURL(blob)", Some(json!([{ "buffer": "never" }]))),
+    ];
+
+    let fail = vec![
+        ("const { Buffer } = require('buffer')", None),
+        ("const Buffer = require('buffer').Buffer", None),
+        ("import { URL } from 'node:url'", None),
+        ("import { URLSearchParams } from 'url'", None),
+        ("const { TextEncoder } = require('util')", None),
+        ("import { TextDecoder } from 'util'", None),
+        ("const process = require('process')", None),
+        ("import console from 'node:console'", None),
+        ("const buffer = Buffer.from('foo')", Some(json!([{ "buffer": "never" }]))),
+        ("process.exit(0)", Some(json!([{ "process": "never" }]))),
+        ("new URL('https://example.com')", Some(json!([{ "url": "never" }]))),
+    ];
+
+    Tester::new(PreferGlobal::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,4 +1,5 @@
 use oxc_semantic::{ReferenceId, ScopeId, SymbolId};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 
@@ -7,8 +8,9 @@ use oxc_ast::{
         ArrayExpression, ArrayExpressionElement, CallExpression, Expression, ObjectExpression,
         ObjectPropertyKind,
     },
-    AstKind, Visit,
+    AstKind,
 };
+use oxc_ast_visit::Visit;
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -61,7 +63,7 @@ fn no_map_spread_diagnostic(
     diagnostic.and_labels(others).and_labels(returned_label)
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NoMapSpreadConfig {
     /// Ignore mapped arrays that are re-read after the `map` call.
@@ -310,7 +312,8 @@ declare_oxc_lint!(
     /// - [JSPerf - `concat` vs array spread performance](https://jsperf.app/pihevu)
     NoMapSpread,
     nursery, // TODO: make this `perf` once we've battle-tested this a bit
-    conditional_fix_suggestion
+    conditional_fix_suggestion,
+    config = NoMapSpreadConfig
 );
 
 const MAP_FN_NAMES: [&str; 2] = ["map", "flatMap"];
@@ -319,10 +322,7 @@ impl Rule for NoMapSpread {
     fn from_configuration(value: serde_json::Value) -> Self {
         let config: NoMapSpreadConfig = value
             .get(0)
-            .map(|obj| {
-                serde_json::from_value(obj.clone())
-                    .expect("Invalid configuration for `oxc/no-map-spread`")
-            })
+            .and_then(|obj| serde_json::from_value(obj.clone()).ok())
             .unwrap_or_default();
 
         Self::from(config)
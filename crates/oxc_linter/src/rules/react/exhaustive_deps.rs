@@ -0,0 +1,682 @@
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, BindingPatternKind, ChainElement, Expression,
+        IdentifierReference, MemberExpression, StaticMemberExpression,
+    },
+    AstKind,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+mod diagnostics {
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_span::Span;
+    const SCOPE: &str = "eslint-plugin-react-hooks";
+
+    pub(super) fn missing_callback(span: Span, hook_name: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "React Hook {hook_name} requires an effect callback. \
+            Did you forget to pass a callback to the hook?"
+        ))
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn missing_dependency_array(span: Span, hook_name: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "React Hook {hook_name} does nothing when called with only one argument. \
+            Did you forget to pass an array of dependencies?"
+        ))
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn unknown_dependencies(span: Span, hook_name: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "React Hook {hook_name} received a function whose dependencies are unknown. \
+            Pass an inline function instead."
+        ))
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn async_effect(span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn(
+            "Effect callbacks are synchronous to prevent race conditions. \
+            Put the async function inside.",
+        )
+        .with_help(
+            "Define an async function inside the effect and call it, \
+            e.g. `useEffect(() => { async function fetchData() {} fetchData(); }, [])`.",
+        )
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn not_array_literal(span: Span, hook_name: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "React Hook {hook_name} was passed a dependency list that is not an array literal. \
+            This means we can't statically verify whether you've passed the correct dependencies."
+        ))
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn spread_element(span: Span, hook_name: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "React Hook {hook_name} has a spread element in its dependency array. \
+            This means we can't statically verify whether you've passed the correct dependencies."
+        ))
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn literal_dependency(span: Span, literal: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "The {literal} literal is not a valid dependency because it never changes. \
+            You can safely remove it."
+        ))
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn string_literal_dependency(
+        span: Span,
+        literal: &str,
+        name: &str,
+    ) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "The {literal} literal is not a valid dependency because it never changes. \
+            Did you mean to include {name} in the array instead?"
+        ))
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn complex_expression(span: Span, hook_name: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "React Hook {hook_name} has a complex expression in the dependency array. \
+            Extract it to a separate variable so it can be statically checked."
+        ))
+        .with_label(span)
+        .with_error_code_scope(SCOPE)
+    }
+
+    pub(super) fn dependencies(
+        span: Span,
+        hook_name: &str,
+        message: &str,
+        suggestion: &str,
+    ) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!("React Hook {hook_name} has {message}"))
+            .with_help(format!("Update the dependencies array to be: {suggestion}"))
+            .with_label(span)
+            .with_error_code_scope(SCOPE)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDeps(Box<ExhaustiveDepsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDepsConfig {
+    /// Custom hooks whose dependencies are checked too, like the ones of `useEffect`.
+    additional_hooks: Option<Regex>,
+}
+
+impl std::ops::Deref for ExhaustiveDeps {
+    type Target = ExhaustiveDepsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies the list of dependencies of Hooks like `useEffect`, `useCallback` and `useMemo`.
+    ///
+    /// Values declared in a component, e.g. its props, state or local variables, which are used
+    /// by the callback of a Hook must be listed in its dependency array. Values which never change
+    /// between renders, e.g. the setter of `useState`, the dispatcher of `useReducer` or the
+    /// result of `useRef`, can be omitted.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A callback with a missing dependency reads stale values from a previous render, and an
+    /// effect with a missing dependency is not run again when the dependency changes.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function MyComponent(props) {
+    ///     useEffect(() => {
+    ///         console.log(props.foo);
+    ///     }, []);
+    ///     const onClick = useCallback(() => {}, [props.foo]);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function MyComponent(props) {
+    ///     const [state, setState] = useState();
+    ///     useEffect(() => {
+    ///         setState(props.foo);
+    ///     }, [props.foo]);
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `additionalHooks`: a regular expression matching the names of custom Hooks whose
+    /// dependencies are checked too, e.g. `{ "additionalHooks": "(useMyEffect|useMyCallback)" }`.
+    /// Their callback must be their first argument, and their dependency array the second.
+    ExhaustiveDeps,
+    correctness,
+    suggestion
+);
+
+impl Rule for ExhaustiveDeps {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let additional_hooks = value
+            .get(0)
+            .and_then(|config| config.get("additionalHooks"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(|pattern| Regex::new(pattern).ok());
+        Self(Box::new(ExhaustiveDepsConfig { additional_hooks }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(name) = hook_name(&call.callee) else { return };
+        let Some(callback_index) = self.callback_index(name) else { return };
+
+        let hook = call.callee.span().source_text(ctx.source_text());
+        let is_effect = is_effect_hook(name);
+
+        let Some(callback) = call.arguments.get(callback_index) else {
+            ctx.diagnostic(diagnostics::missing_callback(call.callee.span(), hook));
+            return;
+        };
+        let Some(dependencies) = call.arguments.get(callback_index + 1).filter(
+            |argument| !matches!(argument, Argument::Identifier(ident) if ident.name == "undefined"),
+        ) else {
+            if matches!(name, "useMemo" | "useCallback") {
+                ctx.diagnostic(diagnostics::missing_dependency_array(call.callee.span(), hook));
+            }
+            return;
+        };
+
+        let Some(callback) = resolve_callback(callback, dependencies, hook, ctx) else { return };
+        if is_effect && callback.is_async {
+            ctx.diagnostic(diagnostics::async_effect(callback.span));
+        }
+        let Argument::ArrayExpression(array) = dependencies else {
+            ctx.diagnostic(diagnostics::not_array_literal(dependencies.span(), hook));
+            return;
+        };
+
+        let pure_scopes = pure_scopes(node, ctx);
+        let used = used_dependencies(callback.span, &pure_scopes, ctx);
+
+        let mut declared = vec![];
+        for element in &array.elements {
+            let expr = match element {
+                ArrayExpressionElement::Elision(_) => continue,
+                ArrayExpressionElement::SpreadElement(spread) => {
+                    ctx.diagnostic(diagnostics::spread_element(spread.span, hook));
+                    continue;
+                }
+                element => element.to_expression(),
+            };
+            if let Some((path, ident)) = dependency_path(expr) {
+                let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id();
+                declared.push(DeclaredDependency { path, symbol_id });
+            } else if expr.is_literal() {
+                let literal = expr.span().source_text(ctx.source_text());
+                match expr {
+                    Expression::StringLiteral(string)
+                        if used.contains_key(string.value.as_str()) =>
+                    {
+                        ctx.diagnostic(diagnostics::string_literal_dependency(
+                            string.span,
+                            literal,
+                            &string.value,
+                        ));
+                    }
+                    _ => ctx.diagnostic(diagnostics::literal_dependency(expr.span(), literal)),
+                }
+            } else {
+                ctx.diagnostic(diagnostics::complex_expression(expr.span(), hook));
+            }
+        }
+
+        // Hooks outside of a function are not called by a component.
+        if pure_scopes.is_empty() {
+            return;
+        }
+
+        let mut missing = used
+            .iter()
+            .filter(|(path, is_stable)| {
+                !**is_stable && !declared.iter().any(|declared| satisfies(&declared.path, path))
+            })
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        missing.sort_unstable();
+
+        let mut suggested = vec![];
+        let mut unnecessary = vec![];
+        let mut duplicate = vec![];
+        let mut external = None;
+        let mut seen = FxHashSet::default();
+        for dependency in &declared {
+            if !seen.insert(dependency.path.as_str()) {
+                duplicate.push(dependency.path.clone());
+                continue;
+            }
+            let is_external = dependency.symbol_id.map_or(true, |symbol_id| {
+                !pure_scopes.contains(&ctx.symbols().get_scope_id(symbol_id))
+            });
+            let is_used = used.keys().any(|path| satisfies(&dependency.path, path));
+            // Effects may depend on more values than they use, to run again when they change.
+            if is_used || (is_effect && !is_external && !dependency.path.ends_with(".current")) {
+                suggested.push(dependency.path.clone());
+            } else {
+                if is_external && external.is_none() {
+                    external = Some(dependency.path.as_str());
+                }
+                unnecessary.push(dependency.path.clone());
+            }
+        }
+        unnecessary.sort_unstable();
+        duplicate.sort_unstable();
+
+        let mut message = if !missing.is_empty() {
+            dependencies_message("missing", "include", &missing)
+        } else if !unnecessary.is_empty() {
+            dependencies_message("unnecessary", "exclude", &unnecessary)
+        } else if !duplicate.is_empty() {
+            dependencies_message("duplicate", "omit", &duplicate)
+        } else {
+            return;
+        };
+        if let Some(mutable) = unnecessary.iter().find(|path| path.ends_with(".current")) {
+            message.push_str(&format!(
+                " Mutable values like '{mutable}' aren't valid dependencies because mutating \
+                them doesn't re-render the component."
+            ));
+        } else if let Some(external) = external {
+            message.push_str(&format!(
+                " Outer scope values like '{external}' aren't valid dependencies because \
+                mutating them doesn't re-render the component."
+            ));
+        }
+
+        // Keep the declared dependencies in their order, unless they were sorted.
+        let is_sorted = declared.windows(2).all(|pair| pair[0].path <= pair[1].path);
+        suggested.extend(missing);
+        if is_sorted {
+            suggested.sort_unstable();
+        }
+        let suggestion = format!("[{}]", suggested.join(", "));
+
+        ctx.diagnostic_with_suggestion(
+            diagnostics::dependencies(array.span, hook, &message, &suggestion),
+            |fixer| fixer.replace(array.span, suggestion.clone()),
+        );
+    }
+}
+
+impl ExhaustiveDeps {
+    /// The index of the callback argument of a hook whose dependencies are checked.
+    fn callback_index(&self, hook_name: &str) -> Option<usize> {
+        match hook_name {
+            "useEffect" | "useLayoutEffect" | "useInsertionEffect" | "useCallback" | "useMemo" => {
+                Some(0)
+            }
+            "useImperativeHandle" => Some(1),
+            _ => self
+                .additional_hooks
+                .as_ref()
+                .is_some_and(|pattern| pattern.is_match(hook_name))
+                .then_some(0),
+        }
+    }
+}
+
+/// The function whose dependencies are checked.
+struct Callback {
+    span: Span,
+    is_async: bool,
+}
+
+/// A dependency listed in a dependency array, e.g. `props.foo` in `[props.foo]`.
+struct DeclaredDependency {
+    path: String,
+    /// The symbol of the root of the path, `None` for globals.
+    symbol_id: Option<SymbolId>,
+}
+
+/// The function passed to a hook, or `None` if its dependencies cannot be checked.
+///
+/// A function passed by name, e.g. `useEffect(handler, [])`, is checked if it is declared in the
+/// same file, or if it is a dependency itself, e.g. `useMemo(fn, [fn])`.
+fn resolve_callback(
+    callback: &Argument,
+    dependencies: &Argument,
+    hook: &str,
+    ctx: &LintContext,
+) -> Option<Callback> {
+    match callback {
+        Argument::ArrowFunctionExpression(arrow) => {
+            return Some(Callback { span: arrow.span, is_async: arrow.r#async })
+        }
+        Argument::FunctionExpression(func) => {
+            return Some(Callback { span: func.span, is_async: func.r#async })
+        }
+        Argument::Identifier(ident) => {
+            if let Argument::ArrayExpression(array) = dependencies {
+                if array.elements.iter().any(|element| {
+                    matches!(element, ArrayExpressionElement::Identifier(dependency)
+                        if dependency.name == ident.name)
+                }) {
+                    return None;
+                }
+            }
+            let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id();
+            if let Some(symbol_id) = symbol_id {
+                match ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id)) {
+                    AstKind::Function(func) => {
+                        return Some(Callback { span: func.span, is_async: func.r#async })
+                    }
+                    AstKind::VariableDeclarator(decl) => {
+                        match decl.init.as_ref().map(Expression::get_inner_expression) {
+                            Some(Expression::ArrowFunctionExpression(arrow)) => {
+                                return Some(Callback { span: arrow.span, is_async: arrow.r#async })
+                            }
+                            Some(Expression::FunctionExpression(func)) => {
+                                return Some(Callback { span: func.span, is_async: func.r#async })
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+    ctx.diagnostic(diagnostics::unknown_dependencies(callback.span(), hook));
+    None
+}
+
+/// The name of the hook called by `callee`, e.g. `useEffect` for `useEffect` or
+/// `React.useEffect`.
+fn hook_name<'a>(callee: &'a Expression) -> Option<&'a str> {
+    match callee {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        Expression::StaticMemberExpression(member) if matches!(&member.object, Expression::Identifier(ident) if ident.name == "React") => {
+            Some(member.property.name.as_str())
+        }
+        _ => None,
+    }
+}
+
+/// Whether a hook is an effect, e.g. `useEffect` or `useLayoutEffect`, whose dependencies may
+/// include values which it does not use.
+fn is_effect_hook(name: &str) -> bool {
+    name.match_indices("Effect").any(|(index, effect)| {
+        name[index + effect.len()..].chars().next().map_or(true, |c| !c.is_ascii_lowercase())
+    })
+}
+
+/// The scopes from the hook call up to the component or hook function which calls it, whose
+/// values may change between renders. Empty if the hook is not called in a function.
+fn pure_scopes(node: &AstNode, ctx: &LintContext) -> FxHashSet<ScopeId> {
+    let mut scopes = FxHashSet::default();
+    for scope_id in ctx.scopes().ancestors(node.scope_id()) {
+        scopes.insert(scope_id);
+        if ctx.scopes().get_flags(scope_id).is_function() {
+            return scopes;
+        }
+    }
+    FxHashSet::default()
+}
+
+/// The dependencies used by a callback, declared in the pure scopes, and whether they are stable.
+fn used_dependencies(
+    callback_span: Span,
+    pure_scopes: &FxHashSet<ScopeId>,
+    ctx: &LintContext,
+) -> FxHashMap<String, bool> {
+    let mut dependencies = FxHashMap::default();
+    for &scope_id in pure_scopes {
+        for symbol_id in ctx.scopes().iter_bindings_in(scope_id) {
+            let mut is_stable = None;
+            for reference in ctx.semantic().symbol_references(symbol_id) {
+                let node_id = reference.node_id();
+                if !reference.is_read()
+                    || !callback_span.contains_inclusive(ctx.nodes().kind(node_id).span())
+                {
+                    continue;
+                }
+                let is_stable = *is_stable.get_or_insert_with(|| is_stable_value(symbol_id, ctx));
+                let path = used_path(node_id, ctx.symbols().get_name(symbol_id), ctx);
+                dependencies.entry(path).or_insert(is_stable);
+            }
+        }
+    }
+    dependencies
+}
+
+/// The dependency used by a reference, e.g. `props.foo` for `props.foo.bar`.
+///
+/// The path stops before `.current`, as refs are mutable, and before methods, as `props.foo()`
+/// passes `props` as `this`.
+fn used_path(ident_id: NodeId, name: &str, ctx: &LintContext) -> String {
+    let nodes = ctx.nodes();
+    let mut path = name.to_string();
+    let mut node_id = ident_id;
+    while let Some(parent) = nodes.parent_node(node_id) {
+        let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(member)) =
+            parent.kind()
+        else {
+            break;
+        };
+        let property = member.property.name.as_str();
+        if property == "current"
+            || matches!(nodes.parent_kind(parent.id()),
+                Some(AstKind::CallExpression(call)) if call.callee.span() == member.span)
+            || matches!(nodes.parent_kind(parent.id()), Some(AstKind::SimpleAssignmentTarget(_)))
+        {
+            break;
+        }
+        path.push('.');
+        path.push_str(property);
+        node_id = parent.id();
+    }
+    path
+}
+
+/// The path of a dependency in a dependency array, e.g. `props.foo` for `props?.foo`, and the
+/// reference to its root, or `None` if it is not a path.
+fn dependency_path<'a, 'b>(
+    expr: &'b Expression<'a>,
+) -> Option<(String, &'b IdentifierReference<'a>)> {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => Some((ident.name.to_string(), ident)),
+        Expression::StaticMemberExpression(member) => member_path(member),
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::StaticMemberExpression(member) => member_path(member),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn member_path<'a, 'b>(
+    member: &'b StaticMemberExpression<'a>,
+) -> Option<(String, &'b IdentifierReference<'a>)> {
+    let (mut path, ident) = dependency_path(&member.object)?;
+    path.push('.');
+    path.push_str(&member.property.name);
+    Some((path, ident))
+}
+
+/// Whether a declared dependency covers a used dependency, e.g. `props` covers `props.foo`.
+fn satisfies(declared: &str, used: &str) -> bool {
+    used.strip_prefix(declared).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// Whether a value never changes between renders, so it is not a dependency: a `const` bound to
+/// a primitive literal, the result of `useRef`, the setter of `useState`, the dispatcher of
+/// `useReducer` or `useActionState`, or the `startTransition` of `useTransition`.
+fn is_stable_value(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let AstKind::VariableDeclarator(decl) =
+        ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id))
+    else {
+        return false;
+    };
+    let Some(init) = decl.init.as_ref().map(Expression::get_inner_expression) else {
+        return false;
+    };
+    if decl.kind.is_const() && init.is_literal() && !matches!(init, Expression::RegExpLiteral(_)) {
+        return true;
+    }
+    let Expression::CallExpression(call) = init else { return false };
+    match (&decl.id.kind, hook_name(&call.callee)) {
+        (BindingPatternKind::BindingIdentifier(_), Some("useRef")) => true,
+        (
+            BindingPatternKind::ArrayPattern(pattern),
+            Some("useState" | "useReducer" | "useActionState" | "useTransition"),
+        ) => pattern.elements.get(1).and_then(Option::as_ref).is_some_and(|element| {
+            matches!(&element.kind, BindingPatternKind::BindingIdentifier(ident)
+                if ident.symbol_id() == symbol_id)
+        }),
+        _ => false,
+    }
+}
+
+/// The main message of a report, e.g. `a missing dependency: 'foo'. Either include it or remove
+/// the dependency array.`
+fn dependencies_message(kind: &str, verb: &str, paths: &[String]) -> String {
+    let list = join_english(paths);
+    if let [_] = paths {
+        let article = if kind == "unnecessary" { "an" } else { "a" };
+        format!(
+            "{article} {kind} dependency: {list}. Either {verb} it or remove the dependency array."
+        )
+    } else {
+        format!("{kind} dependencies: {list}. Either {verb} them or remove the dependency array.")
+    }
+}
+
+/// `'a'`, `'a' and 'b'` or `'a', 'b', and 'c'`.
+fn join_english(paths: &[String]) -> String {
+    let mut list = String::new();
+    for (i, path) in paths.iter().enumerate() {
+        list.push_str(&format!("'{path}'"));
+        if i == 0 && paths.len() == 2 {
+            list.push_str(" and ");
+        } else if paths.len() > 2 && i == paths.len() - 2 {
+            list.push_str(", and ");
+        } else if i < paths.len() - 1 {
+            list.push_str(", ");
+        }
+    }
+    list
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }); }", None),
+        ("function MyComponent() { useEffect(() => { const local = {}; console.log(local); }, []); }", None),
+        ("function MyComponent() { const local = someFunc(); useEffect(() => { console.log(local); }, [local]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props]); }", None),
+        ("function MyComponent(props) { useCallback(() => { console.log(props.foo?.bar); }, [props.foo?.bar]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo!.bar); }, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { props.onChange(); }, [props]); }", None),
+        ("const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent() { useEffect(() => { window.addEventListener('resize', handler); }, []); }", None),
+        ("function MyComponent() { const [state, setState] = useState(); useEffect(() => { setState(1); }, []); }", None),
+        ("function MyComponent() { const [state, dispatch] = React.useReducer(); const ref = useRef(); useEffect(() => { dispatch(1); ref.current = 1; }, []); }", None),
+        ("function MyComponent() { const [isPending, startTransition] = useTransition(); useCallback(() => { startTransition(() => {}); }, []); }", None),
+        ("function MyComponent() { const foo = 'foo'; useEffect(() => { console.log(foo); }, []); }", None),
+        ("function MyComponent() { const local = someFunc(); useEffect(() => {}, [local]); }", None),
+        ("function MyComponent(props) { const fn = useCallback(() => {}, []); useEffect(fn, [fn]); }", None),
+        ("function MyComponent(props) { function handler() { console.log(props.foo); } useEffect(handler, [props.foo]); }", None),
+        ("function useCustom(value) { useImperativeHandle(ref, () => ({ value }), [value]); }", None),
+        ("function MyComponent() { useMemo(() => 1, []); }", None),
+        ("function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }", None),
+        (
+            "function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }); }",
+            Some(json!([{ "additionalHooks": "useCustomEffect" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("function MyComponent(props) { useCallback(() => { console.log(props.foo); }, []); }", None),
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent(props) { const local = {}; useEffect(() => { console.log(local, props.foo, props.bar); }, []); }", None),
+        ("function MyComponent() { const local = {}; useCallback(() => {}, [local]); }", None),
+        ("const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, [local]); }", None),
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [local, local]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo.bar]); }", None),
+        ("function MyComponent() { const local = {}; useMemo(() => local); }", None),
+        ("function MyComponent() { useEffect(); }", None),
+        ("function MyComponent(props) { useEffect(() => {}, props.deps); }", None),
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, [...deps, local]); }", None),
+        ("function MyComponent(props) { useEffect(() => {}, [props.items[0]]); }", None),
+        ("function MyComponent() { useEffect(() => {}, ['foo']); }", None),
+        ("function MyComponent() { const foo = useFoo(); useEffect(() => { console.log(foo); }, ['foo']); }", None),
+        ("function MyComponent() { useEffect(async () => { await fetch(); }, []); }", None),
+        ("function MyComponent(props) { useEffect(handler, [props.foo]); }", None),
+        ("function MyComponent() { const ref = useRef(); useCallback(() => {}, [ref.current]); }", None),
+        ("function MyComponent(props) { React.useEffect(() => { console.log(props.foo); }, []); }", None),
+        ("function MyComponent(props) { const [state, setState] = useState(); useEffect(() => { setState(props.foo); }, [setState]); }", None),
+        (
+            "function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }",
+            Some(json!([{ "additionalHooks": "useCustomEffect" }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "function MyComponent(props) { useCallback(() => { console.log(props.foo); }, []); }",
+            "function MyComponent(props) { useCallback(() => { console.log(props.foo); }, [props.foo]); }",
+            None,
+        ),
+        (
+            "function MyComponent() { const local = {}; useCallback(() => {}, [local]); }",
+            "function MyComponent() { const local = {}; useCallback(() => {}, []); }",
+            None,
+        ),
+        (
+            "function MyComponent(props) { const local = {}; useEffect(() => { console.log(local, props.foo); }, [props.foo]); }",
+            "function MyComponent(props) { const local = {}; useEffect(() => { console.log(local, props.foo); }, [local, props.foo]); }",
+            None,
+        ),
+        (
+            "function MyComponent({ b, a, c }) { useEffect(() => { console.log(a, b, c); }, [b, a]); }",
+            "function MyComponent({ b, a, c }) { useEffect(() => { console.log(a, b, c); }, [b, a, c]); }",
+            None,
+        ),
+    ];
+
+    Tester::new(ExhaustiveDeps::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,224 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{
+        CharacterClass, CharacterClassContents, CharacterClassContentsKind,
+        CharacterClassEscapeKind,
+    },
+    visit::{walk::walk_character_class, Visit},
+};
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_dupe_characters_in_character_class_diagnostic(
+    span: Span,
+    element: &str,
+    other: &str,
+) -> OxcDiagnostic {
+    let help = if element == other {
+        format!("Remove the duplicate `{element}`")
+    } else {
+        format!("`{element}` is already included in `{other}`, remove it")
+    };
+    OxcDiagnostic::warn(format!("Unexpected duplicate `{element}` in character class"))
+        .with_help(help)
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDupeCharactersInCharacterClass;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate characters, ranges and escapes in character classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A character class matches any one of its elements, so an element that is
+    /// repeated, or that is already included in a range or a character class
+    /// escape of the same class, has no effect. It is usually a typo, such as
+    /// `[a-zA-z]` instead of `[a-zA-Z]`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /[\(\)\(]/;
+    /// var foo = /[a-za-f]/;
+    /// var foo = /[\d0-5]/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /[\(\)]/;
+    /// var foo = /[a-z]/;
+    /// var foo = /[\d]/;
+    /// ```
+    NoDupeCharactersInCharacterClass,
+    suspicious,
+    fix
+);
+
+impl Rule for NoDupeCharactersInCharacterClass {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::RegExpLiteral(lit) = node.kind() else {
+            return;
+        };
+        let Some(pattern) = lit.regex.pattern.as_pattern() else {
+            return;
+        };
+
+        let mut finder = DuplicateFinder::default();
+        finder.visit_pattern(pattern);
+
+        let source_text = ctx.source_text();
+        for (span, other) in finder.duplicates {
+            let element = span.source_text(source_text);
+            let diagnostic = no_dupe_characters_in_character_class_diagnostic(
+                span,
+                element,
+                other.source_text(source_text),
+            );
+            // Removing an element next to an unescaped `-` could join its neighbors into a range.
+            let bytes = source_text.as_bytes();
+            if bytes[span.start as usize - 1] == b'-' || bytes[span.end as usize] == b'-' {
+                ctx.diagnostic(diagnostic);
+            } else {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(span));
+            }
+        }
+    }
+}
+
+/// The set of characters matched by an element of a character class, as far as it can be told
+/// without case folding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Element<'a> {
+    /// A character, or a range of characters, as an inclusive range of code points.
+    Range(u32, u32),
+    Escape(CharacterClassEscapeKind),
+    Property(bool, &'a str, Option<&'a str>),
+}
+
+impl<'a> Element<'a> {
+    fn new(contents: &CharacterClassContents<'a>) -> Option<Self> {
+        match contents {
+            CharacterClassContents::Character(c) => Some(Self::Range(c.value, c.value)),
+            CharacterClassContents::CharacterClassRange(range) => {
+                Some(Self::Range(range.min.value, range.max.value))
+            }
+            CharacterClassContents::CharacterClassEscape(escape) => Some(Self::Escape(escape.kind)),
+            CharacterClassContents::UnicodePropertyEscape(escape) => Some(Self::Property(
+                escape.negative,
+                escape.name.as_str(),
+                escape.value.as_ref().map(Atom::as_str),
+            )),
+            CharacterClassContents::NestedCharacterClass(_)
+            | CharacterClassContents::ClassStringDisjunction(_) => None,
+        }
+    }
+
+    /// Returns `true` if every character matched by `other` is also matched by `self`.
+    fn includes(self, other: Self) -> bool {
+        const DIGITS: &[(u32, u32)] = &[('0' as u32, '9' as u32)];
+        const WORDS: &[(u32, u32)] = &[
+            ('0' as u32, '9' as u32),
+            ('A' as u32, 'Z' as u32),
+            ('_' as u32, '_' as u32),
+            ('a' as u32, 'z' as u32),
+        ];
+        let within = |ranges: &[(u32, u32)], min: u32, max: u32| {
+            ranges.iter().any(|&(start, end)| start <= min && max <= end)
+        };
+        match (self, other) {
+            (Self::Range(start, end), Self::Range(min, max)) => start <= min && max <= end,
+            (Self::Escape(CharacterClassEscapeKind::D), Self::Range(min, max)) => {
+                within(DIGITS, min, max)
+            }
+            (Self::Escape(CharacterClassEscapeKind::W), Self::Range(min, max)) => {
+                within(WORDS, min, max)
+            }
+            (
+                Self::Escape(CharacterClassEscapeKind::W),
+                Self::Escape(CharacterClassEscapeKind::D),
+            ) => true,
+            _ => self == other,
+        }
+    }
+}
+
+#[derive(Default)]
+struct DuplicateFinder {
+    /// Spans of the duplicate elements, along with the span of an element that includes them.
+    duplicates: Vec<(Span, Span)>,
+}
+
+impl<'a> Visit<'a> for DuplicateFinder {
+    fn visit_character_class(&mut self, class: &CharacterClass<'a>) {
+        if class.kind == CharacterClassContentsKind::Union {
+            let elements = class
+                .body
+                .iter()
+                .filter_map(|contents| Some((Element::new(contents)?, contents.span())))
+                .collect::<Vec<_>>();
+
+            for (i, &(element, span)) in elements.iter().enumerate() {
+                // An element is a duplicate of an identical element before it, or of a larger
+                // element anywhere in the class.
+                let other = elements.iter().enumerate().find(|&(j, &(other, _))| {
+                    i != j && other.includes(element) && (j < i || !element.includes(other))
+                });
+                if let Some((_, &(_, other_span))) = other {
+                    self.duplicates.push((span, other_span));
+                }
+            }
+        }
+        walk_character_class(self, class);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = /[a-z]/",
+        "var foo = /[a-zA-Z]/",
+        "var foo = /[\\w\\s]/",
+        "var foo = /[\\p{L}\\p{N}]/u",
+        "var foo = /[a][a]/",
+        "var foo = /[[a]a]/v",
+        "var foo = new RegExp('[aa]')",
+    ];
+
+    let fail = vec![
+        "var foo = /[aa]/",
+        "var foo = /[\\(\\)\\(]/",
+        "var foo = /[a-za-f]/",
+        "var foo = /[xa-z]/",
+        "var foo = /[\\d0-5]/",
+        "var foo = /[\\w\\d_]/",
+        "var foo = /[\\p{L}\\p{L}]/u",
+        "var foo = /[a-z-a]/",
+        "var foo = /[[aa]]/v",
+    ];
+
+    let fix = vec![
+        ("var foo = /[aa]/", "var foo = /[a]/"),
+        ("var foo = /[\\(\\)\\(]/", "var foo = /[\\(\\)]/"),
+        ("var foo = /[a-za-f]/", "var foo = /[a-z]/"),
+        ("var foo = /[xa-z]/", "var foo = /[a-z]/"),
+        ("var foo = /[\\d0-5]/", "var foo = /[\\d]/"),
+        ("var foo = /[\\w\\d_]/", "var foo = /[\\w]/"),
+        ("var foo = /[\\p{L}\\p{L}]/u", "var foo = /[\\p{L}]/u"),
+        ("var foo = /[[aa]]/v", "var foo = /[[a]]/v"),
+    ];
+
+    Tester::new(NoDupeCharactersInCharacterClass::NAME, pass, fail)
+        .expect_fix(fix)
+        .with_regexp_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,108 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::Disjunction,
+    visit::{walk::walk_disjunction, Visit},
+};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_empty_alternative_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected empty alternative")
+        .with_help(
+            "If the empty alternative is intentional, make the group optional with `?` instead",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoEmptyAlternative;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow alternatives without elements in regular expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An empty alternative matches the empty string, which is rarely intended
+    /// and is usually left behind by a stray `|`. When it is intended, a
+    /// quantifier states it more clearly.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /a+|b*|/;
+    /// var foo = /(?:a|b|)c/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /a+|b*/;
+    /// var foo = /(?:a|b)?c/;
+    /// ```
+    NoEmptyAlternative,
+    suspicious
+);
+
+impl Rule for NoEmptyAlternative {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::RegExpLiteral(lit) = node.kind() else {
+            return;
+        };
+        let Some(pattern) = lit.regex.pattern.as_pattern() else {
+            return;
+        };
+
+        let mut finder = EmptyAlternativeFinder::default();
+        finder.visit_pattern(pattern);
+
+        for span in finder.disjunctions {
+            ctx.diagnostic(no_empty_alternative_diagnostic(span));
+        }
+    }
+}
+
+#[derive(Default)]
+struct EmptyAlternativeFinder {
+    disjunctions: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for EmptyAlternativeFinder {
+    fn visit_disjunction(&mut self, disjunction: &Disjunction<'a>) {
+        if disjunction.body.len() > 1
+            && disjunction.body.iter().any(|alternative| alternative.body.is_empty())
+        {
+            self.disjunctions.push(disjunction.span);
+        }
+        walk_disjunction(self, disjunction);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = /()|(?:)|(?=)/",
+        "var foo = /(?:)/",
+        "var foo = /a*|b+/",
+        "var foo = /(?:a|b)?c/",
+        "var foo = /[|]/",
+        "var foo = /\\|/",
+        "var foo = new RegExp('a|')",
+    ];
+
+    let fail = vec![
+        "var foo = /|||||/",
+        "var foo = /(a+|b+|)/",
+        "var foo = /(?:\\|\\|||\\|)/",
+        "var foo = /(?<name>(?:a|b|))/",
+        "var foo = /a|(?=b|)/",
+    ];
+
+    Tester::new(NoEmptyAlternative::NAME, pass, fail).with_regexp_plugin(true).test_and_snapshot();
+}
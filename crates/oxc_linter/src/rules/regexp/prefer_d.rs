@@ -0,0 +1,137 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{CharacterClass, CharacterClassContents, CharacterClassContentsKind},
+    visit::{walk::walk_character_class, Visit},
+};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_d_diagnostic(span: Span, kind: &str, text: &str, replacement: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected {kind} `{text}`"))
+        .with_help(format!("Use `{replacement}` instead"))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferD;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using `\d` instead of the `0-9` range in regular expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `\d` matches exactly the characters `0` to `9`, and is shorter and more
+    /// familiar than spelling the range out.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /[0-9]/;
+    /// var foo = /[^0-9]/;
+    /// var foo = /[a-z0-9]/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /\d/;
+    /// var foo = /\D/;
+    /// var foo = /[a-z\d]/;
+    /// ```
+    PreferD,
+    style,
+    fix,
+    style
+);
+
+impl Rule for PreferD {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::RegExpLiteral(lit) = node.kind() else {
+            return;
+        };
+        let Some(pattern) = lit.regex.pattern.as_pattern() else {
+            return;
+        };
+
+        let mut finder = DigitRangeFinder::default();
+        finder.visit_pattern(pattern);
+
+        for (span, kind, replacement) in finder.ranges {
+            ctx.diagnostic_with_fix(
+                prefer_d_diagnostic(span, kind, ctx.source_range(span), replacement),
+                |fixer| fixer.replace(span, replacement),
+            );
+        }
+    }
+}
+
+#[derive(Default)]
+struct DigitRangeFinder {
+    /// Spans of `0-9` ranges, or of classes consisting of nothing else, along with what they are
+    /// and what to replace them with.
+    ranges: Vec<(Span, &'static str, &'static str)>,
+}
+
+impl<'a> Visit<'a> for DigitRangeFinder {
+    fn visit_character_class(&mut self, class: &CharacterClass<'a>) {
+        if class.kind != CharacterClassContentsKind::Union {
+            walk_character_class(self, class);
+            return;
+        }
+        let digits = class.body.iter().filter_map(|contents| match contents {
+            CharacterClassContents::CharacterClassRange(range)
+                if range.min.value == '0' as u32 && range.max.value == '9' as u32 =>
+            {
+                Some(range.span)
+            }
+            _ => None,
+        });
+        if class.body.len() == 1 && digits.clone().next().is_some() {
+            let replacement = if class.negative { "\\D" } else { "\\d" };
+            self.ranges.push((class.span, "character class", replacement));
+            return;
+        }
+        self.ranges.extend(digits.map(|span| (span, "character class range", "\\d")));
+        walk_character_class(self, class);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = /\\d/",
+        "var foo = /[1-9]/",
+        "var foo = /[0-8]/",
+        "var foo = /[a-z\\d]/",
+        "var foo = /0-9/",
+        "var foo = new RegExp('[0-9]')",
+    ];
+
+    let fail = vec![
+        "var foo = /[0-9]/",
+        "var foo = /[^0-9]/",
+        "var foo = /[a-z0-9_]/",
+        "var foo = /[\\x30-\\x39]+/u",
+        "var foo = /[[0-9]--[0-5]]/v",
+    ];
+
+    let fix = vec![
+        ("var foo = /[0-9]/", "var foo = /\\d/"),
+        ("var foo = /[^0-9]/", "var foo = /\\D/"),
+        ("var foo = /[a-z0-9_]/", "var foo = /[a-z\\d_]/"),
+        ("var foo = /[\\x30-\\x39]+/u", "var foo = /\\d+/u"),
+        ("var foo = /[[0-9]--[0-5]]/v", "var foo = /[\\d--[0-5]]/v"),
+    ];
+
+    Tester::new(PreferD::NAME, pass, fail)
+        .expect_fix(fix)
+        .with_regexp_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,212 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{
+        CharacterClass, CharacterClassContents, CharacterClassContentsKind,
+        CharacterClassEscapeKind,
+    },
+    visit::{walk::walk_character_class, Visit},
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_w_diagnostic(span: Span, text: &str, replacement: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected character class `{text}`"))
+        .with_help(format!("Use `{replacement}` instead"))
+        .with_label(span)
+}
+
+fn prefer_w_in_class_diagnostic(span: Span, text: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected character class `{text}`"))
+        .with_help("Replace `a-z`, `A-Z`, `0-9` and `_` with `\\w`")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferW;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using `\w` instead of spelling out the word characters in regular expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `\w` matches exactly the characters `a-z`, `A-Z`, `0-9` and `_`, and is
+    /// shorter and easier to recognize than a character class listing all of them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var foo = /[a-zA-Z0-9_]/;
+    /// var foo = /[^a-zA-Z\d_]/;
+    /// var foo = /[a-zA-Z0-9_$]/;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var foo = /\w/;
+    /// var foo = /\W/;
+    /// var foo = /[\w$]/;
+    /// ```
+    PreferW,
+    style,
+    fix,
+    style
+);
+
+impl Rule for PreferW {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::RegExpLiteral(lit) = node.kind() else {
+            return;
+        };
+        let Some(pattern) = lit.regex.pattern.as_pattern() else {
+            return;
+        };
+
+        let mut finder = WordClassFinder { source_text: ctx.source_text(), classes: vec![] };
+        finder.visit_pattern(pattern);
+
+        for (span, replacement) in finder.classes {
+            let text = ctx.source_range(span);
+            match replacement {
+                Replacement::Class(replacement) => ctx
+                    .diagnostic_with_fix(prefer_w_diagnostic(span, text, replacement), |fixer| {
+                        fixer.replace(span, replacement)
+                    }),
+                Replacement::Contents(Some(replacement)) => ctx
+                    .diagnostic_with_fix(prefer_w_in_class_diagnostic(span, text), |fixer| {
+                        fixer.replace(span, replacement)
+                    }),
+                Replacement::Contents(None) => {
+                    ctx.diagnostic(prefer_w_in_class_diagnostic(span, text));
+                }
+            }
+        }
+    }
+}
+
+enum Replacement {
+    /// The whole class is replaced by `\w` or `\W`.
+    Class(&'static str),
+    /// The word characters in the class are replaced by `\w`. There is no replacement when the
+    /// class also contains a `-`, which could form a new range once the class is rearranged.
+    Contents(Option<String>),
+}
+
+struct WordClassFinder<'s> {
+    source_text: &'s str,
+    /// Spans of the classes that include all word characters, along with their replacement.
+    classes: Vec<(Span, Replacement)>,
+}
+
+impl<'s> WordClassFinder<'s> {
+    /// Returns the index of the word character component that `contents` is, if any: `a-z`,
+    /// `A-Z`, `0-9` (or `\d`) and `_`, in that order.
+    fn word_component(contents: &CharacterClassContents) -> Option<usize> {
+        match contents {
+            CharacterClassContents::CharacterClassRange(range) => {
+                match (char::from_u32(range.min.value)?, char::from_u32(range.max.value)?) {
+                    ('a', 'z') => Some(0),
+                    ('A', 'Z') => Some(1),
+                    ('0', '9') => Some(2),
+                    _ => None,
+                }
+            }
+            CharacterClassContents::CharacterClassEscape(escape)
+                if escape.kind == CharacterClassEscapeKind::D =>
+            {
+                Some(2)
+            }
+            CharacterClassContents::Character(c) if c.value == '_' as u32 => Some(3),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, 's> Visit<'a> for WordClassFinder<'s> {
+    fn visit_character_class(&mut self, class: &CharacterClass<'a>) {
+        if class.kind != CharacterClassContentsKind::Union {
+            walk_character_class(self, class);
+            return;
+        }
+
+        let mut found = [false; 4];
+        for contents in &class.body {
+            if let Some(index) = Self::word_component(contents) {
+                found[index] = true;
+            }
+        }
+        if !found.iter().all(|&found| found) {
+            walk_character_class(self, class);
+            return;
+        }
+
+        let mut components = 0;
+        let mut has_dash = false;
+        let mut rest = String::new();
+        for contents in &class.body {
+            if Self::word_component(contents).is_some() {
+                if components == 0 {
+                    rest.push_str("\\w");
+                }
+                components += 1;
+            } else {
+                if let CharacterClassContents::Character(c) = contents {
+                    has_dash |= c.value == '-' as u32;
+                }
+                rest.push_str(contents.span().source_text(self.source_text));
+            }
+        }
+
+        let replacement = if components == class.body.len() {
+            Replacement::Class(if class.negative { "\\W" } else { "\\w" })
+        } else if has_dash {
+            Replacement::Contents(None)
+        } else {
+            let negate = if class.negative { "^" } else { "" };
+            Replacement::Contents(Some(format!("[{negate}{rest}]")))
+        };
+        self.classes.push((class.span, replacement));
+        walk_character_class(self, class);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = /\\w/",
+        "var foo = /[\\w$]/",
+        "var foo = /[a-zA-Z0-9]/",
+        "var foo = /[a-z0-9_]/",
+        "var foo = /[a-fA-F0-9_]/",
+        "var foo = new RegExp('[a-zA-Z0-9_]')",
+    ];
+
+    let fail = vec![
+        "var foo = /[a-zA-Z0-9_]/",
+        "var foo = /[^a-zA-Z\\d_]/",
+        "var foo = /[_0-9A-Za-z]+/",
+        "var foo = /[a-zA-Z0-9_$]/",
+        "var foo = /[a-z-A-Z0-9_]/",
+        "var foo = /[[a-zA-Z0-9_]--x]/v",
+    ];
+
+    let fix = vec![
+        ("var foo = /[a-zA-Z0-9_]/", "var foo = /\\w/"),
+        ("var foo = /[^a-zA-Z\\d_]/", "var foo = /\\W/"),
+        ("var foo = /[_0-9A-Za-z]+/", "var foo = /\\w+/"),
+        ("var foo = /[a-zA-Z0-9_$]/", "var foo = /[\\w$]/"),
+        ("var foo = /[[a-zA-Z0-9_]--x]/v", "var foo = /[\\w--x]/v"),
+    ];
+
+    Tester::new(PreferW::NAME, pass, fail)
+        .expect_fix(fix)
+        .with_regexp_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,93 @@
+use oxc_ast::{ast::Argument, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::get_node_builtin_module_call, AstNode};
+
+fn detect_child_process_diagnostic(method: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`child_process.{method}` called with a non-literal command"))
+        .with_help("Use `execFile` or `spawn` and pass untrusted input as separate arguments, which are not interpreted by a shell.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectChildProcess;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows calling `exec` and `execSync` of the `child_process` module with a command which
+    /// is not a string literal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `exec` runs its command in a shell. When the command is built from user input, an
+    /// attacker can run any command with e.g. `; rm -rf ~`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import { exec } from "child_process";
+    /// exec(`git log ${branch}`);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { exec, execFile } from "child_process";
+    /// exec("git log");
+    /// execFile("git", ["log", branch]);
+    /// ```
+    DetectChildProcess,
+    correctness
+);
+
+impl Rule for DetectChildProcess {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Some(("child_process", method)) = get_node_builtin_module_call(call_expr, ctx) else {
+            return;
+        };
+        if !matches!(method.as_ref(), "exec" | "execSync") {
+            return;
+        }
+        let Some(command) = call_expr.arguments.first() else {
+            return;
+        };
+        match command {
+            Argument::StringLiteral(_) => {}
+            Argument::TemplateLiteral(lit) if lit.is_no_substitution_template() => {}
+            _ => ctx.diagnostic(detect_child_process_diagnostic(&method, command.span())),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import { exec } from "child_process"; exec("ls -la");"#,
+        r#"import cp from "node:child_process"; cp.execSync(`npm install`);"#,
+        r#"import { execFile } from "child_process"; execFile("git", ["log", branch]);"#,
+        r#"const { spawn } = require("child_process"); spawn(command, args);"#,
+        r#"const cp = require("child_process"); cp.fork(script);"#,
+        "exec(command);",
+        r#"import { exec } from "./utils"; exec(command);"#,
+        "regex.exec(input);",
+    ];
+
+    let fail = vec![
+        r#"import { exec } from "child_process"; exec(command);"#,
+        r#"import * as cp from "node:child_process"; cp.exec(`git log ${branch}`);"#,
+        r#"import { execSync as run } from "child_process"; run("rm -rf " + dir);"#,
+        r#"const cp = require("child_process"); cp.execSync(cmd, { stdio: "inherit" });"#,
+        r#"const { exec } = require("child_process"); exec(req.query.cmd, callback);"#,
+        r#"require("child_process").exec(command);"#,
+    ];
+
+    Tester::new(DetectChildProcess::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,90 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn detect_eval_with_expression_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`eval` called with a non-literal argument")
+        .with_help("Do not evaluate code built at runtime, as it may contain untrusted input.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectEvalWithExpression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows calling `eval` with an argument which is not a string literal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Code built at runtime may contain input controlled by an attacker, e.g. a query parameter,
+    /// and `eval` runs it with all the privileges of your code.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// eval(userInput);
+    /// eval(`process(${data})`);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// eval("1 + 1");
+    /// JSON.parse(userInput);
+    /// ```
+    DetectEvalWithExpression,
+    correctness
+);
+
+impl Rule for DetectEvalWithExpression {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Expression::Identifier(callee) = call_expr.callee.get_inner_expression() else {
+            return;
+        };
+        if callee.name != "eval" || !ctx.semantic().is_reference_to_global_variable(callee) {
+            return;
+        }
+        let Some(argument) = call_expr.arguments.first() else {
+            return;
+        };
+        match argument {
+            Argument::StringLiteral(_) => {}
+            Argument::TemplateLiteral(lit) if lit.is_no_substitution_template() => {}
+            _ => ctx.diagnostic(detect_eval_with_expression_diagnostic(argument.span())),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"eval("1 + 1")"#,
+        "eval(`alert(1)`)",
+        "eval()",
+        "function f(eval) { eval(code) }",
+        "obj.eval(code)",
+        "evaluate(code)",
+    ];
+
+    let fail = vec![
+        "eval(code)",
+        "eval(`process(${data})`)",
+        r#"eval("var x = " + value)"#,
+        "(eval)(getCode())",
+    ];
+
+    Tester::new(DetectEvalWithExpression::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,176 @@
+use oxc_ast::{ast::Argument, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use phf::{phf_map, Map};
+
+use crate::{context::LintContext, rule::Rule, utils::get_node_builtin_module_call, AstNode};
+
+fn detect_non_literal_fs_filename_diagnostic(method: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`fs.{method}` called with a non-literal path"))
+        .with_help("Make sure the path cannot be controlled by an attacker, e.g. to read `../../etc/passwd`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectNonLiteralFsFilename;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows calling the functions of the `fs` and `fs/promises` modules with paths which are
+    /// not string literals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A path built at runtime from user input can escape the directory it is meant to stay in,
+    /// e.g. with `../`, letting an attacker read or overwrite any file the process has access to.
+    ///
+    /// This rule is noisy in code which legitimately works with dynamic paths, so review each
+    /// report rather than treating it as a bug.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import fs from "fs";
+    /// fs.readFile(req.query.file, callback);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from "fs";
+    /// fs.readFile("config.json", callback);
+    /// ```
+    DetectNonLiteralFsFilename,
+    restriction
+);
+
+/// The indexes of the path arguments of the functions of `fs` and `fs/promises`.
+const PATH_ARGUMENTS: Map<&'static str, &'static [usize]> = phf_map! {
+    "access" => &[0],
+    "accessSync" => &[0],
+    "appendFile" => &[0],
+    "appendFileSync" => &[0],
+    "chmod" => &[0],
+    "chmodSync" => &[0],
+    "chown" => &[0],
+    "chownSync" => &[0],
+    "copyFile" => &[0, 1],
+    "copyFileSync" => &[0, 1],
+    "cp" => &[0, 1],
+    "cpSync" => &[0, 1],
+    "createReadStream" => &[0],
+    "createWriteStream" => &[0],
+    "exists" => &[0],
+    "existsSync" => &[0],
+    "lchmod" => &[0],
+    "lchmodSync" => &[0],
+    "lchown" => &[0],
+    "lchownSync" => &[0],
+    "link" => &[0, 1],
+    "linkSync" => &[0, 1],
+    "lstat" => &[0],
+    "lstatSync" => &[0],
+    "lutimes" => &[0],
+    "lutimesSync" => &[0],
+    "mkdir" => &[0],
+    "mkdirSync" => &[0],
+    "mkdtemp" => &[0],
+    "mkdtempSync" => &[0],
+    "open" => &[0],
+    "openSync" => &[0],
+    "opendir" => &[0],
+    "opendirSync" => &[0],
+    "readdir" => &[0],
+    "readdirSync" => &[0],
+    "readFile" => &[0],
+    "readFileSync" => &[0],
+    "readlink" => &[0],
+    "readlinkSync" => &[0],
+    "realpath" => &[0],
+    "realpathSync" => &[0],
+    "rename" => &[0, 1],
+    "renameSync" => &[0, 1],
+    "rm" => &[0],
+    "rmSync" => &[0],
+    "rmdir" => &[0],
+    "rmdirSync" => &[0],
+    "stat" => &[0],
+    "statSync" => &[0],
+    "statfs" => &[0],
+    "statfsSync" => &[0],
+    "symlink" => &[0, 1],
+    "symlinkSync" => &[0, 1],
+    "truncate" => &[0],
+    "truncateSync" => &[0],
+    "unlink" => &[0],
+    "unlinkSync" => &[0],
+    "unwatchFile" => &[0],
+    "utimes" => &[0],
+    "utimesSync" => &[0],
+    "watch" => &[0],
+    "watchFile" => &[0],
+    "writeFile" => &[0],
+    "writeFileSync" => &[0],
+};
+
+impl Rule for DetectNonLiteralFsFilename {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Some((module, method)) = get_node_builtin_module_call(call_expr, ctx) else {
+            return;
+        };
+        if !matches!(module, "fs" | "fs/promises") {
+            return;
+        }
+        let Some(indexes) = PATH_ARGUMENTS.get(method.as_ref()) else {
+            return;
+        };
+        for argument in indexes.iter().filter_map(|&index| call_expr.arguments.get(index)) {
+            let is_literal = match argument {
+                Argument::StringLiteral(_) => true,
+                Argument::TemplateLiteral(lit) => lit.is_no_substitution_template(),
+                Argument::SpreadElement(_) => false,
+                // File descriptors, e.g. `fs.readFile(0)` for stdin.
+                argument => argument.to_expression().is_literal(),
+            };
+            if !is_literal {
+                ctx.diagnostic(detect_non_literal_fs_filename_diagnostic(&method, argument.span()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import fs from "fs"; fs.readFile("config.json", callback);"#,
+        r#"import { readFileSync } from "node:fs"; readFileSync(`config.json`);"#,
+        r#"import fs from "fs/promises"; fs.rename("a.txt", "b.txt");"#,
+        r#"const fs = require("fs"); fs.readFileSync(0);"#,
+        r#"const fs = require("fs"); fs.writeFile("out.txt", data);"#,
+        r#"import fs from "fs-extra"; fs.readFile(file);"#,
+        r#"import path from "path"; path.join(dir, file);"#,
+        "fs.readFile(file);",
+        "readFile(file);",
+        r#"import fs from "fs"; function f(fs) { fs.readFile(file) }"#,
+    ];
+
+    let fail = vec![
+        r#"import fs from "fs"; fs.readFile(file, callback);"#,
+        r#"import * as fs from "node:fs"; fs.createReadStream(`${dir}/data.csv`);"#,
+        r#"import { readFile } from "fs/promises"; await readFile(path);"#,
+        r#"import { unlink as remove } from "fs"; remove(file);"#,
+        r#"const fs = require("fs"); fs.existsSync(dir + "/index.js");"#,
+        r#"const { writeFileSync } = require("fs"); writeFileSync(output, data);"#,
+        r#"require("fs").rmSync(dir, { recursive: true });"#,
+        r#"import fs from "fs"; fs.rename(from, to);"#,
+    ];
+
+    Tester::new(DetectNonLiteralFsFilename::NAME, pass, fail).test_and_snapshot();
+}
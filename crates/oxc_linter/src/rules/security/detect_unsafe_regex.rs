@@ -0,0 +1,158 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Argument, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{Pattern, Quantifier},
+    visit::{walk, Visit},
+    ConstructorParser, Options,
+};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn detect_unsafe_regex_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unsafe regular expression with nested unbounded quantifiers")
+        .with_help("Matching it against some inputs takes exponential time. Remove the nesting, or bound the repetitions.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectUnsafeRegex;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows regular expressions with an unbounded quantifier, e.g. `+` or `*`, inside
+    /// another unbounded quantifier.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Such regular expressions can match the same input in exponentially many ways. When a match
+    /// fails, the regular expression engine backtracks through all of them, so a short input
+    /// controlled by an attacker can block the event loop (ReDoS).
+    ///
+    /// Only regular expression literals and `RegExp` calls with string literals are checked.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /(a+)+$/;
+    /// new RegExp("^(\\w+\\s?)*$");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /a+$/;
+    /// /^(\w+\s?){1,10}$/;
+    /// ```
+    DetectUnsafeRegex,
+    correctness
+);
+
+impl Rule for DetectUnsafeRegex {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::RegExpLiteral(lit) => {
+                let Some(pattern) = lit.regex.pattern.as_pattern() else {
+                    return;
+                };
+                check_pattern(ctx, pattern, lit.span);
+            }
+            // `new RegExp("...")` and `RegExp("...")`
+            AstKind::NewExpression(expr) if expr.callee.is_specific_id("RegExp") => {
+                check_regexp_arguments(ctx, &expr.arguments);
+            }
+            AstKind::CallExpression(expr) if expr.callee.is_specific_id("RegExp") => {
+                check_regexp_arguments(ctx, &expr.arguments);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_regexp_arguments(ctx: &LintContext, arguments: &[Argument]) {
+    let Some(Argument::StringLiteral(pattern)) = arguments.first() else {
+        return;
+    };
+    let flags = match arguments.get(1) {
+        Some(Argument::StringLiteral(flags)) => Some(flags.span),
+        _ => None,
+    };
+
+    let allocator = Allocator::default();
+    let parser = ConstructorParser::new(
+        &allocator,
+        pattern.span.source_text(ctx.source_text()),
+        flags.map(|span| span.source_text(ctx.source_text())),
+        Options {
+            pattern_span_offset: pattern.span.start,
+            flags_span_offset: flags.map_or(0, |span| span.start),
+        },
+    );
+    let Ok(parsed) = parser.parse() else {
+        return;
+    };
+    check_pattern(ctx, &parsed, pattern.span);
+}
+
+fn check_pattern(ctx: &LintContext, pattern: &Pattern, span: Span) {
+    let mut finder = NestedQuantifierFinder::default();
+    finder.visit_pattern(pattern);
+    if finder.found {
+        ctx.diagnostic(detect_unsafe_regex_diagnostic(span));
+    }
+}
+
+/// Finds an unbounded quantifier inside another one, i.e. a star height greater than 1.
+#[derive(Default)]
+struct NestedQuantifierFinder {
+    /// The number of unbounded quantifiers around the visited term.
+    depth: u32,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for NestedQuantifierFinder {
+    fn visit_quantifier(&mut self, it: &Quantifier<'a>) {
+        let is_unbounded = it.max.is_none();
+        if is_unbounded {
+            self.found |= self.depth > 0;
+            self.depth += 1;
+        }
+        walk::walk_quantifier(self, it);
+        if is_unbounded {
+            self.depth -= 1;
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/a+$/",
+        "/(a|b)*c/",
+        r"/^(\w+\s?){1,10}$/",
+        "/(a{1,3}){1,5}/",
+        "/(a+)?b/",
+        r"/[a-z]+\d*/",
+        r#"new RegExp("^[a-z]+$")"#,
+        "new RegExp(pattern)",
+        "new RegExp(`(a+)+`)",
+        "RegExp('(a+', 'u')",
+    ];
+
+    let fail = vec![
+        "/(a+)+$/",
+        r"/^(\w+\s?)*$/",
+        "/(?:a*b*)*c/",
+        "/((ab)*c)+/u",
+        r"/(x+x+)+y/",
+        r#"new RegExp("(a*)*b")"#,
+        r#"RegExp("^(\\d+)+$", "u")"#,
+    ];
+
+    Tester::new(DetectUnsafeRegex::NAME, pass, fail).test_and_snapshot();
+}
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// ```
     ArrayType,
     style,
-    fix
+    fix,
+    style
 );
 
 fn generic(readonly_prefix: &str, name: &str, type_name: &str, span: Span) -> OxcDiagnostic {
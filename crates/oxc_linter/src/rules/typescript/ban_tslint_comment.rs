@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// ```
     BanTslintComment,
     style,
-    fix
+    fix,
+    formatting
 );
 
 impl Rule for BanTslintComment {
@@ -57,7 +57,8 @@ declare_oxc_lint!(
     /// ```
     ConsistentTypeDefinitions,
     style,
-    fix
+    fix,
+    style
 );
 
 impl Rule for ConsistentTypeDefinitions {
@@ -63,7 +63,8 @@ declare_oxc_lint!(
     /// ```
     NoImportTypeSideEffects,
     restriction,
-    fix
+    fix,
+    import_hygiene
 );
 
 impl Rule for NoImportTypeSideEffects {
@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// ```
     NoUselessEmptyExport,
     correctness,
-    fix
+    fix,
+    import_hygiene
 );
 
 impl Rule for NoUselessEmptyExport {
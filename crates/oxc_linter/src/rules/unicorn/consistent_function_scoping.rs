@@ -1,4 +1,5 @@
-use oxc_ast::{AstKind, Visit};
+use oxc_ast::AstKind;
+use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ReferenceId;
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// ```
     EmptyBraceSpaces,
     style,
-    fix
+    fix,
+    formatting
 );
 
 impl Rule for EmptyBraceSpaces {
@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// ```
     EscapeCase,
     pedantic,
-    fix
+    fix,
+    formatting
 );
 
 fn is_hex_char(c: char) -> bool {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// ```
     NoHexEscape,
     pedantic,
-    fix
+    fix,
+    formatting
 );
 
 // \x -> \u00
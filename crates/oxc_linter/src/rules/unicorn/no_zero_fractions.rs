@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// ```
     NoZeroFractions,
     style,
-    fix
+    fix,
+    formatting
 );
 
 impl Rule for NoZeroFractions {
@@ -74,7 +74,8 @@ declare_oxc_lint!(
     /// ```
     NumberLiteralCase,
     style,
-    fix
+    fix,
+    formatting
 );
 
 impl Rule for NumberLiteralCase {
@@ -79,7 +79,8 @@ declare_oxc_lint!(
     /// ```
     NumericSeparatorsStyle,
     style,
-    fix
+    fix,
+    formatting
 );
 
 impl Rule for NumericSeparatorsStyle {
@@ -1,6 +1,6 @@
 use oxc_ast::{
-    ast::{Argument, BinaryExpression, Expression},
-    AstKind,
+    ast::{BinaryExpression, Expression},
+    match_ast, AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -120,44 +120,20 @@ fn check_prefer_log<'a>(expr: &BinaryExpression<'a>, ctx: &LintContext<'a>) {
             check_multiplication(expr.span, &expr.right, &expr.left, ctx);
         }
         BinaryOperator::Division => {
-            let Expression::CallExpression(call_expr) = &expr.left else {
-                return;
-            };
-
-            if !is_method_call(call_expr, None, Some(&["log"]), Some(1), Some(1)) {
-                return;
-            };
-
-            if matches!(call_expr.arguments[0], Argument::SpreadElement(_)) {
+            if !match_ast!(&expr.left, call(member(ident("Math"), "log"), [_])) {
                 return;
             }
 
-            let Some(member_expr) = call_expr.callee.as_member_expression() else {
-                return;
-            };
-
-            if !member_expr.object().is_specific_id("Math") {
-                return;
-            };
-
-            let Some(member_expr) = expr.right.as_member_expression() else {
-                return;
-            };
-
-            if !matches!(
-                member_expr.static_property_name(),
-                Some("LN10" | "LN2" | "LOG10E" | "LOG2E")
-            ) {
-                return;
-            };
-
-            if !member_expr.object().is_specific_id("Math") {
+            let Some(property) = match_ast!(
+                &expr.right,
+                member(ident("Math"), name @ "LN10" | "LN2" | "LOG10E" | "LOG2E")
+            ) else {
                 return;
             };
 
             ctx.diagnostic(prefer_math_log_n(
                 expr.span,
-                get_math_log_replacement(member_expr.static_property_name()),
+                get_math_log_replacement(property),
                 &clean_string(expr.span.source_text(ctx.source_text())),
             ));
         }
@@ -165,10 +141,10 @@ fn check_prefer_log<'a>(expr: &BinaryExpression<'a>, ctx: &LintContext<'a>) {
     }
 }
 
-fn get_math_log_replacement(name: Option<&str>) -> &'static str {
+fn get_math_log_replacement(name: &str) -> &'static str {
     match name {
-        Some("LN2" | "LOG2E") => "log2",
-        Some("LN10" | "LOG10E") => "log10",
+        "LN2" | "LOG2E" => "log2",
+        "LN10" | "LOG10E" => "log10",
         _ => unreachable!(),
     }
 }
@@ -179,41 +155,19 @@ fn check_multiplication<'a, 'b>(
     right: &'b Expression<'a>,
     ctx: &LintContext<'a>,
 ) {
-    let Expression::CallExpression(call_expr) = left else {
-        return;
-    };
-
-    if !is_method_call(call_expr, None, Some(&["log"]), Some(1), Some(1)) {
-        return;
-    };
-
-    if matches!(call_expr.arguments[0], Argument::SpreadElement(_)) {
+    if !match_ast!(left, call(member(ident("Math"), "log"), [_])) {
         return;
     }
 
-    let Some(member_expr) = call_expr.callee.as_member_expression() else {
-        return;
-    };
-
-    if !member_expr.object().is_specific_id("Math") {
-        return;
-    };
-
-    let Some(member_expr) = right.as_member_expression() else {
-        return;
-    };
-
-    if !matches!(member_expr.static_property_name(), Some("LN10" | "LN2" | "LOG10E" | "LOG2E")) {
-        return;
-    };
-
-    if !member_expr.object().is_specific_id("Math") {
+    let Some(property) =
+        match_ast!(right, member(ident("Math"), name @ "LN10" | "LN2" | "LOG10E" | "LOG2E"))
+    else {
         return;
     };
 
     ctx.diagnostic(prefer_math_log_n(
         expr_span,
-        get_math_log_replacement(member_expr.static_property_name()),
+        get_math_log_replacement(property),
         &clean_string(expr_span.source_text(ctx.source_text())),
     ));
 }
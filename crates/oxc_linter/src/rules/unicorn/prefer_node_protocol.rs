@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// ```
     PreferNodeProtocol,
     restriction,
-    fix
+    fix,
+    import_hygiene
 );
 
 impl Rule for PreferNodeProtocol {
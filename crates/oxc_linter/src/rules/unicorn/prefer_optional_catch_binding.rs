@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// ```
     PreferOptionalCatchBinding,
     style,
-    fix
+    fix,
+    style
 );
 
 impl Rule for PreferOptionalCatchBinding {
@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// ```
     SwitchCaseBraces,
     style,
-    fix
+    fix,
+    style
 );
 
 impl Rule for SwitchCaseBraces {
@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// ```
     TextEncodingIdentifierCase,
     style,
-    fix
+    fix,
+    style
 );
 
 impl Rule for TextEncodingIdentifierCase {
@@ -0,0 +1,207 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::OxcDiagnostic;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// The problems of a file, keyed by the code of the rule which reported them and their message,
+/// with how many times they are reported.
+pub(super) type BaselineProblems = FxHashMap<(String, String), usize>;
+
+/// A problem recorded in a baseline file.
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineEntry {
+    /// Code of the rule which reported the problem, e.g. `eslint(no-debugger)`.
+    rule: String,
+    message: String,
+    /// How many times the problem is reported in the file.
+    count: usize,
+}
+
+/// Contents of a baseline file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BaselineFile {
+    /// The problems of the files, keyed by their path relative to the current working directory.
+    files: BTreeMap<String, Vec<BaselineEntry>>,
+}
+
+/// Problems which are known to exist, and are not reported, so that rules can be enabled in an
+/// existing codebase without fixing all of their problems at once.
+///
+/// Problems are matched by file, rule and message, but not by position, so that they still match
+/// after the code around them changed. A problem is only reported when a file has more of it than
+/// recorded in the baseline. Diagnostics which are not reported by a rule, e.g. syntax errors, are
+/// always reported.
+pub struct LintBaseline {
+    path: PathBuf,
+    generate: bool,
+    files: DashMap<String, BaselineProblems>,
+}
+
+impl LintBaseline {
+    /// Load the baseline file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// When the file cannot be read or is not a baseline file.
+    pub fn load<T: Into<PathBuf>>(path: T) -> Result<Self, OxcDiagnostic> {
+        let path = path.into();
+        let json = fs::read_to_string(&path).map_err(|error| {
+            OxcDiagnostic::error(format!("Failed to read the baseline file {path:?}: {error}"))
+        })?;
+        let baseline = serde_json::from_str::<BaselineFile>(&json).map_err(|error| {
+            OxcDiagnostic::error(format!("Failed to parse the baseline file {path:?}: {error}"))
+        })?;
+        let files = baseline
+            .files
+            .into_iter()
+            .map(|(file, entries)| {
+                let problems = entries
+                    .into_iter()
+                    .map(|entry| ((entry.rule, entry.message), entry.count))
+                    .collect();
+                (file, problems)
+            })
+            .collect();
+        Ok(Self { path, generate: false, files })
+    }
+
+    /// Record all problems of rules in a new baseline file at `path`, instead of reporting them.
+    pub fn generate<T: Into<PathBuf>>(path: T) -> Self {
+        Self { path: path.into(), generate: true, files: DashMap::default() }
+    }
+
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The problems recorded for the file at `path`, to pass to [`LintBaseline::suppress`].
+    ///
+    /// Empty when generating the baseline.
+    pub(super) fn problems(&self, path: &str) -> BaselineProblems {
+        if self.generate {
+            return BaselineProblems::default();
+        }
+        self.files.get(path).map(|problems| problems.clone()).unwrap_or_default()
+    }
+
+    /// Remove the diagnostics which are recorded in `problems` from `diagnostics`, and count them
+    /// off `problems`.
+    ///
+    /// When generating the baseline, all diagnostics of rules are removed and recorded in
+    /// `problems` instead.
+    pub(super) fn suppress(
+        &self,
+        problems: &mut BaselineProblems,
+        diagnostics: &mut Vec<OxcDiagnostic>,
+    ) {
+        diagnostics.retain(|diagnostic| {
+            if !diagnostic.code.is_some() {
+                return true;
+            }
+            let key = (diagnostic.code.to_string(), diagnostic.message.to_string());
+            if self.generate {
+                *problems.entry(key).or_default() += 1;
+                return false;
+            }
+            match problems.get_mut(&key) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            }
+        });
+    }
+
+    /// Record `problems`, the problems found in the file at `path`, when generating the baseline.
+    pub(super) fn finish(&self, path: String, problems: BaselineProblems) {
+        if !self.generate {
+            return;
+        }
+        if problems.is_empty() {
+            self.files.remove(&path);
+        } else {
+            self.files.insert(path, problems);
+        }
+    }
+
+    /// Write the baseline to its file, when generating the baseline.
+    pub(super) fn save(&self) -> io::Result<()> {
+        if !self.generate {
+            return Ok(());
+        }
+        let files = self
+            .files
+            .iter()
+            .map(|entry| {
+                let mut entries = entry
+                    .value()
+                    .iter()
+                    .map(|((rule, message), count)| BaselineEntry {
+                        rule: rule.clone(),
+                        message: message.clone(),
+                        count: *count,
+                    })
+                    .collect::<Vec<_>>();
+                entries.sort_unstable_by(|a, b| (&a.rule, &a.message).cmp(&(&b.rule, &b.message)));
+                (entry.key().clone(), entries)
+            })
+            .collect();
+        fs::write(&self.path, serde_json::to_string_pretty(&BaselineFile { files })?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use oxc_diagnostics::OxcDiagnostic;
+
+    use super::LintBaseline;
+
+    fn diagnostics() -> Vec<OxcDiagnostic> {
+        vec![
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger"),
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_error_code("eslint", "no-debugger"),
+            OxcDiagnostic::error("Unexpected token"),
+        ]
+    }
+
+    #[test]
+    fn test_lint_baseline() {
+        let path = std::env::temp_dir().join("oxc_linter_test_lint_baseline.json");
+
+        let baseline = LintBaseline::generate(&path);
+        let mut problems = baseline.problems("a.js");
+        let mut a = diagnostics();
+        baseline.suppress(&mut problems, &mut a);
+        // Diagnostics which are not reported by rules are never recorded.
+        assert_eq!(a.len(), 1);
+        baseline.finish("a.js".to_string(), problems);
+        baseline.save().unwrap();
+
+        let baseline = LintBaseline::load(&path).unwrap();
+        let mut problems = baseline.problems("a.js");
+        let mut a = diagnostics();
+        a.pop();
+        baseline.suppress(&mut problems, &mut a);
+        assert!(a.is_empty());
+
+        // Problems which are not recorded for a file are reported.
+        let mut problems = baseline.problems("b.js");
+        let mut b = diagnostics();
+        baseline.suppress(&mut problems, &mut b);
+        assert_eq!(b.len(), 3);
+
+        fs::remove_file(&path).unwrap();
+        assert!(LintBaseline::load(&path).is_err());
+    }
+}
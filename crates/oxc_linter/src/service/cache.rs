@@ -0,0 +1,124 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+/// Version of the linter, files linted by other versions are linted again.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Contents of a cache file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// Version of the linter which wrote the cache.
+    version: String,
+    /// Hash of the configuration the files were linted with, see [`crate::Linter::config_hash`].
+    config: String,
+    /// Content hashes of the files, keyed by their path relative to the current working
+    /// directory.
+    files: BTreeMap<String, String>,
+}
+
+/// Persistent cache of the files which were linted without any diagnostic, so that they are
+/// skipped until their contents, the configuration or the version of the linter change.
+///
+/// Files with diagnostics are not cached and linted on every run, so that their diagnostics are
+/// reported again.
+pub(super) struct LintCache {
+    path: PathBuf,
+    config: String,
+    files: DashMap<String, String>,
+}
+
+impl LintCache {
+    /// Load the cache at `path` for the configuration hashed to `config`.
+    ///
+    /// The cache is empty if the file does not exist or cannot be read, or if it was written by
+    /// another version of the linter or for another configuration.
+    pub fn load(path: PathBuf, config: String) -> Self {
+        let files = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheFile>(&json).ok())
+            .filter(|cache| cache.version == VERSION && cache.config == config)
+            .map(|cache| cache.files.into_iter().collect())
+            .unwrap_or_default();
+        Self { path, config, files }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Hash of the contents of a file.
+    pub fn hash(source_text: &str) -> String {
+        let mut hasher = Sha1::new();
+        hasher.update(source_text);
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Whether the file at `path` was linted without diagnostics when its contents hashed to
+    /// `hash`.
+    pub fn is_clean(&self, path: &str, hash: &str) -> bool {
+        self.files.get(path).is_some_and(|cached| *cached == hash)
+    }
+
+    /// Record that the file at `path`, whose contents hash to `hash`, was linted without
+    /// diagnostics if `clean`, or forget it otherwise.
+    pub fn update(&self, path: String, hash: String, clean: bool) {
+        if clean {
+            self.files.insert(path, hash);
+        } else {
+            self.files.remove(&path);
+        }
+    }
+
+    /// Write the cache to its file.
+    pub fn save(&self) -> io::Result<()> {
+        let cache = CacheFile {
+            version: VERSION.to_string(),
+            config: self.config.clone(),
+            files: self
+                .files
+                .iter()
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect(),
+        };
+        fs::write(&self.path, serde_json::to_string(&cache)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::LintCache;
+
+    #[test]
+    fn test_lint_cache() {
+        let path = std::env::temp_dir().join("oxc_linter_test_lint_cache");
+        let hash = LintCache::hash("debugger;");
+        assert_ne!(hash, LintCache::hash("debugger"));
+
+        let cache = LintCache::load(path.clone(), "config".to_string());
+        assert!(!cache.is_clean("a.js", &hash));
+        cache.update("a.js".to_string(), hash.clone(), true);
+        cache.update("b.js".to_string(), hash.clone(), true);
+        cache.update("b.js".to_string(), hash.clone(), false);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(path.clone(), "config".to_string());
+        assert!(cache.is_clean("a.js", &hash));
+        assert!(!cache.is_clean("a.js", &LintCache::hash("")));
+        assert!(!cache.is_clean("b.js", &hash));
+
+        // The cache of another configuration is discarded.
+        let cache = LintCache::load(path.clone(), "other config".to_string());
+        assert!(!cache.is_clean("a.js", &hash));
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -1,16 +1,22 @@
+mod baseline;
+mod cache;
 mod module_cache;
+mod module_resolver;
+mod resolver_cache;
 mod runtime;
 
 use std::{
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{mpsc, Arc},
 };
 
-use oxc_diagnostics::DiagnosticSender;
+use oxc_diagnostics::{DiagnosticSender, OxcDiagnostic};
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
 
-use crate::Linter;
+use crate::{utils::read_to_string, Linter};
 
+pub use baseline::LintBaseline;
+pub use module_resolver::ModuleResolver;
 use runtime::Runtime;
 
 pub struct LintServiceOptions {
@@ -24,6 +30,16 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Path of the cache file of the resolver, see [`LintServiceOptions::with_resolver_cache`].
+    resolver_cache: Option<PathBuf>,
+
+    /// Path of the cache file, files are not cached if `None`.
+    /// See [`LintServiceOptions::with_cache`].
+    cache: Option<PathBuf>,
+
+    /// See [`LintServiceOptions::with_baseline`].
+    baseline: Option<LintBaseline>,
 }
 
 impl LintServiceOptions {
@@ -32,7 +48,15 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            paths,
+            tsconfig: None,
+            cross_module: false,
+            resolver_cache: None,
+            cache: None,
+            baseline: None,
+        }
     }
 
     #[inline]
@@ -57,12 +81,88 @@ impl LintServiceOptions {
         self
     }
 
+    /// Keep the `package.json` and `tsconfig.json` files read when resolving imports across
+    /// modules in the file at `cache`, and use them in later runs instead of reading them again
+    /// while their modification time and size are unchanged.
+    #[inline]
+    #[must_use]
+    pub fn with_resolver_cache<T>(mut self, cache: T) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        let cache = cache.into();
+        let cache = if cache.is_relative() { self.cwd.join(cache) } else { cache };
+        self.resolver_cache = Some(cache);
+        self
+    }
+
+    /// Cache the files which are linted without any diagnostic in the file at `cache`, and skip
+    /// them in later runs until their contents, the configuration of the linter or its version
+    /// change.
+    ///
+    /// Files are not cached when linting across modules, as their diagnostics depend on the
+    /// modules they import.
+    #[inline]
+    #[must_use]
+    pub fn with_cache<T>(mut self, cache: T) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        let cache = cache.into();
+        let cache = if cache.is_relative() { self.cwd.join(cache) } else { cache };
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Do not report the problems recorded in `baseline`, or record the problems in it if it is
+    /// generated, see [`LintBaseline`].
+    #[inline]
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: LintBaseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
     }
 }
 
+/// The result of linting a file with [`LintService::lint_source_text`] or
+/// [`LintService::lint_file`].
+#[derive(Debug, Default)]
+pub struct LintResult {
+    /// The diagnostics of the file, whose spans point into the fixed source text if anything was
+    /// fixed, or into the linted source text otherwise.
+    ///
+    /// Fixes which are not applied are attached to their diagnostics if
+    /// [`LinterBuilder::with_report_fixes`](crate::LinterBuilder::with_report_fixes) is enabled.
+    pub diagnostics: Vec<OxcDiagnostic>,
+    /// The fixed source text, if fixing is enabled and anything was fixed.
+    pub fixed_source_text: Option<String>,
+}
+
+/// Lints files with a [`Linter`], in parallel.
+///
+/// Files can be linted by paths, sending their diagnostics to a [`DiagnosticSender`] like
+/// `oxlint` does, or one by one, returning their diagnostics.
+///
+/// # Example
+///
+/// ```
+/// use std::path::Path;
+///
+/// use oxc_linter::{LintService, LintServiceOptions, LinterBuilder, Oxlintrc};
+///
+/// let config = Oxlintrc::from_json(r#"{ "rules": { "no-debugger": "error" } }"#).unwrap();
+/// let linter = LinterBuilder::from_oxlintrc(true, config).build();
+/// let service = LintService::new(linter, LintServiceOptions::new(Path::new("."), vec![]));
+///
+/// let result = service.lint_source_text(Path::new("a.js"), "debugger;");
+/// assert_eq!(result.diagnostics.len(), 1);
+/// assert_eq!(result.diagnostics[0].labels.as_ref().unwrap()[0].offset(), 0);
+/// ```
 #[derive(Clone)]
 pub struct LintService {
     runtime: Arc<Runtime>,
@@ -84,6 +184,12 @@ impl LintService {
         &self.runtime.linter
     }
 
+    /// The resolver of the imports when linting across modules, e.g. to build the module graph
+    /// without linting.
+    pub fn resolver(&self) -> Option<&Arc<ModuleResolver>> {
+        self.runtime.resolver.as_ref()
+    }
+
     pub fn number_of_dependencies(&self) -> usize {
         self.runtime.number_of_dependencies()
     }
@@ -91,12 +197,76 @@ impl LintService {
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
         self.runtime
-            .iter_paths()
+            .paths()
+            .into_iter()
+            .par_bridge()
+            .for_each_with(&self.runtime, |runtime, path| runtime.process_path(&path, tx_error));
+        self.runtime.save_resolver_cache(tx_error);
+        self.runtime.save_cache(tx_error);
+        self.runtime.save_baseline(tx_error);
+        tx_error.send(None).unwrap();
+    }
+
+    /// Lint `paths` again, e.g. because they changed, instead of all paths of this service.
+    /// Paths which are not linted by this service yet are added to it.
+    ///
+    /// When linting across modules, the files which import `paths` are not linted again.
+    ///
+    /// # Panics
+    pub fn run_paths(&self, paths: &[Box<Path>], tx_error: &DiagnosticSender) {
+        self.runtime.invalidate_paths(paths);
+        paths
+            .iter()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        self.runtime.save_resolver_cache(tx_error);
+        self.runtime.save_cache(tx_error);
+        self.runtime.save_baseline(tx_error);
         tx_error.send(None).unwrap();
     }
 
+    /// Lint `source_text` as the contents of `path` instead of the paths of this service, e.g. for
+    /// source text read from stdin. `path` does not need to exist, and is not written to when
+    /// fixing.
+    ///
+    /// Returns the fixed source text if fixing is enabled and anything was fixed.
+    ///
+    /// # Panics
+    pub fn run_source_text(
+        &self,
+        path: &Path,
+        source_text: &str,
+        tx_error: &DiagnosticSender,
+    ) -> Option<String> {
+        let result = self.runtime.process_text(path, source_text, tx_error);
+        let new_source_text = result.fixed_source_text.as_deref().unwrap_or(source_text);
+        self.runtime.send_diagnostics(path, new_source_text, result.diagnostics, tx_error);
+        tx_error.send(None).unwrap();
+        result.fixed_source_text
+    }
+
+    /// Lint `source_text` as the contents of `path`, and return its diagnostics instead of
+    /// sending them. `path` does not need to exist, and is not written to when fixing.
+    pub fn lint_source_text(&self, path: &Path, source_text: &str) -> LintResult {
+        // Only the errors of dependencies which fail to be read are sent when linting across
+        // modules, which are not reported for a single file.
+        let (tx_error, _rx_error) = mpsc::channel();
+        self.runtime.process_text(path, source_text, &tx_error)
+    }
+
+    /// Lint the file at `path` like [`LintService::lint_source_text`]. The file is not written
+    /// to when fixing.
+    ///
+    /// # Errors
+    ///
+    /// When the file cannot be read.
+    pub fn lint_file(&self, path: &Path) -> Result<LintResult, OxcDiagnostic> {
+        let source_text = read_to_string(path).map_err(|e| {
+            OxcDiagnostic::error(format!("Failed to open file {path:?} with error \"{e}\""))
+        })?;
+        Ok(self.lint_source_text(path, &source_text))
+    }
+
     /// For tests
     #[cfg(test)]
     pub(crate) fn run_source<'a>(
@@ -107,12 +277,13 @@ impl LintService {
         tx_error: &DiagnosticSender,
     ) -> Vec<crate::Message<'a>> {
         self.runtime
-            .iter_paths()
+            .paths()
+            .into_iter()
             .flat_map(|path| {
-                let source_type = oxc_span::SourceType::from_path(path).unwrap();
-                self.runtime.init_cache_state(path);
+                let source_type = oxc_span::SourceType::from_path(&path).unwrap();
+                self.runtime.init_cache_state(&path);
                 self.runtime.process_source(
-                    path,
+                    &path,
                     allocator,
                     source_text,
                     source_type,
@@ -97,6 +97,11 @@ impl ModuleCache {
         self.update_cache_state(path);
     }
 
+    /// Forget the module at `path`, so that it is processed again.
+    pub(super) fn remove(&self, path: &Path) {
+        self.modules.remove(path);
+    }
+
     /// # Panics
     /// If a cache entry for `path` does not exist. You must call `init_cache_state` first.
     fn update_cache_state(&self, path: &Path) {
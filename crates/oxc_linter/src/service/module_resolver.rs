@@ -0,0 +1,162 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_resolver::{ResolveOptions, ResolverGeneric, TsconfigOptions, TsconfigReferences};
+use oxc_span::VALID_EXTENSIONS;
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use rustc_hash::FxHashSet;
+
+use super::resolver_cache::CachedFileSystem;
+
+/// Resolves the imports of the modules linted across modules, see [`LintService::resolver`].
+///
+/// [`LintService::resolver`]: super::LintService::resolver
+pub struct ModuleResolver {
+    resolver: ResolverGeneric<CachedFileSystem>,
+}
+
+impl ModuleResolver {
+    /// `tsconfig` is the `tsconfig.json` used for path aliases, it is ignored if it does not exist.
+    pub(super) fn new(file_system: CachedFileSystem, tsconfig: Option<PathBuf>) -> Self {
+        let tsconfig = tsconfig.and_then(|path| {
+            if path.is_file() {
+                Some(TsconfigOptions { config_file: path, references: TsconfigReferences::Auto })
+            } else {
+                None
+            }
+        });
+
+        let resolver = ResolverGeneric::new_with_file_system(
+            file_system,
+            ResolveOptions {
+                extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+                condition_names: vec!["module".into(), "require".into()],
+                tsconfig,
+                ..ResolveOptions::default()
+            },
+        );
+        Self { resolver }
+    }
+
+    /// Resolve `specifier` imported from a module in `dir`, or `None` if it cannot be resolved.
+    pub fn resolve(&self, dir: &Path, specifier: &str) -> Option<PathBuf> {
+        self.resolver.resolve(dir, specifier).ok().map(|resolution| resolution.into_path_buf())
+    }
+
+    /// Resolve all `specifiers` imported from a module in `dir`, in parallel. Returns the
+    /// specifiers with their resolved paths, without duplicates.
+    ///
+    /// The specifiers of the same package share the `package.json` files read by the resolver, so
+    /// one specifier of each package is resolved first, and the others once these files are
+    /// cached, instead of reading them for each specifier when the cache is cold.
+    pub fn resolve_many<'s>(
+        &self,
+        dir: &Path,
+        specifiers: impl IntoIterator<Item = &'s str>,
+    ) -> Vec<(&'s str, Option<PathBuf>)> {
+        let mut seen = FxHashSet::default();
+        let specifiers =
+            specifiers.into_iter().filter(|specifier| seen.insert(*specifier)).collect::<Vec<_>>();
+
+        let mut packages = FxHashSet::default();
+        let (first, rest): (Vec<_>, Vec<_>) = specifiers
+            .iter()
+            .copied()
+            .enumerate()
+            .partition(|&(_, specifier)| packages.insert(package_name(specifier)));
+
+        let mut resolutions = vec![None; specifiers.len()];
+        for batch in [first, rest] {
+            let resolved = batch
+                .into_par_iter()
+                .map(|(i, specifier)| (i, self.resolve(dir, specifier)))
+                .collect::<Vec<_>>();
+            for (i, path) in resolved {
+                resolutions[i] = path;
+            }
+        }
+        specifiers.into_iter().zip(resolutions).collect()
+    }
+
+    /// Resolve `specifier` imported from a module in `dir` on the blocking thread pool of the
+    /// tokio runtime, instead of blocking the worker thread of the calling task on the file system.
+    ///
+    /// # Panics
+    ///
+    /// If called outside of a tokio runtime.
+    pub async fn resolve_async(
+        self: Arc<Self>,
+        dir: PathBuf,
+        specifier: String,
+    ) -> Option<PathBuf> {
+        tokio::task::spawn_blocking(move || self.resolve(&dir, &specifier)).await.ok().flatten()
+    }
+}
+
+/// The package imported by a bare specifier, e.g. `@scope/name` of `@scope/name/file.js`. Relative
+/// and absolute specifiers are `None`, they share the `package.json` of the importer.
+fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+        return None;
+    }
+    let len = if specifier.starts_with('@') {
+        specifier.match_indices('/').nth(1).map(|(i, _)| i)
+    } else {
+        specifier.find('/')
+    };
+    Some(len.map_or(specifier, |len| &specifier[..len]))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::PathBuf, sync::Arc};
+
+    use super::{package_name, ModuleResolver};
+    use crate::service::resolver_cache::CachedFileSystem;
+
+    fn fixtures() -> PathBuf {
+        project_root::get_project_root().unwrap().join("crates/oxc_linter/fixtures/import")
+    }
+
+    fn resolver() -> ModuleResolver {
+        ModuleResolver::new(CachedFileSystem::new(None), None)
+    }
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(package_name("./foo"), None);
+        assert_eq!(package_name("../a"), None);
+        assert_eq!(package_name("/a"), None);
+        assert_eq!(package_name("react"), Some("react"));
+        assert_eq!(package_name("lodash/get"), Some("lodash"));
+        assert_eq!(package_name("@scope/name"), Some("@scope/name"));
+        assert_eq!(package_name("@scope/name/file.js"), Some("@scope/name"));
+    }
+
+    #[test]
+    fn test_resolve_many() {
+        let resolver = resolver();
+        let dir = fixtures();
+        let resolutions = resolver.resolve_many(&dir, ["./foo", "./missing", "./foo", "./bar"]);
+        let specifiers = resolutions.iter().map(|(specifier, _)| *specifier).collect::<Vec<_>>();
+        assert_eq!(specifiers, ["./foo", "./missing", "./bar"]);
+        for (specifier, path) in resolutions {
+            assert_eq!(path, resolver.resolve(&dir, specifier));
+        }
+    }
+
+    #[test]
+    fn test_resolve_async() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let resolver = Arc::new(resolver());
+        let dir = fixtures();
+        let path =
+            runtime.block_on(Arc::clone(&resolver).resolve_async(dir.clone(), "./foo".into()));
+        assert_eq!(path, resolver.resolve(&dir, "./foo"));
+        assert!(path.is_some_and(|path| path.starts_with(&dir)));
+        let path = runtime.block_on(resolver.resolve_async(dir, "./missing".into()));
+        assert_eq!(path, None);
+    }
+}
@@ -0,0 +1,192 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::UNIX_EPOCH,
+};
+
+use dashmap::DashMap;
+use oxc_resolver::{FileMetadata, FileSystem};
+use serde::{Deserialize, Serialize};
+
+/// Version of the linter, caches written by other versions are discarded.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A description file read by the resolver, i.e. a `package.json` or a `tsconfig.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DescriptionFile {
+    /// Modification time in nanoseconds since the Unix epoch.
+    modified: u64,
+    len: u64,
+    contents: String,
+}
+
+/// Contents of a resolver cache file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// Version of the linter which wrote the cache.
+    version: String,
+    files: Vec<(PathBuf, DescriptionFile)>,
+}
+
+/// The description files read by the resolver, kept in a cache file so that a cold start does not
+/// need to read the `package.json` files of every package in `node_modules` again.
+///
+/// A cached file is used while its modification time and size are unchanged.
+pub(super) struct ResolverCache {
+    path: PathBuf,
+    files: DashMap<PathBuf, DescriptionFile>,
+}
+
+impl ResolverCache {
+    /// Load the cache at `path`. The cache is empty if the file does not exist or cannot be read,
+    /// or if it was written by another version of the linter.
+    pub fn load(path: PathBuf) -> Self {
+        let files = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheFile>(&json).ok())
+            .filter(|cache| cache.version == VERSION)
+            .map(|cache| cache.files.into_iter().collect())
+            .unwrap_or_default();
+        Self { path, files }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write the cache to its file.
+    pub fn save(&self) -> io::Result<()> {
+        let mut files = self
+            .files
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        // Sorted, so that the file does not change when the description files do not.
+        files.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        let cache = CacheFile { version: VERSION.to_string(), files };
+        fs::write(&self.path, serde_json::to_string(&cache)?)
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let metadata = fs::metadata(path)?;
+        let modified = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .and_then(|duration| u64::try_from(duration.as_nanos()).ok())
+            .unwrap_or_default();
+        let len = metadata.len();
+        if let Some(file) = self.files.get(path) {
+            if file.modified == modified && file.len == len {
+                return Ok(file.contents.clone());
+            }
+        }
+        let contents = fs::read_to_string(path)?;
+        self.files.insert(
+            path.to_path_buf(),
+            DescriptionFile { modified, len, contents: contents.clone() },
+        );
+        Ok(contents)
+    }
+}
+
+/// The file system of the resolver, which reads the description files from the [`ResolverCache`]
+/// if it is enabled, and everything else from the disk.
+pub(super) struct CachedFileSystem {
+    cache: Option<Arc<ResolverCache>>,
+}
+
+impl CachedFileSystem {
+    pub fn new(cache: Option<Arc<ResolverCache>>) -> Self {
+        Self { cache }
+    }
+
+    fn is_description_file(path: &Path) -> bool {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            return false;
+        };
+        name == "package.json"
+            || (name.starts_with("tsconfig") && path.extension().is_some_and(|ext| ext == "json"))
+    }
+}
+
+impl FileSystem for CachedFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        match &self.cache {
+            Some(cache) if Self::is_description_file(path) => cache.read_to_string(path),
+            _ => fs::read_to_string(path),
+        }
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        fs::metadata(path).map(FileMetadata::from)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        fs::symlink_metadata(path).map(FileMetadata::from)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = fs::canonicalize(path)?;
+        // Strip the verbatim prefix `\\?\` of Windows, which Node.js does not use either.
+        #[cfg(windows)]
+        if let Some(stripped) = path.to_str().and_then(|path| path.strip_prefix(r"\\?\")) {
+            if !stripped.starts_with("UNC") {
+                return Ok(PathBuf::from(stripped));
+            }
+        }
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        sync::Arc,
+        time::{Duration, SystemTime},
+    };
+
+    use oxc_resolver::FileSystem;
+
+    use super::{CachedFileSystem, ResolverCache};
+
+    #[test]
+    fn test_resolver_cache() {
+        let dir = std::env::temp_dir().join("oxc_linter_test_resolver_cache");
+        fs::create_dir_all(&dir).unwrap();
+        let cache_path = dir.join("cache.json");
+        let _ = fs::remove_file(&cache_path);
+        let package_json = dir.join("package.json");
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let write = |contents: &str| {
+            fs::write(&package_json, contents).unwrap();
+            let file = fs::File::options().write(true).open(&package_json).unwrap();
+            file.set_modified(modified).unwrap();
+        };
+        write(r#"{ "name": "a" }"#);
+
+        let cache = Arc::new(ResolverCache::load(cache_path.clone()));
+        let fs = CachedFileSystem::new(Some(Arc::clone(&cache)));
+        assert_eq!(fs.read_to_string(&package_json).unwrap(), r#"{ "name": "a" }"#);
+        cache.save().unwrap();
+
+        // The cached contents are used while the modification time and size are unchanged.
+        write(r#"{ "name": "b" }"#);
+        let cache = Arc::new(ResolverCache::load(cache_path));
+        let fs = CachedFileSystem::new(Some(Arc::clone(&cache)));
+        assert_eq!(fs.read_to_string(&package_json).unwrap(), r#"{ "name": "a" }"#);
+
+        fs::write(&package_json, r#"{ "name": "c" }"#).unwrap();
+        assert_eq!(fs.read_to_string(&package_json).unwrap(), r#"{ "name": "c" }"#);
+
+        // Other files are not cached.
+        let index = dir.join("index.js");
+        fs::write(&index, "a").unwrap();
+        assert_eq!(fs.read_to_string(&index).unwrap(), "a");
+        assert!(!cache.files.contains_key(&index));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
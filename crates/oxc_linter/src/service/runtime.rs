@@ -2,17 +2,16 @@ use std::{
     borrow::Cow,
     ffi::OsStr,
     fs,
-    path::{Path, PathBuf},
+    path::Path,
     rc::Rc,
-    sync::Arc,
+    sync::{Arc, RwLock},
 };
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
-use oxc_resolver::Resolver;
 use oxc_semantic::SemanticBuilder;
-use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_span::{CompactStr, SourceType};
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
 use rustc_hash::FxHashSet;
 
@@ -23,51 +22,57 @@ use crate::{
 };
 
 use super::{
+    baseline::LintBaseline,
+    cache::LintCache,
     module_cache::{ModuleCache, ModuleState},
-    LintServiceOptions,
+    module_resolver::ModuleResolver,
+    resolver_cache::{CachedFileSystem, ResolverCache},
+    LintResult, LintServiceOptions,
 };
 
+/// How many times a file is linted and fixed at most, like ESLint.
+const MAX_FIX_PASSES: usize = 10;
+
 pub struct Runtime {
     cwd: Box<Path>,
     /// All paths to lint
-    paths: FxHashSet<Box<Path>>,
+    paths: RwLock<FxHashSet<Box<Path>>>,
     pub(super) linter: Linter,
-    resolver: Option<Resolver>,
+    pub(super) resolver: Option<Arc<ModuleResolver>>,
+    resolver_cache: Option<Arc<ResolverCache>>,
     modules: ModuleCache,
+    cache: Option<LintCache>,
+    baseline: Option<LintBaseline>,
 }
 
 impl Runtime {
     pub(super) fn new(linter: Linter, options: LintServiceOptions) -> Self {
+        let resolver_cache = options
+            .resolver_cache
+            .filter(|_| options.cross_module)
+            .map(|path| Arc::new(ResolverCache::load(path)));
         let resolver = options.cross_module.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+            Arc::new(ModuleResolver::new(
+                CachedFileSystem::new(resolver_cache.clone()),
+                options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))),
+            ))
         });
+        let cache = options
+            .cache
+            .filter(|_| resolver.is_none())
+            .map(|path| LintCache::load(path, linter.config_hash()));
         Self {
             cwd: options.cwd,
-            paths: options.paths.iter().cloned().collect(),
+            paths: RwLock::new(options.paths.iter().cloned().collect()),
             linter,
             resolver,
+            resolver_cache,
             modules: ModuleCache::default(),
+            cache,
+            baseline: options.baseline,
         }
     }
 
-    fn get_resolver(tsconfig: Option<PathBuf>) -> Resolver {
-        use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
-        let tsconfig = tsconfig.and_then(|path| {
-            if path.is_file() {
-                Some(TsconfigOptions { config_file: path, references: TsconfigReferences::Auto })
-            } else {
-                None
-            }
-        });
-
-        Resolver::new(ResolveOptions {
-            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
-            condition_names: vec!["module".into(), "require".into()],
-            tsconfig,
-            ..ResolveOptions::default()
-        })
-    }
-
     fn get_source_type_and_text(
         path: &Path,
         ext: &str,
@@ -90,9 +95,6 @@ impl Runtime {
         })
     }
 
-    // clippy: the source field is checked and assumed to be less than 4GB, and
-    // we assume that the fix offset will not exceed 2GB in either direction
-    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
     pub(super) fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
         if self.init_cache_state(path) {
             return;
@@ -116,30 +118,109 @@ impl Runtime {
                 return;
             }
         };
+        let relative_path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        let source_type = self.linter.source_type(relative_path, source_type);
 
-        let sources = PartialLoader::parse(ext, &source_text)
-            .unwrap_or_else(|| vec![JavaScriptSource::partial(&source_text, source_type, 0)]);
+        let cache_key = self.cache.as_ref().map(|cache| {
+            (cache, relative_path.to_string_lossy().into_owned(), LintCache::hash(&source_text))
+        });
+        if let Some((cache, relative_path, hash)) = &cache_key {
+            if cache.is_clean(relative_path, hash) {
+                return;
+            }
+        }
+
+        let (result, has_diagnostics) =
+            self.process_source_text(path, ext, source_type, &source_text, tx_error);
+        if let Some((cache, relative_path, hash)) = cache_key {
+            let hash = result.fixed_source_text.as_deref().map_or(hash, LintCache::hash);
+            cache.update(relative_path, hash, !has_diagnostics);
+        }
+        let new_source_text = result.fixed_source_text.as_deref().unwrap_or(&source_text);
+        self.send_diagnostics(path, new_source_text, result.diagnostics, tx_error);
+        if let Some(fixed_source_text) = result.fixed_source_text {
+            fs::write(path, fixed_source_text).unwrap();
+        }
+    }
+
+    /// Lint `source_text` as the contents of `path`, which does not need to exist, e.g. for
+    /// source text read from stdin.
+    pub(super) fn process_text(
+        &self,
+        path: &Path,
+        source_text: &str,
+        tx_error: &DiagnosticSender,
+    ) -> LintResult {
+        self.init_cache_state(path);
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let source_type = SourceType::from_path(path).unwrap_or_default();
+        let source_type =
+            self.linter.source_type(path.strip_prefix(&self.cwd).unwrap_or(path), source_type);
+        self.process_source_text(path, ext, source_type, source_text, tx_error).0
+    }
+
+    /// Send `diagnostics` of the file at `path`, whose spans point into `source_text`.
+    pub(super) fn send_diagnostics(
+        &self,
+        path: &Path,
+        source_text: &str,
+        diagnostics: Vec<OxcDiagnostic>,
+        tx_error: &DiagnosticSender,
+    ) {
+        if diagnostics.is_empty() {
+            return;
+        }
+        let relative_path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        let diagnostics =
+            DiagnosticService::wrap_diagnostics(relative_path, source_text, diagnostics);
+        tx_error.send(Some(diagnostics)).unwrap();
+    }
+
+    /// Lint the sources of `source_text`, the contents of `path`.
+    ///
+    /// Returns the result, and whether the file has any diagnostic, including the diagnostics
+    /// which are suppressed by the baseline.
+    // clippy: the source field is checked and assumed to be less than 4GB, and
+    // we assume that the fix offset will not exceed 2GB in either direction
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn process_source_text(
+        &self,
+        path: &Path,
+        ext: &str,
+        source_type: SourceType,
+        source_text: &str,
+        tx_error: &DiagnosticSender,
+    ) -> (LintResult, bool) {
+        let sources = PartialLoader::parse(ext, source_text)
+            .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
 
         if sources.is_empty() {
             self.ignore_path(path);
-            return;
+            return (LintResult::default(), false);
         }
 
         // If there are fixes, we will accumulate all of them and write to the file at the end.
         // This means we do not write multiple times to the same file if there are multiple sources
         // in the same file (for example, multiple scripts in an `.astro` file).
-        let mut new_source_text = Cow::from(&source_text);
+        let mut new_source_text = Cow::from(source_text);
         // This is used to keep track of the cumulative offset from applying fixes.
         // Otherwise, spans for fixes will be incorrect due to varying size of the
         // source code after each fix.
         let mut fix_offset: i32 = 0;
+        let mut all_diagnostics = vec![];
+        let mut has_diagnostics = false;
+        let relative_path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        let mut baseline_problems = self
+            .baseline
+            .as_ref()
+            .map(|baseline| baseline.problems(&relative_path.to_string_lossy()));
 
         let mut allocator = Allocator::default();
         for (i, source) in sources.into_iter().enumerate() {
             if i >= 1 {
                 allocator.reset();
             }
-            let mut messages = self.process_source(
+            let messages = self.process_source(
                 path,
                 &allocator,
                 source.source_text,
@@ -148,35 +229,88 @@ impl Runtime {
                 tx_error,
             );
 
-            if self.linter.options().fix.is_some() {
-                let fix_result = Fixer::new(source.source_text, messages).fix();
-                if fix_result.fixed {
-                    // write to file, replacing only the changed part
-                    let start = source.start.saturating_add_signed(fix_offset) as usize;
-                    let end = start + source.source_text.len();
-                    new_source_text.to_mut().replace_range(start..end, &fix_result.fixed_code);
-                    let old_code_len = source.source_text.len() as u32;
-                    let new_code_len = fix_result.fixed_code.len() as u32;
-                    fix_offset += new_code_len as i32;
-                    fix_offset -= old_code_len as i32;
-                }
-                messages = fix_result.messages;
+            let (fixed_code, mut diagnostics) = if self.linter.options().fix.is_some() {
+                self.fix_source(path, source.source_text, source.source_type, messages, tx_error)
+            } else {
+                (None, messages.into_iter().map(Into::into).collect())
+            };
+
+            // Where the source starts in `new_source_text`, which contains the fixes of the
+            // sources before it.
+            let start = source.start.saturating_add_signed(fix_offset) as usize;
+            if let Some(fixed_code) = &fixed_code {
+                // write to file, replacing only the changed part
+                let end = start + source.source_text.len();
+                new_source_text.to_mut().replace_range(start..end, fixed_code);
+                let old_code_len = source.source_text.len() as u32;
+                let new_code_len = fixed_code.len() as u32;
+                fix_offset += new_code_len as i32;
+                fix_offset -= old_code_len as i32;
+            }
+
+            // Files with problems in the baseline are not cached, as the baseline can change.
+            if let (Some(baseline), Some(problems)) = (&self.baseline, &mut baseline_problems) {
+                has_diagnostics |= !diagnostics.is_empty();
+                baseline.suppress(problems, &mut diagnostics);
             }
 
-            if !messages.is_empty() {
+            if !diagnostics.is_empty() {
+                has_diagnostics = true;
                 self.ignore_path(path);
-                let errors = messages.into_iter().map(Into::into).collect();
-                let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics =
-                    DiagnosticService::wrap_diagnostics(path, source.source_text, errors);
-                tx_error.send(Some(diagnostics)).unwrap();
+                // Report the diagnostics at their position in the whole file, not in the source.
+                // Fixes of the sources after this one do not move them.
+                all_diagnostics.extend(
+                    diagnostics.into_iter().map(|diagnostic| diagnostic.with_offset(start)),
+                );
             }
         }
 
-        // If the new source text is owned, that means it was modified,
-        // so we write the new source text to the file.
-        if let Cow::Owned(new_source_text) = new_source_text {
-            fs::write(path, new_source_text).unwrap();
+        if let (Some(baseline), Some(problems)) = (&self.baseline, baseline_problems) {
+            baseline.finish(relative_path.to_string_lossy().into_owned(), problems);
+        }
+
+        // If the new source text is owned, that means it was modified.
+        let fixed_source_text = match new_source_text {
+            Cow::Owned(new_source_text) => Some(new_source_text),
+            Cow::Borrowed(_) => None,
+        };
+        (LintResult { diagnostics: all_diagnostics, fixed_source_text }, has_diagnostics)
+    }
+
+    /// Apply the fixes of `messages`, then lint and fix the fixed code again until nothing is left
+    /// to fix, as fixes may uncover other problems or conflict with each other.
+    ///
+    /// Returns the fixed code if anything was fixed, and the diagnostics which are left.
+    fn fix_source(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+        messages: Vec<Message>,
+        tx_error: &DiagnosticSender,
+    ) -> (Option<String>, Vec<OxcDiagnostic>) {
+        let fix_result = Fixer::new(source_text, messages).fix();
+        if !fix_result.fixed {
+            return (None, fix_result.messages.into_iter().map(Into::into).collect());
+        }
+        let mut fixed_code = fix_result.fixed_code.into_owned();
+
+        let mut passes = 1;
+        loop {
+            let allocator = Allocator::default();
+            let messages =
+                self.process_source(path, &allocator, &fixed_code, source_type, true, tx_error);
+            if passes == MAX_FIX_PASSES {
+                let diagnostics = messages.into_iter().map(Into::into).collect();
+                return (Some(fixed_code), diagnostics);
+            }
+            let fix_result = Fixer::new(&fixed_code, messages).fix();
+            let diagnostics = fix_result.messages.into_iter().map(Into::into).collect();
+            match fix_result.fixed.then(|| fix_result.fixed_code.into_owned()) {
+                Some(code) => fixed_code = code,
+                None => return (Some(fixed_code), diagnostics),
+            }
+            passes += 1;
         }
     }
 
@@ -211,23 +345,24 @@ impl Runtime {
             .build_module_record(path, &ret.program);
         let module_record = semantic_builder.module_record();
 
-        if self.resolver.is_some() {
+        if let Some(resolver) = &self.resolver {
             self.modules.add_resolved_module(path, Arc::clone(&module_record));
 
             // Retrieve all dependency modules from this module.
             let dir = path.parent().unwrap();
-            module_record
-                .requested_modules
-                .keys()
-                .par_bridge()
-                .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
+            resolver
+                .resolve_many(dir, module_record.requested_modules.keys().map(CompactStr::as_str))
+                .into_iter()
+                .filter_map(|(specifier, resolution)| {
+                    if resolution.is_none() {
+                        module_record.unresolved_modules.insert(specifier.into());
+                    }
+                    resolution.map(|path| (specifier, path))
                 })
-                .flatten()
-                .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
-                    let path = resolution.path();
-                    self.process_path(path, tx_error);
-                    let Some(target_module_record_ref) = self.modules.get(path) else {
+                .par_bridge()
+                .for_each_with(tx_error, |tx_error, (specifier, path)| {
+                    self.process_path(&path, tx_error);
+                    let Some(target_module_record_ref) = self.modules.get(&path) else {
                         return;
                     };
                     let ModuleState::Resolved(target_module_record) =
@@ -238,7 +373,7 @@ impl Runtime {
                     // Append target_module to loaded_modules
                     module_record
                         .loaded_modules
-                        .insert(specifier.clone(), Arc::clone(target_module_record));
+                        .insert(specifier.into(), Arc::clone(target_module_record));
                 });
 
             // The thread is blocked here until all dependent modules are resolved.
@@ -274,7 +409,7 @@ impl Runtime {
             }
 
             // Stop if the current module is not marked for lint.
-            if !self.paths.contains(path) {
+            if !self.paths.read().unwrap().contains(path) {
                 return vec![];
             }
         }
@@ -287,7 +422,23 @@ impl Runtime {
 
         let mut semantic = semantic_ret.semantic;
         semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
-        self.linter.run(path, Rc::new(semantic))
+        let relative_path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        self.linter.run_relative(path, relative_path, Rc::new(semantic))
+    }
+
+    /// Write the cache of the description files read by the resolver, if it is enabled.
+    pub(super) fn save_resolver_cache(&self, tx_error: &DiagnosticSender) {
+        let Some(cache) = &self.resolver_cache else {
+            return;
+        };
+        let Err(error) = cache.save() else {
+            return;
+        };
+        let path = cache.path();
+        let error = OxcDiagnostic::warn(format!(
+            "Failed to write the resolver cache file {path:?} with error \"{error}\""
+        ));
+        tx_error.send(Some((path.to_path_buf(), vec![Error::new(error)]))).unwrap();
     }
 
     pub(super) fn init_cache_state(&self, path: &Path) -> bool {
@@ -298,15 +449,108 @@ impl Runtime {
         self.modules.init_cache_state(path)
     }
 
+    /// Write the cache of the files linted without diagnostics, if caching is enabled.
+    pub(super) fn save_cache(&self, tx_error: &DiagnosticSender) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+        if let Err(error) = cache.save() {
+            let path = cache.path();
+            let error = OxcDiagnostic::warn(format!(
+                "Failed to write the cache file {path:?} with error \"{error}\""
+            ));
+            tx_error.send(Some((path.to_path_buf(), vec![Error::new(error)]))).unwrap();
+        }
+    }
+
+    /// Write the baseline file, if the baseline is generated.
+    pub(super) fn save_baseline(&self, tx_error: &DiagnosticSender) {
+        let Some(baseline) = &self.baseline else {
+            return;
+        };
+        if let Err(error) = baseline.save() {
+            let path = baseline.path();
+            let error = OxcDiagnostic::error(format!(
+                "Failed to write the baseline file {path:?} with error \"{error}\""
+            ));
+            tx_error.send(Some((path.to_path_buf(), vec![Error::new(error)]))).unwrap();
+        }
+    }
+
     fn ignore_path(&self, path: &Path) {
         self.resolver.is_some().then(|| self.modules.ignore_path(path));
     }
 
     pub(super) fn number_of_dependencies(&self) -> usize {
-        self.modules.len() - self.paths.len()
+        self.modules.len() - self.paths.read().unwrap().len()
     }
 
-    pub(super) fn iter_paths(&self) -> impl Iterator<Item = &Box<Path>> + '_ {
-        self.paths.iter()
+    pub(super) fn paths(&self) -> Vec<Box<Path>> {
+        self.paths.read().unwrap().iter().cloned().collect()
+    }
+
+    /// Add `paths` to the paths to lint, and forget the modules of `paths` which changed since
+    /// they were linted.
+    pub(super) fn invalidate_paths(&self, paths: &[Box<Path>]) {
+        self.paths.write().unwrap().extend(paths.iter().cloned());
+        if self.resolver.is_some() {
+            for path in paths {
+                self.modules.remove(path);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::mpsc};
+
+    use oxc_diagnostics::OxcDiagnostic;
+
+    use crate::{FixKind, LintService, LintServiceOptions, Linter, LinterBuilder, Oxlintrc};
+
+    #[test]
+    fn test_partial_source_offsets() {
+        let source_text = "<template><p>hi</p></template>\n<script>\nexport default {};\n</script>\n<script setup>\ndebugger;\n</script>\n";
+        let service = LintService::from_linter(
+            Linter::default(),
+            LintServiceOptions::new(std::env::current_dir().unwrap(), vec![]),
+        );
+        let (tx_error, rx_error) = mpsc::channel();
+        service.run_source_text(Path::new("a.vue"), source_text, &tx_error);
+
+        let (_, errors) = rx_error.recv().unwrap().unwrap();
+        assert_eq!(errors.len(), 1);
+        let diagnostic = OxcDiagnostic::from_error(&errors[0]).unwrap();
+        let label = &diagnostic.labels.as_ref().unwrap()[0];
+        // The label points into the whole file, not into the `<script setup>` block.
+        assert_eq!(label.offset(), source_text.find("debugger").unwrap());
+        assert_eq!(label.len(), "debugger;".len());
+    }
+
+    #[test]
+    fn test_lint_source_text() {
+        let config = Oxlintrc::from_json(
+            r#"{ "rules": { "no-debugger": "error", "no-self-compare": "error" } }"#,
+        )
+        .unwrap();
+        let linter = LinterBuilder::from_oxlintrc(true, config).with_fix(FixKind::SafeFix).build();
+        let service = LintService::new(
+            linter,
+            LintServiceOptions::new(std::env::current_dir().unwrap(), vec![]),
+        );
+
+        let source_text =
+            "<script>\ndebugger;\n</script>\n<script setup>\ndebugger; a === a;\n</script>\n";
+        let result = service.lint_source_text(Path::new("a.vue"), source_text);
+        let fixed_source_text = result.fixed_source_text.unwrap();
+        assert_eq!(
+            fixed_source_text,
+            "<script>\n\n</script>\n<script setup>\n a === a;\n</script>\n"
+        );
+        assert_eq!(result.diagnostics.len(), 1);
+        let label = &result.diagnostics[0].labels.as_ref().unwrap()[0];
+        // The label points into the fixed source text.
+        assert_eq!(label.offset(), fixed_source_text.find("a === a").unwrap());
     }
 }
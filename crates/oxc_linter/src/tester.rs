@@ -10,8 +10,8 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{
-    fixer::FixKind, rules::RULES, AllowWarnDeny, Fixer, LintPlugins, LintService,
-    LintServiceOptions, LinterBuilder, Oxlintrc, RuleEnum, RuleWithSeverity,
+    config::validate_rule_options, fixer::FixKind, rules::RULES, AllowWarnDeny, Fixer, LintPlugins,
+    LintService, LintServiceOptions, LinterBuilder, Oxlintrc, RuleEnum, RuleWithSeverity,
 };
 
 #[derive(Eq, PartialEq)]
@@ -280,6 +280,11 @@ impl Tester {
         self
     }
 
+    pub fn with_regexp_plugin(mut self, yes: bool) -> Self {
+        self.plugins.set(LintPlugins::REGEXP, yes);
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -428,7 +433,14 @@ impl Tester {
         fix: ExpectFixKind,
     ) -> TestResult {
         let allocator = Allocator::default();
-        let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
+        let rule_config = rule_config.unwrap_or_default();
+        let errors = validate_rule_options(self.find_rule(), &rule_config);
+        assert!(
+            errors.is_empty(),
+            "Invalid options {rule_config} for {}: {errors:?}",
+            self.rule_name
+        );
+        let rule = self.find_rule().read_json(rule_config);
         let linter = eslint_config
             .as_ref()
             .map_or_else(LinterBuilder::empty, |v| {
@@ -439,8 +451,8 @@ impl Tester {
             .with_rule(RuleWithSeverity::new(rule, AllowWarnDeny::Warn))
             .build();
 
-        let path_to_lint = if self.plugins.has_import() {
-            assert!(path.is_none(), "import plugin does not support path");
+        let path_to_lint = if self.plugins.needs_module_resolution() {
+            assert!(path.is_none(), "cross-module tests do not support path");
             self.current_working_directory.join(&self.rule_path)
         } else if let Some(path) = path {
             self.current_working_directory.join(path)
@@ -452,8 +464,8 @@ impl Tester {
 
         let cwd = self.current_working_directory.clone();
         let paths = vec![path_to_lint.into_boxed_path()];
-        let options =
-            LintServiceOptions::new(cwd, paths).with_cross_module(self.plugins.has_import());
+        let options = LintServiceOptions::new(cwd, paths)
+            .with_cross_module(self.plugins.needs_module_resolution());
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
@@ -468,8 +480,9 @@ impl Tester {
             return TestResult::Fixed(fix_result.fixed_code.to_string());
         }
 
-        let diagnostic_path = if self.plugins.has_import() {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
+        // The rule path is only prefixed with the working directory by `change_rule_path`.
+        let diagnostic_path = if self.plugins.needs_module_resolution() {
+            self.rule_path.strip_prefix(&self.current_working_directory).unwrap_or(&self.rule_path)
         } else {
             &self.rule_path
         }
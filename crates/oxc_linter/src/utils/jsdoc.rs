@@ -1,7 +1,4 @@
-use oxc_ast::{
-    ast::{BindingPattern, BindingPatternKind, Expression, FormalParameters},
-    AstKind,
-};
+use oxc_ast::ast::{BindingPattern, BindingPatternKind, Expression, FormalParameters};
 use oxc_semantic::JSDoc;
 use oxc_span::Span;
 use rustc_hash::FxHashSet;
@@ -9,58 +6,12 @@ use rustc_hash::FxHashSet;
 use crate::{config::JSDocPluginSettings, context::LintContext, AstNode};
 
 /// JSDoc is often attached on the parent node of a function.
-///
-/// ```js
-/// /** VariableDeclaration > VariableDeclarator > FunctionExpression */
-/// const foo = function() {}
-///
-/// /** VariableDeclaration > VariableDeclarator > ArrowFunctionExpression */
-/// const bar = () => {},
-///       /** VariableDeclarator > ArrowFunctionExpression */
-///       baz = () => {};
-///
-/// /** MethodDefinition > FunctionExpression */
-/// class X { qux() {} }
-///
-/// /** PropertyDefinition > ArrowFunctionExpression */
-/// class Y { qux = () => {} }
-/// ```
+/// See [`oxc_semantic::JSDocFinder::get_function_jsdoc_node`].
 pub fn get_function_nearest_jsdoc_node<'a, 'b>(
     node: &'b AstNode<'a>,
     ctx: &'b LintContext<'a>,
 ) -> Option<&'b AstNode<'a>> {
-    let mut current_node = node;
-    // Whether the node has attached JSDoc or not is determined by `JSDocBuilder`
-    while ctx.jsdoc().get_all_by_node(current_node).is_none() {
-        // Tie-breaker, otherwise every loop will end at `Program` node!
-        // Maybe more checks should be added
-        match current_node.kind() {
-            AstKind::VariableDeclaration(_)
-            | AstKind::MethodDefinition(_)
-            | AstKind::PropertyDefinition(_)
-            // /** This JSDoc should NOT found for `ArrowFunctionExpression` callback */
-            // function outer() { inner(() => {}) }
-            | AstKind::CallExpression(_)
-            // /** This JSDoc should NOT found for `ArrowFunctionExpression` callback */
-            // new Promise(() => {})
-            | AstKind::NewExpression(_)
-            // /** This JSDoc should NOT found for inner `Function` */
-            // function outer() { return function inner() {} }
-            | AstKind::ReturnStatement(_)
-            => {
-                // /** This JSDoc should NOT found for `VariableDeclaration` */
-                // export const foo = () => {}
-                let parent_node = ctx.nodes().parent_node(current_node.id())?;
-                match parent_node.kind() {
-                    AstKind::ExportDefaultDeclaration(_) | AstKind::ExportNamedDeclaration(_) => return Some(parent_node),
-                    _ => return None
-                }
-            },
-            _ => current_node = ctx.nodes().parent_node(current_node.id())?,
-        }
-    }
-
-    Some(current_node)
+    ctx.jsdoc().get_function_jsdoc_node(node, ctx.nodes())
 }
 
 pub fn should_ignore_as_internal(jsdoc: &JSDoc, settings: &JSDocPluginSettings) -> bool {
@@ -3,6 +3,7 @@ mod express;
 mod jest;
 mod jsdoc;
 mod nextjs;
+mod node;
 mod promise;
 mod react;
 mod react_perf;
@@ -13,8 +14,8 @@ mod vitest;
 use std::{io, path::Path};
 
 pub use self::{
-    config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*, react_perf::*,
-    tree_shaking::*, unicorn::*, vitest::*,
+    config::*, express::*, jest::*, jsdoc::*, nextjs::*, node::*, promise::*, react::*,
+    react_perf::*, tree_shaking::*, unicorn::*, vitest::*,
 };
 
 /// List of Jest rules that have Vitest equivalents.
@@ -42,6 +43,18 @@ pub fn is_jest_rule_adapted_to_vitest(rule_name: &str) -> bool {
     VITEST_COMPATIBLE_JEST_RULES.contains(rule_name)
 }
 
+/// List of Unicorn rules that have Node equivalents.
+const NODE_COMPATIBLE_UNICORN_RULES: phf::Set<&'static str> = phf::phf_set! {
+    "no-process-exit",
+};
+
+/// Check if the Unicorn rule is adapted to Node.
+/// Some rules of `eslint-plugin-n` are also part of `eslint-plugin-unicorn`,
+/// so configuring the Node rule configures the corresponding Unicorn rule.
+pub fn is_unicorn_rule_adapted_to_node(rule_name: &str) -> bool {
+    NODE_COMPATIBLE_UNICORN_RULES.contains(rule_name)
+}
+
 pub fn read_to_string(path: &Path) -> io::Result<String> {
     // `simdutf8` is faster than `std::str::from_utf8` which `fs::read_to_string` uses internally
     let bytes = std::fs::read(path)?;
@@ -0,0 +1,240 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    ast::{
+        Argument, BindingIdentifier, BindingPatternKind, CallExpression, Expression,
+        ImportDeclarationSpecifier,
+    },
+    AstKind,
+};
+use oxc_semantic::NodeId;
+use oxc_span::{GetSpan, Span};
+use phf::{phf_set, set::Set};
+
+use crate::{
+    ast_util::{get_declaration_of_variable, get_symbol_id_of_variable, is_global_require_call},
+    LintContext,
+};
+
+/// Modules built into Node.js which can be imported without the `node:` prefix.
+///
+/// Modules which are only available with the prefix, e.g. `node:test`, are not included.
+const NODE_BUILTIN_MODULES: Set<&'static str> = phf_set! {
+    "assert", "assert/strict", "async_hooks", "buffer", "child_process", "cluster", "console",
+    "constants", "crypto", "dgram", "diagnostics_channel", "dns", "dns/promises", "domain",
+    "events", "fs", "fs/promises", "http", "http2", "https", "inspector", "inspector/promises",
+    "module", "net", "os", "path", "path/posix", "path/win32", "perf_hooks", "process",
+    "punycode", "querystring", "readline", "readline/promises", "repl", "stream",
+    "stream/consumers", "stream/promises", "stream/web", "string_decoder", "sys", "timers",
+    "timers/promises", "tls", "trace_events", "tty", "url", "util", "util/types", "v8", "vm",
+    "wasi", "worker_threads", "zlib",
+};
+
+/// Returns the name of the Node.js builtin module imported by `specifier`, without the `node:`
+/// prefix, or `None` if `specifier` does not refer to a builtin module.
+///
+/// ```js
+/// import fs from "fs";         // Some("fs")
+/// import fs from "node:fs";    // Some("fs")
+/// import test from "node:test" // Some("test")
+/// import foo from "./foo";     // None
+/// ```
+pub fn node_builtin_module_name(specifier: &str) -> Option<&str> {
+    if let Some(name) = specifier.strip_prefix("node:") {
+        return Some(name);
+    }
+    NODE_BUILTIN_MODULES.contains(specifier).then_some(specifier)
+}
+
+/// Returns the module specifier of a call to the global `require` with a string literal, e.g.
+/// `"fs"` in `require("fs")`.
+pub fn get_required_module<'a>(
+    call_expr: &CallExpression<'a>,
+    ctx: &LintContext,
+) -> Option<&'a str> {
+    if !is_global_require_call(call_expr, ctx.semantic()) {
+        return None;
+    }
+    match call_expr.arguments.first() {
+        Some(Argument::StringLiteral(lit)) => Some(lit.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Returns the Node.js builtin module, without the `node:` prefix, and its member called by
+/// `call_expr`, e.g. `("fs", "readFile")` for each of:
+///
+/// ```js
+/// import fs from "node:fs"; fs.readFile(file);
+/// import { readFile } from "fs"; readFile(file);
+/// const { readFile } = require("fs"); readFile(file);
+/// require("fs").readFile(file);
+/// ```
+pub fn get_node_builtin_module_call<'a>(
+    call_expr: &CallExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a str, Cow<'a, str>)> {
+    match call_expr.callee.get_inner_expression() {
+        Expression::Identifier(ident) => {
+            let declaration = get_declaration_of_variable(ident, ctx.semantic())?;
+            match declaration.kind() {
+                AstKind::ImportSpecifier(specifier) => {
+                    let module = imported_builtin_module(declaration.id(), ctx)?;
+                    Some((module, Cow::Borrowed(specifier.imported.name().as_str())))
+                }
+                AstKind::VariableDeclarator(decl) => {
+                    let module = required_builtin_module(decl.init.as_ref()?, ctx)?;
+                    let BindingPatternKind::ObjectPattern(pattern) = &decl.id.kind else {
+                        return None;
+                    };
+                    let symbol_id = get_symbol_id_of_variable(ident, ctx.semantic())?;
+                    let property = pattern.properties.iter().find(|property| {
+                        property
+                            .value
+                            .get_binding_identifier()
+                            .and_then(|ident| ident.symbol_id.get())
+                            == Some(symbol_id)
+                    })?;
+                    Some((module, property.key.static_name()?))
+                }
+                _ => None,
+            }
+        }
+        Expression::StaticMemberExpression(member_expr) => {
+            let module = match member_expr.object.get_inner_expression() {
+                Expression::Identifier(ident) => {
+                    let declaration = get_declaration_of_variable(ident, ctx.semantic())?;
+                    match declaration.kind() {
+                        AstKind::ImportDefaultSpecifier(_)
+                        | AstKind::ImportNamespaceSpecifier(_) => {
+                            imported_builtin_module(declaration.id(), ctx)?
+                        }
+                        AstKind::VariableDeclarator(decl)
+                            if decl.id.kind.is_binding_identifier() =>
+                        {
+                            required_builtin_module(decl.init.as_ref()?, ctx)?
+                        }
+                        _ => return None,
+                    }
+                }
+                object => required_builtin_module(object, ctx)?,
+            };
+            Some((module, Cow::Borrowed(member_expr.property.name.as_str())))
+        }
+        _ => None,
+    }
+}
+
+/// The Node.js builtin module imported by the import declaration of a specifier.
+fn imported_builtin_module<'a>(specifier_id: NodeId, ctx: &LintContext<'a>) -> Option<&'a str> {
+    ctx.nodes().ancestor_kinds(specifier_id).find_map(|kind| match kind {
+        AstKind::ImportDeclaration(decl) => node_builtin_module_name(decl.source.value.as_str()),
+        _ => None,
+    })
+}
+
+/// The Node.js builtin module of `require("fs")`.
+fn required_builtin_module<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let Expression::CallExpression(call_expr) = expr.get_inner_expression() else {
+        return None;
+    };
+    get_required_module(call_expr, ctx).and_then(node_builtin_module_name)
+}
+
+/// Calls `f` with every access to a Node.js builtin module or one of its members.
+///
+/// `f` receives the module name without the `node:` prefix, the accessed member, and the span to
+/// report. Accesses to the module itself have no member:
+///
+/// ```js
+/// import fs from "fs";                // ("fs", None)
+/// import { exists } from "fs";        // ("fs", None), ("fs", Some("exists"))
+/// fs.exists(file);                    // ("fs", Some("exists"))
+/// const { exists } = require("fs");   // ("fs", None), ("fs", Some("exists"))
+/// require("fs").exists(file);         // ("fs", None), ("fs", Some("exists"))
+/// ```
+pub fn for_each_node_builtin_module_access<'a, F>(ctx: &LintContext<'a>, mut f: F)
+where
+    F: FnMut(&str, Option<&str>, Span),
+{
+    for node in ctx.nodes() {
+        match node.kind() {
+            AstKind::ImportDeclaration(decl) => {
+                let Some(module) = node_builtin_module_name(decl.source.value.as_str()) else {
+                    continue;
+                };
+                f(module, None, decl.source.span);
+                for specifier in decl.specifiers.iter().flatten() {
+                    match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            f(module, Some(specifier.imported.name().as_str()), specifier.span);
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(_)
+                        | ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                            for_each_member_access(specifier.local(), module, ctx, &mut f);
+                        }
+                    }
+                }
+            }
+            AstKind::CallExpression(call_expr) => {
+                let Some(module) =
+                    get_required_module(call_expr, ctx).and_then(node_builtin_module_name)
+                else {
+                    continue;
+                };
+                f(module, None, call_expr.span);
+                match ctx.nodes().parent_kind(node.id()) {
+                    // require("fs").exists
+                    Some(AstKind::MemberExpression(member_expr)) => {
+                        if member_expr.object().span() == call_expr.span {
+                            if let Some(member) = member_expr.static_property_name() {
+                                f(module, Some(member), member_expr.span());
+                            }
+                        }
+                    }
+                    Some(AstKind::VariableDeclarator(decl)) => match &decl.id.kind {
+                        // const fs = require("fs")
+                        BindingPatternKind::BindingIdentifier(ident) => {
+                            for_each_member_access(ident, module, ctx, &mut f);
+                        }
+                        // const { exists } = require("fs")
+                        BindingPatternKind::ObjectPattern(pattern) => {
+                            for property in &pattern.properties {
+                                if let Some(member) = property.key.static_name() {
+                                    f(module, Some(&member), property.span);
+                                }
+                            }
+                        }
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Calls `f` with every static member access on a binding of `module`, e.g. `fs.exists`.
+fn for_each_member_access<F>(ident: &BindingIdentifier, module: &str, ctx: &LintContext, f: &mut F)
+where
+    F: FnMut(&str, Option<&str>, Span),
+{
+    let Some(symbol_id) = ident.symbol_id.get() else {
+        return;
+    };
+    for reference in ctx.semantic().symbol_references(symbol_id) {
+        let reference_span = ctx.nodes().get_node(reference.node_id()).kind().span();
+        let Some(AstKind::MemberExpression(member_expr)) =
+            ctx.nodes().parent_kind(reference.node_id())
+        else {
+            continue;
+        };
+        if member_expr.object().span() != reference_span {
+            continue;
+        }
+        if let Some(member) = member_expr.static_property_name() {
+            f(module, Some(member), member_expr.span());
+        }
+    }
+}
@@ -63,53 +63,81 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
 
         use crate::{
             context::{ContextHost, LintContext},
+            custom_rule::CustomRuleEntry,
             rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta},
             utils::PossibleJestNode,
-            AstNode
+            AstNode, FixCategory
         };
         use oxc_semantic::SymbolId;
 
         #[derive(Debug, Clone)]
         #[allow(clippy::enum_variant_names)]
         pub enum RuleEnum {
-            #(#struct_names(#struct_names)),*
+            #(#struct_names(#struct_names),)*
+            /// A rule registered with [`crate::register_custom_rule`].
+            Custom(CustomRuleEntry),
         }
 
         impl RuleEnum {
             pub fn id(&self) -> usize {
                 match self {
-                    #(Self::#struct_names(_) => #ids),*
+                    #(Self::#struct_names(_) => #ids,)*
+                    Self::Custom(rule) => rule.id(),
                 }
             }
 
             pub fn name(&self) -> &'static str {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::NAME),*
+                    #(Self::#struct_names(_) => #struct_names::NAME,)*
+                    Self::Custom(rule) => rule.rule().name(),
                 }
             }
 
             pub fn category(&self) -> RuleCategory {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::CATEGORY),*
+                    #(Self::#struct_names(_) => #struct_names::CATEGORY,)*
+                    Self::Custom(rule) => rule.rule().category(),
                 }
             }
 
             /// This [`Rule`]'s auto-fix capabilities.
             pub fn fix(&self) -> RuleFixMeta {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::FIX),*
+                    #(Self::#struct_names(_) => #struct_names::FIX,)*
+                    Self::Custom(rule) => rule.rule().fix(),
+                }
+            }
+
+            /// The category of the changes made by this [`Rule`]'s fixes.
+            pub fn fix_category(&self) -> FixCategory {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::FIX_CATEGORY,)*
+                    Self::Custom(rule) => rule.rule().fix_category(),
                 }
             }
 
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
-                    #(Self::#struct_names(_) => #struct_names::documentation()),*
+                    #(Self::#struct_names(_) => #struct_names::documentation(),)*
+                    Self::Custom(rule) => rule.rule().documentation(),
+                }
+            }
+
+            /// JSON schema of each of the options of this [`Rule`], if it has options.
+            pub fn schema(
+                &self,
+                gen: &mut schemars::gen::SchemaGenerator,
+            ) -> Option<schemars::schema::Schema> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::schema(gen),)*
+                    Self::Custom(rule) => rule.rule().schema(gen),
                 }
             }
 
             pub fn plugin_name(&self) -> &'static str {
                 match self {
-                    #(Self::#struct_names(_) => #plugin_names),*
+                    #(Self::#struct_names(_) => #plugin_names,)*
+                    Self::Custom(rule) => rule.rule().plugin_name(),
                 }
             }
 
@@ -117,25 +145,29 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 match self {
                     #(Self::#struct_names(_) => Self::#struct_names(
                         #struct_names::from_configuration(value),
-                    )),*
+                    ),)*
+                    Self::Custom(rule) => Self::Custom(rule.read_json(value)),
                 }
             }
 
             pub(super) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
-                    #(Self::#struct_names(rule) => rule.run(node, ctx)),*
+                    #(Self::#struct_names(rule) => rule.run(node, ctx),)*
+                    Self::Custom(rule) => rule.rule().run(node, ctx),
                 }
             }
 
             pub(super) fn run_on_symbol<'a>(&self, symbol_id: SymbolId, ctx: &LintContext<'a>) {
                 match self {
-                    #(Self::#struct_names(rule) => rule.run_on_symbol(symbol_id, ctx)),*
+                    #(Self::#struct_names(rule) => rule.run_on_symbol(symbol_id, ctx),)*
+                    Self::Custom(rule) => rule.rule().run_on_symbol(symbol_id, ctx),
                 }
             }
 
             pub(super) fn run_once<'a>(&self, ctx: &LintContext<'a>) {
                 match self {
-                    #(Self::#struct_names(rule) => rule.run_once(ctx)),*
+                    #(Self::#struct_names(rule) => rule.run_once(ctx),)*
+                    Self::Custom(rule) => rule.rule().run_once(ctx),
                 }
             }
 
@@ -145,13 +177,15 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 ctx: &'c LintContext<'a>,
             ) {
                 match self {
-                    #(Self::#struct_names(rule) => rule.run_on_jest_node(jest_node, ctx)),*
+                    #(Self::#struct_names(rule) => rule.run_on_jest_node(jest_node, ctx),)*
+                    Self::Custom(_) => {}
                 }
             }
 
             pub(super) fn should_run(&self, ctx: &ContextHost) -> bool {
                 match self {
-                    #(Self::#struct_names(rule) => rule.should_run(ctx)),*
+                    #(Self::#struct_names(rule) => rule.should_run(ctx),)*
+                    Self::Custom(_) => true,
                 }
             }
         }
@@ -4,7 +4,7 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
     parse::{Parse, ParseStream},
-    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token,
+    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Path, Result, Token,
 };
 
 pub struct LintRuleMeta {
@@ -12,6 +12,10 @@ pub struct LintRuleMeta {
     category: Ident,
     /// Describes what auto-fixing capabilities the rule has
     fix: Option<Ident>,
+    /// Describes what the auto-fixes of the rule change
+    fix_category: Option<Ident>,
+    /// The type of each of the options of the rule, used for its JSON schema
+    config: Option<Path>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -35,20 +39,45 @@ impl Parse for LintRuleMeta {
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
 
-        // Parse FixMeta if it's specified. It will otherwise be excluded from
-        // the RuleMeta impl, falling back on default set by RuleMeta itself.
-        // Do not provide a default value here so that it can be set there instead.
-        let fix: Option<Ident> = if input.peek(Token!(,)) {
+        // Parse FixMeta and the fix category if they're specified, followed by the options type,
+        // e.g. `config = MaxLinesConfig`. They will otherwise be excluded from the RuleMeta impl,
+        // falling back on the defaults set by RuleMeta itself. Do not provide default values here
+        // so that they can be set there instead.
+        let mut fix: Option<Ident> = None;
+        let mut fix_category: Option<Ident> = None;
+        let mut config: Option<Path> = None;
+        while input.peek(Token!(,)) {
             input.parse::<Token!(,)>()?;
-            input.parse()?
-        } else {
-            None
-        };
+            if input.is_empty() {
+                break;
+            }
+            let ident: Ident = input.parse()?;
+            if ident == "config" {
+                input.parse::<Token!(=)>()?;
+                config = Some(input.parse()?);
+            } else if config.is_some() {
+                return Err(Error::new_spanned(ident, "`config` must be the last argument"));
+            } else if fix.is_none() {
+                fix = Some(ident);
+            } else if fix_category.is_none() {
+                fix_category = Some(ident);
+            } else {
+                break;
+            }
+        }
 
         // Ignore the rest
         input.parse::<proc_macro2::TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, fix, documentation, used_in_test: false })
+        Ok(Self {
+            name: struct_name,
+            category,
+            fix,
+            fix_category,
+            config,
+            documentation,
+            used_in_test: false,
+        })
     }
 }
 
@@ -57,7 +86,8 @@ pub(crate) fn rule_name_converter() -> Converter {
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, fix, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix, fix_category, config, documentation, used_in_test } =
+        metadata;
 
     let canonical_name = rule_name_converter().convert(name.to_string());
     let category = match category.to_string().as_str() {
@@ -77,6 +107,21 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         }
     });
 
+    let fix_category = fix_category.as_ref().map(Ident::to_string).map(|fix_category| {
+        let fix_category = parse_fix_category(&fix_category);
+        quote! {
+            const FIX_CATEGORY: crate::fixer::FixCategory = #fix_category;
+        }
+    });
+
+    let schema = config.map(|config| {
+        quote! {
+            fn schema(gen: &mut schemars::gen::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                Some(gen.subschema_for::<#config>())
+            }
+        }
+    });
+
     let import_statement = if used_in_test {
         None
     } else {
@@ -93,6 +138,10 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             #fix
 
+            #fix_category
+
+            #schema
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
@@ -177,3 +226,15 @@ fn parse_fix_kind(s: &str) -> proc_macro2::TokenStream {
         _ => panic!("invalid fix kind: {s}. Valid fix kinds are fix, suggestion, or dangerous."),
     }
 }
+
+fn parse_fix_category(s: &str) -> proc_macro2::TokenStream {
+    match s {
+        "formatting" => quote! { crate::fixer::FixCategory::Formatting },
+        "import_hygiene" => quote! { crate::fixer::FixCategory::ImportHygiene },
+        "style" => quote! { crate::fixer::FixCategory::Style },
+        "behavior" => quote! { crate::fixer::FixCategory::Behavior },
+        _ => panic!(
+            "invalid fix category: {s}. Valid fix categories are formatting, import_hygiene, style, or behavior."
+        ),
+    }
+}
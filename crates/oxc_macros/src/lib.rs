@@ -11,12 +11,13 @@ mod declare_oxc_secret;
 
 /// Macro used to declare an oxc lint rule
 ///
-/// Every lint declaration consists of 4 parts:
+/// Every lint declaration consists of 5 parts:
 ///
 /// 1. The documentation
 /// 2. The lint's struct
 /// 3. The lint's category
 /// 4. What kind of auto-fixes the lint supports
+/// 5. The type of the lint's options
 ///
 /// ## Documentation
 /// Lint rule documentation added here will be used to build documentation pages
@@ -50,6 +51,21 @@ mod declare_oxc_secret;
 /// - `dangerous_fix_dangerous_suggestion` (provides dangerous fixes and suggestions in all cases)
 ///
 /// `pending` and `none` are special cases that do not follow this pattern.
+///
+/// Rules with fixes can also declare what their fixes change, after the fix
+/// capabilities. `--fix-kind` uses this to only apply some fixes.
+/// - `formatting`: only how code is written, e.g. whitespace or escapes
+/// - `import_hygiene`: only import and export declarations
+/// - `style`: rewrites code into an equivalent form
+/// - `behavior`: may affect program behavior. This is the default.
+///
+/// ## Options
+///
+/// Rules with options can declare the type of each of their options last, e.g.
+/// `config = MaxLinesConfig`. The type must implement `JsonSchema`; its schema
+/// is used to validate the configuration of the rule and report unknown or
+/// invalid options.
+///
 /// # Example
 ///
 /// ```
@@ -1,14 +1,45 @@
 use itertools::Itertools;
-use oxc_ast::ast::Program;
+use oxc_ast::{
+    ast::{
+        AssignmentOperator, AssignmentTarget, AssignmentTargetProperty, BindingPatternKind,
+        Expression, ModuleExportName, Program, PropertyKey,
+    },
+    AstKind,
+};
 use oxc_index::{index_vec, Idx, IndexVec};
-use oxc_semantic::{ReferenceId, ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_semantic::{
+    ReferenceId, ScopeTree, Semantic, SemanticBuilder, SymbolFlags, SymbolId, SymbolTable,
+};
 use oxc_span::CompactStr;
+use rustc_hash::FxHashSet;
 
 type Slot = usize;
 
 #[derive(Default)]
 pub struct MangleOptions {
     pub debug: bool,
+
+    /// Mangle names declared in the top level scope.
+    ///
+    /// Exported bindings are never mangled.
+    ///
+    /// Default `false`
+    pub top_level: bool,
+
+    /// Names that are never mangled, and never used as a mangled name.
+    pub reserved: Vec<String>,
+
+    /// Do not mangle bindings whose name is observable through `Function.prototype.name`,
+    /// e.g. `function foo() {}` or `const foo = () => {}`.
+    ///
+    /// Default `false`
+    pub keep_fnames: bool,
+
+    /// Do not mangle bindings whose name is observable through a class's `name` property,
+    /// e.g. `class Foo {}` or `const Foo = class {}`.
+    ///
+    /// Default `false`
+    pub keep_classnames: bool,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -52,11 +83,25 @@ pub struct MangleOptions {
 ///     }
 /// }
 /// ```
+///
+/// Slots with no mangleable symbols do not consume a name, and ties in frequency are broken by
+/// slot order, so the result is deterministic.
+///
+/// Like terser, names are not taken from a fixed alphabet: characters are ordered by how often
+/// they appear in the rest of the output, so the generated names reuse characters that are
+/// already common in the file, which compresses better. In the example above, `a` stands for
+/// the first name generated from that alphabet.
 #[derive(Default)]
 pub struct Mangler {
     symbol_table: SymbolTable,
 
+    /// Number of symbols that were given a different name.
+    renamed_symbols: usize,
+
     options: MangleOptions,
+
+    /// See [`Mangler::with_frequency_text`].
+    frequency_text: Option<String>,
 }
 
 impl Mangler {
@@ -71,6 +116,18 @@ impl Mangler {
         self
     }
 
+    /// Order the characters of mangled names by how often they appear in `text`, instead of in the
+    /// source text of the program.
+    ///
+    /// Minifiers should pass the program printed without mangling, so that the names do not depend
+    /// on the comments and formatting of the source, and minifying the output again produces the
+    /// same names.
+    #[must_use]
+    pub fn with_frequency_text(mut self, text: String) -> Self {
+        self.frequency_text = Some(text);
+        self
+    }
+
     pub fn get_symbol_name(&self, symbol_id: SymbolId) -> &str {
         self.symbol_table.get_name(symbol_id)
     }
@@ -80,14 +137,35 @@ impl Mangler {
         Some(self.symbol_table.get_name(symbol_id))
     }
 
+    /// Number of symbols whose mangled name differs from their original name.
+    pub fn renamed_symbols(&self) -> usize {
+        self.renamed_symbols
+    }
+
     #[must_use]
     pub fn build<'a>(mut self, program: &'a Program<'a>) -> Mangler {
         let semantic = SemanticBuilder::new().build(program).semantic;
 
+        // Scopes in which binding names are observable at runtime.
+        let unsafe_scopes = Self::collect_unsafe_scopes(&semantic);
+
+        // Symbols whose names must be preserved because of `keep_fnames` / `keep_classnames`.
+        let named_symbols = self.collect_named_symbols(&semantic);
+
+        let shorthand_symbols = Self::collect_shorthand_symbols(&semantic);
+
+        let exported_symbols = Self::collect_exported_symbols(&semantic);
+
         // Mangle the symbol table by computing slots from the scope tree.
         // A slot is the occurrence index of a binding identifier inside a scope.
         let (mut symbol_table, scope_tree) = semantic.into_symbol_table_and_scope_tree();
 
+        // Bindings exported by `export { foo }` are only flagged as `Export` when they are
+        // declared with `export`, flag them too so that they are not mangled at the top level.
+        for symbol_id in exported_symbols {
+            symbol_table.union_flag(symbol_id, SymbolFlags::Export);
+        }
+
         // Total number of slots for all scopes
         let mut total_number_of_slots: Slot = 0;
 
@@ -123,26 +201,49 @@ impl Mangler {
             }
         }
 
+        let mangleable = self.compute_mangleable_symbols(
+            &symbol_table,
+            &scope_tree,
+            &unsafe_scopes,
+            &named_symbols,
+        );
+
         let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, &scope_tree, total_number_of_slots, &slots);
+            Self::tally_slot_frequencies(&symbol_table, total_number_of_slots, &slots, &mangleable);
+
+        let frequency_text = self.frequency_text.take();
+        let alphabet = NameAlphabet::new(
+            frequency_text.as_deref().unwrap_or(program.source_text),
+            &symbol_table,
+            &mangleable,
+            &shorthand_symbols,
+        );
 
         let root_unresolved_references = scope_tree.root_unresolved_references();
-        let root_bindings = scope_tree.get_bindings(scope_tree.root_scope_id());
 
-        let mut reserved_names = Vec::with_capacity(total_number_of_slots);
+        // Names of symbols that keep their original name, plus user reserved names.
+        let mut kept_names = symbol_table
+            .names
+            .iter_enumerated()
+            .filter(|(symbol_id, _)| !mangleable[*symbol_id])
+            .map(|(_, name)| name.as_str())
+            .collect::<FxHashSet<_>>();
+        kept_names.extend(self.options.reserved.iter().map(String::as_str));
+
+        let mut reserved_names = Vec::with_capacity(frequencies.len());
 
-        let generate_name = if self.options.debug { debug_name } else { base54 };
         let mut count = 0;
-        for _ in 0..total_number_of_slots {
+        for _ in 0..frequencies.len() {
             let name = loop {
-                let name = generate_name(count);
+                let name =
+                    if self.options.debug { debug_name(count) } else { alphabet.base54(count) };
                 count += 1;
                 // Do not mangle keywords and unresolved references
                 let n = name.as_str();
                 if !is_keyword(n)
                     && !is_special_name(n)
                     && !root_unresolved_references.contains_key(n)
-                    && !root_bindings.contains_key(n)
+                    && !kept_names.contains(n)
                 {
                     break name;
                 }
@@ -191,6 +292,9 @@ impl Mangler {
             // rename the variables
             for (symbol_to_rename, new_name) in symbols_to_rename_with_new_names {
                 for &symbol_id in &symbol_to_rename.symbol_ids {
+                    if symbol_table.get_name(symbol_id) != new_name.as_str() {
+                        self.renamed_symbols += 1;
+                    }
                     symbol_table.set_name(symbol_id, new_name.clone());
                 }
             }
@@ -200,19 +304,257 @@ impl Mangler {
         self
     }
 
-    fn tally_slot_frequencies(
+    /// Collect scopes whose bindings must keep their names.
+    ///
+    /// A direct `eval` or a `with` statement can look up any binding visible from where it
+    /// appears by name, so mangling is disabled for the enclosing scope and all of its ancestors.
+    fn collect_unsafe_scopes(semantic: &Semantic<'_>) -> Vec<bool> {
+        let scope_tree = semantic.scopes();
+        let symbol_table = semantic.symbols();
+        let mut unsafe_scopes = vec![false; scope_tree.len()];
+        for node in semantic.nodes().iter() {
+            let is_unsafe = match node.kind() {
+                AstKind::WithStatement(_) => true,
+                AstKind::CallExpression(call) => {
+                    call.callee.get_identifier_reference().is_some_and(|ident| {
+                        ident.name == "eval"
+                            && ident.reference_id.get().is_some_and(|reference_id| {
+                                symbol_table.get_reference(reference_id).symbol_id().is_none()
+                            })
+                    })
+                }
+                _ => false,
+            };
+            if !is_unsafe {
+                continue;
+            }
+            for scope_id in scope_tree.ancestors(node.scope_id()) {
+                if unsafe_scopes[scope_id.index()] {
+                    break;
+                }
+                unsafe_scopes[scope_id.index()] = true;
+            }
+        }
+        unsafe_scopes
+    }
+
+    /// Collect symbols whose names are observable at runtime through the `name` property of the
+    /// function or class they are bound to.
+    ///
+    /// This covers named functions and classes, as well as anonymous ones that get their name
+    /// from the binding they are assigned to (e.g. `const foo = () => {}`, `foo = class {}`,
+    /// `function f(foo = function () {}) {}`).
+    fn collect_named_symbols(&self, semantic: &Semantic<'_>) -> FxHashSet<SymbolId> {
+        let mut named_symbols = FxHashSet::default();
+        if !self.options.keep_fnames && !self.options.keep_classnames {
+            return named_symbols;
+        }
+        let symbol_table = semantic.symbols();
+        for node in semantic.nodes().iter() {
+            let symbol_id = match node.kind() {
+                AstKind::Function(func) if self.options.keep_fnames => {
+                    func.id.as_ref().and_then(|id| id.symbol_id.get())
+                }
+                AstKind::Class(class) if self.options.keep_classnames => {
+                    class.id.as_ref().and_then(|id| id.symbol_id.get())
+                }
+                AstKind::VariableDeclarator(decl) => match &decl.id.kind {
+                    BindingPatternKind::BindingIdentifier(id)
+                        if decl
+                            .init
+                            .as_ref()
+                            .is_some_and(|init| self.is_named_by_binding(init)) =>
+                    {
+                        id.symbol_id.get()
+                    }
+                    _ => None,
+                },
+                AstKind::AssignmentPattern(pattern) => match &pattern.left.kind {
+                    BindingPatternKind::BindingIdentifier(id)
+                        if self.is_named_by_binding(&pattern.right) =>
+                    {
+                        id.symbol_id.get()
+                    }
+                    _ => None,
+                },
+                AstKind::AssignmentExpression(assign)
+                    if (assign.operator == AssignmentOperator::Assign
+                        || assign.operator.is_logical())
+                        && self.is_named_by_binding(&assign.right) =>
+                {
+                    Self::get_assignment_target_symbol(&assign.left, symbol_table)
+                }
+                AstKind::AssignmentTargetWithDefault(target)
+                    if self.is_named_by_binding(&target.init) =>
+                {
+                    Self::get_assignment_target_symbol(&target.binding, symbol_table)
+                }
+                _ => None,
+            };
+            if let Some(symbol_id) = symbol_id {
+                named_symbols.insert(symbol_id);
+            }
+        }
+        named_symbols
+    }
+
+    /// Collect the symbols of shorthands such as `{ foo }`, once per shorthand.
+    ///
+    /// The name of a shorthand is printed once, but it is also the property key or module export
+    /// name, which is still printed after the symbol is renamed, e.g. `{ foo: a }`.
+    fn collect_shorthand_symbols(semantic: &Semantic<'_>) -> Vec<SymbolId> {
+        let symbol_table = semantic.symbols();
+        let reference_symbol = |reference_id: Option<ReferenceId>| {
+            reference_id
+                .and_then(|reference_id| symbol_table.get_reference(reference_id).symbol_id())
+        };
+        let mut shorthand_symbols = vec![];
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::ObjectProperty(prop) => {
+                    if let (PropertyKey::StaticIdentifier(key), Expression::Identifier(ident)) =
+                        (&prop.key, prop.value.without_parentheses())
+                    {
+                        if key.name == ident.name {
+                            shorthand_symbols.extend(reference_symbol(ident.reference_id.get()));
+                        }
+                    }
+                }
+                AstKind::ObjectPattern(pattern) => {
+                    for prop in &pattern.properties {
+                        let PropertyKey::StaticIdentifier(key) = &prop.key else {
+                            continue;
+                        };
+                        let ident = match &prop.value.kind {
+                            BindingPatternKind::BindingIdentifier(ident) => ident,
+                            BindingPatternKind::AssignmentPattern(pattern) => {
+                                let BindingPatternKind::BindingIdentifier(ident) =
+                                    &pattern.left.kind
+                                else {
+                                    continue;
+                                };
+                                ident
+                            }
+                            _ => continue,
+                        };
+                        if key.name == ident.name {
+                            shorthand_symbols.extend(ident.symbol_id.get());
+                        }
+                    }
+                }
+                AstKind::ObjectAssignmentTarget(target) => {
+                    for prop in &target.properties {
+                        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) =
+                            prop
+                        {
+                            shorthand_symbols
+                                .extend(reference_symbol(prop.binding.reference_id.get()));
+                        }
+                    }
+                }
+                AstKind::ImportSpecifier(specifier) => {
+                    if specifier.imported.name() == specifier.local.name {
+                        shorthand_symbols.extend(specifier.local.symbol_id.get());
+                    }
+                }
+                AstKind::ExportSpecifier(specifier) => {
+                    if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
+                        if specifier.exported.name() == ident.name {
+                            shorthand_symbols.extend(reference_symbol(ident.reference_id.get()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        shorthand_symbols
+    }
+
+    /// Collect the local symbols of export specifiers, e.g. `foo` of `export { foo as bar }`.
+    fn collect_exported_symbols(semantic: &Semantic<'_>) -> Vec<SymbolId> {
+        let symbol_table = semantic.symbols();
+        semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| {
+                let AstKind::ExportSpecifier(specifier) = node.kind() else {
+                    return None;
+                };
+                // Re-exports such as `export { foo } from 'mod'` have no reference.
+                let ModuleExportName::IdentifierReference(ident) = &specifier.local else {
+                    return None;
+                };
+                symbol_table.get_reference(ident.reference_id.get()?).symbol_id()
+            })
+            .collect()
+    }
+
+    /// Returns `true` if `expr` is an anonymous function or class whose `name` is taken from the
+    /// binding it is assigned to, and that name needs to be kept.
+    fn is_named_by_binding(&self, expr: &Expression<'_>) -> bool {
+        match expr.without_parentheses() {
+            Expression::FunctionExpression(func) => self.options.keep_fnames && func.id.is_none(),
+            Expression::ArrowFunctionExpression(_) => self.options.keep_fnames,
+            Expression::ClassExpression(class) => {
+                self.options.keep_classnames && class.id.is_none()
+            }
+            _ => false,
+        }
+    }
+
+    fn get_assignment_target_symbol(
+        target: &AssignmentTarget<'_>,
+        symbol_table: &SymbolTable,
+    ) -> Option<SymbolId> {
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = target else {
+            return None;
+        };
+        symbol_table.get_reference(ident.reference_id.get()?).symbol_id()
+    }
+
+    fn compute_mangleable_symbols(
+        &self,
         symbol_table: &SymbolTable,
         scope_tree: &ScopeTree,
+        unsafe_scopes: &[bool],
+        named_symbols: &FxHashSet<SymbolId>,
+    ) -> IndexVec<SymbolId, bool> {
+        let root_scope_id = scope_tree.root_scope_id();
+        symbol_table
+            .names
+            .iter_enumerated()
+            .map(|(symbol_id, name)| {
+                let scope_id = symbol_table.get_scope_id(symbol_id);
+                if unsafe_scopes[scope_id.index()]
+                    || is_special_name(name)
+                    || named_symbols.contains(&symbol_id)
+                {
+                    return false;
+                }
+                if scope_id == root_scope_id
+                    && (!self.options.top_level
+                        || symbol_table.get_flags(symbol_id).contains(SymbolFlags::Export))
+                {
+                    return false;
+                }
+                !self.options.reserved.iter().any(|reserved| reserved == name.as_str())
+            })
+            .collect()
+    }
+
+    /// Count references to the mangleable symbols of each slot.
+    ///
+    /// Slots without mangleable symbols are dropped. The result is sorted by frequency from
+    /// highest to lowest, then by slot.
+    fn tally_slot_frequencies(
+        symbol_table: &SymbolTable,
         total_number_of_slots: usize,
         slots: &IndexVec<SymbolId, Slot>,
+        mangleable: &IndexVec<SymbolId, bool>,
     ) -> Vec<SlotFrequency> {
-        let root_scope_id = scope_tree.root_scope_id();
         let mut frequencies = vec![SlotFrequency::default(); total_number_of_slots];
         for (symbol_id, slot) in slots.iter_enumerated() {
-            if symbol_table.get_scope_id(symbol_id) == root_scope_id {
-                continue;
-            }
-            if is_special_name(symbol_table.get_name(symbol_id)) {
+            if !mangleable[symbol_id] {
                 continue;
             }
             let index = *slot;
@@ -221,7 +563,8 @@ impl Mangler {
                 symbol_table.get_resolved_reference_ids(symbol_id).len();
             frequencies[index].symbol_ids.push(symbol_id);
         }
-        frequencies.sort_unstable_by_key(|x| std::cmp::Reverse(x.frequency));
+        frequencies.retain(|x| !x.symbol_ids.is_empty());
+        frequencies.sort_unstable_by_key(|x| (std::cmp::Reverse(x.frequency), x.slot));
         frequencies
     }
 }
@@ -247,25 +590,79 @@ fn is_keyword(s: &str) -> bool {
 
 const BASE54_CHARS: &[u8; 64] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
 
-/// Get the shortest mangled name for a given n.
-/// Code adapted from [terser](https://github.com/terser/terser/blob/8b966d687395ab493d2c6286cc9dd38650324c11/lib/scope.js#L1041-L1051)
-fn base54(n: usize) -> CompactStr {
-    let mut num = n;
-    // Base 54 at first because these are the usable first characters in JavaScript identifiers
-    // <https://tc39.es/ecma262/#prod-IdentifierStart>
-    let base = 54usize;
-    let mut ret = String::new();
-    ret.push(BASE54_CHARS[num % base] as char);
-    num /= base;
-    // Base 64 for the rest because after the first character we can also use 0-9 too
-    // <https://tc39.es/ecma262/#prod-IdentifierPart>
-    let base = 64usize;
-    while num > 0 {
-        num -= 1;
-        ret.push(BASE54_CHARS[num % base] as char);
+/// The characters used for mangled names, ordered by how often they appear in the output.
+///
+/// Frequencies are counted over the source text, or the text passed to
+/// [`Mangler::with_frequency_text`], minus the names of symbols that are about to be mangled (once
+/// for the declaration and once per reference, except for the key of shorthands which is still
+/// printed). Ties keep the order of [`BASE54_CHARS`] so the alphabet is deterministic.
+/// See [terser](https://github.com/terser/terser/blob/8b966d687395ab493d2c6286cc9dd38650324c11/lib/scope.js#L1005-L1039)
+struct NameAlphabet {
+    /// Characters allowed at the start of an identifier.
+    leading: [u8; 54],
+    /// All characters allowed in an identifier.
+    trailing: [u8; 64],
+}
+
+impl NameAlphabet {
+    fn new(
+        source_text: &str,
+        symbol_table: &SymbolTable,
+        mangleable: &IndexVec<SymbolId, bool>,
+        shorthand_symbols: &[SymbolId],
+    ) -> Self {
+        let mut frequencies = [0usize; 256];
+        for byte in source_text.bytes() {
+            frequencies[usize::from(byte)] += 1;
+        }
+        let mut shorthands = index_vec![0usize; symbol_table.len()];
+        for symbol_id in shorthand_symbols {
+            shorthands[*symbol_id] += 1;
+        }
+        for (symbol_id, name) in symbol_table.names.iter_enumerated() {
+            if !mangleable[symbol_id] {
+                continue;
+            }
+            let uses = (symbol_table.get_resolved_reference_ids(symbol_id).len() + 1)
+                .saturating_sub(shorthands[symbol_id]);
+            for byte in name.bytes() {
+                let frequency = &mut frequencies[usize::from(byte)];
+                *frequency = frequency.saturating_sub(uses);
+            }
+        }
+
+        let mut trailing = *BASE54_CHARS;
+        // stable sort, so ties keep their original order
+        trailing.sort_by_key(|&c| std::cmp::Reverse(frequencies[usize::from(c)]));
+
+        let mut leading = [0; 54];
+        for (slot, c) in leading.iter_mut().zip(trailing.iter().filter(|c| !c.is_ascii_digit())) {
+            *slot = *c;
+        }
+
+        Self { leading, trailing }
+    }
+
+    /// Get the shortest mangled name for a given n.
+    /// Code adapted from [terser](https://github.com/terser/terser/blob/8b966d687395ab493d2c6286cc9dd38650324c11/lib/scope.js#L1041-L1051)
+    fn base54(&self, n: usize) -> CompactStr {
+        let mut num = n;
+        // Base 54 at first because these are the usable first characters in JavaScript identifiers
+        // <https://tc39.es/ecma262/#prod-IdentifierStart>
+        let base = 54usize;
+        let mut ret = String::new();
+        ret.push(self.leading[num % base] as char);
         num /= base;
+        // Base 64 for the rest because after the first character we can also use 0-9 too
+        // <https://tc39.es/ecma262/#prod-IdentifierPart>
+        let base = 64usize;
+        while num > 0 {
+            num -= 1;
+            ret.push(self.trailing[num % base] as char);
+            num /= base;
+        }
+        CompactStr::new(&ret)
     }
-    CompactStr::new(&ret)
 }
 
 fn debug_name(n: usize) -> CompactStr {
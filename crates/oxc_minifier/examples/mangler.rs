@@ -17,17 +17,18 @@ fn main() -> std::io::Result<()> {
 
     let name = args.subcommand().ok().flatten().unwrap_or_else(|| String::from("test.js"));
     let debug = args.contains("--debug");
+    let top_level = args.contains("--top-level");
     let twice = args.contains("--twice");
 
     let path = Path::new(&name);
     let source_text = std::fs::read_to_string(path)?;
     let source_type = SourceType::from_path(path).unwrap();
 
-    let printed = mangler(&source_text, source_type, debug);
+    let printed = mangler(&source_text, source_type, debug, top_level);
     println!("{printed}");
 
     if twice {
-        let printed2 = mangler(&printed, source_type, debug);
+        let printed2 = mangler(&printed, source_type, debug, top_level);
         println!("{printed2}");
         println!("same = {}", printed == printed2);
     }
@@ -35,9 +36,10 @@ fn main() -> std::io::Result<()> {
     Ok(())
 }
 
-fn mangler(source_text: &str, source_type: SourceType, debug: bool) -> String {
+fn mangler(source_text: &str, source_type: SourceType, debug: bool, top_level: bool) -> String {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
-    let mangler = Mangler::new().with_options(MangleOptions { debug }).build(&ret.program);
+    let options = MangleOptions { debug, top_level, ..MangleOptions::default() };
+    let mangler = Mangler::new().with_options(options).build(&ret.program);
     CodeGenerator::new().with_mangler(Some(mangler)).build(&ret.program).code
 }
@@ -3,7 +3,7 @@ use std::path::Path;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::{CodeGenerator, CodegenOptions};
-use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
+use oxc_minifier::{CompressOptions, Minifier, MinifierMetafile, MinifierOptions};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 use pico_args::Arguments;
@@ -19,18 +19,57 @@ fn main() -> std::io::Result<()> {
     let mangle = args.contains("--mangle");
     let nospace = args.contains("--nospace");
     let twice = args.contains("--twice");
+    let keep_names = args.contains("--keep-names");
+    let stats = args.contains("--stats");
+    let meta = args.contains("--meta");
 
     let path = Path::new(&name);
     let source_text = std::fs::read_to_string(path)?;
     let source_type = SourceType::from_path(path).unwrap();
 
     let mut allocator = Allocator::default();
-    let printed = minify(&allocator, &source_text, source_type, mangle, nospace);
+    let printed = minify(&allocator, &source_text, source_type, mangle, nospace, keep_names);
     println!("{printed}");
 
+    if stats {
+        allocator.reset();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        let mut program = ret.program;
+        let options = MinifierOptions {
+            mangle,
+            compress: CompressOptions::default(),
+            keep_fnames: keep_names,
+            keep_classnames: keep_names,
+        };
+        let stats =
+            Minifier::new(options).build_with_stats(&allocator, &mut program).stats.unwrap();
+        println!("original:   {} bytes", stats.original_size);
+        println!("compressed: {} bytes", stats.compressed_size);
+        println!("mangled:    {} bytes", stats.mangled_size);
+        println!("gzip:       {} bytes", stats.gzip_size);
+        println!("removed statements: {}", stats.removed_statements);
+        println!("renamed symbols:    {}", stats.renamed_symbols);
+    }
+
+    if meta {
+        allocator.reset();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        let mut program = ret.program;
+        let options = MinifierOptions {
+            mangle,
+            compress: CompressOptions::default(),
+            keep_fnames: keep_names,
+            keep_classnames: keep_names,
+        };
+        let meta = Minifier::new(options).build_with_meta(&allocator, &mut program).meta.unwrap();
+        let mut metafile = MinifierMetafile::default();
+        metafile.add(name.clone(), meta);
+        println!("{}", metafile.to_json());
+    }
+
     if twice {
         allocator.reset();
-        let printed2 = minify(&allocator, &printed, source_type, mangle, nospace);
+        let printed2 = minify(&allocator, &printed, source_type, mangle, nospace, keep_names);
         println!("{printed2}");
         println!("same = {}", printed == printed2);
     }
@@ -44,10 +83,16 @@ fn minify(
     source_type: SourceType,
     mangle: bool,
     nospace: bool,
+    keep_names: bool,
 ) -> String {
     let ret = Parser::new(allocator, source_text, source_type).parse();
     let mut program = ret.program;
-    let options = MinifierOptions { mangle, compress: CompressOptions::default() };
+    let options = MinifierOptions {
+        mangle,
+        compress: CompressOptions::default(),
+        keep_fnames: keep_names,
+        keep_classnames: keep_names,
+    };
     let ret = Minifier::new(options).build(allocator, &mut program);
     CodeGenerator::new()
         .with_options(CodegenOptions { minify: nospace, ..CodegenOptions::default() })
@@ -0,0 +1,559 @@
+use std::borrow::Cow;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{CloneIn, Vec};
+use oxc_ast::ast::*;
+use oxc_ecmascript::ToJsString;
+use oxc_semantic::{ObjectImmutability, ReferenceFlags, SymbolFlags, SymbolId};
+use oxc_span::GetSpan;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::{
+    node_util::{can_be_shorthand, Ctx},
+    CompressorPass,
+};
+
+/// Propagate constants and copies across statements.
+///
+/// * `const x = 1; f(x)` -> `f(1)`
+/// * `let x = 'str'; f(x)` -> `f('str')` when `x` is read once
+/// * `const y = x; f(y)` -> `f(x)` when `x` is never written
+/// * `const o = { a: 1 }; f(o.a)` -> `f(1)` when the object is immutable
+/// * `const a = 'a'; const b = a + 'b'; f(b)` -> `f('ab')` when `b` is read once
+///
+/// Only `let` and `const` bindings that are never written are propagated,
+/// and only into references that appear after the declaration.
+/// A declaration is removed once all of its references are replaced.
+///
+/// Terser option: `reduce_vars: true`.
+pub struct ConstantPropagation<'a> {
+    is_module: bool,
+
+    /// Objects whose properties never change, so that reads of their properties can be folded.
+    immutable_objects: ObjectImmutability,
+
+    /// Values of bindings that are propagated,
+    /// and the end of their declaration.
+    values: FxHashMap<SymbolId, (u32, Value<'a>)>,
+
+    /// Values of bindings to constant strings, e.g. `a` and `b` in
+    /// `const a = 'a'; const b = `${a}b``, and the end of their declaration.
+    strings: FxHashMap<SymbolId, (u32, Atom<'a>)>,
+
+    changed: bool,
+}
+
+enum Value<'a> {
+    Literal(Expression<'a>),
+    /// A copy of another binding.
+    Copy(Atom<'a>, SymbolId),
+    /// An immutable object or array, with the values of its properties.
+    Object(FxHashMap<Cow<'a, str>, Expression<'a>>),
+}
+
+impl<'a> CompressorPass<'a> for ConstantPropagation<'a> {
+    fn changed(&self) -> bool {
+        self.changed
+    }
+
+    fn build(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.changed = false;
+        self.is_module = program.source_type.is_module();
+        self.values.clear();
+        self.strings.clear();
+        oxc_traverse::walk_program(self, program, ctx);
+    }
+}
+
+impl<'a> Traverse<'a> for ConstantPropagation<'a> {
+    fn exit_variable_declarator(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.fold_string_binding(decl, ctx);
+        if let Some((symbol_id, value)) = self.propagatable_value(decl, Ctx(ctx)) {
+            self.values.insert(symbol_id, (decl.span.end, value));
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.try_fold_property_read(expr, ctx) {
+            return;
+        }
+        let Expression::Identifier(ident) = expr else { return };
+        let Some(symbol_id) = ctx.symbols().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        let Some((declaration_end, value)) = self.values.get(&symbol_id) else { return };
+        if ident.span.start < *declaration_end {
+            return;
+        }
+        let new_expr = match value {
+            Value::Literal(value) => value.clone_in(ctx.ast.allocator),
+            Value::Copy(name, copied_symbol_id) => {
+                // `const y = x; function g(x) { f(y) }`
+                if ctx.scopes().find_binding(ctx.current_scope_id(), name)
+                    != Some(*copied_symbol_id)
+                {
+                    return;
+                }
+                let copied = ctx.create_bound_reference_id(
+                    ident.span,
+                    name.clone(),
+                    *copied_symbol_id,
+                    ReferenceFlags::Read,
+                );
+                Expression::Identifier(ctx.ast.alloc(copied))
+            }
+            // Only the properties of objects are propagated.
+            Value::Object(_) => return,
+        };
+        ctx.delete_reference_for_identifier(ident);
+        *expr = new_expr;
+        self.changed = true;
+    }
+
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, _ctx: &mut TraverseCtx<'a>) {
+        // `{ x }` -> `{ x: 1 }`
+        if prop.shorthand && !can_be_shorthand(prop) {
+            prop.shorthand = false;
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if self.values.is_empty() && self.strings.is_empty() {
+            return;
+        }
+        for stmt in stmts.iter_mut() {
+            let Statement::VariableDeclaration(decl) = stmt else { continue };
+            decl.declarations.retain(|declarator| {
+                if !self.is_fully_propagated(declarator, ctx) {
+                    return true;
+                }
+                if let Some(Expression::Identifier(ident)) = &declarator.init {
+                    ctx.delete_reference_for_identifier(ident);
+                }
+                false
+            });
+        }
+        let len = stmts.len();
+        stmts.retain(|stmt| {
+            !matches!(stmt, Statement::VariableDeclaration(decl) if decl.declarations.is_empty())
+        });
+        if stmts.len() != len {
+            self.changed = true;
+        }
+    }
+}
+
+impl<'a> ConstantPropagation<'a> {
+    pub fn new(immutable_objects: ObjectImmutability) -> Self {
+        Self {
+            is_module: false,
+            immutable_objects,
+            values: FxHashMap::default(),
+            strings: FxHashMap::default(),
+            changed: false,
+        }
+    }
+
+    /// `const a = 'a'; const b = a + 'b'; f(b)` -> `const a = 'a'; const b = 'ab'; f(b)`
+    ///
+    /// The string is only folded into bindings read once, which are then propagated, as it can be
+    /// longer than the concatenation. Bindings to constant strings are recorded, so that they can
+    /// be folded into later concatenations, and are removed once they are no longer read.
+    fn fold_string_binding(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !matches!(decl.kind, VariableDeclarationKind::Const | VariableDeclarationKind::Let) {
+            return;
+        }
+        let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return };
+        let symbol_id = ident.symbol_id();
+        let Some(init) = &mut decl.init else { return };
+        if Self::has_writes(symbol_id, Ctx(ctx)) {
+            return;
+        }
+        let Some(value) = self.eval_string(init, Ctx(ctx)) else { return };
+        let value = ctx.ast.atom(&value);
+        if !matches!(init, Expression::StringLiteral(_))
+            && ctx.symbols().get_resolved_reference_ids(symbol_id).len() == 1
+        {
+            Self::delete_references(init, ctx);
+            *init = ctx.ast.expression_string_literal(init.span(), value.clone());
+            self.changed = true;
+        }
+        self.strings.insert(symbol_id, (decl.span.end, value));
+    }
+
+    /// The value of a concatenation of constant strings, e.g. `a + 'b'` or `` `${a}b` `` for
+    /// `const a = 'a'`.
+    fn eval_string(&self, expr: &Expression<'a>, ctx: Ctx<'a, '_>) -> Option<Cow<'a, str>> {
+        match expr {
+            Expression::StringLiteral(lit) => Some(Cow::Borrowed(lit.value.as_str())),
+            Expression::Identifier(ident) => {
+                let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id()?;
+                let (declaration_end, value) = self.strings.get(&symbol_id)?;
+                (ident.span.start >= *declaration_end).then(|| Cow::Borrowed(value.as_str()))
+            }
+            Expression::ParenthesizedExpression(e) => self.eval_string(&e.expression, ctx),
+            Expression::TemplateLiteral(lit) => {
+                let mut value = String::new();
+                for (i, quasi) in lit.quasis.iter().enumerate() {
+                    value.push_str(quasi.value.cooked.as_ref()?);
+                    if let Some(expr) = lit.expressions.get(i) {
+                        let expr_value =
+                            self.eval_string(expr, ctx).or_else(|| Self::primitive_string(expr))?;
+                        value.push_str(&expr_value);
+                    }
+                }
+                Some(Cow::Owned(value))
+            }
+            // `1 + 2 + 'a'` is `'3a'`, so one of the operands must be a string.
+            Expression::BinaryExpression(e) if e.operator == BinaryOperator::Addition => {
+                let (left, right) =
+                    match (self.eval_string(&e.left, ctx), self.eval_string(&e.right, ctx)) {
+                        (Some(left), Some(right)) => (left, right),
+                        (Some(left), None) => (left, Self::primitive_string(&e.right)?),
+                        (None, Some(right)) => (Self::primitive_string(&e.left)?, right),
+                        (None, None) => return None,
+                    };
+                Some(Cow::Owned(left.into_owned() + &right))
+            }
+            _ => None,
+        }
+    }
+
+    /// `1`, `true` or `null` converted to a string.
+    fn primitive_string(expr: &Expression<'a>) -> Option<Cow<'a, str>> {
+        match expr {
+            Expression::NumericLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_) => expr.to_js_string(),
+            _ => None,
+        }
+    }
+
+    /// Delete the references in a concatenation folded by [`Self::eval_string`].
+    fn delete_references(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::Identifier(ident) => ctx.delete_reference_for_identifier(ident),
+            Expression::ParenthesizedExpression(e) => Self::delete_references(&e.expression, ctx),
+            Expression::TemplateLiteral(lit) => {
+                for expr in &lit.expressions {
+                    Self::delete_references(expr, ctx);
+                }
+            }
+            Expression::BinaryExpression(e) => {
+                Self::delete_references(&e.left, ctx);
+                Self::delete_references(&e.right, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// `const o = { a: 1 }; f(o.a)` -> `f(1)`
+    fn try_fold_property_read(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        let Some(member) = expr.as_member_expression() else { return false };
+        let Expression::Identifier(ident) = member.object() else { return false };
+        // `delete o.a` is `false` for frozen objects.
+        if matches!(ctx.parent(), Ancestor::UnaryExpressionArgument(e)
+            if *e.operator() == UnaryOperator::Delete)
+        {
+            return false;
+        }
+        let Some(symbol_id) = ctx.symbols().get_reference(ident.reference_id()).symbol_id() else {
+            return false;
+        };
+        let Some((declaration_end, Value::Object(properties))) = self.values.get(&symbol_id) else {
+            return false;
+        };
+        if ident.span.start < *declaration_end {
+            return false;
+        }
+        let name = match member {
+            MemberExpression::StaticMemberExpression(e) => Cow::Borrowed(e.property.name.as_str()),
+            MemberExpression::ComputedMemberExpression(e) => match &e.expression {
+                Expression::StringLiteral(lit) => Cow::Borrowed(lit.value.as_str()),
+                Expression::NumericLiteral(lit) => Cow::Owned(lit.value.to_string()),
+                _ => return false,
+            },
+            MemberExpression::PrivateFieldExpression(_) => return false,
+        };
+        let Some(value) = properties.get(&name) else { return false };
+        let value = value.clone_in(ctx.ast.allocator);
+        ctx.delete_reference_for_identifier(ident);
+        *expr = value;
+        self.changed = true;
+        true
+    }
+
+    /// Get the value to propagate for the binding declared by `decl`.
+    fn propagatable_value(
+        &self,
+        decl: &VariableDeclarator<'a>,
+        ctx: Ctx<'a, '_>,
+    ) -> Option<(SymbolId, Value<'a>)> {
+        if !matches!(decl.kind, VariableDeclarationKind::Const | VariableDeclarationKind::Let) {
+            return None;
+        }
+        let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return None };
+        let init = decl.init.as_ref()?;
+        let symbol_id = ident.symbol_id();
+        if Self::has_writes(symbol_id, ctx) {
+            return None;
+        }
+        let reference_count = ctx.symbols().get_resolved_reference_ids(symbol_id).len();
+        if reference_count == 0 {
+            return None;
+        }
+        if self.immutable_objects.is_immutable(symbol_id) {
+            return Self::object_properties(init, reference_count, ctx)
+                .map(|properties| (symbol_id, Value::Object(properties)));
+        }
+        let propagate = match init {
+            Expression::Identifier(copied) => {
+                let copied_symbol_id =
+                    ctx.symbols().get_reference(copied.reference_id()).symbol_id()?;
+                let propagate = copied_symbol_id != symbol_id
+                    && !Self::has_writes(copied_symbol_id, ctx)
+                    // Reading the binding before its declaration is a TDZ error.
+                    && ctx.symbols().get_span(copied_symbol_id).end <= decl.span.start;
+                return propagate
+                    .then(|| (symbol_id, Value::Copy(copied.name.clone(), copied_symbol_id)));
+            }
+            _ => Self::is_propagatable_literal(init, reference_count, ctx),
+        };
+        propagate.then(|| (symbol_id, Value::Literal(init.clone_in(ctx.ast.allocator))))
+    }
+
+    fn is_propagatable_literal(
+        expr: &Expression<'a>,
+        reference_count: usize,
+        ctx: Ctx<'a, '_>,
+    ) -> bool {
+        match expr {
+            Expression::NumericLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_) => true,
+            Expression::UnaryExpression(e) => {
+                (e.operator == UnaryOperator::UnaryNegation
+                    && matches!(e.argument, Expression::NumericLiteral(_)))
+                    || ctx.is_expression_undefined(expr)
+            }
+            // Strings and bigints can make the output larger when duplicated.
+            Expression::StringLiteral(_) | Expression::BigIntLiteral(_) => reference_count == 1,
+            _ => false,
+        }
+    }
+
+    /// Get the values of the properties of an immutable object, e.g. `{ a: 1 }` or
+    /// `Object.freeze([1, 2])`.
+    ///
+    /// All values must be literals, so that the declaration can be removed once all reads are
+    /// folded.
+    fn object_properties(
+        init: &Expression<'a>,
+        reference_count: usize,
+        ctx: Ctx<'a, '_>,
+    ) -> Option<FxHashMap<Cow<'a, str>, Expression<'a>>> {
+        let init = match init {
+            // `Object.freeze(...)`
+            Expression::CallExpression(call) => call.arguments.first()?.as_expression()?,
+            init => init,
+        };
+        let mut properties = FxHashMap::default();
+        match init {
+            Expression::ObjectExpression(object) => {
+                for property in &object.properties {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        return None;
+                    };
+                    if property.kind != PropertyKind::Init
+                        || !Self::is_propagatable_literal(&property.value, reference_count, ctx)
+                    {
+                        return None;
+                    }
+                    let name = property.key.static_name()?;
+                    // `{ __proto__: null }` sets the prototype.
+                    if !property.computed && name == "__proto__" {
+                        continue;
+                    }
+                    properties.insert(name, property.value.clone_in(ctx.ast.allocator));
+                }
+            }
+            Expression::ArrayExpression(array) => {
+                for (index, element) in array.elements.iter().enumerate() {
+                    let element = element.as_expression()?;
+                    if !Self::is_propagatable_literal(element, reference_count, ctx) {
+                        return None;
+                    }
+                    properties
+                        .insert(Cow::Owned(index.to_string()), element.clone_in(ctx.ast.allocator));
+                }
+                #[allow(clippy::cast_precision_loss)]
+                let length = array.elements.len() as f64;
+                properties.insert(
+                    Cow::Borrowed("length"),
+                    ctx.ast.expression_numeric_literal(array.span, length, "", NumberBase::Decimal),
+                );
+            }
+            _ => return None,
+        }
+        Some(properties)
+    }
+
+    fn has_writes(symbol_id: SymbolId, ctx: Ctx<'a, '_>) -> bool {
+        ctx.symbols()
+            .get_resolved_reference_ids(symbol_id)
+            .iter()
+            .any(|reference_id| ctx.symbols().get_reference(*reference_id).is_write())
+    }
+
+    /// Whether all references to the binding declared by `declarator` have been replaced.
+    fn is_fully_propagated(
+        &self,
+        declarator: &VariableDeclarator<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return false;
+        };
+        let symbol_id = ident.symbol_id();
+        if !(self.values.contains_key(&symbol_id) || self.strings.contains_key(&symbol_id))
+            || !ctx.symbols().get_resolved_reference_ids(symbol_id).is_empty()
+            || ctx.symbols().get_flags(symbol_id).contains(SymbolFlags::Export)
+        {
+            return false;
+        }
+        // Top level bindings of a script are visible to other scripts.
+        self.is_module || ctx.symbols().get_scope_id(symbol_id) != ctx.scopes().root_scope_id()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::{ObjectImmutability, SemanticBuilder};
+    use oxc_span::SourceType;
+
+    use crate::tester;
+
+    fn test(source_text: &str, expected: &str) {
+        let allocator = Allocator::default();
+        let mut pass = super::ConstantPropagation::new(immutable_objects(source_text));
+        tester::test(&allocator, source_text, expected, &mut pass);
+    }
+
+    /// The immutable objects of `source_text`, parsed like [`tester::test`] does.
+    fn immutable_objects(source_text: &str) -> ObjectImmutability {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        ObjectImmutability::new(&SemanticBuilder::new().build(&program).semantic)
+    }
+
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn constants() {
+        test("const x = 1; f(x)", "f(1)");
+        test("const x = 1; f(x, x)", "f(1, 1)");
+        test("let x = true; f(x)", "f(true)");
+        test("const x = null; f(x)", "f(null)");
+        test("const x = -1; f(x)", "f(-1)");
+        test("const x = void 0; f(x)", "f(void 0)");
+        test("const x = 1; f({ x })", "f({ x: 1 })");
+        test("const x = 1; function g() { return x }", "function g() { return 1 }");
+        test("{ const x = 1; f(x) } f(x)", "{ f(1) } f(x)");
+    }
+
+    #[test]
+    fn single_use() {
+        test("const x = 'str'; f(x)", "f('str')");
+        test("const x = 1n; f(x)", "f(1n)");
+        test_same("const x = 'str'; f(x, x)");
+    }
+
+    #[test]
+    fn copies() {
+        test("const x = a(); const y = x; f(y)", "const x = a(); f(x)");
+        test("let x = a(); let y = x; f(y)", "let x = a(); f(x)");
+        test("const x = a(); const y = x; f({ y })", "const x = a(); f({ y: x })");
+        test_same("let x = a(); const y = x; x = 1; f(y)");
+        test_same("const y = x; f(y)");
+        test_same("const x = a(); const y = x; function g(x) { f(y) }");
+    }
+
+    #[test]
+    fn string_concatenation() {
+        test("const a = 'a'; const b = a + 'b'; f(b)", "f('ab')");
+        test("const a = 'a'; const b = a + 'b'; const c = a + 'c'; f(b, c)", "f('ab', 'ac')");
+        test("const a = 'a'; const b = `${a}-${1}`; f(b, a)", "const a = 'a'; f('a-1', a)");
+        test("const a = 'a'; const b = a + 1 + true; f(b, a)", "const a = 'a'; f('a1true', a)");
+        test("const a = 'a'; const b = a + 'b'; f(b); g(a)", "const a = 'a'; f('ab'); g(a)");
+        test(
+            "function g() { const a = 'a'; const b = a + 'b'; return [a, b] }",
+            "function g() { const a = 'a'; return [a, 'ab'] }",
+        );
+        test_same("const a = 'a'; const b = a + 'b'; f(a, b, b)");
+        test_same("const a = 'a'; const b = 1 + 2 + a; f(a, b)");
+        test_same("const a = 'a'; const b = a + x; f(a, b)");
+        test_same("let a = 'a'; a = 'c'; const b = a + 'b'; f(b)");
+        test_same("const b = a + 'b'; const a = 'a'; f(a, b)");
+        test_same("const a = 'a'; const b = `${a}${{}}`; f(a, b)");
+    }
+
+    #[test]
+    fn property_reads() {
+        test("const o = { a: 1, b: true }; f(o.a, o.b)", "f(1, true)");
+        test("const o = { a: 1, 'b-c': null }; f(o['a'], o['b-c'])", "f(1, null)");
+        test("const o = { a: 'str' }; f(o.a)", "f('str')");
+        test("const o = [1, 2]; f(o[0], o[1], o.length)", "f(1, 2, 2)");
+        test("const o = Object.freeze({ a: 1 }); f(o.a)", "f(1)");
+        test(
+            "const o = Object.freeze({ __proto__: null, a: 1 }); f(o.a); g(o)",
+            "const o = Object.freeze({ __proto__: null, a: 1 }); f(1); g(o)",
+        );
+        test("function g() { const o = { a: 1 }; return o.a }", "function g() { return 1 }");
+        test("const o = { a: 1 }; f(o.a, o.b)", "const o = { a: 1 }; f(1, o.b)");
+    }
+
+    #[test]
+    fn property_reads_bail() {
+        test_same("const o = { a: 1 }; o.a = 2; f(o.a)");
+        test_same("const o = { a: 1 }; g(o); f(o.a)");
+        test_same("const o = { a: 'str' }; f(o.a, o.a)");
+        test_same("const o = { a: g() }; f(o.a)");
+        test_same("const o = { a: 1, get b() { return 2 } }; f(o.a)");
+        test_same("const o = { a: 1, ...p }; f(o.a)");
+        test_same("const o = [1, , 2]; f(o[0])");
+        test_same("let o = { a: 1 }; f(o.a)");
+        test_same("f(o.a); const o = { a: 1 }");
+        test_same("const o = Object.freeze({ a: 1 }); f(delete o.a)");
+        test_same("export const o = { a: 1 }; f(o.a)");
+    }
+
+    #[test]
+    fn bail() {
+        test_same("var x = 1; f(x)");
+        test_same("let x = 1; x = 2; f(x)");
+        test_same("let x = 1; x++; f(x)");
+        test_same("const x = {}; f(x)");
+        test_same("const x = a(); f(x)");
+        test_same("f(x); const x = 1");
+        test_same("const x = 1; export { x }");
+        test("export const x = 1; f(x)", "export const x = 1; f(1)");
+    }
+}
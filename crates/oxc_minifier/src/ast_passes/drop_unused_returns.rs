@@ -0,0 +1,331 @@
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_semantic::{ReferenceId, ScopeId, ScopeTree, SymbolFlags, SymbolId, SymbolTable};
+use oxc_syntax::scope::ScopeFlags;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::CompressorPass;
+
+/// Drop the return values of functions whose calls never use them.
+///
+/// * `function f() { log(); return x } f()` -> `function f() { log(); return } f()`
+/// * `function f() { return log() } f()` -> `function f() { log() } f()`
+///
+/// A function qualifies when it does not escape: every reference to it is a direct call in a void
+/// position, i.e. an expression statement or the operand of `void`, or the callback passed to
+/// `forEach`. Functions passed to `forEach` directly also qualify:
+///
+/// * `a.forEach(x => { return log(x) })` -> `a.forEach(x => { log(x) })`
+///
+/// `forEach` is assumed to be the built-in method, which ignores the return values of the
+/// callback, see [`CompressOptions::unused_returns`](crate::CompressOptions::unused_returns).
+/// Async functions and generators are kept, as their return values are observable through the
+/// returned promise or iterator.
+///
+/// Values with side effects are only dropped from the last statement of the body, elsewhere
+/// keeping them would need a block.
+pub struct DropUnusedReturns {
+    /// Scopes of the functions whose return values are unused.
+    candidates: FxHashSet<ScopeId>,
+
+    /// Whether each function being visited is one of [Self::candidates].
+    function_stack: std::vec::Vec<bool>,
+
+    changed: bool,
+}
+
+impl<'a> CompressorPass<'a> for DropUnusedReturns {
+    fn changed(&self) -> bool {
+        self.changed
+    }
+
+    fn build(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.changed = false;
+        self.collect_candidates(program, ctx);
+        if self.candidates.is_empty() {
+            return;
+        }
+        oxc_traverse::walk_program(self, program, ctx);
+    }
+}
+
+impl<'a> Traverse<'a> for DropUnusedReturns {
+    fn enter_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        let is_candidate = func.scope_id.get().is_some_and(|id| self.candidates.contains(&id));
+        self.function_stack.push(is_candidate);
+    }
+
+    fn exit_function(&mut self, _func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.function_stack.pop();
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_candidate = arrow.scope_id.get().is_some_and(|id| self.candidates.contains(&id));
+        self.function_stack.push(is_candidate);
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        _arrow: &mut ArrowFunctionExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.function_stack.pop();
+    }
+
+    fn enter_return_statement(
+        &mut self,
+        stmt: &mut ReturnStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.in_candidate() {
+            return;
+        }
+        // `return x` -> `return`
+        if stmt.argument.as_ref().is_some_and(|argument| !argument.may_have_side_effects()) {
+            stmt.argument = None;
+            self.changed = true;
+        }
+    }
+
+    fn exit_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.in_candidate() {
+            return;
+        }
+        // `return foo()` -> `foo()`
+        self.drop_last_return(&mut body.statements, ctx);
+    }
+}
+
+impl<'a> DropUnusedReturns {
+    pub fn new() -> Self {
+        Self { candidates: FxHashSet::default(), function_stack: vec![], changed: false }
+    }
+
+    fn in_candidate(&self) -> bool {
+        self.function_stack.last().copied().unwrap_or(false)
+    }
+
+    fn drop_last_return(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        let Some(Statement::ReturnStatement(ret)) = stmts.last_mut() else { return };
+        let span = ret.span;
+        let Some(argument) = ret.argument.take() else { return };
+        *stmts.last_mut().unwrap() = ctx.ast.statement_expression(span, argument);
+        self.changed = true;
+    }
+
+    fn collect_candidates(&mut self, program: &Program<'a>, ctx: &TraverseCtx<'a>) {
+        let mut collector = UnusedReturnCollector {
+            symbols: ctx.symbols(),
+            scopes: ctx.scopes(),
+            is_module: program.source_type.is_module(),
+            functions: vec![],
+            void_calls: FxHashSet::default(),
+            callbacks: vec![],
+        };
+        collector.visit_program(program);
+
+        self.candidates.clear();
+        self.candidates.extend(collector.callbacks);
+        for (symbol_id, scope_id) in &collector.functions {
+            let references = ctx.symbols().get_resolved_reference_ids(*symbol_id);
+            if references.iter().all(|reference_id| collector.void_calls.contains(reference_id)) {
+                self.candidates.insert(*scope_id);
+            }
+        }
+    }
+}
+
+/// Find the functions bound to a name and the calls whose value is unused.
+struct UnusedReturnCollector<'s> {
+    symbols: &'s SymbolTable,
+    scopes: &'s ScopeTree,
+    is_module: bool,
+    /// Bindings of functions and the scopes of the functions.
+    functions: std::vec::Vec<(SymbolId, ScopeId)>,
+    /// References called in a void position, e.g. `f` in `f();` or `void f()`, or passed to
+    /// `forEach`.
+    void_calls: FxHashSet<ReferenceId>,
+    /// Scopes of the functions passed to `forEach` directly.
+    callbacks: std::vec::Vec<ScopeId>,
+}
+
+impl<'s> UnusedReturnCollector<'s> {
+    fn add_function(&mut self, symbol_id: SymbolId, scope_id: ScopeId) {
+        let declaration_scope_id = self.symbols.get_scope_id(symbol_id);
+        if self.symbols.get_flags(symbol_id).contains(SymbolFlags::Export)
+            // Top level functions of a script are visible to other scripts.
+            || (!self.is_module && declaration_scope_id == self.scopes.root_scope_id())
+        {
+            return;
+        }
+        self.functions.push((symbol_id, scope_id));
+    }
+
+    fn add_void_call(&mut self, expr: &Expression<'_>) {
+        let Expression::CallExpression(call) = expr.without_parentheses() else { return };
+        if let Expression::Identifier(callee) = &call.callee {
+            if let Some(reference_id) = callee.reference_id.get() {
+                self.void_calls.insert(reference_id);
+            }
+        }
+    }
+
+    /// `a.forEach(f)` or `a.forEach(() => {})`.
+    fn add_for_each_callback(&mut self, call: &CallExpression<'_>) {
+        if !call
+            .callee
+            .as_member_expression()
+            .is_some_and(|callee| callee.static_property_name() == Some("forEach"))
+        {
+            return;
+        }
+        let Some(callback) = call.arguments.first().and_then(Argument::as_expression) else {
+            return;
+        };
+        match callback.without_parentheses() {
+            Expression::Identifier(ident) => {
+                if let Some(reference_id) = ident.reference_id.get() {
+                    self.void_calls.insert(reference_id);
+                }
+            }
+            Expression::ArrowFunctionExpression(arrow) if !arrow.r#async => {
+                self.callbacks.extend(arrow.scope_id.get());
+            }
+            // A named function expression can use its own return value through its name.
+            Expression::FunctionExpression(func)
+                if func.id.is_none() && !func.r#async && !func.generator =>
+            {
+                self.callbacks.extend(func.scope_id.get());
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 's> Visit<'a> for UnusedReturnCollector<'s> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if func.is_declaration() && !func.r#async && !func.generator {
+            if let (Some(id), Some(scope_id)) = (&func.id, func.scope_id.get()) {
+                // Annex B function declarations in blocks are also visible outside the block.
+                if self.scopes.get_parent_id(scope_id)
+                    == Some(self.symbols.get_scope_id(id.symbol_id()))
+                {
+                    self.add_function(id.symbol_id(), scope_id);
+                }
+            }
+        }
+        oxc_ast_visit::walk::walk_function(self, func, flags);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        // `const f = () => {}`, reassignments are references which are not void calls.
+        if let (Some(id), Some(init)) = (declarator.id.get_binding_identifier(), &declarator.init) {
+            let scope_id = match init {
+                Expression::ArrowFunctionExpression(arrow) if !arrow.r#async => {
+                    arrow.scope_id.get()
+                }
+                // A named function expression can use its own return value through its name.
+                Expression::FunctionExpression(func)
+                    if func.id.is_none() && !func.r#async && !func.generator =>
+                {
+                    func.scope_id.get()
+                }
+                _ => None,
+            };
+            if let Some(scope_id) = scope_id {
+                self.add_function(id.symbol_id(), scope_id);
+            }
+        }
+        oxc_ast_visit::walk::walk_variable_declarator(self, declarator);
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &ExpressionStatement<'a>) {
+        self.add_void_call(&stmt.expression);
+        oxc_ast_visit::walk::walk_expression_statement(self, stmt);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        self.add_for_each_callback(call);
+        oxc_ast_visit::walk::walk_call_expression(self, call);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator.is_void() {
+            self.add_void_call(&expr.argument);
+        }
+        oxc_ast_visit::walk::walk_unary_expression(self, expr);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use crate::tester;
+
+    fn test(source_text: &str, expected: &str) {
+        let allocator = Allocator::default();
+        let mut pass = super::DropUnusedReturns::new();
+        tester::test(&allocator, source_text, expected, &mut pass);
+    }
+
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn void_calls() {
+        test("function f() { log(); return 1 } f()", "function f() { log(); return } f()");
+        test("function f() { return log() } f(); void f()", "function f() { log() } f(); void f()");
+        test(
+            "function f() { if (a) return b(); return 1 } f()",
+            "function f() { if (a) return b(); return } f()",
+        );
+        test("const f = () => { return log() }; f()", "const f = () => { log() }; f()");
+        test("const f = function () { return log() }; f()", "const f = function () { log() }; f()");
+        // Nested functions return their own values.
+        test(
+            "function f() { g(() => { return 1 }); return 2 } f()",
+            "function f() { g(() => { return 1 }); return } f()",
+        );
+    }
+
+    #[test]
+    fn escaping_functions() {
+        test_same("function f() { return 1 } x = f()");
+        test_same("function f() { return 1 } f(); g(f)");
+        test_same("function f() { return 1 } f(); f.call()");
+        test_same("export function f() { return 1 } f()");
+        test_same("let f = () => { return 1 }; f(); f = g");
+        test_same("const f = function g() { return g() ? 1 : 2 }; f()");
+        test_same("async function f() { return g() } f()");
+        test_same("function* f() { return 1 } f()");
+    }
+
+    #[test]
+    fn for_each_callbacks() {
+        test("a.forEach(x => { return log(x) })", "a.forEach(x => { log(x) })");
+        test("a.forEach(function (x) { return log(x) })", "a.forEach(function (x) { log(x) })");
+        test(
+            "function f(x) { if (x) return 1; log(x) } a.forEach(f)",
+            "function f(x) { if (x) return; log(x) } a.forEach(f)",
+        );
+        test(
+            "const f = (x) => { return log(x) }; a.forEach(f); f(1)",
+            "const f = (x) => { log(x) }; a.forEach(f); f(1)",
+        );
+        test_same("a.map(x => { return log(x) })");
+        test_same("a.forEach(async x => { return log(x) })");
+        test_same("a.forEach(function f(x) { return f })");
+        test_same("function f(x) { return log(x) } a.forEach(f); b.map(f)");
+        test_same("function f(x) { return log(x) } a.forEach(f, f)");
+    }
+}
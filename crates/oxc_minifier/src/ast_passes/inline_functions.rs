@@ -0,0 +1,621 @@
+use std::cell::Cell;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{CloneIn, Vec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceFlags, ScopeId, ScopeTree, SymbolFlags, SymbolId, SymbolTable};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::scope::ScopeFlags;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{node_util::can_be_shorthand, CompressorPass, InlineLevel};
+
+/// Size of the code removed by inlining a function: `function f(){return}` and `f()`.
+const FUNCTION_OVERHEAD: usize = "function f(){return}f()".len();
+
+/// Inline small functions into their call sites.
+///
+/// * `(() => x)()` -> `x`
+/// * `function f(a) { return a + 1 } f(x)` -> `x + 1` when `f` is called once
+///
+/// Only functions whose body is a single `return` statement are inlined.
+/// Arguments must be literals or bindings that are never written, so evaluation order is kept.
+/// A function is not inlined when duplicating its arguments costs more than its declaration.
+///
+/// Terser option: `inline`.
+pub struct InlineFunctions<'a> {
+    level: InlineLevel,
+
+    /// Functions to inline into their only call site.
+    candidates: FxHashSet<SymbolId>,
+
+    /// Parameters and return values taken from the declarations of [Self::candidates].
+    bodies: FxHashMap<SymbolId, InlinedFunction<'a>>,
+
+    /// Functions inlined into their call site, whose declarations are removed.
+    inlined: FxHashSet<SymbolId>,
+
+    /// Arguments substituted for the parameters of inlined functions.
+    arguments: FxHashMap<SymbolId, Substitution<'a>>,
+
+    changed: bool,
+}
+
+struct InlinedFunction<'a> {
+    params: std::vec::Vec<SymbolId>,
+    value: Option<Expression<'a>>,
+}
+
+enum Substitution<'a> {
+    Literal(Expression<'a>),
+    Binding(Atom<'a>, SymbolId),
+}
+
+impl<'a> CompressorPass<'a> for InlineFunctions<'a> {
+    fn changed(&self) -> bool {
+        self.changed
+    }
+
+    fn build(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.changed = false;
+        if self.level == InlineLevel::Off {
+            return;
+        }
+        self.candidates.clear();
+        self.bodies.clear();
+        self.inlined.clear();
+        self.arguments.clear();
+        if self.level >= InlineLevel::Functions {
+            self.collect_candidates(program, ctx);
+        }
+        oxc_traverse::walk_program(self, program, ctx);
+    }
+}
+
+impl<'a> Traverse<'a> for InlineFunctions<'a> {
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, _ctx: &mut TraverseCtx<'a>) {
+        if self.candidates.is_empty() {
+            return;
+        }
+        // Take the bodies before visiting the statements,
+        // the only call site is always visited after its declaration's statement list.
+        for stmt in stmts.iter_mut() {
+            let Statement::FunctionDeclaration(func) = stmt else { continue };
+            let Some(symbol_id) = self.candidate_symbol_id(func) else { continue };
+            let params = func
+                .params
+                .items
+                .iter()
+                .filter_map(|param| param.pattern.get_binding_identifier())
+                .map(BindingIdentifier::symbol_id)
+                .collect();
+            let Some(Statement::ReturnStatement(ret)) =
+                func.body.as_mut().and_then(|body| body.statements.first_mut())
+            else {
+                continue;
+            };
+            self.bodies.insert(symbol_id, InlinedFunction { params, value: ret.argument.take() });
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, _ctx: &mut TraverseCtx<'a>) {
+        if self.candidates.is_empty() {
+            return;
+        }
+        for stmt in stmts.iter_mut() {
+            let Statement::FunctionDeclaration(func) = stmt else { continue };
+            let Some(symbol_id) = self.candidate_symbol_id(func) else { continue };
+            // Put back the bodies of functions whose call site was not inlined.
+            let Some(body) = self.bodies.remove(&symbol_id) else { continue };
+            if let Some(Statement::ReturnStatement(ret)) =
+                func.body.as_mut().and_then(|body| body.statements.first_mut())
+            {
+                ret.argument = body.value;
+            }
+        }
+        let len = stmts.len();
+        stmts.retain(|stmt| {
+            !matches!(stmt, Statement::FunctionDeclaration(func)
+                if func.id.as_ref().is_some_and(|id| self.inlined.contains(&id.symbol_id())))
+        });
+        if stmts.len() != len {
+            self.changed = true;
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let new_expr = match expr {
+            Expression::CallExpression(call) => {
+                Self::inline_iife(call, ctx).or_else(|| self.inline_function_call(call, ctx))
+            }
+            Expression::Identifier(ident) => self.substitute_argument(ident, ctx),
+            _ => None,
+        };
+        // `function f(a) { return a } f(1)`, the children of `a` are visited but `a` is not.
+        let new_expr = match new_expr {
+            Some(Expression::Identifier(ident)) => {
+                self.substitute_argument(&ident, ctx).or(Some(Expression::Identifier(ident)))
+            }
+            new_expr => new_expr,
+        };
+        if let Some(new_expr) = new_expr {
+            *expr = new_expr;
+            self.changed = true;
+        }
+    }
+
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, _ctx: &mut TraverseCtx<'a>) {
+        // `function f(a) { return { a } }` -> `{ a: 1 }`
+        if prop.shorthand && !can_be_shorthand(prop) {
+            prop.shorthand = false;
+        }
+    }
+}
+
+impl<'a> InlineFunctions<'a> {
+    pub fn new(level: InlineLevel) -> Self {
+        Self {
+            level,
+            candidates: FxHashSet::default(),
+            bodies: FxHashMap::default(),
+            inlined: FxHashSet::default(),
+            arguments: FxHashMap::default(),
+            changed: false,
+        }
+    }
+
+    fn candidate_symbol_id(&self, func: &Function<'a>) -> Option<SymbolId> {
+        let symbol_id = func.id.as_ref()?.symbol_id();
+        self.candidates.contains(&symbol_id).then_some(symbol_id)
+    }
+
+    /// `(() => x)()` -> `x`
+    /// `(function () { return x })()` -> `x`
+    fn inline_iife(
+        call: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if call.optional || !call.arguments.is_empty() {
+            return None;
+        }
+        let (body, is_arrow, is_expression_body) = match &mut call.callee {
+            Expression::ArrowFunctionExpression(arrow) => {
+                if arrow.r#async || !Self::is_empty_params(&arrow.params) {
+                    return None;
+                }
+                let expression = arrow.expression;
+                (&mut arrow.body, true, expression)
+            }
+            Expression::FunctionExpression(func) => {
+                if func.id.is_some()
+                    || func.r#async
+                    || func.generator
+                    || func.this_param.is_some()
+                    || !Self::is_empty_params(&func.params)
+                {
+                    return None;
+                }
+                (func.body.as_mut()?, false, false)
+            }
+            _ => return None,
+        };
+        if !body.directives.is_empty() || body.statements.len() > 1 {
+            return None;
+        }
+        let value = match body.statements.first_mut() {
+            None => None,
+            Some(Statement::ExpressionStatement(stmt)) if is_expression_body => {
+                Some(&mut stmt.expression)
+            }
+            Some(Statement::ReturnStatement(ret)) => ret.argument.as_mut(),
+            Some(_) => return None,
+        };
+        let Some(value) = value else {
+            return Some(ctx.ast.void_0(call.span));
+        };
+        let mut checker = InlineBodyChecker::new(ctx.symbols(), &[], is_arrow);
+        checker.visit_expression(value);
+        checker.inlinable.then(|| ctx.ast.move_expression(value))
+    }
+
+    /// `function f(a) { return a + 1 } f(x)` -> `x + 1`
+    fn inline_function_call(
+        &mut self,
+        call: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let Expression::Identifier(callee) = &call.callee else { return None };
+        let symbol_id = ctx.symbols().get_reference(callee.reference_id()).symbol_id()?;
+        let body = self.bodies.remove(&symbol_id)?;
+        for (i, param) in body.params.iter().enumerate() {
+            let Some(arg) = call.arguments.get_mut(i) else {
+                self.arguments.insert(*param, Substitution::Literal(ctx.ast.void_0(SPAN)));
+                continue;
+            };
+            let substitution = match ctx.ast.move_expression(arg.to_expression_mut()) {
+                Expression::Identifier(ident) => {
+                    match ctx.symbols().get_reference(ident.reference_id()).symbol_id() {
+                        Some(symbol_id) => {
+                            ctx.delete_reference_for_identifier(&ident);
+                            Substitution::Binding(ident.name.clone(), symbol_id)
+                        }
+                        None => Substitution::Literal(Expression::Identifier(ident)),
+                    }
+                }
+                expr => Substitution::Literal(expr),
+            };
+            self.arguments.insert(*param, substitution);
+        }
+        ctx.delete_reference_for_identifier(callee);
+        self.inlined.insert(symbol_id);
+        Some(body.value.unwrap_or_else(|| ctx.ast.void_0(call.span)))
+    }
+
+    /// Replace a reference to a parameter of an inlined function with its argument.
+    fn substitute_argument(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if self.arguments.is_empty() {
+            return None;
+        }
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id()?;
+        let new_expr = match self.arguments.get(&symbol_id)? {
+            Substitution::Literal(expr) => expr.clone_in(ctx.ast.allocator),
+            Substitution::Binding(name, symbol_id) => {
+                let ident = ctx.create_bound_reference_id(
+                    ident.span,
+                    name.clone(),
+                    *symbol_id,
+                    ReferenceFlags::Read,
+                );
+                Expression::Identifier(ctx.ast.alloc(ident))
+            }
+        };
+        ctx.delete_reference_for_identifier(ident);
+        Some(new_expr)
+    }
+
+    fn is_empty_params(params: &FormalParameters<'a>) -> bool {
+        params.items.is_empty() && params.rest.is_none()
+    }
+
+    fn collect_candidates(&mut self, program: &Program<'a>, ctx: &TraverseCtx<'a>) {
+        let mut collector = InlineCandidateCollector {
+            symbols: ctx.symbols(),
+            scopes: ctx.scopes(),
+            is_module: program.source_type.is_module(),
+            scope_stack: vec![],
+            functions: FxHashMap::default(),
+            calls: FxHashMap::default(),
+        };
+        collector.visit_program(program);
+
+        let InlineCandidateCollector { functions, calls, .. } = collector;
+        for (symbol_id, function) in &functions {
+            let Some(call) = calls.get(symbol_id) else { continue };
+            // Inlining into another inlined function could change what its names refer to.
+            let in_inlined_function = ctx
+                .scopes()
+                .ancestors(call.scope_id)
+                .any(|scope_id| functions.values().any(|function| function.scope_id == scope_id));
+            if in_inlined_function || call.arguments.len() > function.param_references.len() {
+                continue;
+            }
+            // Names used by the function must refer to the same bindings at the call site.
+            if !function.free_references.iter().all(|(name, symbol_id)| {
+                ctx.scopes().find_binding(call.scope_id, name) == *symbol_id
+            }) {
+                continue;
+            }
+            let added_size = function
+                .param_references
+                .iter()
+                .enumerate()
+                .map(|(i, references)| {
+                    let size = call.arguments.get(i).map_or("void 0".len(), |size| *size as usize);
+                    references.saturating_sub(1) * size
+                })
+                .sum::<usize>();
+            if added_size <= FUNCTION_OVERHEAD {
+                self.candidates.insert(*symbol_id);
+            }
+        }
+    }
+}
+
+struct FunctionInfo<'a> {
+    scope_id: ScopeId,
+    /// Number of references to each parameter.
+    param_references: std::vec::Vec<usize>,
+    /// Names used in the return value that are not parameters.
+    free_references: std::vec::Vec<(&'a str, Option<SymbolId>)>,
+}
+
+struct CallInfo {
+    scope_id: ScopeId,
+    /// Sizes of the arguments.
+    arguments: std::vec::Vec<u32>,
+}
+
+/// Find function declarations with a single `return` statement and their only call site.
+struct InlineCandidateCollector<'a, 's> {
+    symbols: &'s SymbolTable,
+    scopes: &'s ScopeTree,
+    is_module: bool,
+    scope_stack: std::vec::Vec<ScopeId>,
+    functions: FxHashMap<SymbolId, FunctionInfo<'a>>,
+    calls: FxHashMap<SymbolId, CallInfo>,
+}
+
+impl<'a, 's> InlineCandidateCollector<'a, 's> {
+    fn function_info(&self, func: &Function<'a>) -> Option<(SymbolId, FunctionInfo<'a>)> {
+        let symbol_id = func.id.as_ref()?.symbol_id();
+        let scope_id = func.scope_id.get()?;
+        let declaration_scope_id = self.symbols.get_scope_id(symbol_id);
+        if func.r#async
+            || func.generator
+            || func.this_param.is_some()
+            || func.params.rest.is_some()
+            || self.symbols.get_flags(symbol_id).contains(SymbolFlags::Export)
+            // Annex B function declarations in blocks are also visible outside the block.
+            || self.scopes.get_parent_id(scope_id) != Some(declaration_scope_id)
+            // Top level functions of a script are visible to other scripts.
+            || (!self.is_module && declaration_scope_id == self.scopes.root_scope_id())
+        {
+            return None;
+        }
+        let references = self.symbols.get_resolved_reference_ids(symbol_id);
+        if references.len() != 1 || self.symbols.get_reference(references[0]).is_write() {
+            return None;
+        }
+        let body = func.body.as_ref()?;
+        if !body.directives.is_empty() || body.statements.len() != 1 {
+            return None;
+        }
+        let Statement::ReturnStatement(ret) = &body.statements[0] else { return None };
+
+        let mut params = vec![];
+        for param in &func.params.items {
+            let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else {
+                return None;
+            };
+            params.push(ident.symbol_id());
+        }
+        let mut param_references = vec![];
+        for param in &params {
+            let references = self.symbols.get_resolved_reference_ids(*param);
+            if references.iter().any(|id| self.symbols.get_reference(*id).is_write()) {
+                return None;
+            }
+            param_references.push(references.len());
+        }
+
+        let mut checker = InlineBodyChecker::new(self.symbols, &params, false);
+        if let Some(argument) = &ret.argument {
+            checker.visit_expression(argument);
+        }
+        checker.inlinable.then(|| {
+            let free_references = checker.free_references;
+            (symbol_id, FunctionInfo { scope_id, param_references, free_references })
+        })
+    }
+
+    fn call_info(&self, call: &CallExpression<'a>) -> Option<(SymbolId, CallInfo)> {
+        let Expression::Identifier(callee) = &call.callee else { return None };
+        let symbol_id = self.symbols.get_reference(callee.reference_id.get()?).symbol_id()?;
+        let mut arguments = vec![];
+        for arg in &call.arguments {
+            let stable = match arg {
+                Argument::NumericLiteral(_)
+                | Argument::StringLiteral(_)
+                | Argument::BooleanLiteral(_)
+                | Argument::NullLiteral(_)
+                | Argument::BigIntLiteral(_) => true,
+                Argument::UnaryExpression(e) => e.operator.is_void() && e.argument.is_number(),
+                // Reading a binding that is never written always gives the same value.
+                Argument::Identifier(ident) => ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id())
+                    .is_some_and(|symbol_id| {
+                        self.symbols.get_span(symbol_id).end <= call.span.start
+                            && !self
+                                .symbols
+                                .get_resolved_reference_ids(symbol_id)
+                                .iter()
+                                .any(|id| self.symbols.get_reference(*id).is_write())
+                    }),
+                _ => false,
+            };
+            if !stable {
+                return None;
+            }
+            arguments.push(arg.span().size());
+        }
+        let scope_id = *self.scope_stack.last()?;
+        Some((symbol_id, CallInfo { scope_id, arguments }))
+    }
+}
+
+impl<'a, 's> Visit<'a> for InlineCandidateCollector<'a, 's> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if let Some(scope_id) = scope_id.get() {
+            self.scope_stack.push(scope_id);
+        }
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if func.is_declaration() {
+            if let Some((symbol_id, info)) = self.function_info(func) {
+                self.functions.insert(symbol_id, info);
+            }
+        }
+        oxc_ast_visit::walk::walk_function(self, func, flags);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if !call.optional {
+            if let Some((symbol_id, info)) = self.call_info(call) {
+                self.calls.insert(symbol_id, info);
+            }
+        }
+        oxc_ast_visit::walk::walk_call_expression(self, call);
+    }
+}
+
+/// Check whether the return value of a function can be moved to its call site.
+struct InlineBodyChecker<'a, 's> {
+    symbols: &'s SymbolTable,
+    params: &'s [SymbolId],
+    /// `this` and `arguments` of arrow functions refer to the enclosing function.
+    is_arrow: bool,
+    inlinable: bool,
+    free_references: std::vec::Vec<(&'a str, Option<SymbolId>)>,
+}
+
+impl<'a, 's> InlineBodyChecker<'a, 's> {
+    fn new(symbols: &'s SymbolTable, params: &'s [SymbolId], is_arrow: bool) -> Self {
+        Self { symbols, params, is_arrow, inlinable: true, free_references: vec![] }
+    }
+}
+
+impl<'a, 's> Visit<'a> for InlineBodyChecker<'a, 's> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.inlinable {
+            oxc_ast_visit::walk::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id());
+        if symbol_id.is_some_and(|symbol_id| self.params.contains(&symbol_id)) {
+            return;
+        }
+        // `arguments` is a binding of the function, and a direct `eval` can read any binding.
+        if symbol_id.is_none()
+            && (ident.name == "eval" || (ident.name == "arguments" && !self.is_arrow))
+        {
+            self.inlinable = false;
+        }
+        self.free_references.push((ident.name.as_str(), symbol_id));
+    }
+
+    fn visit_this_expression(&mut self, _: &ThisExpression) {
+        self.inlinable &= self.is_arrow;
+    }
+
+    fn visit_super(&mut self, _: &Super) {
+        self.inlinable &= self.is_arrow;
+    }
+
+    fn visit_meta_property(&mut self, _: &MetaProperty<'a>) {
+        self.inlinable &= self.is_arrow;
+    }
+
+    // Moving scopes is not supported.
+    fn visit_function(&mut self, _: &Function<'a>, _: ScopeFlags) {
+        self.inlinable = false;
+    }
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression<'a>) {
+        self.inlinable = false;
+    }
+
+    fn visit_class(&mut self, _: &Class<'a>) {
+        self.inlinable = false;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use crate::{tester, InlineLevel};
+
+    fn test_with_level(source_text: &str, expected: &str, level: InlineLevel) {
+        let allocator = Allocator::default();
+        let mut pass = super::InlineFunctions::new(level);
+        tester::test(&allocator, source_text, expected, &mut pass);
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        test_with_level(source_text, expected, InlineLevel::Functions);
+    }
+
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn iife() {
+        test("f((() => 1)())", "f(1)");
+        test("f((() => { return a + b })())", "f(a + b)");
+        test("f((function () { return a })())", "f(a)");
+        test("f((() => {})())", "f(void 0)");
+        test("f((() => this)())", "f(this)");
+        test_same("f((function () { return this })())");
+        test_same("f((function () { return arguments })())");
+        test_same("f((function f() { return f })())");
+        test_same("f((async () => 1)())");
+        test_same("f(((a) => a)(1))");
+        test_same("f((() => () => 1)())");
+        test_same("f((() => { a(); return b })())");
+    }
+
+    #[test]
+    fn single_use_function() {
+        test("function g() { return 1 } f(g())", "f(1)");
+        test("function g(a, b) { return a + b } f(g(1, 2))", "f(1 + 2)");
+        test("function g(a) { return a } f(g())", "f(void 0)");
+        test("function g(a) { return { a } } f(g(1))", "f({ a: 1 })");
+        test("const x = a(); function g(a) { return a * a } f(g(x))", "const x = a(); f(x * x)");
+        test("f(g(1)); function g(a) { return a + 1 }", "f(1 + 1)");
+        test("function h() { function g() { return 1 } return g() }", "function h() { return 1 }");
+    }
+
+    #[test]
+    fn bail_single_use_function() {
+        test_same("function g() { return 1 } f(g(), g())");
+        test_same("function g() { return 1 } f(g)");
+        test_same("export function g() { return 1 } f(g())");
+        test_same("function g(a) { return a } f(g(x()))");
+        test_same("function g(a) { return a } let x = 1; x = 2; f(g(x))");
+        test_same("function g(a) { return a } f(g(1, 2))");
+        test_same("function g(a = 1) { return a } f(g())");
+        test_same("function g() { a(); return 1 } f(g())");
+        test_same("function g() { return this } f(g())");
+        test_same("function g() { return arguments } f(g())");
+        test_same("function g() { return () => 1 } f(g())");
+        test_same("function* g() { return 1 } f(g())");
+        test_same("async function g() { return 1 } f(g())");
+        test_same("function g() { return g } f(g())");
+        // `x` refers to a different binding at the call site.
+        test_same("let x = 1; function g() { return x } function h(x) { return g() }");
+        // Duplicating the argument is larger than the function.
+        test_same(
+            "function g(a) { return a + a + a } f(g('a very long string that is not worth it'))",
+        );
+    }
+
+    #[test]
+    fn levels() {
+        test_with_level("f((() => 1)())", "f((() => 1)())", InlineLevel::Off);
+        test_with_level("f((() => 1)())", "f(1)", InlineLevel::Iife);
+        test_with_level(
+            "function g() { return 1 } f(g())",
+            "function g() { return 1 } f(g())",
+            InlineLevel::Iife,
+        );
+    }
+}
@@ -1,21 +1,29 @@
 mod collapse_variable_declarations;
+mod constant_propagation;
+mod drop_unused_returns;
 mod exploit_assigns;
+mod inline_functions;
 mod peephole_fold_constants;
 mod peephole_minimize_conditions;
 mod peephole_remove_dead_code;
 mod peephole_replace_known_methods;
 mod peephole_substitute_alternate_syntax;
 mod remove_syntax;
+mod remove_unused_pure_calls;
 mod statement_fusion;
 
 pub use collapse_variable_declarations::CollapseVariableDeclarations;
+pub use constant_propagation::ConstantPropagation;
+pub use drop_unused_returns::DropUnusedReturns;
 pub use exploit_assigns::ExploitAssigns;
+pub use inline_functions::InlineFunctions;
 pub use peephole_fold_constants::PeepholeFoldConstants;
 pub use peephole_minimize_conditions::PeepholeMinimizeConditions;
 pub use peephole_remove_dead_code::PeepholeRemoveDeadCode;
 pub use peephole_replace_known_methods::PeepholeReplaceKnownMethods;
 pub use peephole_substitute_alternate_syntax::PeepholeSubstituteAlternateSyntax;
 pub use remove_syntax::RemoveSyntax;
+pub use remove_unused_pure_calls::RemoveUnusedPureCalls;
 pub use statement_fusion::StatementFusion;
 
 use oxc_ast::ast::Program;
@@ -16,6 +16,11 @@ use crate::{node_util::Ctx, CompressorPass};
 ///
 /// <https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/PeepholeFoldConstants.java>
 pub struct PeepholeFoldConstants {
+    /// Fold string additions into template literals, see [`CompressOptions::template_literals`].
+    ///
+    /// [`CompressOptions::template_literals`]: crate::CompressOptions::template_literals
+    template_literals: bool,
+
     changed: bool,
 }
 
@@ -42,7 +47,7 @@ impl<'a> Traverse<'a> for PeepholeFoldConstants {
             // return tryFoldSpread(subtree);
             Expression::ArrayExpression(e) => Self::try_flatten_array_expression(e, ctx),
             Expression::ObjectExpression(e) => Self::try_flatten_object_expression(e, ctx),
-            Expression::BinaryExpression(e) => Self::try_fold_binary_expression(e, ctx),
+            Expression::BinaryExpression(e) => self.try_fold_binary_expression(e, ctx),
             #[allow(clippy::float_cmp)]
             Expression::UnaryExpression(e) => {
                 match e.operator {
@@ -68,8 +73,8 @@ impl<'a> Traverse<'a> for PeepholeFoldConstants {
 }
 
 impl<'a, 'b> PeepholeFoldConstants {
-    pub fn new() -> Self {
-        Self { changed: false }
+    pub fn new(template_literals: bool) -> Self {
+        Self { template_literals, changed: false }
     }
 
     fn try_fold_useless_object_dot_define_properties_call(
@@ -224,15 +229,24 @@ impl<'a, 'b> PeepholeFoldConstants {
     }
 
     fn try_fold_binary_expression(
+        &self,
         e: &mut BinaryExpression<'a>,
         ctx: Ctx<'a, 'b>,
     ) -> Option<Expression<'a>> {
         // TODO: tryReduceOperandsForOp
         match e.operator {
+            BinaryOperator::Addition => {
+                ctx.eval_binary_expression(e).map(|v| ctx.value_to_expr(e.span, v)).or_else(|| {
+                    if self.template_literals {
+                        Self::try_fold_template_addition(e, ctx)
+                    } else {
+                        None
+                    }
+                })
+            }
             BinaryOperator::ShiftLeft
             | BinaryOperator::ShiftRight
             | BinaryOperator::ShiftRightZeroFill
-            | BinaryOperator::Addition
             | BinaryOperator::Subtraction
             | BinaryOperator::Division
             | BinaryOperator::Remainder
@@ -254,6 +268,59 @@ impl<'a, 'b> PeepholeFoldConstants {
         }
     }
 
+    /// `` "a" + `b${c}` `` -> `` `ab${c}` ``
+    ///
+    /// Only strings and template literals are joined: `"a" + b` is not `` `a${b}` `` when `b` is
+    /// an object, as `+` converts it with `valueOf` but a template literal with `toString`.
+    fn try_fold_template_addition(
+        e: &mut BinaryExpression<'a>,
+        ctx: Ctx<'a, 'b>,
+    ) -> Option<Expression<'a>> {
+        let is_operand = |expr: &Expression<'a>| match expr {
+            Expression::StringLiteral(_) => true,
+            Expression::TemplateLiteral(lit) => {
+                lit.quasis.iter().all(|quasi| quasi.value.cooked.is_some())
+            }
+            _ => false,
+        };
+        if !is_operand(&e.left) || !is_operand(&e.right) {
+            return None;
+        }
+
+        let mut quasis = ctx.ast.vec();
+        let mut expressions = ctx.ast.vec();
+        let mut raw = String::new();
+        let mut cooked = String::new();
+        for operand in [&mut e.left, &mut e.right] {
+            match operand {
+                Expression::StringLiteral(lit) => {
+                    push_template_raw(&mut raw, &escape_template_raw(&lit.value));
+                    cooked.push_str(&lit.value);
+                }
+                Expression::TemplateLiteral(lit) => {
+                    let lit = &mut **lit;
+                    for (i, quasi) in lit.quasis.iter().enumerate() {
+                        push_template_raw(&mut raw, &quasi.value.raw);
+                        cooked.push_str(quasi.value.cooked.as_deref().unwrap_or_default());
+                        if let Some(expr) = lit.expressions.get_mut(i) {
+                            let value = TemplateElementValue {
+                                raw: ctx.ast.atom(&std::mem::take(&mut raw)),
+                                cooked: Some(ctx.ast.atom(&std::mem::take(&mut cooked))),
+                            };
+                            quasis.push(ctx.ast.template_element(SPAN, false, value));
+                            expressions.push(ctx.ast.move_expression(expr));
+                        }
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+        let value =
+            TemplateElementValue { raw: ctx.ast.atom(&raw), cooked: Some(ctx.ast.atom(&cooked)) };
+        quasis.push(ctx.ast.template_element(SPAN, true, value));
+        Some(ctx.ast.expression_template_literal(e.span, quasis, expressions))
+    }
+
     fn try_fold_comparison(e: &BinaryExpression<'a>, ctx: Ctx<'a, 'b>) -> Option<Expression<'a>> {
         let left = &e.left;
         let right = &e.right;
@@ -449,6 +516,34 @@ impl<'a, 'b> PeepholeFoldConstants {
     }
 }
 
+/// Append `piece` to the raw text of a template element, keeping a trailing `$` from starting a
+/// substitution.
+fn push_template_raw(raw: &mut String, piece: &str) {
+    if raw.ends_with('$') && piece.starts_with('{') {
+        raw.push('\\');
+    }
+    raw.push_str(piece);
+}
+
+/// Escape the value of a string literal for the raw text of a template literal.
+fn escape_template_raw(s: &str) -> String {
+    let mut raw = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' | '`' => {
+                raw.push('\\');
+                raw.push(c);
+            }
+            '$' if chars.peek() == Some(&'{') => raw.push_str("\\$"),
+            // Line breaks in templates are normalized to `\n`.
+            '\r' => raw.push_str("\\r"),
+            _ => raw.push(c),
+        }
+    }
+    raw
+}
+
 /// <https://github.com/google/closure-compiler/blob/master/test/com/google/javascript/jscomp/PeepholeFoldConstantsTest.java>
 #[cfg(test)]
 mod test {
@@ -464,13 +559,13 @@ mod test {
 
     fn test(source_text: &str, expected: &str) {
         let allocator = Allocator::default();
-        let mut pass = super::PeepholeFoldConstants::new();
+        let mut pass = super::PeepholeFoldConstants::new(true);
         tester::test(&allocator, source_text, expected, &mut pass);
     }
 
     fn test_nospace(source_text: &str, expected: &str) {
         let allocator = Allocator::default();
-        let mut pass = super::PeepholeFoldConstants::new();
+        let mut pass = super::PeepholeFoldConstants::new(true);
         tester::test_impl(&allocator, source_text, expected, &mut pass, true);
     }
 
@@ -1232,6 +1327,23 @@ mod test {
         test("x = Infinity % 0", "x = NaN");
     }
 
+    #[test]
+    fn test_fold_template_addition() {
+        test("x = 'a' + `b${y}`", "x = `ab${y}`");
+        test("x = `a${y}` + 'b'", "x = `a${y}b`");
+        test("x = `a${y}` + `b${z}c`", "x = `a${y}b${z}c`");
+        test("x = 'a`\\\\' + `${y}`", "x = `a\\`\\\\${y}`");
+        test("x = 'a${' + `${y}`", "x = `a\\${${y}`");
+        test("x = `a$` + `{${y}`", "x = `a$\\{${y}`");
+        test_same("x = 'a' + y + `b${z}`");
+        test_same("x = y + `a${z}`");
+
+        let allocator = Allocator::default();
+        let mut pass = super::PeepholeFoldConstants::new(/* template_literals */ false);
+        let source_text = "x = 'a' + `b${y}`";
+        tester::test(&allocator, source_text, source_text, &mut pass);
+    }
+
     #[test]
     fn test_to_number() {
         test("x = +''", "x = 0");
@@ -1,19 +1,24 @@
 use oxc_allocator::Vec;
-use oxc_ast::{ast::*, Visit};
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
 use oxc_ecmascript::constant_evaluation::{ConstantEvaluation, IsLiteralValue};
 use oxc_span::SPAN;
 use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
 
-use crate::node_util::Ctx;
+use crate::node_util::{is_preserved_directive, Ctx};
 use crate::{keep_var::KeepVar, CompressorPass};
 
 /// Remove Dead Code from the AST.
 ///
 /// Terser option: `dead_code: true`.
 ///
+/// String literal statements listed in `compress.preserve_directives` are kept.
+///
 /// See `KeepVar` at the end of this file for `var` hoisting logic.
 /// <https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/PeepholeRemoveDeadCode.java>
 pub struct PeepholeRemoveDeadCode {
+    preserve_directives: std::vec::Vec<String>,
+
     changed: bool,
 }
 
@@ -30,6 +35,9 @@ impl<'a> CompressorPass<'a> for PeepholeRemoveDeadCode {
 
 impl<'a> Traverse<'a> for PeepholeRemoveDeadCode {
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if is_preserved_directive(stmt, &self.preserve_directives) {
+            return;
+        }
         let ctx = Ctx(ctx);
         if let Some(new_stmt) = match stmt {
             Statement::IfStatement(if_stmt) => self.try_fold_if(if_stmt, ctx),
@@ -69,8 +77,8 @@ impl<'a> Traverse<'a> for PeepholeRemoveDeadCode {
 }
 
 impl<'a, 'b> PeepholeRemoveDeadCode {
-    pub fn new() -> Self {
-        Self { changed: false }
+    pub fn new(preserve_directives: std::vec::Vec<String>) -> Self {
+        Self { preserve_directives, changed: false }
     }
 
     /// Removes dead code thats comes after `return` statements after inlining `if` statements
@@ -422,11 +430,12 @@ impl<'a, 'b> PeepholeRemoveDeadCode {
 mod test {
     use oxc_allocator::Allocator;
 
-    use crate::tester;
+    use crate::{tester, CompressOptions};
 
     fn test(source_text: &str, positive: &str) {
         let allocator = Allocator::default();
-        let mut pass = super::PeepholeRemoveDeadCode::new();
+        let mut pass =
+            super::PeepholeRemoveDeadCode::new(CompressOptions::default().preserve_directives);
         tester::test(&allocator, source_text, positive, &mut pass);
     }
 
@@ -555,4 +564,10 @@ mod test {
         fold("([...a, b, ...c])", "([...a, ...c])");
         fold_same("([...b, ...c])"); // It would also be fine if the spreads were split apart.
     }
+    #[test]
+    fn test_preserve_directives() {
+        fold_same("import a from 'a'; 'use client'; a()");
+        fold_same("foo(); 'use server'");
+        fold("foo(); 'use strict'", "foo()");
+    }
 }
@@ -0,0 +1,410 @@
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, Comment};
+use oxc_ast_visit::Visit;
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_semantic::SymbolId;
+use oxc_span::GetSpan;
+use oxc_syntax::scope::ScopeFlags;
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::{CompressOptions, CompressorPass};
+
+/// Remove calls whose return value is unused and are known to be side effect free.
+///
+/// A call is side effect free when it is:
+///
+/// * annotated with `/* #__PURE__ */`
+/// * a call to one of `compress.pure_funcs`
+/// * a call to a function annotated with `/* #__NO_SIDE_EFFECTS__ */`
+///
+/// Arguments with side effects are kept.
+///
+/// Terser options: `annotations: true`, `pure_funcs: [...]`.
+/// <https://github.com/javascript-compiler-hints/compiler-notations-spec>
+pub struct RemoveUnusedPureCalls {
+    options: CompressOptions,
+
+    /// Starts of `CallExpression` or `NewExpression` annotated with `#__PURE__`.
+    pure_annotations: FxHashSet<u32>,
+
+    /// Functions annotated with `#__NO_SIDE_EFFECTS__`.
+    no_side_effects_symbols: FxHashSet<SymbolId>,
+
+    changed: bool,
+}
+
+impl<'a> CompressorPass<'a> for RemoveUnusedPureCalls {
+    fn changed(&self) -> bool {
+        self.changed
+    }
+
+    fn build(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.changed = false;
+        if self.options.annotations {
+            self.collect_annotations(program);
+        }
+        oxc_traverse::walk_program(self, program, ctx);
+    }
+}
+
+impl<'a> Traverse<'a> for RemoveUnusedPureCalls {
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        // `() => foo()` returns the value of `foo()`.
+        if let Ancestor::ArrowFunctionExpressionBody(body) = ctx.ancestor(1) {
+            if *body.expression() {
+                return;
+            }
+        }
+
+        // Indexes of the statements left empty, other empty statements are not ours to remove.
+        let mut emptied = std::vec::Vec::new();
+        for (index, stmt) in stmts.iter_mut().enumerate() {
+            let Statement::ExpressionStatement(expr_stmt) = stmt else { continue };
+            if !self.is_pure_call(&expr_stmt.expression, ctx) {
+                continue;
+            }
+            let span = expr_stmt.span;
+            let expr = ctx.ast.move_expression(&mut expr_stmt.expression);
+            let mut side_effects = ctx.ast.vec();
+            self.extract_side_effects(expr, &mut side_effects, ctx);
+            match side_effects.len() {
+                0 => emptied.push(index),
+                1 => *stmt = ctx.ast.statement_expression(span, side_effects.pop().unwrap()),
+                _ => {
+                    *stmt = ctx.ast.statement_expression(
+                        span,
+                        ctx.ast.expression_sequence(span, side_effects),
+                    );
+                }
+            }
+            self.changed = true;
+        }
+        if !emptied.is_empty() {
+            let mut index = 0;
+            stmts.retain(|_| {
+                let keep = emptied.binary_search(&index).is_err();
+                index += 1;
+                keep
+            });
+        }
+    }
+}
+
+impl<'a> RemoveUnusedPureCalls {
+    pub fn new(options: CompressOptions) -> Self {
+        Self {
+            options,
+            pure_annotations: FxHashSet::default(),
+            no_side_effects_symbols: FxHashSet::default(),
+            changed: false,
+        }
+    }
+
+    fn collect_annotations(&mut self, program: &Program<'a>) {
+        let mut no_side_effects_annotations = FxHashSet::default();
+        for comment in &program.comments {
+            match Annotation::from_comment(comment, program.source_text) {
+                Some(Annotation::Pure) => {
+                    self.pure_annotations.insert(comment.attached_to);
+                }
+                Some(Annotation::NoSideEffects) => {
+                    no_side_effects_annotations.insert(comment.attached_to);
+                }
+                None => {}
+            }
+        }
+        if no_side_effects_annotations.is_empty() {
+            return;
+        }
+        let mut collector = NoSideEffectsCollector {
+            annotations: no_side_effects_annotations,
+            symbols: &mut self.no_side_effects_symbols,
+        };
+        collector.visit_program(program);
+    }
+
+    fn is_pure_call(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let (callee, arguments) = match expr {
+            Expression::CallExpression(e) => (&e.callee, &e.arguments),
+            Expression::NewExpression(e) => (&e.callee, &e.arguments),
+            _ => return false,
+        };
+        // Spreading an argument runs the iterator protocol.
+        if arguments.iter().any(Argument::is_spread) {
+            return false;
+        }
+        if self.pure_annotations.contains(&expr.span().start) {
+            return true;
+        }
+        if self.options.pure_funcs.iter().any(|name| Self::is_callee_named(callee, name)) {
+            return true;
+        }
+        callee.get_identifier_reference().is_some_and(|ident| {
+            ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+                .is_some_and(|symbol_id| self.no_side_effects_symbols.contains(&symbol_id))
+        })
+    }
+
+    /// Whether `callee` is written as `name`, e.g. `foo` or `console.log`.
+    fn is_callee_named(callee: &Expression<'a>, name: &str) -> bool {
+        match callee {
+            Expression::Identifier(ident) => ident.name == name,
+            Expression::StaticMemberExpression(member) => {
+                name.rsplit_once('.').is_some_and(|(object, property)| {
+                    member.property.name == property
+                        && Self::is_callee_named(&member.object, object)
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// Collect the parts of `expr` that must be kept when its value is unused.
+    fn extract_side_effects(
+        &self,
+        expr: Expression<'a>,
+        side_effects: &mut Vec<'a, Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.is_pure_call(&expr, ctx) {
+            if expr.may_have_side_effects() && !Self::is_local_reference(&expr, ctx) {
+                side_effects.push(expr);
+            }
+            return;
+        }
+        let arguments = match expr {
+            Expression::CallExpression(e) => e.unbox().arguments,
+            Expression::NewExpression(e) => e.unbox().arguments,
+            _ => unreachable!(),
+        };
+        for argument in arguments {
+            self.extract_side_effects(argument.into_expression(), side_effects, ctx);
+        }
+    }
+
+    /// Reading a declared binding has no side effects.
+    fn is_local_reference(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        ident.reference_id.get().is_some_and(|reference_id| {
+            ctx.symbols().get_reference(reference_id).symbol_id().is_some()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Annotation {
+    Pure,
+    NoSideEffects,
+}
+
+impl Annotation {
+    /// `#__PURE__` Notation Specification
+    ///
+    /// <https://github.com/javascript-compiler-hints/compiler-notations-spec/blob/main/pure-notation-spec.md>
+    ///
+    /// Legal comments such as `/*! #__NO_SIDE_EFFECTS__ */` are accepted too, Vue uses them so
+    /// that the annotations survive minifiers which only keep legal comments.
+    fn from_comment(comment: &Comment, source_text: &str) -> Option<Self> {
+        let s = comment.content_span().source_text(source_text).trim_start();
+        let s = s.strip_prefix('!').map_or(s, str::trim_start);
+        let s = s.strip_prefix(['@', '#'])?;
+        if s.starts_with("__PURE__") {
+            Some(Self::Pure)
+        } else if s.starts_with("__NO_SIDE_EFFECTS__") {
+            Some(Self::NoSideEffects)
+        } else {
+            None
+        }
+    }
+}
+
+/// Find functions annotated with `#__NO_SIDE_EFFECTS__`.
+///
+/// <https://github.com/javascript-compiler-hints/compiler-notations-spec/blob/main/no-side-effects-notation-spec.md>
+struct NoSideEffectsCollector<'s> {
+    /// Starts of nodes annotated with `#__NO_SIDE_EFFECTS__`.
+    annotations: FxHashSet<u32>,
+    symbols: &'s mut FxHashSet<SymbolId>,
+}
+
+impl<'s> NoSideEffectsCollector<'s> {
+    fn is_annotated(&self, start: u32) -> bool {
+        self.annotations.contains(&start)
+    }
+
+    fn add(&mut self, ident: Option<&BindingIdentifier>) {
+        if let Some(symbol_id) = ident.and_then(|ident| ident.symbol_id.get()) {
+            self.symbols.insert(symbol_id);
+        }
+    }
+}
+
+impl<'a, 's> Visit<'a> for NoSideEffectsCollector<'s> {
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        // `/* #__NO_SIDE_EFFECTS__ */ export function foo() {}`
+        if self.is_annotated(decl.span.start) {
+            if let Some(declaration) = &decl.declaration {
+                self.annotations.insert(declaration.span().start);
+            }
+        }
+        oxc_ast_visit::walk::walk_export_named_declaration(self, decl);
+    }
+
+    fn visit_export_default_declaration(&mut self, decl: &ExportDefaultDeclaration<'a>) {
+        // `/* #__NO_SIDE_EFFECTS__ */ export default function foo() {}`
+        if self.is_annotated(decl.span.start) {
+            self.annotations.insert(decl.declaration.span().start);
+        }
+        oxc_ast_visit::walk::walk_export_default_declaration(self, decl);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        // `/* #__NO_SIDE_EFFECTS__ */ const foo = () => {}`
+        // Like Rollup, only the first declarator is annotated.
+        if self.is_annotated(decl.span.start) {
+            if let Some(declarator) = decl.declarations.first() {
+                self.annotations.insert(declarator.span.start);
+            }
+        }
+        oxc_ast_visit::walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        // `const /* #__NO_SIDE_EFFECTS__ */ foo = () => {}`
+        // `const foo = /* #__NO_SIDE_EFFECTS__ */ () => {}`
+        if declarator.kind.is_const() {
+            if let Some(init) = &declarator.init {
+                if matches!(
+                    init,
+                    Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+                ) && (self.is_annotated(declarator.span.start)
+                    || self.is_annotated(init.span().start))
+                {
+                    self.add(declarator.id.get_binding_identifier());
+                }
+            }
+        }
+        oxc_ast_visit::walk::walk_variable_declarator(self, declarator);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        // `/* #__NO_SIDE_EFFECTS__ */ function foo() {}`
+        if func.is_declaration() && self.is_annotated(func.span.start) {
+            self.add(func.id.as_ref());
+        }
+        oxc_ast_visit::walk::walk_function(self, func, flags);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use crate::{tester, CompressOptions};
+
+    fn test_with_options(source_text: &str, expected: &str, options: CompressOptions) {
+        let allocator = Allocator::default();
+        let mut pass = super::RemoveUnusedPureCalls::new(options);
+        tester::test(&allocator, source_text, expected, &mut pass);
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        test_with_options(source_text, expected, CompressOptions::all_true());
+    }
+
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn pure_annotation() {
+        test("/* #__PURE__ */ foo()", "");
+        test("/* @__PURE__ */ foo()", "");
+        test("/* #__PURE__ */ new Foo()", "");
+        test("/* #__PURE__ */ foo.bar()", "");
+        test("/* #__PURE__ */ foo(1, 'a', () => {})", "");
+        test("/* #__PURE__ */ foo(bar())", "bar()");
+        test("/* #__PURE__ */ foo(bar(), baz())", "bar(), baz()");
+        test("/* #__PURE__ */ foo(/* #__PURE__ */ bar())", "");
+        test("let x; /* #__PURE__ */ foo(x)", "let x");
+        // Reading an undeclared variable can throw.
+        test("/* #__PURE__ */ foo(x)", "x");
+        test_same("/* #__PURE__ */ foo(...args)");
+        test_same("let x = /* #__PURE__ */ foo()");
+        test_same("foo()");
+    }
+
+    #[test]
+    fn keep_other_empty_statements() {
+        // Empty statements in statement lists are removed by `RemoveSyntax` before this pass.
+        test_same("if (a) ;");
+        test("/* #__PURE__ */ foo(); if (a) ;", "if (a) ;");
+    }
+
+    #[test]
+    fn pure_annotation_in_arrow_expression() {
+        test_same("() => /* #__PURE__ */ foo()");
+        test("() => { /* #__PURE__ */ foo() }", "() => {}");
+    }
+
+    #[test]
+    fn pure_funcs() {
+        let options = CompressOptions {
+            pure_funcs: vec!["foo".into(), "console.info".into()],
+            ..CompressOptions::all_false()
+        };
+        test_with_options("foo()", "", options.clone());
+        test_with_options("console.info('x')", "", options.clone());
+        test_with_options("console.log('x')", "console.log('x')", options.clone());
+        test_with_options("bar.foo()", "bar.foo()", options);
+    }
+
+    #[test]
+    fn no_side_effects_annotation() {
+        test(
+            "/* #__NO_SIDE_EFFECTS__ */ function foo() {} foo()",
+            "/* #__NO_SIDE_EFFECTS__ */ function foo() {}",
+        );
+        test(
+            "/* #__NO_SIDE_EFFECTS__ */ export function foo() {} foo()",
+            "/* #__NO_SIDE_EFFECTS__ */ export function foo() {}",
+        );
+        test(
+            "export /* #__NO_SIDE_EFFECTS__ */ function foo() {} foo()",
+            "export /* #__NO_SIDE_EFFECTS__ */ function foo() {}",
+        );
+        test(
+            "/* #__NO_SIDE_EFFECTS__ */ const foo = () => {}; foo()",
+            "/* #__NO_SIDE_EFFECTS__ */ const foo = () => {}",
+        );
+        test(
+            "const foo = /* #__NO_SIDE_EFFECTS__ */ () => {}; foo()",
+            "const foo = /* #__NO_SIDE_EFFECTS__ */ () => {}",
+        );
+        test(
+            "foo(); /* #__NO_SIDE_EFFECTS__ */ function foo() {}",
+            "/* #__NO_SIDE_EFFECTS__ */ function foo() {}",
+        );
+        test(
+            "/*! #__NO_SIDE_EFFECTS__ */ function foo() {} foo()",
+            "/*! #__NO_SIDE_EFFECTS__ */ function foo() {}",
+        );
+        test(
+            "/* #__NO_SIDE_EFFECTS__ */ const foo = () => {}, bar = () => {}; foo(); bar()",
+            "/* #__NO_SIDE_EFFECTS__ */ const foo = () => {}, bar = () => {}; bar()",
+        );
+        test_same("/* #__NO_SIDE_EFFECTS__ */ let foo = () => {}; foo()");
+        test_same("function foo() {} foo()");
+    }
+
+    #[test]
+    fn annotations_disabled() {
+        let options = CompressOptions::all_false();
+        test_with_options("/* #__PURE__ */ foo()", "/* #__PURE__ */ foo()", options);
+    }
+}
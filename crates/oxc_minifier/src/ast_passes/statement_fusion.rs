@@ -4,14 +4,18 @@ use oxc_ecmascript::side_effects::MayHaveSideEffects;
 use oxc_span::SPAN;
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::CompressorPass;
+use crate::{node_util::is_preserved_directive, CompressorPass};
 
 /// Statement Fusion
 ///
 /// Tries to fuse all the statements in a block into a one statement by using COMMAs or statements.
+/// Blocks containing a string literal statement listed in `compress.preserve_directives` are not
+/// fused.
 ///
 /// <https://github.com/google/closure-compiler/blob/master/src/com/google/javascript/jscomp/StatementFusion.java>
 pub struct StatementFusion {
+    preserve_directives: std::vec::Vec<String>,
+
     changed: bool,
 }
 
@@ -41,21 +45,24 @@ impl<'a> Traverse<'a> for StatementFusion {
 }
 
 impl<'a> StatementFusion {
-    pub fn new() -> Self {
-        Self { changed: false }
+    pub fn new(preserve_directives: std::vec::Vec<String>) -> Self {
+        Self { preserve_directives, changed: false }
     }
 
     fn fuse_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
-        if Self::can_fuse_into_one_statement(stmts) {
+        if self.can_fuse_into_one_statement(stmts) {
             self.fuse_into_one_statement(stmts, ctx);
         }
     }
 
-    fn can_fuse_into_one_statement(stmts: &[Statement<'a>]) -> bool {
+    fn can_fuse_into_one_statement(&self, stmts: &[Statement<'a>]) -> bool {
         let len = stmts.len();
         if len <= 1 {
             return false;
         }
+        if stmts.iter().any(|s| is_preserved_directive(s, &self.preserve_directives)) {
+            return false;
+        }
         if stmts[0..len - 1].iter().any(|s| !matches!(s, Statement::ExpressionStatement(_))) {
             return false;
         }
@@ -187,11 +194,11 @@ fn can_merge_block_stmt_member(node: &Statement) -> bool {
 mod test {
     use oxc_allocator::Allocator;
 
-    use crate::tester;
+    use crate::{tester, CompressOptions};
 
     fn test(source_text: &str, expected: &str) {
         let allocator = Allocator::default();
-        let mut pass = super::StatementFusion::new();
+        let mut pass = super::StatementFusion::new(CompressOptions::default().preserve_directives);
         tester::test(&allocator, source_text, expected, &mut pass);
     }
 
@@ -348,4 +355,9 @@ mod test {
     fn no_function_block_changes() {
         test_same("function foo() { a,b,c }");
     }
+    #[test]
+    fn preserve_directives() {
+        fuse_same("a; 'use client'; b");
+        fuse("a; 'use strict'; b", "a, 'use strict', b");
+    }
 }
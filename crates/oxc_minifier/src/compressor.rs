@@ -1,13 +1,14 @@
 use oxc_allocator::Allocator;
 use oxc_ast::ast::*;
-use oxc_semantic::{ScopeTree, SemanticBuilder, SymbolTable};
+use oxc_semantic::{ObjectImmutability, ScopeTree, SemanticBuilder, SymbolTable};
 use oxc_traverse::TraverseCtx;
 
 use crate::{
     ast_passes::{
-        CollapseVariableDeclarations, ExploitAssigns, PeepholeFoldConstants,
-        PeepholeMinimizeConditions, PeepholeRemoveDeadCode, PeepholeReplaceKnownMethods,
-        PeepholeSubstituteAlternateSyntax, RemoveSyntax, StatementFusion,
+        CollapseVariableDeclarations, ConstantPropagation, DropUnusedReturns, ExploitAssigns,
+        InlineFunctions, PeepholeFoldConstants, PeepholeMinimizeConditions, PeepholeRemoveDeadCode,
+        PeepholeReplaceKnownMethods, PeepholeSubstituteAlternateSyntax, RemoveSyntax,
+        RemoveUnusedPureCalls, StatementFusion,
     },
     CompressOptions, CompressorPass,
 };
@@ -23,34 +24,54 @@ impl<'a> Compressor<'a> {
     }
 
     pub fn build(self, program: &mut Program<'a>) {
-        let (symbols, scopes) =
-            SemanticBuilder::new().build(program).semantic.into_symbol_table_and_scope_tree();
-        self.build_with_symbols_and_scopes(symbols, scopes, program);
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        let immutable_objects = ObjectImmutability::new(&semantic);
+        let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+        self.build_impl(symbols, scopes, immutable_objects, program);
     }
 
+    /// Reads of the properties of immutable objects are not folded, as finding them needs the
+    /// [`Semantic`](oxc_semantic::Semantic) of the program. Use [`Compressor::build`] for that.
     pub fn build_with_symbols_and_scopes(
         self,
         symbols: SymbolTable,
         scopes: ScopeTree,
         program: &mut Program<'a>,
+    ) {
+        self.build_impl(symbols, scopes, ObjectImmutability::default(), program);
+    }
+
+    fn build_impl(
+        self,
+        symbols: SymbolTable,
+        scopes: ScopeTree,
+        immutable_objects: ObjectImmutability,
+        program: &mut Program<'a>,
     ) {
         let mut ctx = TraverseCtx::new(scopes, symbols, self.allocator);
-        RemoveSyntax::new(self.options).build(program, &mut ctx);
+        RemoveSyntax::new(self.options.clone()).build(program, &mut ctx);
+        RemoveUnusedPureCalls::new(self.options.clone()).build(program, &mut ctx);
 
         if self.options.dead_code_elimination {
-            Self::dead_code_elimination(program, &mut ctx);
+            Self::dead_code_elimination(&self.options, program, &mut ctx);
             return;
         }
 
+        if self.options.unused_returns {
+            DropUnusedReturns::new().build(program, &mut ctx);
+        }
+
         // See `latePeepholeOptimizations`
-        let mut passes: [&mut dyn CompressorPass; 6] = [
-            &mut StatementFusion::new(),
-            &mut PeepholeRemoveDeadCode::new(),
+        let mut passes: [&mut dyn CompressorPass; 8] = [
+            &mut StatementFusion::new(self.options.preserve_directives.clone()),
+            &mut PeepholeRemoveDeadCode::new(self.options.preserve_directives.clone()),
             // TODO: MinimizeExitPoints
             &mut PeepholeMinimizeConditions::new(),
             &mut PeepholeSubstituteAlternateSyntax::new(/* in_fixed_loop */ true),
             &mut PeepholeReplaceKnownMethods::new(),
-            &mut PeepholeFoldConstants::new(),
+            &mut PeepholeFoldConstants::new(self.options.template_literals),
+            &mut ConstantPropagation::new(immutable_objects),
+            &mut InlineFunctions::new(self.options.inline),
         ];
 
         let mut i = 0;
@@ -80,9 +101,13 @@ impl<'a> Compressor<'a> {
         PeepholeSubstituteAlternateSyntax::new(/* in_fixed_loop */ false).build(program, &mut ctx);
     }
 
-    fn dead_code_elimination(program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
-        PeepholeFoldConstants::new().build(program, ctx);
+    fn dead_code_elimination(
+        options: &CompressOptions,
+        program: &mut Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        PeepholeFoldConstants::new(options.template_literals).build(program, ctx);
         PeepholeMinimizeConditions::new().build(program, ctx);
-        PeepholeRemoveDeadCode::new().build(program, ctx);
+        PeepholeRemoveDeadCode::new(options.preserve_directives.clone()).build(program, ctx);
     }
 }
@@ -1,5 +1,6 @@
 use oxc_allocator::Box as ArenaBox;
-use oxc_ast::{ast::*, AstBuilder, Visit, NONE};
+use oxc_ast::{ast::*, AstBuilder, NONE};
+use oxc_ast_visit::Visit;
 use oxc_ecmascript::BoundNames;
 use oxc_span::{Atom, Span, SPAN};
 
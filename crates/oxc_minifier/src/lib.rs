@@ -3,32 +3,61 @@
 mod ast_passes;
 mod compressor;
 mod keep_var;
+mod meta;
 mod node_util;
 mod options;
+mod stats;
 
 #[cfg(test)]
 mod tester;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
-use oxc_mangler::Mangler;
+use oxc_mangler::{MangleOptions, Mangler};
 
-pub use crate::{ast_passes::CompressorPass, compressor::Compressor, options::CompressOptions};
+pub use crate::{
+    ast_passes::CompressorPass,
+    compressor::Compressor,
+    meta::{MinifierMeta, MinifierMetafile},
+    options::{CompressOptions, InlineLevel},
+    stats::MinifierStats,
+};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct MinifierOptions {
     pub mangle: bool,
     pub compress: CompressOptions,
+
+    /// Preserve `Function.prototype.name` for functions through mangling.
+    ///
+    /// Default `false`
+    pub keep_fnames: bool,
+
+    /// Preserve the `name` of classes through mangling.
+    ///
+    /// Default `false`
+    pub keep_classnames: bool,
 }
 
 impl Default for MinifierOptions {
     fn default() -> Self {
-        Self { mangle: true, compress: CompressOptions::default() }
+        Self {
+            mangle: true,
+            compress: CompressOptions::default(),
+            keep_fnames: false,
+            keep_classnames: false,
+        }
     }
 }
 
 pub struct MinifierReturn {
     pub mangler: Option<Mangler>,
+
+    /// Size statistics, only collected by [Minifier::build_with_stats].
+    pub stats: Option<MinifierStats>,
+
+    /// Metadata of the minified file, only collected by [Minifier::build_with_meta].
+    pub meta: Option<MinifierMeta>,
 }
 
 pub struct Minifier {
@@ -41,8 +70,83 @@ impl Minifier {
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
-        Compressor::new(allocator, self.options.compress).build(program);
-        let mangler = self.options.mangle.then(|| Mangler::default().build(program));
-        MinifierReturn { mangler }
+        self.build_impl(allocator, program, false, false)
+    }
+
+    /// Same as [Minifier::build], and also collect [MinifierStats].
+    ///
+    /// The program is printed after every step to measure it, so this is considerably slower.
+    pub fn build_with_stats<'a>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+    ) -> MinifierReturn {
+        self.build_impl(allocator, program, true, false)
+    }
+
+    /// Same as [Minifier::build], and also collect [MinifierMeta].
+    ///
+    /// The program is printed to measure the size of the output.
+    pub fn build_with_meta<'a>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+    ) -> MinifierReturn {
+        self.build_impl(allocator, program, false, true)
+    }
+
+    fn build_impl<'a>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        collect_stats: bool,
+        collect_meta: bool,
+    ) -> MinifierReturn {
+        let statements_before = collect_stats.then(|| stats::count_statements(program));
+        let declarations_before = collect_meta.then(|| meta::top_level_declarations(program));
+        Compressor::new(allocator, self.options.compress.clone()).build(program);
+        let compressed = collect_stats
+            .then(|| (stats::print(program, None).len(), stats::count_statements(program)));
+        let mangler = self.options.mangle.then(|| self.mangle(program));
+        let stats = statements_before.zip(compressed).map(|(before, (compressed_size, after))| {
+            // The mangler is handed over to the caller, so a second one prints the measured code.
+            let code = stats::print(program, self.options.mangle.then(|| self.mangle(program)));
+            MinifierStats {
+                original_size: program.source_text.len(),
+                compressed_size,
+                mangled_size: code.len(),
+                gzip_size: stats::gzip_size(&code),
+                removed_statements: before.saturating_sub(after),
+                renamed_symbols: mangler.as_ref().map_or(0, Mangler::renamed_symbols),
+            }
+        });
+        let meta = declarations_before.map(|before| {
+            let after = meta::top_level_declarations(program);
+            let code = stats::print(program, self.options.mangle.then(|| self.mangle(program)));
+            MinifierMeta {
+                input_bytes: program.source_text.len(),
+                output_bytes: code.len(),
+                removed_declarations: before
+                    .into_iter()
+                    .filter(|name| !after.contains(name))
+                    .collect(),
+                exports: meta::exports(program),
+            }
+        });
+        MinifierReturn { mangler, stats, meta }
+    }
+
+    fn mangle(&self, program: &Program<'_>) -> Mangler {
+        let options = MangleOptions {
+            keep_fnames: self.options.keep_fnames,
+            keep_classnames: self.options.keep_classnames,
+            ..MangleOptions::default()
+        };
+        // Names are ordered by the characters of the printed code, which minifying the output
+        // again prints the same, rather than of the source text.
+        Mangler::new()
+            .with_options(options)
+            .with_frequency_text(stats::print(program, None))
+            .build(program)
     }
 }
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+
+use oxc_ast::ast::{ExportDefaultDeclarationKind, ModuleDeclaration, Program, Statement};
+use oxc_ecmascript::BoundNames;
+use serde::Serialize;
+
+/// Metadata of the minification of a file, see [crate::Minifier::build_with_meta].
+///
+/// Collect the metadata of each file of a bundle in a [MinifierMetafile] to describe it as JSON,
+/// e.g. for bundle analysis tools.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MinifierMeta {
+    /// Size of the source text in bytes.
+    pub input_bytes: usize,
+
+    /// Size of the minified output in bytes, printed without whitespace and, if mangling is
+    /// enabled, with mangled names.
+    pub output_bytes: usize,
+
+    /// Names of the top-level declarations which were removed, in source order.
+    pub removed_declarations: Vec<String>,
+
+    /// Names of the exports which were retained, in source order. `export * from` is not included
+    /// as its names are not known.
+    pub exports: Vec<String>,
+}
+
+/// The [MinifierMeta] of the files of a bundle, keyed by path.
+///
+/// ```json
+/// {
+///   "files": {
+///     "src/index.js": {
+///       "inputBytes": 1024,
+///       "outputBytes": 256,
+///       "removedDeclarations": ["unused"],
+///       "exports": ["default", "foo"]
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct MinifierMetafile {
+    pub files: BTreeMap<String, MinifierMeta>,
+}
+
+impl MinifierMetafile {
+    pub fn add(&mut self, path: impl Into<String>, meta: MinifierMeta) {
+        self.files.insert(path.into(), meta);
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Names of the declarations at the top level of `program`.
+pub(crate) fn top_level_declarations(program: &Program<'_>) -> Vec<String> {
+    let mut names = vec![];
+    for stmt in &program.body {
+        let declaration = match stmt {
+            Statement::ExportNamedDeclaration(decl) => decl.declaration.as_ref(),
+            stmt => stmt.as_declaration(),
+        };
+        if let Some(declaration) = declaration {
+            declaration.bound_names(&mut |ident| names.push(ident.name.to_string()));
+        }
+    }
+    names
+}
+
+/// Names of the exports of `program`.
+pub(crate) fn exports(program: &Program<'_>) -> Vec<String> {
+    let mut names = vec![];
+    for stmt in &program.body {
+        let Some(decl) = stmt.as_module_declaration() else {
+            continue;
+        };
+        match decl {
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = &decl.declaration {
+                    declaration.bound_names(&mut |ident| names.push(ident.name.to_string()));
+                }
+                names
+                    .extend(decl.specifiers.iter().map(|specifier| specifier.exported.to_string()));
+            }
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                if !matches!(
+                    decl.declaration,
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)
+                ) {
+                    names.push("default".to_string());
+                }
+            }
+            ModuleDeclaration::ExportAllDeclaration(decl) => {
+                if let Some(exported) = &decl.exported {
+                    names.push(exported.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    names
+}
@@ -25,6 +25,19 @@ pub fn is_exact_int64(num: f64) -> bool {
     num.fract() == 0.0
 }
 
+/// Whether `stmt` is a string literal statement listed in `compress.preserve_directives`,
+/// e.g. `"use client"` below an import.
+pub fn is_preserved_directive(stmt: &Statement, preserve_directives: &[String]) -> bool {
+    matches!(stmt, Statement::ExpressionStatement(stmt)
+        if matches!(&stmt.expression, Expression::StringLiteral(lit)
+            if preserve_directives.iter().any(|directive| directive == lit.value.as_str())))
+}
+
+/// Whether `prop` can still be printed as `{ x }` after its value is replaced.
+pub fn can_be_shorthand(prop: &ObjectProperty) -> bool {
+    matches!(&prop.value, Expression::Identifier(ident) if prop.key.is_specific_id(&ident.name))
+}
+
 impl<'a, 'b> Ctx<'a, 'b> {
     fn symbols(&self) -> &SymbolTable {
         self.0.symbols()
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct CompressOptions {
     pub dead_code_elimination: bool,
 
@@ -11,25 +11,106 @@ pub struct CompressOptions {
     ///
     /// Default `false`
     pub drop_console: bool,
+
+    /// Remove unused calls annotated with `/* #__PURE__ */`,
+    /// and unused calls to functions annotated with `/* #__NO_SIDE_EFFECTS__ */`.
+    ///
+    /// Default `true`
+    pub annotations: bool,
+
+    /// Names of functions whose unused calls can be removed, e.g. `["Math.floor", "debug"]`.
+    ///
+    /// Default `[]`
+    pub pure_funcs: Vec<String>,
+
+    /// Inline functions into their call sites.
+    ///
+    /// Default `InlineLevel::Off`
+    pub inline: InlineLevel,
+
+    /// Directives which are kept as statements where they are not in a directive prologue, e.g.
+    /// React Server Components markers moved below the imports by a bundler.
+    /// Directive prologues are always kept as is.
+    ///
+    /// Default `["use client", "use server"]`
+    pub preserve_directives: Vec<String>,
+
+    /// Fold additions of strings and template literals into template literals, e.g.
+    /// `` "a" + `b${c}` `` -> `` `ab${c}` ``.
+    ///
+    /// Disable for engines predating ES2015, which do not support template literals.
+    ///
+    /// Default `true`
+    pub template_literals: bool,
+
+    /// Drop the return values of local functions which are only called in void positions, e.g.
+    /// `function f() { return g() } f()` -> `function f() { g() } f()`.
+    ///
+    /// Callbacks passed to `forEach` are assumed to be called by the built-in `forEach` of
+    /// arrays, maps, sets or DOM collections, which ignores their return values.
+    ///
+    /// Default `true`
+    pub unused_returns: bool,
+}
+
+/// How aggressively functions are inlined.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InlineLevel {
+    /// Do not inline functions.
+    #[default]
+    Off,
+    /// Inline immediately invoked functions without parameters: `(() => x)()` -> `x`.
+    Iife,
+    /// Also inline functions that return a single expression and are called once.
+    Functions,
 }
 
 #[allow(clippy::derivable_impls)]
 impl Default for CompressOptions {
     fn default() -> Self {
-        Self { dead_code_elimination: false, drop_console: false, ..Self::all_true() }
+        Self {
+            dead_code_elimination: false,
+            drop_console: false,
+            inline: InlineLevel::Off,
+            ..Self::all_true()
+        }
     }
 }
 
 impl CompressOptions {
     pub fn all_true() -> Self {
-        Self { dead_code_elimination: false, drop_debugger: true, drop_console: true }
+        Self {
+            dead_code_elimination: false,
+            drop_debugger: true,
+            drop_console: true,
+            annotations: true,
+            pure_funcs: vec![],
+            inline: InlineLevel::Functions,
+            preserve_directives: Self::default_preserve_directives(),
+            template_literals: true,
+            unused_returns: true,
+        }
     }
 
     pub fn all_false() -> Self {
-        Self { dead_code_elimination: false, drop_debugger: false, drop_console: false }
+        Self {
+            dead_code_elimination: false,
+            drop_debugger: false,
+            drop_console: false,
+            annotations: false,
+            pure_funcs: vec![],
+            inline: InlineLevel::Off,
+            preserve_directives: Self::default_preserve_directives(),
+            template_literals: false,
+            unused_returns: false,
+        }
     }
 
     pub fn dead_code_elimination() -> Self {
         Self { dead_code_elimination: true, ..Self::all_false() }
     }
+
+    fn default_preserve_directives() -> Vec<String> {
+        vec!["use client".into(), "use server".into()]
+    }
 }
@@ -0,0 +1,69 @@
+use std::io::Write;
+
+use flate2::{write::GzEncoder, Compression};
+use oxc_ast::ast::{Program, Statement};
+use oxc_ast_visit::{walk, Visit};
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_mangler::Mangler;
+
+/// Size statistics of a minification, see [crate::Minifier::build_with_stats].
+///
+/// Sizes are in bytes. Apart from `original_size`, they are measured on the output of
+/// [CodeGenerator] with whitespace removed, so that the effect of each pass can be told apart
+/// from the effect of printing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MinifierStats {
+    /// Size of the source text.
+    pub original_size: usize,
+
+    /// Size of the output after compression, without mangling.
+    pub compressed_size: usize,
+
+    /// Size of the output after compression and mangling.
+    ///
+    /// Equal to `compressed_size` when mangling is disabled.
+    pub mangled_size: usize,
+
+    /// Size of the final output after gzip compression.
+    pub gzip_size: usize,
+
+    /// Number of statements removed by compression.
+    pub removed_statements: usize,
+
+    /// Number of symbols renamed by mangling.
+    pub renamed_symbols: usize,
+}
+
+pub(crate) fn print(program: &Program<'_>, mangler: Option<Mangler>) -> String {
+    CodeGenerator::new()
+        .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+        .with_mangler(mangler)
+        .build(program)
+        .code
+}
+
+pub(crate) fn gzip_size(code: &str) -> usize {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(code.as_bytes())
+        .and_then(|()| encoder.finish())
+        .map_or(0, |bytes| bytes.len())
+}
+
+pub(crate) fn count_statements(program: &Program<'_>) -> usize {
+    let mut counter = StatementCounter::default();
+    counter.visit_program(program);
+    counter.count
+}
+
+#[derive(Default)]
+struct StatementCounter {
+    count: usize,
+}
+
+impl<'a> Visit<'a> for StatementCounter {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        self.count += 1;
+        walk::walk_statement(self, stmt);
+    }
+}
@@ -57,3 +57,24 @@ fn tagged_template() {
     test("foo(true && o.f)", "foo(o.f)");
     test("foo(true ? o.f : false)", "foo(o.f)");
 }
+
+#[test]
+fn directives() {
+    // Directive prologues are kept in order, whether the directive is known or not.
+    test_same("'use client'; 'use custom'; 'use strict'; foo()");
+    test_same("export function foo() { 'use server'; 'use custom'; return bar() }");
+
+    // Directives listed in `preserve_directives` are kept outside of a prologue.
+    test_same("import a from 'a'; 'use client'; a()");
+    test("import a from 'a'; 'use custom'; a()", "import a from 'a'; a()");
+
+    let options = CompressOptions {
+        preserve_directives: vec!["use custom".into()],
+        ..CompressOptions::default()
+    };
+    crate::test(
+        "import a from 'a'; 'use custom'; a()",
+        "import a from 'a'; 'use custom'; a()",
+        options,
+    );
+}
@@ -2,16 +2,20 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_mangler::Mangler;
+use oxc_mangler::{MangleOptions, Mangler};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
-fn mangle(source_text: &str) -> String {
+fn mangle(source_text: &str, options: MangleOptions) -> String {
+    mangle_with(source_text, Mangler::new().with_options(options))
+}
+
+fn mangle_with(source_text: &str, mangler: Mangler) -> String {
     let allocator = Allocator::default();
     let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let program = ret.program;
-    let mangler = Mangler::new().build(&program);
+    let mangler = mangler.build(&program);
     CodeGenerator::new().with_mangler(Some(mangler)).build(&program).code
 }
 
@@ -27,7 +31,7 @@ fn mangler() {
     ];
 
     let snapshot = cases.into_iter().fold(String::new(), |mut w, case| {
-        write!(w, "{case}\n{}\n", mangle(case)).unwrap();
+        write!(w, "{case}\n{}\n", mangle(case, MangleOptions::default())).unwrap();
         w
     });
 
@@ -35,3 +39,59 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+#[test]
+fn top_level() {
+    let cases = [
+        "function foo(a) {a}",
+        "var x = 1; export var y = x;",
+        "var x = 1, y = x; export { x, y as z }",
+        "function foo(a) { eval('') }",
+    ];
+
+    let snapshot = cases.into_iter().fold(String::new(), |mut w, case| {
+        let options = MangleOptions { top_level: true, ..MangleOptions::default() };
+        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("top_level", snapshot);
+    });
+}
+
+#[test]
+fn reserved() {
+    let options = MangleOptions { reserved: vec!["b".into()], ..MangleOptions::default() };
+    assert_eq!(
+        mangle("function foo(a, b) { a; b }", options),
+        "function foo(o, b) {\n\to;\n\tb;\n}\n"
+    );
+}
+
+#[test]
+fn keep_names() {
+    let source = "function foo(a) { function bar() {} let baz = () => {}; let qux = class {}; a(bar, baz, qux) }";
+
+    let options = MangleOptions { keep_fnames: true, ..MangleOptions::default() };
+    assert_eq!(
+        mangle(source, options),
+        "function foo(a) {\n\tfunction bar() {}\n\tlet baz = () => {};\n\tlet b = class {};\n\ta(bar, baz, b);\n}\n"
+    );
+
+    let options = MangleOptions { keep_classnames: true, ..MangleOptions::default() };
+    assert_eq!(
+        mangle(source, options),
+        "function foo(n) {\n\tfunction o() {}\n\tlet t = () => {};\n\tlet qux = class {};\n\tn(o, t, qux);\n}\n"
+    );
+}
+
+#[test]
+fn frequency_text() {
+    // `z` is the most frequent character of the source text because of the comment.
+    let source = "/* zzzzzzzz */ function foo(a) { a }";
+    assert_eq!(mangle(source, MangleOptions::default()), "function foo(z) {\n\tz;\n}\n");
+
+    let mangler = Mangler::new().with_frequency_text("function foo(a) { a }".into());
+    assert_eq!(mangle_with(source, mangler), "function foo(o) {\n\to;\n}\n");
+}
@@ -0,0 +1,27 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{Minifier, MinifierMetafile, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+#[test]
+fn meta() {
+    let source_text = "const x = 1; f(x); export const y = 2; export function g() {} export { g as h }; export default 1; export * from 'a'; export * as ns from 'b';";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let ret = Minifier::new(MinifierOptions::default()).build(&allocator, &mut program);
+    assert!(ret.meta.is_none());
+
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let ret = Minifier::new(MinifierOptions::default()).build_with_meta(&allocator, &mut program);
+    let meta = ret.meta.unwrap();
+    assert_eq!(meta.input_bytes, source_text.len());
+    assert!(meta.output_bytes < meta.input_bytes);
+    assert_eq!(meta.removed_declarations, vec!["x"]);
+    assert_eq!(meta.exports, vec!["y", "g", "h", "default", "ns"]);
+
+    let mut metafile = MinifierMetafile::default();
+    metafile.add("index.js", meta);
+    let json: serde_json::Value = serde_json::from_str(&metafile.to_json()).unwrap();
+    assert_eq!(json["files"]["index.js"]["removedDeclarations"], serde_json::json!(["x"]));
+    assert_eq!(json["files"]["index.js"]["inputBytes"], serde_json::json!(source_text.len()));
+}
@@ -1,5 +1,7 @@
 mod ast_passes;
 mod mangler;
+mod meta;
+mod stats;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::{CodeGenerator, CodegenOptions};
@@ -0,0 +1,25 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+#[test]
+fn stats() {
+    let source_text = "function foo(longName) { if (false) { bar(); } return longName; } foo(1);";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let ret = Minifier::new(MinifierOptions::default()).build(&allocator, &mut program);
+    assert!(ret.stats.is_none());
+
+    let mut program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let ret = Minifier::new(MinifierOptions::default()).build_with_stats(&allocator, &mut program);
+    let stats = ret.stats.unwrap();
+    assert_eq!(stats.original_size, source_text.len());
+    assert!(stats.compressed_size < stats.original_size);
+    assert!(stats.mangled_size < stats.compressed_size);
+    assert!(stats.gzip_size > 0);
+    // `if (false) { bar(); }` is three statements.
+    assert!(stats.removed_statements >= 3);
+    assert_eq!(stats.renamed_symbols, ret.mangler.unwrap().renamed_symbols());
+    assert!(stats.renamed_symbols > 0);
+}
@@ -2,7 +2,8 @@
 //!
 //! * <https://github.com/guybedford/es-module-lexer>
 
-use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{walk, Visit};
 use oxc_ecmascript::BoundNames;
 use oxc_span::{Atom, GetSpan};
 
@@ -329,6 +329,24 @@ impl<'a> ParserImpl<'a> {
         result
     }
 
+    /// Parse a nested construct with `cb`, failing if it exceeds
+    /// [`ParseOptions::max_nesting_depth`](crate::ParseOptions::max_nesting_depth).
+    #[inline]
+    pub(crate) fn nested<F, T>(&mut self, cb: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        if let Some(max_depth) = self.options.max_nesting_depth {
+            if self.depth >= max_depth {
+                return Err(diagnostics::nesting_too_deep(self.cur_token().span(), max_depth));
+            }
+        }
+        self.depth += 1;
+        let result = cb(self);
+        self.depth -= 1;
+        result
+    }
+
     pub(crate) fn consume_decorators(&mut self) -> Vec<'a, Decorator<'a>> {
         let decorators = std::mem::take(&mut self.state.decorators);
         self.ast.vec_from_iter(decorators)
@@ -27,6 +27,12 @@ pub fn overlong_source() -> OxcDiagnostic {
     OxcDiagnostic::error("Source length exceeds 4 GiB limit")
 }
 
+#[cold]
+pub fn nesting_too_deep(span: Span, max_depth: u32) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Nesting depth exceeds the maximum of {max_depth}"))
+        .with_label(span)
+}
+
 #[cold]
 pub fn flow(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span)
@@ -79,6 +85,14 @@ pub fn reg_exp_flag(x0: char, span1: Span) -> OxcDiagnostic {
         .with_label(span1)
 }
 
+#[cold]
+pub fn reg_exp_unsupported_feature(feature: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The configured targets do not support {feature} in regular expressions"
+    ))
+    .with_label(span)
+}
+
 #[cold]
 pub fn reg_exp_flag_twice(x0: char, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Flag {x0} is mentioned twice in regular expression literal"))
@@ -232,6 +246,13 @@ pub fn for_await(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[cold]
+pub fn source_phase_import_specifiers(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Source phase imports must have a single default binding")
+        .with_label(span)
+        .with_help("Use `import source x from \"y\"`")
+}
+
 #[cold]
 pub fn new_dynamic_import(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Cannot use new with dynamic import").with_label(span)
@@ -503,3 +524,30 @@ pub fn computed_property_names_not_allowed_in_enums(span: Span) -> OxcDiagnostic
 pub fn enum_member_cannot_have_numeric_name(span: Span) -> OxcDiagnostic {
     ts_error("2452", "An enum member cannot have a numeric name.").with_label(span)
 }
+
+#[cold]
+pub fn json_unexpected_value(span: Span, mode: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Expected a {mode} value")).with_label(span)
+}
+
+#[cold]
+pub fn json_single_quoted_string(span: Span, mode: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Strings must be double quoted in {mode}"))
+        .with_label(span)
+        .with_help("Use `\"` instead of `'`")
+}
+
+#[cold]
+pub fn json_invalid_number(span: Span, mode: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid number in {mode}")).with_label(span)
+}
+
+#[cold]
+pub fn json_trailing_comma(span: Span, mode: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Trailing commas are not allowed in {mode}")).with_label(span)
+}
+
+#[cold]
+pub fn json_comment(span: Span, mode: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Comments are not allowed in {mode}")).with_label(span)
+}
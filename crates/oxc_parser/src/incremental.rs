@@ -0,0 +1,132 @@
+//! Incremental re-parsing, see [`crate::Parser::reparse`].
+
+use oxc_ast::ast::Expression;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ParserImpl, ParserReturn, MAX_LEN};
+
+impl<'a> ParserImpl<'a> {
+    pub(crate) fn reparse(mut self, previous: ParserReturn<'a>, changed: Span) -> ParserReturn<'a> {
+        let Some((reused, offset)) = self.reusable_statements(&previous, changed) else {
+            return self.parse();
+        };
+        let ParserReturn {
+            mut program,
+            errors,
+            irregular_whitespaces,
+            skipped_spans,
+            lazy_function_bodies,
+            ..
+        } = previous;
+
+        self.lexer.skip_to(offset);
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let panicked = match self.parse_directives_and_statements(/* is_top_level */ true) {
+            Ok((directives, statements)) => {
+                program.body.truncate(reused);
+                // String literals after the reused statements are not directives.
+                for directive in directives {
+                    let expr = Expression::StringLiteral(self.ast.alloc(directive.expression));
+                    program.body.push(self.ast.statement_expression(directive.span, expr));
+                }
+                program.body.extend(statements);
+                program.comments.retain(|comment| comment.span.end <= offset);
+                program.comments.extend(self.lexer.trivia_builder.comments.iter().copied());
+                #[allow(clippy::cast_possible_truncation)]
+                let span = Span::new(0, self.source_text.len() as u32);
+                program.span = span;
+                program.source_text = self.source_text;
+                false
+            }
+            Err(error) => {
+                self.error(error);
+                program = self.empty_program();
+                true
+            }
+        };
+
+        let mut ret = self.finish(program, panicked);
+        // Keep what was found in the reused statements.
+        let len = offset as usize;
+        let is_reused = |span: &Span| span.end <= offset;
+        ret.errors = errors
+            .into_iter()
+            .filter(|error| {
+                error.labels.iter().flatten().all(|label| label.offset() + label.len() <= len)
+            })
+            .chain(std::mem::take(&mut ret.errors))
+            .collect();
+        ret.irregular_whitespaces = irregular_whitespaces
+            .iter()
+            .copied()
+            .filter(is_reused)
+            .chain(ret.irregular_whitespaces.iter().copied())
+            .collect();
+        ret.skipped_spans = skipped_spans
+            .into_iter()
+            .filter(is_reused)
+            .chain(std::mem::take(&mut ret.skipped_spans))
+            .collect();
+        ret.lazy_function_bodies = lazy_function_bodies
+            .into_iter()
+            .filter(|body| is_reused(&body.span))
+            .chain(std::mem::take(&mut ret.lazy_function_bodies))
+            .collect();
+        ret
+    }
+
+    /// The number of top-level statements of `previous` which can be reused, and the offset to
+    /// resume parsing from. `None` if nothing can be reused.
+    fn reusable_statements(
+        &self,
+        previous: &ParserReturn<'a>,
+        changed: Span,
+    ) -> Option<(usize, u32)> {
+        let program = &previous.program;
+        if previous.panicked
+            || self.source_text.len() > MAX_LEN
+            // The source type of unambiguous files depends on the whole program.
+            || self.source_type.is_unambiguous()
+            || program.source_type != self.source_type
+        {
+            return None;
+        }
+        // Errors without a location may be caused by any statement.
+        if previous.errors.iter().any(|error| error.labels.is_none()) {
+            return None;
+        }
+        // `@flow` is detected from the first comment of the whole file.
+        if program
+            .comments
+            .first()
+            .is_some_and(|comment| comment.span.source_text(program.source_text).contains("@flow"))
+        {
+            return None;
+        }
+
+        // The statement right before the edit is parsed again, because the edit may continue it,
+        // e.g. `a` followed by `(b)` on the next line becomes `a(b)`.
+        let reused = program
+            .body
+            .iter()
+            .take_while(|stmt| stmt.span().end < changed.start)
+            .count()
+            .saturating_sub(1);
+        if reused == 0 {
+            return None;
+        }
+        let offset = program.body[reused - 1].span().end;
+
+        // The source text of the reused statements must be unchanged.
+        let len = offset as usize;
+        match (self.source_text.get(..len), program.source_text.get(..len)) {
+            (Some(source_text), Some(previous_source_text))
+                if source_text == previous_source_text =>
+            {
+                Some((reused, offset))
+            }
+            _ => None,
+        }
+    }
+}
@@ -2,7 +2,7 @@ use cow_utils::CowUtils;
 use oxc_allocator::Box;
 use oxc_ast::ast::*;
 use oxc_diagnostics::Result;
-use oxc_regular_expression::ast::Pattern;
+use oxc_regular_expression::{ast::Pattern, RegExpFeatures};
 use oxc_span::{Atom, Span};
 use oxc_syntax::{
     number::{BigintBase, NumberBase},
@@ -383,7 +383,21 @@ impl<'a> ParserImpl<'a> {
         )
         .parse()
         {
-            Ok(regular_expression) => Some(self.alloc(regular_expression)),
+            Ok(regular_expression) => {
+                let unsupported = self.options.unsupported_regular_expression_features
+                    & (RegExpFeatures::of_flags(flags)
+                        | RegExpFeatures::of_pattern(&regular_expression));
+                #[allow(clippy::cast_possible_truncation)]
+                let span =
+                    Span::new(pattern_span_offset - 1, flags_span_offset + flags.len() as u32);
+                for feature in unsupported.iter() {
+                    self.error(diagnostics::reg_exp_unsupported_feature(
+                        feature.description(),
+                        span,
+                    ));
+                }
+                Some(self.alloc(regular_expression))
+            }
             Err(diagnostic) => {
                 self.error(diagnostic);
                 None
@@ -744,7 +758,7 @@ impl<'a> ParserImpl<'a> {
         let rhs_span = self.start_span();
 
         let mut optional = false;
-        let mut callee = self.parse_member_expression_or_higher(&mut optional)?;
+        let mut callee = self.nested(|p| p.parse_member_expression_or_higher(&mut optional))?;
 
         let mut type_parameter = None;
         if let Expression::TSInstantiationExpression(instantiation_expr) = callee {
@@ -869,7 +883,7 @@ impl<'a> ParserImpl<'a> {
         if kind.is_update_operator() {
             let operator = map_update_operator(kind);
             self.bump_any();
-            let argument = self.parse_unary_expression_or_higher(lhs_span)?;
+            let argument = self.nested(|p| p.parse_unary_expression_or_higher(lhs_span))?;
             let argument = SimpleAssignmentTarget::cover(argument, self)?;
             return Ok(self.ast.expression_update(
                 self.end_span(lhs_span),
@@ -934,7 +948,7 @@ impl<'a> ParserImpl<'a> {
         let span = self.start_span();
         let operator = map_unary_operator(self.cur_kind());
         self.bump_any();
-        let argument = self.parse_simple_unary_expression(span)?;
+        let argument = self.nested(|p| p.parse_simple_unary_expression(span))?;
         Ok(self.ast.expression_unary(self.end_span(span), operator, argument))
     }
 
@@ -1054,6 +1068,10 @@ impl<'a> ParserImpl<'a> {
 
     /// `AssignmentExpression`[In, Yield, Await] :
     pub(crate) fn parse_assignment_expression_or_higher(&mut self) -> Result<Expression<'a>> {
+        self.nested(Self::parse_assignment_expression_or_higher_impl)
+    }
+
+    fn parse_assignment_expression_or_higher_impl(&mut self) -> Result<Expression<'a>> {
         // [+Yield] YieldExpression
         if self.is_yield_expression() {
             return self.parse_yield_expression();
@@ -1125,7 +1143,7 @@ impl<'a> ParserImpl<'a> {
             self.error(diagnostics::await_expression(Span::new(span.start, span.start + 5)));
         }
         let argument = self.context(Context::Await, Context::empty(), |p| {
-            p.parse_simple_unary_expression(lhs_span)
+            p.nested(|p| p.parse_simple_unary_expression(lhs_span))
         })?;
         Ok(self.ast.expression_await(self.end_span(span), argument))
     }
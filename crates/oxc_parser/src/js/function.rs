@@ -8,7 +8,8 @@ use crate::{
     diagnostics,
     lexer::Kind,
     modifiers::{ModifierFlags, ModifierKind, Modifiers},
-    Context, ParserImpl, StatementContext,
+    tokens::TokenScanner,
+    Context, LazyFunctionBody, ParserImpl, StatementContext,
 };
 
 impl FunctionKind {
@@ -30,6 +31,15 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn parse_function_body(&mut self) -> Result<Box<'a, FunctionBody<'a>>> {
+        if self.options.lazy_function_bodies {
+            if let Some(body) = self.skip_function_body() {
+                return Ok(body);
+            }
+        }
+        self.parse_function_body_eagerly()
+    }
+
+    pub(crate) fn parse_function_body_eagerly(&mut self) -> Result<Box<'a, FunctionBody<'a>>> {
         let span = self.start_span();
         self.expect(Kind::LCurly)?;
 
@@ -41,6 +51,46 @@ impl<'a> ParserImpl<'a> {
         Ok(self.ast.alloc_function_body(self.end_span(span), directives, statements))
     }
 
+    /// Skip the tokens of a function body for [`crate::ParseOptions::lazy_function_bodies`],
+    /// returning an empty body. Returns `None` without advancing if the end of the body cannot be
+    /// found by matching braces, e.g. for JSX or lexer errors, so the body is parsed eagerly.
+    fn skip_function_body(&mut self) -> Option<Box<'a, FunctionBody<'a>>> {
+        if !self.at(Kind::LCurly) {
+            return None;
+        }
+        let checkpoint = self.checkpoint();
+        let lexer_errors = self.lexer.errors.len();
+        let span = self.start_span();
+        self.bump_any();
+        let mut scanner = TokenScanner::default();
+        let closed = loop {
+            match self.cur_kind() {
+                Kind::Eof => break false,
+                Kind::LAngle if self.source_type.is_jsx() => break false,
+                Kind::RCurly if scanner.is_top_level() => {
+                    self.bump_any();
+                    break true;
+                }
+                _ => {}
+            }
+            if self.rescan_token(&mut scanner).is_err() {
+                break false;
+            }
+            self.bump_any();
+        };
+        if !closed || self.lexer.errors.len() != lexer_errors {
+            self.rewind(checkpoint);
+            return None;
+        }
+        let span = self.end_span(span);
+        self.state.lazy_function_bodies.push(LazyFunctionBody {
+            span,
+            r#async: self.ctx.has_await(),
+            generator: self.ctx.has_yield(),
+        });
+        Some(self.ast.alloc_function_body(span, self.ast.vec(), self.ast.vec()))
+    }
+
     pub(crate) fn parse_formal_parameters(
         &mut self,
         params_kind: FormalParameterKind,
@@ -47,6 +47,13 @@ impl<'a> ParserImpl<'a> {
         // `import type ...`
         let import_kind = self.parse_import_or_export_kind();
 
+        // `import source ...`, `import defer ...`
+        let phase = if self.options.experimental_import_phases && import_kind.is_value() {
+            self.parse_import_phase()
+        } else {
+            None
+        };
+
         let specifiers = if self.at(Kind::Str) {
             // import "source"
             None
@@ -54,6 +61,18 @@ impl<'a> ParserImpl<'a> {
             Some(self.parse_import_declaration_specifiers()?)
         };
 
+        if phase == Some(ImportPhase::Source) {
+            let is_single_default = specifiers.as_ref().is_some_and(|specifiers| {
+                matches!(
+                    specifiers.as_slice(),
+                    [ImportDeclarationSpecifier::ImportDefaultSpecifier(_)]
+                )
+            });
+            if !is_single_default {
+                self.error(diagnostics::source_phase_import_specifiers(self.end_span(span)));
+            }
+        }
+
         let source = self.parse_literal_string()?;
         let with_clause = self.parse_import_attributes()?;
         self.asi()?;
@@ -66,10 +85,39 @@ impl<'a> ParserImpl<'a> {
                 source,
                 with_clause,
                 import_kind,
+                phase,
             )
             .into())
     }
 
+    /// `source` in `import source x from "y"`, or `defer` in `import defer * as ns from "y"`.
+    ///
+    /// Both are also valid names of default imports, e.g. `import source from "y"`.
+    fn parse_import_phase(&mut self) -> Option<ImportPhase> {
+        if !self.at(Kind::Ident) {
+            return None;
+        }
+        let phase = match self.cur_src() {
+            "source" => ImportPhase::Source,
+            "defer" => ImportPhase::Defer,
+            _ => return None,
+        };
+        let is_phase = match phase {
+            // `import source from "y"` is a default import, `import source from from "y"` is not
+            ImportPhase::Source => {
+                self.peek_kind().is_binding_identifier()
+                    && !(self.peek_at(Kind::From) && self.nth_at(2, Kind::Str))
+            }
+            // Only namespace imports can be deferred.
+            ImportPhase::Defer => self.peek_at(Kind::Star),
+        };
+        if !is_phase {
+            return None;
+        }
+        self.bump_any();
+        Some(phase)
+    }
+
     // Full Syntax: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import#syntax>
     fn parse_import_declaration_specifiers(
         &mut self,
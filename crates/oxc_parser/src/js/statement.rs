@@ -40,7 +40,20 @@ impl<'a> ParserImpl<'a> {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let ctx = self.ctx;
+            let span = self.start_span();
+            let stmt = match self.parse_statement_list_item(StatementContext::StatementList) {
+                Ok(stmt) => stmt,
+                Err(error) if self.options.recover_from_errors => {
+                    self.error(error);
+                    self.ctx = ctx;
+                    self.state.decorators.clear();
+                    self.skip_to_next_statement(span, is_top_level);
+                    expecting_directives = false;
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
 
             if is_top_level && stmt.is_module_declaration() {
                 self.set_source_type_to_module_if_unambiguous();
@@ -71,12 +84,54 @@ impl<'a> ParserImpl<'a> {
         Ok((directives, statements))
     }
 
+    /// Skip the tokens of a statement which failed to parse.
+    /// See [`ParseOptions::recover_from_errors`].
+    ///
+    /// Stops after a `;` or before a token on a new line, ignoring the ones nested in brackets,
+    /// or before the `}` which closes the current function body.
+    /// Lexer errors of the skipped tokens are not reported.
+    ///
+    /// [`ParseOptions::recover_from_errors`]: crate::ParseOptions::recover_from_errors
+    fn skip_to_next_statement(&mut self, span: Span, is_top_level: bool) {
+        let lexer_errors_len = self.lexer.errors.len();
+        // Always make progress, otherwise the statement list loop would not terminate.
+        if self.cur_token().start == span.start && !self.at(Kind::Eof) {
+            self.bump_any();
+        }
+        let mut depth = 0u32;
+        loop {
+            match self.cur_kind() {
+                Kind::Eof => break,
+                Kind::Semicolon if depth == 0 => {
+                    self.bump_any();
+                    break;
+                }
+                Kind::RCurly if depth == 0 && !is_top_level => break,
+                _ if depth == 0 && self.cur_token().is_on_new_line => break,
+                Kind::LCurly | Kind::LParen | Kind::LBrack => depth += 1,
+                Kind::RCurly | Kind::RParen | Kind::RBrack => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            self.bump_any();
+        }
+        self.lexer.errors.truncate(lexer_errors_len);
+        self.state.cover_initialized_name.retain(|&start, _| start < span.start);
+        self.state.skipped_spans.push(self.end_span(span));
+    }
+
     /// `StatementListItem`[Yield, Await, Return] :
     ///     Statement[?Yield, ?Await, ?Return]
     ///     Declaration[?Yield, ?Await]
     pub(crate) fn parse_statement_list_item(
         &mut self,
         stmt_ctx: StatementContext,
+    ) -> Result<Statement<'a>> {
+        self.nested(|p| p.parse_statement_list_item_impl(stmt_ctx))
+    }
+
+    fn parse_statement_list_item_impl(
+        &mut self,
+        stmt_ctx: StatementContext,
     ) -> Result<Statement<'a>> {
         let start_span = self.start_span();
 
@@ -0,0 +1,182 @@
+//! Parsing JSON and its supersets, see [`crate::Parser::parse_json`].
+//!
+//! JSON values are a subset of JavaScript expressions, so they are parsed as an [`Expression`]
+//! and then checked against the grammar of the [`JsonMode`].
+
+use oxc_ast::ast::{
+    ArrayExpressionElement, Expression, ObjectPropertyKind, PropertyKey, PropertyKind,
+    UnaryOperator,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::number::NumberBase;
+
+use crate::{diagnostics, lexer::Kind, ParserImpl};
+
+/// Dialect of JSON accepted by [`crate::Parser::parse_json`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsonMode {
+    /// Strict JSON, see <https://www.json.org>.
+    Json,
+    /// JSON with comments and trailing commas, as used by `tsconfig.json` and `.oxlintrc.json`.
+    #[default]
+    Jsonc,
+    /// JSON5, see <https://spec.json5.org>.
+    Json5,
+}
+
+impl JsonMode {
+    fn allows_comments_and_trailing_commas(self) -> bool {
+        matches!(self, Self::Jsonc | Self::Json5)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Jsonc => "JSONC",
+            Self::Json5 => "JSON5",
+        }
+    }
+}
+
+struct JsonChecker<'s> {
+    source_text: &'s str,
+    mode: JsonMode,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl JsonChecker<'_> {
+    fn check_value(&mut self, expr: &Expression<'_>) {
+        match expr {
+            Expression::NullLiteral(_) | Expression::BooleanLiteral(_) => {}
+            Expression::StringLiteral(lit) => self.check_string(lit.span),
+            Expression::NumericLiteral(lit) => self.check_number(lit.span, lit.raw, lit.base),
+            Expression::Identifier(ident)
+                if self.mode == JsonMode::Json5
+                    && matches!(ident.name.as_str(), "Infinity" | "NaN") => {}
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation
+                    || (self.mode == JsonMode::Json5
+                        && unary.operator == UnaryOperator::UnaryPlus) =>
+            {
+                match &unary.argument {
+                    Expression::NumericLiteral(lit) => {
+                        self.check_number(lit.span, lit.raw, lit.base);
+                    }
+                    Expression::Identifier(ident)
+                        if self.mode == JsonMode::Json5
+                            && matches!(ident.name.as_str(), "Infinity" | "NaN") => {}
+                    argument => self.unexpected(argument.span()),
+                }
+            }
+            Expression::ArrayExpression(array) => {
+                for element in &array.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(_)
+                        | ArrayExpressionElement::Elision(_) => self.unexpected(element.span()),
+                        _ => self.check_value(element.to_expression()),
+                    }
+                }
+                self.check_trailing_comma(array.trailing_comma);
+            }
+            Expression::ObjectExpression(object) => {
+                for property in &object.properties {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        self.unexpected(property.span());
+                        continue;
+                    };
+                    if property.kind != PropertyKind::Init
+                        || property.method
+                        || property.shorthand
+                        || property.computed
+                    {
+                        self.unexpected(property.span);
+                        continue;
+                    }
+                    match &property.key {
+                        PropertyKey::StringLiteral(lit) => self.check_string(lit.span),
+                        PropertyKey::StaticIdentifier(_) if self.mode == JsonMode::Json5 => {}
+                        key => self.unexpected(key.span()),
+                    }
+                    self.check_value(&property.value);
+                }
+                self.check_trailing_comma(object.trailing_comma);
+            }
+            _ => self.unexpected(expr.span()),
+        }
+    }
+
+    fn check_string(&mut self, span: Span) {
+        if self.mode != JsonMode::Json5 && !span.source_text(self.source_text).starts_with('"') {
+            self.errors.push(diagnostics::json_single_quoted_string(span, self.mode.name()));
+        }
+    }
+
+    /// Numbers are decimal without separators, leading zeros, or a leading or trailing `.`,
+    /// JSON5 also allows hexadecimal numbers and a leading or trailing `.`.
+    fn check_number(&mut self, span: Span, raw: &str, base: NumberBase) {
+        let is_valid = if self.mode == JsonMode::Json5 {
+            matches!(base, NumberBase::Float | NumberBase::Decimal | NumberBase::Hex)
+                && !raw.contains('_')
+        } else {
+            let bytes = raw.as_bytes();
+            base.is_base_10()
+                && !raw.contains('_')
+                && bytes[0] != b'.'
+                && !raw.ends_with('.')
+                && !raw.contains(".e")
+                && !raw.contains(".E")
+                && !(bytes[0] == b'0' && bytes.get(1).is_some_and(u8::is_ascii_digit))
+        };
+        if !is_valid {
+            self.errors.push(diagnostics::json_invalid_number(span, self.mode.name()));
+        }
+    }
+
+    fn check_trailing_comma(&mut self, trailing_comma: Option<Span>) {
+        if let Some(span) = trailing_comma {
+            if !self.mode.allows_comments_and_trailing_commas() {
+                self.errors.push(diagnostics::json_trailing_comma(span, self.mode.name()));
+            }
+        }
+    }
+
+    fn unexpected(&mut self, span: Span) {
+        self.errors.push(diagnostics::json_unexpected_value(span, self.mode.name()));
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    pub(crate) fn parse_json(
+        mut self,
+        mode: JsonMode,
+    ) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let expr = self
+            .parse_expr()
+            .and_then(|expr| self.expect(Kind::Eof).map(|()| expr))
+            .map_err(|diagnostic| vec![diagnostic])?;
+        self.check_unfinished_errors();
+
+        let mut checker = JsonChecker { source_text: self.source_text, mode, errors: vec![] };
+        if !mode.allows_comments_and_trailing_commas() {
+            for comment in &self.lexer.trivia_builder.comments {
+                checker.errors.push(diagnostics::json_comment(comment.span, mode.name()));
+            }
+        }
+        checker.check_value(&expr);
+
+        let errors = self
+            .lexer
+            .errors
+            .into_iter()
+            .chain(self.errors)
+            .chain(checker.errors)
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(expr)
+    }
+}
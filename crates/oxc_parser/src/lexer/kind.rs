@@ -1,10 +1,11 @@
 //! ECMAScript Token Kinds
+#![allow(missing_docs)] // Kinds are named after the tokens they match.
 
 use std::fmt;
 
 /// Lexer token kind
 ///
-/// Exported for other oxc crates to use. You generally don't need to use this directly.
+/// Exported for other oxc crates, and for the tokens of [`crate::Parser::tokenize`].
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Kind {
@@ -165,6 +165,14 @@ impl<'a> Lexer<'a> {
         self.lookahead.clear();
     }
 
+    /// Continue lexing from `offset`, the source text before it is not lexed.
+    /// Used for re-parsing the end of the source text only.
+    pub(crate) fn skip_to(&mut self, offset: u32) {
+        self.source.advance_to_offset(offset);
+        // Unlike the start of file, `offset` is not on a new line unless a line break follows.
+        self.token = Token::default();
+    }
+
     /// Find the nth lookahead token lazily
     pub fn lookahead(&mut self, n: u8) -> Token {
         let n = n as usize;
@@ -197,6 +197,24 @@ impl<'a> Source<'a> {
         self.ptr = self.end;
     }
 
+    /// Advance `Source`'s cursor to `offset`.
+    ///
+    /// # Panics
+    /// Panics if `offset` is before current position or after end of source,
+    /// or is not on a UTF-8 character boundary.
+    pub(super) fn advance_to_offset(&mut self, offset: u32) {
+        let current = self.offset();
+        assert!(offset >= current, "Cannot advance to offset {offset} before {current}");
+        let n = (offset - current) as usize;
+        assert!(
+            self.remaining().is_char_boundary(n),
+            "Offset {offset} is not on a UTF-8 character boundary"
+        );
+        // SAFETY: `is_char_boundary` check ensures that moving forward `n` bytes stays within
+        // the source text, and that the new position is on a UTF-8 character boundary.
+        self.ptr = unsafe { self.position().add(n) }.ptr;
+    }
+
     /// Advance `Source`'s cursor by one byte if it is equal to the given ASCII value.
     ///
     /// # SAFETY
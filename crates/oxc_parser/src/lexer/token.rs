@@ -4,6 +4,7 @@ use oxc_span::Span;
 
 use super::kind::Kind;
 
+/// A token of the source text, see [`crate::Parser::tokenize`].
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Token {
     /// Token Kind
@@ -49,14 +50,17 @@ impl Token {
         Self { is_on_new_line: true, ..Self::default() }
     }
 
+    /// Span of the token in the source text.
     pub fn span(&self) -> Span {
         Span::new(self.start, self.end)
     }
 
+    /// Whether the identifier, string or template token contains escape sequences.
     pub fn escaped(&self) -> bool {
         self.escaped
     }
 
+    /// Whether the numeric literal token contains separators (`_`).
     #[inline]
     pub fn has_separator(&self) -> bool {
         debug_assert!(!self.has_separator || self.kind.is_number());
@@ -47,7 +47,7 @@
 //!
 //! # Visitor
 //!
-//! See [oxc_ast::Visit] and [oxc_ast::VisitMut]
+//! See [oxc_ast_visit::Visit] and [oxc_ast_visit::VisitMut]
 //!
 //! # Visiting without a visitor
 //!
@@ -68,8 +68,12 @@
 
 mod context;
 mod cursor;
+mod incremental;
+mod json;
 mod modifiers;
 mod state;
+mod template;
+mod tokens;
 
 mod js;
 mod jsx;
@@ -87,16 +91,20 @@ pub mod lexer;
 use context::{Context, StatementContext};
 use oxc_allocator::{Allocator, Box as ArenaBox};
 use oxc_ast::{
-    ast::{Expression, Program},
+    ast::{Expression, FunctionBody, Program},
     AstBuilder,
 };
 use oxc_diagnostics::{OxcDiagnostic, Result};
+use oxc_regular_expression::RegExpFeatures;
 use oxc_span::{ModuleKind, SourceType, Span};
 
-use crate::{
-    lexer::{Kind, Lexer, Token},
-    state::ParserState,
+pub use crate::{
+    json::JsonMode,
+    lexer::{Kind, Token},
+    template::Template,
+    tokens::TokenizeReturn,
 };
+use crate::{lexer::Lexer, state::ParserState};
 
 /// Maximum length of source which can be parsed (in bytes).
 /// ~4 GiB on 64-bit systems, ~2 GiB on 32-bit systems.
@@ -168,6 +176,28 @@ pub struct ParserReturn<'a> {
     /// [`program`]: ParserReturn::program
     /// [`errors`]: ParserReturn::errors
     pub panicked: bool,
+
+    /// Spans of the statements which were skipped to recover from syntax errors.
+    ///
+    /// Always empty unless [`ParseOptions::recover_from_errors`] is enabled.
+    pub skipped_spans: Vec<Span>,
+
+    /// Function bodies which were skipped, in source order. Parse them with
+    /// [`Parser::parse_lazy_function_body`].
+    ///
+    /// Always empty unless [`ParseOptions::lazy_function_bodies`] is enabled.
+    pub lazy_function_bodies: Vec<LazyFunctionBody>,
+}
+
+/// A function body skipped by [`ParseOptions::lazy_function_bodies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LazyFunctionBody {
+    /// Span of the body, including its braces.
+    pub span: Span,
+    /// Whether the function is `async`, i.e. `await` is a keyword in the body.
+    pub r#async: bool,
+    /// Whether the function is a generator, i.e. `yield` is a keyword in the body.
+    pub generator: bool,
 }
 
 /// Parse options
@@ -180,6 +210,13 @@ pub struct ParseOptions {
     /// Default: `false`
     pub parse_regular_expression: bool,
 
+    /// Regular expression features to warn about, e.g. the features which are not supported by
+    /// the engines the code is compiled for. Requires
+    /// [`ParseOptions::parse_regular_expression`].
+    ///
+    /// Default: empty
+    pub unsupported_regular_expression_features: RegExpFeatures,
+
     /// Allow [`return`] statements outside of functions.
     ///
     /// By default, a return statement at the top level raises an error (`false`).
@@ -198,14 +235,64 @@ pub struct ParseOptions {
     ///
     /// [`ParenthesizedExpression`]: oxc_ast::ast::ParenthesizedExpression
     pub preserve_parens: bool,
+
+    /// Skip the statements which fail to parse instead of terminating early.
+    ///
+    /// The errors are reported in [`ParserReturn::errors`], and the source of the skipped
+    /// statements in [`ParserReturn::skipped_spans`]. This produces a best-effort AST for source
+    /// text which is being edited, e.g. for editor integrations.
+    ///
+    /// Default: `false`
+    pub recover_from_errors: bool,
+
+    /// Skip the bodies of functions, leaving empty [`FunctionBody`]s in the AST.
+    ///
+    /// The skipped bodies are recorded in [`ParserReturn::lazy_function_bodies`], and can be
+    /// parsed on demand with [`Parser::parse_lazy_function_body`]. The tokens of a skipped body
+    /// are still scanned to find its end, but no AST is built for it, which makes parsing faster
+    /// when most functions are never inspected. Syntax errors in skipped bodies are not reported.
+    /// Bodies which cannot be skipped reliably, e.g. bodies containing JSX, are parsed as usual.
+    ///
+    /// Default: `false`
+    pub lazy_function_bodies: bool,
+
+    /// Parse the import phases of the stage 3 proposals
+    /// [Source Phase Imports](https://github.com/tc39/proposal-source-phase-imports) and
+    /// [Deferring Module Evaluation](https://github.com/tc39/proposal-defer-import-eval),
+    /// i.e. `import source x from "y"` and `import defer * as ns from "y"`.
+    ///
+    /// The phase is recorded in [`ImportDeclaration::phase`]. This option is experimental, as
+    /// the proposals may still change.
+    ///
+    /// Default: `false`
+    ///
+    /// [`ImportDeclaration::phase`]: oxc_ast::ast::ImportDeclaration::phase
+    pub experimental_import_phases: bool,
+
+    /// Maximum depth of nested statements, expressions, binding patterns, types and JSX
+    /// elements.
+    ///
+    /// Parsing pathologically nested source text may exhaust the stack. With a limit, parsing
+    /// stops with a syntax error instead when the limit is exceeded, like for other syntax
+    /// errors: the statement is skipped if [`ParseOptions::recover_from_errors`] is enabled, or
+    /// the parser panics otherwise. Set this when parsing untrusted source text, e.g. in a
+    /// playground. This also bounds the depth of the AST for later recursive passes.
+    ///
+    /// Default: `None` (no limit)
+    pub max_nesting_depth: Option<u32>,
 }
 
 impl Default for ParseOptions {
     fn default() -> Self {
         Self {
             parse_regular_expression: false,
+            unsupported_regular_expression_features: RegExpFeatures::empty(),
             allow_return_outside_function: false,
             preserve_parens: true,
+            recover_from_errors: false,
+            lazy_function_bodies: false,
+            experimental_import_phases: false,
+            max_nesting_depth: None,
         }
     }
 }
@@ -293,6 +380,44 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Re-parse after an edit, reusing the AST of the unchanged statements at the start of
+        /// the previous source text.
+        ///
+        /// `previous` is the [`ParserReturn`] of the previous source text, which must have been
+        /// parsed into the same allocator with the same options, and must not have been modified.
+        /// `changed` is the range of the previous source text which was replaced.
+        ///
+        /// The top-level statements before the edit are kept as they are, and parsing resumes
+        /// after them, so the cost is proportional to the source text after the edit.
+        /// Falls back to a full parse when the previous AST cannot be reused.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::{SourceType, Span};
+        ///
+        /// let allocator = Allocator::new();
+        /// let source_type = SourceType::default();
+        /// let previous = Parser::new(&allocator, "let a = 1;\nlet b = 2;\nlet c = 3;", source_type).parse();
+        /// // Replace `3` with `42`.
+        /// let ret = Parser::new(&allocator, "let a = 1;\nlet b = 2;\nlet c = 42;", source_type)
+        ///     .reparse(previous, Span::new(30, 31));
+        /// assert_eq!(ret.program.body.len(), 3);
+        /// ```
+        pub fn reparse(self, previous: ParserReturn<'a>, changed: Span) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.reparse(previous, changed)
+        }
+
         /// Parse a single [`Expression`].
         ///
         /// # Example
@@ -323,6 +448,117 @@ mod parser_parse {
             );
             parser.parse_expression()
         }
+
+        /// Parse the source text as a JSON value in the dialect of `mode`, e.g. `tsconfig.json`.
+        ///
+        /// The value is returned as an [`Expression`] of object, array and literal
+        /// expressions, so that the spans can be used in diagnostics.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_ast::ast::Expression;
+        /// use oxc_parser::{JsonMode, Parser};
+        /// use oxc_span::SourceType;
+        ///
+        /// let src = r#"{ "rules": { "eqeqeq": "error" }, /* trailing comma */ }"#;
+        /// let allocator = Allocator::new();
+        /// let value =
+        ///     Parser::new(&allocator, src, SourceType::default()).parse_json(JsonMode::Jsonc).unwrap();
+        /// assert!(matches!(value, Expression::ObjectExpression(_)));
+        /// ```
+        ///
+        /// # Errors
+        /// If the source text is not valid in the dialect of `mode`.
+        pub fn parse_json(
+            self,
+            mode: JsonMode,
+        ) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_json(mode)
+        }
+
+        /// Lex the source text into tokens, without parsing it.
+        ///
+        /// This is much cheaper than [`Parser::parse`] for tools which only need tokens, e.g.
+        /// syntax highlighters. Whether a `/` starts a regular expression is decided from the
+        /// previous token, as the grammar is not known. JSX text and TypeScript type arguments
+        /// are lexed as JavaScript tokens.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{Kind, Parser};
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let ret = Parser::new(&allocator, "a = /b/; // c", SourceType::default()).tokenize();
+        /// let kinds = ret.tokens.iter().map(|token| token.kind).collect::<Vec<_>>();
+        /// assert_eq!(kinds, [Kind::Ident, Kind::Eq, Kind::RegExp, Kind::Semicolon]);
+        /// assert_eq!(ret.comments.len(), 1);
+        /// ```
+        pub fn tokenize(self) -> TokenizeReturn {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.tokenize()
+        }
+
+        /// Parse a function body skipped by [`ParseOptions::lazy_function_bodies`].
+        ///
+        /// The source text and source type must be the ones the body was skipped in. Function
+        /// bodies nested in `body` are skipped again if [`ParseOptions::lazy_function_bodies`] is
+        /// enabled, but they are not recorded.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{ParseOptions, Parser};
+        /// use oxc_span::SourceType;
+        ///
+        /// let src = "function foo() { return 1; }";
+        /// let allocator = Allocator::new();
+        /// let source_type = SourceType::default();
+        /// let options = ParseOptions { lazy_function_bodies: true, ..ParseOptions::default() };
+        ///
+        /// let ret = Parser::new(&allocator, src, source_type).with_options(options).parse();
+        /// let body = Parser::new(&allocator, src, source_type)
+        ///     .parse_lazy_function_body(ret.lazy_function_bodies[0])
+        ///     .unwrap();
+        /// assert_eq!(body.statements.len(), 1);
+        /// ```
+        ///
+        /// # Errors
+        /// If the function body has syntax errors.
+        pub fn parse_lazy_function_body(
+            self,
+            body: LazyFunctionBody,
+        ) -> std::result::Result<ArenaBox<'a, FunctionBody<'a>>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_lazy_function_body(body)
+        }
     }
 }
 use parser_parse::UniquePromise;
@@ -361,6 +597,9 @@ struct ParserImpl<'a> {
 
     /// Precomputed typescript detection
     is_ts: bool,
+
+    /// Current nesting depth, see [`ParseOptions::max_nesting_depth`]
+    depth: u32,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -388,6 +627,7 @@ impl<'a> ParserImpl<'a> {
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             is_ts: source_type.is_typescript(),
+            depth: 0,
         }
     }
 
@@ -401,19 +641,26 @@ impl<'a> ParserImpl<'a> {
             Ok(program) => (program, false),
             Err(error) => {
                 self.error(self.overlong_error().unwrap_or(error));
-                let program = self.ast.program(
-                    Span::default(),
-                    self.source_type,
-                    self.source_text,
-                    self.ast.vec(),
-                    None,
-                    self.ast.vec(),
-                    self.ast.vec(),
-                );
-                (program, true)
+                (self.empty_program(), true)
             }
         };
+        self.finish(program, panicked)
+    }
 
+    fn empty_program(&self) -> Program<'a> {
+        self.ast.program(
+            Span::default(),
+            self.source_type,
+            self.source_text,
+            self.ast.vec(),
+            None,
+            self.ast.vec(),
+            self.ast.vec(),
+        )
+    }
+
+    /// Collect the errors and trivia of the parsed `program`.
+    fn finish(mut self, program: Program<'a>, panicked: bool) -> ParserReturn<'a> {
         self.check_unfinished_errors();
         let mut errors = vec![];
         // only check for `@flow` if the file failed to parse.
@@ -429,7 +676,16 @@ impl<'a> ParserImpl<'a> {
         }
         let irregular_whitespaces =
             self.lexer.trivia_builder.irregular_whitespaces.into_boxed_slice();
-        ParserReturn { program, errors, irregular_whitespaces, panicked }
+        let skipped_spans = self.state.skipped_spans;
+        let lazy_function_bodies = self.state.lazy_function_bodies;
+        ParserReturn {
+            program,
+            errors,
+            irregular_whitespaces,
+            panicked,
+            skipped_spans,
+            lazy_function_bodies,
+        }
     }
 
     pub fn parse_expression(mut self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
@@ -444,6 +700,23 @@ impl<'a> ParserImpl<'a> {
         Ok(expr)
     }
 
+    pub fn parse_lazy_function_body(
+        mut self,
+        body: LazyFunctionBody,
+    ) -> std::result::Result<ArenaBox<'a, FunctionBody<'a>>, Vec<OxcDiagnostic>> {
+        self.ctx = self.ctx.and_await(body.r#async).and_yield(body.generator);
+        self.lexer.skip_to(body.span.start);
+        // initialize cur_token and prev_token by moving onto the `{`
+        self.bump_any();
+        let body = self.parse_function_body_eagerly().map_err(|diagnostic| vec![diagnostic])?;
+        self.check_unfinished_errors();
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect::<Vec<_>>();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(body)
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn parse_program(&mut self) -> Result<Program<'a>> {
         // initialize cur_token and prev_token by moving onto the first token
@@ -558,7 +831,8 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression};
+    use oxc_ast::ast::{Argument, CommentKind, Expression, ImportPhase, Statement};
+    use oxc_span::GetSpan;
 
     use super::*;
 
@@ -656,6 +930,200 @@ mod test {
         assert_eq!(ret.program.hashbang.unwrap().value.as_str(), "/usr/bin/node");
     }
 
+    #[test]
+    fn recover_from_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let options = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+
+        let source = "let a = 1;\nlet b = ;\nlet c = 3;";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.program.body.len(), 2);
+        let skipped =
+            ret.skipped_spans.iter().map(|span| span.source_text(source)).collect::<Vec<_>>();
+        assert_eq!(skipped, ["let b = ;"]);
+
+        let source = "function foo() {\n  a b c\n  return 1;\n}\nfoo();";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.program.body.len(), 2);
+        let Statement::FunctionDeclaration(func) = &ret.program.body[0] else { unreachable!() };
+        assert_eq!(func.body.as_ref().unwrap().statements.len(), 1);
+        let skipped =
+            ret.skipped_spans.iter().map(|span| span.source_text(source)).collect::<Vec<_>>();
+        assert_eq!(skipped, ["a b c"]);
+
+        // Terminates early without the option.
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.body.is_empty());
+        assert!(ret.skipped_spans.is_empty());
+    }
+
+    #[test]
+    fn max_nesting_depth() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true).with_jsx(true);
+        let options = ParseOptions { max_nesting_depth: Some(32), ..ParseOptions::default() };
+
+        let source =
+            "function f(): Array<Array<T>> { if (a) { return <a><b /></a>; } const [[x]] = -!y; }";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+
+        // Too deeply nested source text fails to parse instead of exhausting the stack.
+        let nested = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+        let sources = [
+            nested.clone(),
+            "{".repeat(100_000),
+            "- ".repeat(100_000),
+            "++".repeat(100_000),
+            format!("{}a", "new ".repeat(100_000)),
+            format!("let {nested} = a;"),
+            format!("type A = {};", "A<".repeat(100_000)),
+            format!("type A = {}A;", "keyof ".repeat(100_000)),
+            "<a>".repeat(100_000),
+        ];
+        for source in &sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+            assert!(ret.panicked);
+            assert_eq!(ret.errors.len(), 1);
+            assert_eq!(ret.errors[0].to_string(), "Nesting depth exceeds the maximum of 32");
+        }
+
+        // The statement is skipped when recovering from errors.
+        let options = ParseOptions { recover_from_errors: true, ..options };
+        let source = format!("let a = 1;\nlet b = {nested};\nlet c = 3;");
+        let ret = Parser::new(&allocator, &source, source_type).with_options(options).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.program.body.len(), 2);
+    }
+
+    #[test]
+    fn reparse() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+
+        let previous_source = "let a = 1;\nlet b = 2;\nlet c = 3;";
+        let previous = Parser::new(&allocator, previous_source, source_type).parse();
+        let Statement::VariableDeclaration(decl) = &previous.program.body[0] else {
+            unreachable!()
+        };
+        let first = std::ptr::from_ref(&**decl);
+        let source = "let a = 1;\nlet b = 2;\nlet c = 42;";
+        let ret = Parser::new(&allocator, source, source_type).reparse(previous, Span::new(30, 31));
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.source_text, source);
+        let spans = ret.program.body.iter().map(|stmt| stmt.span().source_text(source));
+        assert_eq!(spans.collect::<Vec<_>>(), ["let a = 1;", "let b = 2;", "let c = 42;"]);
+        let Statement::VariableDeclaration(decl) = &ret.program.body[0] else { unreachable!() };
+        assert!(std::ptr::eq(first, &**decl));
+
+        // The re-parsed statements are not directives.
+        let previous = Parser::new(&allocator, "a;\nb;\n'c';\nd;", source_type).parse();
+        let source = "a;\nb;\n'c';\ne;";
+        let ret = Parser::new(&allocator, source, source_type).reparse(previous, Span::new(11, 12));
+        assert!(ret.program.directives.is_empty());
+        assert_eq!(ret.program.body.len(), 4);
+        assert!(matches!(ret.program.body[2], Statement::ExpressionStatement(_)));
+
+        // Errors of the reused statements are kept.
+        let options = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let previous = Parser::new(&allocator, "let a = ;\nb;\nc;\nd;", source_type)
+            .with_options(options)
+            .parse();
+        assert_eq!(previous.errors.len(), 1);
+        let source = "let a = ;\nb;\nc;\ne;";
+        let ret = Parser::new(&allocator, source, source_type)
+            .with_options(options)
+            .reparse(previous, Span::new(16, 17));
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.skipped_spans.len(), 1);
+        assert_eq!(ret.program.body.len(), 3);
+    }
+
+    #[test]
+    fn lazy_function_bodies() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let options = ParseOptions { lazy_function_bodies: true, ..ParseOptions::default() };
+
+        let source = "function foo() { let a = { b: `${ {} }` }; return a / 2 / /}/g; }\n\
+                      const bar = async () => { await x; };\n\
+                      foo();";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.body.len(), 3);
+        let Statement::FunctionDeclaration(func) = &ret.program.body[0] else { unreachable!() };
+        assert!(func.body.as_ref().unwrap().statements.is_empty());
+        let bodies = ret
+            .lazy_function_bodies
+            .iter()
+            .map(|body| (body.span.source_text(source), body.r#async))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bodies,
+            [("{ let a = { b: `${ {} }` }; return a / 2 / /}/g; }", false), ("{ await x; }", true)]
+        );
+
+        let body = Parser::new(&allocator, source, source_type)
+            .parse_lazy_function_body(ret.lazy_function_bodies[0])
+            .unwrap();
+        assert_eq!(body.statements.len(), 2);
+        let body = Parser::new(&allocator, source, source_type)
+            .parse_lazy_function_body(ret.lazy_function_bodies[1])
+            .unwrap();
+        assert_eq!(body.statements.len(), 1);
+
+        // Syntax errors are reported when the body is parsed.
+        let source = "function foo() { let = ; }";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+        let result = Parser::new(&allocator, source, source_type)
+            .parse_lazy_function_body(ret.lazy_function_bodies[0]);
+        assert!(result.is_err());
+
+        // Bodies with JSX are parsed eagerly.
+        let source = "function Foo() { return <div>{'}'}</div>; }";
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.lazy_function_bodies.is_empty());
+    }
+
+    #[test]
+    fn import_phases() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let options = ParseOptions { experimental_import_phases: true, ..ParseOptions::default() };
+        let phase = |source: &str| {
+            let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            let Statement::ImportDeclaration(decl) = &ret.program.body[0] else { unreachable!() };
+            decl.phase
+        };
+        assert_eq!(phase("import source x from 'y'"), Some(ImportPhase::Source));
+        assert_eq!(phase("import source from from 'y'"), Some(ImportPhase::Source));
+        assert_eq!(phase("import defer * as ns from 'y'"), Some(ImportPhase::Defer));
+        // Default imports named `source` and `defer`
+        assert_eq!(phase("import source from 'y'"), None);
+        assert_eq!(phase("import source, { x } from 'y'"), None);
+        assert_eq!(phase("import defer from 'y'"), None);
+
+        for source in ["import source { x } from 'y'", "import source x, { z } from 'y'"] {
+            let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+
+        // The phases are not parsed by default.
+        let source = "import defer * as ns from 'y'";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(!ret.errors.is_empty());
+    }
+
     #[test]
     fn unambiguous() {
         let allocator = Allocator::default();
@@ -733,4 +1201,144 @@ mod test {
         assert!(ret.errors.is_empty());
         assert_eq!(ret.program.body.len(), 2);
     }
+
+    #[test]
+    fn unsupported_regular_expression_features() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let options = ParseOptions {
+            parse_regular_expression: true,
+            unsupported_regular_expression_features: RegExpFeatures::NAMED_CAPTURE_GROUPS
+                | RegExpFeatures::DOT_ALL_FLAG,
+            ..ParseOptions::default()
+        };
+        let source = "/(?<a>.)/s; /(a)/g; /(?<b>)/v";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        let messages = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "The configured targets do not support dotAll flag (`s`) in regular expressions",
+                "The configured targets do not support named capture groups in regular expressions",
+                "The configured targets do not support named capture groups in regular expressions",
+            ]
+        );
+        assert_eq!(ret.errors[0].labels.as_ref().unwrap()[0].offset(), 0);
+
+        // Only reported when regular expressions are parsed.
+        let options = ParseOptions { parse_regular_expression: false, ..options };
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_json() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let parse = |source: &'static str, mode: JsonMode| {
+            Parser::new(&allocator, source, source_type).parse_json(mode).map_err(|errors| {
+                errors.into_iter().map(|error| error.to_string()).collect::<Vec<_>>()
+            })
+        };
+
+        let source = r#"{ "a": [1, -2.5e3, "b", true, null], "c": {} }"#;
+        for mode in [JsonMode::Json, JsonMode::Jsonc, JsonMode::Json5] {
+            assert!(parse(source, mode).is_ok());
+        }
+
+        let source = "{ // comment\n \"a\": [1,], }";
+        assert_eq!(
+            parse(source, JsonMode::Json).unwrap_err(),
+            [
+                "Comments are not allowed in JSON",
+                "Trailing commas are not allowed in JSON",
+                "Trailing commas are not allowed in JSON"
+            ]
+        );
+        assert!(parse(source, JsonMode::Jsonc).is_ok());
+
+        let source = "{ a: 'b', c: +Infinity, d: 0x1F, e: .5 }";
+        assert!(parse(source, JsonMode::Json5).is_ok());
+        assert_eq!(
+            parse(source, JsonMode::Jsonc).unwrap_err(),
+            [
+                "Expected a JSONC value",
+                "Strings must be double quoted in JSONC",
+                "Expected a JSONC value",
+                "Expected a JSONC value",
+                "Expected a JSONC value",
+                "Invalid number in JSONC",
+                "Expected a JSONC value",
+                "Invalid number in JSONC"
+            ]
+        );
+
+        for source in ["undefined", "[...a]", "{ a }", "`a`", "01", "1n", "1 2"] {
+            assert!(parse(source, JsonMode::Json5).is_err(), "{source}");
+        }
+    }
+
+    #[test]
+    fn tokenize() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "a / b; /re/g; `x${ {} }y${c}z` // d";
+        let ret = Parser::new(&allocator, source, source_type).tokenize();
+        assert!(ret.errors.is_empty());
+        let kinds = ret.tokens.iter().map(|token| token.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                Kind::Ident,
+                Kind::Slash,
+                Kind::Ident,
+                Kind::Semicolon,
+                Kind::RegExp,
+                Kind::Semicolon,
+                Kind::TemplateHead,
+                Kind::LCurly,
+                Kind::RCurly,
+                Kind::TemplateMiddle,
+                Kind::Ident,
+                Kind::TemplateTail,
+            ]
+        );
+        assert_eq!(ret.tokens[4].span().source_text(source), "/re/g");
+        assert_eq!(ret.comments.len(), 1);
+
+        let ret = Parser::new(&allocator, "'a", source_type).tokenize();
+        assert_eq!(ret.errors.len(), 1);
+    }
+
+    #[test]
+    fn template() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+
+        let expr = Template::new(&allocator, "$console.log($message, $other)")
+            .identifier("console", "logger")
+            .expression("message", ast.expression_string_literal(oxc_span::SPAN, "hello"))
+            .build_expression()
+            .unwrap();
+        let Expression::CallExpression(call) = expr else { panic!() };
+        let Expression::StaticMemberExpression(callee) = &call.callee else { panic!() };
+        assert!(matches!(&callee.object, Expression::Identifier(ident) if ident.name == "logger"));
+        assert!(call.arguments[0].as_expression().unwrap().is_specific_string_literal("hello"));
+        assert!(
+            matches!(&call.arguments[1], Argument::Identifier(ident) if ident.name == "$other")
+        );
+
+        let body = Template::new(&allocator, "foo(); bar();").build_statements().unwrap();
+        let statements = Template::new(&allocator, "if ($test) { $body; baz(); }")
+            .expression("test", ast.expression_boolean_literal(oxc_span::SPAN, true))
+            .statements("body", body)
+            .build_statements()
+            .unwrap();
+        let Statement::IfStatement(if_stmt) = &statements[0] else { panic!() };
+        assert!(matches!(if_stmt.test, Expression::BooleanLiteral(_)));
+        let Statement::BlockStatement(block) = &if_stmt.consequent else { panic!() };
+        assert_eq!(block.body.len(), 3);
+
+        assert!(Template::new(&allocator, "a +").build_expression().is_err());
+    }
 }
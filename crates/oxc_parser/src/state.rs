@@ -1,6 +1,9 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_ast::ast::{AssignmentExpression, Decorator};
+use oxc_span::Span;
+
+use crate::LazyFunctionBody;
 
 #[derive(Default)]
 pub struct ParserState<'a> {
@@ -11,4 +14,10 @@ pub struct ParserState<'a> {
     /// Temporary storage for `CoverInitializedName` `({ foo = bar })`.
     /// Keyed by `ObjectProperty`'s span.start.
     pub cover_initialized_name: FxHashMap<u32, AssignmentExpression<'a>>,
+
+    /// Spans of the statements skipped when recovering from errors.
+    pub skipped_spans: Vec<Span>,
+
+    /// Function bodies skipped by `ParseOptions::lazy_function_bodies`.
+    pub lazy_function_bodies: Vec<LazyFunctionBody>,
 }
@@ -0,0 +1,190 @@
+//! Build AST nodes from source text templates.
+
+use oxc_allocator::{Allocator, CloneIn, Vec};
+use oxc_ast::{
+    ast::{BindingIdentifier, Expression, IdentifierName, IdentifierReference, Statement},
+    AstBuilder,
+};
+use oxc_ast_visit::{walk_mut, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, SourceType};
+use rustc_hash::FxHashMap;
+
+use crate::Parser;
+
+/// Builds expressions and statements from a source text template, for codemods and transform
+/// plugins which would otherwise have to construct every node with [`AstBuilder`].
+///
+/// Identifiers starting with `$` are placeholders, which are replaced by the values given with
+/// [`Template::expression`], [`Template::statements`] and [`Template::identifier`]. A statement
+/// placeholder is written as an expression statement, e.g. `$body;`. Identifiers without a
+/// value are kept as is.
+///
+/// The spans of the nodes parsed from the template refer to the template, and no semantic
+/// information (scopes, symbols and references) is set.
+///
+/// # Example
+///
+/// ```rust
+/// use oxc_allocator::Allocator;
+/// use oxc_ast::{ast::Expression, AstBuilder};
+/// use oxc_parser::Template;
+/// use oxc_span::SPAN;
+///
+/// let allocator = Allocator::default();
+/// let ast = AstBuilder::new(&allocator);
+/// let expr = Template::new(&allocator, "$console.log($message)")
+///     .identifier("console", "logger")
+///     .expression("message", ast.expression_string_literal(SPAN, "hello"))
+///     .build_expression()
+///     .unwrap();
+/// assert!(matches!(expr, Expression::CallExpression(_)));
+/// ```
+pub struct Template<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+    expressions: FxHashMap<&'a str, Expression<'a>>,
+    statements: FxHashMap<&'a str, Vec<'a, Statement<'a>>>,
+    identifiers: FxHashMap<&'a str, Atom<'a>>,
+}
+
+impl<'a> Template<'a> {
+    /// Create a template of `source_text`, which is parsed as an ES module by default.
+    pub fn new(allocator: &'a Allocator, source_text: &str) -> Self {
+        let ast = AstBuilder::new(allocator);
+        Self {
+            allocator,
+            source_text: ast.str(source_text),
+            source_type: SourceType::mjs(),
+            expressions: FxHashMap::default(),
+            statements: FxHashMap::default(),
+            identifiers: FxHashMap::default(),
+        }
+    }
+
+    /// Parse the template as `source_type`, e.g. to use TypeScript syntax.
+    #[must_use]
+    pub fn with_source_type(mut self, source_type: SourceType) -> Self {
+        self.source_type = source_type;
+        self
+    }
+
+    /// Replace the expressions `$name` with `value`.
+    #[must_use]
+    pub fn expression(mut self, name: &str, value: Expression<'a>) -> Self {
+        let name = AstBuilder::new(self.allocator).str(name);
+        self.expressions.insert(name, value);
+        self
+    }
+
+    /// Replace the statements `$name;` with `value`.
+    #[must_use]
+    pub fn statements(mut self, name: &str, value: Vec<'a, Statement<'a>>) -> Self {
+        let name = AstBuilder::new(self.allocator).str(name);
+        self.statements.insert(name, value);
+        self
+    }
+
+    /// Rename the identifiers `$name`, including bindings and property names, to `value`.
+    #[must_use]
+    pub fn identifier(mut self, name: &str, value: &str) -> Self {
+        let ast = AstBuilder::new(self.allocator);
+        self.identifiers.insert(ast.str(name), ast.atom(value));
+        self
+    }
+
+    /// Parse the template as an expression and fill in the placeholders.
+    ///
+    /// # Errors
+    /// If the template has syntax errors.
+    pub fn build_expression(self) -> Result<Expression<'a>, std::vec::Vec<OxcDiagnostic>> {
+        let mut expr =
+            Parser::new(self.allocator, self.source_text, self.source_type).parse_expression()?;
+        Substitute { template: &self }.visit_expression(&mut expr);
+        Ok(expr)
+    }
+
+    /// Parse the template as a list of statements and fill in the placeholders.
+    ///
+    /// # Errors
+    /// If the template has syntax errors.
+    pub fn build_statements(self) -> Result<Vec<'a, Statement<'a>>, std::vec::Vec<OxcDiagnostic>> {
+        let ret = Parser::new(self.allocator, self.source_text, self.source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors);
+        }
+        let mut statements = ret.program.body;
+        Substitute { template: &self }.visit_statements(&mut statements);
+        Ok(statements)
+    }
+}
+
+/// Replaces the placeholders of a template. Values are cloned for every use of a placeholder and
+/// are not visited themselves.
+struct Substitute<'a, 't> {
+    template: &'t Template<'a>,
+}
+
+impl<'a> Substitute<'a, '_> {
+    fn placeholder<'n>(name: &'n str) -> Option<&'n str> {
+        name.strip_prefix('$')
+    }
+
+    fn statement_placeholder(&self, stmt: &Statement<'a>) -> Option<&Vec<'a, Statement<'a>>> {
+        let Statement::ExpressionStatement(stmt) = stmt else { return None };
+        let Expression::Identifier(ident) = &stmt.expression else { return None };
+        self.template.statements.get(Self::placeholder(&ident.name)?)
+    }
+
+    fn rename(&self, name: &mut Atom<'a>) {
+        if let Some(value) = Self::placeholder(name).and_then(|n| self.template.identifiers.get(n))
+        {
+            *name = value.clone();
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for Substitute<'a, '_> {
+    fn visit_statements(&mut self, it: &mut Vec<'a, Statement<'a>>) {
+        if !it.iter().any(|stmt| self.statement_placeholder(stmt).is_some()) {
+            walk_mut::walk_statements(self, it);
+            return;
+        }
+        let ast = AstBuilder::new(self.template.allocator);
+        let mut statements = ast.vec_with_capacity(it.len());
+        for mut stmt in ast.move_vec(it) {
+            if let Some(value) = self.statement_placeholder(&stmt) {
+                statements.extend(value.iter().map(|s| s.clone_in(self.template.allocator)));
+            } else {
+                self.visit_statement(&mut stmt);
+                statements.push(stmt);
+            }
+        }
+        *it = statements;
+    }
+
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = it {
+            if let Some(value) =
+                Self::placeholder(&ident.name).and_then(|n| self.template.expressions.get(n))
+            {
+                *it = value.clone_in(self.template.allocator);
+                return;
+            }
+        }
+        walk_mut::walk_expression(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'a>) {
+        self.rename(&mut it.name);
+    }
+
+    fn visit_binding_identifier(&mut self, it: &mut BindingIdentifier<'a>) {
+        self.rename(&mut it.name);
+    }
+
+    fn visit_identifier_name(&mut self, it: &mut IdentifierName<'a>) {
+        self.rename(&mut it.name);
+    }
+}
@@ -0,0 +1,112 @@
+//! Lexing source text into tokens without parsing, see [`crate::Parser::tokenize`].
+
+use oxc_ast::Comment;
+use oxc_diagnostics::{OxcDiagnostic, Result};
+use oxc_span::Span;
+
+use crate::{
+    lexer::{Kind, Token},
+    ParserImpl,
+};
+
+/// Return value of [`crate::Parser::tokenize`].
+#[derive(Debug)]
+pub struct TokenizeReturn {
+    /// All tokens of the source text in order, without trivia and the end of file.
+    pub tokens: Vec<Token>,
+
+    /// All comments of the source text in order.
+    pub comments: Vec<Comment>,
+
+    /// Irregular whitespaces of the source text.
+    pub irregular_whitespaces: Box<[Span]>,
+
+    /// Lexer errors, e.g. unterminated strings.
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// State for lexing tokens without parsing: the lexer cannot tell whether a `/` starts a regular
+/// expression, or whether a `}` ends a template substitution, without the previous tokens.
+#[derive(Default)]
+pub(crate) struct TokenScanner {
+    /// The open `{` and template heads (`${`).
+    open: Vec<Kind>,
+    prev_kind: Kind,
+}
+
+impl TokenScanner {
+    /// Whether all `{` and template heads scanned so far are closed.
+    pub(crate) fn is_top_level(&self) -> bool {
+        self.open.is_empty()
+    }
+
+    /// Whether a `/` after `kind` is a division rather than the start of a regular expression.
+    fn can_precede_division(kind: Kind) -> bool {
+        kind.is_identifier()
+            || kind.is_literal()
+            || matches!(
+                kind,
+                Kind::RParen
+                    | Kind::RBrack
+                    | Kind::RCurly
+                    | Kind::This
+                    | Kind::Super
+                    | Kind::Plus2
+                    | Kind::Minus2
+                    | Kind::PrivateIdentifier
+                    | Kind::NoSubstitutionTemplate
+                    | Kind::TemplateTail
+            )
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Re-lex the current token where the lexer needs the context of the previous tokens, i.e.
+    /// regular expressions and the continuations of templates, and record it in `scanner`.
+    pub(crate) fn rescan_token(&mut self, scanner: &mut TokenScanner) -> Result<()> {
+        match self.cur_kind() {
+            kind @ (Kind::LCurly | Kind::TemplateHead) => scanner.open.push(kind),
+            Kind::RCurly => {
+                if scanner.open.pop() == Some(Kind::TemplateHead) {
+                    self.re_lex_template_substitution_tail();
+                    if self.at(Kind::TemplateMiddle) {
+                        scanner.open.push(Kind::TemplateHead);
+                    }
+                }
+            }
+            Kind::Slash | Kind::SlashEq
+                if !TokenScanner::can_precede_division(scanner.prev_kind) =>
+            {
+                self.read_regex()?;
+            }
+            _ => {}
+        }
+        scanner.prev_kind = self.cur_kind();
+        Ok(())
+    }
+
+    pub(crate) fn tokenize(mut self) -> TokenizeReturn {
+        let mut tokens = vec![];
+        let mut scanner = TokenScanner::default();
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        while !self.at(Kind::Eof) {
+            if let Err(error) = self.rescan_token(&mut scanner) {
+                self.error(error);
+            }
+            tokens.push(self.cur_token());
+            self.bump_any();
+        }
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        TokenizeReturn {
+            tokens,
+            comments: self.lexer.trivia_builder.comments,
+            irregular_whitespaces: self
+                .lexer
+                .trivia_builder
+                .irregular_whitespaces
+                .into_boxed_slice(),
+            errors,
+        }
+    }
+}
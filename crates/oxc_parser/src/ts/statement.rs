@@ -416,7 +416,7 @@ impl<'a> ParserImpl<'a> {
         let type_annotation = self.parse_ts_type()?;
         self.expect(Kind::RAngle)?;
         let lhs_span = self.start_span();
-        let expression = self.parse_simple_unary_expression(lhs_span)?;
+        let expression = self.nested(|p| p.parse_simple_unary_expression(lhs_span))?;
         Ok(self.ast.expression_ts_type_assertion(self.end_span(span), expression, type_annotation))
     }
 
@@ -13,6 +13,10 @@ use crate::{
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type(&mut self) -> Result<TSType<'a>> {
+        self.nested(Self::parse_ts_type_impl)
+    }
+
+    fn parse_ts_type_impl(&mut self) -> Result<TSType<'a>> {
         if self.is_start_of_function_type_or_constructor_type() {
             return self.parse_function_or_constructor_type();
         }
@@ -253,7 +257,7 @@ impl<'a> ParserImpl<'a> {
     fn parse_type_operator(&mut self, operator: TSTypeOperatorOperator) -> Result<TSType<'a>> {
         let span = self.start_span();
         self.bump_any(); // bump operator
-        let type_annotation = self.parse_type_operator_or_higher()?;
+        let type_annotation = self.nested(Self::parse_type_operator_or_higher)?;
         Ok(self.ast.ts_type_type_operator(self.end_span(span), operator, type_annotation))
     }
 
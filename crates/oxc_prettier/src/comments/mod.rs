@@ -27,6 +27,9 @@ impl Comment {
         self
     }
 
+    /// Whether the comment is of the kind in `flags`. Like in prettier, the position flags are
+    /// checked by the caller, and no comment matches both [`CommentFlags::Block`] and
+    /// [`CommentFlags::Line`].
     pub fn matches_flags(self, flags: CommentFlags) -> bool {
         if flags.contains(CommentFlags::Block) && !self.is_block {
             return false;
@@ -36,6 +39,13 @@ impl Comment {
         }
         true
     }
+
+    /// `// prettier-ignore` or `/* prettier-ignore */`
+    pub fn is_prettier_ignore(self, source_text: &str) -> bool {
+        let text = &source_text[self.start as usize..self.end as usize];
+        let value = if self.is_block { &text[2..text.len() - 2] } else { &text[2..] };
+        value.trim() == "prettier-ignore"
+    }
 }
 
 #[derive(Default)]
@@ -63,3 +73,39 @@ bitflags! {
         const Last           = 1 << 7; // Check comment is the last attached comment
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Comment, CommentFlags};
+
+    fn comment(source_text: &str) -> Comment {
+        #[allow(clippy::cast_possible_truncation)]
+        let end = source_text.len() as u32;
+        Comment { start: 0, end, is_block: source_text.starts_with("/*"), has_line_suffix: false }
+    }
+
+    #[test]
+    fn matches_flags() {
+        let line = comment("// a");
+        let block = comment("/* a */");
+        assert!(line.matches_flags(CommentFlags::empty()));
+        assert!(block.matches_flags(CommentFlags::empty()));
+        assert!(line.matches_flags(CommentFlags::Line | CommentFlags::Leading));
+        assert!(!block.matches_flags(CommentFlags::Line | CommentFlags::Leading));
+        assert!(block.matches_flags(CommentFlags::Block | CommentFlags::Trailing));
+        assert!(!line.matches_flags(CommentFlags::Block | CommentFlags::Trailing));
+        // A comment is never both.
+        assert!(!line.matches_flags(CommentFlags::Block | CommentFlags::Line));
+        assert!(!block.matches_flags(CommentFlags::Block | CommentFlags::Line));
+    }
+
+    #[test]
+    fn is_prettier_ignore() {
+        for source_text in ["// prettier-ignore", "/* prettier-ignore */", "//prettier-ignore  "] {
+            assert!(comment(source_text).is_prettier_ignore(source_text), "{source_text}");
+        }
+        for source_text in ["// prettier-ignore-start", "/** prettier-ignore */", "// a"] {
+            assert!(!comment(source_text).is_prettier_ignore(source_text), "{source_text}");
+        }
+    }
+}
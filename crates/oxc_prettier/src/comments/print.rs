@@ -3,10 +3,10 @@ use oxc_span::Span;
 
 use crate::{
     doc::{Doc, DocBuilder},
-    Prettier,
+    hardline, line, ss, Prettier,
 };
 
-use super::{CommentFlags, DanglingCommentsPrintOptions};
+use super::{Comment, CommentFlags, DanglingCommentsPrintOptions};
 
 impl<'a> Prettier<'a> {
     #[must_use]
@@ -30,31 +30,305 @@ impl<'a> Prettier<'a> {
         doc
     }
 
-    pub(crate) fn has_comment(&mut self, _span: Span, _flags: CommentFlags) -> bool {
-        false
+    /// Comments that have not been printed yet, in source order.
+    fn remaining_comments(&self) -> &[Comment] {
+        &self.comments[self.comment_index..]
+    }
+
+    /// Leading comments of `span`: the unprinted comments before it that are separated from it,
+    /// and from each other, only by whitespace.
+    fn leading_comments(&self, span: Span) -> &[Comment] {
+        let comments = self.remaining_comments();
+        let before = comments.partition_point(|comment| comment.end <= span.start);
+        let mut boundary = span.start;
+        let mut first = before;
+        for comment in comments[..before].iter().rev() {
+            if !self.source_text[comment.end as usize..boundary as usize].trim().is_empty() {
+                break;
+            }
+            boundary = comment.start;
+            first -= 1;
+        }
+        &comments[first..before]
+    }
+
+    /// Trailing comments of `span`: the unprinted comments after it on the same line.
+    fn trailing_comments(&self, span: Span) -> &[Comment] {
+        let comments = self.remaining_comments();
+        let first = comments.partition_point(|comment| comment.start < span.end);
+        let mut boundary = span.end;
+        let mut last = first;
+        for comment in &comments[first..] {
+            let between = &self.source_text[boundary as usize..comment.start as usize];
+            if !between.chars().all(|c| matches!(c, ' ' | '\t' | ',' | ';')) {
+                break;
+            }
+            boundary = comment.end;
+            last += 1;
+        }
+        &comments[first..last]
+    }
+
+    /// Dangling comments of `span`: the unprinted comments inside it.
+    fn dangling_comments(&self, span: Span) -> &[Comment] {
+        let comments = self.remaining_comments();
+        let first = comments.partition_point(|comment| comment.start < span.start);
+        let last = comments.partition_point(|comment| comment.end <= span.end);
+        &comments[first..last.max(first)]
+    }
+
+    pub(crate) fn has_comment(&mut self, span: Span, flags: CommentFlags) -> bool {
+        let position = CommentFlags::Leading | CommentFlags::Trailing | CommentFlags::Dangling;
+        let any_position = !flags.intersects(position);
+        let source_text = self.source_text;
+        let check = |comments: &[Comment]| {
+            comments.iter().enumerate().any(|(i, comment)| {
+                comment.matches_flags(flags)
+                    && (!flags.contains(CommentFlags::First) || i == 0)
+                    && (!flags.contains(CommentFlags::Last) || i + 1 == comments.len())
+                    && (!flags.contains(CommentFlags::PrettierIgnore)
+                        || comment.is_prettier_ignore(source_text))
+            })
+        };
+        ((any_position || flags.contains(CommentFlags::Leading))
+            && check(self.leading_comments(span)))
+            || ((any_position || flags.contains(CommentFlags::Trailing))
+                && check(self.trailing_comments(span)))
+            || ((any_position || flags.contains(CommentFlags::Dangling))
+                && check(self.dangling_comments(span)))
     }
 
     #[must_use]
-    pub(crate) fn print_leading_comments(&mut self, _span: Span) -> Option<Doc<'a>> {
-        None
+    pub(crate) fn print_leading_comments(&mut self, span: Span) -> Option<Doc<'a>> {
+        // Comments before `span` that are not attached to anything else are printed here as
+        // well, so that no comment is dropped.
+        let comments = self.remaining_comments();
+        let count = comments.partition_point(|comment| comment.end <= span.start);
+        if count == 0 {
+            return None;
+        }
+        let comments = comments[..count].to_vec();
+        self.comment_index += count;
+        let mut parts = self.vec();
+        for comment in comments {
+            parts.push(self.print_leading_comment(comment));
+        }
+        Some(Doc::Array(parts))
     }
 
+    /// Comments inside `span` that have not been printed by any of its children have nowhere
+    /// else to go, so they are printed after the node along with its trailing comments.
     #[must_use]
-    pub(crate) fn print_trailing_comments(&mut self, _span: Span) -> Option<Doc<'a>> {
-        None
+    pub(crate) fn print_trailing_comments(&mut self, span: Span) -> Option<Doc<'a>> {
+        let mut comments = self.dangling_comments(span).to_vec();
+        self.comment_index += comments.len();
+        let trailing = self.trailing_comments(span).to_vec();
+        self.comment_index += trailing.len();
+        comments.extend(trailing);
+        if comments.is_empty() {
+            return None;
+        }
+        let mut parts = self.vec();
+        let mut previous_comment: Option<Comment> = None;
+        for comment in comments {
+            let (doc, comment) = self.print_trailing_comment(comment, previous_comment);
+            parts.push(doc);
+            previous_comment = Some(comment);
+        }
+        Some(Doc::Array(parts))
     }
 
+    /// Prettier has no notion of inner comments for this case: it prints the dangling comments
+    /// of an argument-less call between its parentheses.
     #[must_use]
-    pub(crate) fn print_inner_comment(&mut self, _span: Span) -> Vec<'a, Doc<'a>> {
-        self.vec()
+    pub(crate) fn print_inner_comment(&mut self, span: Span) -> Vec<'a, Doc<'a>> {
+        let mut parts = self.vec();
+        let comments = self.dangling_comments(span).to_vec();
+        self.comment_index += comments.len();
+        for comment in comments {
+            parts.push(self.print_comment(comment));
+            if comment.is_block {
+                continue;
+            }
+            parts.push(Doc::BreakParent);
+            parts.extend(hardline!());
+        }
+        parts
     }
 
     #[must_use]
     pub(crate) fn print_dangling_comments(
         &mut self,
-        _span: Span,
-        _dangling_options: Option<&DanglingCommentsPrintOptions>,
+        span: Span,
+        dangling_options: Option<&DanglingCommentsPrintOptions>,
     ) -> Option<Doc<'a>> {
-        None
+        let comments = self.dangling_comments(span).to_vec();
+        if comments.is_empty() {
+            return None;
+        }
+        self.comment_index += comments.len();
+        let mut parts = self.vec();
+        for (i, comment) in comments.into_iter().enumerate() {
+            if i > 0 {
+                parts.extend(hardline!());
+            }
+            parts.push(self.print_comment(comment));
+            if !comment.is_block {
+                parts.push(Doc::BreakParent);
+            }
+        }
+        let doc = Doc::Array(parts);
+        if dangling_options.is_some_and(|options| options.ident) {
+            let mut parts = self.vec();
+            parts.extend(hardline!());
+            parts.push(doc);
+            return Some(Doc::Indent(parts));
+        }
+        Some(doc)
+    }
+
+    /// Print comments left over at the end of a block, each on its own line, keeping at most one
+    /// blank line before each of them.
+    #[must_use]
+    pub(crate) fn print_own_line_dangling_comments(
+        &mut self,
+        span: Span,
+        has_content: bool,
+    ) -> Option<Doc<'a>> {
+        let comments = self.dangling_comments(span).to_vec();
+        if comments.is_empty() {
+            return None;
+        }
+        self.comment_index += comments.len();
+        let mut parts = self.vec();
+        for (i, comment) in comments.into_iter().enumerate() {
+            if i > 0 || has_content {
+                parts.extend(hardline!());
+                if self.is_previous_line_empty(comment.start) {
+                    parts.extend(hardline!());
+                }
+            }
+            parts.push(self.print_comment(comment));
+            if !comment.is_block {
+                parts.push(Doc::BreakParent);
+            }
+        }
+        Some(Doc::Array(parts))
+    }
+
+    fn print_leading_comment(&mut self, comment: Comment) -> Doc<'a> {
+        let mut parts = self.vec();
+        parts.push(self.print_comment(comment));
+        if comment.is_block {
+            let line_break = if self.has_newline(comment.end, /* backwards */ false) {
+                if self.has_newline(comment.start, /* backwards */ true) {
+                    let mut parts = self.vec();
+                    parts.extend(hardline!());
+                    Doc::Array(parts)
+                } else {
+                    line!()
+                }
+            } else {
+                ss!(" ")
+            };
+            parts.push(line_break);
+        } else {
+            parts.extend(hardline!());
+        }
+        // Preserve a single blank line between the comment and the node.
+        let index = self.skip_spaces(Some(comment.end), /* backwards */ false);
+        let index = self.skip_newline(index, /* backwards */ false);
+        if index.is_some_and(|index| self.has_newline(index, /* backwards */ false)) {
+            parts.extend(hardline!());
+        }
+        Doc::Array(parts)
+    }
+
+    fn print_trailing_comment(
+        &mut self,
+        comment: Comment,
+        previous_comment: Option<Comment>,
+    ) -> (Doc<'a>, Comment) {
+        let printed = self.print_comment(comment);
+
+        if previous_comment.is_some_and(|c| c.has_line_suffix && !c.is_block)
+            || self.has_newline(comment.start, /* backwards */ true)
+        {
+            // This allows comments at the end of nested structures:
+            // {
+            //   x: 1,
+            //   y: 2
+            //   // A comment
+            // }
+            // Those kinds of comments are almost always leading comments, but
+            // here it doesn't go "outside" the block and turns it into a
+            // trailing comment for `2`. We can simulate the above by checking
+            // if this a comment on its own line; normal trailing comments are
+            // always at the end of another expression.
+            let is_line_before_empty = self.is_previous_line_empty(comment.start);
+            let mut parts = self.vec();
+            parts.extend(hardline!());
+            if is_line_before_empty {
+                parts.extend(hardline!());
+            }
+            parts.push(printed);
+            return (Doc::LineSuffix(parts), comment.with_line_suffix(true));
+        }
+
+        if !comment.is_block || previous_comment.is_some_and(|c| c.has_line_suffix) {
+            let mut suffix = self.vec();
+            suffix.push(ss!(" "));
+            suffix.push(printed);
+            let mut parts = self.vec();
+            parts.push(Doc::LineSuffix(suffix));
+            if !comment.is_block {
+                parts.push(Doc::BreakParent);
+            }
+            return (Doc::Array(parts), comment.with_line_suffix(true));
+        }
+
+        let mut parts = self.vec();
+        parts.push(ss!(" "));
+        parts.push(printed);
+        (Doc::Array(parts), comment.with_line_suffix(false))
+    }
+
+    /// Line comments are printed as they are, without trailing whitespace. Block comments whose
+    /// lines all start with `*` are re-indented so that the stars line up at the current
+    /// indentation; other block comments are printed as they are.
+    fn print_comment(&self, comment: Comment) -> Doc<'a> {
+        let text = &self.source_text[comment.start as usize..comment.end as usize];
+        if !comment.is_block {
+            return Doc::Str(text.trim_end());
+        }
+        if !Self::is_indentable_block_comment(text) {
+            return Doc::Str(text);
+        }
+        let value = &text[2..text.len() - 2];
+        let lines = value.split('\n').collect::<std::vec::Vec<_>>();
+        let mut parts = self.vec();
+        parts.push(ss!("/*"));
+        for (i, line) in lines.iter().enumerate() {
+            if i == 0 {
+                parts.push(Doc::Str(line.trim_end()));
+                continue;
+            }
+            parts.extend(hardline!());
+            parts.push(ss!(" "));
+            parts.push(Doc::Str(if i < lines.len() - 1 { line.trim() } else { line.trim_start() }));
+        }
+        parts.push(ss!("*/"));
+        Doc::Array(parts)
+    }
+
+    fn is_indentable_block_comment(text: &str) -> bool {
+        let value = &text[2..text.len() - 2];
+        let lines = value.split('\n').collect::<std::vec::Vec<_>>();
+        // The first line directly follows `/*`, and the last line may consist of the `*/` alone.
+        lines.len() > 1
+            && lines.iter().enumerate().skip(1).all(|(i, line)| {
+                let line = line.trim_start();
+                line.starts_with('*') || (i == lines.len() - 1 && line.is_empty())
+            })
     }
 }
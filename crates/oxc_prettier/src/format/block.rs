@@ -1,19 +1,22 @@
 use oxc_ast::{ast::*, AstKind};
+use oxc_span::Span;
 
 use super::{statement, Format};
 use crate::{
+    comments::CommentFlags,
     doc::{Doc, DocBuilder},
     hardline, ss, Prettier,
 };
 
 pub(super) fn print_block<'a>(
     p: &mut Prettier<'a>,
+    span: Span,
     stmts: &[Statement<'a>],
     directives: Option<&[Directive<'a>]>,
 ) -> Doc<'a> {
     let mut parts = p.vec();
     parts.push(ss!("{"));
-    if let Some(doc) = print_block_body(p, stmts, directives, true, false) {
+    if let Some(doc) = print_block_body(p, span, stmts, directives, true, false) {
         parts.push({
             let mut parts = p.vec();
             parts.extend(hardline!());
@@ -51,6 +54,7 @@ pub(super) fn print_block<'a>(
 
 pub(super) fn print_block_body<'a>(
     p: &mut Prettier<'a>,
+    span: Span,
     stmts: &[Statement<'a>],
     directives: Option<&[Directive<'a>]>,
     remove_last_statement_hardline: bool,
@@ -58,8 +62,9 @@ pub(super) fn print_block_body<'a>(
 ) -> Option<Doc<'a>> {
     let has_directives = directives.is_some_and(|directives| !directives.is_empty());
     let has_body = stmts.iter().any(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+    let has_dangling_comments = p.has_comment(span, CommentFlags::Dangling);
 
-    if !has_body && !has_directives {
+    if !has_body && !has_directives && !has_dangling_comments {
         return None;
     }
 
@@ -67,7 +72,13 @@ pub(super) fn print_block_body<'a>(
 
     if has_directives {
         if let Some(directives) = directives {
-            parts.extend(directives.iter().map(|d| d.format(p)));
+            for (i, directive) in directives.iter().enumerate() {
+                parts.push(directive.format(p));
+                let is_last = i == directives.len() - 1;
+                if (!is_last || has_body) && p.is_next_line_empty(directive.span) {
+                    parts.extend(hardline!());
+                }
+            }
         }
     }
 
@@ -80,5 +91,11 @@ pub(super) fn print_block_body<'a>(
         ));
     }
 
+    // Comments after the last statement are printed on their own lines instead of being
+    // attached to whatever follows the block.
+    if let Some(comments) = p.print_own_line_dangling_comments(span, has_body) {
+        parts.push(comments);
+    }
+
     Some(Doc::Array(parts))
 }
@@ -36,8 +36,15 @@ pub fn print_call_arguments<'a>(
         return Doc::Array(parts);
     }
 
+    // The arguments may be printed several times for the alternatives of a conditional group,
+    // every time with their comments.
+    let comment_index = p.comment_index;
+
     #[allow(clippy::cast_sign_loss)]
     let get_printed_arguments = |p: &mut Prettier<'a>, skip_index: isize| {
+        if skip_index <= 0 {
+            p.comment_index = comment_index;
+        }
         let mut printed_arguments = p.vec();
         let mut len = arguments.len();
         let arguments: Box<dyn Iterator<Item = (usize, &Argument)>> = match skip_index {
@@ -15,15 +15,7 @@ pub(super) fn should_hug_the_only_function_parameter(
     }
     let Some(parameter) = params.items.first() else { return false };
 
-    let all_comment_flags = CommentFlags::Trailing
-        | CommentFlags::Leading
-        | CommentFlags::Dangling
-        | CommentFlags::Block
-        | CommentFlags::Line
-        | CommentFlags::PrettierIgnore
-        | CommentFlags::First
-        | CommentFlags::Last;
-    if p.has_comment(parameter.span, all_comment_flags) {
+    if p.has_comment(parameter.span, CommentFlags::empty()) {
         return false;
     }
 
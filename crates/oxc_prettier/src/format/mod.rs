@@ -63,12 +63,14 @@ impl<'a> Format<'a> for Program<'a> {
         }
         if let Some(doc) = block::print_block_body(
             p,
+            self.span,
             &self.body,
             Some(&self.directives),
             false,
             /* is_root */ true,
         ) {
             parts.push(doc);
+            parts.extend(hardline!());
         }
         p.leave_node();
         Doc::Array(parts)
@@ -93,14 +95,18 @@ impl<'a> Format<'a> for Hashbang<'a> {
 impl<'a> Format<'a> for Directive<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         let mut parts = p.vec();
-        parts.push(Doc::Str(string::print_string(
-            p,
-            self.directive.as_str(),
-            p.options.single_quote,
-        )));
-        if let Some(semi) = p.semi() {
-            parts.push(semi);
-        }
+        parts.push(wrap!(p, self, Directive, {
+            let mut parts = p.vec();
+            parts.push(Doc::Str(string::print_string(
+                p,
+                self.directive.as_str(),
+                p.options.single_quote,
+            )));
+            if let Some(semi) = p.semi() {
+                parts.push(semi);
+            }
+            Doc::Array(parts)
+        }));
         parts.extend(hardline!());
         Doc::Array(parts)
     }
@@ -197,7 +203,7 @@ impl<'a> Format<'a> for IfStatement<'a> {
 
 impl<'a> Format<'a> for BlockStatement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        wrap!(p, self, BlockStatement, { block::print_block(p, &self.body, None) })
+        wrap!(p, self, BlockStatement, { block::print_block(p, self.span, &self.body, None) })
     }
 }
 
@@ -1508,7 +1514,7 @@ impl<'a> Format<'a> for Function<'a> {
 impl<'a> Format<'a> for FunctionBody<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         wrap!(p, self, FunctionBody, {
-            block::print_block(p, &self.statements, Some(&self.directives))
+            block::print_block(p, self.span, &self.statements, Some(&self.directives))
         })
     }
 }
@@ -1534,6 +1540,10 @@ impl<'a> Format<'a> for ImportDeclaration<'a> {
         if self.import_kind.is_type() {
             parts.push(ss!(" type"));
         }
+        if let Some(phase) = self.phase {
+            parts.push(space!());
+            parts.push(ss!(phase.as_str()));
+        }
 
         if let Some(specifiers) = &self.specifiers {
             let is_default = specifiers.first().is_some_and(|x| {
@@ -2777,7 +2787,7 @@ impl<'a> Format<'a> for JSXFragment<'a> {
 impl<'a> Format<'a> for StaticBlock<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         wrap!(p, self, StaticBlock, {
-            array![p, ss!("static "), block::print_block(p, &self.body, None)]
+            array![p, ss!("static "), block::print_block(p, self.span, &self.body, None)]
         })
     }
 }
@@ -2,12 +2,12 @@ use oxc_ast::{
     ast::{ObjectAssignmentTarget, ObjectExpression, ObjectPattern, TSTypeLiteral, WithClause},
     AstKind,
 };
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use super::{misc, Format};
 use crate::{
     doc::{Doc, DocBuilder, Group},
-    group, if_break, line, softline, ss, Prettier,
+    group, hardline, if_break, line, softline, ss, Prettier,
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -63,6 +63,18 @@ impl<'a, 'b> ObjectLike<'a, 'b> {
         }
     }
 
+    fn member_spans(&self) -> std::vec::Vec<Span> {
+        match self {
+            Self::Expression(object) => object.properties.iter().map(GetSpan::span).collect(),
+            Self::AssignmentTarget(object) => object.properties.iter().map(GetSpan::span).collect(),
+            Self::Pattern(object) => object.properties.iter().map(GetSpan::span).collect(),
+            Self::WithClause(attributes) => {
+                attributes.with_entries.iter().map(|entry| entry.span).collect()
+            }
+            Self::TSTypeLiteral(literal) => literal.members.iter().map(GetSpan::span).collect(),
+        }
+    }
+
     fn iter(&'b self, p: &'b mut Prettier<'a>) -> Box<dyn Iterator<Item = Doc<'a>> + 'b> {
         match self {
             Self::Expression(object) => {
@@ -113,10 +125,12 @@ pub(super) fn print_object_properties<'a>(
         parts.push(Doc::Indent({
             let len = object.len();
             let has_rest = object.has_rest();
+            let member_spans = object.member_spans();
             let mut indent_parts = p.vec();
 
             indent_parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
-            for (i, doc) in object.iter(p).enumerate() {
+            let docs = object.iter(p).collect::<std::vec::Vec<_>>();
+            for (i, doc) in docs.into_iter().enumerate() {
                 indent_parts.push(doc);
                 if i == len - 1 && !has_rest {
                     break;
@@ -124,6 +138,9 @@ pub(super) fn print_object_properties<'a>(
 
                 indent_parts.push(ss!(member_separator));
                 indent_parts.push(line!());
+                if p.is_next_line_empty(member_spans[i]) {
+                    indent_parts.extend(hardline!());
+                }
             }
             match object {
                 ObjectLike::Expression(_)
@@ -22,6 +22,7 @@ use oxc_syntax::identifier::is_line_terminator;
 
 pub use crate::options::{ArrowParens, EndOfLine, PrettierOptions, QuoteProps, TrailingComma};
 use crate::{
+    comments::Comment,
     doc::{Doc, DocBuilder},
     format::Format,
     printer::Printer,
@@ -57,6 +58,12 @@ pub struct Prettier<'a> {
     /// See <https://github.com/prettier/prettier/blob/main/src/common/ast-path.js>
     stack: Vec<AstKind<'a>>,
 
+    /// Comments of the program, in source order.
+    comments: Vec<Comment>,
+
+    /// Index of the first comment in `comments` that has not been printed yet.
+    comment_index: usize,
+
     group_id_builder: GroupIdBuilder,
     args: PrettierArgs,
 }
@@ -76,21 +83,30 @@ impl<'a> Prettier<'a> {
             source_text: "",
             options,
             stack: vec![],
+            comments: vec![],
+            comment_index: 0,
             group_id_builder: GroupIdBuilder::default(),
             args: PrettierArgs::default(),
         }
     }
 
     pub fn build(&mut self, program: &Program<'a>) -> String {
-        self.source_text = program.source_text;
+        self.init(program);
         let doc = program.format(self);
         Printer::new(doc, program.source_text, self.options, self.allocator).build()
     }
 
     pub fn doc(mut self, program: &Program<'a>) -> Doc<'a> {
+        self.init(program);
         program.format(&mut self)
     }
 
+    fn init(&mut self, program: &Program<'a>) {
+        self.source_text = program.source_text;
+        self.comments = program.comments.iter().copied().map(Comment::new).collect();
+        self.comment_index = 0;
+    }
+
     fn enter_node(&mut self, kind: AstKind<'a>) {
         self.stack.push(kind);
     }
@@ -177,9 +193,19 @@ impl<'a> Prettier<'a> {
         self.skip_everything_but_new_line(Some(start_index), /* backwards */ false)
     }
 
-    #[allow(clippy::unused_self)]
+    /// Skip a block comment that starts at `start_index` and does not span multiple lines.
+    #[allow(clippy::cast_possible_truncation)]
     fn skip_inline_comment(&self, start_index: Option<u32>) -> Option<u32> {
         let start_index = start_index?;
+        let rest = &self.source_text[start_index as usize..];
+        if !rest.starts_with("/*") {
+            return Some(start_index);
+        }
+        if let Some(end) = rest[2..].find("*/") {
+            if !rest[2..end + 2].chars().any(is_line_terminator) {
+                return Some(start_index + end as u32 + 4);
+            }
+        }
         Some(start_index)
     }
 
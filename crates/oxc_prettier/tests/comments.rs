@@ -0,0 +1,41 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::SourceType;
+
+fn format(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    Prettier::new(&allocator, PrettierOptions::default()).build(&ret.program)
+}
+
+/// These are formatted by prettier as they are.
+#[test]
+fn comments() {
+    let cases = [
+        // leading
+        "// a\nfoo();\n",
+        "/* a */ foo();\n",
+        "// a\n\nfoo();\n",
+        // trailing
+        "foo(); // a\n",
+        // dangling
+        "function foo() {\n  // a\n}\n",
+        // own line at the end of a block
+        "function foo() {\n  bar();\n\n  // a\n}\n",
+    ];
+    for source_text in cases {
+        assert_eq!(format(source_text), source_text);
+    }
+}
+
+#[test]
+fn reindent_block_comments() {
+    assert_eq!(
+        format("function foo() {\n    /**\n     * a\n     */\n  bar();\n}\n"),
+        "function foo() {\n  /**\n   * a\n   */\n  bar();\n}\n"
+    );
+    // Only comments whose lines all start with `*` are re-indented.
+    let source_text = "function foo() {\n  /*\n      a\n  */\n  bar();\n}\n";
+    assert_eq!(format(source_text), source_text);
+}
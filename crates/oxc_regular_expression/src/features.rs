@@ -0,0 +1,139 @@
+use bitflags::bitflags;
+
+use crate::{
+    ast::{LookAroundAssertionKind, Pattern},
+    visit::{RegExpAstKind, Visit},
+};
+
+bitflags! {
+    /// Regular expression features which are not supported by all engines.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct RegExpFeatures: u8 {
+        /// `y` flag, ES2015
+        const STICKY_FLAG = 1 << 0;
+        /// `u` flag, ES2015
+        const UNICODE_FLAG = 1 << 1;
+        /// `s` flag, ES2018
+        const DOT_ALL_FLAG = 1 << 2;
+        /// `(?<name>x)`, ES2018
+        const NAMED_CAPTURE_GROUPS = 1 << 3;
+        /// `(?<=x)` and `(?<!x)`, ES2018
+        const LOOKBEHIND_ASSERTIONS = 1 << 4;
+        /// `\p{...}` and `\P{...}`, ES2018
+        const UNICODE_PROPERTY_ESCAPES = 1 << 5;
+        /// `d` flag, ES2022
+        const MATCH_INDICES_FLAG = 1 << 6;
+        /// `v` flag, ES2024
+        const UNICODE_SETS_FLAG = 1 << 7;
+    }
+}
+
+impl RegExpFeatures {
+    /// Features used by the flags of a regular expression, e.g. `"gsu"`.
+    pub fn of_flags(flags: &str) -> Self {
+        flags
+            .chars()
+            .map(|flag| match flag {
+                'y' => Self::STICKY_FLAG,
+                'u' => Self::UNICODE_FLAG,
+                's' => Self::DOT_ALL_FLAG,
+                'd' => Self::MATCH_INDICES_FLAG,
+                'v' => Self::UNICODE_SETS_FLAG,
+                _ => Self::empty(),
+            })
+            .collect()
+    }
+
+    /// Features used by the syntax of `pattern`.
+    pub fn of_pattern(pattern: &Pattern<'_>) -> Self {
+        let mut collector = FeatureCollector { features: Self::empty() };
+        collector.visit_pattern(pattern);
+        collector.features
+    }
+
+    /// Human readable name of a single feature, e.g. "named capture groups".
+    pub fn description(self) -> &'static str {
+        const DESCRIPTIONS: [(RegExpFeatures, &str); 8] = [
+            (RegExpFeatures::STICKY_FLAG, "sticky flag (`y`)"),
+            (RegExpFeatures::UNICODE_FLAG, "unicode flag (`u`)"),
+            (RegExpFeatures::DOT_ALL_FLAG, "dotAll flag (`s`)"),
+            (RegExpFeatures::NAMED_CAPTURE_GROUPS, "named capture groups"),
+            (RegExpFeatures::LOOKBEHIND_ASSERTIONS, "lookbehind assertions"),
+            (RegExpFeatures::UNICODE_PROPERTY_ESCAPES, "unicode property escapes"),
+            (RegExpFeatures::MATCH_INDICES_FLAG, "match indices flag (`d`)"),
+            (RegExpFeatures::UNICODE_SETS_FLAG, "unicode sets flag (`v`)"),
+        ];
+        DESCRIPTIONS
+            .iter()
+            .find(|(feature, _)| *feature == self)
+            .map_or("features", |(_, description)| description)
+    }
+}
+
+struct FeatureCollector {
+    features: RegExpFeatures,
+}
+
+impl<'a> Visit<'a> for FeatureCollector {
+    fn enter_node(&mut self, kind: RegExpAstKind<'a>) {
+        match kind {
+            RegExpAstKind::CapturingGroup(group) if group.name.is_some() => {
+                self.features |= RegExpFeatures::NAMED_CAPTURE_GROUPS;
+            }
+            RegExpAstKind::LookAroundAssertion(assertion)
+                if matches!(
+                    assertion.kind,
+                    LookAroundAssertionKind::Lookbehind
+                        | LookAroundAssertionKind::NegativeLookbehind
+                ) =>
+            {
+                self.features |= RegExpFeatures::LOOKBEHIND_ASSERTIONS;
+            }
+            RegExpAstKind::UnicodePropertyEscape(_) => {
+                self.features |= RegExpFeatures::UNICODE_PROPERTY_ESCAPES;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use super::RegExpFeatures;
+    use crate::{LiteralParser, Options};
+
+    #[test]
+    fn features() {
+        let allocator = Allocator::default();
+        for (source, flags, expected) in [
+            ("a", "g", RegExpFeatures::empty()),
+            (
+                "a",
+                "yus",
+                RegExpFeatures::STICKY_FLAG
+                    | RegExpFeatures::UNICODE_FLAG
+                    | RegExpFeatures::DOT_ALL_FLAG,
+            ),
+            ("(?<a>x)(y)", "", RegExpFeatures::NAMED_CAPTURE_GROUPS),
+            ("(?:(?<!x))", "", RegExpFeatures::LOOKBEHIND_ASSERTIONS),
+            ("(?=x)", "", RegExpFeatures::empty()),
+            (
+                "[\\p{L}]",
+                "u",
+                RegExpFeatures::UNICODE_PROPERTY_ESCAPES | RegExpFeatures::UNICODE_FLAG,
+            ),
+            ("[a--b]", "v", RegExpFeatures::UNICODE_SETS_FLAG),
+        ] {
+            let pattern = LiteralParser::new(&allocator, source, Some(flags), Options::default())
+                .parse()
+                .unwrap();
+            assert_eq!(
+                RegExpFeatures::of_flags(flags) | RegExpFeatures::of_pattern(&pattern),
+                expected,
+                "/{source}/{flags}"
+            );
+        }
+    }
+}
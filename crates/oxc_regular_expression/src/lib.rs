@@ -2,6 +2,7 @@
 
 mod ast_impl;
 mod diagnostics;
+mod features;
 mod options;
 mod parser;
 mod surrogate_pair;
@@ -17,6 +18,7 @@ mod generated {
 pub mod ast;
 pub use crate::{
     ast_impl::visit,
+    features::RegExpFeatures,
     options::Options,
     parser::{ConstructorParser, LiteralParser},
 };
@@ -0,0 +1,191 @@
+//! Getters and setters of object literals and classes, paired by property.
+
+use oxc_ast::{
+    ast::{ClassElement, MethodDefinitionKind, ObjectPropertyKind, PropertyKey, PropertyKind},
+    AstKind,
+};
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{NodeId, Semantic};
+
+/// Whether an [`Accessor`] is a getter or a setter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessorKind {
+    Get,
+    Set,
+}
+
+/// A getter or a setter, e.g. `get foo() {}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Accessor {
+    /// The [`ObjectProperty`] or [`MethodDefinition`] node.
+    ///
+    /// [`ObjectProperty`]: oxc_ast::ast::ObjectProperty
+    /// [`MethodDefinition`]: oxc_ast::ast::MethodDefinition
+    pub node_id: NodeId,
+    pub kind: AccessorKind,
+    pub span: Span,
+    /// Position of the property in the object literal, or of the element in the class body.
+    pub index: usize,
+}
+
+/// The getter and the setter of a property of an object literal or a class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessorPair {
+    /// The [`ObjectExpression`] or [`ClassBody`] node.
+    ///
+    /// [`ObjectExpression`]: oxc_ast::ast::ObjectExpression
+    /// [`ClassBody`]: oxc_ast::ast::ClassBody
+    pub owner_id: NodeId,
+    /// Name of the property, without `#` for private properties.
+    ///
+    /// [`None`] for computed keys which are not literals, e.g. `get [foo]() {}`. Such accessors
+    /// are never paired with each other.
+    pub name: Option<CompactStr>,
+    /// `static` class members. Always `false` in object literals.
+    pub r#static: bool,
+    pub is_private: bool,
+    /// The last getter of the property, as it overrides the previous ones.
+    pub getter: Option<Accessor>,
+    /// The last setter of the property, as it overrides the previous ones.
+    pub setter: Option<Accessor>,
+}
+
+impl AccessorPair {
+    /// Whether the property has both a getter and a setter.
+    pub fn is_complete(&self) -> bool {
+        self.getter.is_some() && self.setter.is_some()
+    }
+
+    /// Whether the getter and the setter are defined next to each other.
+    ///
+    /// `false` if the pair is not complete.
+    pub fn is_grouped(&self) -> bool {
+        match (&self.getter, &self.setter) {
+            (Some(getter), Some(setter)) => getter.index.abs_diff(setter.index) == 1,
+            _ => false,
+        }
+    }
+}
+
+/// The accessor pairs of all object literals and classes of a program.
+#[derive(Debug, Default)]
+pub struct AccessorPairs {
+    pairs: Vec<AccessorPair>,
+    /// Index into `pairs` by the node of the accessor.
+    by_accessor: FxHashMap<NodeId, usize>,
+}
+
+impl AccessorPairs {
+    pub fn new(semantic: &Semantic) -> Self {
+        let nodes = semantic.nodes();
+        let mut accessor_pairs = Self::default();
+        let mut by_property = FxHashMap::<(NodeId, CompactStr, bool, bool), usize>::default();
+
+        for node in nodes.iter() {
+            let Some(owner_id) = nodes.parent_id(node.id()) else { continue };
+            let (key, kind, r#static, index) = match (node.kind(), nodes.kind(owner_id)) {
+                (AstKind::ObjectProperty(prop), AstKind::ObjectExpression(object)) => {
+                    let kind = match prop.kind {
+                        PropertyKind::Get => AccessorKind::Get,
+                        PropertyKind::Set => AccessorKind::Set,
+                        PropertyKind::Init => continue,
+                    };
+                    let index = object.properties.iter().position(|property| {
+                        matches!(property, ObjectPropertyKind::ObjectProperty(property)
+                            if std::ptr::eq(&**property, prop))
+                    });
+                    (&prop.key, kind, false, index)
+                }
+                (AstKind::MethodDefinition(method), AstKind::ClassBody(body)) => {
+                    let kind = match method.kind {
+                        MethodDefinitionKind::Get => AccessorKind::Get,
+                        MethodDefinitionKind::Set => AccessorKind::Set,
+                        MethodDefinitionKind::Method | MethodDefinitionKind::Constructor => {
+                            continue
+                        }
+                    };
+                    let index = body.body.iter().position(|element| {
+                        matches!(element, ClassElement::MethodDefinition(element)
+                            if std::ptr::eq(&**element, method))
+                    });
+                    (&method.key, kind, method.r#static, index)
+                }
+                _ => continue,
+            };
+            let Some(index) = index else { continue };
+
+            let accessor = Accessor { node_id: node.id(), kind, span: node.kind().span(), index };
+            let is_private = key.is_private_identifier();
+            let name = property_name(key);
+            let property = name.clone().map(|name| (owner_id, name, r#static, is_private));
+            let pair_index = match property.as_ref().and_then(|property| by_property.get(property))
+            {
+                Some(&pair_index) => pair_index,
+                None => {
+                    let pair_index = accessor_pairs.pairs.len();
+                    accessor_pairs.pairs.push(AccessorPair {
+                        owner_id,
+                        name,
+                        r#static,
+                        is_private,
+                        getter: None,
+                        setter: None,
+                    });
+                    if let Some(property) = property {
+                        by_property.insert(property, pair_index);
+                    }
+                    pair_index
+                }
+            };
+            let pair = &mut accessor_pairs.pairs[pair_index];
+            match kind {
+                AccessorKind::Get => pair.getter = Some(accessor),
+                AccessorKind::Set => pair.setter = Some(accessor),
+            }
+            accessor_pairs.by_accessor.insert(node.id(), pair_index);
+        }
+
+        accessor_pairs
+    }
+
+    /// All accessor pairs, in source order of their first accessor.
+    pub fn pairs(&self) -> &[AccessorPair] {
+        &self.pairs
+    }
+
+    /// The accessor pairs of an [`ObjectExpression`] or [`ClassBody`] node.
+    ///
+    /// [`ObjectExpression`]: oxc_ast::ast::ObjectExpression
+    /// [`ClassBody`]: oxc_ast::ast::ClassBody
+    pub fn pairs_of(&self, owner_id: NodeId) -> impl Iterator<Item = &AccessorPair> + '_ {
+        self.pairs.iter().filter(move |pair| pair.owner_id == owner_id)
+    }
+
+    /// Get the pair of a getter or a setter, by its [`ObjectProperty`] or [`MethodDefinition`]
+    /// node.
+    ///
+    /// [`ObjectProperty`]: oxc_ast::ast::ObjectProperty
+    /// [`MethodDefinition`]: oxc_ast::ast::MethodDefinition
+    pub fn get(&self, accessor_id: NodeId) -> Option<&AccessorPair> {
+        self.by_accessor.get(&accessor_id).map(|&index| &self.pairs[index])
+    }
+
+    /// Whether a public property named `name` may be defined by a getter or a setter anywhere in
+    /// the program.
+    ///
+    /// Accessors run code when their property is read or written, so such properties must not be
+    /// renamed or removed, e.g. when mangling properties. Accessors with computed keys which are
+    /// not literals may define any property.
+    pub fn may_define(&self, name: &str) -> bool {
+        self.pairs
+            .iter()
+            .filter(|pair| !pair.is_private)
+            .any(|pair| pair.name.as_ref().map_or(true, |pair_name| pair_name == name))
+    }
+}
+
+fn property_name(key: &PropertyKey) -> Option<CompactStr> {
+    key.name().map(|name| CompactStr::from(name.as_ref()))
+}
@@ -8,13 +8,15 @@ use std::{
 
 use rustc_hash::FxHashMap;
 
-use oxc_ast::{ast::*, AstKind, Visit};
+use oxc_ast::{ast::*, AstKind};
+use oxc_ast_visit::Visit;
 use oxc_cfg::{
     ControlFlowGraphBuilder, CtxCursor, CtxFlags, EdgeType, ErrorEdgeKind,
     IterationInstructionKind, ReturnInstructionKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_span::{Atom, CompactStr, SourceType, Span};
+use oxc_ecmascript::BoundNames;
+use oxc_span::{Atom, CompactStr, GetSpan, SourceType, Span};
 use oxc_syntax::{module_record::ModuleRecord, operator::AssignmentOperator};
 
 use crate::{
@@ -29,7 +31,7 @@ use crate::{
     reference::{Reference, ReferenceFlags, ReferenceId},
     scope::{Bindings, ScopeFlags, ScopeId, ScopeTree},
     stats::Stats,
-    symbol::{SymbolFlags, SymbolId, SymbolTable},
+    symbol::{ReexportSource, SymbolExport, SymbolFlags, SymbolId, SymbolTable},
     unresolved_stack::UnresolvedReferencesStack,
     JSDocFinder, Semantic,
 };
@@ -584,6 +586,89 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// Record the names under which top-level symbols are exported, and where exported import
+    /// bindings come from.
+    fn add_symbol_exports(&mut self, program: &Program<'a>) {
+        for stmt in &program.body {
+            match stmt {
+                // `export { a } from "mod"` does not export a local binding
+                Statement::ExportNamedDeclaration(decl) if decl.source.is_none() => {
+                    if let Some(declaration) = &decl.declaration {
+                        declaration.bound_names(&mut |ident| {
+                            if let Some(symbol_id) = ident.symbol_id.get() {
+                                let name = ident.name.to_compact_str();
+                                let export = SymbolExport { span: ident.span, name };
+                                self.symbols.add_export(symbol_id, export);
+                            }
+                        });
+                    }
+                    for specifier in &decl.specifiers {
+                        let Some(local) = specifier.local.identifier_name() else { continue };
+                        if let Some(symbol_id) =
+                            self.scope.get_binding(self.current_scope_id, local.as_str())
+                        {
+                            let export = SymbolExport {
+                                span: specifier.exported.span(),
+                                name: specifier.exported.name().to_compact_str(),
+                            };
+                            self.symbols.add_export(symbol_id, export);
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    let symbol_id = match &decl.declaration {
+                        ExportDefaultDeclarationKind::Identifier(ident) => {
+                            self.scope.get_binding(self.current_scope_id, ident.name.as_str())
+                        }
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                            func.id.as_ref().and_then(|id| id.symbol_id.get())
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                            class.id.as_ref().and_then(|id| id.symbol_id.get())
+                        }
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                            decl.id.symbol_id.get()
+                        }
+                        _ => None,
+                    };
+                    if let Some(symbol_id) = symbol_id {
+                        let export =
+                            SymbolExport { span: decl.exported.span(), name: "default".into() };
+                        self.symbols.add_export(symbol_id, export);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                let (local, imported_name) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        (&specifier.local, Some(specifier.imported.name().to_compact_str()))
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (&specifier.local, Some(CompactStr::new("default")))
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                        (&specifier.local, None)
+                    }
+                };
+                let Some(symbol_id) = local.symbol_id.get() else { continue };
+                if self.symbols.get_exports(symbol_id).is_empty() {
+                    continue;
+                }
+                let source = ReexportSource {
+                    module_request: decl.source.value.to_compact_str(),
+                    imported_name,
+                };
+                self.symbols.set_reexport_source(symbol_id, source);
+            }
+        }
+    }
+
     /// Flag the symbol bound to an identifier in the current scope as exported.
     fn add_export_flag_to_identifier(&mut self, name: &str) {
         if let Some(symbol_id) = self.scope.get_binding(self.current_scope_id, name) {
@@ -1964,6 +2049,7 @@ impl<'a> SemanticBuilder<'a> {
         match kind {
             AstKind::Program(program) => {
                 self.add_export_flag_to_export_identifiers(program);
+                self.add_symbol_exports(program);
             }
             AstKind::Class(_) => {
                 self.current_node_flags -= NodeFlags::Class;
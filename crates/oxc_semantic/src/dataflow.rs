@@ -0,0 +1,525 @@
+//! Dataflow analyses of the symbols of a program, on top of its [`ControlFlowGraph`].
+//!
+//! [`SymbolDataflow`] solves reaching definitions, liveness and definite assignment for every
+//! symbol, and answers queries about them by [`SymbolId`], [`ReferenceId`] and [`NodeId`].
+
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    dataflow::{AnalysisDirection, BitSet, DataflowAnalysis, DataflowResults},
+    graph::{visit::EdgeRef, Direction},
+    BlockNodeId, ControlFlowGraph, EdgeType,
+};
+use oxc_index::Idx;
+use oxc_span::GetSpan;
+use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    node::NodeId, reference::ReferenceId, symbol::SymbolDeclarationKind, AstNodes, ScopeTree,
+    Semantic,
+};
+
+/// A point where a symbol is given a value: a declaration with an initializer, a parameter, or
+/// a write reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Definition {
+    pub symbol_id: SymbolId,
+    /// The [`BindingIdentifier`] or [`IdentifierReference`] node of the definition.
+    ///
+    /// [`BindingIdentifier`]: oxc_ast::ast::BindingIdentifier
+    /// [`IdentifierReference`]: oxc_ast::ast::IdentifierReference
+    pub node_id: NodeId,
+    /// The write reference of the definition, [`None`] for declarations.
+    pub reference_id: Option<ReferenceId>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Event {
+    Read { symbol_id: SymbolId },
+    Define { definition: usize, symbol_id: SymbolId },
+}
+
+/// Reaching definitions, liveness and definite assignment of every symbol in a program.
+///
+/// Symbols which are referenced from a nested function can be read or written at any time the
+/// function is called, so queries about them are answered conservatively: every definition
+/// reaches such references, they are always considered assigned, and definitions of symbols
+/// read from a nested function are always considered used.
+///
+/// ## Example
+/// ```ignore
+/// let dataflow = SymbolDataflow::new(&semantic).unwrap();
+/// for definition in dataflow.definitions(symbol_id) {
+///     if !dataflow.is_used(definition) {
+///         // the value assigned by `definition` is never read
+///     }
+/// }
+/// ```
+pub struct SymbolDataflow {
+    definitions: Vec<Definition>,
+    definitions_by_symbol: FxHashMap<SymbolId, Vec<usize>>,
+    /// The events of each basic block, in execution order.
+    events: Vec<Vec<Event>>,
+    /// The basic block and index in [`Self::events`] of the event of each reference.
+    reference_events: FxHashMap<ReferenceId, (BlockNodeId, usize)>,
+    /// The basic block and index in [`Self::events`] of each definition.
+    definition_events: Vec<(BlockNodeId, usize)>,
+    /// Symbols referenced from a function other than the one they are declared in.
+    captured: BitSet,
+    /// Symbols read from a function other than the one they are declared in.
+    captured_reads: BitSet,
+    hoisted: BitSet,
+    symbol_count: usize,
+    reaching: DataflowResults<BitSet>,
+    liveness: DataflowResults<BitSet>,
+    assigned: DataflowResults<BitSet>,
+}
+
+impl SymbolDataflow {
+    /// Solve the analyses for `semantic`.
+    ///
+    /// Returns [`None`] if `semantic` was built without a control flow graph.
+    pub fn new(semantic: &Semantic) -> Option<Self> {
+        let cfg = semantic.cfg()?;
+        let nodes = semantic.nodes();
+        let scopes = semantic.scopes();
+        let symbols = semantic.symbols();
+        let symbol_count = symbols.len();
+
+        let mut definitions = vec![];
+        let mut definitions_by_symbol = FxHashMap::<SymbolId, Vec<usize>>::default();
+        let mut hoisted = BitSet::new_empty(symbol_count);
+        let mut captured = BitSet::new_empty(symbol_count);
+        let mut captured_reads = BitSet::new_empty(symbol_count);
+        // (block, position, is_write, event, reference)
+        let mut located = vec![];
+
+        for node in nodes.iter() {
+            match node.kind() {
+                AstKind::BindingIdentifier(ident) => {
+                    let Some(symbol_id) = ident.symbol_id.get() else { continue };
+                    if matches!(
+                        symbols.get_declaration_kind(symbol_id),
+                        SymbolDeclarationKind::Function
+                            | SymbolDeclarationKind::Import
+                            | SymbolDeclarationKind::TypeImport
+                    ) {
+                        hoisted.insert(symbol_id.index());
+                        continue;
+                    }
+                    let (block, position) = match declarator_of(nodes, node.id()) {
+                        Some((declarator_id, true)) => {
+                            evaluated_at(cfg, nodes, declarator_id, node.cfg_id())
+                        }
+                        // `let x;` leaves `x` unassigned, but `for (let x of xs)` assigns it.
+                        Some((declarator_id, false))
+                            if !is_for_in_of_left(nodes, declarator_id) =>
+                        {
+                            continue
+                        }
+                        _ => (node.cfg_id(), ident.span.end),
+                    };
+                    let definition = definitions.len();
+                    definitions.push(Definition {
+                        symbol_id,
+                        node_id: node.id(),
+                        reference_id: None,
+                    });
+                    definitions_by_symbol.entry(symbol_id).or_default().push(definition);
+                    located.push((
+                        block,
+                        position,
+                        true,
+                        Event::Define { definition, symbol_id },
+                        None,
+                    ));
+                }
+                AstKind::IdentifierReference(ident) => {
+                    let Some(reference_id) = ident.reference_id.get() else { continue };
+                    let reference = symbols.get_reference(reference_id);
+                    let Some(symbol_id) = reference.symbol_id() else { continue };
+                    if !reference.is_value() {
+                        continue;
+                    }
+                    if function_scope(scopes, node.scope_id())
+                        != function_scope(scopes, symbols.get_scope_id(symbol_id))
+                    {
+                        captured.insert(symbol_id.index());
+                        if reference.is_read() {
+                            captured_reads.insert(symbol_id.index());
+                        }
+                    }
+                    if reference.is_read() {
+                        located.push((
+                            node.cfg_id(),
+                            ident.span.start,
+                            false,
+                            Event::Read { symbol_id },
+                            Some(reference_id),
+                        ));
+                    }
+                    if reference.is_write() {
+                        let (block, position) = match assignment_of(nodes, node.id()) {
+                            Some(assignment_id) => {
+                                evaluated_at(cfg, nodes, assignment_id, node.cfg_id())
+                            }
+                            None => (node.cfg_id(), ident.span.end),
+                        };
+                        let definition = definitions.len();
+                        definitions.push(Definition {
+                            symbol_id,
+                            node_id: node.id(),
+                            reference_id: Some(reference_id),
+                        });
+                        definitions_by_symbol.entry(symbol_id).or_default().push(definition);
+                        located.push((
+                            block,
+                            position,
+                            true,
+                            Event::Define { definition, symbol_id },
+                            // The read of `x += 1` is the event of the reference.
+                            (!reference.is_read()).then_some(reference_id),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Reads happen before writes at the same position, e.g. `x = x`.
+        located
+            .sort_by_key(|(block, position, is_write, _, _)| (block.index(), *position, *is_write));
+
+        let mut events = vec![vec![]; cfg.graph().node_count()];
+        let mut reference_events = FxHashMap::default();
+        let mut definition_events = vec![(BlockNodeId::new(0), 0); definitions.len()];
+        for (block, _, _, event, reference_id) in located {
+            let block_events = &mut events[block.index()];
+            if let Some(reference_id) = reference_id {
+                reference_events.insert(reference_id, (block, block_events.len()));
+            }
+            if let Event::Define { definition, .. } = event {
+                definition_events[definition] = (block, block_events.len());
+            }
+            block_events.push(event);
+        }
+
+        let reaching = cfg.solve_dataflow(&ReachingDefinitions {
+            events: &events,
+            definitions: &definitions,
+            definitions_by_symbol: &definitions_by_symbol,
+        });
+        let liveness = cfg.solve_dataflow(&Liveness { events: &events, symbol_count });
+        let assigned = cfg.solve_dataflow(&DefiniteAssignment {
+            events: &events,
+            symbol_count,
+            hoisted: &hoisted,
+        });
+
+        Some(Self {
+            definitions,
+            definitions_by_symbol,
+            events,
+            reference_events,
+            definition_events,
+            captured,
+            captured_reads,
+            hoisted,
+            symbol_count,
+            reaching,
+            liveness,
+            assigned,
+        })
+    }
+
+    /// All the definitions of `symbol_id`, in source order within each basic block.
+    pub fn definitions(&self, symbol_id: SymbolId) -> impl Iterator<Item = &Definition> + '_ {
+        self.definitions_by_symbol
+            .get(&symbol_id)
+            .into_iter()
+            .flatten()
+            .map(|&definition| &self.definitions[definition])
+    }
+
+    /// The definitions whose value may be read by `reference_id`.
+    ///
+    /// Returns an empty [`Vec`] for references which are not reads of a symbol.
+    pub fn reaching_definitions(&self, reference_id: ReferenceId) -> Vec<&Definition> {
+        let Some(&(block, index)) = self.reference_events.get(&reference_id) else {
+            return vec![];
+        };
+        let Event::Read { symbol_id } = self.events[block.index()][index] else {
+            return vec![];
+        };
+        if self.captured.contains(symbol_id.index()) {
+            return self.definitions(symbol_id).collect();
+        }
+        let mut state = self.reaching.entry(block).clone();
+        let analysis = ReachingDefinitions {
+            events: &self.events,
+            definitions: &self.definitions,
+            definitions_by_symbol: &self.definitions_by_symbol,
+        };
+        for event in &self.events[block.index()][..index] {
+            analysis.apply(event, &mut state);
+        }
+        self.definitions_by_symbol
+            .get(&symbol_id)
+            .into_iter()
+            .flatten()
+            .filter(|&&definition| state.contains(definition))
+            .map(|&definition| &self.definitions[definition])
+            .collect()
+    }
+
+    /// Whether the symbol read by `reference_id` is assigned on every path to the reference.
+    ///
+    /// Returns `true` for references which are not reads of a symbol.
+    pub fn is_definitely_assigned(&self, reference_id: ReferenceId) -> bool {
+        let Some(&(block, index)) = self.reference_events.get(&reference_id) else {
+            return true;
+        };
+        let Event::Read { symbol_id } = self.events[block.index()][index] else {
+            return true;
+        };
+        if self.captured.contains(symbol_id.index()) || self.hoisted.contains(symbol_id.index()) {
+            return true;
+        }
+        let mut state = self.assigned.entry(block).clone();
+        let analysis = DefiniteAssignment {
+            events: &self.events,
+            symbol_count: self.symbol_count,
+            hoisted: &self.hoisted,
+        };
+        for event in &self.events[block.index()][..index] {
+            analysis.apply(event, &mut state);
+        }
+        state.contains(symbol_id.index())
+    }
+
+    /// Whether the value assigned by `definition` may be read afterwards.
+    pub fn is_used(&self, definition: &Definition) -> bool {
+        let symbol_id = definition.symbol_id;
+        if self.captured_reads.contains(symbol_id.index()) {
+            return true;
+        }
+        let Some(index) = self.definitions.iter().position(|d| d == definition) else {
+            return true;
+        };
+        let (block, index) = self.definition_events[index];
+        let mut state = self.liveness.exit(block).clone();
+        let analysis = Liveness { events: &self.events, symbol_count: self.symbol_count };
+        for event in self.events[block.index()][index + 1..].iter().rev() {
+            analysis.apply(event, &mut state);
+        }
+        state.contains(symbol_id.index())
+    }
+}
+
+struct ReachingDefinitions<'s> {
+    events: &'s [Vec<Event>],
+    definitions: &'s [Definition],
+    definitions_by_symbol: &'s FxHashMap<SymbolId, Vec<usize>>,
+}
+
+impl ReachingDefinitions<'_> {
+    fn apply(&self, event: &Event, state: &mut BitSet) {
+        if let Event::Define { definition, symbol_id } = *event {
+            for &other in &self.definitions_by_symbol[&symbol_id] {
+                state.remove(other);
+            }
+            state.insert(definition);
+        }
+    }
+}
+
+impl DataflowAnalysis for ReachingDefinitions<'_> {
+    type Domain = BitSet;
+
+    const DIRECTION: AnalysisDirection = AnalysisDirection::Forward;
+
+    fn bottom(&self) -> BitSet {
+        BitSet::new_empty(self.definitions.len())
+    }
+
+    fn boundary(&self) -> BitSet {
+        self.bottom()
+    }
+
+    fn join(&self, state: &mut BitSet, other: &BitSet) {
+        state.union_with(other);
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut BitSet) {
+        for event in &self.events[block.index()] {
+            self.apply(event, state);
+        }
+    }
+}
+
+struct Liveness<'s> {
+    events: &'s [Vec<Event>],
+    symbol_count: usize,
+}
+
+impl Liveness<'_> {
+    fn apply(&self, event: &Event, state: &mut BitSet) {
+        match *event {
+            Event::Read { symbol_id } => state.insert(symbol_id.index()),
+            Event::Define { symbol_id, .. } => state.remove(symbol_id.index()),
+        }
+    }
+}
+
+impl DataflowAnalysis for Liveness<'_> {
+    type Domain = BitSet;
+
+    const DIRECTION: AnalysisDirection = AnalysisDirection::Backward;
+
+    fn bottom(&self) -> BitSet {
+        BitSet::new_empty(self.symbol_count)
+    }
+
+    fn boundary(&self) -> BitSet {
+        self.bottom()
+    }
+
+    fn join(&self, state: &mut BitSet, other: &BitSet) {
+        state.union_with(other);
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut BitSet) {
+        for event in self.events[block.index()].iter().rev() {
+            self.apply(event, state);
+        }
+    }
+}
+
+struct DefiniteAssignment<'s> {
+    events: &'s [Vec<Event>],
+    symbol_count: usize,
+    hoisted: &'s BitSet,
+}
+
+impl DefiniteAssignment<'_> {
+    fn apply(&self, event: &Event, state: &mut BitSet) {
+        if let Event::Define { symbol_id, .. } = *event {
+            state.insert(symbol_id.index());
+        }
+    }
+}
+
+impl DataflowAnalysis for DefiniteAssignment<'_> {
+    type Domain = BitSet;
+
+    const DIRECTION: AnalysisDirection = AnalysisDirection::Forward;
+
+    fn bottom(&self) -> BitSet {
+        BitSet::new_filled(self.symbol_count)
+    }
+
+    fn boundary(&self) -> BitSet {
+        self.hoisted.clone()
+    }
+
+    fn join(&self, state: &mut BitSet, other: &BitSet) {
+        state.intersect_with(other);
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut BitSet) {
+        for event in &self.events[block.index()] {
+            self.apply(event, state);
+        }
+    }
+}
+
+/// The closest function or top level scope enclosing `scope_id`.
+fn function_scope(scopes: &ScopeTree, scope_id: ScopeId) -> ScopeId {
+    scopes
+        .ancestors(scope_id)
+        .find(|&scope_id| {
+            let flags = scopes.get_flags(scope_id);
+            flags.is_function() || flags.is_arrow() || flags.is_top()
+        })
+        .unwrap_or(scope_id)
+}
+
+/// The [`VariableDeclarator`] declaring the binding `node_id`, and whether it has an initializer.
+///
+/// [`VariableDeclarator`]: oxc_ast::ast::VariableDeclarator
+fn declarator_of(nodes: &AstNodes, node_id: NodeId) -> Option<(NodeId, bool)> {
+    for ancestor in nodes.ancestors(node_id).skip(1) {
+        match ancestor.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                return Some((ancestor.id(), declarator.init.is_some()));
+            }
+            AstKind::ObjectPattern(_)
+            | AstKind::ArrayPattern(_)
+            | AstKind::AssignmentPattern(_)
+            | AstKind::BindingRestElement(_) => {}
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Whether `declarator_id` is the left side of a `for-in` or `for-of` loop.
+fn is_for_in_of_left(nodes: &AstNodes, declarator_id: NodeId) -> bool {
+    nodes
+        .ancestor_kinds(declarator_id)
+        .skip(1)
+        .find(|kind| !matches!(kind, AstKind::VariableDeclaration(_)))
+        .is_some_and(|kind| matches!(kind, AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)))
+}
+
+/// The assignment or update expression writing to the reference `node_id`.
+fn assignment_of(nodes: &AstNodes, node_id: NodeId) -> Option<NodeId> {
+    for ancestor in nodes.ancestors(node_id).skip(1) {
+        match ancestor.kind() {
+            AstKind::AssignmentExpression(_) | AstKind::UpdateExpression(_) => {
+                return Some(ancestor.id());
+            }
+            kind if kind.is_statement() || kind.is_declaration() || kind.is_function_like() => {
+                return None;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The basic block and position at which the evaluation of `node_id` completes.
+///
+/// When the evaluation branches, e.g. `x = a || b`, it completes in the block joining the
+/// branches, which is a successor of the block of its last node.
+fn evaluated_at(
+    cfg: &ControlFlowGraph,
+    nodes: &AstNodes,
+    node_id: NodeId,
+    start_block: BlockNodeId,
+) -> (BlockNodeId, u32) {
+    let node = nodes.get_node(node_id);
+    let end = node.kind().span().end;
+    let scope = function_scope_of(nodes, node_id);
+    let last_block = nodes
+        .iter()
+        .skip(node_id.index() + 1)
+        // Nodes are added in pre-order, so the descendants of a node directly follow it.
+        .take_while(|descendant| nodes.parent_id(descendant.id()) >= Some(node_id))
+        .filter(|descendant| function_scope_of(nodes, descendant.id()) == scope)
+        .last()
+        .map_or(start_block, |descendant| descendant.cfg_id());
+    if last_block == start_block {
+        return (start_block, end);
+    }
+    let join = cfg
+        .graph()
+        .edges_directed(last_block, Direction::Outgoing)
+        .find(|edge| matches!(edge.weight(), EdgeType::Normal))
+        .map_or(last_block, |edge| edge.target());
+    (join, end)
+}
+
+fn function_scope_of(nodes: &AstNodes, node_id: NodeId) -> Option<NodeId> {
+    nodes.enclosing_function(node_id).map(|node| node.id())
+}
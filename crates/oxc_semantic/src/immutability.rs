@@ -0,0 +1,210 @@
+//! Shallow immutability of the object and array literals bound to `const` bindings.
+//!
+//! The properties of such an object cannot change when it is frozen, e.g.
+//! `const x = Object.freeze({ a: 1 })`, or when it is only ever used to read its properties, e.g.
+//! `const x = { a: 1 }; f(x.a)`. Reads of its properties can then be replaced by their values,
+//! e.g. when minifying.
+
+use oxc_ast::{
+    ast::{BindingPatternKind, CallExpression, Expression, ObjectPropertyKind, PropertyKind},
+    AstKind,
+};
+use oxc_span::GetSpan;
+use oxc_syntax::{
+    operator::UnaryOperator,
+    symbol::{SymbolFlags, SymbolId},
+};
+use rustc_hash::FxHashMap;
+
+use crate::{AstNodes, IsGlobalReference, NodeId, Semantic};
+
+/// Why the properties of an [`ImmutableObject`] cannot change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Immutability {
+    /// `const x = Object.freeze({ a: 1 })`
+    ///
+    /// Writes to the properties of the object fail, so it can be used in any way.
+    Frozen,
+    /// `const x = { a: 1 }` or `const x = [1]`, which is only used to read its properties.
+    ///
+    /// The object is never written to, passed to a function, aliased or exported, and its methods
+    /// are never called, as they could write to it through `this`.
+    NeverMutated,
+}
+
+/// An object or array literal bound to a `const` binding, whose properties cannot change.
+///
+/// Immutability is shallow: the objects in the properties of an immutable object may change,
+/// e.g. `x.a.b = 1` for `const x = Object.freeze({ a: {} })`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImmutableObject {
+    pub symbol_id: SymbolId,
+    /// The [`ObjectExpression`] or [`ArrayExpression`] node.
+    ///
+    /// [`ObjectExpression`]: oxc_ast::ast::ObjectExpression
+    /// [`ArrayExpression`]: oxc_ast::ast::ArrayExpression
+    pub node_id: NodeId,
+    pub immutability: Immutability,
+}
+
+/// The immutable objects of a program, by the symbol they are bound to.
+///
+/// Properties defined with getters are not constant, even on immutable objects. Objects with
+/// getters are never [`Immutability::NeverMutated`], as a getter could write to the object
+/// through `this`.
+///
+/// ## Example
+/// ```ignore
+/// let immutability = ObjectImmutability::new(&semantic);
+/// if immutability.is_immutable(symbol_id) {
+///     // `x.a` can be replaced by the value of `a` in the object literal bound to `x`
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ObjectImmutability {
+    objects: FxHashMap<SymbolId, ImmutableObject>,
+}
+
+impl ObjectImmutability {
+    pub fn new(semantic: &Semantic) -> Self {
+        let nodes = semantic.nodes();
+        let mut immutability = Self::default();
+
+        for node in nodes.iter() {
+            let has_accessors = match node.kind() {
+                AstKind::ObjectExpression(object) => object.properties.iter().any(|property| {
+                    matches!(property, ObjectPropertyKind::ObjectProperty(property)
+                        if property.kind != PropertyKind::Init)
+                }),
+                AstKind::ArrayExpression(_) => false,
+                _ => continue,
+            };
+
+            let mut kind = Immutability::NeverMutated;
+            let Some(mut parent_id) = nodes.parent_id(outermost_wrapper(nodes, node.id())) else {
+                continue;
+            };
+            if let AstKind::Argument(_) = nodes.kind(parent_id) {
+                let Some(call_id) = nodes.parent_id(parent_id) else { continue };
+                let AstKind::CallExpression(call) = nodes.kind(call_id) else { continue };
+                if !is_object_freeze(call, semantic) {
+                    continue;
+                }
+                kind = Immutability::Frozen;
+                let Some(id) = nodes.parent_id(outermost_wrapper(nodes, call_id)) else { continue };
+                parent_id = id;
+            }
+
+            let AstKind::VariableDeclarator(decl) = nodes.kind(parent_id) else { continue };
+            if !decl.kind.is_const() {
+                continue;
+            }
+            let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { continue };
+            let symbol_id = ident.symbol_id();
+            if kind == Immutability::NeverMutated
+                && (has_accessors || !is_never_mutated(semantic, symbol_id))
+            {
+                continue;
+            }
+            immutability.objects.insert(
+                symbol_id,
+                ImmutableObject { symbol_id, node_id: node.id(), immutability: kind },
+            );
+        }
+
+        immutability
+    }
+
+    /// The immutable object bound to a symbol.
+    pub fn get(&self, symbol_id: SymbolId) -> Option<&ImmutableObject> {
+        self.objects.get(&symbol_id)
+    }
+
+    /// Whether a symbol is bound to an immutable object.
+    pub fn is_immutable(&self, symbol_id: SymbolId) -> bool {
+        self.objects.contains_key(&symbol_id)
+    }
+
+    /// All immutable objects, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &ImmutableObject> + '_ {
+        self.objects.values()
+    }
+}
+
+/// The outermost of the parentheses and TypeScript expressions around a node, or the node
+/// itself, e.g. `({ a: 1 } as const)` for `{ a: 1 }`.
+fn outermost_wrapper(nodes: &AstNodes, node_id: NodeId) -> NodeId {
+    let mut node_id = node_id;
+    while let Some(parent_id) = nodes.parent_id(node_id) {
+        if !matches!(
+            nodes.kind(parent_id),
+            AstKind::ParenthesizedExpression(_)
+                | AstKind::TSAsExpression(_)
+                | AstKind::TSSatisfiesExpression(_)
+                | AstKind::TSNonNullExpression(_)
+        ) {
+            break;
+        }
+        node_id = parent_id;
+    }
+    node_id
+}
+
+/// `Object.freeze(value)`, where this `Object` is the global, not a binding shadowing it, and the
+/// global is never reassigned, e.g. `Object = { freeze: f }`.
+fn is_object_freeze(call: &CallExpression, semantic: &Semantic) -> bool {
+    let Expression::StaticMemberExpression(callee) = &call.callee else { return false };
+    call.arguments.len() == 1
+        && callee.property.name == "freeze"
+        && callee.object.is_global_reference_name("Object", semantic.symbols())
+        && !semantic.scopes().root_unresolved_references().get("Object").is_some_and(|ids| {
+            ids.iter().any(|&id| semantic.symbols().get_reference(id).is_write())
+        })
+}
+
+/// Whether every reference to a symbol only reads a property of its value, or freezes it.
+fn is_never_mutated(semantic: &Semantic, symbol_id: SymbolId) -> bool {
+    // Exported bindings, and top level bindings of scripts, can be written to by other files.
+    if semantic.symbols().get_flags(symbol_id).contains(SymbolFlags::Export)
+        || (!semantic.source_type().is_module()
+            && semantic.symbols().get_scope_id(symbol_id) == semantic.scopes().root_scope_id())
+    {
+        return false;
+    }
+    let nodes = semantic.nodes();
+    semantic.symbol_references(symbol_id).all(|reference| {
+        let ident_id = outermost_wrapper(nodes, reference.node_id());
+        let Some(parent_id) = nodes.parent_id(ident_id) else { return false };
+        match nodes.kind(parent_id) {
+            // `x.a`, not `a[x]`
+            AstKind::MemberExpression(member) => {
+                member.object().span() == nodes.kind(ident_id).span()
+                    && is_property_read(nodes, parent_id)
+            }
+            // `Object.freeze(x)`
+            AstKind::Argument(_) => nodes.parent_id(parent_id).is_some_and(|call_id| {
+                matches!(nodes.kind(call_id), AstKind::CallExpression(call)
+                    if is_object_freeze(call, semantic))
+            }),
+            _ => false,
+        }
+    })
+}
+
+/// Whether a member expression only reads the property, e.g. `x.a` but not `x.a = 1`,
+/// `delete x.a` or `x.a()`, which passes `x` as `this`.
+fn is_property_read(nodes: &AstNodes, member_id: NodeId) -> bool {
+    let member_id = outermost_wrapper(nodes, member_id);
+    let Some(parent_id) = nodes.parent_id(member_id) else { return true };
+    let member_span = nodes.kind(member_id).span();
+    match nodes.kind(parent_id) {
+        AstKind::SimpleAssignmentTarget(_)
+        | AstKind::AssignmentTarget(_)
+        | AstKind::AssignmentTargetWithDefault(_)
+        | AstKind::UpdateExpression(_) => false,
+        AstKind::UnaryExpression(expr) => expr.operator != UnaryOperator::Delete,
+        AstKind::CallExpression(call) => call.callee.span() != member_span,
+        AstKind::TaggedTemplateExpression(expr) => expr.tag.span() != member_span,
+        _ => true,
+    }
+}
@@ -118,7 +118,7 @@ impl<'a> JSDocBuilder<'a> {
         false
     }
 
-    fn parse_jsdoc_comment(comment: &Comment, source_text: &'a str) -> JSDoc<'a> {
+    pub(crate) fn parse_jsdoc_comment(comment: &Comment, source_text: &'a str) -> JSDoc<'a> {
         let span = comment.content_span();
         // Remove the very first `*`
         let jsdoc_span = Span::new(span.start + 1, span.end);
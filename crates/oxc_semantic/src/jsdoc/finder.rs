@@ -2,7 +2,9 @@ use rustc_hash::FxHashMap;
 
 use oxc_span::{GetSpan, Span};
 
-use crate::AstNode;
+use oxc_ast::AstKind;
+
+use crate::{AstNode, AstNodes};
 
 use super::parser::JSDoc;
 
@@ -42,4 +44,70 @@ impl<'a> JSDocFinder<'a> {
     pub fn iter_all<'b>(&'b self) -> impl Iterator<Item = &'b JSDoc<'a>> + 'b {
         self.attached.values().flatten().chain(self.not_attached.iter())
     }
+
+    /// Get the node which JSDocs of a function are attached to.
+    ///
+    /// JSDoc is often attached on the parent node of a function.
+    ///
+    /// ```js
+    /// /** VariableDeclaration > VariableDeclarator > FunctionExpression */
+    /// const foo = function() {}
+    ///
+    /// /** VariableDeclaration > VariableDeclarator > ArrowFunctionExpression */
+    /// const bar = () => {},
+    ///       /** VariableDeclarator > ArrowFunctionExpression */
+    ///       baz = () => {};
+    ///
+    /// /** MethodDefinition > FunctionExpression */
+    /// class X { qux() {} }
+    ///
+    /// /** PropertyDefinition > ArrowFunctionExpression */
+    /// class Y { qux = () => {} }
+    /// ```
+    pub fn get_function_jsdoc_node<'b>(
+        &self,
+        node: &'b AstNode<'a>,
+        nodes: &'b AstNodes<'a>,
+    ) -> Option<&'b AstNode<'a>> {
+        let mut current_node = node;
+        // Whether the node has attached JSDoc or not is determined by `JSDocBuilder`
+        while self.get_all_by_node(current_node).is_none() {
+            // Tie-breaker, otherwise every loop will end at `Program` node!
+            match current_node.kind() {
+                AstKind::VariableDeclaration(_)
+                | AstKind::MethodDefinition(_)
+                | AstKind::PropertyDefinition(_)
+                // /** This JSDoc should NOT found for `ArrowFunctionExpression` callback */
+                // function outer() { inner(() => {}) }
+                | AstKind::CallExpression(_)
+                // /** This JSDoc should NOT found for `ArrowFunctionExpression` callback */
+                // new Promise(() => {})
+                | AstKind::NewExpression(_)
+                // /** This JSDoc should NOT found for inner `Function` */
+                // function outer() { return function inner() {} }
+                | AstKind::ReturnStatement(_) => {
+                    // /** This JSDoc should NOT found for `VariableDeclaration` */
+                    // export const foo = () => {}
+                    let parent_node = nodes.parent_node(current_node.id())?;
+                    return match parent_node.kind() {
+                        AstKind::ExportDefaultDeclaration(_)
+                        | AstKind::ExportNamedDeclaration(_) => Some(parent_node),
+                        _ => None,
+                    };
+                }
+                _ => current_node = nodes.parent_node(current_node.id())?,
+            }
+        }
+
+        Some(current_node)
+    }
+
+    /// Get the JSDocs of a function, nearest last. See [`JSDocFinder::get_function_jsdoc_node`].
+    pub fn get_all_by_function(
+        &self,
+        node: &AstNode<'a>,
+        nodes: &AstNodes<'a>,
+    ) -> Option<Vec<JSDoc<'a>>> {
+        self.get_function_jsdoc_node(node, nodes).and_then(|node| self.get_all_by_node(node))
+    }
 }
@@ -4,4 +4,4 @@ mod parser;
 
 pub use builder::JSDocBuilder;
 pub use finder::JSDocFinder;
-pub use parser::{JSDoc, JSDocTag};
+pub use parser::{JSDoc, JSDocRecordField, JSDocTag, JSDocType, JSDocTypeKind};
@@ -29,6 +29,14 @@ impl<'a> JSDoc<'a> {
         &self.parse().1
     }
 
+    /// Tags of a kind, e.g. `param` for `@param`, in source order.
+    pub fn tags_by_kind<'b>(
+        &'b self,
+        kind: &'b str,
+    ) -> impl Iterator<Item = &'b JSDocTag<'a>> + 'b {
+        self.tags().iter().filter(move |tag| tag.kind.parsed() == kind)
+    }
+
     fn parse(&self) -> &ParsedJSDoc<'a> {
         self.cached.get_or_init(|| parse_jsdoc(self.raw, self.span.start))
     }
@@ -40,7 +48,7 @@ mod test {
     use oxc_parser::Parser;
     use oxc_span::SourceType;
 
-    use crate::{Semantic, SemanticBuilder};
+    use crate::{jsdoc::JSDocTypeKind, Semantic, SemanticBuilder};
 
     fn build_semantic<'a>(allocator: &'a Allocator, source_text: &'a str) -> Semantic<'a> {
         let source_type = SourceType::default();
@@ -326,4 +334,31 @@ line2
         let tag = tags.next().unwrap();
         assert_eq!(tag.kind.parsed(), "example");
     }
+
+    #[test]
+    fn tags_by_kind_with_types() {
+        let allocator = Allocator::default();
+        let source_text = "
+            /**
+             * @param {Array.<string>} names
+             * @returns {?number}
+             * @param {string=} [prefix]
+             */
+            ";
+        let semantic = build_semantic(&allocator, source_text);
+        let jsdoc = semantic.jsdoc().iter_all().next().unwrap();
+
+        let params = jsdoc.tags_by_kind("param").collect::<Vec<_>>();
+        assert_eq!(params.len(), 2);
+        let (type_part, name_part, _) = params[1].type_name_comment();
+        assert_eq!(name_part.unwrap().parsed(), "prefix");
+        let ty = type_part.unwrap().parse_type().unwrap();
+        assert!(matches!(ty.kind, JSDocTypeKind::Optional(_)));
+        assert_eq!(ty.span.source_text(source_text), "string=");
+
+        let ty = params[0].r#type().unwrap().parse_type().unwrap();
+        assert_eq!(ty.names(), vec!["Array", "string"]);
+        assert_eq!(jsdoc.tags_by_kind("returns").count(), 1);
+        assert_eq!(jsdoc.tags_by_kind("throws").count(), 0);
+    }
 }
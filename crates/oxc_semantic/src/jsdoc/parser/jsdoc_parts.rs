@@ -1,5 +1,7 @@
 use oxc_span::Span;
 
+use super::jsdoc_type::JSDocType;
+
 /// Used for `JSDoc.comment` and `JSDocTag.comment`
 #[derive(Debug, Clone, Copy)]
 pub struct JSDocCommentPart<'a> {
@@ -125,6 +127,13 @@ impl<'a> JSDocTagTypePart<'a> {
         // +1 for `{`, -1 for `}`
         self.raw[1..self.raw.len() - 1].trim()
     }
+
+    /// Parse the type content into a [`JSDocType`].
+    /// Returns [`None`] if it is not a valid type expression.
+    pub fn parse_type(&self) -> Option<JSDocType<'a>> {
+        // +1 for `{`, -1 for `}`
+        JSDocType::parse(&self.raw[1..self.raw.len() - 1], self.span.start + 1)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
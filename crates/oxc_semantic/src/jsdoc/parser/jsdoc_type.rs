@@ -0,0 +1,467 @@
+use oxc_span::Span;
+
+/// A parsed JSDoc type expression, e.g. `Array<string>` in `@param {Array<string>} names`.
+///
+/// Both the Closure Compiler syntax (`Array.<string>`, `?number`, `function(string): number`)
+/// and the common TypeScript flavored forms (`string[]`, `(a | b)`) are supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocType<'a> {
+    pub kind: JSDocTypeKind<'a>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JSDocTypeKind<'a> {
+    /// `*`
+    Any,
+    /// `?`
+    Unknown,
+    /// `string`, `Foo.Bar`, `module:foo/bar`
+    Name(&'a str),
+    /// `'foo'` or `"foo"`, without the quotes
+    StringLiteral(&'a str),
+    /// `1`, `-1.5`
+    NumberLiteral(&'a str),
+    /// `Array<string>` or `Array.<string>`
+    Generic { base: Box<JSDocType<'a>>, arguments: Vec<JSDocType<'a>> },
+    /// `string[]`
+    Array(Box<JSDocType<'a>>),
+    /// `string | number`
+    Union(Vec<JSDocType<'a>>),
+    /// `?number` or `number?`
+    Nullable(Box<JSDocType<'a>>),
+    /// `!Object` or `Object!`
+    NonNullable(Box<JSDocType<'a>>),
+    /// `number=`, an optional parameter
+    Optional(Box<JSDocType<'a>>),
+    /// `...number`, a rest parameter
+    Rest(Box<JSDocType<'a>>),
+    /// `function(string, number): boolean`
+    Function { params: Vec<JSDocType<'a>>, return_type: Option<Box<JSDocType<'a>>> },
+    /// `{ a: string, b }`
+    Record(Vec<JSDocRecordField<'a>>),
+    /// `[string, number]`
+    Tuple(Vec<JSDocType<'a>>),
+}
+
+/// A field of a [`JSDocTypeKind::Record`], e.g. `a: string` in `{ a: string }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocRecordField<'a> {
+    pub key: &'a str,
+    /// [`None`] for fields without a type, e.g. `b` in `{ a: string, b }`.
+    pub value: Option<JSDocType<'a>>,
+    pub span: Span,
+}
+
+impl<'a> JSDocType<'a> {
+    /// Parse a type expression without the surrounding `{` and `}`.
+    ///
+    /// `span_start` is the position of `source_text` in the file.
+    /// Returns [`None`] if `source_text` is not a valid type expression.
+    pub fn parse(source_text: &'a str, span_start: u32) -> Option<Self> {
+        let mut parser = TypeParser { source_text, pos: 0, span_start };
+        let ty = parser.parse_type()?;
+        parser.skip_whitespace();
+        (parser.pos == source_text.len()).then_some(ty)
+    }
+
+    /// The names referenced by this type, e.g. `Array` and `Foo` in `Array<Foo>`.
+    pub fn names(&self) -> Vec<&'a str> {
+        let mut names = vec![];
+        self.collect_names(&mut names);
+        names
+    }
+
+    fn collect_names(&self, names: &mut Vec<&'a str>) {
+        match &self.kind {
+            JSDocTypeKind::Name(name) => names.push(*name),
+            JSDocTypeKind::Any
+            | JSDocTypeKind::Unknown
+            | JSDocTypeKind::StringLiteral(_)
+            | JSDocTypeKind::NumberLiteral(_) => {}
+            JSDocTypeKind::Generic { base, arguments } => {
+                base.collect_names(names);
+                arguments.iter().for_each(|ty| ty.collect_names(names));
+            }
+            JSDocTypeKind::Array(ty)
+            | JSDocTypeKind::Nullable(ty)
+            | JSDocTypeKind::NonNullable(ty)
+            | JSDocTypeKind::Optional(ty)
+            | JSDocTypeKind::Rest(ty) => ty.collect_names(names),
+            JSDocTypeKind::Union(types) | JSDocTypeKind::Tuple(types) => {
+                types.iter().for_each(|ty| ty.collect_names(names));
+            }
+            JSDocTypeKind::Function { params, return_type } => {
+                params.iter().for_each(|ty| ty.collect_names(names));
+                if let Some(return_type) = return_type {
+                    return_type.collect_names(names);
+                }
+            }
+            JSDocTypeKind::Record(fields) => {
+                for field in fields {
+                    if let Some(value) = &field.value {
+                        value.collect_names(names);
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct TypeParser<'a> {
+    source_text: &'a str,
+    pos: usize,
+    span_start: u32,
+}
+
+impl<'a> TypeParser<'a> {
+    fn span(&self, start: usize) -> Span {
+        let offset = |pos: usize| self.span_start + u32::try_from(pos).unwrap_or_default();
+        Span::new(offset(start), offset(self.pos))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source_text[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Skip whitespace, and the leading `*` of lines of multiline types.
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            let skipped = &rest[..rest.len() - trimmed.len()];
+            self.pos += skipped.len();
+            if skipped.contains('\n') && trimmed.starts_with('*') {
+                self.pos += 1;
+                continue;
+            }
+            break;
+        }
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, s: &str) -> Option<()> {
+        self.eat(s).then_some(())
+    }
+
+    fn new_type(&self, kind: JSDocTypeKind<'a>, start: usize) -> JSDocType<'a> {
+        JSDocType { kind, span: self.span(start) }
+    }
+
+    fn parse_type(&mut self) -> Option<JSDocType<'a>> {
+        self.skip_whitespace();
+        let start = self.pos;
+        // A leading `|` is allowed, e.g. `| 'a' | 'b'`.
+        self.eat("|");
+        let first = self.parse_prefix()?;
+        if !self.eat("|") {
+            return Some(first);
+        }
+        let mut types = vec![first];
+        loop {
+            types.push(self.parse_prefix()?);
+            if !self.eat("|") {
+                break;
+            }
+        }
+        Some(self.new_type(JSDocTypeKind::Union(types), start))
+    }
+
+    fn parse_prefix(&mut self) -> Option<JSDocType<'a>> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let wrap: fn(Box<JSDocType<'a>>) -> JSDocTypeKind<'a> = if self.eat("...") {
+            JSDocTypeKind::Rest
+        } else if self.eat("!") {
+            JSDocTypeKind::NonNullable
+        } else if self.rest().starts_with('?') && !self.is_unknown_type() {
+            self.pos += 1;
+            JSDocTypeKind::Nullable
+        } else {
+            return self.parse_postfix();
+        };
+        let ty = self.parse_prefix()?;
+        Some(self.new_type(wrap(Box::new(ty)), start))
+    }
+
+    /// Whether the `?` at the current position is the unknown type, not a nullable prefix.
+    fn is_unknown_type(&self) -> bool {
+        self.rest()[1..]
+            .trim_start()
+            .chars()
+            .next()
+            .map_or(true, |ch| matches!(ch, ',' | '>' | ')' | ']' | '}' | '|' | '=' | ':'))
+    }
+
+    fn parse_postfix(&mut self) -> Option<JSDocType<'a>> {
+        let start = self.pos;
+        let mut ty = self.parse_primary()?;
+        loop {
+            let kind = if self.eat("[]") {
+                JSDocTypeKind::Array(Box::new(ty))
+            } else if self.eat(".<") || self.eat("<") {
+                let arguments = self.parse_list(">")?;
+                JSDocTypeKind::Generic { base: Box::new(ty), arguments }
+            } else if self.eat("=") {
+                JSDocTypeKind::Optional(Box::new(ty))
+            } else if self.rest().starts_with('?') {
+                self.pos += 1;
+                JSDocTypeKind::Nullable(Box::new(ty))
+            } else if self.rest().starts_with('!') {
+                self.pos += 1;
+                JSDocTypeKind::NonNullable(Box::new(ty))
+            } else {
+                return Some(ty);
+            };
+            ty = self.new_type(kind, start);
+        }
+    }
+
+    /// Parse types separated by `,` until `close`.
+    fn parse_list(&mut self, close: &str) -> Option<Vec<JSDocType<'a>>> {
+        let mut types = vec![];
+        if self.eat(close) {
+            return Some(types);
+        }
+        loop {
+            types.push(self.parse_type()?);
+            if self.eat(close) {
+                return Some(types);
+            }
+            self.expect(",")?;
+        }
+    }
+
+    fn parse_primary(&mut self) -> Option<JSDocType<'a>> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let ch = self.peek()?;
+        let kind = match ch {
+            '*' => {
+                self.pos += 1;
+                JSDocTypeKind::Any
+            }
+            '?' => {
+                self.pos += 1;
+                JSDocTypeKind::Unknown
+            }
+            '(' => {
+                self.pos += 1;
+                let ty = self.parse_type()?;
+                self.expect(")")?;
+                return Some(ty);
+            }
+            '[' => {
+                self.pos += 1;
+                JSDocTypeKind::Tuple(self.parse_list("]")?)
+            }
+            '{' => {
+                self.pos += 1;
+                JSDocTypeKind::Record(self.parse_record_fields()?)
+            }
+            '\'' | '"' => {
+                let end = self.rest()[1..].find(ch)? + 1;
+                let value = &self.rest()[1..end];
+                self.pos += end + 1;
+                JSDocTypeKind::StringLiteral(value)
+            }
+            _ if ch == '-' || ch.is_ascii_digit() => {
+                let len = self.rest()[1..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                    .map_or(self.rest().len(), |len| len + 1);
+                let value = &self.rest()[..len];
+                self.pos += len;
+                JSDocTypeKind::NumberLiteral(value)
+            }
+            _ => {
+                let name = self.parse_name(true)?;
+                if name == "function" && self.eat("(") {
+                    return self.parse_function(start);
+                }
+                JSDocTypeKind::Name(name)
+            }
+        };
+        Some(self.new_type(kind, start))
+    }
+
+    fn parse_name(&mut self, allow_separators: bool) -> Option<&'a str> {
+        let rest = self.rest();
+        let mut len = 0;
+        for (idx, ch) in rest.char_indices() {
+            let is_name_char = ch.is_alphanumeric() || matches!(ch, '_' | '$' | '#' | '~' | '/');
+            // `.` and `:` join the parts of a namepath, e.g. `module:foo.Bar`, but `.<` starts
+            // type arguments and `a: string` is a record field.
+            let is_separator = allow_separators
+                && matches!(ch, '.' | ':')
+                && rest[idx + 1..]
+                    .starts_with(|c: char| c.is_alphanumeric() || matches!(c, '_' | '$'));
+            if !(is_name_char || (is_separator && idx > 0)) {
+                break;
+            }
+            len = idx + ch.len_utf8();
+        }
+        if len == 0 {
+            return None;
+        }
+        self.pos += len;
+        Some(&rest[..len])
+    }
+
+    fn parse_function(&mut self, start: usize) -> Option<JSDocType<'a>> {
+        let mut params = vec![];
+        if !self.eat(")") {
+            loop {
+                // `function(this:Foo)` and `function(new:Foo)`
+                let checkpoint = self.pos;
+                self.skip_whitespace();
+                if !(self.eat("this") || self.eat("new")) || !self.eat(":") {
+                    self.pos = checkpoint;
+                }
+                params.push(self.parse_type()?);
+                if self.eat(")") {
+                    break;
+                }
+                self.expect(",")?;
+            }
+        }
+        let return_type = if self.eat(":") { Some(Box::new(self.parse_prefix()?)) } else { None };
+        Some(self.new_type(JSDocTypeKind::Function { params, return_type }, start))
+    }
+
+    fn parse_record_fields(&mut self) -> Option<Vec<JSDocRecordField<'a>>> {
+        let mut fields = vec![];
+        loop {
+            if self.eat("}") {
+                return Some(fields);
+            }
+            self.skip_whitespace();
+            let start = self.pos;
+            let key = match self.peek()? {
+                quote @ ('\'' | '"') => {
+                    let end = self.rest()[1..].find(quote)? + 1;
+                    let key = &self.rest()[1..end];
+                    self.pos += end + 1;
+                    key
+                }
+                _ => self.parse_name(false)?,
+            };
+            let value = if self.eat(":") { Some(self.parse_type()?) } else { None };
+            fields.push(JSDocRecordField { key, value, span: self.span(start) });
+            // Both `,` and `;` separate fields, e.g. `{ a: string; b: number }`.
+            if !self.eat(",") && !self.eat(";") {
+                self.expect("}")?;
+                return Some(fields);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::Span;
+
+    use super::{JSDocType, JSDocTypeKind};
+
+    fn to_string(ty: &JSDocType) -> String {
+        let list = |types: &[JSDocType]| types.iter().map(to_string).collect::<Vec<_>>().join(", ");
+        match &ty.kind {
+            JSDocTypeKind::Any => "*".to_string(),
+            JSDocTypeKind::Unknown => "?".to_string(),
+            JSDocTypeKind::Name(name) | JSDocTypeKind::NumberLiteral(name) => (*name).to_string(),
+            JSDocTypeKind::StringLiteral(value) => format!("'{value}'"),
+            JSDocTypeKind::Generic { base, arguments } => {
+                format!("{}<{}>", to_string(base), list(arguments))
+            }
+            JSDocTypeKind::Array(ty) => format!("Array<{}>", to_string(ty)),
+            JSDocTypeKind::Union(types) => {
+                format!("({})", types.iter().map(to_string).collect::<Vec<_>>().join(" | "))
+            }
+            JSDocTypeKind::Nullable(ty) => format!("Nullable<{}>", to_string(ty)),
+            JSDocTypeKind::NonNullable(ty) => format!("NonNullable<{}>", to_string(ty)),
+            JSDocTypeKind::Optional(ty) => format!("Optional<{}>", to_string(ty)),
+            JSDocTypeKind::Rest(ty) => format!("Rest<{}>", to_string(ty)),
+            JSDocTypeKind::Function { params, return_type } => format!(
+                "function({}){}",
+                list(params),
+                return_type.as_ref().map(|ty| format!(": {}", to_string(ty))).unwrap_or_default()
+            ),
+            JSDocTypeKind::Record(fields) => format!(
+                "{{{}}}",
+                fields
+                    .iter()
+                    .map(|field| match &field.value {
+                        Some(value) => format!("{}: {}", field.key, to_string(value)),
+                        None => field.key.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            JSDocTypeKind::Tuple(types) => format!("[{}]", list(types)),
+        }
+    }
+
+    #[test]
+    fn parse_types() {
+        for (source_text, expect) in [
+            ("string", "string"),
+            (" Foo.Bar ", "Foo.Bar"),
+            ("module:foo/bar.Baz", "module:foo/bar.Baz"),
+            ("*", "*"),
+            ("?", "?"),
+            ("'a' | \"b\" | 1", "('a' | 'b' | 1)"),
+            ("Array.<string>", "Array<string>"),
+            ("Object<string, number[]>", "Object<string, Array<number>>"),
+            ("string[][]", "Array<Array<string>>"),
+            ("?number", "Nullable<number>"),
+            ("number?", "Nullable<number>"),
+            ("!Object", "NonNullable<Object>"),
+            ("number=", "Optional<number>"),
+            ("...number", "Rest<number>"),
+            ("(string | number)[]", "Array<(string | number)>"),
+            ("function(string, ?): boolean", "function(string, ?): boolean"),
+            ("function(this:Foo, ...*)", "function(Foo, Rest<*>)"),
+            ("{a: string, 'b': ?number, c}", "{a: string, b: Nullable<number>, c}"),
+            ("{a: string; b: number}", "{a: string, b: number}"),
+            ("{a:string}", "{a: string}"),
+            ("[string, number]", "[string, number]"),
+            ("Map<string, ?>", "Map<string, ?>"),
+            ("{\n * a: string,\n * b: number\n * }", "{a: string, b: number}"),
+        ] {
+            let ty = JSDocType::parse(source_text, 0)
+                .unwrap_or_else(|| panic!("failed to parse {source_text:?}"));
+            assert_eq!(to_string(&ty), expect, "{source_text:?}");
+        }
+    }
+
+    #[test]
+    fn parse_invalid_types() {
+        for source_text in ["", "Array<string", "string)", "{a: }", "a |", "function(", "'a"] {
+            assert_eq!(JSDocType::parse(source_text, 0), None, "{source_text:?}");
+        }
+    }
+
+    #[test]
+    fn type_spans() {
+        let source_text = "Array<Foo | bar.Baz>";
+        let ty = JSDocType::parse(source_text, 10).unwrap();
+        assert_eq!(ty.span, Span::new(10, 30));
+        let JSDocTypeKind::Generic { arguments, .. } = &ty.kind else { unreachable!() };
+        assert_eq!(arguments[0].span, Span::new(16, 29));
+        let JSDocTypeKind::Union(types) = &arguments[0].kind else { unreachable!() };
+        assert_eq!(types[1].span, Span::new(22, 29));
+        assert_eq!(ty.names(), vec!["Array", "Foo", "bar.Baz"]);
+    }
+}
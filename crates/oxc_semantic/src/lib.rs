@@ -9,7 +9,8 @@ use std::ops::RangeBounds;
 use std::sync::Arc;
 
 use oxc_ast::{
-    ast::IdentifierReference, comments_range, has_comments_between, AstKind, Comment, CommentsRange,
+    ast::IdentifierReference, comments_range, has_comments_between, leading_comments,
+    trailing_comments, AstKind, Comment, CommentsRange,
 };
 use oxc_cfg::ControlFlowGraph;
 use oxc_span::{GetSpan, SourceType, Span};
@@ -21,29 +22,46 @@ pub use oxc_syntax::{
 
 pub mod dot;
 
+mod accessor_pairs;
 mod binder;
 mod builder;
 mod checker;
 mod class;
+mod dataflow;
 mod diagnostics;
+mod immutability;
 mod jsdoc;
 mod label;
 mod module_record;
 mod node;
+mod project;
 mod reference;
+mod rename;
 mod scope;
 mod stats;
 mod symbol;
+mod type_inference;
 mod unresolved_stack;
+mod unused_imports;
 
 pub use crate::{
+    accessor_pairs::{Accessor, AccessorKind, AccessorPair, AccessorPairs},
     builder::{SemanticBuilder, SemanticBuilderReturn},
-    jsdoc::{JSDoc, JSDocFinder, JSDocTag},
+    dataflow::{Definition, SymbolDataflow},
+    immutability::{Immutability, ImmutableObject, ObjectImmutability},
+    jsdoc::{JSDoc, JSDocFinder, JSDocRecordField, JSDocTag, JSDocType, JSDocTypeKind},
     node::{AstNode, AstNodes, NodeId},
+    project::{
+        BindingOrigin, ExportResolution, ProjectSemantic, ResolvedExport, UnusedExport,
+        UnusedExports, UnusedExportsOptions,
+    },
     reference::{Reference, ReferenceFlags, ReferenceId},
+    rename::{RenameEdit, RenameError, SymbolRename},
     scope::ScopeTree,
     stats::Stats,
-    symbol::{IsGlobalReference, SymbolTable},
+    symbol::{IsGlobalReference, ReexportSource, SymbolDeclarationKind, SymbolExport, SymbolTable},
+    type_inference::{Type, TypeInference},
+    unused_imports::{import_specifier_removal_span, UnusedImport, UnusedImports},
 };
 use class::ClassTable;
 
@@ -151,6 +169,16 @@ impl<'a> Semantic<'a> {
         has_comments_between(self.comments, span)
     }
 
+    /// Comments before the node at `span` which are attached to it, including its JSDoc.
+    pub fn leading_comments(&self, span: Span) -> &[Comment] {
+        leading_comments(self.comments, span)
+    }
+
+    /// Comments after the node at `span` until the end of the line.
+    pub fn trailing_comments(&self, span: Span) -> &[Comment] {
+        trailing_comments(self.comments, self.source_text, span)
+    }
+
     pub fn irregular_whitespaces(&self) -> &[Span] {
         &self.irregular_whitespaces
     }
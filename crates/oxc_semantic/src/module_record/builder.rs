@@ -4,6 +4,9 @@ use oxc_ast::ast::*;
 use oxc_ecmascript::BoundNames;
 use oxc_span::{CompactStr, GetSpan, Span};
 use oxc_syntax::module_record::*;
+use rustc_hash::FxHashMap;
+
+use crate::jsdoc::JSDocBuilder;
 
 #[derive(Default)]
 pub struct ModuleRecordBuilder {
@@ -30,6 +33,10 @@ impl ModuleRecordBuilder {
         // The `ParseModule` algorithm requires `importedBoundNames` (import entries) to be
         // resolved before resolving export entries.
         self.resolve_export_entries();
+
+        if !self.module_record.not_esm {
+            self.visit_deprecations(program);
+        }
     }
 
     pub fn build(self) -> ModuleRecord {
@@ -121,10 +128,10 @@ impl ModuleRecordBuilder {
                                         }
                                         // `import d from "mod"`
                                         // `export { d }`
-                                        //           ^ this is local_name of ie
-                                        ImportImportName::Default(_) => {
-                                            ExportImportName::Name(ie.local_name.clone())
-                                        }
+                                        // re-exports the `default` export of "mod"
+                                        ImportImportName::Default(span) => ExportImportName::Name(
+                                            NameSpan::new(CompactStr::new("default"), *span),
+                                        ),
                                         ImportImportName::NamespaceObject => unreachable!(),
                                     },
                                     export_name: ee.export_name.clone(),
@@ -149,6 +156,88 @@ impl ModuleRecordBuilder {
         }
     }
 
+    /// Find the deprecated module and exports from the `@deprecated` tags of JSDoc comments.
+    fn visit_deprecations(&mut self, program: &Program) {
+        let source_text = program.source_text;
+        // Deprecation messages, keyed by the start of the node the JSDoc comment is attached to.
+        let mut deprecations = FxHashMap::default();
+        let comments = program
+            .comments
+            .iter()
+            .filter(|comment| comment.is_leading() && comment.is_jsdoc(source_text));
+        for comment in comments {
+            let jsdoc = JSDocBuilder::parse_jsdoc_comment(comment, source_text);
+            let message = jsdoc
+                .tags_by_kind("deprecated")
+                .next()
+                .map(|tag| CompactStr::from(tag.comment().parsed()));
+            if jsdoc.tags_by_kind("module").next().is_some() {
+                if self.module_record.deprecated.is_none() {
+                    self.module_record.deprecated = message;
+                }
+                continue;
+            }
+            // A node is documented by the closest JSDoc comment before it.
+            match message {
+                Some(message) => deprecations.insert(comment.attached_to, message),
+                None => deprecations.remove(&comment.attached_to),
+            };
+        }
+        if deprecations.is_empty() {
+            return;
+        }
+
+        // Deprecated bindings in the root scope, keyed by name.
+        let mut bindings = FxHashMap::default();
+        for stmt in &program.body {
+            let declaration = match stmt {
+                Statement::ExportNamedDeclaration(decl) => decl.declaration.as_ref(),
+                Statement::ExportDefaultDeclaration(decl) => {
+                    if let Some(message) = deprecations.get(&decl.span.start) {
+                        self.module_record
+                            .deprecated_exports
+                            .insert(CompactStr::new("default"), message.clone());
+                    }
+                    continue;
+                }
+                stmt => stmt.as_declaration(),
+            };
+            let Some(declaration) = declaration else {
+                continue;
+            };
+            let message = deprecations
+                .get(&stmt.span().start)
+                .or_else(|| deprecations.get(&declaration.span().start));
+            // Each declarator of `const a = 1, b = 2` can have its own JSDoc comment.
+            if let Declaration::VariableDeclaration(decl) = declaration {
+                for declarator in &decl.declarations {
+                    let Some(message) = deprecations.get(&declarator.span.start).or(message) else {
+                        continue;
+                    };
+                    declarator.id.bound_names(&mut |ident| {
+                        bindings.insert(ident.name.to_compact_str(), message.clone());
+                    });
+                }
+            } else if let Some(message) = message {
+                declaration.bound_names(&mut |ident| {
+                    bindings.insert(ident.name.to_compact_str(), message.clone());
+                });
+            }
+        }
+
+        for entry in &self.module_record.local_export_entries {
+            let Some(message) = entry.local_name.name().and_then(|name| bindings.get(name)) else {
+                continue;
+            };
+            let export_name = match &entry.export_name {
+                ExportExportName::Name(name) => name.name().clone(),
+                ExportExportName::Default(_) => CompactStr::new("default"),
+                ExportExportName::Null => continue,
+            };
+            self.module_record.deprecated_exports.insert(export_name, message.clone());
+        }
+    }
+
     pub fn visit_module_declaration(&mut self, module_decl: &ModuleDeclaration) {
         match module_decl {
             ModuleDeclaration::ImportDeclaration(import_decl) => {
@@ -275,4 +275,67 @@ mod module_record_tests {
             }
         );
     }
+
+    #[test]
+    fn indirect_export_entries_default_import() {
+        let module_record = build("import d from 'mod';export { d };");
+        assert_eq!(module_record.indirect_export_entries.len(), 1);
+        assert_eq!(
+            module_record.indirect_export_entries[0],
+            ExportEntry {
+                module_request: Some(NameSpan::new("mod".into(), Span::new(14, 19))),
+                span: Span::new(29, 30),
+                import_name: ExportImportName::Name(NameSpan::new(
+                    "default".into(),
+                    Span::new(7, 8)
+                )),
+                export_name: ExportExportName::Name(NameSpan::new("d".into(), Span::new(29, 30))),
+                local_name: ExportLocalName::Null,
+            }
+        );
+    }
+
+    #[test]
+    fn deprecated_exports() {
+        let module_record = build(
+            "
+/** @deprecated use `b` */
+export function a() {}
+/** not deprecated */
+export function b() {}
+/** @deprecated */
+const c = 1,
+    /** @deprecated use `b` */ d = 2;
+export { c, d as e };
+/** @deprecated old default */
+export default class {}
+",
+        );
+        assert_eq!(module_record.deprecated, None);
+        let mut deprecated_exports = module_record
+            .deprecated_exports
+            .iter()
+            .map(|(name, message)| (name.as_str(), message.as_str()))
+            .collect::<Vec<_>>();
+        deprecated_exports.sort_unstable();
+        assert_eq!(
+            deprecated_exports,
+            [("a", "use `b`"), ("c", ""), ("default", "old default"), ("e", "use `b`")]
+        );
+    }
+
+    #[test]
+    fn deprecated_module() {
+        let module_record = build(
+            "
+/**
+ * @module old
+ * @deprecated use `new` instead
+ */
+export const a = 1;
+",
+        );
+        assert_eq!(module_record.deprecated.as_deref(), Some("use `new` instead"));
+        assert!(module_record.deprecated_exports.is_empty());
+    }
 }
@@ -1,6 +1,6 @@
 use oxc_ast::AstKind;
 use oxc_cfg::BlockNodeId;
-use oxc_index::IndexVec;
+use oxc_index::{Idx, IndexVec};
 use oxc_span::GetSpan;
 pub use oxc_syntax::node::{NodeFlags, NodeId};
 
@@ -237,6 +237,123 @@ impl<'a> AstNodes<'a> {
         std::iter::successors(Some(node_id), |&node_id| parent_ids[node_id])
     }
 
+    /// Iterate over the children of a node, in the order they were visited.
+    pub fn child_ids(&self, node_id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        // Nodes are added in pre-order, so the descendants of a node directly follow it and are
+        // the only following nodes whose parents are not before it.
+        self.parent_ids
+            .iter_enumerated()
+            .skip(node_id.index() + 1)
+            .take_while(move |(_, parent_id)| **parent_id >= Some(node_id))
+            .filter_map(move |(id, parent_id)| (*parent_id == Some(node_id)).then_some(id))
+    }
+
+    /// Iterate over the other children of the parent of a node, in the order they were visited.
+    ///
+    /// The iterator is empty for the root node.
+    pub fn sibling_ids(&self, node_id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.parent_id(node_id)
+            .into_iter()
+            .flat_map(|parent_id| self.child_ids(parent_id))
+            .filter(move |&id| id != node_id)
+    }
+
+    /// Get the nearest [`Function`] or [`ArrowFunctionExpression`] which contains a node,
+    /// excluding the node itself.
+    ///
+    /// [`Function`]: oxc_ast::ast::Function
+    /// [`ArrowFunctionExpression`]: oxc_ast::ast::ArrowFunctionExpression
+    pub fn enclosing_function(&self, node_id: NodeId) -> Option<&AstNode<'a>> {
+        self.ancestors(node_id).skip(1).find(|node| node.kind().is_function_like())
+    }
+
+    /// Get the nearest [`Class`] which contains a node, excluding the node itself.
+    ///
+    /// [`Class`]: oxc_ast::ast::Class
+    pub fn enclosing_class(&self, node_id: NodeId) -> Option<&AstNode<'a>> {
+        self.ancestors(node_id).skip(1).find(|node| matches!(node.kind(), AstKind::Class(_)))
+    }
+
+    /// Get the nearest statement or declaration which contains a node, excluding the node
+    /// itself.
+    ///
+    /// The expression statement wrapping the body of an arrow function like `() => value` is not
+    /// in the source, so it is skipped.
+    pub fn enclosing_statement(&self, node_id: NodeId) -> Option<&AstNode<'a>> {
+        self.ancestors(node_id).skip(1).find(|node| {
+            let kind = node.kind();
+            if matches!(kind, AstKind::ExpressionStatement(_))
+                && self.is_arrow_expression_body(node.id())
+            {
+                return false;
+            }
+            kind.is_statement()
+                || (kind.is_declaration() && !matches!(kind, AstKind::PropertyDefinition(_)))
+        })
+    }
+
+    fn is_arrow_expression_body(&self, node_id: NodeId) -> bool {
+        self.parent_id(node_id).and_then(|body_id| self.parent_kind(body_id)).is_some_and(
+            |kind| matches!(kind, AstKind::ArrowFunctionExpression(arrow) if arrow.expression),
+        )
+    }
+
+    /// Get the statement which a `break` or `continue` statement exits or continues.
+    ///
+    /// An unlabeled `break` targets the nearest loop or `switch`, and an unlabeled `continue` the
+    /// nearest loop. A labeled jump targets the body of the matching [`LabeledStatement`], with
+    /// any nested labels skipped, e.g. the `for` in `a: b: for (;;) break a`.
+    ///
+    /// Returns `None` if the node is not a `break` or `continue`, or it has no target.
+    ///
+    /// [`LabeledStatement`]: oxc_ast::ast::LabeledStatement
+    pub fn jump_target(&self, node_id: NodeId) -> Option<&AstNode<'a>> {
+        let (label, is_continue) = match self.kind(node_id) {
+            AstKind::BreakStatement(stmt) => (stmt.label.as_ref(), false),
+            AstKind::ContinueStatement(stmt) => (stmt.label.as_ref(), true),
+            _ => return None,
+        };
+        self.resolve_jump(node_id, label.map(|label| label.name.as_str()), is_continue)
+    }
+
+    /// Get the statement which a `break`, or a `continue` if `is_continue`, with an optional
+    /// `label` would target if it was at `node_id`. See [`AstNodes::jump_target`].
+    ///
+    /// Useful to check whether a label is needed, e.g. whether `break a` and `break` target the
+    /// same statement.
+    pub fn resolve_jump(
+        &self,
+        node_id: NodeId,
+        label: Option<&str>,
+        is_continue: bool,
+    ) -> Option<&AstNode<'a>> {
+        // The innermost statement below the current ancestor which is not a label.
+        let mut body = self.get_node(node_id);
+        for node in self.ancestors(node_id).skip(1) {
+            let kind = node.kind();
+            match kind {
+                // Jumps do not cross functions and static blocks.
+                _ if kind.is_function_like() => return None,
+                AstKind::StaticBlock(_) | AstKind::Program(_) => return None,
+                AstKind::LabeledStatement(stmt) => {
+                    if label == Some(stmt.label.name.as_str()) {
+                        return Some(body);
+                    }
+                    continue;
+                }
+                _ if label.is_none()
+                    && (kind.is_iteration_statement()
+                        || (!is_continue && matches!(kind, AstKind::SwitchStatement(_)))) =>
+                {
+                    return Some(node);
+                }
+                _ => {}
+            }
+            body = node;
+        }
+        None
+    }
+
     /// Create and add an [`AstNode`] to the [`AstNodes`] tree and get its [`NodeId`].
     /// Node must not be [`Program`]; if it is, use [`add_program_node`] instead.
     ///
@@ -0,0 +1,332 @@
+//! Cross-file semantic analysis.
+//!
+//! [`ProjectSemantic`] links the [`Semantic`] of every file of a project, and follows imports and
+//! re-exports across files to the declarations they refer to.
+//!
+//! Oxc does not resolve module specifiers itself. The module graph is read from
+//! [`ModuleRecord::loaded_modules`], which the caller fills in with the resolved modules, e.g.
+//! with `oxc_resolver`.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_span::{CompactStr, Span};
+use oxc_syntax::{
+    module_record::{
+        ExportExportName, ExportImportName, ExportLocalName, ImportEntry, ImportImportName,
+        ModuleRecord,
+    },
+    symbol::SymbolId,
+};
+use rustc_hash::FxHashMap;
+
+use crate::Semantic;
+
+mod unused_exports;
+
+pub use unused_exports::{UnusedExport, UnusedExports, UnusedExportsOptions};
+
+/// The binding an exported name of a module refers to.
+///
+/// See [`ProjectSemantic::resolve_export`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedExport {
+    /// A binding in the root scope of the module at `path`, e.g. `foo` in
+    /// `export function foo() {}`.
+    Binding { path: PathBuf, name: CompactStr },
+    /// The namespace object of the module at `path`, e.g. `export * as ns from './mod'`.
+    Namespace { path: PathBuf },
+    /// A default export without a name, e.g. `export default 1`. `span` is the span of the
+    /// exported expression or declaration.
+    AnonymousDefault { path: PathBuf, span: Span },
+}
+
+/// The result of [`ProjectSemantic::resolve_export`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportResolution {
+    /// The name refers to a binding.
+    Resolved(ResolvedExport),
+    /// The module does not export the name, or re-exports it in a cycle.
+    NotFound,
+    /// The name is exported by more than one `export * from` with different bindings.
+    Ambiguous,
+    /// A module on the way is unknown, because its specifier was not resolved or it is not an
+    /// ES module.
+    Unknown,
+}
+
+/// Where a binding is defined. See [`ProjectSemantic::definition`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindingOrigin {
+    /// A symbol declared in the file at `path`.
+    Symbol { path: PathBuf, symbol_id: SymbolId },
+    /// The namespace object of the module at `path`, e.g. `import * as ns from './mod'`.
+    Namespace { path: PathBuf },
+    /// A default export without a name, see [`ResolvedExport::AnonymousDefault`].
+    AnonymousDefault { path: PathBuf, span: Span },
+}
+
+/// Semantic analysis of the files of a project, linked by their imports and exports.
+///
+/// Files are keyed by [`ModuleRecord::resolved_absolute_path`], so build their [`Semantic`]
+/// with [`SemanticBuilder::build_module_record`].
+///
+/// [`SemanticBuilder::build_module_record`]: crate::SemanticBuilder::build_module_record
+#[derive(Default)]
+pub struct ProjectSemantic<'s, 'a> {
+    modules: FxHashMap<PathBuf, &'s Semantic<'a>>,
+}
+
+impl<'s, 'a> ProjectSemantic<'s, 'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file to the project.
+    pub fn add(&mut self, semantic: &'s Semantic<'a>) {
+        let path = semantic.module_record().resolved_absolute_path.clone();
+        self.modules.insert(path, semantic);
+    }
+
+    /// Get the [`Semantic`] of the file at `path`.
+    pub fn get(&self, path: &Path) -> Option<&'s Semantic<'a>> {
+        self.modules.get(path).copied()
+    }
+
+    /// Resolve `export_name` exported by the file at `path`, following re-exports.
+    ///
+    /// See <https://tc39.es/ecma262/#sec-resolveexport>.
+    pub fn resolve_export(&self, path: &Path, export_name: &str) -> ExportResolution {
+        match self.get(path) {
+            Some(semantic) => resolve_export(semantic.module_record(), export_name, &mut vec![]),
+            None => ExportResolution::Unknown,
+        }
+    }
+
+    /// Resolve an import binding of the file at `path`, e.g. `foo` in
+    /// `import { foo } from './foo'`, to the binding exported by the imported module.
+    ///
+    /// [`None`] if the symbol is not declared by an import declaration.
+    pub fn resolve_import(&self, path: &Path, symbol_id: SymbolId) -> Option<ExportResolution> {
+        let semantic = self.get(path)?;
+        let symbols = semantic.symbols();
+        if !symbols.get_flags(symbol_id).is_import() {
+            return None;
+        }
+        let name = symbols.get_name(symbol_id);
+        let module_record = semantic.module_record();
+        let entry =
+            module_record.import_entries.iter().find(|entry| entry.local_name.name() == name)?;
+        Some(resolve_import_entry(module_record, entry))
+    }
+
+    /// Find where a symbol of the file at `path` is defined, following imports across files.
+    ///
+    /// Symbols which are not imported are defined where they are declared. [`None`] if an import
+    /// can not be resolved, see [`ProjectSemantic::resolve_import`].
+    pub fn definition(&self, path: &Path, symbol_id: SymbolId) -> Option<BindingOrigin> {
+        let mut path = path.to_path_buf();
+        let mut symbol_id = symbol_id;
+        let mut visited = vec![];
+        loop {
+            let Some(resolution) = self.resolve_import(&path, symbol_id) else {
+                return Some(BindingOrigin::Symbol { path, symbol_id });
+            };
+            if visited.contains(&(path.clone(), symbol_id)) {
+                return None;
+            }
+            visited.push((path, symbol_id));
+            let ExportResolution::Resolved(resolved) = resolution else {
+                return None;
+            };
+            match resolved {
+                // The binding can be an import again, e.g. `ns` in
+                // `import * as ns from './ns'; export { ns }`, or `a` in
+                // `import { a } from './a'; export default a`.
+                ResolvedExport::Binding { path: binding_path, name } => {
+                    symbol_id = self.get(&binding_path)?.scopes().get_root_binding(&name)?;
+                    path = binding_path;
+                }
+                ResolvedExport::Namespace { path } => {
+                    return Some(BindingOrigin::Namespace { path })
+                }
+                ResolvedExport::AnonymousDefault { path, span } => {
+                    return Some(BindingOrigin::AnonymousDefault { path, span });
+                }
+            }
+        }
+    }
+
+    /// Get the names exported by the file at `path`, including the names re-exported by
+    /// `export * from`.
+    ///
+    /// See <https://tc39.es/ecma262/#sec-getexportednames>.
+    pub fn exported_names(&self, path: &Path) -> Vec<CompactStr> {
+        let mut names = vec![];
+        if let Some(semantic) = self.get(path) {
+            exported_names(semantic.module_record(), &mut vec![], &mut names);
+        }
+        names
+    }
+}
+
+fn loaded_module(module_record: &ModuleRecord, specifier: &str) -> Option<Arc<ModuleRecord>> {
+    module_record.loaded_modules.get(specifier).map(|module| Arc::clone(module.value()))
+}
+
+fn export_name_matches(export_name: &ExportExportName, name: &str) -> bool {
+    match export_name {
+        ExportExportName::Name(export_name) => export_name.name() == name,
+        ExportExportName::Default(_) => name == "default",
+        ExportExportName::Null => false,
+    }
+}
+
+fn resolve_import_entry(module_record: &ModuleRecord, entry: &ImportEntry) -> ExportResolution {
+    let Some(imported) = loaded_module(module_record, entry.module_request.name()) else {
+        return ExportResolution::Unknown;
+    };
+    match &entry.import_name {
+        ImportImportName::NamespaceObject => {
+            ExportResolution::Resolved(ResolvedExport::Namespace {
+                path: imported.resolved_absolute_path.clone(),
+            })
+        }
+        ImportImportName::Name(name) => resolve_export(&imported, name.name(), &mut vec![]),
+        ImportImportName::Default(_) => resolve_export(&imported, "default", &mut vec![]),
+    }
+}
+
+fn resolve_export(
+    module_record: &ModuleRecord,
+    export_name: &str,
+    resolve_set: &mut Vec<(PathBuf, CompactStr)>,
+) -> ExportResolution {
+    if module_record.not_esm {
+        return ExportResolution::Unknown;
+    }
+    let path = &module_record.resolved_absolute_path;
+
+    // 1-2. A circular import request.
+    if resolve_set.iter().any(|(p, name)| p == path && name == export_name) {
+        return ExportResolution::NotFound;
+    }
+    resolve_set.push((path.clone(), CompactStr::from(export_name)));
+
+    // 3. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+    for entry in &module_record.local_export_entries {
+        if !export_name_matches(&entry.export_name, export_name) {
+            continue;
+        }
+        let path = path.clone();
+        return ExportResolution::Resolved(match &entry.local_name {
+            ExportLocalName::Name(name) | ExportLocalName::Default(name) => {
+                ResolvedExport::Binding { path, name: name.name().clone() }
+            }
+            ExportLocalName::Null => ResolvedExport::AnonymousDefault { path, span: entry.span },
+        });
+    }
+
+    // 4. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+    for entry in &module_record.indirect_export_entries {
+        if !export_name_matches(&entry.export_name, export_name) {
+            continue;
+        }
+        let Some(imported) = entry
+            .module_request
+            .as_ref()
+            .and_then(|request| loaded_module(module_record, request.name()))
+        else {
+            return ExportResolution::Unknown;
+        };
+        return match &entry.import_name {
+            // `export * as ns from 'mod'`
+            ExportImportName::All => ExportResolution::Resolved(ResolvedExport::Namespace {
+                path: imported.resolved_absolute_path.clone(),
+            }),
+            ExportImportName::Name(name) => resolve_export(&imported, name.name(), resolve_set),
+            ExportImportName::AllButDefault | ExportImportName::Null => ExportResolution::NotFound,
+        };
+    }
+
+    // 5. A default export cannot be provided by export * from "mod".
+    if export_name == "default" {
+        return ExportResolution::NotFound;
+    }
+
+    // 6-7. For each ExportEntry Record e of module.[[StarExportEntries]], do
+    let mut star_resolution = None;
+    let mut unknown = false;
+    for entry in &module_record.star_export_entries {
+        let Some(imported) = entry
+            .module_request
+            .as_ref()
+            .and_then(|request| loaded_module(module_record, request.name()))
+        else {
+            unknown = true;
+            continue;
+        };
+        match resolve_export(&imported, export_name, resolve_set) {
+            ExportResolution::Resolved(resolution) => match &star_resolution {
+                None => star_resolution = Some(resolution),
+                Some(star_resolution) if *star_resolution != resolution => {
+                    return ExportResolution::Ambiguous;
+                }
+                Some(_) => {}
+            },
+            ExportResolution::Ambiguous => return ExportResolution::Ambiguous,
+            ExportResolution::Unknown => unknown = true,
+            ExportResolution::NotFound => {}
+        }
+    }
+
+    match star_resolution {
+        Some(resolution) => ExportResolution::Resolved(resolution),
+        None if unknown => ExportResolution::Unknown,
+        None => ExportResolution::NotFound,
+    }
+}
+
+fn exported_names(
+    module_record: &ModuleRecord,
+    export_star_set: &mut Vec<PathBuf>,
+    names: &mut Vec<CompactStr>,
+) {
+    // 2. Circular `export *`.
+    if export_star_set.contains(&module_record.resolved_absolute_path) {
+        return;
+    }
+    export_star_set.push(module_record.resolved_absolute_path.clone());
+
+    let entries =
+        module_record.local_export_entries.iter().chain(&module_record.indirect_export_entries);
+    for entry in entries {
+        let name = match &entry.export_name {
+            ExportExportName::Name(name) => name.name().clone(),
+            ExportExportName::Default(_) => CompactStr::new("default"),
+            ExportExportName::Null => continue,
+        };
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    for entry in &module_record.star_export_entries {
+        let Some(imported) = entry
+            .module_request
+            .as_ref()
+            .and_then(|request| loaded_module(module_record, request.name()))
+        else {
+            continue;
+        };
+        let mut star_names = vec![];
+        exported_names(&imported, export_star_set, &mut star_names);
+        for name in star_names {
+            if name.as_str() != "default" && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Exports which are not imported by any file of a project.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_span::{CompactStr, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ImportImportName, ModuleRecord,
+};
+use rustc_hash::FxHashSet;
+
+use super::{export_name_matches, exported_names, loaded_module, ProjectSemantic};
+
+/// Options for [`ProjectSemantic::unused_exports`].
+#[derive(Default)]
+pub struct UnusedExportsOptions {
+    /// Files whose exports are the public API of the project, e.g. `main` and `exports` of
+    /// `package.json`.
+    ///
+    /// Their exports, including what they re-export, are always used. Files which can not be
+    /// reached from them are reported in [`UnusedExports::files`]; without entry points no file is
+    /// reported.
+    pub entry_points: Vec<PathBuf>,
+
+    /// Whether importing a file has side effects, like `sideEffects` of `package.json`.
+    ///
+    /// A file without side effects which is only imported for side effects, e.g.
+    /// `import './polyfill'`, is unused, as bundlers drop the import.
+    ///
+    /// Default is `None`, every file has side effects.
+    pub side_effects: Option<Arc<dyn Fn(&Path) -> bool + Send + Sync>>,
+}
+
+impl fmt::Debug for UnusedExportsOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnusedExportsOptions")
+            .field("entry_points", &self.entry_points)
+            .field("side_effects", &self.side_effects.as_ref().map(|_| "Fn"))
+            .finish()
+    }
+}
+
+impl UnusedExportsOptions {
+    fn has_side_effects(&self, path: &Path) -> bool {
+        self.side_effects.as_ref().map_or(true, |side_effects| side_effects(path))
+    }
+}
+
+/// An export which is not imported by any file of the project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnusedExport {
+    pub path: PathBuf,
+    /// The exported name, `default` for default exports.
+    pub name: CompactStr,
+    /// Span of the exported name, or of `default` in `export default`.
+    pub span: Span,
+}
+
+/// The result of [`ProjectSemantic::unused_exports`], sorted by path.
+#[derive(Debug, Default)]
+pub struct UnusedExports {
+    pub exports: Vec<UnusedExport>,
+    /// Files which can not be reached from [`UnusedExportsOptions::entry_points`].
+    pub files: Vec<PathBuf>,
+}
+
+impl<'s, 'a> ProjectSemantic<'s, 'a> {
+    /// Find the exports which are not imported by any file of the project, like `knip` or
+    /// `ts-prune`.
+    ///
+    /// An export is used if it is imported by name, or through a namespace import
+    /// (`import * as ns`) of its module. Re-exports are followed, so
+    /// `export { foo } from './foo'` uses `foo` of `./foo` only if it is used itself.
+    /// Dynamic imports and CommonJS are not analyzed.
+    pub fn unused_exports(&self, options: &UnusedExportsOptions) -> UnusedExports {
+        let mut used = UsedExports::default();
+
+        for path in &options.entry_points {
+            if let Some(semantic) = self.get(path) {
+                used.use_all(semantic.module_record());
+            }
+        }
+        for semantic in self.modules.values() {
+            let module_record = semantic.module_record();
+            for entry in &module_record.import_entries {
+                let Some(imported) = loaded_module(module_record, entry.module_request.name())
+                else {
+                    continue;
+                };
+                match &entry.import_name {
+                    ImportImportName::Name(name) => used.use_export(&imported, name.name()),
+                    ImportImportName::Default(_) => used.use_export(&imported, "default"),
+                    ImportImportName::NamespaceObject => used.use_all(&imported),
+                }
+            }
+        }
+
+        let mut paths = self.modules.keys().collect::<Vec<_>>();
+        paths.sort_unstable();
+
+        let mut unused_exports = UnusedExports::default();
+        for path in &paths {
+            let module_record = self.modules[*path].module_record();
+            let entries = module_record
+                .local_export_entries
+                .iter()
+                .chain(&module_record.indirect_export_entries);
+            for entry in entries {
+                let (name, span) = match &entry.export_name {
+                    ExportExportName::Name(name) => (name.name().clone(), name.span()),
+                    ExportExportName::Default(span) => (CompactStr::new("default"), *span),
+                    ExportExportName::Null => continue,
+                };
+                if !used.contains(path, &name) {
+                    unused_exports.exports.push(UnusedExport { path: (*path).clone(), name, span });
+                }
+            }
+        }
+
+        if !options.entry_points.is_empty() {
+            let reachable = self.reachable_files(options);
+            unused_exports.files =
+                paths.into_iter().filter(|path| !reachable.contains(*path)).cloned().collect();
+        }
+
+        unused_exports
+    }
+
+    /// The files imported directly or indirectly by the entry points, including the entry points.
+    fn reachable_files(&self, options: &UnusedExportsOptions) -> FxHashSet<PathBuf> {
+        let mut reachable = FxHashSet::default();
+        let mut stack = options.entry_points.clone();
+        while let Some(path) = stack.pop() {
+            if !reachable.insert(path.clone()) {
+                continue;
+            }
+            let Some(semantic) = self.get(&path) else { continue };
+            let module_record = semantic.module_record();
+            for entry in module_record.loaded_modules.iter() {
+                let (specifier, imported) = entry.pair();
+                let imports_bindings = module_record
+                    .import_entries
+                    .iter()
+                    .any(|entry| entry.module_request.name() == specifier)
+                    || module_record
+                        .indirect_export_entries
+                        .iter()
+                        .chain(&module_record.star_export_entries)
+                        .any(|entry| {
+                            entry
+                                .module_request
+                                .as_ref()
+                                .is_some_and(|request| request.name() == specifier)
+                        });
+                if imports_bindings || options.has_side_effects(&imported.resolved_absolute_path) {
+                    stack.push(imported.resolved_absolute_path.clone());
+                }
+            }
+        }
+        reachable
+    }
+}
+
+/// Exported names which are used, keyed by the path of their module.
+#[derive(Default)]
+struct UsedExports {
+    used: FxHashSet<(PathBuf, CompactStr)>,
+}
+
+impl UsedExports {
+    fn contains(&self, path: &Path, name: &str) -> bool {
+        self.used.contains(&(path.to_path_buf(), CompactStr::from(name)))
+    }
+
+    /// Use all names exported by a module, e.g. for `import * as ns from './mod'`.
+    fn use_all(&mut self, module_record: &ModuleRecord) {
+        let mut names = vec![];
+        exported_names(module_record, &mut vec![], &mut names);
+        for name in names {
+            self.use_export(module_record, &name);
+        }
+    }
+
+    /// Use an exported name, and the names of other modules it re-exports.
+    fn use_export(&mut self, module_record: &ModuleRecord, name: &str) {
+        let key = (module_record.resolved_absolute_path.clone(), CompactStr::from(name));
+        if !self.used.insert(key) {
+            return;
+        }
+
+        if module_record
+            .local_export_entries
+            .iter()
+            .any(|e| export_name_matches(&e.export_name, name))
+        {
+            return;
+        }
+
+        if let Some(entry) = module_record
+            .indirect_export_entries
+            .iter()
+            .find(|entry| export_name_matches(&entry.export_name, name))
+        {
+            let Some(imported) = entry
+                .module_request
+                .as_ref()
+                .and_then(|request| loaded_module(module_record, request.name()))
+            else {
+                return;
+            };
+            match &entry.import_name {
+                ExportImportName::Name(import_name) => {
+                    self.use_export(&imported, import_name.name());
+                }
+                // `export * as ns from './mod'`
+                ExportImportName::All => self.use_all(&imported),
+                ExportImportName::AllButDefault | ExportImportName::Null => {}
+            }
+            return;
+        }
+
+        // `export * from './mod'` does not re-export the default export.
+        if name == "default" {
+            return;
+        }
+        for entry in &module_record.star_export_entries {
+            let Some(imported) = entry
+                .module_request
+                .as_ref()
+                .and_then(|request| loaded_module(module_record, request.name()))
+            else {
+                continue;
+            };
+            let mut names = vec![];
+            exported_names(&imported, &mut vec![], &mut names);
+            if names.iter().any(|exported| exported == name) {
+                self.use_export(&imported, name);
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Renaming a symbol and all of its references, e.g. for editor rename refactorings.
+
+use oxc_ast::{
+    ast::{AssignmentTargetProperty, ObjectPropertyKind},
+    AstKind,
+};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    keyword::is_reserved_keyword,
+    scope::ScopeId,
+    symbol::{SymbolFlags, SymbolId},
+};
+use rustc_hash::FxHashSet;
+
+use crate::Semantic;
+
+/// A text replacement of a [`SymbolRename`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+/// Why a symbol cannot be renamed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameError {
+    /// The new name is not a valid identifier, e.g. `1a` or `class`.
+    InvalidName,
+    /// The symbol is visible to other files: it is imported, exported, or a global of a script.
+    CrossFile,
+    /// The new name would be shadowed by, or shadow, the binding or reference at `span`.
+    Conflict { span: Span },
+}
+
+/// The edits renaming a file-local symbol to a new name.
+///
+/// Renames the declaration, redeclarations and references of the symbol, and expands shorthand
+/// properties, e.g. `({ a })` becomes `({ a: b })`.
+#[derive(Debug)]
+pub struct SymbolRename {
+    pub symbol_id: SymbolId,
+    pub edits: Vec<RenameEdit>,
+}
+
+impl SymbolRename {
+    /// Rename `symbol_id` to `new_name`, failing if the program would change its meaning.
+    ///
+    /// # Errors
+    /// See [`RenameError`].
+    pub fn new(
+        semantic: &Semantic<'_>,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<Self, RenameError> {
+        if !is_identifier_name(new_name) || is_reserved_keyword(new_name) {
+            return Err(RenameError::InvalidName);
+        }
+        if Self::is_cross_file(semantic, symbol_id) {
+            return Err(RenameError::CrossFile);
+        }
+        Self::check_conflicts(semantic, symbol_id, new_name)?;
+
+        let symbols = semantic.symbols();
+        let name = symbols.get_name(symbol_id);
+        let shorthand_spans = Self::shorthand_spans(semantic);
+        let spans = std::iter::once(symbols.get_span(symbol_id))
+            .chain(symbols.get_redeclarations(symbol_id).iter().copied())
+            .chain(
+                symbols
+                    .get_resolved_references(symbol_id)
+                    .map(|reference| semantic.nodes().kind(reference.node_id()).span()),
+            );
+        let mut edits = spans
+            .map(|span| {
+                let new_text = if shorthand_spans.contains(&span) {
+                    format!("{name}: {new_name}")
+                } else {
+                    new_name.to_string()
+                };
+                RenameEdit { span, new_text }
+            })
+            .collect::<Vec<_>>();
+        edits.sort_unstable_by_key(|edit| edit.span.start);
+        edits.dedup_by_key(|edit| edit.span);
+        Ok(Self { symbol_id, edits })
+    }
+
+    /// The symbol declared or referenced by the identifier at `offset`, with the span of that
+    /// identifier. The end of the identifier is included, like a cursor right after it.
+    pub fn symbol_at(semantic: &Semantic<'_>, offset: u32) -> Option<(SymbolId, Span)> {
+        semantic.nodes().iter().find_map(|node| {
+            let (symbol_id, span) = match node.kind() {
+                AstKind::BindingIdentifier(ident) => (ident.symbol_id.get()?, ident.span),
+                AstKind::IdentifierReference(ident) => {
+                    let reference_id = ident.reference_id.get()?;
+                    (semantic.symbols().get_reference(reference_id).symbol_id()?, ident.span)
+                }
+                _ => return None,
+            };
+            (span.start <= offset && offset <= span.end).then_some((symbol_id, span))
+        })
+    }
+
+    /// Whether `symbol_id` is visible to other files, see [`RenameError::CrossFile`].
+    pub fn is_cross_file(semantic: &Semantic<'_>, symbol_id: SymbolId) -> bool {
+        let symbols = semantic.symbols();
+        let scopes = semantic.scopes();
+        symbols.get_flags(symbol_id).intersects(SymbolFlags::Import | SymbolFlags::Export)
+            || !symbols.get_exports(symbol_id).is_empty()
+            || (semantic.source_type().is_script()
+                && symbols.get_scope_id(symbol_id) == scopes.root_scope_id())
+    }
+
+    fn check_conflicts(
+        semantic: &Semantic<'_>,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<(), RenameError> {
+        let symbols = semantic.symbols();
+        let scopes = semantic.scopes();
+        let scope_id = symbols.get_scope_id(symbol_id);
+
+        // `let a, b;`: `b` is already declared.
+        if let Some(binding) = scopes.get_binding(scope_id, new_name) {
+            return Err(RenameError::Conflict { span: symbols.get_span(binding) });
+        }
+
+        // `let a; { let b; a; }`: the reference of `a` would resolve to the inner `b`.
+        for reference in symbols.get_resolved_references(symbol_id) {
+            let reference_scope_id = semantic.nodes().get_node(reference.node_id()).scope_id();
+            if let Some(binding) =
+                Self::find_binding_below(semantic, reference_scope_id, scope_id, new_name)
+            {
+                return Err(RenameError::Conflict { span: symbols.get_span(binding) });
+            }
+        }
+
+        // `let b; { let a; b; }`: the reference of the outer `b` would resolve to `a`.
+        for reference in &symbols.references {
+            let node = semantic.nodes().get_node(reference.node_id());
+            let AstKind::IdentifierReference(ident) = node.kind() else { continue };
+            if ident.name.as_str() != new_name
+                || !scopes.ancestors(node.scope_id()).any(|ancestor| ancestor == scope_id)
+            {
+                continue;
+            }
+            let resolves_inside = reference.symbol_id().is_some_and(|binding| {
+                Self::find_binding_below(semantic, node.scope_id(), scope_id, new_name)
+                    == Some(binding)
+            });
+            if !resolves_inside {
+                return Err(RenameError::Conflict { span: ident.span });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The binding of `name` in `scope_id` or its ancestors, up to but excluding `until`.
+    fn find_binding_below(
+        semantic: &Semantic<'_>,
+        scope_id: ScopeId,
+        until: ScopeId,
+        name: &str,
+    ) -> Option<SymbolId> {
+        let scopes = semantic.scopes();
+        scopes
+            .ancestors(scope_id)
+            .take_while(|ancestor| *ancestor != until)
+            .find_map(|ancestor| scopes.get_binding(ancestor, name))
+    }
+
+    /// Spans of identifiers which are both a property key and a binding or reference, e.g. `a`
+    /// in `({ a })`, `({ a } = b)` and `const { a } = b`.
+    fn shorthand_spans(semantic: &Semantic<'_>) -> FxHashSet<Span> {
+        let mut spans = FxHashSet::default();
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::ObjectExpression(object) => {
+                    spans.extend(object.properties.iter().filter_map(|property| match property {
+                        ObjectPropertyKind::ObjectProperty(property) if property.shorthand => {
+                            Some(property.value.span())
+                        }
+                        _ => None,
+                    }));
+                }
+                AstKind::ObjectAssignmentTarget(target) => {
+                    spans.extend(target.properties.iter().filter_map(|property| match property {
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                            Some(property.binding.span)
+                        }
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(_) => None,
+                    }));
+                }
+                AstKind::ObjectPattern(pattern) => {
+                    spans.extend(
+                        pattern
+                            .properties
+                            .iter()
+                            .filter(|property| property.shorthand)
+                            .filter_map(|property| property.value.get_binding_identifier())
+                            .map(|ident| ident.span),
+                    );
+                }
+                _ => {}
+            }
+        }
+        spans
+    }
+}
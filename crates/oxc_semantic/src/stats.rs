@@ -4,8 +4,11 @@ use oxc_ast::{
     ast::{
         BindingIdentifier, IdentifierReference, Program, TSEnumMemberName, TSModuleDeclarationName,
     },
-    visit::walk::{walk_ts_enum_member_name, walk_ts_module_declaration_name},
-    AstKind, Visit,
+    AstKind,
+};
+use oxc_ast_visit::{
+    walk::{walk_ts_enum_member_name, walk_ts_module_declaration_name},
+    Visit,
 };
 use oxc_syntax::scope::{ScopeFlags, ScopeId};
 
@@ -12,6 +12,7 @@ pub use oxc_syntax::{
     scope::ScopeId,
     symbol::{RedeclarationId, SymbolFlags, SymbolId},
 };
+use rustc_hash::FxHashMap;
 
 use crate::{
     node::NodeId,
@@ -47,6 +48,69 @@ pub struct SymbolTable {
     redeclaration_spans: IndexVec<RedeclarationId, Vec<Span>>,
 
     pub references: IndexVec<ReferenceId, Reference>,
+
+    /// Most symbols are not exported, so module exports are stored sparsely.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    exports: FxHashMap<SymbolId, Vec<SymbolExport>>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    reexport_sources: FxHashMap<SymbolId, ReexportSource>,
+}
+
+/// The kind of declaration that introduced a symbol.
+///
+/// See [`SymbolTable::get_declaration_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolDeclarationKind {
+    /// `var`, `using`, or a function parameter
+    Var,
+    Let,
+    Const,
+    /// A function declaration, or the name of a function expression
+    Function,
+    /// A class declaration, or the name of a class expression
+    Class,
+    /// `try {} catch (e) {}`
+    CatchParameter,
+    /// `import a from "mod"` or `import a = require("mod")`
+    Import,
+    /// `import type a from "mod"` or `import { type a } from "mod"`
+    TypeImport,
+    Enum,
+    ConstEnum,
+    EnumMember,
+    Interface,
+    TypeAlias,
+    TypeParameter,
+    Namespace,
+    /// No declaration, e.g. a symbol created by a transform without any [`SymbolFlags`].
+    Unknown,
+}
+
+/// A name under which a top-level symbol is exported from its module.
+///
+/// See [`SymbolTable::get_exports`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolExport {
+    /// Span of the exported name
+    pub span: Span,
+    /// `b` in `export { a as b }`, or `default` for `export default a`
+    pub name: CompactStr,
+}
+
+/// The binding of another module that an exported import refers to.
+///
+/// ```js
+/// import { a as b } from "mod";
+/// //       ^ imported_name  ^^^ module_request
+/// export { b as c };
+/// ```
+///
+/// See [`SymbolTable::get_reexport_source`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReexportSource {
+    pub module_request: CompactStr,
+    /// `default` for default imports, [`None`] for namespace imports (`import * as ns`)
+    pub imported_name: Option<CompactStr>,
 }
 
 impl SymbolTable {
@@ -121,6 +185,89 @@ impl SymbolTable {
         &mut self.flags[symbol_id]
     }
 
+    /// Get the kind of declaration that introduced a symbol, derived from its [`SymbolFlags`].
+    pub fn get_declaration_kind(&self, symbol_id: SymbolId) -> SymbolDeclarationKind {
+        let flags = self.get_flags(symbol_id);
+        // Order matters: e.g. catch parameters are also function-scoped variables, and functions
+        // declared in blocks are also block-scoped variables.
+        if flags.contains(SymbolFlags::TypeImport) {
+            SymbolDeclarationKind::TypeImport
+        } else if flags.contains(SymbolFlags::Import) {
+            SymbolDeclarationKind::Import
+        } else if flags.is_catch_variable() {
+            SymbolDeclarationKind::CatchParameter
+        } else if flags.is_function() {
+            SymbolDeclarationKind::Function
+        } else if flags.is_class() {
+            SymbolDeclarationKind::Class
+        } else if flags.contains(SymbolFlags::ConstEnum) {
+            SymbolDeclarationKind::ConstEnum
+        } else if flags.contains(SymbolFlags::RegularEnum) {
+            SymbolDeclarationKind::Enum
+        } else if flags.is_enum_member() {
+            SymbolDeclarationKind::EnumMember
+        } else if flags.is_interface() {
+            SymbolDeclarationKind::Interface
+        } else if flags.is_type_alias() {
+            SymbolDeclarationKind::TypeAlias
+        } else if flags.is_type_parameter() {
+            SymbolDeclarationKind::TypeParameter
+        } else if flags.contains(SymbolFlags::NameSpaceModule) {
+            SymbolDeclarationKind::Namespace
+        } else if flags.is_const_variable() {
+            SymbolDeclarationKind::Const
+        } else if flags.contains(SymbolFlags::BlockScopedVariable) {
+            SymbolDeclarationKind::Let
+        } else if flags.is_function_scoped_declaration() {
+            SymbolDeclarationKind::Var
+        } else {
+            SymbolDeclarationKind::Unknown
+        }
+    }
+
+    /// Returns `true` if a symbol only exists at the type level and is erased from the emitted
+    /// JavaScript, e.g. interfaces, type aliases, type-only imports, uninstantiated namespaces
+    /// and `declare namespace`.
+    ///
+    /// Whether an import binding that is only _used_ as a type can be elided depends on its
+    /// references, not on its declaration, so this returns `false` for regular imports.
+    pub fn is_type_only(&self, symbol_id: SymbolId) -> bool {
+        let flags = self.get_flags(symbol_id);
+        flags.contains(SymbolFlags::Ambient)
+            || (!flags.is_value()
+                && flags.intersects(
+                    SymbolFlags::Type | SymbolFlags::TypeImport | SymbolFlags::NameSpaceModule,
+                ))
+    }
+
+    /// Get the names under which a top-level symbol is exported from its module.
+    ///
+    /// Unlike [`SymbolFlags::Export`], which is also set on exported members of a namespace,
+    /// this only covers module exports. Empty if the symbol is not exported.
+    pub fn get_exports(&self, symbol_id: SymbolId) -> &[SymbolExport] {
+        self.exports.get(&symbol_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn add_export(&mut self, symbol_id: SymbolId, export: SymbolExport) {
+        self.exports.entry(symbol_id).or_default().push(export);
+    }
+
+    /// Get the binding of another module that an exported import binding refers to.
+    ///
+    /// [`None`] if the symbol is not an import, or is not exported.
+    pub fn get_reexport_source(&self, symbol_id: SymbolId) -> Option<&ReexportSource> {
+        self.reexport_sources.get(&symbol_id)
+    }
+
+    pub fn set_reexport_source(&mut self, symbol_id: SymbolId, source: ReexportSource) {
+        self.reexport_sources.insert(symbol_id, source);
+    }
+
+    /// Returns `true` if a symbol is an import binding that is exported again by its module.
+    pub fn is_reexported(&self, symbol_id: SymbolId) -> bool {
+        self.reexport_sources.contains_key(&symbol_id)
+    }
+
     #[inline]
     pub fn get_redeclarations(&self, symbol_id: SymbolId) -> &[Span] {
         if let Some(redeclaration_id) = self.redeclarations[symbol_id] {
@@ -0,0 +1,706 @@
+//! A minimal inference of the types of expressions, for type-aware lint rules like
+//! `no-floating-promises`, `no-misused-promises` and `restrict-plus-operands` without the
+//! TypeScript compiler.
+//!
+//! Types are inferred from literals, operators, type annotations, the initializers of bindings
+//! which are never reassigned, and the return values of functions. Anything else is
+//! [`Type::Unknown`], so rules should only report what is known.
+
+use std::{
+    cell::{OnceCell, RefCell},
+    fmt,
+};
+
+use oxc_ast::{
+    ast::{
+        ArrayExpressionElement, ArrowFunctionExpression, AssignmentTarget, BinaryExpression,
+        BindingPatternKind, CallExpression, ChainElement, Expression, Function,
+        IdentifierReference, LogicalOperator, MemberExpression, NewExpression,
+        SimpleAssignmentTarget, TSLiteral, TSType, TSTypeName, TSTypeQueryExprName, UnaryOperator,
+    },
+    AstKind,
+};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator},
+    scope::ScopeId,
+    symbol::{SymbolFlags, SymbolId},
+};
+use rustc_hash::FxHashMap;
+
+use crate::{BindingOrigin, IsGlobalReference, NodeId, ProjectSemantic, Semantic};
+
+/// How many files [`TypeInference`] follows imports through.
+const MAX_IMPORT_DEPTH: usize = 8;
+
+/// An inferred type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    /// The type could not be inferred.
+    Unknown,
+    /// `any`
+    Any,
+    /// `never`
+    Never,
+    /// `undefined` or `void`
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    BigInt,
+    String,
+    Symbol,
+    /// Any other object, e.g. an object literal, a regular expression or a class instance.
+    Object,
+    Array(Box<Type>),
+    /// A function, with the type of what it returns.
+    Function(Box<Type>),
+    /// A promise or a thenable, with the type it resolves to.
+    Promise(Box<Type>),
+    /// A union of at least two types, none of them a union.
+    Union(Vec<Type>),
+}
+
+impl Type {
+    /// A union of types, flattening nested unions and removing duplicates.
+    ///
+    /// `any` absorbs every other type, and a union of a single type is that type.
+    pub fn union<I: IntoIterator<Item = Type>>(types: I) -> Type {
+        let mut members: Vec<Type> = vec![];
+        for ty in types {
+            let flattened = match ty {
+                Type::Union(types) => types,
+                Type::Never => continue,
+                ty => vec![ty],
+            };
+            for ty in flattened {
+                if ty == Type::Any {
+                    return Type::Any;
+                }
+                if !members.contains(&ty) {
+                    members.push(ty);
+                }
+            }
+        }
+        match members.len() {
+            0 => Type::Never,
+            1 => members.swap_remove(0),
+            _ => Type::Union(members),
+        }
+    }
+
+    /// The members of a union, or the type itself.
+    pub fn members(&self) -> &[Type] {
+        match self {
+            Type::Union(types) => types,
+            ty => std::slice::from_ref(ty),
+        }
+    }
+
+    /// Whether the type is known, i.e. it is not [`Type::Unknown`] or [`Type::Any`], and is not a
+    /// union containing them.
+    pub fn is_known(&self) -> bool {
+        self.members().iter().all(|ty| !matches!(ty, Type::Unknown | Type::Any))
+    }
+
+    /// Whether the value may be a promise, e.g. `Promise<T>` or `Promise<T> | undefined`.
+    pub fn is_promise_like(&self) -> bool {
+        self.members().iter().any(|ty| matches!(ty, Type::Promise(_)))
+    }
+
+    /// Whether the value may be a function which returns a promise, e.g. an async function.
+    pub fn returns_promise(&self) -> bool {
+        self.members()
+            .iter()
+            .any(|ty| matches!(ty, Type::Function(returns) if returns.is_promise_like()))
+    }
+
+    /// Whether the type is `string`, or a union of strings.
+    pub fn is_string(&self) -> bool {
+        self.members().iter().all(|ty| matches!(ty, Type::String))
+    }
+
+    /// Whether the type is `number`, or a union of numbers.
+    pub fn is_number(&self) -> bool {
+        self.members().iter().all(|ty| matches!(ty, Type::Number))
+    }
+
+    /// Whether the type is `bigint`, or a union of bigints.
+    pub fn is_bigint(&self) -> bool {
+        self.members().iter().all(|ty| matches!(ty, Type::BigInt))
+    }
+
+    /// Whether the value may be `null` or `undefined`.
+    pub fn is_nullable(&self) -> bool {
+        self.members().iter().any(|ty| matches!(ty, Type::Null | Type::Undefined))
+    }
+
+    /// The type without `null` and `undefined`, like `NonNullable<T>`.
+    pub fn non_nullable(&self) -> Type {
+        Type::union(
+            self.members().iter().filter(|ty| !matches!(ty, Type::Null | Type::Undefined)).cloned(),
+        )
+    }
+
+    /// The type of `await value`, like `Awaited<T>`.
+    pub fn awaited(&self) -> Type {
+        Type::union(self.members().iter().map(|ty| match ty {
+            Type::Promise(ty) => ty.awaited(),
+            ty => ty.clone(),
+        }))
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Unknown => f.write_str("unknown"),
+            Type::Any => f.write_str("any"),
+            Type::Never => f.write_str("never"),
+            Type::Undefined => f.write_str("undefined"),
+            Type::Null => f.write_str("null"),
+            Type::Boolean => f.write_str("boolean"),
+            Type::Number => f.write_str("number"),
+            Type::BigInt => f.write_str("bigint"),
+            Type::String => f.write_str("string"),
+            Type::Symbol => f.write_str("symbol"),
+            Type::Object => f.write_str("object"),
+            Type::Array(ty) if matches!(**ty, Type::Union(_) | Type::Function(_)) => {
+                write!(f, "({ty})[]")
+            }
+            Type::Array(ty) => write!(f, "{ty}[]"),
+            Type::Function(returns) => write!(f, "() => {returns}"),
+            Type::Promise(ty) => write!(f, "Promise<{ty}>"),
+            Type::Union(types) => {
+                for (i, ty) in types.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    if matches!(ty, Type::Function(_)) {
+                        write!(f, "({ty})")?;
+                    } else {
+                        write!(f, "{ty}")?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Infers the types of the expressions and symbols of a program.
+///
+/// Inferred types are cached, so a [`TypeInference`] should be reused for a program.
+///
+/// ## Example
+/// ```ignore
+/// let types = TypeInference::new(&semantic);
+/// if let AstKind::ExpressionStatement(stmt) = node.kind() {
+///     if types.expression_type(&stmt.expression).is_promise_like() {
+///         // a floating promise
+///     }
+/// }
+/// ```
+pub struct TypeInference<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    project: Option<&'s ProjectSemantic<'s, 'a>>,
+    /// Number of files followed through imports to get here.
+    depth: usize,
+    symbols: RefCell<FxHashMap<SymbolId, Type>>,
+    /// Return statements by the function which contains them.
+    returns: OnceCell<FxHashMap<NodeId, Vec<NodeId>>>,
+}
+
+impl<'s, 'a> TypeInference<'s, 'a> {
+    pub fn new(semantic: &'s Semantic<'a>) -> Self {
+        Self {
+            semantic,
+            project: None,
+            depth: 0,
+            symbols: RefCell::default(),
+            returns: OnceCell::new(),
+        }
+    }
+
+    /// Infer the types of imported bindings from the files of a project, which must contain the
+    /// file of this program. Without a project imported bindings are [`Type::Unknown`].
+    #[must_use]
+    pub fn with_project(mut self, project: &'s ProjectSemantic<'s, 'a>) -> Self {
+        self.project = Some(project);
+        self
+    }
+
+    /// The type of the value of an expression.
+    pub fn expression_type(&self, expr: &Expression<'a>) -> Type {
+        match expr {
+            Expression::BooleanLiteral(_) => Type::Boolean,
+            Expression::NullLiteral(_) => Type::Null,
+            Expression::NumericLiteral(_) => Type::Number,
+            Expression::BigIntLiteral(_) => Type::BigInt,
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => Type::String,
+            Expression::RegExpLiteral(_)
+            | Expression::ObjectExpression(_)
+            | Expression::JSXElement(_)
+            | Expression::JSXFragment(_) => Type::Object,
+            Expression::ClassExpression(_) => Type::Function(Box::new(Type::Object)),
+            Expression::Identifier(ident) => self.identifier_type(ident),
+            Expression::ArrayExpression(array) => {
+                let elements = array.elements.iter().map(|element| match element {
+                    ArrayExpressionElement::SpreadElement(_) => Type::Unknown,
+                    ArrayExpressionElement::Elision(_) => Type::Undefined,
+                    element => self.expression_type(element.to_expression()),
+                });
+                Type::Array(Box::new(Type::union(elements)))
+            }
+            Expression::FunctionExpression(func) => self.function_type(func),
+            Expression::ArrowFunctionExpression(arrow) => self.arrow_function_type(arrow),
+            Expression::ParenthesizedExpression(expr) => self.expression_type(&expr.expression),
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.last().map_or(Type::Undefined, |expr| self.expression_type(expr))
+            }
+            Expression::AssignmentExpression(expr) => match expr.operator {
+                AssignmentOperator::Assign => self.expression_type(&expr.right),
+                AssignmentOperator::Addition => addition_type(
+                    &self.assignment_target_type(&expr.left),
+                    &self.expression_type(&expr.right),
+                ),
+                operator if operator.is_arithmetic() || operator.is_bitwise() => numeric_type(
+                    &self.assignment_target_type(&expr.left),
+                    &self.expression_type(&expr.right),
+                ),
+                _ => Type::Unknown,
+            },
+            Expression::ConditionalExpression(expr) => Type::union([
+                self.expression_type(&expr.consequent),
+                self.expression_type(&expr.alternate),
+            ]),
+            Expression::LogicalExpression(expr) => {
+                let left = self.expression_type(&expr.left);
+                let right = self.expression_type(&expr.right);
+                match expr.operator {
+                    LogicalOperator::Coalesce => Type::union([left.non_nullable(), right]),
+                    LogicalOperator::And | LogicalOperator::Or => Type::union([left, right]),
+                }
+            }
+            Expression::UnaryExpression(expr) => match expr.operator {
+                UnaryOperator::Typeof => Type::String,
+                UnaryOperator::Void => Type::Undefined,
+                UnaryOperator::LogicalNot | UnaryOperator::Delete => Type::Boolean,
+                UnaryOperator::UnaryPlus => Type::Number,
+                UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
+                    match self.expression_type(&expr.argument) {
+                        Type::BigInt => Type::BigInt,
+                        ty if ty.is_known() => Type::Number,
+                        _ => Type::Unknown,
+                    }
+                }
+            },
+            Expression::UpdateExpression(expr) => {
+                let argument = match &expr.argument {
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                        self.identifier_type(ident)
+                    }
+                    target => target
+                        .as_member_expression()
+                        .map_or(Type::Unknown, |member| self.member_type(member)),
+                };
+                match argument {
+                    Type::BigInt => Type::BigInt,
+                    ty if ty.is_known() => Type::Number,
+                    _ => Type::Unknown,
+                }
+            }
+            Expression::BinaryExpression(expr) => self.binary_type(expr),
+            Expression::PrivateInExpression(_) => Type::Boolean,
+            Expression::AwaitExpression(expr) => self.expression_type(&expr.argument).awaited(),
+            Expression::CallExpression(call) => self.call_type(call),
+            Expression::NewExpression(new) => self.new_type(new),
+            Expression::ImportExpression(_) => Type::Promise(Box::new(Type::Object)),
+            Expression::ChainExpression(chain) => {
+                let ty = match &chain.expression {
+                    ChainElement::CallExpression(call) => self.call_type(call),
+                    element => element
+                        .as_member_expression()
+                        .map_or(Type::Unknown, |member| self.member_type(member)),
+                };
+                Type::union([ty, Type::Undefined])
+            }
+            Expression::TSAsExpression(expr) => self.ts_type(&expr.type_annotation),
+            Expression::TSTypeAssertion(expr) => self.ts_type(&expr.type_annotation),
+            Expression::TSSatisfiesExpression(expr) => self.expression_type(&expr.expression),
+            Expression::TSInstantiationExpression(expr) => self.expression_type(&expr.expression),
+            Expression::TSNonNullExpression(expr) => {
+                self.expression_type(&expr.expression).non_nullable()
+            }
+            expr => {
+                expr.as_member_expression().map_or(Type::Unknown, |member| self.member_type(member))
+            }
+        }
+    }
+
+    /// The type of the value of a symbol, from its type annotation or its declaration.
+    ///
+    /// Bindings which are reassigned are [`Type::Unknown`], unless they have a type annotation.
+    pub fn symbol_type(&self, symbol_id: SymbolId) -> Type {
+        if let Some(ty) = self.symbols.borrow().get(&symbol_id) {
+            return ty.clone();
+        }
+        // Break cycles, e.g. `const a = b; const b = a;` or recursive functions.
+        self.symbols.borrow_mut().insert(symbol_id, Type::Unknown);
+        let ty = self.infer_symbol_type(symbol_id);
+        self.symbols.borrow_mut().insert(symbol_id, ty.clone());
+        ty
+    }
+
+    /// The type described by a TypeScript type annotation.
+    pub fn ts_type(&self, ts_type: &TSType<'a>) -> Type {
+        match ts_type {
+            TSType::TSAnyKeyword(_) => Type::Any,
+            TSType::TSNeverKeyword(_) => Type::Never,
+            TSType::TSUndefinedKeyword(_) | TSType::TSVoidKeyword(_) => Type::Undefined,
+            TSType::TSNullKeyword(_) => Type::Null,
+            TSType::TSBooleanKeyword(_) => Type::Boolean,
+            TSType::TSNumberKeyword(_) => Type::Number,
+            TSType::TSBigIntKeyword(_) => Type::BigInt,
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => Type::String,
+            TSType::TSSymbolKeyword(_) => Type::Symbol,
+            TSType::TSObjectKeyword(_) | TSType::TSTypeLiteral(_) | TSType::TSMappedType(_) => {
+                Type::Object
+            }
+            TSType::TSLiteralType(literal) => match &literal.literal {
+                TSLiteral::BooleanLiteral(_) => Type::Boolean,
+                TSLiteral::NullLiteral(_) => Type::Null,
+                TSLiteral::NumericLiteral(_) => Type::Number,
+                TSLiteral::BigIntLiteral(_) => Type::BigInt,
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => Type::String,
+                TSLiteral::RegExpLiteral(_) => Type::Object,
+                TSLiteral::UnaryExpression(expr) => self.expression_type(&expr.argument),
+            },
+            TSType::TSArrayType(array) => Type::Array(Box::new(self.ts_type(&array.element_type))),
+            TSType::TSTupleType(_) => Type::Array(Box::new(Type::Unknown)),
+            TSType::TSFunctionType(func) => {
+                Type::Function(Box::new(self.ts_type(&func.return_type.type_annotation)))
+            }
+            TSType::TSConstructorType(_) => Type::Function(Box::new(Type::Object)),
+            TSType::TSUnionType(union) => {
+                Type::union(union.types.iter().map(|ty| self.ts_type(ty)))
+            }
+            TSType::TSParenthesizedType(ty) => self.ts_type(&ty.type_annotation),
+            TSType::TSTypeReference(reference) => {
+                let type_argument = |index: usize| {
+                    reference
+                        .type_parameters
+                        .as_ref()
+                        .and_then(|params| params.params.get(index))
+                        .map_or(Type::Unknown, |ty| self.ts_type(ty))
+                };
+                let TSTypeName::IdentifierReference(ident) = &reference.type_name else {
+                    return Type::Unknown;
+                };
+                if let Some(symbol_id) = self.reference_symbol(ident) {
+                    // A class or an interface names the type of its instances.
+                    let flags = self.semantic.symbols().get_flags(symbol_id);
+                    if flags.intersects(SymbolFlags::Class | SymbolFlags::Interface) {
+                        return Type::Object;
+                    }
+                    if !flags.contains(SymbolFlags::TypeAlias) {
+                        return Type::Unknown;
+                    }
+                    return self.symbol_type(symbol_id);
+                }
+                match ident.name.as_str() {
+                    "Promise" | "PromiseLike" => Type::Promise(Box::new(type_argument(0))),
+                    "Array" | "ReadonlyArray" => Type::Array(Box::new(type_argument(0))),
+                    "Awaited" => type_argument(0).awaited(),
+                    "NonNullable" => type_argument(0).non_nullable(),
+                    "Function" => Type::Function(Box::new(Type::Unknown)),
+                    "Object" | "RegExp" | "Date" | "Error" | "Map" | "Set" | "WeakMap"
+                    | "WeakSet" | "Record" => Type::Object,
+                    _ => Type::Unknown,
+                }
+            }
+            TSType::TSTypeQuery(query) => match &query.expr_name {
+                TSTypeQueryExprName::IdentifierReference(ident) => self.identifier_type(ident),
+                _ => Type::Unknown,
+            },
+            _ => Type::Unknown,
+        }
+    }
+
+    fn assignment_target_type(&self, target: &AssignmentTarget<'a>) -> Type {
+        match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => self.identifier_type(ident),
+            target => target
+                .as_member_expression()
+                .map_or(Type::Unknown, |member| self.member_type(member)),
+        }
+    }
+
+    fn identifier_type(&self, ident: &IdentifierReference<'a>) -> Type {
+        if let Some(symbol_id) = self.reference_symbol(ident) {
+            return self.symbol_type(symbol_id);
+        }
+        match ident.name.as_str() {
+            "undefined" => Type::Undefined,
+            "NaN" | "Infinity" => Type::Number,
+            _ => Type::Unknown,
+        }
+    }
+
+    fn reference_symbol(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        let reference_id = ident.reference_id.get()?;
+        self.semantic.symbols().get_reference(reference_id).symbol_id()
+    }
+
+    fn infer_symbol_type(&self, symbol_id: SymbolId) -> Type {
+        let symbols = self.semantic.symbols();
+        let flags = symbols.get_flags(symbol_id);
+        if flags.contains(SymbolFlags::Import) {
+            return self.import_type(symbol_id);
+        }
+        let is_reassigned =
+            symbols.get_resolved_references(symbol_id).any(|reference| reference.is_write());
+
+        let declaration = symbols.get_declaration(symbol_id);
+        match self.semantic.nodes().kind(declaration) {
+            AstKind::VariableDeclarator(decl) => {
+                let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else {
+                    return Type::Unknown;
+                };
+                if ident.symbol_id.get() != Some(symbol_id) {
+                    return Type::Unknown;
+                }
+                if let Some(annotation) = &decl.id.type_annotation {
+                    return self.ts_type(&annotation.type_annotation);
+                }
+                match &decl.init {
+                    _ if is_reassigned => Type::Unknown,
+                    Some(init) => self.expression_type(init),
+                    None => Type::Undefined,
+                }
+            }
+            AstKind::FormalParameter(param) => {
+                let Some(annotation) = &param.pattern.type_annotation else {
+                    return Type::Unknown;
+                };
+                let ty = self.ts_type(&annotation.type_annotation);
+                if param.pattern.optional {
+                    Type::union([ty, Type::Undefined])
+                } else {
+                    ty
+                }
+            }
+            AstKind::Function(func) if !is_reassigned => self.function_type(func),
+            AstKind::Class(_) if !is_reassigned => Type::Function(Box::new(Type::Object)),
+            AstKind::TSTypeAliasDeclaration(alias) => self.ts_type(&alias.type_annotation),
+            AstKind::TSInterfaceDeclaration(_) => Type::Object,
+            _ => Type::Unknown,
+        }
+    }
+
+    /// The type of an imported binding, from the file it is exported by.
+    fn import_type(&self, symbol_id: SymbolId) -> Type {
+        let Some(project) = self.project else { return Type::Unknown };
+        if self.depth >= MAX_IMPORT_DEPTH {
+            return Type::Unknown;
+        }
+        let path = &self.semantic.module_record().resolved_absolute_path;
+        match project.definition(path, symbol_id) {
+            Some(BindingOrigin::Symbol { path, symbol_id }) => {
+                let Some(semantic) = project.get(&path) else { return Type::Unknown };
+                let types = TypeInference {
+                    semantic,
+                    project: Some(project),
+                    depth: self.depth + 1,
+                    symbols: RefCell::default(),
+                    returns: OnceCell::new(),
+                };
+                types.symbol_type(symbol_id)
+            }
+            Some(BindingOrigin::Namespace { .. }) => Type::Object,
+            _ => Type::Unknown,
+        }
+    }
+
+    fn function_type(&self, func: &Function<'a>) -> Type {
+        if func.generator {
+            return Type::Function(Box::new(Type::Object));
+        }
+        let returns = match &func.return_type {
+            Some(annotation) => self.ts_type(&annotation.type_annotation),
+            None if func.body.is_none() => Type::Unknown,
+            None => self.return_statements_type(func.scope_id()),
+        };
+        Type::Function(Box::new(async_return_type(func.r#async, returns)))
+    }
+
+    fn arrow_function_type(&self, arrow: &ArrowFunctionExpression<'a>) -> Type {
+        let returns = match &arrow.return_type {
+            Some(annotation) => self.ts_type(&annotation.type_annotation),
+            None => match arrow.get_expression() {
+                Some(expr) => self.expression_type(expr),
+                None => self.return_statements_type(arrow.scope_id()),
+            },
+        };
+        Type::Function(Box::new(async_return_type(arrow.r#async, returns)))
+    }
+
+    /// The union of the values returned by the return statements of a function.
+    fn return_statements_type(&self, scope_id: ScopeId) -> Type {
+        let nodes = self.semantic.nodes();
+        let returns = self.returns.get_or_init(|| {
+            let mut returns = FxHashMap::<NodeId, Vec<NodeId>>::default();
+            for node in nodes.iter() {
+                if matches!(node.kind(), AstKind::ReturnStatement(_)) {
+                    if let Some(func) = nodes.enclosing_function(node.id()) {
+                        returns.entry(func.id()).or_default().push(node.id());
+                    }
+                }
+            }
+            returns
+        });
+        let function_id = self.semantic.scopes().get_node_id(scope_id);
+        let Some(statements) = returns.get(&function_id) else { return Type::Undefined };
+        Type::union(statements.iter().map(|&id| match nodes.kind(id) {
+            AstKind::ReturnStatement(stmt) => {
+                stmt.argument.as_ref().map_or(Type::Undefined, |arg| self.expression_type(arg))
+            }
+            _ => Type::Unknown,
+        }))
+    }
+
+    fn binary_type(&self, expr: &BinaryExpression<'a>) -> Type {
+        let operator = expr.operator;
+        if operator.is_equality() || operator.is_compare() || operator.is_relational() {
+            return Type::Boolean;
+        }
+        let left = self.expression_type(&expr.left);
+        let right = self.expression_type(&expr.right);
+        match operator {
+            BinaryOperator::Addition => addition_type(&left, &right),
+            // `>>>` throws on bigints.
+            BinaryOperator::ShiftRightZeroFill => Type::Number,
+            _ => numeric_type(&left, &right),
+        }
+    }
+
+    fn call_type(&self, call: &CallExpression<'a>) -> Type {
+        let symbols = self.semantic.symbols();
+        let callee = call.callee.without_parentheses();
+        if let Expression::Identifier(ident) = callee {
+            if ident.is_global_reference(symbols) {
+                return match ident.name.as_str() {
+                    "String" => Type::String,
+                    "Number" | "parseInt" | "parseFloat" => Type::Number,
+                    "Boolean" | "isNaN" | "isFinite" => Type::Boolean,
+                    "BigInt" => Type::BigInt,
+                    "Symbol" => Type::Symbol,
+                    "fetch" => Type::Promise(Box::new(Type::Object)),
+                    _ => Type::Unknown,
+                };
+            }
+        }
+        if let Some(member) = callee.as_member_expression() {
+            let object = member.object().without_parentheses();
+            let property = member.static_property_name();
+            if object.is_global_reference_name("Promise", symbols) {
+                return match property {
+                    Some("resolve" | "reject" | "all" | "allSettled" | "race" | "any") => {
+                        Type::Promise(Box::new(Type::Unknown))
+                    }
+                    _ => Type::Unknown,
+                };
+            }
+            if matches!(property, Some("then" | "catch" | "finally"))
+                && self.expression_type(object).is_promise_like()
+            {
+                return Type::Promise(Box::new(Type::Unknown));
+            }
+        }
+        match self.expression_type(callee) {
+            Type::Function(returns) => *returns,
+            Type::Union(types) => Type::union(types.into_iter().map(|ty| match ty {
+                Type::Function(returns) => *returns,
+                _ => Type::Unknown,
+            })),
+            Type::Any => Type::Any,
+            _ => Type::Unknown,
+        }
+    }
+
+    fn new_type(&self, new: &NewExpression<'a>) -> Type {
+        let symbols = self.semantic.symbols();
+        match new.callee.without_parentheses() {
+            Expression::Identifier(ident) if ident.is_global_reference(symbols) => {
+                match ident.name.as_str() {
+                    "Promise" => Type::Promise(Box::new(
+                        new.type_parameters
+                            .as_ref()
+                            .and_then(|params| params.params.first())
+                            .map_or(Type::Unknown, |ty| self.ts_type(ty)),
+                    )),
+                    "Array" => Type::Array(Box::new(Type::Unknown)),
+                    _ => Type::Object,
+                }
+            }
+            _ => Type::Object,
+        }
+    }
+
+    fn member_type(&self, member: &MemberExpression<'a>) -> Type {
+        if member.static_property_name() == Some("length") {
+            let object = self.expression_type(member.object());
+            if object.members().iter().all(|ty| matches!(ty, Type::String | Type::Array(_))) {
+                return Type::Number;
+            }
+        }
+        Type::Unknown
+    }
+}
+
+/// The return type of a function, wrapped in a promise if it is async.
+fn async_return_type(is_async: bool, returns: Type) -> Type {
+    if is_async {
+        Type::Promise(Box::new(returns.awaited()))
+    } else {
+        returns
+    }
+}
+
+/// The type of `left + right`.
+fn addition_type(left: &Type, right: &Type) -> Type {
+    if left.is_string() || right.is_string() {
+        return Type::String;
+    }
+    if !left.is_known() || !right.is_known() {
+        return Type::Unknown;
+    }
+    let is_numeric = |ty: &Type| {
+        ty.members()
+            .iter()
+            .all(|ty| matches!(ty, Type::Number | Type::Boolean | Type::Null | Type::Undefined))
+    };
+    if left.is_bigint() && right.is_bigint() {
+        Type::BigInt
+    } else if is_numeric(left) && is_numeric(right) {
+        Type::Number
+    } else {
+        Type::Unknown
+    }
+}
+
+/// The type of an arithmetic or bitwise operation other than `+`.
+fn numeric_type(left: &Type, right: &Type) -> Type {
+    if left.is_bigint() && right.is_bigint() {
+        Type::BigInt
+    } else if left
+        .members()
+        .iter()
+        .chain(right.members())
+        .any(|ty| matches!(ty, Type::BigInt | Type::Unknown | Type::Any | Type::Object))
+    {
+        // Objects may be converted to bigints by `valueOf`.
+        Type::Unknown
+    } else {
+        Type::Number
+    }
+}
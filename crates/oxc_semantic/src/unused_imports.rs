@@ -0,0 +1,225 @@
+//! Unused imports, shared by fixes removing them and TypeScript import elision.
+
+use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier, Program, Statement};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::symbol::{SymbolFlags, SymbolId};
+
+use crate::SymbolTable;
+
+/// An import specifier reported by [`UnusedImports`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnusedImport {
+    /// The symbol of the imported binding.
+    pub symbol_id: SymbolId,
+    /// Span of the import specifier.
+    pub span: Span,
+    /// Span to delete to remove the import. This is the whole declaration if none of its other
+    /// specifiers are needed, see [`UnusedImports::unused`] and [`UnusedImports::type_only`],
+    /// otherwise the specifier with its separators, see [`import_specifier_removal_span`].
+    pub removal_span: Span,
+}
+
+/// The top-level import specifiers which are not needed at runtime.
+///
+/// TypeScript elides both kinds of imports, fixes and code actions remove
+/// [`UnusedImports::unused`] only.
+#[derive(Debug, Default)]
+pub struct UnusedImports {
+    /// Imports which are not referenced at all, or shadowed by a value redeclaration.
+    ///
+    /// The removal span is the whole declaration if all of its specifiers are unused.
+    pub unused: Vec<UnusedImport>,
+    /// Imports which are only referenced as types, e.g. `import { Foo } from 'foo'; let x: Foo;`.
+    /// `import type` declarations and `type` specifiers are never reported here.
+    ///
+    /// The removal span is the whole declaration if none of its specifiers are used as values.
+    pub type_only: Vec<UnusedImport>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Usage {
+    Unused,
+    TypeOnly,
+    Value,
+}
+
+impl UnusedImports {
+    /// Find the unused imports of the top-level import declarations of `program`.
+    pub fn new(program: &Program<'_>, symbols: &SymbolTable) -> Self {
+        let mut unused_imports = Self::default();
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(specifiers) = &decl.specifiers else { continue };
+            let usages = specifiers
+                .iter()
+                .map(|specifier| Self::usage(decl, specifier, symbols))
+                .collect::<Vec<_>>();
+            let all_unused = usages.iter().all(|usage| *usage == Usage::Unused);
+            let no_value = usages.iter().all(|usage| *usage != Usage::Value);
+            for (index, (specifier, usage)) in specifiers.iter().zip(usages).enumerate() {
+                let (list, is_whole_declaration) = match usage {
+                    Usage::Unused => (&mut unused_imports.unused, all_unused),
+                    Usage::TypeOnly => (&mut unused_imports.type_only, no_value),
+                    Usage::Value => continue,
+                };
+                let Some(symbol_id) = specifier.local().symbol_id.get() else { continue };
+                let removal_span = if is_whole_declaration {
+                    decl.span
+                } else {
+                    import_specifier_removal_span(program.source_text, decl, index)
+                };
+                list.push(UnusedImport { symbol_id, span: specifier.span(), removal_span });
+            }
+        }
+        unused_imports
+    }
+
+    /// Whether the import of `symbol_id` is unused or only referenced as a type, i.e. it is
+    /// elided by TypeScript.
+    pub fn is_elided(&self, symbol_id: SymbolId) -> bool {
+        self.unused.iter().chain(&self.type_only).any(|import| import.symbol_id == symbol_id)
+    }
+
+    fn usage(
+        decl: &ImportDeclaration<'_>,
+        specifier: &ImportDeclarationSpecifier<'_>,
+        symbols: &SymbolTable,
+    ) -> Usage {
+        let Some(symbol_id) = specifier.local().symbol_id.get() else { return Usage::Value };
+        // `import T from 'mod'; const T = 1;` the references resolve to the value
+        // redeclaration, so the import is shadowed.
+        if (symbols.get_flags(symbol_id) - SymbolFlags::Import).is_value() {
+            return Usage::Unused;
+        }
+        let mut references = symbols.get_resolved_references(symbol_id).peekable();
+        if references.peek().is_none() {
+            return Usage::Unused;
+        }
+        let is_type_specifier = match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                specifier.import_kind.is_type()
+            }
+            _ => false,
+        };
+        if decl.import_kind.is_type()
+            || is_type_specifier
+            || references.any(|reference| !reference.is_type())
+        {
+            Usage::Value
+        } else {
+            Usage::TypeOnly
+        }
+    }
+}
+
+/// Span to delete to remove the specifier at `index` from `decl`, including the separators and
+/// comments around it, so that the rest of the declaration stays valid and tidy:
+///
+/// * `import a from 'a'`: the whole declaration.
+/// * `import { a, b } from 'a'`: `a, ` or `, b`.
+/// * `import a, { b } from 'a'`: `a, ` or `, { b }`.
+///
+/// # Panics
+/// If `decl` has no specifier at `index`.
+pub fn import_specifier_removal_span(
+    source_text: &str,
+    decl: &ImportDeclaration<'_>,
+    index: usize,
+) -> Span {
+    let specifiers = decl.specifiers.as_ref().expect("import declaration has no specifiers");
+    let span = specifiers[index].span();
+    if specifiers.len() == 1 {
+        return decl.span;
+    }
+    let is_named = |specifier: &ImportDeclarationSpecifier| {
+        matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(_))
+    };
+    #[allow(clippy::cast_possible_truncation)]
+    let find = |from: u32, pat: char| {
+        source_text[from as usize..].find(pat).map(|offset| from + offset as u32)
+    };
+
+    match (index.checked_sub(1).map(|i| &specifiers[i]), specifiers.get(index + 1)) {
+        // The only named specifier after a default one, remove the braces too.
+        (Some(prev), None) if is_named(&specifiers[index]) && !is_named(prev) => {
+            let end = find(span.end, '}').map_or(span.end, |end| end + 1);
+            Span::new(prev.span().end, end)
+        }
+        (Some(prev), None) => Span::new(prev.span().end, span.end),
+        // A default specifier followed by named specifiers, keep the open brace.
+        (_, Some(next)) if is_named(next) && !is_named(&specifiers[index]) => {
+            Span::new(span.start, find(span.end, '{').unwrap_or(next.span().start))
+        }
+        (_, Some(next)) => Span::new(span.start, next.span().start),
+        (None, None) => decl.span,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::SourceType;
+
+    use super::UnusedImports;
+    use crate::SemanticBuilder;
+
+    fn unused_imports(source: &str) -> (Vec<&str>, Vec<&str>) {
+        let allocator = Allocator::default();
+        let ret = oxc_parser::Parser::new(&allocator, source, SourceType::ts()).parse();
+        assert!(ret.errors.is_empty());
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let unused_imports = UnusedImports::new(&ret.program, semantic.symbols());
+        let removals = |imports: &[super::UnusedImport]| {
+            imports.iter().map(|import| import.removal_span.source_text(source)).collect()
+        };
+        (removals(&unused_imports.unused), removals(&unused_imports.type_only))
+    }
+
+    #[test]
+    fn test_unused_imports() {
+        let none: Vec<&str> = vec![];
+        assert_eq!(
+            unused_imports("import a from 'a';"),
+            (vec!["import a from 'a';"], none.clone())
+        );
+        assert_eq!(unused_imports("import a from 'a'; a;"), (none.clone(), none.clone()));
+        assert_eq!(unused_imports("import 'a';"), (none.clone(), none.clone()));
+        assert_eq!(
+            unused_imports("import { a, b, c } from 'a'; b;"),
+            (vec!["a, ", ", c"], none.clone())
+        );
+        assert_eq!(
+            unused_imports("import { a, /* b */ b } from 'a'; b;"),
+            (vec!["a, /* b */ "], none.clone())
+        );
+        assert_eq!(unused_imports("import a, { b } from 'a'; b;"), (vec!["a, "], none.clone()));
+        assert_eq!(unused_imports("import a, { b } from 'a'; a;"), (vec![", { b }"], none.clone()));
+        assert_eq!(
+            unused_imports("import a, * as b from 'a'; a;"),
+            (vec![", * as b"], none.clone())
+        );
+    }
+
+    #[test]
+    fn test_type_only_imports() {
+        let none: Vec<&str> = vec![];
+        assert_eq!(
+            unused_imports("import { A } from 'a'; let x: A;"),
+            (none.clone(), vec!["import { A } from 'a';"])
+        );
+        assert_eq!(
+            unused_imports("import { A, b, C } from 'a'; let x: A = b;"),
+            (vec![", C"], vec!["A, "])
+        );
+        assert_eq!(
+            unused_imports("import { A, B } from 'a'; let x: A;"),
+            (vec![", B"], vec!["import { A, B } from 'a';"])
+        );
+        // Type imports are already elided.
+        assert_eq!(
+            unused_imports("import type { A } from 'a'; let x: A;"),
+            (none.clone(), none.clone())
+        );
+        assert_eq!(unused_imports("import { type A } from 'a'; let x: A;"), (none.clone(), none));
+    }
+}
@@ -0,0 +1,95 @@
+use oxc_semantic::{AccessorKind, AccessorPair, AccessorPairs};
+
+use crate::util::SemanticTester;
+
+/// `(name, static, has getter, has setter, grouped)` of every pair.
+fn summary(pairs: &[AccessorPair]) -> Vec<(Option<&str>, bool, bool, bool, bool)> {
+    pairs
+        .iter()
+        .map(|pair| {
+            (
+                pair.name.as_deref(),
+                pair.r#static,
+                pair.getter.is_some(),
+                pair.setter.is_some(),
+                pair.is_grouped(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_object_literals() {
+    let tester = SemanticTester::js(
+        "
+        const a = {
+            get foo() {}, set foo(v) {},
+            get bar() {}, baz: 1, set bar(v) {},
+            set 'qux'(v) {},
+            get [key]() {}, set [key](v) {},
+        };
+        const b = { get foo() {} };
+        ",
+    );
+    let semantic = tester.build();
+    let accessor_pairs = AccessorPairs::new(&semantic);
+    assert_eq!(
+        summary(accessor_pairs.pairs()),
+        vec![
+            (Some("foo"), false, true, true, true),
+            (Some("bar"), false, true, true, false),
+            (Some("qux"), false, false, true, false),
+            (None, false, true, false, false),
+            (None, false, false, true, false),
+            (Some("foo"), false, true, false, false),
+        ]
+    );
+
+    let owner_id = accessor_pairs.pairs()[0].owner_id;
+    assert_eq!(accessor_pairs.pairs_of(owner_id).count(), 5);
+
+    let setter = accessor_pairs.pairs()[1].setter.unwrap();
+    assert_eq!(setter.kind, AccessorKind::Set);
+    assert_eq!(setter.index, 4);
+    assert_eq!(accessor_pairs.get(setter.node_id), Some(&accessor_pairs.pairs()[1]));
+}
+
+#[test]
+fn test_classes() {
+    let tester = SemanticTester::js(
+        "
+        class A {
+            get foo() {}
+            static set foo(v) {}
+            set foo(v) {}
+            static get foo() {}
+            get #bar() {}
+            set #bar(v) {}
+            baz() {}
+        }
+        ",
+    );
+    let semantic = tester.build();
+    let accessor_pairs = AccessorPairs::new(&semantic);
+    assert_eq!(
+        summary(accessor_pairs.pairs()),
+        vec![
+            (Some("foo"), false, true, true, false),
+            (Some("foo"), true, true, true, false),
+            (Some("bar"), false, true, true, true),
+        ]
+    );
+    assert!(accessor_pairs.pairs()[2].is_private);
+
+    assert!(accessor_pairs.may_define("foo"));
+    assert!(!accessor_pairs.may_define("bar"));
+    assert!(!accessor_pairs.may_define("baz"));
+}
+
+#[test]
+fn test_computed_keys_may_define_any_property() {
+    let tester = SemanticTester::js("const a = { get [key]() {} };");
+    let semantic = tester.build();
+    let accessor_pairs = AccessorPairs::new(&semantic);
+    assert!(accessor_pairs.may_define("anything"));
+}
@@ -0,0 +1,168 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{ReferenceId, Semantic, SymbolDataflow};
+use oxc_span::GetSpan;
+
+use crate::util::SemanticTester;
+
+/// The reference ids of the reads of `name`, in source order.
+fn reads(semantic: &Semantic, name: &str) -> Vec<ReferenceId> {
+    semantic
+        .nodes()
+        .iter()
+        .filter_map(|node| match node.kind() {
+            AstKind::IdentifierReference(ident) if ident.name == name => ident.reference_id.get(),
+            _ => None,
+        })
+        .filter(|&reference_id| semantic.symbols().get_reference(reference_id).is_read())
+        .collect()
+}
+
+/// The source text of the reaching definitions of a read, e.g. `x = 2`.
+fn reaching(
+    semantic: &Semantic,
+    dataflow: &SymbolDataflow,
+    reference_id: ReferenceId,
+) -> Vec<String> {
+    let mut definitions = dataflow
+        .reaching_definitions(reference_id)
+        .into_iter()
+        .map(|definition| {
+            let node = semantic
+                .nodes()
+                .ancestors(definition.node_id)
+                .find(|node| {
+                    matches!(
+                        node.kind(),
+                        AstKind::AssignmentExpression(_) | AstKind::UpdateExpression(_)
+                    )
+                })
+                .unwrap_or_else(|| semantic.nodes().get_node(definition.node_id));
+            node.kind().span().source_text(semantic.source_text()).to_string()
+        })
+        .collect::<Vec<_>>();
+    definitions.sort();
+    definitions
+}
+
+#[test]
+fn test_requires_cfg() {
+    let tester = SemanticTester::js("let x = 1;");
+    assert!(SymbolDataflow::new(&tester.build()).is_none());
+}
+
+#[test]
+fn test_reaching_definitions() {
+    let tester = SemanticTester::js(
+        "
+        let x = 1;
+        read(x);
+        if (cond) {
+            x = 2;
+        }
+        read(x);
+        x = x + 1;
+        read(x);
+        ",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let dataflow = SymbolDataflow::new(&semantic).unwrap();
+    let reads = reads(&semantic, "x");
+
+    assert_eq!(reaching(&semantic, &dataflow, reads[0]), vec!["x"]);
+    assert_eq!(reaching(&semantic, &dataflow, reads[1]), vec!["x", "x = 2"]);
+    // `x = x + 1` reads `x` before writing it
+    assert_eq!(reaching(&semantic, &dataflow, reads[2]), vec!["x", "x = 2"]);
+    assert_eq!(reaching(&semantic, &dataflow, reads[3]), vec!["x = x + 1"]);
+}
+
+#[test]
+fn test_reaching_definitions_in_loop() {
+    let tester = SemanticTester::js(
+        "
+        let i = 0;
+        while (i < 10) {
+            i++;
+        }
+        ",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let dataflow = SymbolDataflow::new(&semantic).unwrap();
+    let reads = reads(&semantic, "i");
+
+    assert_eq!(reaching(&semantic, &dataflow, reads[0]), vec!["i", "i++"]);
+}
+
+#[test]
+fn test_definite_assignment() {
+    let tester = SemanticTester::js(
+        "
+        let x;
+        if (cond) {
+            x = 1;
+        }
+        read(x);
+        let y;
+        if (cond) {
+            y = 1;
+        } else {
+            y = 2;
+        }
+        read(y);
+        read(f);
+        function f() {}
+        ",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let dataflow = SymbolDataflow::new(&semantic).unwrap();
+
+    assert!(!dataflow.is_definitely_assigned(reads(&semantic, "x")[0]));
+    assert!(dataflow.is_definitely_assigned(reads(&semantic, "y")[0]));
+    assert!(dataflow.is_definitely_assigned(reads(&semantic, "f")[0]));
+}
+
+#[test]
+fn test_liveness() {
+    let tester = SemanticTester::js(
+        "
+        let x = 1;
+        x = 2;
+        read(x);
+        x = 3;
+        let y = 1;
+        const callback = () => y;
+        y = 2;
+        ",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let dataflow = SymbolDataflow::new(&semantic).unwrap();
+
+    let x = semantic.scopes().get_root_binding("x").unwrap();
+    let used = dataflow.definitions(x).map(|definition| dataflow.is_used(definition));
+    assert_eq!(used.collect::<Vec<_>>(), vec![false, true, false]);
+
+    // `y` is read by a closure which may be called at any time
+    let y = semantic.scopes().get_root_binding("y").unwrap();
+    assert!(dataflow.definitions(y).all(|definition| dataflow.is_used(definition)));
+}
+
+#[test]
+fn test_closures_are_conservative() {
+    let tester = SemanticTester::js(
+        "
+        let x = 1;
+        function set() {
+            x = 2;
+        }
+        read(x);
+        ",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let dataflow = SymbolDataflow::new(&semantic).unwrap();
+
+    assert_eq!(reaching(&semantic, &dataflow, reads(&semantic, "x")[0]), vec!["x", "x = 2"]);
+}
@@ -0,0 +1,167 @@
+use oxc_semantic::{Immutability, ObjectImmutability, Semantic};
+
+use crate::util::SemanticTester;
+
+/// The immutability of the object bound to each of `names`.
+fn immutability_of(
+    semantic: &Semantic,
+    immutability: &ObjectImmutability,
+    names: &[&str],
+) -> Vec<Option<Immutability>> {
+    names
+        .iter()
+        .map(|name| {
+            let symbol_id = semantic
+                .symbols()
+                .symbol_ids()
+                .find(|&symbol_id| semantic.symbols().get_name(symbol_id) == *name)
+                .unwrap_or_else(|| panic!("no symbol named {name}"));
+            immutability.get(symbol_id).map(|object| object.immutability)
+        })
+        .collect()
+}
+
+#[test]
+fn test_frozen() {
+    let tester = SemanticTester::js(
+        "
+        const a = Object.freeze({ x: 1 });
+        const b = Object.freeze([1, 2]);
+        const c = Object.freeze({ x: 1 });
+        c.x = 2;
+        f(c);
+        let d = Object.freeze({ x: 1 });
+        const e = Object.freeze(obj);
+        function k(Object) { const g = Object.freeze({ x: 1 }); g.x = 2; }
+        ",
+    );
+    let semantic = tester.build();
+    let immutability = ObjectImmutability::new(&semantic);
+    assert_eq!(
+        immutability_of(&semantic, &immutability, &["a", "b", "c", "d", "e", "g"]),
+        vec![
+            Some(Immutability::Frozen),
+            Some(Immutability::Frozen),
+            Some(Immutability::Frozen),
+            None,
+            None,
+            None,
+        ]
+    );
+
+    // `Object.freeze` may not be the global one anymore.
+    let tester = SemanticTester::js(
+        "
+        Object = { freeze: (x) => x };
+        const a = Object.freeze({ x: 1 });
+        a.x = 2;
+        ",
+    );
+    let semantic = tester.build();
+    let immutability = ObjectImmutability::new(&semantic);
+    assert_eq!(immutability_of(&semantic, &immutability, &["a"]), vec![None]);
+}
+
+#[test]
+fn test_never_mutated() {
+    let tester = SemanticTester::js(
+        "
+        const a = { x: 1, y: 'y' };
+        f(a.x, a['y'], a?.x, typeof a.x);
+        const b = [1, 2];
+        f(b[0], b.length);
+        const c = { x: 1 };
+        Object.freeze(c);
+        f(c.x);
+        const d = {};
+        ",
+    );
+    let semantic = tester.build();
+    let immutability = ObjectImmutability::new(&semantic);
+    assert_eq!(
+        immutability_of(&semantic, &immutability, &["a", "b", "c", "d"]),
+        vec![Some(Immutability::NeverMutated); 4]
+    );
+}
+
+#[test]
+fn test_mutated_or_escaped() {
+    let tester = SemanticTester::js(
+        "
+        const a = { x: 1 }; a.x = 2;
+        const b = { x: 1 }; b.x++;
+        const c = { x: 1 }; delete c.x;
+        const d = { x: 1 }; [d.x] = [2];
+        const e = { x: 1 }; for (e.x in obj);
+        const g = [1]; g.push(2);
+        const h = { x: 1 }; f(h);
+        const i = { x: 1 }; const j = i;
+        const k = { x: 1, get y() { return this.x++ } };
+        const l = { x: 1 }; f(obj[l]);
+        const m = { x: 1 }; m.x.y = 2;
+        export const n = { x: 1 };
+        var o = { x: 1 };
+        ",
+    );
+    let semantic = tester.build();
+    let immutability = ObjectImmutability::new(&semantic);
+    assert_eq!(
+        immutability_of(
+            &semantic,
+            &immutability,
+            &["a", "b", "c", "d", "e", "g", "h", "i", "k", "l", "m", "n", "o"]
+        ),
+        vec![
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            // Only the object bound to `m` is immutable, not the object in its property.
+            Some(Immutability::NeverMutated),
+            None,
+            None,
+        ]
+    );
+}
+
+#[test]
+fn test_typescript() {
+    let tester = SemanticTester::ts(
+        "
+        const a = { x: 1 } as const;
+        f(a.x, (a as any).x, a!.x);
+        const b = { x: 1 } satisfies object;
+        (b as any).x = 2;
+        ",
+    );
+    let semantic = tester.build();
+    let immutability = ObjectImmutability::new(&semantic);
+    assert_eq!(
+        immutability_of(&semantic, &immutability, &["a", "b"]),
+        vec![Some(Immutability::NeverMutated), None]
+    );
+}
+
+#[test]
+fn test_script_top_level() {
+    let tester = SemanticTester::new(
+        "
+        const a = { x: 1 };
+        const b = Object.freeze({ x: 1 });
+        function f() { const c = { x: 1 }; return c.x }
+        ",
+        oxc_span::SourceType::cjs(),
+    );
+    let semantic = tester.build();
+    let immutability = ObjectImmutability::new(&semantic);
+    assert_eq!(
+        immutability_of(&semantic, &immutability, &["a", "b", "c"]),
+        vec![None, Some(Immutability::Frozen), Some(Immutability::NeverMutated)]
+    );
+}
@@ -1,8 +1,15 @@
 #![allow(clippy::missing_panics_doc)]
 
+pub mod accessor_pairs;
 pub mod cfg;
 pub mod classes;
+pub mod dataflow;
+pub mod immutability;
 pub mod modules;
+pub mod nodes;
+pub mod project;
+pub mod rename;
 pub mod scopes;
 pub mod symbols;
+pub mod type_inference;
 pub mod util;
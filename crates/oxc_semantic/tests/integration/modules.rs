@@ -1,4 +1,4 @@
-use oxc_semantic::{SemanticBuilderReturn, SymbolFlags};
+use oxc_semantic::{ReexportSource, SemanticBuilderReturn, SymbolFlags};
 
 use crate::util::SemanticTester;
 
@@ -258,3 +258,54 @@ fn test_import_type() {
         .contains_flags(SymbolFlags::TypeImport)
         .test();
 }
+
+#[test]
+fn test_symbol_exports() {
+    let test = SemanticTester::ts(
+        "
+        import { a as b } from './a';
+        import * as ns from './ns';
+        import type { T } from './types';
+        const c = 1;
+        export function f() {}
+        export { b as c2, c, c as d, ns };
+        export default c;
+        export type { T };
+        export { x } from './x';
+        namespace N { export const y = 1 }
+    ",
+    );
+    let semantic = test.build();
+    let symbols = semantic.symbols();
+    let exports = |name: &str| {
+        let (_, symbol_id) = test.has_some_symbol(name).inner();
+        symbols
+            .get_exports(symbol_id)
+            .iter()
+            .map(|export| export.name.to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(exports("b"), ["c2"]);
+    assert_eq!(exports("ns"), ["ns"]);
+    assert_eq!(exports("T"), ["T"]);
+    assert_eq!(exports("c"), ["c", "d", "default"]);
+    assert_eq!(exports("f"), ["f"]);
+    // namespace members are not module exports
+    assert!(exports("y").is_empty());
+
+    let reexport_source = |name: &str| {
+        let (_, symbol_id) = test.has_some_symbol(name).inner();
+        symbols.get_reexport_source(symbol_id).cloned()
+    };
+    assert_eq!(
+        reexport_source("b"),
+        Some(ReexportSource { module_request: "./a".into(), imported_name: Some("a".into()) })
+    );
+    assert_eq!(
+        reexport_source("ns"),
+        Some(ReexportSource { module_request: "./ns".into(), imported_name: None })
+    );
+    assert!(reexport_source("c").is_none());
+    let (_, c) = test.has_some_symbol("c").inner();
+    assert!(!symbols.is_reexported(c));
+}
@@ -0,0 +1,124 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNode, Semantic};
+
+use crate::util::SemanticTester;
+
+fn find_identifier_reference<'a, 's>(semantic: &'s Semantic<'a>, name: &str) -> &'s AstNode<'a> {
+    semantic
+        .nodes()
+        .iter()
+        .find(|node| node.kind().is_specific_id_reference(name))
+        .unwrap_or_else(|| panic!("Could not find a reference to {name}"))
+}
+
+#[test]
+fn test_enclosing_nodes() {
+    let tester = SemanticTester::js(
+        "
+        class Foo {
+            bar() {
+                if (cond) {
+                    return () => value;
+                }
+            }
+        }
+        top;
+        ",
+    );
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let value = find_identifier_reference(&semantic, "value");
+    let function = nodes.enclosing_function(value.id()).unwrap();
+    assert!(matches!(function.kind(), AstKind::ArrowFunctionExpression(_)));
+    let method = nodes.enclosing_function(function.id()).unwrap();
+    assert!(matches!(method.kind(), AstKind::Function(_)));
+    let class = nodes.enclosing_class(value.id()).unwrap();
+    assert!(
+        matches!(class.kind(), AstKind::Class(class) if class.id.as_ref().unwrap().name == "Foo")
+    );
+    let statement = nodes.enclosing_statement(value.id()).unwrap();
+    assert!(matches!(statement.kind(), AstKind::ReturnStatement(_)));
+    let statement = nodes.enclosing_statement(statement.id()).unwrap();
+    assert!(matches!(statement.kind(), AstKind::BlockStatement(_)));
+
+    let top = find_identifier_reference(&semantic, "top");
+    assert!(nodes.enclosing_function(top.id()).is_none());
+    assert!(nodes.enclosing_class(top.id()).is_none());
+    assert!(matches!(
+        nodes.enclosing_statement(top.id()).unwrap().kind(),
+        AstKind::ExpressionStatement(_)
+    ));
+}
+
+#[test]
+fn test_children_and_siblings() {
+    let tester = SemanticTester::js("foo(a, b, c); bar;");
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let root = nodes.root().unwrap();
+    let statements = nodes
+        .child_ids(root)
+        .filter(|id| matches!(nodes.kind(*id), AstKind::ExpressionStatement(_)))
+        .count();
+    assert_eq!(statements, 2);
+
+    let call = nodes.iter().find(|node| matches!(node.kind(), AstKind::CallExpression(_))).unwrap();
+    let children = nodes.child_ids(call.id()).collect::<Vec<_>>();
+    assert_eq!(children.len(), 4);
+    assert!(nodes.kind(children[0]).is_specific_id_reference("foo"));
+    assert!(children[1..].iter().all(|id| matches!(nodes.kind(*id), AstKind::Argument(_))));
+
+    let siblings = nodes.sibling_ids(children[1]).collect::<Vec<_>>();
+    assert_eq!(siblings, [children[0], children[2], children[3]]);
+    assert_eq!(nodes.sibling_ids(root).count(), 0);
+}
+
+#[test]
+fn test_jump_targets() {
+    let tester = SemanticTester::js(
+        "
+        outer: for (;;) {
+            switch (x) {
+                case 1: break;
+                case 2: continue;
+                case 3: break outer;
+            }
+            inner: a: { break inner; }
+            b: c: while (y) { continue b; }
+            () => { for (;;) {} };
+        }
+        ",
+    );
+    let semantic = tester.build();
+    let nodes = semantic.nodes();
+
+    let jumps = nodes
+        .iter()
+        .filter(|node| {
+            matches!(node.kind(), AstKind::BreakStatement(_) | AstKind::ContinueStatement(_))
+        })
+        .map(|node| nodes.jump_target(node.id()).unwrap().kind().debug_name().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        jumps,
+        ["SwitchStatement", "ForStatement", "ForStatement", "BlockStatement", "WhileStatement"]
+    );
+
+    // The label of `break outer` is needed: without it, the `switch` would be exited.
+    let break_outer = nodes
+        .iter()
+        .find(|node| matches!(node.kind(), AstKind::BreakStatement(stmt) if stmt.label.is_some()))
+        .unwrap();
+    let labeled = nodes.resolve_jump(break_outer.id(), Some("outer"), false).unwrap();
+    let unlabeled = nodes.resolve_jump(break_outer.id(), None, false).unwrap();
+    assert!(matches!(labeled.kind(), AstKind::ForStatement(_)));
+    assert!(matches!(unlabeled.kind(), AstKind::SwitchStatement(_)));
+    assert!(nodes.resolve_jump(break_outer.id(), Some("missing"), false).is_none());
+
+    // Jumps do not cross functions.
+    let arrow_body =
+        nodes.iter().find(|node| matches!(node.kind(), AstKind::FunctionBody(_))).unwrap();
+    assert!(nodes.resolve_jump(arrow_body.id(), None, false).is_none());
+}
@@ -0,0 +1,262 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::{
+    BindingOrigin, ExportResolution, ProjectSemantic, ResolvedExport, Semantic, SemanticBuilder,
+    UnusedExportsOptions,
+};
+use oxc_span::{CompactStr, SourceType};
+
+/// Build the files at `/{name}.js`, linking `./{name}` specifiers to them.
+fn build<'a>(allocator: &'a Allocator, files: &[(&str, &'a str)]) -> Vec<Semantic<'a>> {
+    let mut module_records = vec![];
+    let semantics = files
+        .iter()
+        .map(|(name, source_text)| {
+            let path = PathBuf::from(format!("/{name}.js"));
+            let program = Parser::new(allocator, source_text, SourceType::mjs()).parse().program;
+            let builder = SemanticBuilder::new().build_module_record(&path, &program);
+            module_records.push(builder.module_record());
+            builder.build(&program).semantic
+        })
+        .collect::<Vec<_>>();
+
+    for module_record in &module_records {
+        for specifier in module_record.requested_modules.keys() {
+            let path = PathBuf::from(format!("/{}.js", specifier.trim_start_matches("./")));
+            if let Some(target) = module_records.iter().find(|m| m.resolved_absolute_path == path) {
+                module_record.loaded_modules.insert(specifier.clone(), Arc::clone(target));
+            }
+        }
+    }
+    semantics
+}
+
+fn binding(path: &str, name: &str) -> ExportResolution {
+    ExportResolution::Resolved(ResolvedExport::Binding {
+        path: PathBuf::from(path),
+        name: name.into(),
+    })
+}
+
+/// Where the import `name` of the file at `path` is defined, as `(path, declared name)`.
+fn definition(project: &ProjectSemantic, path: &str, name: &str) -> Option<(PathBuf, String)> {
+    let symbol_id = project.get(Path::new(path))?.scopes().get_root_binding(name)?;
+    match project.definition(Path::new(path), symbol_id)? {
+        BindingOrigin::Symbol { path, symbol_id } => {
+            let name = project.get(&path)?.symbols().get_name(symbol_id).to_string();
+            Some((path, name))
+        }
+        origin => panic!("expected a symbol, got {origin:?}"),
+    }
+}
+
+#[test]
+fn test_resolve_export() {
+    let allocator = Allocator::default();
+    let semantics = build(
+        &allocator,
+        &[
+            (
+                "a",
+                "export const a = 1; const b = 2; export { b as c };
+                export default function f() {}",
+            ),
+            (
+                "b",
+                "export { a, c as d } from './a'; export * from './c';
+                export * as ns from './a';",
+            ),
+            ("c", "export const e = 1; export default 2;"),
+            ("d", "export * from './b'; export * from './e';"),
+            ("e", "export const e = 3;"),
+        ],
+    );
+    let mut project = ProjectSemantic::new();
+    for semantic in &semantics {
+        project.add(semantic);
+    }
+    let resolve = |path: &str, name: &str| project.resolve_export(Path::new(path), name);
+
+    assert_eq!(resolve("/a.js", "a"), binding("/a.js", "a"));
+    assert_eq!(resolve("/a.js", "c"), binding("/a.js", "b"));
+    assert_eq!(resolve("/a.js", "default"), binding("/a.js", "f"));
+    assert_eq!(resolve("/a.js", "b"), ExportResolution::NotFound);
+
+    assert_eq!(resolve("/b.js", "a"), binding("/a.js", "a"));
+    assert_eq!(resolve("/b.js", "d"), binding("/a.js", "b"));
+    assert_eq!(resolve("/b.js", "e"), binding("/c.js", "e"));
+    assert_eq!(
+        resolve("/b.js", "ns"),
+        ExportResolution::Resolved(ResolvedExport::Namespace { path: PathBuf::from("/a.js") })
+    );
+    // `export *` does not re-export the default export.
+    assert_eq!(resolve("/b.js", "default"), ExportResolution::NotFound);
+    assert!(matches!(
+        resolve("/c.js", "default"),
+        ExportResolution::Resolved(ResolvedExport::AnonymousDefault { .. })
+    ));
+
+    // `e` is exported by both `./b` (from `./c`) and `./e`.
+    assert_eq!(resolve("/d.js", "e"), ExportResolution::Ambiguous);
+    assert_eq!(resolve("/d.js", "a"), binding("/a.js", "a"));
+
+    assert_eq!(
+        project.exported_names(Path::new("/d.js")),
+        ["a", "d", "ns", "e"].map(CompactStr::new)
+    );
+}
+
+#[test]
+fn test_unknown_modules() {
+    let allocator = Allocator::default();
+    let semantics = build(
+        &allocator,
+        &[("a", "export { x } from 'pkg'; export * from 'other'; export const y = 1;")],
+    );
+    let mut project = ProjectSemantic::new();
+    project.add(&semantics[0]);
+
+    assert_eq!(project.resolve_export(Path::new("/a.js"), "x"), ExportResolution::Unknown);
+    assert_eq!(project.resolve_export(Path::new("/a.js"), "z"), ExportResolution::Unknown);
+    assert_eq!(project.resolve_export(Path::new("/a.js"), "y"), binding("/a.js", "y"));
+    assert_eq!(project.resolve_export(Path::new("/b.js"), "y"), ExportResolution::Unknown);
+}
+
+#[test]
+fn test_circular_reexports() {
+    let allocator = Allocator::default();
+    let semantics = build(
+        &allocator,
+        &[
+            ("a", "export * from './b'; export { x } from './b';"),
+            ("b", "export * from './a'; export { x as y } from './a';"),
+        ],
+    );
+    let mut project = ProjectSemantic::new();
+    for semantic in &semantics {
+        project.add(semantic);
+    }
+
+    assert_eq!(project.resolve_export(Path::new("/a.js"), "x"), ExportResolution::NotFound);
+    assert_eq!(project.resolve_export(Path::new("/a.js"), "z"), ExportResolution::NotFound);
+    assert_eq!(project.exported_names(Path::new("/a.js")), ["x", "y"].map(CompactStr::new));
+}
+
+#[test]
+fn test_definition() {
+    let allocator = Allocator::default();
+    let semantics = build(
+        &allocator,
+        &[
+            (
+                "main",
+                "import { foo, bar as baz } from './reexport';
+                import def, * as ns from './lib';
+                import { lib } from './reexport';
+                let local;",
+            ),
+            (
+                "reexport",
+                "import * as lib from './lib';
+                import d from './lib';
+                export { lib, d as bar };
+                export * from './lib';",
+            ),
+            ("lib", "export function foo() {} export default class Bar {}"),
+        ],
+    );
+    let mut project = ProjectSemantic::new();
+    for semantic in &semantics {
+        project.add(semantic);
+    }
+
+    let lib = Some((PathBuf::from("/lib.js"), "foo".to_string()));
+    assert_eq!(definition(&project, "/main.js", "foo"), lib);
+    let bar = Some((PathBuf::from("/lib.js"), "Bar".to_string()));
+    assert_eq!(definition(&project, "/main.js", "baz"), bar);
+    assert_eq!(definition(&project, "/main.js", "def"), bar);
+    assert_eq!(
+        definition(&project, "/main.js", "local"),
+        Some((PathBuf::from("/main.js"), "local".to_string()))
+    );
+
+    for name in ["ns", "lib"] {
+        let symbol_id = semantics[0].scopes().get_root_binding(name).unwrap();
+        assert_eq!(
+            project.definition(Path::new("/main.js"), symbol_id),
+            Some(BindingOrigin::Namespace { path: PathBuf::from("/lib.js") })
+        );
+    }
+
+    let local = semantics[0].scopes().get_root_binding("local").unwrap();
+    assert_eq!(project.resolve_import(Path::new("/main.js"), local), None);
+}
+
+#[test]
+fn test_unused_exports() {
+    let allocator = Allocator::default();
+    let semantics = build(
+        &allocator,
+        &[
+            (
+                "index",
+                "import { used } from './lib';
+                import { fromBarrel } from './barrel';
+                import * as ns from './ns';
+                import './polyfill';
+                import './pure';
+                export { reexported } from './lib';",
+            ),
+            (
+                "lib",
+                "export const used = 1; export const unused = 2; export const reexported = 3;
+                export default 4;",
+            ),
+            ("barrel", "export * from './deep'; export { a as renamed } from './ns';"),
+            ("deep", "export const fromBarrel = 1; export const deepUnused = 2;"),
+            ("ns", "export const a = 1; export const b = 2;"),
+            ("polyfill", "export const p = 1;"),
+            ("pure", "export const q = 1;"),
+            ("orphan", "export function orphan() {}"),
+        ],
+    );
+    let mut project = ProjectSemantic::new();
+    for semantic in &semantics {
+        project.add(semantic);
+    }
+
+    let options = UnusedExportsOptions {
+        entry_points: vec![PathBuf::from("/index.js")],
+        side_effects: Some(Arc::new(|path: &Path| path != Path::new("/pure.js"))),
+    };
+    let unused = project.unused_exports(&options);
+    let exports = unused
+        .exports
+        .iter()
+        .map(|export| (export.path.to_str().unwrap(), export.name.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        exports,
+        vec![
+            ("/barrel.js", "renamed"),
+            ("/deep.js", "deepUnused"),
+            ("/lib.js", "unused"),
+            ("/lib.js", "default"),
+            ("/orphan.js", "orphan"),
+            ("/polyfill.js", "p"),
+            ("/pure.js", "q"),
+        ]
+    );
+    assert_eq!(unused.files, vec![PathBuf::from("/orphan.js"), PathBuf::from("/pure.js")]);
+
+    // Without entry points, the exports of `index` are unused too and no file is reported.
+    let unused = project.unused_exports(&UnusedExportsOptions::default());
+    assert!(unused.exports.iter().any(|export| export.name.as_str() == "reexported"));
+    assert!(unused.exports.iter().all(|export| export.path != Path::new("/ns.js")));
+    assert!(unused.files.is_empty());
+}
@@ -0,0 +1,74 @@
+use oxc_semantic::{RenameError, Semantic, SymbolRename};
+use oxc_span::Span;
+
+use crate::util::SemanticTester;
+
+/// Rename the symbol declared or referenced at the first occurrence of `at` in `source_text`.
+fn rename(source_text: &'static str, at: &str, new_name: &str) -> Result<String, RenameError> {
+    let tester = SemanticTester::js(source_text);
+    let semantic = tester.build();
+    let symbol_id = symbol_at(&semantic, source_text, at);
+    let rename = SymbolRename::new(&semantic, symbol_id, new_name)?;
+    let mut output = source_text.to_string();
+    for edit in rename.edits.iter().rev() {
+        output.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.new_text);
+    }
+    Ok(output)
+}
+
+fn symbol_at(semantic: &Semantic<'_>, source_text: &str, at: &str) -> oxc_semantic::SymbolId {
+    let offset = u32::try_from(source_text.find(at).unwrap()).unwrap();
+    SymbolRename::symbol_at(semantic, offset).unwrap().0
+}
+
+#[test]
+fn test_rename() {
+    assert_eq!(
+        rename("let a = 1; a++; function f() { return a; }", "a", "b"),
+        Ok("let b = 1; b++; function f() { return b; }".to_string())
+    );
+    assert_eq!(
+        rename("function f(a) { return a; } a;", "a)", "b"),
+        Ok("function f(b) { return b; } a;".to_string())
+    );
+    assert_eq!(
+        rename("const { a } = obj; ({ a }); ({ a } = obj);", "a", "b"),
+        Ok("const { a: b } = obj; ({ a: b }); ({ a: b } = obj);".to_string())
+    );
+    assert_eq!(rename("var a; var a; a;", "a;", "b"), Ok("var b; var b; b;".to_string()));
+    // An offset right after an identifier, like a cursor at its end, is also in it.
+    assert_eq!(rename("let a = 1;", " = 1", "b"), Ok("let b = 1;".to_string()));
+}
+
+#[test]
+fn test_rename_errors() {
+    assert_eq!(rename("let a;", "a", "1b"), Err(RenameError::InvalidName));
+    assert_eq!(rename("let a;", "a", "class"), Err(RenameError::InvalidName));
+
+    assert_eq!(rename("import { a } from 'a'; a;", "a", "b"), Err(RenameError::CrossFile));
+    assert_eq!(rename("export const a = 1;", "a", "b"), Err(RenameError::CrossFile));
+    assert_eq!(rename("const a = 1; export { a };", "a", "b"), Err(RenameError::CrossFile));
+
+    // `b` is already declared.
+    assert_eq!(rename("let a, b;", "a", "b"), Err(RenameError::Conflict { span: Span::new(7, 8) }));
+    // The reference of `a` would resolve to the inner `b`.
+    assert_eq!(
+        rename("let a; { let b; a; }", "a", "b"),
+        Err(RenameError::Conflict { span: Span::new(13, 14) })
+    );
+    // The reference of the outer `b` would resolve to `a`.
+    assert_eq!(
+        rename("let b; { let a; b; }", "a", "b"),
+        Err(RenameError::Conflict { span: Span::new(16, 17) })
+    );
+    // The reference of the global `b` would resolve to `a`.
+    assert_eq!(
+        rename("function f() { let a; b; }", "a", "b"),
+        Err(RenameError::Conflict { span: Span::new(22, 23) })
+    );
+    // A reference resolved to a binding inside the scope of `a` is not affected.
+    assert_eq!(
+        rename("let a; { let b; b; } a;", "a", "b"),
+        Ok("let b; { let b; b; } b;".to_string())
+    );
+}
@@ -1,4 +1,4 @@
-use oxc_semantic::{Reference, SymbolFlags};
+use oxc_semantic::{Reference, SymbolDeclarationKind, SymbolFlags};
 
 use crate::util::SemanticTester;
 
@@ -490,3 +490,86 @@ fn test_class_merging() {
     .contains_flags(SymbolFlags::Interface)
     .test();
 }
+
+#[test]
+fn test_declaration_kind() {
+    let test = SemanticTester::ts(
+        "
+        import a from 'a';
+        import type b from 'b';
+        var c;
+        let d;
+        const e = 1;
+        function f(g) {
+            try {} catch (h) {}
+            { function i() {} }
+        }
+        class J {}
+        enum K { L }
+        const enum M {}
+        interface N<O> {}
+        type P = 1;
+        namespace Q {}
+    ",
+    );
+    let semantic = test.build();
+    let cases = [
+        ("a", SymbolDeclarationKind::Import),
+        ("b", SymbolDeclarationKind::TypeImport),
+        ("c", SymbolDeclarationKind::Var),
+        ("d", SymbolDeclarationKind::Let),
+        ("e", SymbolDeclarationKind::Const),
+        ("f", SymbolDeclarationKind::Function),
+        ("g", SymbolDeclarationKind::Var),
+        ("h", SymbolDeclarationKind::CatchParameter),
+        ("i", SymbolDeclarationKind::Function),
+        ("J", SymbolDeclarationKind::Class),
+        ("K", SymbolDeclarationKind::Enum),
+        ("L", SymbolDeclarationKind::EnumMember),
+        ("M", SymbolDeclarationKind::ConstEnum),
+        ("N", SymbolDeclarationKind::Interface),
+        ("O", SymbolDeclarationKind::TypeParameter),
+        ("P", SymbolDeclarationKind::TypeAlias),
+        ("Q", SymbolDeclarationKind::Namespace),
+    ];
+    for (name, kind) in cases {
+        let (_, symbol_id) = test.has_some_symbol(name).inner();
+        assert_eq!(semantic.symbols().get_declaration_kind(symbol_id), kind, "{name}");
+    }
+}
+
+#[test]
+fn test_type_only() {
+    let test = SemanticTester::ts(
+        "
+        import a from 'a';
+        import type b from 'b';
+        interface C {}
+        type D = 1;
+        namespace E {}
+        namespace F { export const x = 1 }
+        declare namespace G {}
+        class H {}
+        interface H {}
+        enum I {}
+        function j<K>() {}
+    ",
+    );
+    let semantic = test.build();
+    for (name, is_type_only) in [
+        ("a", false),
+        ("b", true),
+        ("C", true),
+        ("D", true),
+        ("E", true),
+        ("F", false),
+        ("G", true),
+        ("H", false),
+        ("I", false),
+        ("j", false),
+        ("K", true),
+    ] {
+        let (_, symbol_id) = test.has_some_symbol(name).inner();
+        assert_eq!(semantic.symbols().is_type_only(symbol_id), is_type_only, "{name}");
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::{ProjectSemantic, Semantic, SemanticBuilder, Type, TypeInference};
+use oxc_span::SourceType;
+
+use crate::util::SemanticTester;
+
+/// The inferred type of every root binding in `names`, as written in TypeScript.
+fn binding_types(semantic: &Semantic, types: &TypeInference, names: &[&str]) -> Vec<String> {
+    names
+        .iter()
+        .map(|name| {
+            let symbol_id = semantic.scopes().get_root_binding(name).unwrap();
+            types.symbol_type(symbol_id).to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn test_literals_and_operators() {
+    let tester = SemanticTester::ts(
+        "
+        const a = 1 + '';
+        const b = 1 - 2n;
+        const c = 2n ** 3n;
+        const d = `${a}`;
+        const e = !a;
+        const f = typeof a;
+        const g = a ?? 1;
+        const h = [1, 2];
+        const i = /re/;
+        const j = void 0;
+        const k = a || 1;
+        ",
+    );
+    let semantic = tester.build();
+    let types = TypeInference::new(&semantic);
+    assert_eq!(
+        binding_types(&semantic, &types, &["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"]),
+        vec![
+            "string",
+            "unknown",
+            "bigint",
+            "string",
+            "boolean",
+            "string",
+            "string | number",
+            "number[]",
+            "object",
+            "undefined",
+            "string | number"
+        ]
+    );
+}
+
+#[test]
+fn test_bindings() {
+    let tester = SemanticTester::ts(
+        "
+        let a = 1;
+        a = 2;
+        let b = 1;
+        const c: number | undefined = undefined;
+        const d: Promise<string> = load();
+        const e = d;
+        class F {}
+        const g: F = new F();
+        type H = string[];
+        const i: H = [];
+        interface J {}
+        ",
+    );
+    let semantic = tester.build();
+    let types = TypeInference::new(&semantic);
+    assert_eq!(
+        binding_types(&semantic, &types, &["a", "b", "c", "d", "e", "g", "H", "i", "J"]),
+        vec![
+            "unknown",
+            "number",
+            "number | undefined",
+            "Promise<string>",
+            "Promise<string>",
+            "object",
+            "string[]",
+            "string[]",
+            "object"
+        ]
+    );
+}
+
+#[test]
+fn test_functions_and_promises() {
+    let tester = SemanticTester::ts(
+        "
+        async function a() { return 1; }
+        const b = a();
+        const c = async () => 'c';
+        const d = () => b;
+        function e(flag: boolean) {
+            if (flag) return 1;
+            return 'e';
+        }
+        function f() {}
+        const g = fetch('/');
+        const h = Promise.resolve(1).then(() => 'h');
+        const i = new Promise<boolean>(() => {});
+        async function j() { return await b; }
+        ",
+    );
+    let semantic = tester.build();
+    let types = TypeInference::new(&semantic);
+    assert_eq!(
+        binding_types(&semantic, &types, &["a", "b", "c", "d", "e", "f", "g", "i", "j"]),
+        vec![
+            "() => Promise<number>",
+            "Promise<number>",
+            "() => Promise<string>",
+            "() => Promise<number>",
+            "() => number | string",
+            "() => undefined",
+            "Promise<object>",
+            "Promise<boolean>",
+            "() => Promise<number>"
+        ]
+    );
+    assert!(types.symbol_type(semantic.scopes().get_root_binding("h").unwrap()).is_promise_like());
+    assert!(types.symbol_type(semantic.scopes().get_root_binding("c").unwrap()).returns_promise());
+}
+
+#[test]
+fn test_recursion() {
+    let tester = SemanticTester::ts(
+        "
+        function a() { return b(); }
+        function b() { return a(); }
+        const c = c;
+        ",
+    );
+    let semantic = tester.build();
+    let types = TypeInference::new(&semantic);
+    assert_eq!(
+        binding_types(&semantic, &types, &["a", "b", "c"]),
+        vec!["() => unknown", "() => unknown", "unknown"]
+    );
+}
+
+/// Build the files at `/{name}.js`, linking `./{name}` specifiers to them.
+fn build<'a>(allocator: &'a Allocator, files: &[(&str, &'a str)]) -> Vec<Semantic<'a>> {
+    let mut module_records = vec![];
+    let semantics = files
+        .iter()
+        .map(|(name, source_text)| {
+            let path = PathBuf::from(format!("/{name}.js"));
+            let program = Parser::new(allocator, source_text, SourceType::mjs()).parse().program;
+            let builder = SemanticBuilder::new().build_module_record(&path, &program);
+            module_records.push(builder.module_record());
+            builder.build(&program).semantic
+        })
+        .collect::<Vec<_>>();
+
+    for module_record in &module_records {
+        for specifier in module_record.requested_modules.keys() {
+            let path = PathBuf::from(format!("/{}.js", specifier.trim_start_matches("./")));
+            if let Some(target) = module_records.iter().find(|m| m.resolved_absolute_path == path) {
+                module_record.loaded_modules.insert(specifier.clone(), Arc::clone(target));
+            }
+        }
+    }
+    semantics
+}
+
+#[test]
+fn test_imports() {
+    let allocator = Allocator::default();
+    let semantics = build(
+        &allocator,
+        &[
+            ("a", "export async function load() { return ''; } export const n = 1;"),
+            ("b", "export { load as fetchName } from './a'; export * as all from './a';"),
+            ("c", "import { fetchName, all } from './b'; import { n } from './a'; import x from './x';"),
+        ],
+    );
+    let mut project = ProjectSemantic::new();
+    for semantic in &semantics {
+        project.add(semantic);
+    }
+    let semantic = project.get(Path::new("/c.js")).unwrap();
+
+    let types = TypeInference::new(semantic).with_project(&project);
+    assert_eq!(
+        binding_types(semantic, &types, &["fetchName", "all", "n", "x"]),
+        vec!["() => Promise<string>", "object", "number", "unknown"]
+    );
+
+    let types = TypeInference::new(semantic);
+    assert_eq!(types.symbol_type(semantic.scopes().get_root_binding("n").unwrap()), Type::Unknown);
+}
@@ -77,6 +77,17 @@ impl ConcatSourceMapBuilder {
     }
 
     pub fn add_sourcemap(&mut self, sourcemap: &SourceMap, line_offset: u32) {
+        self.add_sourcemap_with_offset(sourcemap, line_offset, 0);
+    }
+
+    /// Add a sourcemap whose generated code starts at `column_offset` of line `line_offset`,
+    /// rather than at the start of the line.
+    pub fn add_sourcemap_with_offset(
+        &mut self,
+        sourcemap: &SourceMap,
+        line_offset: u32,
+        column_offset: u32,
+    ) {
         let source_offset = self.sources.len() as u32;
         let name_offset = self.names.len() as u32;
 
@@ -126,7 +137,11 @@ impl ConcatSourceMapBuilder {
         let tokens = sourcemap.get_tokens().map(|token| {
             Token::new(
                 token.get_dst_line() + line_offset,
-                token.get_dst_col(),
+                if token.get_dst_line() == 0 {
+                    token.get_dst_col() + column_offset
+                } else {
+                    token.get_dst_col()
+                },
                 token.get_src_line(),
                 token.get_src_col(),
                 token.get_source_id().map(|x| x + source_offset),
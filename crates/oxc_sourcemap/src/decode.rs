@@ -3,7 +3,7 @@
 use std::sync::Arc;
 
 use crate::error::{Error, Result};
-use crate::{SourceMap, Token};
+use crate::{IndexSourceMap, SourceMap, Token};
 
 /// See <https://github.com/tc39/source-map/blob/main/source-map-rev3.md>.
 #[derive(serde::Deserialize, Default)]
@@ -12,22 +12,46 @@ pub struct JSONSourceMap {
     /// An optional name of the generated code that this source map is associated with.
     pub file: Option<String>,
     /// A string with the encoded mapping data.
+    #[serde(default)]
     pub mappings: String,
     /// An optional source root, useful for relocating source files on a server or removing repeated values in the “sources” entry.
     /// This value is prepended to the individual entries in the “source” field.
     pub source_root: Option<String>,
     /// A list of original sources used by the “mappings” entry.
+    #[serde(default)]
     pub sources: Vec<String>,
     /// An optional list of source content, useful when the “source” can’t be hosted.
     /// The contents are listed in the same order as the sources in line 5. “null” may be used if some original sources should be retrieved by name.
     pub sources_content: Option<Vec<Option<String>>>,
     /// A list of symbol names used by the “mappings” entry.
+    #[serde(default)]
     pub names: Vec<String>,
     /// An optional field containing the debugId for this sourcemap.
     pub debug_id: Option<String>,
+    /// The sections of an index map, which take the place of the other fields except `file`.
+    pub sections: Option<Vec<JSONSourceMapSection>>,
+}
+
+/// A section of an index map, see <https://tc39.es/source-map/#index-map>.
+#[derive(serde::Deserialize, Default)]
+pub struct JSONSourceMapSection {
+    /// The position in the generated code where the section starts.
+    pub offset: JSONSourceMapOffset,
+    /// The source map of the section.
+    pub map: JSONSourceMap,
+}
+
+/// A zero-based position in the generated code.
+#[derive(serde::Deserialize, Default)]
+pub struct JSONSourceMapOffset {
+    pub line: u32,
+    pub column: u32,
 }
 
 pub fn decode(json: JSONSourceMap) -> Result<SourceMap> {
+    if json.sections.is_some() {
+        return IndexSourceMap::from_json(json).map(|index_map| index_map.to_sourcemap());
+    }
     let tokens = decode_mapping(&json.mappings, json.names.len(), json.sources.len())?;
     Ok(SourceMap {
         file: json.file.map(Arc::from),
@@ -22,6 +22,7 @@ pub fn encode(sourcemap: &SourceMap) -> JSONSourceMap {
             .map(|x| x.iter().map(ToString::to_string).map(Some).collect()),
         names: sourcemap.names.iter().map(ToString::to_string).collect(),
         debug_id: sourcemap.get_debug_id().map(ToString::to_string),
+        sections: None,
     }
 }
 
@@ -344,7 +345,7 @@ impl<'a> PreAllocatedString<'a> {
     }
 }
 
-fn escape_json_string<S: AsRef<str>>(s: S) -> String {
+pub(crate) fn escape_json_string<S: AsRef<str>>(s: S) -> String {
     let s = s.as_ref();
     let mut escaped_buf = Vec::with_capacity(s.len() * 2 + 2);
     // This call is infallible as only error it can return is if the writer errors.
@@ -16,6 +16,8 @@ pub enum Error {
     BadSourceReference(u32),
     /// a reference to a non existing name was encountered
     BadNameReference(u32),
+    /// a section of an index map started before the previous section
+    BadSectionOffset(u32, u32),
 }
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -33,6 +35,9 @@ impl fmt::Display for Error {
             Error::BadNameReference(idx) => {
                 write!(f, "Reference to non-existing name at position {idx}")
             }
+            Error::BadSectionOffset(line, column) => {
+                write!(f, "Section at {line}:{column} starts before the previous section")
+            }
         }
     }
 }
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use crate::{
+    decode::{decode, JSONSourceMap},
+    encode::escape_json_string,
+    error::{Error, Result},
+    ConcatSourceMapBuilder, SourceMap,
+};
+
+/// A section of an [`IndexSourceMap`], the map of the generated code starting at `line` and
+/// `column`.
+#[derive(Debug, Clone)]
+pub struct SourceMapSection {
+    pub(crate) line: u32,
+    pub(crate) column: u32,
+    pub(crate) map: SourceMap,
+}
+
+impl SourceMapSection {
+    pub fn new(line: u32, column: u32, map: SourceMap) -> Self {
+        Self { line, column, map }
+    }
+
+    pub fn get_line(&self) -> u32 {
+        self.line
+    }
+
+    pub fn get_column(&self) -> u32 {
+        self.column
+    }
+
+    pub fn get_map(&self) -> &SourceMap {
+        &self.map
+    }
+}
+
+/// An index map, made of sections which each hold the map of a part of the generated code.
+///
+/// Writing an index map embeds the map of each section as is, so concatenated chunks can get a
+/// single map without their mappings being rewritten. See <https://tc39.es/source-map/#index-map>.
+#[derive(Debug, Clone, Default)]
+pub struct IndexSourceMap {
+    pub(crate) file: Option<Arc<str>>,
+    pub(crate) sections: Vec<SourceMapSection>,
+}
+
+impl IndexSourceMap {
+    pub fn new(file: Option<Arc<str>>, sections: Vec<SourceMapSection>) -> Self {
+        Self { file, sections }
+    }
+
+    /// Convert the index map to `IndexSourceMap`. A regular map becomes a single section.
+    /// # Errors
+    ///
+    /// The decoding Error of a section, or [`Error::BadSectionOffset`] if the sections are not
+    /// in order.
+    pub fn from_json(value: JSONSourceMap) -> Result<Self> {
+        let Some(json_sections) = value.sections else {
+            let file = value.file.clone().map(Arc::from);
+            return Ok(Self::new(file, vec![SourceMapSection::new(0, 0, decode(value)?)]));
+        };
+        let mut sections: Vec<SourceMapSection> = Vec::with_capacity(json_sections.len());
+        for section in json_sections {
+            let (line, column) = (section.offset.line, section.offset.column);
+            if sections.last().is_some_and(|last| (line, column) < (last.line, last.column)) {
+                return Err(Error::BadSectionOffset(line, column));
+            }
+            sections.push(SourceMapSection::new(line, column, decode(section.map)?));
+        }
+        Ok(Self::new(value.file.map(Arc::from), sections))
+    }
+
+    /// Convert the index map string to `IndexSourceMap`.
+    /// # Errors
+    ///
+    /// The `serde_json` deserialize Error, or the errors of [`IndexSourceMap::from_json`].
+    pub fn from_json_string(value: &str) -> Result<Self> {
+        Self::from_json(serde_json::from_str(value)?)
+    }
+
+    /// Convert `IndexSourceMap` to an index map string.
+    pub fn to_json_string(&self) -> String {
+        let mut json = String::from("{\"version\":3,");
+        if let Some(file) = &self.file {
+            json.push_str("\"file\":");
+            json.push_str(&escape_json_string(file));
+            json.push(',');
+        }
+        json.push_str("\"sections\":[");
+        for (i, section) in self.sections.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!(
+                "{{\"offset\":{{\"line\":{},\"column\":{}}},\"map\":{}}}",
+                section.line,
+                section.column,
+                section.map.to_json_string()
+            ));
+        }
+        json.push_str("]}");
+        json
+    }
+
+    pub fn get_file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn set_file(&mut self, file: &str) {
+        self.file = Some(file.into());
+    }
+
+    /// Add a section. Sections must be added in the order of the generated code.
+    pub fn add_section(&mut self, line: u32, column: u32, map: SourceMap) {
+        self.sections.push(SourceMapSection::new(line, column, map));
+    }
+
+    pub fn get_sections(&self) -> impl Iterator<Item = &SourceMapSection> {
+        self.sections.iter()
+    }
+
+    /// Flatten the sections into a single `SourceMap`.
+    pub fn to_sourcemap(&self) -> SourceMap {
+        let mut builder = ConcatSourceMapBuilder::default();
+        for section in &self.sections {
+            builder.add_sourcemap_with_offset(&section.map, section.line, section.column);
+        }
+        let mut sourcemap = builder.into_sourcemap();
+        sourcemap.file.clone_from(&self.file);
+        sourcemap
+    }
+}
+
+#[test]
+fn test_index_sourcemap() {
+    let input = r#"{
+        "version": 3,
+        "file": "bundle.js",
+        "sections": [
+            {
+                "offset": { "line": 0, "column": 0 },
+                "map": { "version": 3, "sources": ["a.js"], "names": ["a"], "mappings": "AAAAA" }
+            },
+            {
+                "offset": { "line": 1, "column": 4 },
+                "map": { "version": 3, "sources": ["b.js"], "names": [], "mappings": "AAAA;AACA" }
+            }
+        ]
+    }"#;
+    let index_map = IndexSourceMap::from_json_string(input).unwrap();
+    assert_eq!(index_map.get_file(), Some("bundle.js"));
+    assert_eq!(index_map.get_sections().count(), 2);
+
+    let sm = index_map.to_sourcemap();
+    assert_eq!(sm.get_file(), Some("bundle.js"));
+    let view_tokens = sm.get_source_view_tokens().collect::<Vec<_>>();
+    let tokens = view_tokens
+        .iter()
+        .map(|token| (token.get_dst_line(), token.get_dst_col(), token.to_tuple()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        vec![
+            (0, 0, (Some("a.js"), 0, 0, Some("a"))),
+            (1, 4, (Some("b.js"), 0, 0, None)),
+            (2, 0, (Some("b.js"), 1, 0, None)),
+        ]
+    );
+
+    // `SourceMap` reads index maps by flattening them.
+    let flattened = SourceMap::from_json_string(input).unwrap();
+    assert_eq!(flattened.to_json_string(), sm.to_json_string());
+
+    // Sections are written as they are.
+    let output = index_map.to_json_string();
+    assert_eq!(
+        output,
+        r#"{"version":3,"file":"bundle.js","sections":[{"offset":{"line":0,"column":0},"map":{"version":3,"names":["a"],"sources":["a.js"],"mappings":"AAAAA"}},{"offset":{"line":1,"column":4},"map":{"version":3,"names":[],"sources":["b.js"],"mappings":"AAAA;AACA"}}]}"#
+    );
+    assert_eq!(IndexSourceMap::from_json_string(&output).unwrap().to_sourcemap().tokens, sm.tokens);
+}
+
+#[test]
+fn test_index_sourcemap_bad_section_offset() {
+    let input = r#"{
+        "version": 3,
+        "sections": [
+            { "offset": { "line": 1, "column": 0 }, "map": { "version": 3, "sources": [], "names": [], "mappings": "" } },
+            { "offset": { "line": 0, "column": 0 }, "map": { "version": 3, "sources": [], "names": [], "mappings": "" } }
+        ]
+    }"#;
+    assert!(matches!(IndexSourceMap::from_json_string(input), Err(Error::BadSectionOffset(0, 0))));
+}
@@ -3,15 +3,19 @@ mod concat_sourcemap_builder;
 mod decode;
 mod encode;
 mod error;
+mod index_sourcemap;
 mod sourcemap;
 mod sourcemap_builder;
+mod sourcemap_consumer;
 mod sourcemap_visualizer;
 mod token;
 
 pub use concat_sourcemap_builder::ConcatSourceMapBuilder;
-pub use decode::JSONSourceMap;
+pub use decode::{JSONSourceMap, JSONSourceMapOffset, JSONSourceMapSection};
 pub use error::Error;
+pub use index_sourcemap::{IndexSourceMap, SourceMapSection};
 pub use sourcemap::SourceMap;
 pub use sourcemap_builder::SourceMapBuilder;
+pub use sourcemap_consumer::{OriginalPosition, SourceMapConsumer};
 pub use sourcemap_visualizer::SourcemapVisualizer;
 pub use token::{SourceViewToken, Token, TokenChunk};
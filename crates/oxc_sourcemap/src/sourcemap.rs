@@ -5,7 +5,7 @@ use crate::{
     encode::{encode, encode_to_string},
     error::Result,
     token::{Token, TokenChunk},
-    SourceViewToken,
+    SourceMapBuilder, SourceViewToken,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -92,6 +92,10 @@ impl SourceMap {
         self.source_root.as_deref()
     }
 
+    pub fn set_source_root(&mut self, source_root: &str) {
+        self.source_root = Some(source_root.into());
+    }
+
     /// Set `x_google_ignoreList`.
     pub fn set_x_google_ignore_list(&mut self, x_google_ignore_list: Vec<u32>) {
         self.x_google_ignore_list = Some(x_google_ignore_list);
@@ -127,6 +131,11 @@ impl SourceMap {
         self.source_contents.as_ref().map(|v| v.iter().map(AsRef::as_ref))
     }
 
+    /// Remove `source_content`, e.g. when the consumer can load the sources itself.
+    pub fn remove_source_contents(&mut self) {
+        self.source_contents = None;
+    }
+
     pub fn get_token(&self, index: u32) -> Option<&Token> {
         self.tokens.get(index as usize)
     }
@@ -195,6 +204,116 @@ impl SourceMap {
     ) -> Option<SourceViewToken<'_>> {
         self.lookup_token(lookup_table, line, col).map(|token| SourceViewToken::new(token, self))
     }
+
+    /// Chain this map onto `input`, the map of the code this map was generated from, e.g. the map
+    /// emitted by a previous build step.
+    ///
+    /// The result maps this map's generated code straight back to the sources of `input`. Tokens
+    /// without a counterpart on the same line of `input` are dropped. Names recorded in `input`
+    /// take precedence over names recorded in this map.
+    pub fn remap(&self, input: &SourceMap) -> SourceMap {
+        let mut sourcemap = self.remap_sources(|_| Some(input));
+        sourcemap.source_root.clone_from(&input.source_root);
+        sourcemap
+    }
+
+    /// Chain this map onto the maps of its sources, e.g. a map of concatenated files onto the
+    /// maps of each file.
+    ///
+    /// `load` is called with each source of this map, and returns the map of the code of that
+    /// source, if there is one. Tokens of sources with a map are remapped as in
+    /// [`SourceMap::remap`], and tokens of other sources are kept as they are. The `sourceRoot`s
+    /// of the loaded maps are not applied to their sources.
+    ///
+    /// `sourcesContent` is taken from the map each source ends up in. It is omitted when none of
+    /// the sources have content.
+    pub fn remap_sources<'m, F>(&self, mut load: F) -> SourceMap
+    where
+        F: FnMut(&str) -> Option<&'m SourceMap>,
+    {
+        let inputs = self
+            .sources
+            .iter()
+            .map(|source| load(source).map(|input| (input, input.generate_lookup_table())))
+            .collect::<Vec<_>>();
+
+        let mut builder = SourceMapBuilder::default();
+        if let Some(file) = &self.file {
+            builder.set_file(file);
+        }
+        let mut has_contents = false;
+        let mut add_source = |builder: &mut SourceMapBuilder, sourcemap: &SourceMap, id: u32| {
+            let source = sourcemap.get_source(id)?;
+            let content = sourcemap.get_source_content(id);
+            has_contents |= content.is_some();
+            Some(builder.add_source_and_content(source, content.unwrap_or_default()))
+        };
+
+        for token in &self.tokens {
+            let Some(source_id) = token.source_id else {
+                continue;
+            };
+            let name = token.name_id.and_then(|id| self.get_name(id));
+            match inputs.get(source_id as usize) {
+                Some(Some((input, lookup_table))) => {
+                    let Some(original) =
+                        input.lookup_token(lookup_table, token.src_line, token.src_col)
+                    else {
+                        continue;
+                    };
+                    if original.dst_line != token.src_line {
+                        continue;
+                    }
+                    let Some(source_id) =
+                        original.source_id.and_then(|id| add_source(&mut builder, input, id))
+                    else {
+                        continue;
+                    };
+                    let name = original.name_id.and_then(|id| input.get_name(id)).or(name);
+                    let name_id = name.map(|name| builder.add_name(name));
+                    builder.add_token(
+                        token.dst_line,
+                        token.dst_col,
+                        original.src_line,
+                        original.src_col,
+                        Some(source_id),
+                        name_id,
+                    );
+                }
+                Some(None) => {
+                    let Some(source_id) = add_source(&mut builder, self, source_id) else {
+                        continue;
+                    };
+                    let name_id = name.map(|name| builder.add_name(name));
+                    builder.add_token(
+                        token.dst_line,
+                        token.dst_col,
+                        token.src_line,
+                        token.src_col,
+                        Some(source_id),
+                        name_id,
+                    );
+                }
+                None => {}
+            }
+        }
+
+        let mut sourcemap = builder.into_sourcemap();
+        sourcemap.source_root.clone_from(&self.source_root);
+        if !has_contents {
+            sourcemap.remove_source_contents();
+        }
+        sourcemap
+    }
+
+    /// Merge the maps of a chain of build steps, e.g. transform and then minify, into a single
+    /// map from the output of the last step to the sources of the first.
+    ///
+    /// `maps` are in the order of the build steps. Returns `None` if `maps` is empty.
+    pub fn merge(maps: &[&SourceMap]) -> Option<SourceMap> {
+        let (first, rest) = maps.split_first()?;
+        Some(rest.iter().fold((*first).clone(), |merged, sourcemap| sourcemap.remap(&merged)))
+    }
 }
 
 fn greatest_lower_bound<'a, T, K: Ord, F: Fn(&'a T) -> K>(
@@ -280,10 +399,132 @@ fn test_sourcemap_source_view_token() {
 fn test_mut_sourcemap() {
     let mut sm = SourceMap::default();
     sm.set_file("index.js");
+    sm.set_source_root("src/");
     sm.set_sources(vec!["foo.js"]);
     sm.set_source_contents(vec!["foo"]);
 
     assert_eq!(sm.get_file(), Some("index.js"));
+    assert_eq!(sm.get_source_root(), Some("src/"));
     assert_eq!(sm.get_source(0), Some("foo.js"));
     assert_eq!(sm.get_source_content(0), Some("foo"));
+
+    sm.remove_source_contents();
+    assert_eq!(sm.get_source_content(0), None);
+}
+
+#[test]
+fn test_remap() {
+    // `a.ts` -> `a.js`: `let foo = 1;` on line 1 becomes `var foo = 1;` on line 0.
+    let input = SourceMap::new(
+        None,
+        vec!["foo".into()],
+        None,
+        vec!["a.ts".into()],
+        Some(vec!["\nlet foo = 1;".into()]),
+        vec![Token::new(0, 0, 1, 0, Some(0), None), Token::new(0, 4, 1, 4, Some(0), Some(0))],
+        None,
+    );
+    // `a.js` -> `a.min.js`: `var foo = 1;` becomes `var a=1;`, the second token has no
+    // counterpart on line 1 of `a.js`.
+    let sm = SourceMap::new(
+        Some("a.min.js".into()),
+        vec!["foo".into()],
+        None,
+        vec!["a.js".into()],
+        None,
+        vec![
+            Token::new(0, 0, 0, 0, Some(0), None),
+            Token::new(0, 4, 0, 4, Some(0), Some(0)),
+            Token::new(0, 8, 1, 0, Some(0), None),
+        ],
+        None,
+    );
+    let remapped = sm.remap(&input);
+    assert_eq!(remapped.get_file(), Some("a.min.js"));
+    assert_eq!(remapped.get_source_and_content(0), Some(("a.ts", "\nlet foo = 1;")));
+    assert_eq!(remapped.get_source_view_token(0).unwrap().to_tuple(), (Some("a.ts"), 1, 0, None));
+    assert_eq!(
+        remapped.get_source_view_token(1).unwrap().to_tuple(),
+        (Some("a.ts"), 1, 4, Some("foo"))
+    );
+    assert!(remapped.get_token(2).is_none());
+}
+
+#[test]
+fn test_remap_sources() {
+    // `a.js` was compiled from `a.ts`, `b.js` has no map.
+    let a = SourceMap::new(
+        None,
+        vec![],
+        None,
+        vec!["a.ts".into()],
+        Some(vec!["\nlet a;".into()]),
+        vec![Token::new(0, 0, 1, 0, Some(0), None)],
+        None,
+    );
+    // `a.js` and `b.js` concatenated.
+    let sm = SourceMap::new(
+        Some("bundle.js".into()),
+        vec!["b".into()],
+        None,
+        vec!["a.js".into(), "b.js".into()],
+        None,
+        vec![Token::new(0, 0, 0, 0, Some(0), None), Token::new(1, 0, 0, 0, Some(1), Some(0))],
+        None,
+    );
+    let remapped = sm.remap_sources(|source| (source == "a.js").then_some(&a));
+    assert_eq!(remapped.get_sources().collect::<Vec<_>>(), vec!["a.ts", "b.js"]);
+    assert_eq!(remapped.get_source_content(0), Some("\nlet a;"));
+    assert_eq!(remapped.get_source_content(1), Some(""));
+    assert_eq!(remapped.get_source_view_token(0).unwrap().to_tuple(), (Some("a.ts"), 1, 0, None));
+    assert_eq!(
+        remapped.get_source_view_token(1).unwrap().to_tuple(),
+        (Some("b.js"), 0, 0, Some("b"))
+    );
+}
+
+#[test]
+fn test_merge() {
+    assert!(SourceMap::merge(&[]).is_none());
+
+    // `a.ts` -> `a.js` -> `a.min.js` -> `a.min.js` with a banner line.
+    let transform = SourceMap::new(
+        None,
+        vec![],
+        None,
+        vec!["a.ts".into()],
+        None,
+        vec![Token::new(0, 0, 2, 2, Some(0), None)],
+        None,
+    );
+    let minify = SourceMap::new(
+        None,
+        vec!["foo".into()],
+        None,
+        vec!["a.js".into()],
+        None,
+        vec![Token::new(0, 1, 0, 0, Some(0), Some(0))],
+        None,
+    );
+    let banner = SourceMap::new(
+        Some("a.min.js".into()),
+        vec![],
+        None,
+        vec!["a.min.js".into()],
+        None,
+        vec![Token::new(1, 1, 0, 1, Some(0), None)],
+        None,
+    );
+    let merged = SourceMap::merge(&[&transform, &minify, &banner]).unwrap();
+    assert_eq!(merged.get_file(), Some("a.min.js"));
+    assert_eq!(
+        merged.get_source_view_token(0).unwrap().to_tuple(),
+        (Some("a.ts"), 2, 2, Some("foo"))
+    );
+    assert_eq!(merged.get_token(0).unwrap().get_dst_line(), 1);
+    assert!(merged.get_source_contents().is_none());
+    assert_eq!(
+        merged.to_json_string(),
+        r#"{"version":3,"file":"a.min.js","names":["foo"],"sources":["a.ts"],"mappings":";CAEEA"}"#
+    );
 }
@@ -0,0 +1,83 @@
+use crate::SourceMap;
+
+/// An original position, see [`SourceMapConsumer::original_position_for`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OriginalPosition<'a> {
+    pub source: &'a str,
+    pub source_content: Option<&'a str>,
+    /// Zero-based line in `source`.
+    pub line: u32,
+    /// Zero-based column in `source`.
+    pub column: u32,
+    pub name: Option<&'a str>,
+}
+
+/// The `SourceMapConsumer` looks up the original positions of generated positions, e.g. to map
+/// the positions of a stack trace back to the sources.
+///
+/// The lookup table of the sourcemap is built once, so repeated lookups only binary search it.
+#[derive(Debug)]
+pub struct SourceMapConsumer<'a> {
+    sourcemap: &'a SourceMap,
+    lookup_table: Vec<(u32, u32, u32)>,
+}
+
+impl<'a> SourceMapConsumer<'a> {
+    pub fn new(sourcemap: &'a SourceMap) -> Self {
+        Self { sourcemap, lookup_table: sourcemap.generate_lookup_table() }
+    }
+
+    pub fn get_sourcemap(&self) -> &'a SourceMap {
+        self.sourcemap
+    }
+
+    /// Find the original position of the zero-based `line` and `column` in the generated code.
+    ///
+    /// The closest token at or before `column` on `line` is used. Returns `None` if there is no
+    /// such token, or if it doesn't map to a source.
+    pub fn original_position_for(&self, line: u32, column: u32) -> Option<OriginalPosition<'a>> {
+        let token = self.sourcemap.lookup_token(&self.lookup_table, line, column)?;
+        if token.dst_line != line {
+            return None;
+        }
+        let source_id = token.source_id?;
+        Some(OriginalPosition {
+            source: self.sourcemap.get_source(source_id)?,
+            source_content: self.sourcemap.get_source_content(source_id),
+            line: token.src_line,
+            column: token.src_col,
+            name: token.name_id.and_then(|id| self.sourcemap.get_name(id)),
+        })
+    }
+}
+
+#[test]
+fn test_original_position_for() {
+    let input = r#"{
+        "version": 3,
+        "sources": ["coolstuff.js"],
+        "sourcesContent": ["var x = 1;"],
+        "names": ["x","alert"],
+        "mappings": "AAAA,GAAIA,GAAI,EACR,IAAIA,GAAK,EAAG,CACVC,MAAM;;A"
+    }"#;
+    let sm = SourceMap::from_json_string(input).unwrap();
+    let consumer = SourceMapConsumer::new(&sm);
+
+    assert_eq!(
+        consumer.original_position_for(0, 3),
+        Some(OriginalPosition {
+            source: "coolstuff.js",
+            source_content: Some("var x = 1;"),
+            line: 0,
+            column: 4,
+            name: Some("x"),
+        })
+    );
+    // Between tokens, the token before is used.
+    let position = consumer.original_position_for(0, 5).unwrap();
+    assert_eq!((position.line, position.column, position.name), (0, 4, Some("x")));
+    // No token on the line.
+    assert_eq!(consumer.original_position_for(1, 0), None);
+    // Token without a source.
+    assert_eq!(consumer.original_position_for(2, 0), None);
+}
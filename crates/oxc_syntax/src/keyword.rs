@@ -23,6 +23,17 @@ pub fn is_reserved_keyword(s: &str) -> bool {
     RESERVED_KEYWORDS.contains(s)
 }
 
+/// Checks if the given string is a reserved word in ES3, which engines predating ES5 (e.g. IE 8)
+/// do not allow as a property name in dot notation or as an unquoted property key, e.g. `default`
+/// in `a.default`.
+///
+/// These are the [reserved keywords](is_reserved_keyword) and the [future reserved words of
+/// ES3](ES3_RESERVED_WORDS).
+#[inline]
+pub fn is_es3_reserved_word(s: &str) -> bool {
+    is_reserved_keyword(s) || ES3_RESERVED_WORDS.contains(s)
+}
+
 /// Checks `Infinity`, `NaN`, `globalThis` and `undefined`
 #[inline]
 pub fn is_global_object(s: &str) -> bool {
@@ -93,3 +104,25 @@ pub const RESERVED_KEYWORDS: Set<&'static str> = phf_set! {
     "with",
     "yield",
 };
+
+/// Future reserved words of ES3 which are no longer reserved since ES5.
+///
+/// Reference: <https://www-archive.mozilla.org/js/language/E262-3.pdf> (7.5.3 Future Reserved Words)
+pub const ES3_RESERVED_WORDS: Set<&'static str> = phf_set! {
+    "abstract",
+    "boolean",
+    "byte",
+    "char",
+    "double",
+    "final",
+    "float",
+    "goto",
+    "int",
+    "long",
+    "native",
+    "short",
+    "synchronized",
+    "throws",
+    "transient",
+    "volatile",
+};
@@ -3,7 +3,7 @@
 
 use std::{fmt, hash::BuildHasherDefault, path::PathBuf, sync::Arc};
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use oxc_span::{CompactStr, Span};
 use rustc_hash::{FxHashMap, FxHasher};
 
@@ -43,6 +43,12 @@ pub struct ModuleRecord {
     /// [`ModuleRecord`] is created. You must link the module records yourself.
     pub loaded_modules: DashMap<CompactStr, Arc<ModuleRecord>, BuildHasherDefault<FxHasher>>,
 
+    /// Specifiers of [`Self::requested_modules`] that failed to resolve.
+    ///
+    /// Like [`Self::loaded_modules`], this set is filled in by the caller which resolves the
+    /// requested modules.
+    pub unresolved_modules: DashSet<CompactStr, BuildHasherDefault<FxHasher>>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of ImportEntry records derived from the code of this module
@@ -84,6 +90,16 @@ pub struct ModuleRecord {
 
     /// Duplicated span of `export default` for diagnostics
     pub export_default_duplicated: Vec<Span>,
+
+    /// Deprecation message of this module, from a JSDoc comment with a `@module` and a
+    /// `@deprecated` tag
+    pub deprecated: Option<CompactStr>,
+
+    /// Deprecation messages of the local exports whose declaration has a JSDoc comment with a
+    /// `@deprecated` tag, keyed by export name.
+    ///
+    /// The message is empty for a `@deprecated` tag without description.
+    pub deprecated_exports: FxHashMap<CompactStr, CompactStr>,
 }
 
 impl ModuleRecord {
@@ -102,11 +118,19 @@ impl fmt::Debug for ModuleRecord {
             .reduce(|acc, key| format!("{acc}, {key}"))
             .unwrap_or_default();
         let loaded_modules = format!("{{ {loaded_modules} }}");
+        let unresolved_modules = self
+            .unresolved_modules
+            .iter()
+            .map(|key| key.key().to_string())
+            .reduce(|acc, key| format!("{acc}, {key}"))
+            .unwrap_or_default();
+        let unresolved_modules = format!("{{ {unresolved_modules} }}");
         f.debug_struct("ModuleRecord")
             .field("not_esm", &self.not_esm)
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
+            .field("unresolved_modules", &unresolved_modules)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -116,6 +140,8 @@ impl fmt::Debug for ModuleRecord {
             .field("exported_bindings_from_star_export", &self.exported_bindings_from_star_export)
             .field("export_default", &self.export_default)
             .field("export_default_duplicated", &self.export_default_duplicated)
+            .field("deprecated", &self.deprecated)
+            .field("deprecated_exports", &self.deprecated_exports)
             .finish()
     }
 }
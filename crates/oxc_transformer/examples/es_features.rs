@@ -0,0 +1,60 @@
+#![allow(clippy::print_stdout)]
+use std::{path::Path, str::FromStr};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::{ESFeatureAnalyzer, ESTarget};
+use pico_args::Arguments;
+
+// Instruction:
+// create a `test.js`,
+// run `just example es_features` or `just example es_features -- --target es2017`
+//
+// With `--target`, only the features which are newer than the target are listed.
+
+fn main() {
+    let mut args = Arguments::from_env();
+    let target: Option<String> = args.opt_value_from_str("--target").unwrap_or(None);
+    let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
+
+    let target = target.map(|target| ESTarget::from_str(&target).unwrap());
+    let path = Path::new(&name);
+    let source_text =
+        std::fs::read_to_string(path).unwrap_or_else(|err| panic!("{name} not found.\n{err}"));
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(path).unwrap();
+
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+
+    if !ret.errors.is_empty() {
+        println!("Parser Errors:");
+        for error in &ret.errors {
+            let error = error.clone().with_source_code(source_text.clone());
+            println!("{error:?}");
+        }
+    }
+
+    let ret = ESFeatureAnalyzer::new(&allocator).build(&ret.program);
+
+    if !ret.errors.is_empty() {
+        println!("RegExp Errors:");
+        for error in &ret.errors {
+            let error = error.clone().with_source_code(source_text.clone());
+            println!("{error:?}");
+        }
+    }
+
+    for usage in &ret.features {
+        let es_target = usage.feature.es_target();
+        if target.is_some_and(|target| es_target <= target) {
+            continue;
+        }
+        let before = &source_text[..usage.span.start as usize];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+        println!("{name}:{line}:{column} {:?} ({es_target})", usage.feature);
+    }
+
+    println!("Requires {}", ret.es_target());
+}
@@ -217,6 +217,7 @@ impl<'a> ModuleImportsStore<'a> {
             ctx.ast.string_literal(SPAN, source),
             NONE,
             ImportOrExportKind::Value,
+            None,
         ))
     }
 
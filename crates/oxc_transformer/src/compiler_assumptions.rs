@@ -85,7 +85,6 @@ pub struct CompilerAssumptions {
     pub set_computed_properties: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_public_class_fields: bool,
 
     #[serde(default)]
@@ -4,7 +4,9 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use oxc_ast::{ast::Expression, AstBuilder};
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
 use oxc_span::SourceType;
 
 use crate::{
@@ -13,7 +15,7 @@ use crate::{
         statement_injector::StatementInjectorStore, top_level_statements::TopLevelStatementsStore,
         var_declarations::VarDeclarationsStore,
     },
-    Module, TransformOptions,
+    CompilerAssumptions, Module, TransformOptions,
 };
 
 pub struct TransformCtx<'a> {
@@ -25,12 +27,23 @@ pub struct TransformCtx<'a> {
     /// Source path in the form of `<CWD>/path/to/file/input.js`
     pub source_path: PathBuf,
 
+    /// See [`TransformOptions::filename_expression`]
+    pub filename_expression: Option<String>,
+
+    /// See [`TransformOptions::module_id_expression`]
+    pub module_id_expression: Option<String>,
+
     pub source_type: SourceType,
 
     pub source_text: &'a str,
 
     pub module: Module,
 
+    pub assumptions: CompilerAssumptions,
+
+    /// See [`TransformOptions::keep_class_names`]
+    pub keep_class_names: bool,
+
     // Helpers
     /// Manage helper loading
     pub helper_loader: HelperLoaderStore<'a>,
@@ -58,9 +71,13 @@ impl<'a> TransformCtx<'a> {
             errors: RefCell::new(vec![]),
             filename,
             source_path,
+            filename_expression: options.filename_expression.clone(),
+            module_id_expression: options.module_id_expression.clone(),
             source_type: SourceType::default(),
             source_text: "",
             module: options.env.module,
+            assumptions: options.assumptions,
+            keep_class_names: options.keep_class_names,
             helper_loader: HelperLoaderStore::new(&options.helper_loader),
             module_imports: ModuleImportsStore::new(),
             var_declarations: VarDeclarationsStore::new(),
@@ -77,4 +94,22 @@ impl<'a> TransformCtx<'a> {
     pub fn error(&self, error: OxcDiagnostic) {
         self.errors.borrow_mut().push(error);
     }
+
+    /// Parse an expression given in the options, e.g. [`TransformOptions::filename_expression`].
+    ///
+    /// Syntax errors are reported, and `None` is returned.
+    pub fn parse_option_expression(
+        &self,
+        source_text: &str,
+        ast: AstBuilder<'a>,
+    ) -> Option<Expression<'a>> {
+        let source_text = ast.allocator.alloc_str(source_text);
+        match Parser::new(ast.allocator, source_text, SourceType::mjs()).parse_expression() {
+            Ok(expr) => Some(expr),
+            Err(errors) => {
+                self.errors.borrow_mut().extend(errors);
+                None
+            }
+        }
+    }
 }
@@ -53,6 +53,47 @@
 //!
 //! WORK IN PROGRESS. INCOMPLETE.
 //!
+//! Only the `setPublicClassFields` mode (`loose` option or the `setPublicClassFields` assumption,
+//! which matches TypeScript's `useDefineForClassFields: false`) is implemented so far.
+//! In that mode, public instance fields are initialized by assignments in the constructor,
+//! so setters inherited from a super class are invoked:
+//!
+//! ```js
+//! class C extends S {
+//!   foo = 123;
+//!   bar;
+//! }
+//! // ->
+//! class C extends S {
+//!   constructor(...args) {
+//!     super(...args);
+//!     this.foo = 123;
+//!     this.bar = void 0;
+//!   }
+//! }
+//! ```
+//!
+//! Static fields, private fields, and classes with instance fields with computed keys which are not
+//! literals are left as they are. So are derived classes whose constructor does not call `super()`
+//! at its top level.
+//!
+//! A field initialized with an anonymous class names the class after the field, which the
+//! assignment does not. With [`TransformOptions::keep_class_names`], the name is kept:
+//!
+//! ```js
+//! class C {
+//!   foo = class {};
+//! }
+//! // ->
+//! class C {
+//!   constructor() {
+//!     this.foo = Object.defineProperty(class {}, "name", { value: "foo", configurable: true });
+//!   }
+//! }
+//! ```
+//!
+//! [`TransformOptions::keep_class_names`]: crate::TransformOptions::keep_class_names
+//!
 //! Implementation based on [@babel/plugin-transform-class-properties](https://babel.dev/docs/babel-plugin-transform-class-properties).
 //!
 //! ## References:
@@ -62,10 +103,22 @@
 //!   * <https://github.com/babel/babel/blob/main/packages/babel-helper-create-class-features-plugin/src/fields.ts>
 //! * Class properties TC39 proposal: <https://github.com/tc39/proposal-class-fields>
 
+use std::cell::Cell;
+
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Deserialize;
 
-use oxc_ast::ast::*;
-use oxc_traverse::{Traverse, TraverseCtx};
+use oxc_ast::{ast::*, NONE};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_semantic::SymbolTable;
+use oxc_span::SPAN;
+use oxc_syntax::{
+    operator::AssignmentOperator,
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
 
 use crate::TransformCtx;
 
@@ -77,18 +130,345 @@ pub struct ClassPropertiesOptions {
 }
 
 pub struct ClassProperties<'a, 'ctx> {
-    #[expect(dead_code)]
     options: ClassPropertiesOptions,
-    #[expect(dead_code)]
     ctx: &'ctx TransformCtx<'a>,
 }
 
 impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
-    pub fn new(options: ClassPropertiesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(mut options: ClassPropertiesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        options.set_public_class_fields |= ctx.assumptions.set_public_class_fields;
         Self { options, ctx }
     }
 }
 
 impl<'a, 'ctx> Traverse<'a> for ClassProperties<'a, 'ctx> {
-    fn enter_class_body(&mut self, _body: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.set_public_class_fields {
+            self.transform_public_fields_to_assignments(body, ctx);
+        }
+    }
+}
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    /// `class C { foo = 1 }` -> `class C { constructor() { this.foo = 1; } }`
+    fn transform_public_fields_to_assignments(
+        &self,
+        body: &mut ClassBody<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut has_fields = false;
+        for element in &body.body {
+            if let ClassElement::PropertyDefinition(prop) = element {
+                if Self::is_public_instance_field(prop) {
+                    // Babel stores computed keys in temporary variables, which is not supported yet
+                    if prop.key.static_name().is_none() {
+                        return;
+                    }
+                    has_fields = true;
+                }
+            }
+        }
+        if !has_fields {
+            return;
+        }
+
+        let is_derived =
+            matches!(ctx.parent(), Ancestor::ClassBody(class) if class.super_class().is_some());
+        let constructor = body.body.iter().find_map(|element| match element {
+            ClassElement::MethodDefinition(method) if method.kind.is_constructor() => {
+                Some(&method.value)
+            }
+            _ => None,
+        });
+
+        // Index of the constructor body statement to insert the assignments at
+        let insert_at = match constructor {
+            Some(func) if is_derived => {
+                let Some(index) = func.body.as_ref().and_then(|body| {
+                    body.statements.iter().position(|stmt| {
+                        matches!(stmt, Statement::ExpressionStatement(stmt)
+                            if stmt.expression.is_super_call_expression())
+                    })
+                }) else {
+                    return;
+                };
+                index + 1
+            }
+            Some(_) => 0,
+            None => usize::from(is_derived),
+        };
+
+        let mut fields = vec![];
+        for element in ctx.ast.move_vec(&mut body.body) {
+            match element {
+                ClassElement::PropertyDefinition(prop) if Self::is_public_instance_field(&prop) => {
+                    fields.push(prop.unbox());
+                }
+                element => body.body.push(element),
+            }
+        }
+
+        let mut initializers = InitializerCollector::default();
+        for field in &fields {
+            if let Some(value) = &field.value {
+                initializers.visit_expression(value);
+            }
+        }
+
+        let index = body.body.iter().position(|element| {
+            matches!(element, ClassElement::MethodDefinition(method) if method.kind.is_constructor())
+        });
+        let index = index.unwrap_or_else(|| {
+            body.body.insert(0, Self::create_constructor(is_derived, ctx));
+            0
+        });
+        let Some(ClassElement::MethodDefinition(method)) = body.body.get_mut(index) else {
+            unreachable!();
+        };
+        let constructor = &mut method.value;
+        Self::rename_shadowing_bindings(constructor, &initializers.names, ctx);
+
+        // Initializers move from the class scope into the constructor
+        let constructor_scope_id = constructor.scope_id();
+        for scope_id in initializers.scope_ids {
+            ctx.scopes_mut().change_parent_id(scope_id, Some(constructor_scope_id));
+        }
+
+        let keep_class_names = self.ctx.keep_class_names;
+        let assignments = fields
+            .into_iter()
+            .map(|field| Self::create_this_assignment(field, keep_class_names, ctx))
+            .collect::<Vec<_>>();
+        constructor
+            .body
+            .get_or_insert_with(|| ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec()))
+            .statements
+            .splice(insert_at..insert_at, assignments);
+    }
+
+    fn is_public_instance_field(prop: &PropertyDefinition<'a>) -> bool {
+        !prop.r#static
+            && prop.r#type == PropertyDefinitionType::PropertyDefinition
+            && !prop.key.is_private_identifier()
+    }
+
+    /// `foo = 1` -> `this.foo = 1;`, `"foo-bar";` -> `this["foo-bar"] = void 0;`
+    fn create_this_assignment(
+        field: PropertyDefinition<'a>,
+        keep_class_names: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let mut value = field.value.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+        if keep_class_names {
+            if let Some(name) = field.key.static_name() {
+                value = Self::keep_class_name(value, &name, ctx);
+            }
+        }
+        let object = ctx.ast.expression_this(SPAN);
+        let target = match field.key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.member_expression_static(SPAN, object, ident.unbox(), false)
+            }
+            key => ctx.ast.member_expression_computed(SPAN, object, key.into_expression(), false),
+        };
+        ctx.ast.statement_expression(
+            field.span,
+            ctx.ast.expression_assignment(
+                field.span,
+                AssignmentOperator::Assign,
+                SimpleAssignmentTarget::from(target).into(),
+                value,
+            ),
+        )
+    }
+
+    /// `class {}` -> `Object.defineProperty(class {}, "name", { value: "foo", configurable: true })`
+    ///
+    /// Anonymous classes which define a static `name` of their own are left as they are.
+    fn keep_class_name(
+        value: Expression<'a>,
+        name: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let Expression::ClassExpression(class) = &value else {
+            return value;
+        };
+        let has_own_name = class.body.body.iter().any(|element| {
+            element.r#static() && element.static_name().is_some_and(|key| key == "name")
+        });
+        if class.id.is_some() || has_own_name {
+            return value;
+        }
+
+        let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "Object");
+        let object =
+            ctx.create_reference_id(SPAN, Atom::from("Object"), symbol_id, ReferenceFlags::Read);
+        let callee = ctx.ast.member_expression_static(
+            SPAN,
+            Expression::Identifier(ctx.alloc(object)),
+            ctx.ast.identifier_name(SPAN, "defineProperty"),
+            false,
+        );
+        let ast = ctx.ast;
+        let property = |key: &'static str, value: Expression<'a>| {
+            ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ast.property_key_identifier_name(SPAN, key),
+                value,
+                false,
+                false,
+                false,
+            )
+        };
+        let descriptor = ast.expression_object(
+            SPAN,
+            ast.vec_from_iter([
+                property("value", ast.expression_string_literal(SPAN, ast.atom(name))),
+                property("configurable", ast.expression_boolean_literal(SPAN, true)),
+            ]),
+            None,
+        );
+        let arguments = ast.vec_from_iter([
+            Argument::from(value),
+            Argument::from(ast.expression_string_literal(SPAN, "name")),
+            Argument::from(descriptor),
+        ]);
+        ast.expression_call(SPAN, Expression::from(callee), NONE, arguments, false)
+    }
+
+    /// `constructor() {}`, or `constructor(...args) { super(...args); }` in a derived class.
+    fn create_constructor(is_derived: bool, ctx: &mut TraverseCtx<'a>) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope_of_current(
+            ScopeFlags::Function | ScopeFlags::Constructor | ScopeFlags::StrictMode,
+        );
+        let (rest, statements) = if is_derived {
+            // Renamed by `rename_shadowing_bindings` if an initializer references another `args`
+            let args = ctx.generate_binding(
+                ctx.ast.atom("args"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let rest = ctx.ast.alloc_binding_rest_element(SPAN, args.create_binding_pattern(ctx));
+            let super_call = ctx.ast.expression_call(
+                SPAN,
+                ctx.ast.expression_super(SPAN),
+                NONE,
+                ctx.ast
+                    .vec1(ctx.ast.argument_spread_element(SPAN, args.create_read_expression(ctx))),
+                false,
+            );
+            (Some(rest), ctx.ast.vec1(ctx.ast.statement_expression(SPAN, super_call)))
+        } else {
+            (None, ctx.ast.vec())
+        };
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            rest,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        let func = ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        );
+        ctx.ast.class_element_method_definition(
+            MethodDefinitionType::MethodDefinition,
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.property_key_identifier_name(SPAN, "constructor"),
+            func,
+            MethodDefinitionKind::Constructor,
+            false,
+            false,
+            false,
+            false,
+            None,
+        )
+    }
+
+    /// Rename the constructor's own bindings which would shadow names referenced by initializers.
+    /// `class C { foo = x; constructor(x) {} }` -> `class C { constructor(_x) { this.foo = x; } }`
+    fn rename_shadowing_bindings(
+        func: &mut Function<'a>,
+        names: &FxHashSet<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = func.scope_id();
+        let mut renamed = FxHashMap::default();
+        for name in names {
+            let Some(symbol_id) = ctx.scopes().get_binding(scope_id, name) else {
+                continue;
+            };
+            let new_name = ctx.generate_uid_name(name);
+            ctx.symbols_mut().set_name(symbol_id, new_name.clone());
+            ctx.scopes_mut().remove_binding(scope_id, &name.to_compact_str());
+            ctx.scopes_mut().add_binding(scope_id, new_name.clone(), symbol_id);
+            renamed.insert(symbol_id, ctx.ast.atom(&new_name));
+        }
+        if !renamed.is_empty() {
+            BindingRenamer { symbols: ctx.symbols(), names: &renamed }
+                .visit_function(func, ScopeFlags::empty());
+        }
+    }
+}
+
+/// Collects the names referenced by field initializers, and the outermost scopes inside them.
+#[derive(Default)]
+struct InitializerCollector<'a> {
+    names: FxHashSet<Atom<'a>>,
+    scope_ids: Vec<ScopeId>,
+    scope_depth: usize,
+}
+
+impl<'a> Visit<'a> for InitializerCollector<'a> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.scope_depth == 0 {
+            self.scope_ids.push(scope_id.get().unwrap());
+        }
+        self.scope_depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_depth -= 1;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.names.insert(ident.name.clone());
+    }
+}
+
+/// Renames the bindings of the given symbols, and every reference to them.
+struct BindingRenamer<'a, 's> {
+    symbols: &'s SymbolTable,
+    names: &'s FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 's> VisitMut<'a> for BindingRenamer<'a, 's> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(name) = ident.symbol_id.get().and_then(|symbol_id| self.names.get(&symbol_id)) {
+            ident.name = name.clone();
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id());
+        if let Some(name) = symbol_id.and_then(|symbol_id| self.names.get(&symbol_id)) {
+            ident.name = name.clone();
+        }
+    }
 }
@@ -42,7 +42,8 @@
 use itoa::Buffer as ItoaBuffer;
 
 use oxc_allocator::String as ArenaString;
-use oxc_ast::{ast::*, Visit, NONE};
+use oxc_ast::{ast::*, NONE};
+use oxc_ast_visit::Visit;
 use oxc_semantic::SymbolTable;
 use oxc_span::SPAN;
 use oxc_syntax::{
@@ -0,0 +1,64 @@
+//! ES5: Member Expression Literals
+//!
+//! This plugin quotes the properties of member expressions which are reserved words in ES3, for
+//! engines predating ES5 which do not allow them in dot notation.
+//!
+//! > This plugin is included in `preset-env`, in ES5
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! obj.default = 1;
+//! obj.class.foo;
+//! ```
+//!
+//! Output:
+//! ```js
+//! obj["default"] = 1;
+//! obj["class"].foo;
+//! ```
+//!
+//! Member expressions created after transforming are not visited by this plugin, see
+//! `CodegenOptions::quote_reserved_properties` for printing them the same way.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-member-expression-literals](https://babel.dev/docs/babel-plugin-transform-member-expression-literals).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-member-expression-literals>
+//! * ES3 future reserved words: <https://www-archive.mozilla.org/js/language/E262-3.pdf>
+
+use oxc_ast::ast::*;
+use oxc_syntax::keyword::is_es3_reserved_word;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub struct MemberExpressionLiterals;
+
+impl MemberExpressionLiterals {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for MemberExpressionLiterals {
+    /// Transform `obj.default` to `obj["default"]`.
+    fn exit_member_expression(
+        &mut self,
+        expr: &mut MemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let MemberExpression::StaticMemberExpression(member) = expr else {
+            return;
+        };
+        if !is_es3_reserved_word(&member.property.name) {
+            return;
+        }
+        let (span, optional) = (member.span, member.optional);
+        let object = ctx.ast.move_expression(&mut member.object);
+        let property =
+            ctx.ast.expression_string_literal(member.property.span, member.property.name.clone());
+        *expr = ctx.ast.member_expression_computed(span, object, property, optional);
+    }
+}
@@ -0,0 +1,46 @@
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+mod member_expression_literals;
+mod options;
+mod property_literals;
+
+pub use member_expression_literals::MemberExpressionLiterals;
+pub use options::ES5Options;
+pub use property_literals::PropertyLiterals;
+
+pub struct ES5 {
+    options: ES5Options,
+
+    // Plugins
+    member_expression_literals: MemberExpressionLiterals,
+    property_literals: PropertyLiterals,
+}
+
+impl ES5 {
+    pub fn new(options: ES5Options) -> Self {
+        Self {
+            member_expression_literals: MemberExpressionLiterals::new(),
+            property_literals: PropertyLiterals::new(),
+            options,
+        }
+    }
+}
+
+impl<'a> Traverse<'a> for ES5 {
+    fn exit_member_expression(
+        &mut self,
+        expr: &mut MemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.member_expression_literals {
+            self.member_expression_literals.exit_member_expression(expr, ctx);
+        }
+    }
+
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.property_literals {
+            self.property_literals.enter_object_property(prop, ctx);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ES5Options {
+    #[serde(skip)]
+    pub member_expression_literals: bool,
+
+    #[serde(skip)]
+    pub property_literals: bool,
+}
@@ -0,0 +1,62 @@
+//! ES5: Property Literals
+//!
+//! This plugin quotes the keys of object literal properties which are reserved words in ES3, for
+//! engines predating ES5 which do not allow them unquoted.
+//!
+//! > This plugin is included in `preset-env`, in ES5
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = {
+//!   default: 1,
+//!   foo: 2,
+//! };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = {
+//!   "default": 1,
+//!   foo: 2,
+//! };
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-property-literals](https://babel.dev/docs/babel-plugin-transform-property-literals).
+//!
+//! Unlike Babel, the keys of methods and accessors are quoted too.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-property-literals>
+//! * ES3 future reserved words: <https://www-archive.mozilla.org/js/language/E262-3.pdf>
+
+use oxc_ast::ast::*;
+use oxc_syntax::keyword::is_es3_reserved_word;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub struct PropertyLiterals;
+
+impl PropertyLiterals {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for PropertyLiterals {
+    /// Transform `{ default: 1 }` to `{ "default": 1 }`.
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        let PropertyKey::StaticIdentifier(key) = &prop.key else {
+            return;
+        };
+        if !is_es3_reserved_word(&key.name) {
+            return;
+        }
+        let key = ctx.ast.alloc_string_literal(key.span, key.name.clone());
+        prop.key = PropertyKey::StringLiteral(key);
+        // `{ let }` -> `{ "let": let }`
+        prop.shorthand = false;
+    }
+}
@@ -0,0 +1,403 @@
+//! ES Feature Analyzer
+//!
+//! Reports the syntax features used by a program, along with where they are used.
+//!
+//! This is the transformer's feature table in reverse: instead of deciding which features need
+//! transforming for some targets, it finds the features a program uses, so the lowest ES version
+//! which can run the program without transformation can be told.
+//!
+//! ```ignore
+//! let ret = ESFeatureAnalyzer::new(&allocator).build(&program);
+//! for usage in &ret.features {
+//!     println!("{:?} ({}) at {:?}", usage.feature, usage.feature.es_target(), usage.span);
+//! }
+//! println!("requires {}", ret.es_target());
+//! ```
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_ast_visit::{walk, Visit};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_regular_expression::{
+    ast::{
+        CapturingGroup, IgnoreGroup, LookAroundAssertion, LookAroundAssertionKind, Pattern,
+        UnicodePropertyEscape,
+    },
+    visit::{walk as regex_walk, Visit as RegExpVisit},
+    LiteralParser, Options,
+};
+use oxc_span::Span;
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    scope::ScopeFlags,
+};
+
+use crate::options::{ESFeature, ESTarget};
+
+/// A syntax feature used by a program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ESFeatureUsage {
+    pub feature: ESFeature,
+    /// Span of the node which uses the feature.
+    pub span: Span,
+}
+
+pub struct ESFeatureAnalyzerReturn {
+    /// Features used by the program, in source order.
+    pub features: Vec<ESFeatureUsage>,
+    /// Errors from parsing regular expression patterns which were not parsed by the parser.
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+impl ESFeatureAnalyzerReturn {
+    /// The lowest ES version which supports all the features used by the program.
+    pub fn es_target(&self) -> ESTarget {
+        self.features.iter().map(|usage| usage.feature.es_target()).max().unwrap_or(ESTarget::ES5)
+    }
+}
+
+pub struct ESFeatureAnalyzer<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    features: Vec<ESFeatureUsage>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> ESFeatureAnalyzer<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { allocator, source_text: "", features: vec![], errors: vec![] }
+    }
+
+    pub fn build(mut self, program: &Program<'a>) -> ESFeatureAnalyzerReturn {
+        self.source_text = program.source_text;
+        self.visit_program(program);
+        self.features.sort_by_key(|usage| usage.span.start);
+        ESFeatureAnalyzerReturn { features: self.features, errors: self.errors }
+    }
+
+    fn report(&mut self, feature: ESFeature, span: Span) {
+        self.features.push(ESFeatureUsage { feature, span });
+    }
+
+    fn check_regexp_pattern(&mut self, lit: &RegExpLiteral<'a>) {
+        let pattern = match &lit.regex.pattern {
+            RegExpPattern::Pattern(pattern) => &**pattern,
+            RegExpPattern::Raw(raw) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let pattern_len = raw.len() as u32;
+                let pattern_span_start = lit.span.start + 1; // +1 to skip the opening `/`
+                let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
+                let flags_text =
+                    Span::new(flags_span_start, lit.span.end).source_text(self.source_text);
+                let options = Options {
+                    pattern_span_offset: pattern_span_start,
+                    flags_span_offset: flags_span_start,
+                };
+                match LiteralParser::new(self.allocator, raw, Some(flags_text), options).parse() {
+                    Ok(pattern) => self.allocator.alloc(pattern),
+                    Err(error) => {
+                        self.errors.push(error);
+                        return;
+                    }
+                }
+            }
+            RegExpPattern::Invalid(_) => return,
+        };
+
+        let mut finder = RegExpFeatureFinder::default();
+        finder.visit_pattern(pattern);
+        self.features.append(&mut finder.features);
+    }
+}
+
+impl<'a> Visit<'a> for ESFeatureAnalyzer<'a> {
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+            self.report(ESFeature::ES2015BlockScoping, decl.span);
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_object_pattern(&mut self, pat: &ObjectPattern<'a>) {
+        self.report(ESFeature::ES2015Destructuring, pat.span);
+        if let Some(rest) = &pat.rest {
+            self.report(ESFeature::ES2018ObjectRestSpread, rest.span);
+        }
+        walk::walk_object_pattern(self, pat);
+    }
+
+    fn visit_array_pattern(&mut self, pat: &ArrayPattern<'a>) {
+        self.report(ESFeature::ES2015Destructuring, pat.span);
+        walk::walk_array_pattern(self, pat);
+    }
+
+    fn visit_object_assignment_target(&mut self, target: &ObjectAssignmentTarget<'a>) {
+        self.report(ESFeature::ES2015Destructuring, target.span);
+        if let Some(rest) = &target.rest {
+            self.report(ESFeature::ES2018ObjectRestSpread, rest.span);
+        }
+        walk::walk_object_assignment_target(self, target);
+    }
+
+    fn visit_array_assignment_target(&mut self, target: &ArrayAssignmentTarget<'a>) {
+        self.report(ESFeature::ES2015Destructuring, target.span);
+        walk::walk_array_assignment_target(self, target);
+    }
+
+    fn visit_formal_parameters(&mut self, params: &FormalParameters<'a>) {
+        for param in &params.items {
+            if param.pattern.kind.is_assignment_pattern() {
+                self.report(ESFeature::ES2015Parameters, param.span);
+            }
+        }
+        if let Some(rest) = &params.rest {
+            self.report(ESFeature::ES2015Parameters, rest.span);
+        }
+        walk::walk_formal_parameters(self, params);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        let feature = match (func.r#async, func.generator) {
+            (true, true) => Some(ESFeature::ES2018AsyncGeneratorFunctions),
+            (true, false) => Some(ESFeature::ES2017AsyncToGenerator),
+            (false, true) => Some(ESFeature::ES2015Regenerator),
+            (false, false) => None,
+        };
+        if let Some(feature) = feature {
+            self.report(feature, func.span);
+        }
+        walk::walk_function(self, func, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.report(ESFeature::ES2015ArrowFunctions, arrow.span);
+        if arrow.r#async {
+            self.report(ESFeature::ES2017AsyncToGenerator, arrow.span);
+        }
+        walk::walk_arrow_function_expression(self, arrow);
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.report(ESFeature::ES2015Classes, class.span);
+        walk::walk_class(self, class);
+    }
+
+    fn visit_property_definition(&mut self, def: &PropertyDefinition<'a>) {
+        self.report(ESFeature::ES2022ClassProperties, def.span);
+        walk::walk_property_definition(self, def);
+    }
+
+    fn visit_method_definition(&mut self, def: &MethodDefinition<'a>) {
+        if def.key.is_private_identifier() {
+            self.report(ESFeature::ES2022PrivateMethods, def.span);
+        }
+        walk::walk_method_definition(self, def);
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock<'a>) {
+        self.report(ESFeature::ES2022ClassStaticBlock, block.span);
+        walk::walk_static_block(self, block);
+    }
+
+    fn visit_private_in_expression(&mut self, expr: &PrivateInExpression<'a>) {
+        self.report(ESFeature::ES2022PrivatePropertyInObject, expr.span);
+        walk::walk_private_in_expression(self, expr);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        let feature = if stmt.r#await {
+            ESFeature::ES2018AsyncGeneratorFunctions
+        } else {
+            ESFeature::ES2015ForOf
+        };
+        self.report(feature, stmt.span);
+        walk::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause<'a>) {
+        if clause.param.is_none() {
+            self.report(ESFeature::ES2018OptionalCatchBinding, clause.span);
+        }
+        walk::walk_catch_clause(self, clause);
+    }
+
+    fn visit_object_expression(&mut self, expr: &ObjectExpression<'a>) {
+        for property in &expr.properties {
+            match property {
+                ObjectPropertyKind::ObjectProperty(prop) => {
+                    if prop.shorthand || prop.method {
+                        self.report(ESFeature::ES2015ShorthandProperties, prop.span);
+                    }
+                    if prop.computed {
+                        self.report(ESFeature::ES2015ComputedProperties, prop.span);
+                    }
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    self.report(ESFeature::ES2018ObjectRestSpread, spread.span);
+                }
+            }
+        }
+        walk::walk_object_expression(self, expr);
+    }
+
+    fn visit_array_expression_element(&mut self, element: &ArrayExpressionElement<'a>) {
+        if let ArrayExpressionElement::SpreadElement(spread) = element {
+            self.report(ESFeature::ES2015Spread, spread.span);
+        }
+        walk::walk_array_expression_element(self, element);
+    }
+
+    fn visit_argument(&mut self, arg: &Argument<'a>) {
+        if let Argument::SpreadElement(spread) = arg {
+            self.report(ESFeature::ES2015Spread, spread.span);
+        }
+        walk::walk_argument(self, arg);
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if meta.meta.name == "new" && meta.property.name == "target" {
+            self.report(ESFeature::ES2015NewTarget, meta.span);
+        }
+        walk::walk_meta_property(self, meta);
+    }
+
+    fn visit_template_literal(&mut self, lit: &TemplateLiteral<'a>) {
+        self.report(ESFeature::ES2015TemplateLiterals, lit.span);
+        walk::walk_template_literal(self, lit);
+    }
+
+    fn visit_template_element(&mut self, elem: &TemplateElement<'a>) {
+        if elem.value.raw.contains("\\u{") {
+            self.report(ESFeature::ES2015UnicodeEscapes, elem.span);
+        }
+        walk::walk_template_element(self, elem);
+    }
+
+    fn visit_string_literal(&mut self, lit: &StringLiteral<'a>) {
+        let raw = lit.span.source_text(self.source_text);
+        if raw.contains("\\u{") {
+            self.report(ESFeature::ES2015UnicodeEscapes, lit.span);
+        }
+        if raw.contains(['\u{2028}', '\u{2029}']) {
+            self.report(ESFeature::ES2019JsonStrings, lit.span);
+        }
+        walk::walk_string_literal(self, lit);
+    }
+
+    fn visit_numeric_literal(&mut self, lit: &NumericLiteral<'a>) {
+        if lit.raw.len() > 1 && matches!(&lit.raw.as_bytes()[..2], b"0b" | b"0B" | b"0o" | b"0O") {
+            self.report(ESFeature::ES2015Literals, lit.span);
+        }
+        if lit.raw.contains('_') {
+            self.report(ESFeature::ES2021NumericSeparator, lit.span);
+        }
+        walk::walk_numeric_literal(self, lit);
+    }
+
+    fn visit_big_int_literal(&mut self, lit: &BigIntLiteral<'a>) {
+        self.report(ESFeature::ES2020BigInt, lit.span);
+        if lit.raw.contains('_') {
+            self.report(ESFeature::ES2021NumericSeparator, lit.span);
+        }
+        walk::walk_big_int_literal(self, lit);
+    }
+
+    fn visit_reg_exp_literal(&mut self, lit: &RegExpLiteral<'a>) {
+        let flags = lit.regex.flags;
+        for (flag, feature) in [
+            (RegExpFlags::Y, ESFeature::ES2015StickyRegex),
+            (RegExpFlags::U, ESFeature::ES2015UnicodeRegex),
+            (RegExpFlags::S, ESFeature::ES2018DotallRegex),
+            (RegExpFlags::D, ESFeature::ES2022MatchIndicesRegex),
+            (RegExpFlags::V, ESFeature::ES2024UnicodeSetsRegex),
+        ] {
+            if flags.contains(flag) {
+                self.report(feature, lit.span);
+            }
+        }
+        self.check_regexp_pattern(lit);
+        walk::walk_reg_exp_literal(self, lit);
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        if expr.operator == BinaryOperator::Exponential {
+            self.report(ESFeature::ES2016ExponentiationOperator, expr.span);
+        }
+        walk::walk_binary_expression(self, expr);
+    }
+
+    fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
+        if expr.operator == LogicalOperator::Coalesce {
+            self.report(ESFeature::ES2020NullishCoalescingOperator, expr.span);
+        }
+        walk::walk_logical_expression(self, expr);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if expr.operator == AssignmentOperator::Exponential {
+            self.report(ESFeature::ES2016ExponentiationOperator, expr.span);
+        } else if expr.operator.is_logical() {
+            self.report(ESFeature::ES2020LogicalAssignmentOperators, expr.span);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_chain_expression(&mut self, expr: &ChainExpression<'a>) {
+        self.report(ESFeature::ES2019OptionalChaining, expr.span);
+        walk::walk_chain_expression(self, expr);
+    }
+}
+
+#[derive(Default)]
+struct RegExpFeatureFinder<'a> {
+    features: Vec<ESFeatureUsage>,
+    group_names: FxHashSet<&'a str>,
+}
+
+impl<'a> RegExpFeatureFinder<'a> {
+    fn report(&mut self, feature: ESFeature, span: Span) {
+        self.features.push(ESFeatureUsage { feature, span });
+    }
+}
+
+impl<'a> RegExpVisit<'a> for RegExpFeatureFinder<'a> {
+    fn visit_pattern(&mut self, pattern: &Pattern<'a>) {
+        self.group_names.clear();
+        regex_walk::walk_pattern(self, pattern);
+    }
+
+    fn visit_capturing_group(&mut self, group: &CapturingGroup<'a>) {
+        if let Some(name) = &group.name {
+            self.report(ESFeature::ES2018NamedCapturingGroupsRegex, group.span);
+            // Names can only be repeated in different alternatives, which the parser checks.
+            if !self.group_names.insert(name.as_str()) {
+                self.report(ESFeature::ES2025DuplicateNamedCapturingGroupsRegex, group.span);
+            }
+        }
+        regex_walk::walk_capturing_group(self, group);
+    }
+
+    fn visit_ignore_group(&mut self, group: &IgnoreGroup<'a>) {
+        if group.modifiers.is_some() {
+            self.report(ESFeature::ES2025RegexpModifiers, group.span);
+        }
+        regex_walk::walk_ignore_group(self, group);
+    }
+
+    fn visit_lookaround_assertion(&mut self, assertion: &LookAroundAssertion<'a>) {
+        if matches!(
+            assertion.kind,
+            LookAroundAssertionKind::Lookbehind | LookAroundAssertionKind::NegativeLookbehind
+        ) {
+            self.report(ESFeature::ES2018LookbehindRegex, assertion.span);
+        }
+        regex_walk::walk_lookaround_assertion(self, assertion);
+    }
+
+    fn visit_unicode_property_escape(&mut self, escape: &UnicodePropertyEscape<'a>) {
+        self.report(ESFeature::ES2018UnicodePropertyRegex, escape.span);
+        regex_walk::walk_unicode_property_escape(self, escape);
+    }
+}
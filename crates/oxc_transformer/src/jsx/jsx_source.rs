@@ -202,7 +202,14 @@ impl<'a, 'ctx> JsxSource<'a, 'ctx> {
         let filename_var = self.filename_var.as_ref()?;
 
         let id = filename_var.create_binding_pattern(ctx);
-        let init = ctx.ast.expression_string_literal(SPAN, self.ctx.source_path.to_string_lossy());
+        let init = self
+            .ctx
+            .filename_expression
+            .as_deref()
+            .and_then(|source_text| self.ctx.parse_option_expression(source_text, ctx.ast))
+            .unwrap_or_else(|| {
+                ctx.ast.expression_string_literal(SPAN, self.ctx.source_path.to_string_lossy())
+            });
         let decl =
             ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, Some(init), false);
         Some(decl)
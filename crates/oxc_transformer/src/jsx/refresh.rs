@@ -6,7 +6,7 @@ use oxc_allocator::{CloneIn, GetAddress, Vec as ArenaVec};
 use oxc_ast::{ast::*, match_expression, AstBuilder, NONE};
 use oxc_semantic::{Reference, ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
 use oxc_span::{Atom, GetSpan, SPAN};
-use oxc_syntax::operator::AssignmentOperator;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
 use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
 
 use crate::TransformCtx;
@@ -154,6 +154,11 @@ impl<'a, 'ctx> Traverse<'a> for ReactRefresh<'a, 'ctx> {
 
         let mut variable_declarator_items = ctx.ast.vec_with_capacity(self.registrations.len());
         let mut new_statements = ctx.ast.vec_with_capacity(self.registrations.len() + 1);
+        let module_id = self
+            .ctx
+            .module_id_expression
+            .as_deref()
+            .and_then(|source_text| self.ctx.parse_option_expression(source_text, ctx.ast));
         for (binding, persistent_id) in self.registrations.drain(..) {
             variable_declarator_items.push(ctx.ast.variable_declarator(
                 SPAN,
@@ -166,9 +171,20 @@ impl<'a, 'ctx> Traverse<'a> for ReactRefresh<'a, 'ctx> {
             let callee = self.refresh_reg.to_expression(ctx);
             let mut arguments = ctx.ast.vec_with_capacity(2);
             arguments.push(Argument::from(binding.create_read_expression(ctx)));
-            arguments.push(Argument::from(
-                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&persistent_id)),
-            ));
+            let id = match &module_id {
+                // `module.id + " App"`
+                Some(module_id) => ctx.ast.expression_binary(
+                    SPAN,
+                    module_id.clone_in(ctx.ast.allocator),
+                    BinaryOperator::Addition,
+                    ctx.ast.expression_string_literal(
+                        SPAN,
+                        ctx.ast.atom(&format!(" {persistent_id}")),
+                    ),
+                ),
+                None => ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&persistent_id)),
+            };
+            arguments.push(Argument::from(id));
             new_statements.push(ctx.ast.statement_expression(
                 SPAN,
                 ctx.ast.expression_call(SPAN, callee, NONE, arguments, false),
@@ -18,7 +18,9 @@ use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
 mod common;
 mod compiler_assumptions;
 mod context;
+mod feature_analyzer;
 mod options;
+mod profile;
 
 // Presets: <https://babel.dev/docs/presets>
 mod es2015;
@@ -29,6 +31,7 @@ mod es2019;
 mod es2020;
 mod es2021;
 mod es2022;
+mod es5;
 mod jsx;
 mod regexp;
 mod typescript;
@@ -45,7 +48,9 @@ use es2019::ES2019;
 use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
+use es5::ES5;
 use jsx::Jsx;
+use profile::{Plugin, Profiler};
 use regexp::RegExp;
 use typescript::TypeScript;
 
@@ -53,12 +58,14 @@ pub use crate::{
     common::helper_loader::HelperLoaderMode,
     compiler_assumptions::CompilerAssumptions,
     es2015::{ArrowFunctionsOptions, ES2015Options},
+    feature_analyzer::{ESFeatureAnalyzer, ESFeatureAnalyzerReturn, ESFeatureUsage},
     jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
     options::{
         babel::{BabelEnvOptions, BabelOptions},
-        ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
+        ESFeature, ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
     },
     plugins::*,
+    profile::{PluginProfile, TransformProfile},
     typescript::{RewriteExtensionsMode, TypeScriptOptions},
 };
 
@@ -66,6 +73,8 @@ pub struct TransformerReturn {
     pub errors: std::vec::Vec<OxcDiagnostic>,
     pub symbols: SymbolTable,
     pub scopes: ScopeTree,
+    /// Time spent in each plugin, when [`TransformOptions::profile`] is enabled.
+    pub profile: Option<TransformProfile>,
 }
 
 pub struct Transformer<'a> {
@@ -76,6 +85,7 @@ pub struct Transformer<'a> {
     typescript: TypeScriptOptions,
     jsx: JsxOptions,
     env: EnvOptions,
+    profile: bool,
 }
 
 impl<'a> Transformer<'a> {
@@ -87,6 +97,7 @@ impl<'a> Transformer<'a> {
             typescript: options.typescript.clone(),
             jsx: options.jsx.clone(),
             env: options.env,
+            profile: options.profile,
         }
     }
 
@@ -123,11 +134,14 @@ impl<'a> Transformer<'a> {
             x2_es2016: ES2016::new(self.env.es2016, &self.ctx),
             x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
+            x3_es5: ES5::new(self.env.es5),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            profiler: Profiler::new(self.profile),
         };
 
         let (symbols, scopes) = traverse_mut(&mut transformer, allocator, program, symbols, scopes);
-        TransformerReturn { errors: self.ctx.take_errors(), symbols, scopes }
+        let profile = transformer.profiler.finish();
+        TransformerReturn { errors: self.ctx.take_errors(), symbols, scopes, profile }
     }
 }
 
@@ -144,24 +158,26 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2016: ES2016<'a, 'ctx>,
     #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
+    x3_es5: ES5,
     x4_regexp: RegExp<'a, 'ctx>,
     common: Common<'a, 'ctx>,
+    profiler: Profiler,
 }
 
 impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_program(program, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_program(program, ctx));
         }
-        self.x1_jsx.enter_program(program, ctx);
+        self.profiler.measure(Plugin::Jsx, || self.x1_jsx.enter_program(program, ctx));
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.x1_jsx.exit_program(program, ctx);
+        self.profiler.measure(Plugin::Jsx, || self.x1_jsx.exit_program(program, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.exit_program(program, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.exit_program(program, ctx));
         }
-        self.common.exit_program(program, ctx);
+        self.profiler.measure(Plugin::Common, || self.common.exit_program(program, ctx));
     }
 
     // ALPHASORT
@@ -172,7 +188,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_arrow_function_expression(arrow, ctx);
+            self.profiler.measure(Plugin::TypeScript, || {
+                typescript.enter_arrow_function_expression(arrow, ctx)
+            });
         }
     }
 
@@ -182,46 +200,49 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_variable_declarator(decl, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_variable_declarator(decl, ctx));
         }
     }
 
     fn enter_big_int_literal(&mut self, node: &mut BigIntLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.x2_es2020.enter_big_int_literal(node, ctx);
+        self.profiler.measure(Plugin::ES2020, || self.x2_es2020.enter_big_int_literal(node, ctx));
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_binding_pattern(pat, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_binding_pattern(pat, ctx));
         }
     }
 
     fn enter_call_expression(&mut self, expr: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_call_expression(expr, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_call_expression(expr, ctx));
         }
-        self.x1_jsx.enter_call_expression(expr, ctx);
+        self.profiler.measure(Plugin::Jsx, || self.x1_jsx.enter_call_expression(expr, ctx));
     }
 
     fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_class(class, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_class(class, ctx));
         }
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_class_body(body, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_class_body(body, ctx));
         }
-        self.x2_es2022.enter_class_body(body, ctx);
+        self.profiler.measure(Plugin::ES2022, || self.x2_es2022.enter_class_body(body, ctx));
     }
 
     fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.common.enter_static_block(block, ctx);
+        self.profiler.measure(Plugin::Common, || self.common.enter_static_block(block, ctx));
     }
 
     fn exit_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.common.exit_static_block(block, ctx);
+        self.profiler.measure(Plugin::Common, || self.common.exit_static_block(block, ctx));
     }
 
     fn enter_ts_module_declaration(
@@ -230,28 +251,29 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_ts_module_declaration(decl, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_ts_module_declaration(decl, ctx));
         }
     }
 
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_expression(expr, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_expression(expr, ctx));
         }
-        self.x2_es2021.enter_expression(expr, ctx);
-        self.x2_es2020.enter_expression(expr, ctx);
-        self.x2_es2018.enter_expression(expr, ctx);
-        self.x2_es2016.enter_expression(expr, ctx);
-        self.x4_regexp.enter_expression(expr, ctx);
-        self.common.enter_expression(expr, ctx);
+        self.profiler.measure(Plugin::ES2021, || self.x2_es2021.enter_expression(expr, ctx));
+        self.profiler.measure(Plugin::ES2020, || self.x2_es2020.enter_expression(expr, ctx));
+        self.profiler.measure(Plugin::ES2018, || self.x2_es2018.enter_expression(expr, ctx));
+        self.profiler.measure(Plugin::ES2016, || self.x2_es2016.enter_expression(expr, ctx));
+        self.profiler.measure(Plugin::RegExp, || self.x4_regexp.enter_expression(expr, ctx));
+        self.profiler.measure(Plugin::Common, || self.common.enter_expression(expr, ctx));
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.x1_jsx.exit_expression(expr, ctx);
-        self.x2_es2018.exit_expression(expr, ctx);
-        self.x2_es2017.exit_expression(expr, ctx);
-        self.common.exit_expression(expr, ctx);
+        self.profiler.measure(Plugin::Jsx, || self.x1_jsx.exit_expression(expr, ctx));
+        self.profiler.measure(Plugin::ES2018, || self.x2_es2018.exit_expression(expr, ctx));
+        self.profiler.measure(Plugin::ES2017, || self.x2_es2017.exit_expression(expr, ctx));
+        self.profiler.measure(Plugin::Common, || self.common.exit_expression(expr, ctx));
     }
 
     fn enter_simple_assignment_target(
@@ -260,7 +282,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_simple_assignment_target(node, ctx);
+            self.profiler.measure(Plugin::TypeScript, || {
+                typescript.enter_simple_assignment_target(node, ctx)
+            });
         }
     }
 
@@ -270,7 +294,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_assignment_target(node, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_assignment_target(node, ctx));
         }
     }
 
@@ -280,32 +305,33 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_formal_parameter(param, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_formal_parameter(param, ctx));
         }
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.common.enter_function(func, ctx);
+        self.profiler.measure(Plugin::Common, || self.common.enter_function(func, ctx));
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.exit_function(func, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.exit_function(func, ctx));
         }
-        self.x1_jsx.exit_function(func, ctx);
-        self.x2_es2018.exit_function(func, ctx);
-        self.x2_es2017.exit_function(func, ctx);
-        self.common.exit_function(func, ctx);
+        self.profiler.measure(Plugin::Jsx, || self.x1_jsx.exit_function(func, ctx));
+        self.profiler.measure(Plugin::ES2018, || self.x2_es2018.exit_function(func, ctx));
+        self.profiler.measure(Plugin::ES2017, || self.x2_es2017.exit_function(func, ctx));
+        self.profiler.measure(Plugin::Common, || self.common.exit_function(func, ctx));
     }
 
     fn enter_jsx_element(&mut self, node: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_jsx_element(node, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_jsx_element(node, ctx));
         }
     }
 
     fn enter_jsx_element_name(&mut self, node: &mut JSXElementName<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.common.enter_jsx_element_name(node, ctx);
+        self.profiler.measure(Plugin::Common, || self.common.enter_jsx_element_name(node, ctx));
     }
 
     fn enter_jsx_member_expression_object(
@@ -313,12 +339,13 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         node: &mut JSXMemberExpressionObject<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        self.common.enter_jsx_member_expression_object(node, ctx);
+        self.profiler
+            .measure(Plugin::Common, || self.common.enter_jsx_member_expression_object(node, ctx));
     }
 
     fn enter_jsx_fragment(&mut self, node: &mut JSXFragment<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_jsx_fragment(node, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_jsx_fragment(node, ctx));
         }
     }
 
@@ -328,9 +355,10 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_jsx_opening_element(elem, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_jsx_opening_element(elem, ctx));
         }
-        self.x1_jsx.enter_jsx_opening_element(elem, ctx);
+        self.profiler.measure(Plugin::Jsx, || self.x1_jsx.enter_jsx_opening_element(elem, ctx));
     }
 
     fn enter_method_definition(
@@ -339,7 +367,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_method_definition(def, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_method_definition(def, ctx));
         }
     }
 
@@ -349,23 +378,38 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.exit_method_definition(def, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.exit_method_definition(def, ctx));
         }
     }
 
     fn enter_new_expression(&mut self, expr: &mut NewExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_new_expression(expr, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_new_expression(expr, ctx));
         }
     }
 
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.profiler.measure(Plugin::ES5, || self.x3_es5.enter_object_property(prop, ctx));
+    }
+
+    fn exit_member_expression(
+        &mut self,
+        expr: &mut MemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.profiler.measure(Plugin::ES5, || self.x3_es5.exit_member_expression(expr, ctx));
+    }
+
     fn enter_property_definition(
         &mut self,
         def: &mut PropertyDefinition<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_property_definition(def, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_property_definition(def, ctx));
         }
     }
 
@@ -375,7 +419,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_accessor_property(node, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_accessor_property(node, ctx));
         }
     }
 
@@ -384,9 +429,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         stmts: &mut ArenaVec<'a, Statement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        self.common.enter_statements(stmts, ctx);
+        self.profiler.measure(Plugin::Common, || self.common.enter_statements(stmts, ctx));
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_statements(stmts, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_statements(stmts, ctx));
         }
     }
 
@@ -420,17 +465,17 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.exit_statements(stmts, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.exit_statements(stmts, ctx));
         }
-        self.common.exit_statements(stmts, ctx);
+        self.profiler.measure(Plugin::Common, || self.common.exit_statements(stmts, ctx));
     }
 
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.exit_statement(stmt, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.exit_statement(stmt, ctx));
         }
-        self.x2_es2018.exit_statement(stmt, ctx);
-        self.x2_es2017.exit_statement(stmt, ctx);
+        self.profiler.measure(Plugin::ES2018, || self.x2_es2018.exit_statement(stmt, ctx));
+        self.profiler.measure(Plugin::ES2017, || self.x2_es2017.exit_statement(stmt, ctx));
     }
 
     fn enter_tagged_template_expression(
@@ -439,32 +484,35 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_tagged_template_expression(expr, ctx);
+            self.profiler.measure(Plugin::TypeScript, || {
+                typescript.enter_tagged_template_expression(expr, ctx)
+            });
         }
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_statement(stmt, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_statement(stmt, ctx));
         }
-        self.x2_es2018.enter_statement(stmt, ctx);
+        self.profiler.measure(Plugin::ES2018, || self.x2_es2018.enter_statement(stmt, ctx));
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_declaration(decl, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_declaration(decl, ctx));
         }
     }
 
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_if_statement(stmt, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_if_statement(stmt, ctx));
         }
     }
 
     fn enter_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_while_statement(stmt, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_while_statement(stmt, ctx));
         }
     }
 
@@ -474,31 +522,34 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_do_while_statement(stmt, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_do_while_statement(stmt, ctx));
         }
     }
 
     fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_for_statement(stmt, ctx);
+            self.profiler.measure(Plugin::TypeScript, || typescript.enter_for_statement(stmt, ctx));
         }
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_for_of_statement(stmt, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_for_of_statement(stmt, ctx));
         }
-        self.x2_es2018.enter_for_of_statement(stmt, ctx);
+        self.profiler.measure(Plugin::ES2018, || self.x2_es2018.enter_for_of_statement(stmt, ctx));
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_for_in_statement(stmt, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_for_in_statement(stmt, ctx));
         }
     }
 
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
-        self.x2_es2019.enter_catch_clause(clause, ctx);
+        self.profiler.measure(Plugin::ES2019, || self.x2_es2019.enter_catch_clause(clause, ctx));
     }
 
     fn enter_import_declaration(
@@ -507,7 +558,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_import_declaration(node, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_import_declaration(node, ctx));
         }
     }
 
@@ -517,7 +569,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_export_all_declaration(node, ctx);
+            self.profiler
+                .measure(Plugin::TypeScript, || typescript.enter_export_all_declaration(node, ctx));
         }
     }
 
@@ -527,7 +580,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_export_named_declaration(node, ctx);
+            self.profiler.measure(Plugin::TypeScript, || {
+                typescript.enter_export_named_declaration(node, ctx)
+            });
         }
     }
 
@@ -537,7 +592,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
-            typescript.enter_ts_export_assignment(export_assignment, ctx);
+            self.profiler.measure(Plugin::TypeScript, || {
+                typescript.enter_ts_export_assignment(export_assignment, ctx)
+            });
         }
     }
 }
@@ -7,7 +7,10 @@ use oxc_diagnostics::Error;
 
 pub use browserslist::Version;
 
-use crate::options::{BrowserslistQuery, Engine, EngineTargets};
+use crate::options::{
+    engine_targets::{unknown_engine, unsupported_target},
+    BrowserslistQuery, Engine, EngineTargets,
+};
 
 /// <https://babel.dev/docs/babel-preset-env#targets>
 #[derive(Debug, Deserialize)]
@@ -45,28 +48,29 @@ impl TryFrom<BabelTargets> for EngineTargets {
                 for (key, value) in map {
                     // TODO: Implement these targets.
                     if matches!(key.as_str(), "esmodules" | "browsers") {
+                        engine_targets.push_warning(unsupported_target(&key));
                         continue;
                     }
-                    // TODO: Implement `Version::from_number`
-                    if matches!(value, BabelTargetsValue::Int(_) | BabelTargetsValue::Float(_)) {
-                        continue;
-                    };
-                    let BabelTargetsValue::String(v) = value else {
-                        return Err(Error::msg(format!("{value:?} is not a string for {key}.")));
+                    let v = match value {
+                        BabelTargetsValue::String(v) => v,
+                        BabelTargetsValue::Int(n) => n.to_string(),
+                        BabelTargetsValue::Float(n) => n.to_string(),
+                        value => {
+                            return Err(Error::msg(format!(
+                                "{value:?} is not a string for {key}."
+                            )));
+                        }
                     };
-                    // TODO: Implement this target.
-                    if key == "node" && v == "current" {
-                        continue;
-                    }
-                    // TODO: Implement this target.
-                    if key == "safari" && v == "tp" {
+                    // TODO: Implement these targets.
+                    if (key == "node" && v == "current") || (key == "safari" && v == "tp") {
+                        engine_targets.push_warning(unsupported_target(&format!("{key} {v}")));
                         continue;
                     }
-                    // TODO: Some keys are not implemented yet.
                     // <https://babel.dev/docs/options#targets>:
                     // Supported environments: android, chrome, deno, edge, electron, firefox, ie, ios, node, opera, rhino, safari, samsung.
                     let Ok(engine) = Engine::from_str(&key) else {
-                        return Err(Error::msg(format!("engine '{key}' is not supported.")));
+                        engine_targets.push_warning(unknown_engine(&key));
+                        continue;
                     };
                     match Version::parse(&v) {
                         Ok(version) => {
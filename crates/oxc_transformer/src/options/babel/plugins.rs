@@ -50,6 +50,9 @@ pub struct BabelPlugins {
     pub match_indices: bool,
     /// Enables plugin to transform the RegExp literal has `v` flag
     pub set_notation: bool,
+    // ES5
+    pub member_expression_literals: bool,
+    pub property_literals: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
     // ES2016
@@ -111,6 +114,8 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-unicode-property-regex" => p.unicode_property_escapes = true,
                 "esbuild-regexp-match-indices" => p.match_indices = true,
                 "transform-unicode-sets-regex" => p.set_notation = true,
+                "transform-member-expression-literals" => p.member_expression_literals = true,
+                "transform-property-literals" => p.property_literals = true,
                 "transform-arrow-functions" => {
                     p.arrow_function = entry
                         .value::<ArrowFunctionsOptions>()
@@ -1,6 +1,8 @@
 use serde::Deserialize;
 
-use super::PluginPresetEntries;
+use oxc_diagnostics::OxcDiagnostic;
+
+use super::{BabelEnvOptions, PluginPresetEntries};
 
 use crate::{EnvOptions, JsxOptions, TypeScriptOptions};
 
@@ -9,6 +11,8 @@ use crate::{EnvOptions, JsxOptions, TypeScriptOptions};
 pub struct BabelPresets {
     pub errors: Vec<String>,
     pub unsupported: Vec<String>,
+    /// Warnings for options that were ignored, e.g. unknown engines in `targets`.
+    pub warnings: Vec<OxcDiagnostic>,
 
     pub env: Option<EnvOptions>,
 
@@ -25,7 +29,14 @@ impl TryFrom<PluginPresetEntries> for BabelPresets {
         for entry in entries.0 {
             match entry.name() {
                 "env" => {
-                    p.env = entry.value::<EnvOptions>().map_err(|err| p.errors.push(err)).ok();
+                    p.env = entry
+                        .value::<BabelEnvOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok()
+                        .map(|env| {
+                            p.warnings.extend(env.targets.warnings().iter().cloned());
+                            EnvOptions::from(env)
+                        });
                 }
                 "typescript" => {
                     p.typescript =
@@ -8,7 +8,7 @@ use browserslist::Version;
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{Error, OxcDiagnostic};
 
 use super::{
     babel::BabelTargets,
@@ -17,28 +17,40 @@ use super::{
     BrowserslistQuery,
 };
 
+/// Browserslist distributions that have no corresponding [Engine].
+///
+/// These are returned by queries such as `defaults` and are skipped without a warning.
+const UNSUPPORTED_BROWSERSLIST_NAMES: [&str; 6] =
+    ["and_qq", "and_uc", "baidu", "bb", "kaios", "op_mini"];
+
 /// A map of engine names to minimum supported versions.
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "BabelTargets")]
-pub struct EngineTargets(FxHashMap<Engine, Version>);
+pub struct EngineTargets {
+    targets: FxHashMap<Engine, Version>,
+
+    /// Engines and versions that were skipped while parsing.
+    warnings: Vec<OxcDiagnostic>,
+}
 
 impl Deref for EngineTargets {
     type Target = FxHashMap<Engine, Version>;
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.targets
     }
 }
 
 impl DerefMut for EngineTargets {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.targets
     }
 }
 
 impl EngineTargets {
     pub fn new(map: FxHashMap<Engine, Version>) -> Self {
-        Self(map)
+        Self { targets: map, warnings: vec![] }
     }
+
     /// # Errors
     ///
     /// * Query is invalid.
@@ -48,7 +60,16 @@ impl EngineTargets {
 
     /// Returns true if all fields are [None].
     pub fn is_any_target(&self) -> bool {
-        self.0.is_empty()
+        self.targets.is_empty()
+    }
+
+    /// Warnings for unknown engines and malformed versions that were skipped while parsing.
+    pub fn warnings(&self) -> &[OxcDiagnostic] {
+        &self.warnings
+    }
+
+    pub(crate) fn push_warning(&mut self, warning: OxcDiagnostic) {
+        self.warnings.push(warning);
     }
 
     pub fn has_feature(&self, feature: ESFeature) -> bool {
@@ -67,17 +88,26 @@ impl EngineTargets {
     }
 
     /// Parses the value returned from `browserslist`.
+    ///
+    /// Unknown engines and malformed versions are skipped and recorded in [EngineTargets::warnings].
     pub fn parse_versions(versions: Vec<(String, String)>) -> Self {
         let mut engine_targets = Self::default();
-        for (engine, version) in versions {
-            let Ok(engine) = Engine::from_str(&engine) else {
+        for (name, version) in versions {
+            if UNSUPPORTED_BROWSERSLIST_NAMES.contains(&name.as_str()) {
+                continue;
+            }
+            let Ok(engine) = Engine::from_str(&name) else {
+                engine_targets.push_warning(unknown_engine(&name));
                 continue;
             };
-            let Ok(version) = Version::from_str(&version) else {
+            // Ranges such as `ios_saf 15.2-15.3` target their lower bound.
+            let lower_bound = version.split_once('-').map_or(version.as_str(), |(v, _)| v);
+            let Ok(version) = Version::from_str(lower_bound) else {
+                engine_targets.push_warning(malformed_version(&name, &version));
                 continue;
             };
             engine_targets
-                .0
+                .targets
                 .entry(engine)
                 .and_modify(|v| {
                     if version < *v {
@@ -89,3 +119,52 @@ impl EngineTargets {
         engine_targets
     }
 }
+
+pub(crate) fn unknown_engine(name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unknown engine `{name}` is ignored."))
+        .with_help("Supported engines: android, chrome, deno, edge, electron, firefox, hermes, ie, ios, node, opera, rhino, safari, samsung.")
+}
+
+fn malformed_version(name: &str, version: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Malformed version `{version}` for `{name}` is ignored."))
+        .with_help(r#"Version numbers must be in the format "X", "X.Y", or "X.Y.Z"."#)
+}
+
+pub(crate) fn unsupported_target(target: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Target `{target}` is not supported yet and is ignored."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Engine, EngineTargets};
+
+    fn parse(versions: &[(&str, &str)]) -> EngineTargets {
+        EngineTargets::parse_versions(
+            versions
+                .iter()
+                .map(|(name, version)| (name.to_string(), version.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn parse_versions_warnings() {
+        let targets = parse(&[("chrome", "80"), ("chorme", "80"), ("firefox", "latest")]);
+        assert_eq!(targets.len(), 1);
+        assert!(targets.contains_key(&Engine::Chrome));
+        assert_eq!(targets.warnings().len(), 2);
+
+        let targets = parse(&[("ios_saf", "15.2-15.3"), ("op_mini", "all")]);
+        assert!(targets.contains_key(&Engine::Ios));
+        assert!(targets.warnings().is_empty());
+    }
+
+    #[test]
+    fn unsupported_targets_warnings() {
+        let targets: EngineTargets =
+            serde_json::from_str(r#"{ "chrome": 80, "node": "current", "esmodules": true }"#)
+                .unwrap();
+        assert!(targets.contains_key(&Engine::Chrome));
+        assert_eq!(targets.warnings().len(), 2);
+    }
+}
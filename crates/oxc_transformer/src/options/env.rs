@@ -12,6 +12,7 @@ use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
+    es5::ES5Options,
     regexp::RegExpOptions,
     EngineTargets,
 };
@@ -26,6 +27,8 @@ pub struct EnvOptions {
 
     pub regexp: RegExpOptions,
 
+    pub es5: ES5Options,
+
     pub es2015: ES2015Options,
 
     pub es2016: ES2016Options,
@@ -61,6 +64,7 @@ impl EnvOptions {
                 match_indices: true,
                 set_notation: true,
             },
+            es5: ES5Options { member_expression_literals: true, property_literals: true },
             es2015: ES2015Options {
                 // Turned off because it is not ready.
                 arrow_function: if include_unfinished_plugins {
@@ -164,6 +168,10 @@ impl From<EngineTargets> for EnvOptions {
                 match_indices: o.has_feature(ES2022MatchIndicesRegex),
                 set_notation: o.has_feature(ES2024UnicodeSetsRegex),
             },
+            es5: ES5Options {
+                member_expression_literals: o.has_feature(ES5MemberExpressionLiterals),
+                property_literals: o.has_feature(ES5PropertyLiterals),
+            },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
             },
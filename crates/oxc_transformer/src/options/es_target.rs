@@ -3,6 +3,8 @@ use std::{fmt, str::FromStr};
 use browserslist::Version;
 use cow_utils::CowUtils;
 
+use super::{es_features::features, ESFeature, Engine};
+
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 pub enum ESTarget {
     ES5,
@@ -84,3 +86,29 @@ impl ESTarget {
         }
     }
 }
+
+impl ESFeature {
+    /// The ES version which introduced this feature, looked up from the `es` entry of the
+    /// compatibility table.
+    pub fn es_target(self) -> ESTarget {
+        let Some(version) = features().get(&self).and_then(|targets| targets.get(&Engine::Es))
+        else {
+            return ESTarget::ESNext;
+        };
+        match version.0 {
+            0..=2014 => ESTarget::ES5,
+            2015 => ESTarget::ES2015,
+            2016 => ESTarget::ES2016,
+            2017 => ESTarget::ES2017,
+            2018 => ESTarget::ES2018,
+            2019 => ESTarget::ES2019,
+            2020 => ESTarget::ES2020,
+            2021 => ESTarget::ES2021,
+            2022 => ESTarget::ES2022,
+            2023 => ESTarget::ES2023,
+            2024 => ESTarget::ES2024,
+            2025 => ESTarget::ES2025,
+            _ => ESTarget::ESNext,
+        }
+    }
+}
@@ -10,7 +10,7 @@ mod module;
 
 use std::path::PathBuf;
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{Error, OxcDiagnostic};
 
 use crate::{
     common::helper_loader::{HelperLoaderMode, HelperLoaderOptions},
@@ -23,6 +23,7 @@ use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::ES2022Options,
+    es5::ES5Options,
     jsx::JsxOptions,
     regexp::RegExpOptions,
     typescript::TypeScriptOptions,
@@ -45,6 +46,21 @@ pub struct TransformOptions {
     /// The working directory that all paths in the programmatic options will be resolved relative to.
     pub cwd: PathBuf,
 
+    /// Expression which evaluates to the file name of the module, e.g. `import.meta.url` or
+    /// `__filename`.
+    ///
+    /// When set, transforms which reference the file being transformed, such as the `__source`
+    /// of `jsx.development`, emit this expression instead of its path, so that the output does not
+    /// depend on the machine it was built on.
+    pub filename_expression: Option<String>,
+
+    /// Expression which evaluates to the id of the module, e.g. `import.meta.hot.id` or
+    /// `module.id`.
+    ///
+    /// When set, the components registered by `jsx.refresh` are prefixed with the module id, e.g.
+    /// `$RefreshReg$(_c, module.id + " App")`.
+    pub module_id_expression: Option<String>,
+
     // Core
     /// Set assumptions in order to produce smaller output.
     /// For more information, check the [assumptions](https://babel.dev/docs/assumptions) documentation page.
@@ -63,6 +79,20 @@ pub struct TransformOptions {
     pub env: EnvOptions,
 
     pub helper_loader: HelperLoaderOptions,
+
+    /// Keep the `name` of classes which a transform would otherwise change, by defining it with
+    /// `Object.defineProperty(C, "name", { value: "C", configurable: true })`.
+    ///
+    /// e.g. an anonymous class initializing a class field is named after the field, which it is
+    /// not once the field is transformed to an assignment. Useful for frameworks which key off
+    /// class names.
+    pub keep_class_names: bool,
+
+    /// Measure the time spent in each plugin, returned in
+    /// [`TransformerReturn::profile`](crate::TransformerReturn::profile).
+    ///
+    /// Used to find out which plugin makes transforming a file slow.
+    pub profile: bool,
 }
 
 impl TransformOptions {
@@ -73,6 +103,8 @@ impl TransformOptions {
     pub fn enable_all() -> Self {
         Self {
             cwd: PathBuf::new(),
+            filename_expression: None,
+            module_id_expression: None,
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
             jsx: JsxOptions {
@@ -85,6 +117,8 @@ impl TransformOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
             },
+            keep_class_names: false,
+            profile: false,
         }
     }
 
@@ -118,6 +152,19 @@ impl TransformOptions {
     pub fn from_target_list<S: AsRef<str>>(list: &[S]) -> Result<Self, Error> {
         EnvOptions::from_target_list(list).map(|env| Self { env, ..Self::default() })
     }
+
+    /// Initialize from babel options, together with the warnings for options which were ignored,
+    /// e.g. unknown engines in the `targets` of `preset-env`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`TransformOptions::try_from`].
+    pub fn from_babel_options(
+        options: &BabelOptions,
+    ) -> Result<(Self, Vec<OxcDiagnostic>), Vec<Error>> {
+        let transform_options = Self::try_from(options)?;
+        Ok((transform_options, options.presets.warnings.clone()))
+    }
 }
 
 impl From<ESTarget> for TransformOptions {
@@ -182,6 +229,12 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             set_notation: env.regexp.set_notation || options.plugins.set_notation,
         };
 
+        let es5 = ES5Options {
+            member_expression_literals: options.plugins.member_expression_literals
+                || env.es5.member_expression_literals,
+            property_literals: options.plugins.property_literals || env.es5.property_literals,
+        };
+
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
         };
@@ -240,12 +293,15 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         Ok(Self {
             cwd: options.cwd.clone().unwrap_or_default(),
+            filename_expression: None,
+            module_id_expression: None,
             assumptions: options.assumptions,
             typescript,
             jsx,
             env: EnvOptions {
                 module,
                 regexp,
+                es5,
                 es2015,
                 es2016,
                 es2017,
@@ -256,6 +312,8 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2022,
             },
             helper_loader,
+            keep_class_names: false,
+            profile: false,
         })
     }
 }
@@ -199,7 +199,7 @@ impl<'a> InjectGlobalVariables<'a> {
             let kind = ImportOrExportKind::Value;
             let import_decl = self
                 .ast
-                .module_declaration_import_declaration(SPAN, specifiers, source, NONE, kind);
+                .module_declaration_import_declaration(SPAN, specifiers, source, NONE, kind, None);
             Statement::from(import_decl)
         });
         program.body.splice(0..0, imports);
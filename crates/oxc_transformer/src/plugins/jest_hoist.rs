@@ -0,0 +1,352 @@
+use oxc_allocator::{Allocator, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeTree, SymbolTable};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+const JEST_GLOBALS_MODULE: &str = "@jest/globals";
+
+/// Globals which a module factory may reference.
+///
+/// <https://github.com/jestjs/jest/blob/v29.7.0/packages/babel-plugin-jest-hoist/src/index.ts#L30-L89>
+const ALLOWED_IDENTIFIERS: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "Boolean",
+    "BigInt",
+    "Buffer",
+    "DataView",
+    "Date",
+    "Error",
+    "EvalError",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "Generator",
+    "GeneratorFunction",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "InternalError",
+    "Intl",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "Promise",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakSet",
+    "__dirname",
+    "__filename",
+    "arguments",
+    "clearImmediate",
+    "clearInterval",
+    "clearTimeout",
+    "console",
+    "exports",
+    "expect",
+    "globalThis",
+    "isNaN",
+    "jest",
+    "module",
+    "parseFloat",
+    "parseInt",
+    "process",
+    "require",
+    "setImmediate",
+    "setInterval",
+    "setTimeout",
+    "undefined",
+];
+
+fn factory_not_function(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("The second argument of `jest.mock` must be an inline function.")
+        .with_label(span)
+}
+
+fn out_of_scope_reference(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "The module factory of `jest.mock()` is not allowed to reference any out-of-scope variables.",
+    )
+    .with_help(format!(
+        "Invalid variable access: `{name}`. This is a precaution to guard against uninitialized mock variables. If it is ensured that the mock is required lazily, variable names prefixed with `mock` (case insensitive) are permitted."
+    ))
+    .with_label(span)
+}
+
+#[must_use]
+pub struct JestHoistReturn {
+    pub errors: Vec<OxcDiagnostic>,
+    pub symbols: SymbolTable,
+    pub scopes: ScopeTree,
+}
+
+/// Hoists `jest.mock()` calls above the imports and other statements, so that the mocks are
+/// registered before the modules they replace are loaded.
+///
+/// `jest.mock`, `jest.unmock`, `jest.deepUnmock`, `jest.enableAutomock` and
+/// `jest.disableAutomock` calls are moved to the top of the statement list which contains them,
+/// keeping their order. `jest` is either the global or imported from `@jest/globals`.
+///
+/// A module factory, the second argument of `jest.mock`, must be an inline function which only
+/// references its own variables, allowed globals, and variables prefixed with `mock`. It may also
+/// reference a `const` initialized with a literal in the same statement list, which is hoisted
+/// with it.
+///
+/// This does not rewrite the imports to `require` calls, so it is only useful when the output is
+/// transformed to CommonJS afterwards, like with `babel-jest`.
+///
+/// References:
+///
+/// * <https://github.com/jestjs/jest/tree/v29.7.0/packages/babel-plugin-jest-hoist>
+pub struct JestHoist<'a> {
+    allocator: &'a Allocator,
+
+    // states
+    /// Symbols of `jest` imported from `@jest/globals`.
+    jest_symbols: Vec<SymbolId>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Traverse<'a> for JestHoist<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.source.value.as_str() != JEST_GLOBALS_MODULE {
+                continue;
+            }
+            for specifier in decl.specifiers.iter().flatten() {
+                if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
+                    if specifier.imported.name().as_str() == "jest" {
+                        self.jest_symbols.push(specifier.local.symbol_id());
+                    }
+                }
+            }
+        }
+    }
+
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let constants = stmts.iter().filter_map(literal_constant).collect::<Vec<_>>();
+        let mut hoisted_calls = vec![];
+        let mut hoisted_constants = vec![];
+        for (index, stmt) in stmts.iter().enumerate() {
+            let Statement::ExpressionStatement(stmt) = stmt else { continue };
+            if self.is_hoistable_call(&stmt.expression, &constants, &mut hoisted_constants, ctx) {
+                hoisted_calls.push(index);
+            }
+        }
+        if hoisted_calls.is_empty() {
+            return;
+        }
+
+        let mut constants = vec![];
+        let mut calls = vec![];
+        let mut rest = vec![];
+        for (index, stmt) in stmts.drain(..).enumerate() {
+            if hoisted_calls.contains(&index) {
+                calls.push(stmt);
+            } else if literal_constant(&stmt).is_some_and(|id| hoisted_constants.contains(&id)) {
+                constants.push(stmt);
+            } else {
+                rest.push(stmt);
+            }
+        }
+        stmts.extend(constants.into_iter().chain(calls).chain(rest));
+    }
+}
+
+impl<'a> JestHoist<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { allocator, jest_symbols: vec![], errors: vec![] }
+    }
+
+    pub fn build(
+        mut self,
+        symbols: SymbolTable,
+        scopes: ScopeTree,
+        program: &mut Program<'a>,
+    ) -> JestHoistReturn {
+        let allocator = self.allocator;
+        let (symbols, scopes) = traverse_mut(&mut self, allocator, program, symbols, scopes);
+        JestHoistReturn { errors: self.errors, symbols, scopes }
+    }
+
+    /// Whether `expr` is a call of `jest` which should be hoisted, e.g. `jest.mock('./a')` or
+    /// `jest.mock('./a').mock('./b')`.
+    fn is_hoistable_call(
+        &mut self,
+        expr: &Expression<'a>,
+        constants: &[SymbolId],
+        hoisted_constants: &mut Vec<SymbolId>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let Expression::CallExpression(call) = expr else { return false };
+        let Some(member) = call.callee.as_member_expression() else { return false };
+        let Some(method) = member.static_property_name() else { return false };
+        let is_jest = match member.object() {
+            Expression::Identifier(ident) => {
+                match ctx.symbols().get_reference(ident.reference_id()).symbol_id() {
+                    Some(symbol_id) => self.jest_symbols.contains(&symbol_id),
+                    None => ident.name.as_str() == "jest",
+                }
+            }
+            object => self.is_hoistable_call(object, constants, hoisted_constants, ctx),
+        };
+        if !is_jest {
+            return false;
+        }
+
+        let arguments = &call.arguments;
+        match method {
+            "mock" => match arguments.len() {
+                1 => is_literal(&arguments[0]),
+                2 | 3 => {
+                    self.check_module_factory(&arguments[1], constants, hoisted_constants, ctx)
+                }
+                _ => false,
+            },
+            "unmock" | "deepUnmock" => {
+                arguments.len() == 1 && matches!(arguments[0], Argument::StringLiteral(_))
+            }
+            "enableAutomock" | "disableAutomock" => arguments.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Check the module factory of `jest.mock()`, reporting references to variables which may
+    /// not be initialized when it is hoisted.
+    fn check_module_factory(
+        &mut self,
+        factory: &Argument<'a>,
+        constants: &[SymbolId],
+        hoisted_constants: &mut Vec<SymbolId>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let factory_scope_id = match factory {
+            Argument::ArrowFunctionExpression(arrow) => arrow.scope_id(),
+            Argument::FunctionExpression(func) => func.scope_id(),
+            _ => {
+                self.errors.push(factory_not_function(factory.span()));
+                return false;
+            }
+        };
+
+        let mut references = FactoryReferences {
+            ctx,
+            factory_scope_id,
+            constants,
+            jest_symbols: &self.jest_symbols,
+            referenced_constants: vec![],
+            invalid: vec![],
+        };
+        references.visit_argument(factory);
+
+        if !references.invalid.is_empty() {
+            let errors = references
+                .invalid
+                .into_iter()
+                .map(|(name, span)| out_of_scope_reference(name.as_str(), span));
+            self.errors.extend(errors);
+            return false;
+        }
+        for symbol_id in references.referenced_constants {
+            if !hoisted_constants.contains(&symbol_id) {
+                hoisted_constants.push(symbol_id);
+            }
+        }
+        true
+    }
+}
+
+/// Checks the references of a module factory to variables outside of it.
+struct FactoryReferences<'a, 'c> {
+    ctx: &'c TraverseCtx<'a>,
+    factory_scope_id: ScopeId,
+    /// `const`s initialized with a literal, which are hoisted with the factory if it references
+    /// them.
+    constants: &'c [SymbolId],
+    jest_symbols: &'c [SymbolId],
+    referenced_constants: Vec<SymbolId>,
+    invalid: Vec<(Atom<'a>, Span)>,
+}
+
+impl<'a, 'c> Visit<'a> for FactoryReferences<'a, 'c> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.ctx.symbols().get_reference(ident.reference_id());
+        if !reference.is_value() || is_allowed_name(&ident.name) {
+            return;
+        }
+        match reference.symbol_id() {
+            Some(symbol_id) => {
+                let scope_id = self.ctx.symbols().get_scope_id(symbol_id);
+                if self.ctx.scopes().ancestors(scope_id).any(|id| id == self.factory_scope_id)
+                    || self.jest_symbols.contains(&symbol_id)
+                {
+                    return;
+                }
+                if self.constants.contains(&symbol_id) {
+                    self.referenced_constants.push(symbol_id);
+                    return;
+                }
+            }
+            None if ALLOWED_IDENTIFIERS.contains(&ident.name.as_str()) => return,
+            None => {}
+        }
+        self.invalid.push((ident.name.clone(), ident.span));
+    }
+}
+
+/// Names prefixed with `mock`, and the coverage variables of istanbul.
+fn is_allowed_name(name: &str) -> bool {
+    name.get(..4).is_some_and(|prefix| prefix.eq_ignore_ascii_case("mock"))
+        || name.starts_with("cov")
+        || name.starts_with("__cov")
+}
+
+fn is_literal(argument: &Argument) -> bool {
+    match argument {
+        Argument::TemplateLiteral(_) => true,
+        argument => argument.as_expression().is_some_and(Expression::is_literal),
+    }
+}
+
+/// The symbol of `const x = <literal>`.
+fn literal_constant(stmt: &Statement) -> Option<SymbolId> {
+    let Statement::VariableDeclaration(decl) = stmt else { return None };
+    let [declarator] = decl.declarations.as_slice() else { return None };
+    if decl.kind != VariableDeclarationKind::Const {
+        return None;
+    }
+    let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return None };
+    match &declarator.init {
+        Some(Expression::TemplateLiteral(lit)) if lit.expressions.is_empty() => {}
+        Some(init) if init.is_literal() => {}
+        _ => return None,
+    }
+    Some(ident.symbol_id())
+}
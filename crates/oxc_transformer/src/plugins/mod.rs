@@ -1,5 +1,7 @@
 mod inject_global_variables;
+mod jest_hoist;
 mod replace_global_defines;
 
 pub use inject_global_variables::*;
+pub use jest_hoist::*;
 pub use replace_global_defines::*;
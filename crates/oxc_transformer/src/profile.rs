@@ -0,0 +1,113 @@
+//! Time spent in each plugin of the transformer.
+//!
+//! Enabled with [`TransformOptions::profile`](crate::TransformOptions::profile).
+
+use std::time::{Duration, Instant};
+
+/// Time spent in the plugins of a transform, returned in
+/// [`TransformerReturn::profile`](crate::TransformerReturn::profile).
+#[derive(Debug, Default, Clone)]
+pub struct TransformProfile {
+    /// The plugins which were called, in the order they run in.
+    pub plugins: Vec<PluginProfile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PluginProfile {
+    /// Name of the plugin, e.g. `typescript` or `es2020`.
+    pub name: &'static str,
+    /// Total time spent in the visitor methods of the plugin.
+    pub duration: Duration,
+    /// Number of visitor methods of the plugin which were called.
+    pub calls: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Plugin {
+    TypeScript,
+    Jsx,
+    ES2022,
+    ES2021,
+    ES2020,
+    ES2019,
+    ES2018,
+    ES2017,
+    ES2016,
+    ES5,
+    RegExp,
+    Common,
+}
+
+impl Plugin {
+    const ALL: [Self; 12] = [
+        Self::TypeScript,
+        Self::Jsx,
+        Self::ES2022,
+        Self::ES2021,
+        Self::ES2020,
+        Self::ES2019,
+        Self::ES2018,
+        Self::ES2017,
+        Self::ES2016,
+        Self::ES5,
+        Self::RegExp,
+        Self::Common,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::TypeScript => "typescript",
+            Self::Jsx => "jsx",
+            Self::ES2022 => "es2022",
+            Self::ES2021 => "es2021",
+            Self::ES2020 => "es2020",
+            Self::ES2019 => "es2019",
+            Self::ES2018 => "es2018",
+            Self::ES2017 => "es2017",
+            Self::ES2016 => "es2016",
+            Self::ES5 => "es5",
+            Self::RegExp => "regexp",
+            Self::Common => "common",
+        }
+    }
+}
+
+/// Measures the visitor methods of each [`Plugin`], when profiling is enabled.
+pub(crate) struct Profiler {
+    plugins: Option<[(Duration, u32); Plugin::ALL.len()]>,
+}
+
+impl Profiler {
+    pub fn new(enabled: bool) -> Self {
+        Self { plugins: enabled.then(|| [(Duration::ZERO, 0); Plugin::ALL.len()]) }
+    }
+
+    /// Call `f`, a visitor method of `plugin`, and add the time it took to the plugin.
+    #[inline]
+    pub fn measure<T>(&mut self, plugin: Plugin, f: impl FnOnce() -> T) -> T {
+        let Some(plugins) = self.plugins.as_mut() else {
+            return f();
+        };
+        let start = Instant::now();
+        let result = f();
+        let (duration, calls) = &mut plugins[plugin as usize];
+        *duration += start.elapsed();
+        *calls += 1;
+        result
+    }
+
+    pub fn finish(self) -> Option<TransformProfile> {
+        let plugins = self.plugins?;
+        let plugins = Plugin::ALL
+            .into_iter()
+            .zip(plugins)
+            .filter(|(_, (_, calls))| *calls > 0)
+            .map(|(plugin, (duration, calls))| PluginProfile {
+                name: plugin.name(),
+                duration,
+                calls,
+            })
+            .collect();
+        Some(TransformProfile { plugins })
+    }
+}
@@ -1,3 +1,5 @@
+use oxc_regular_expression::RegExpFeatures;
+
 #[derive(Default, Debug, Clone, Copy)]
 pub struct RegExpOptions {
     /// Enables plugin to transform the RegExp literal with a `y` flag
@@ -32,3 +34,22 @@ pub struct RegExpOptions {
     /// ES2024 <https://babel.dev/docs/babel-plugin-transform-unicode-sets-regex>
     pub set_notation: bool,
 }
+
+impl RegExpOptions {
+    /// The features which are transformed, i.e. not supported by the targets.
+    ///
+    /// Pass them to `oxc_parser::ParseOptions::unsupported_regular_expression_features` to warn
+    /// about regular expressions which need a `RegExp` polyfill at runtime.
+    pub fn unsupported_features(&self) -> RegExpFeatures {
+        let mut features = RegExpFeatures::empty();
+        features.set(RegExpFeatures::STICKY_FLAG, self.sticky_flag);
+        features.set(RegExpFeatures::UNICODE_FLAG, self.unicode_flag);
+        features.set(RegExpFeatures::UNICODE_PROPERTY_ESCAPES, self.unicode_property_escapes);
+        features.set(RegExpFeatures::DOT_ALL_FLAG, self.dot_all_flag);
+        features.set(RegExpFeatures::NAMED_CAPTURE_GROUPS, self.named_capture_groups);
+        features.set(RegExpFeatures::LOOKBEHIND_ASSERTIONS, self.look_behind_assertions);
+        features.set(RegExpFeatures::MATCH_INDICES_FLAG, self.match_indices);
+        features.set(RegExpFeatures::UNICODE_SETS_FLAG, self.set_notation);
+        features
+    }
+}
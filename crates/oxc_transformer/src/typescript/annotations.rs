@@ -3,7 +3,7 @@ use rustc_hash::FxHashSet;
 use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::ast::*;
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::SymbolFlags;
+use oxc_semantic::UnusedImports;
 use oxc_span::{Atom, GetSpan, Span, SPAN};
 use oxc_syntax::{
     operator::AssignmentOperator,
@@ -64,6 +64,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut no_modules_remaining = true;
         let mut some_modules_deleted = false;
+        let unused_imports = UnusedImports::new(program, ctx.symbols());
 
         program.body.retain_mut(|stmt| {
             let need_retain = match stmt {
@@ -118,7 +119,8 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                                         &s.local
                                     }
                                 };
-                                self.has_value_reference(&id.name, ctx)
+                                !unused_imports.is_elided(id.symbol_id())
+                                    || self.is_jsx_imports(&id.name)
                             });
                             !specifiers.is_empty()
                         }
@@ -567,29 +569,6 @@ impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
             *stmt = Statement::BlockStatement(block);
         }
     }
-
-    pub fn has_value_reference(&self, name: &str, ctx: &TraverseCtx<'a>) -> bool {
-        if let Some(symbol_id) = ctx.scopes().get_root_binding(name) {
-            // `import T from 'mod'; const T = 1;` The T has a value redeclaration
-            // `import T from 'mod'; type T = number;` The T has a type redeclaration
-            // If the symbol is still a value symbol after SymbolFlags::Import is removed, then it's a value redeclaration.
-            // That means the import is shadowed, and we can safely remove the import.
-            let has_value_redeclaration =
-                (ctx.symbols().get_flags(symbol_id) - SymbolFlags::Import).is_value();
-            if has_value_redeclaration {
-                return false;
-            }
-            if ctx
-                .symbols()
-                .get_resolved_references(symbol_id)
-                .any(|reference| !reference.is_type())
-            {
-                return true;
-            }
-        }
-
-        self.is_jsx_imports(name)
-    }
 }
 
 struct Assignment<'a> {
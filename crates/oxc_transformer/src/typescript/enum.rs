@@ -1,7 +1,8 @@
 use rustc_hash::FxHashMap;
 
 use oxc_allocator::Vec as ArenaVec;
-use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
+use oxc_ast::{ast::*, NONE};
+use oxc_ast_visit::{walk_mut, VisitMut};
 use oxc_ecmascript::ToInt32;
 use oxc_span::{Atom, Span, SPAN};
 use oxc_syntax::{
@@ -12,17 +13,23 @@ use oxc_syntax::{
 };
 use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
 
-pub struct TypeScriptEnum<'a> {
+use crate::TransformCtx;
+
+use super::TypeScriptOptions;
+
+pub struct TypeScriptEnum<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    compact: bool,
     enums: FxHashMap<Atom<'a>, FxHashMap<Atom<'a>, ConstantValue>>,
 }
 
-impl<'a> TypeScriptEnum<'a> {
-    pub fn new() -> Self {
-        Self { enums: FxHashMap::default() }
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
+    pub fn new(options: &TypeScriptOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, compact: options.compact_enums, enums: FxHashMap::default() }
     }
 }
 
-impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
+impl<'a, 'ctx> Traverse<'a> for TypeScriptEnum<'a, 'ctx> {
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_stmt = match stmt {
             Statement::TSEnumDeclaration(ts_enum_decl) => {
@@ -39,13 +46,16 @@ impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
             _ => None,
         };
 
-        if let Some(new_stmt) = new_stmt {
+        if let Some((new_stmt, declaration)) = new_stmt {
             *stmt = new_stmt;
+            if let Some(declaration) = declaration {
+                self.ctx.statement_injector.insert_before(stmt, declaration);
+            }
         }
     }
 }
 
-impl<'a> TypeScriptEnum<'a> {
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
     /// ```TypeScript
     /// enum Foo {
     ///   X = 1,
@@ -59,12 +69,24 @@ impl<'a> TypeScriptEnum<'a> {
     ///   return Foo;
     /// })(Foo || {});
     /// ```
+    ///
+    /// Or with [`TypeScriptOptions::compact_enums`], as `tsc` does:
+    /// ```JavaScript
+    /// var Foo;
+    /// (function (Foo) {
+    ///   Foo[Foo["X"] = 1] = "X";
+    ///   Foo[Foo["Y"] = 2] = "Y";
+    /// })(Foo || (Foo = {}));
+    /// ```
+    ///
+    /// Returns the statement replacing the enum, and the declaration of the enum to insert before
+    /// it in the compact form.
     fn transform_ts_enum(
         &mut self,
         decl: &mut TSEnumDeclaration<'a>,
         export_span: Option<Span>,
         ctx: &mut TraverseCtx<'a>,
-    ) -> Option<Statement<'a>> {
+    ) -> Option<(Statement<'a>, Option<Statement<'a>>)> {
         if decl.declare {
             return None;
         }
@@ -115,6 +137,17 @@ impl<'a> TypeScriptEnum<'a> {
         ));
 
         let var_symbol_id = decl.id.symbol_id();
+        if self.compact {
+            return Some(self.transform_ts_enum_compact(
+                decl,
+                export_span,
+                callee,
+                is_already_declared,
+                is_not_top_scope,
+                ctx,
+            ));
+        }
+
         let arguments = if (is_export || is_not_top_scope) && !is_already_declared {
             // }({});
             let object_expr = ast.expression_object(SPAN, ast.vec(), None);
@@ -146,7 +179,7 @@ impl<'a> TypeScriptEnum<'a> {
             );
             let left = AssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(left));
             let expr = ast.expression_assignment(SPAN, op, left, call_expression);
-            return Some(ast.statement_expression(decl.span, expr));
+            return Some((ast.statement_expression(decl.span, expr), None));
         }
 
         let kind = if is_export || is_not_top_scope {
@@ -172,7 +205,71 @@ impl<'a> TypeScriptEnum<'a> {
         } else {
             Statement::from(variable_declaration)
         };
-        Some(stmt)
+        Some((stmt, None))
+    }
+
+    /// `(function (Foo) { ... })(Foo || (Foo = {}));`, and `var Foo;` unless the enum is merged
+    /// into an earlier declaration.
+    fn transform_ts_enum_compact(
+        &self,
+        decl: &TSEnumDeclaration<'a>,
+        export_span: Option<Span>,
+        callee: Expression<'a>,
+        is_already_declared: bool,
+        is_not_top_scope: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Statement<'a>, Option<Statement<'a>>) {
+        let ast = ctx.ast;
+        let enum_name = decl.id.name.clone();
+        let var_symbol_id = decl.id.symbol_id();
+
+        // Foo || (Foo = {})
+        let left = ctx.create_bound_reference_id(
+            decl.id.span,
+            enum_name.clone(),
+            var_symbol_id,
+            ReferenceFlags::Read,
+        );
+        let left = Expression::Identifier(ctx.alloc(left));
+        let target = ctx.create_bound_reference_id(
+            decl.id.span,
+            enum_name,
+            var_symbol_id,
+            ReferenceFlags::Write,
+        );
+        let target = AssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(target));
+        let object_expr = ast.expression_object(SPAN, ast.vec(), None);
+        let assignment =
+            ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, object_expr);
+        let right = ast.expression_parenthesized(SPAN, assignment);
+        let argument = ast.expression_logical(SPAN, left, LogicalOperator::Or, right);
+
+        let call_expression =
+            ast.expression_call(SPAN, callee, NONE, ast.vec1(Argument::from(argument)), false);
+        let stmt = ast.statement_expression(decl.span, call_expression);
+        if is_already_declared {
+            return (stmt, None);
+        }
+
+        // var Foo;
+        let kind = if is_not_top_scope {
+            VariableDeclarationKind::Let
+        } else {
+            VariableDeclarationKind::Var
+        };
+        let binding_pattern_kind =
+            BindingPatternKind::BindingIdentifier(ctx.alloc(decl.id.clone()));
+        let binding = ast.binding_pattern(binding_pattern_kind, NONE, false);
+        let decls = ast.vec1(ast.variable_declarator(SPAN, kind, binding, None, false));
+        let variable_declaration = ast.declaration_variable(SPAN, kind, decls, false);
+        let declaration = if let Some(export_span) = export_span {
+            let declaration =
+                ast.plain_export_named_declaration_declaration(export_span, variable_declaration);
+            Statement::ExportNamedDeclaration(declaration)
+        } else {
+            Statement::from(variable_declaration)
+        };
+        (stmt, Some(declaration))
     }
 
     #[allow(clippy::needless_pass_by_value)]
@@ -198,8 +295,11 @@ impl<'a> TypeScriptEnum<'a> {
             };
 
             let init = if let Some(initializer) = &mut member.initializer {
-                let constant_value =
-                    self.computed_constant_value(initializer, &previous_enum_members);
+                let constant_value = self.computed_constant_value(
+                    initializer,
+                    &param_binding.name,
+                    &previous_enum_members,
+                );
 
                 // prev_constant_value = constant_value
                 let init = match constant_value {
@@ -298,10 +398,12 @@ impl<'a> TypeScriptEnum<'a> {
 
         self.enums.insert(param_binding.name.clone(), previous_enum_members.clone());
 
-        let enum_ref = param_binding.create_read_expression(ctx);
-        // return Foo;
-        let return_stmt = ast.statement_return(SPAN, Some(enum_ref));
-        statements.push(return_stmt);
+        if !self.compact {
+            let enum_ref = param_binding.create_read_expression(ctx);
+            // return Foo;
+            let return_stmt = ast.statement_return(SPAN, Some(enum_ref));
+            statements.push(return_stmt);
+        }
 
         statements
     }
@@ -335,27 +437,35 @@ enum ConstantValue {
     String(String),
 }
 
-impl<'a> TypeScriptEnum<'a> {
+impl<'a, 'ctx> TypeScriptEnum<'a, 'ctx> {
     /// Evaluate the expression to a constant value.
     /// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
     fn computed_constant_value(
         &self,
         expr: &Expression<'a>,
+        enum_name: &Atom<'a>,
         prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
     ) -> Option<ConstantValue> {
-        self.evaluate(expr, prev_members)
+        self.evaluate(expr, enum_name, prev_members)
     }
 
     fn evaluate_ref(
         &self,
         expr: &Expression<'a>,
+        enum_name: &Atom<'a>,
         prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
     ) -> Option<ConstantValue> {
         match expr {
             match_member_expression!(Expression) => {
                 let expr = expr.to_member_expression();
                 let Expression::Identifier(ident) = expr.object() else { return None };
-                let members = self.enums.get(&ident.name)?;
+                // `E.a` refers to an earlier member of the enum being transformed, which is
+                // only recorded in `self.enums` once all of its members are.
+                let members = if ident.name == *enum_name {
+                    prev_members
+                } else {
+                    self.enums.get(&ident.name)?
+                };
                 let property = expr.static_property_name()?;
                 return members.get(property).cloned();
             }
@@ -384,26 +494,39 @@ impl<'a> TypeScriptEnum<'a> {
     fn evaluate(
         &self,
         expr: &Expression<'a>,
+        enum_name: &Atom<'a>,
         prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
     ) -> Option<ConstantValue> {
         match expr {
             Expression::Identifier(_)
             | Expression::ComputedMemberExpression(_)
             | Expression::StaticMemberExpression(_)
-            | Expression::PrivateFieldExpression(_) => self.evaluate_ref(expr, prev_members),
-            Expression::BinaryExpression(expr) => self.eval_binary_expression(expr, prev_members),
-            Expression::UnaryExpression(expr) => self.eval_unary_expression(expr, prev_members),
+            | Expression::PrivateFieldExpression(_) => {
+                self.evaluate_ref(expr, enum_name, prev_members)
+            }
+            Expression::BinaryExpression(expr) => {
+                self.eval_binary_expression(expr, enum_name, prev_members)
+            }
+            Expression::UnaryExpression(expr) => {
+                self.eval_unary_expression(expr, enum_name, prev_members)
+            }
             Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
             Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.to_string())),
             Expression::TemplateLiteral(lit) => {
                 let mut value = String::new();
-                for part in &lit.quasis {
+                for (i, part) in lit.quasis.iter().enumerate() {
                     value.push_str(&part.value.raw);
+                    if let Some(expr) = lit.expressions.get(i) {
+                        match self.evaluate(expr, enum_name, prev_members)? {
+                            ConstantValue::String(str) => value.push_str(&str),
+                            ConstantValue::Number(v) => value.push_str(&v.to_js_string()),
+                        }
+                    }
                 }
                 Some(ConstantValue::String(value))
             }
             Expression::ParenthesizedExpression(expr) => {
-                self.evaluate(&expr.expression, prev_members)
+                self.evaluate(&expr.expression, enum_name, prev_members)
             }
             _ => None,
         }
@@ -413,10 +536,11 @@ impl<'a> TypeScriptEnum<'a> {
     fn eval_binary_expression(
         &self,
         expr: &BinaryExpression<'a>,
+        enum_name: &Atom<'a>,
         prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
     ) -> Option<ConstantValue> {
-        let left = self.evaluate(&expr.left, prev_members)?;
-        let right = self.evaluate(&expr.right, prev_members)?;
+        let left = self.evaluate(&expr.left, enum_name, prev_members)?;
+        let right = self.evaluate(&expr.right, enum_name, prev_members)?;
 
         if matches!(expr.operator, BinaryOperator::Addition)
             && (matches!(left, ConstantValue::String(_))
@@ -478,9 +602,10 @@ impl<'a> TypeScriptEnum<'a> {
     fn eval_unary_expression(
         &self,
         expr: &UnaryExpression<'a>,
+        enum_name: &Atom<'a>,
         prev_members: &FxHashMap<Atom<'a>, ConstantValue>,
     ) -> Option<ConstantValue> {
-        let value = self.evaluate(&expr.argument, prev_members)?;
+        let value = self.evaluate(&expr.argument, enum_name, prev_members)?;
 
         let value = match value {
             ConstantValue::Number(value) => value,
@@ -45,7 +45,7 @@ pub struct TypeScript<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
 
     annotations: TypeScriptAnnotations<'a, 'ctx>,
-    r#enum: TypeScriptEnum<'a>,
+    r#enum: TypeScriptEnum<'a, 'ctx>,
     namespace: TypeScriptNamespace<'a, 'ctx>,
     module: TypeScriptModule<'a, 'ctx>,
     rewrite_extensions: Option<TypeScriptRewriteExtensions>,
@@ -56,7 +56,7 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
         Self {
             ctx,
             annotations: TypeScriptAnnotations::new(options, ctx),
-            r#enum: TypeScriptEnum::new(),
+            r#enum: TypeScriptEnum::new(options, ctx),
             namespace: TypeScriptNamespace::new(options, ctx),
             module: TypeScriptModule::new(ctx),
             rewrite_extensions: TypeScriptRewriteExtensions::new(options),
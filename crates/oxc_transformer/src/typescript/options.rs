@@ -47,6 +47,10 @@ pub struct TypeScriptOptions {
     /// Unused.
     pub optimize_const_enums: bool,
 
+    /// Emit enums in the compact form of `tsc`, `var E; (function (E) { ... })(E || (E = {}));`,
+    /// instead of the form of Babel, `var E = function (E) { ...; return E; }(E || {});`.
+    pub compact_enums: bool,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -67,6 +71,7 @@ impl Default for TypeScriptOptions {
             allow_namespaces: default_as_true(),
             allow_declare_fields: default_as_true(),
             optimize_const_enums: false,
+            compact_enums: false,
             rewrite_import_extensions: None,
         }
     }
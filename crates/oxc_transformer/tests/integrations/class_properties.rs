@@ -0,0 +1,59 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test};
+
+fn test_set_public_class_fields(source_text: &str, expected: &str) {
+    let mut options = TransformOptions::from_target("es2021").unwrap();
+    options.assumptions.set_public_class_fields = true;
+    let result = test(source_text, &options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::mjs()), "for source {source_text}");
+}
+
+#[test]
+fn set_public_class_fields() {
+    test_set_public_class_fields(
+        "class C { foo = 1; bar; static baz = 2; #qux = 3; method() {} }",
+        "class C { constructor() { this.foo = 1; this.bar = void 0; } static baz = 2; #qux = 3; method() {} }",
+    );
+    test_set_public_class_fields(
+        "class C extends S { foo = () => this.bar; }",
+        "class C extends S { constructor(...args) { super(...args); this.foo = () => this.bar; } }",
+    );
+    test_set_public_class_fields(
+        "let args = 1; class C extends S { foo = args; }",
+        "let args = 1; class C extends S { constructor(..._args) { super(..._args); this.foo = args; } }",
+    );
+    test_set_public_class_fields(
+        "let x = 1; class C extends S { foo = x; 'a-b' = 2; constructor(x) { super(x); log(x); } }",
+        "let x = 1; class C extends S { constructor(_x) { super(_x); this.foo = x; this['a-b'] = 2; log(_x); } }",
+    );
+
+    // Not supported yet
+    test_set_public_class_fields("class C { [foo] = 1 }", "class C { [foo] = 1 }");
+    test_set_public_class_fields(
+        "class C extends S { foo = 1; constructor() { if (a) super(); else super(); } }",
+        "class C extends S { foo = 1; constructor() { if (a) super(); else super(); } }",
+    );
+}
+
+#[test]
+fn keep_class_names() {
+    let mut options = TransformOptions::from_target("es2021").unwrap();
+    options.assumptions.set_public_class_fields = true;
+    options.keep_class_names = true;
+    let test_keep_class_names = |source_text: &str, expected: &str| {
+        let result = test(source_text, &options).unwrap();
+        assert_eq!(result, codegen(expected, SourceType::mjs()), "for source {source_text}");
+    };
+
+    test_keep_class_names(
+        "class C { foo = class {}; 'a-b' = class {}; }",
+        "class C { constructor() { this.foo = Object.defineProperty(class {}, 'name', { value: 'foo', configurable: true }); this['a-b'] = Object.defineProperty(class {}, 'name', { value: 'a-b', configurable: true }); } }",
+    );
+    // Named classes and classes with their own static `name` keep their names anyway
+    test_keep_class_names(
+        "class C { foo = class Bar {}; baz = class { static name = 'qux' }; }",
+        "class C { constructor() { this.foo = class Bar {}; this.baz = class { static name = 'qux' }; } }",
+    );
+}
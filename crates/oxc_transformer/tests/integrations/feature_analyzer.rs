@@ -0,0 +1,85 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::{ESFeature, ESFeatureAnalyzer, ESTarget};
+
+fn analyze(source_text: &str) -> (Vec<(ESFeature, &str)>, ESTarget) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty(), "{source_text}");
+    let ret = ESFeatureAnalyzer::new(&allocator).build(&ret.program);
+    assert!(ret.errors.is_empty(), "{source_text}");
+    let features = ret
+        .features
+        .iter()
+        .map(|usage| (usage.feature, usage.span.source_text(source_text)))
+        .collect();
+    (features, ret.es_target())
+}
+
+#[test]
+fn es5() {
+    let (features, target) = analyze("var a = function (b) { return [b, { c: 1 }]; }");
+    assert!(features.is_empty());
+    assert_eq!(target, ESTarget::ES5);
+}
+
+#[test]
+fn features() {
+    let cases = [
+        ("const a = 1", ESFeature::ES2015BlockScoping, "const a = 1"),
+        ("() => {}", ESFeature::ES2015ArrowFunctions, "() => {}"),
+        ("function f(a = 1) {}", ESFeature::ES2015Parameters, "a = 1"),
+        ("f(...a)", ESFeature::ES2015Spread, "...a"),
+        ("for (a of b);", ESFeature::ES2015ForOf, "for (a of b);"),
+        ("({ a })", ESFeature::ES2015ShorthandProperties, "a"),
+        ("({ [a]: 1 })", ESFeature::ES2015ComputedProperties, "[a]: 1"),
+        ("[a] = b", ESFeature::ES2015Destructuring, "[a]"),
+        ("0b11", ESFeature::ES2015Literals, "0b11"),
+        ("'\\u{61}'", ESFeature::ES2015UnicodeEscapes, "'\\u{61}'"),
+        ("/a/y", ESFeature::ES2015StickyRegex, "/a/y"),
+        ("a ** b", ESFeature::ES2016ExponentiationOperator, "a ** b"),
+        ("async function f() {}", ESFeature::ES2017AsyncToGenerator, "async function f() {}"),
+        ("({ ...a })", ESFeature::ES2018ObjectRestSpread, "...a"),
+        ("try {} catch {}", ESFeature::ES2018OptionalCatchBinding, "catch {}"),
+        ("/(?<a>b)/", ESFeature::ES2018NamedCapturingGroupsRegex, "(?<a>b)"),
+        ("/(?<=a)b/", ESFeature::ES2018LookbehindRegex, "(?<=a)"),
+        ("/\\p{L}/u", ESFeature::ES2018UnicodePropertyRegex, "\\p{L}"),
+        ("a?.b", ESFeature::ES2019OptionalChaining, "a?.b"),
+        ("a ?? b", ESFeature::ES2020NullishCoalescingOperator, "a ?? b"),
+        ("a ||= b", ESFeature::ES2020LogicalAssignmentOperators, "a ||= b"),
+        ("1n", ESFeature::ES2020BigInt, "1n"),
+        ("1_000", ESFeature::ES2021NumericSeparator, "1_000"),
+        ("class A { #a() {} }", ESFeature::ES2022PrivateMethods, "#a() {}"),
+        ("class A { static {} }", ESFeature::ES2022ClassStaticBlock, "static {}"),
+        ("/a/d", ESFeature::ES2022MatchIndicesRegex, "/a/d"),
+        ("/[a]/v", ESFeature::ES2024UnicodeSetsRegex, "/[a]/v"),
+        ("/(?i:a)/", ESFeature::ES2025RegexpModifiers, "(?i:a)"),
+        ("/(?<a>b)|(?<a>c)/", ESFeature::ES2025DuplicateNamedCapturingGroupsRegex, "(?<a>c)"),
+    ];
+
+    for (source_text, feature, span_text) in cases {
+        let (features, target) = analyze(source_text);
+        assert!(
+            features.contains(&(feature, span_text)),
+            "expected {feature:?} at `{span_text}` in `{source_text}`, found {features:?}"
+        );
+        assert_eq!(target, feature.es_target(), "{source_text}");
+    }
+}
+
+#[test]
+fn source_order() {
+    let (features, target) = analyze("let a = b ?? c\nconst d = () => e?.f");
+    assert_eq!(
+        features,
+        vec![
+            (ESFeature::ES2015BlockScoping, "let a = b ?? c"),
+            (ESFeature::ES2020NullishCoalescingOperator, "b ?? c"),
+            (ESFeature::ES2015BlockScoping, "const d = () => e?.f"),
+            (ESFeature::ES2015ArrowFunctions, "() => e?.f"),
+            (ESFeature::ES2019OptionalChaining, "e?.f"),
+        ]
+    );
+    assert_eq!(target, ESTarget::ES2020);
+}
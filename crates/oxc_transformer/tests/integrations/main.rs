@@ -1,6 +1,11 @@
+mod class_properties;
 mod es_target;
+mod feature_analyzer;
+mod module_expressions;
 mod plugins;
+mod profile;
 mod targets;
+mod typescript_enum;
 
 use std::path::Path;
 
@@ -25,7 +30,14 @@ pub(crate) fn test(
     source_text: &str,
     options: &TransformOptions,
 ) -> Result<String, Vec<OxcDiagnostic>> {
-    let source_type = SourceType::default();
+    test_with_source_type(source_text, SourceType::default(), options)
+}
+
+pub(crate) fn test_with_source_type(
+    source_text: &str,
+    source_type: SourceType,
+    options: &TransformOptions,
+) -> Result<String, Vec<OxcDiagnostic>> {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let mut program = ret.program;
@@ -0,0 +1,34 @@
+use oxc_span::SourceType;
+use oxc_transformer::{JsxOptions, ReactRefreshOptions, TransformOptions};
+
+use crate::test_with_source_type;
+
+#[test]
+fn filename_expression() {
+    let options = TransformOptions {
+        jsx: JsxOptions { development: true, ..JsxOptions::default() },
+        filename_expression: Some("import.meta.url".to_string()),
+        ..TransformOptions::default()
+    };
+    let result = test_with_source_type("<div />", SourceType::jsx(), &options).unwrap();
+    assert!(result.contains("var _jsxFileName = import.meta.url;"), "{result}");
+
+    let options = TransformOptions { filename_expression: Some("a +".to_string()), ..options };
+    assert!(test_with_source_type("<div />", SourceType::jsx(), &options).is_err());
+}
+
+#[test]
+fn module_id_expression() {
+    let options = TransformOptions {
+        jsx: JsxOptions { refresh: Some(ReactRefreshOptions::default()), ..JsxOptions::default() },
+        module_id_expression: Some("module.id".to_string()),
+        ..TransformOptions::default()
+    };
+    let result = test_with_source_type(
+        "export default function App() { return <div />; }",
+        SourceType::jsx(),
+        &options,
+    )
+    .unwrap();
+    assert!(result.contains("$RefreshReg$(_c, module.id + ' App');"), "{result}");
+}
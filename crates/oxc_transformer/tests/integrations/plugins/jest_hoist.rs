@@ -0,0 +1,183 @@
+//! References
+//!
+//! * <https://github.com/jestjs/jest/tree/v29.7.0/packages/babel-plugin-jest-hoist/src/__tests__>
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::JestHoist;
+
+use crate::codegen;
+
+fn transform(source_text: &str) -> Result<String, Vec<OxcDiagnostic>> {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let ret = JestHoist::new(&allocator).build(symbols, scopes, &mut program);
+    if !ret.errors.is_empty() {
+        return Err(ret.errors);
+    }
+    Ok(CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code)
+}
+
+fn test(source_text: &str, expected: &str) {
+    let result = transform(source_text).unwrap();
+    let expected = codegen(expected, SourceType::mjs());
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+fn test_error(source_text: &str, message: &str) {
+    let errors = transform(source_text).unwrap_err();
+    assert_eq!(errors.len(), 1, "for source {source_text}");
+    assert_eq!(errors[0].to_string(), message, "for source {source_text}");
+}
+
+#[test]
+fn hoists_above_imports() {
+    test(
+        "
+        import { a } from './a';
+        import b from './b';
+        jest.mock('./a');
+        a();
+        jest.unmock('./b');
+        jest.enableAutomock();
+        ",
+        "
+        jest.mock('./a');
+        jest.unmock('./b');
+        jest.enableAutomock();
+        import { a } from './a';
+        import b from './b';
+        a();
+        ",
+    );
+}
+
+#[test]
+fn hoists_within_blocks() {
+    test(
+        "
+        describe('a', () => {
+            foo();
+            jest.mock('./a', () => ({ a: 1 }));
+        });
+        ",
+        "
+        describe('a', () => {
+            jest.mock('./a', () => ({ a: 1 }));
+            foo();
+        });
+        ",
+    );
+}
+
+#[test]
+fn hoists_chains() {
+    test(
+        "
+        import a from './a';
+        jest.mock('./a').mock('./b', () => {});
+        ",
+        "
+        jest.mock('./a').mock('./b', () => {});
+        import a from './a';
+        ",
+    );
+}
+
+#[test]
+fn jest_from_globals() {
+    test(
+        "
+        import { jest as j } from '@jest/globals';
+        import a from './a';
+        j.mock('./a', () => j.fn());
+        ",
+        "
+        j.mock('./a', () => j.fn());
+        import { jest as j } from '@jest/globals';
+        import a from './a';
+        ",
+    );
+    // `jest` is a local variable.
+    test_same(
+        "
+        const jest = { mock() {} };
+        foo();
+        jest.mock('./a');
+        ",
+    );
+}
+
+#[test]
+fn ignores_invalid_calls() {
+    test_same(
+        "
+        foo();
+        jest.mock();
+        jest.mock(a);
+        jest.unmock('./a', 1);
+        jest.disableAutomock(true);
+        jest.fn();
+        jest.mock('./a')();
+        ",
+    );
+}
+
+#[test]
+fn module_factory_references() {
+    test(
+        "
+        import a from './a';
+        const mockValue = { a: 1 };
+        const value = 'value';
+        jest.mock('./a', function factory() {
+            const local = 1;
+            return { local, mockValue, MockValue: mockValue, value, Math, require, __cov_1 };
+        });
+        ",
+        "
+        const value = 'value';
+        jest.mock('./a', function factory() {
+            const local = 1;
+            return { local, mockValue, MockValue: mockValue, value, Math, require, __cov_1 };
+        });
+        import a from './a';
+        const mockValue = { a: 1 };
+        ",
+    );
+}
+
+#[test]
+fn module_factory_errors() {
+    test_error(
+        "import a from './a'; jest.mock('./a', () => a);",
+        "The module factory of `jest.mock()` is not allowed to reference any out-of-scope variables.",
+    );
+    test_error(
+        "const value = {}; jest.mock('./a', () => value);",
+        "The module factory of `jest.mock()` is not allowed to reference any out-of-scope variables.",
+    );
+    test_error(
+        "jest.mock('./a', () => window);",
+        "The module factory of `jest.mock()` is not allowed to reference any out-of-scope variables.",
+    );
+    test_error(
+        "jest.mock('./a', factory);",
+        "The second argument of `jest.mock` must be an inline function.",
+    );
+}
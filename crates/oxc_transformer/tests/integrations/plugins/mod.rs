@@ -1,2 +1,3 @@
 mod inject_global_variables;
+mod jest_hoist;
 mod replace_global_defines;
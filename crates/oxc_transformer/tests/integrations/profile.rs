@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, TransformOptions, TransformProfile, Transformer};
+
+fn profile(source_text: &str, options: &TransformOptions) -> Option<TransformProfile> {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    Transformer::new(&allocator, Path::new(""), options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program)
+        .profile
+}
+
+#[test]
+fn profile_plugins() {
+    let options = TransformOptions::from(ESTarget::ES2015);
+    assert!(profile("a ?? b", &options).is_none());
+
+    let options = TransformOptions { profile: true, ..options };
+    let profile = profile("a ?? b", &options).unwrap();
+    let names = profile.plugins.iter().map(|plugin| plugin.name).collect::<Vec<_>>();
+    assert!(names.contains(&"es2020"), "{names:?}");
+    assert!(!names.contains(&"typescript"), "{names:?}");
+    assert!(profile.plugins.iter().all(|plugin| plugin.calls > 0));
+}
@@ -1,6 +1,6 @@
 use crate::{codegen, test};
 use oxc_span::SourceType;
-use oxc_transformer::{ESTarget, EnvOptions, TransformOptions};
+use oxc_transformer::{BabelOptions, ESTarget, EnvOptions, TransformOptions};
 
 #[test]
 fn targets() {
@@ -34,3 +34,30 @@ fn targets() {
         assert_eq!(test(case, &options), test(case, &options_node));
     }
 }
+
+#[test]
+fn es3_reserved_words() {
+    let options = TransformOptions {
+        env: EnvOptions::from_browserslist_query("ie 8").unwrap(),
+        ..TransformOptions::default()
+    };
+    let code = "obj.default = { class: 1, a: 2, if() {} }; obj.b.char.c;";
+    let expected =
+        "obj['default'] = {\n\t'class': 1,\n\ta: 2,\n\t'if'() {}\n};\nobj.b['char'].c;\n";
+    assert_eq!(test(code, &options), Ok(expected.to_string()));
+
+    // Reserved words are allowed as property names since ES5.
+    let options = TransformOptions::from(ESTarget::ES5);
+    assert_eq!(test(code, &options), Ok(codegen(code, SourceType::mjs())));
+}
+
+#[test]
+fn babel_targets_warnings() {
+    let options: BabelOptions = serde_json::from_str(
+        r#"{ "presets": [["env", { "targets": { "foo": "1", "chrome": "80" } }]] }"#,
+    )
+    .unwrap();
+    let (_, warnings) = TransformOptions::from_babel_options(&options).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].to_string().contains("foo"), "{warnings:?}");
+}
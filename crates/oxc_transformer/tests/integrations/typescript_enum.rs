@@ -0,0 +1,45 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test_with_source_type};
+
+fn test_enum(source_text: &str, expected: &str, compact: bool) {
+    let mut options = TransformOptions::default();
+    options.typescript.compact_enums = compact;
+    let result = test_with_source_type(source_text, SourceType::ts(), &options).unwrap();
+    assert_eq!(result, codegen(expected, SourceType::mjs()), "for source {source_text}");
+}
+
+#[test]
+fn constant_folding() {
+    test_enum(
+        "enum E { A = 1, B = E.A + 1, C = E['B'] * 2 }",
+        "var E = function(E) { E[E['A'] = 1] = 'A'; E[E['B'] = 2] = 'B'; E[E['C'] = 4] = 'C'; return E; }(E || {});",
+        false,
+    );
+    test_enum(
+        "enum E { A = 1 } enum F { B = E.A << 2, C = `${B}` }",
+        "var E = function(E) { E[E['A'] = 1] = 'A'; return E; }(E || {}); var F = function(F) { F[F['B'] = 4] = 'B'; F['C'] = '4'; return F; }(F || {});",
+        false,
+    );
+}
+
+#[test]
+fn compact() {
+    test_enum(
+        "enum E { A = 1, B }",
+        "var E; (function(E) { E[E['A'] = 1] = 'A'; E[E['B'] = 2] = 'B'; })(E || (E = {}));",
+        true,
+    );
+    test_enum(
+        "export enum E { A }",
+        "export var E; (function(E) { E[E['A'] = 0] = 'A'; })(E || (E = {}));",
+        true,
+    );
+    // Merged declarations are declared once, and fold the members of the earlier ones.
+    test_enum(
+        "enum E { A } enum E { B = A + 1 }",
+        "var E; (function(E) { E[E['A'] = 0] = 'A'; })(E || (E = {})); (function(E) { E[E['B'] = 1] = 'B'; })(E || (E = {}));",
+        true,
+    );
+}
@@ -9327,6 +9327,7 @@ pub(crate) const OFFSET_IMPORT_DECLARATION_WITH_CLAUSE: usize =
     offset_of!(ImportDeclaration, with_clause);
 pub(crate) const OFFSET_IMPORT_DECLARATION_IMPORT_KIND: usize =
     offset_of!(ImportDeclaration, import_kind);
+pub(crate) const OFFSET_IMPORT_DECLARATION_PHASE: usize = offset_of!(ImportDeclaration, phase);
 
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug)]
@@ -9364,6 +9365,14 @@ impl<'a, 't> ImportDeclarationWithoutSpecifiers<'a, 't> {
                 as *const ImportOrExportKind)
         }
     }
+
+    #[inline]
+    pub fn phase(self) -> &'t Option<ImportPhase> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_IMPORT_DECLARATION_PHASE)
+                as *const Option<ImportPhase>)
+        }
+    }
 }
 
 impl<'a, 't> GetAddress for ImportDeclarationWithoutSpecifiers<'a, 't> {
@@ -9409,6 +9418,14 @@ impl<'a, 't> ImportDeclarationWithoutSource<'a, 't> {
                 as *const ImportOrExportKind)
         }
     }
+
+    #[inline]
+    pub fn phase(self) -> &'t Option<ImportPhase> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_IMPORT_DECLARATION_PHASE)
+                as *const Option<ImportPhase>)
+        }
+    }
 }
 
 impl<'a, 't> GetAddress for ImportDeclarationWithoutSource<'a, 't> {
@@ -9454,6 +9471,14 @@ impl<'a, 't> ImportDeclarationWithoutWithClause<'a, 't> {
                 as *const ImportOrExportKind)
         }
     }
+
+    #[inline]
+    pub fn phase(self) -> &'t Option<ImportPhase> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_IMPORT_DECLARATION_PHASE)
+                as *const Option<ImportPhase>)
+        }
+    }
 }
 
 impl<'a, 't> GetAddress for ImportDeclarationWithoutWithClause<'a, 't> {
@@ -11,7 +11,8 @@ use std::{
 
 use oxc::{
     allocator::Allocator,
-    ast::{ast::Program, Comment as OxcComment, CommentKind, Visit},
+    ast::{ast::Program, Comment as OxcComment, CommentKind},
+    ast_visit::Visit,
     codegen::{CodeGenerator, CodegenOptions},
     diagnostics::Error,
     minifier::{CompressOptions, Minifier, MinifierOptions},
@@ -193,6 +194,7 @@ impl Oxc {
             preserve_parens: parser_options
                 .preserve_parens
                 .unwrap_or(default_parser_options.preserve_parens),
+            ..default_parser_options
         };
         let ParserReturn { mut program, errors, .. } =
             Parser::new(&allocator, source_text, source_type)
@@ -267,6 +269,7 @@ impl Oxc {
                 } else {
                     CompressOptions::all_false()
                 },
+                ..MinifierOptions::default()
             };
             Minifier::new(options).build(&allocator, &mut program).mangler
         } else {
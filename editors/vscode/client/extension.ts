@@ -12,6 +12,24 @@ import { ConfigService } from './config';
 const languageClientName = 'oxc';
 const outputChannelName = 'Oxc';
 const commandPrefix = 'oxc';
+// The extensions of `VALID_EXTENSIONS` and `LINT_PARTIAL_LOADER_EXT` in the language server.
+const supportedExtensions = [
+  'js',
+  'mjs',
+  'cjs',
+  'jsx',
+  'ts',
+  'mts',
+  'cts',
+  'tsx',
+  'vue',
+  'astro',
+  'svelte',
+  'md',
+  'mdx',
+  'html',
+  'htm',
+];
 
 const enum OxcCommands {
   RestartServer = `${commandPrefix}.restartServer`,
@@ -127,26 +145,15 @@ export async function activate(context: ExtensionContext) {
   // Otherwise the run options are used
   // Options to control the language client
   let clientOptions: LanguageClientOptions = {
-    // Register the server for plain text documents
+    // Register the server for all the files it can lint, matching extensions instead of
+    // languages, which are not known without extensions for e.g. Astro.
+    // The server watches its config files itself.
     documentSelector: [
-      'typescript',
-      'javascript',
-      'typescriptreact',
-      'javascriptreact',
-      'vue',
-      'svelte',
-    ].map((lang) => ({
-      language: lang,
-      scheme: 'file',
-    })),
-    synchronize: {
-      // Notify the server about file config changes in the workspace
-      fileEvents: [
-        workspace.createFileSystemWatcher('**/.eslintr{c,c.json}'),
-        workspace.createFileSystemWatcher('**/.oxlint{.json,rc.json,rc}'),
-        workspace.createFileSystemWatcher('**/oxlint{.json,rc.json}'),
-      ],
-    },
+      {
+        pattern: `**/*.{${supportedExtensions.join(',')}}`,
+        scheme: 'file',
+      },
+    ],
     initializationOptions: {
       settings: config.toLanguageServerConfig(),
     },
@@ -1,3 +1,42 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
-export declare function minify(filename: string, sourceText: string): string;
+/** An error or warning reported while processing a file. */
+export interface Diagnostic {
+  severity: 'Error' | 'Warning' | 'Advice'
+  /** The code of the diagnostic, e.g. `TS1109`. */
+  code?: string
+  message: string
+  /** The source ranges the diagnostic refers to. */
+  labels: Array<DiagnosticLabel>
+  help?: string
+  /**
+   * The diagnostic rendered with a code frame of the source text, as printed by the CLI.
+   *
+   * This is not set if the diagnostic does not refer to the source text.
+   */
+  codeframe?: string
+}
+
+/** A source range of a {@link Diagnostic}. */
+export interface DiagnosticLabel {
+  message?: string
+  /** Start offset of the range, in UTF-8 bytes. */
+  start: number
+  /** End offset of the range, in UTF-8 bytes. */
+  end: number
+}
+
+export declare function minify(filename: string, sourceText: string): MinifyResult
+
+export interface MinifyResult {
+  /** The minified code. */
+  code: string
+  /**
+   * Parse errors.
+   *
+   * Oxc's parser recovers from common syntax errors, meaning that
+   * minified code may still be available even if there are errors in this
+   * list.
+   */
+  errors: Array<Diagnostic>
+}
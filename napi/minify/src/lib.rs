@@ -1,27 +1,42 @@
+use std::path::Path;
+
 use napi_derive::napi;
 
-use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
-use oxc_parser::Parser;
-use oxc_span::SourceType;
+use oxc::{
+    allocator::Allocator,
+    codegen::{Codegen, CodegenOptions},
+    minifier::{CompressOptions, Minifier, MinifierOptions},
+    napi::{diagnostic::Diagnostic, minify::MinifyResult},
+    parser::Parser,
+    span::SourceType,
+};
+
+pub use oxc::napi::{diagnostic, minify};
 
 #[allow(clippy::needless_pass_by_value)]
 #[napi]
-pub fn minify(filename: String, source_text: String) -> String {
+pub fn minify(filename: String, source_text: String) -> MinifyResult {
     let allocator = Allocator::default();
     let source_type = SourceType::from_path(&filename).unwrap_or_default().with_typescript(true);
 
-    let mut program = Parser::new(&allocator, &source_text, source_type).parse().program;
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let mut program = ret.program;
 
-    let mangler =
-        Minifier::new(MinifierOptions { mangle: true, compress: CompressOptions::default() })
-            .build(&allocator, &mut program)
-            .mangler;
+    let options = MinifierOptions {
+        mangle: true,
+        compress: CompressOptions::default(),
+        ..MinifierOptions::default()
+    };
+    let mangler = Minifier::new(options).build(&allocator, &mut program).mangler;
 
-    Codegen::new()
+    let code = Codegen::new()
         .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
         .with_mangler(mangler)
         .build(&program)
-        .code
+        .code;
+
+    let errors =
+        Diagnostic::from_diagnostics(Path::new(&filename), source_type, &source_text, ret.errors);
+
+    MinifyResult { code, errors }
 }
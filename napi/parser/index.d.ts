@@ -9,6 +9,32 @@ export interface Comment {
   end: number
 }
 
+/** An error or warning reported while processing a file. */
+export interface Diagnostic {
+  severity: 'Error' | 'Warning' | 'Advice'
+  /** The code of the diagnostic, e.g. `TS1109`. */
+  code?: string
+  message: string
+  /** The source ranges the diagnostic refers to. */
+  labels: Array<DiagnosticLabel>
+  help?: string
+  /**
+   * The diagnostic rendered with a code frame of the source text, as printed by the CLI.
+   *
+   * This is not set if the diagnostic does not refer to the source text.
+   */
+  codeframe?: string
+}
+
+/** A source range of a {@link Diagnostic}. */
+export interface DiagnosticLabel {
+  message?: string
+  /** Start offset of the range, in UTF-8 bytes. */
+  start: number
+  /** End offset of the range, in UTF-8 bytes. */
+  end: number
+}
+
 export interface ModuleLexer {
   imports: Array<ModuleLexerImportSpecifier>
   exports: Array<ModuleLexerExportSpecifier>
@@ -96,7 +122,7 @@ export declare function parseAsync(sourceText: string, options?: ParserOptions |
 export interface ParseResult {
   program: import("@oxc-project/types").Program
   comments: Array<Comment>
-  errors: Array<string>
+  errors: Array<Diagnostic>
 }
 
 /**
@@ -1,6 +1,6 @@
 mod module_lexer;
 
-use std::sync::Arc;
+use std::path::Path;
 
 use napi::{bindgen_prelude::AsyncTask, Task};
 use napi_derive::napi;
@@ -8,30 +8,35 @@ use napi_derive::napi;
 use oxc::{
     allocator::Allocator,
     ast::CommentKind,
-    diagnostics::{Error, NamedSource},
-    napi::parse::{Comment, ParseResult, ParserOptions},
+    napi::{
+        diagnostic::Diagnostic,
+        parse::{Comment, ParseResult, ParserOptions},
+    },
     parser::{ParseOptions, Parser, ParserReturn},
     span::SourceType,
 };
 
 pub use crate::module_lexer::*;
 
-fn parse<'a>(
-    allocator: &'a Allocator,
-    source_text: &'a str,
-    options: &ParserOptions,
-) -> ParserReturn<'a> {
+fn get_source_type(options: &ParserOptions) -> SourceType {
     let source_type = options
         .source_filename
         .as_ref()
         .and_then(|name| SourceType::from_path(name).ok())
         .unwrap_or_default();
-    let source_type = match options.source_type.as_deref() {
+    match options.source_type.as_deref() {
         Some("script") => source_type.with_script(true),
         Some("module") => source_type.with_module(true),
         _ => source_type,
-    };
-    Parser::new(allocator, source_text, source_type)
+    }
+}
+
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    options: &ParserOptions,
+) -> ParserReturn<'a> {
+    Parser::new(allocator, source_text, get_source_type(options))
         .with_options(ParseOptions {
             preserve_parens: options.preserve_parens.unwrap_or(true),
             ..ParseOptions::default()
@@ -60,17 +65,13 @@ fn parse_with_return<'a>(source_text: &'a str, options: &ParserOptions) -> Parse
     let ret = parse(&allocator, source_text, options);
     let program = serde_json::to_string(&ret.program).unwrap();
 
-    let errors = if ret.errors.is_empty() {
-        vec![]
-    } else {
-        let file_name = options.source_filename.clone().unwrap_or_default();
-        let source = Arc::new(NamedSource::new(file_name, source_text.to_string()));
-        ret.errors
-            .into_iter()
-            .map(|diagnostic| Error::from(diagnostic).with_source_code(Arc::clone(&source)))
-            .map(|error| format!("{error:?}"))
-            .collect()
-    };
+    let file_name = options.source_filename.as_deref().unwrap_or_default();
+    let errors = Diagnostic::from_diagnostics(
+        Path::new(file_name),
+        get_source_type(options),
+        source_text,
+        ret.errors,
+    );
 
     let comments = ret
         .program
@@ -23,4 +23,15 @@ describe('parse', () => {
     const ret2 = await oxc.parseAsync(code);
     expect(ret).toEqual(ret2);
   });
+
+  it('reports structured errors', () => {
+    const ret = oxc.parseSync('let a = ;', { sourceFilename: 'test.js' });
+    expect(ret.errors.length).toBe(1);
+
+    const error = ret.errors[0];
+    expect(error.severity).toBe('Error');
+    expect(error.message).toBe('Unexpected token');
+    expect(error.labels).toEqual([{ start: 8, end: 9 }]);
+    expect(error.codeframe).toContain('let a = ;');
+  });
 });
@@ -12,14 +12,80 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+/** An error or warning reported while processing a file. */
+export interface Diagnostic {
+  severity: 'Error' | 'Warning' | 'Advice'
+  /** The code of the diagnostic, e.g. `TS1109`. */
+  code?: string
+  message: string
+  /** The source ranges the diagnostic refers to. */
+  labels: Array<DiagnosticLabel>
+  help?: string
+  /**
+   * The diagnostic rendered with a code frame of the source text, as printed by the CLI.
+   *
+   * This is not set if the diagnostic does not refer to the source text.
+   */
+  codeframe?: string
+}
+
+/** A source range of a {@link Diagnostic}. */
+export interface DiagnosticLabel {
+  message?: string
+  /** Start offset of the range, in UTF-8 bytes. */
+  start: number
+  /** End offset of the range, in UTF-8 bytes. */
+  end: number
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
 }
 
+/**
+ * List the syntax features used by a file, and the ES version they require.
+ *
+ * Useful for checking that code meets its claimed browser support without being transformed.
+ */
+export declare function esFeatures(filename: string, sourceText: string): EsFeaturesResult
+
+export interface EsFeaturesResult {
+  /** Features used by the file, in source order. */
+  features: Array<EsFeatureUsage>
+  /** The lowest ES version which supports all the features used by the file. */
+  esTarget: string
+  errors: Array<Diagnostic>
+}
+
+/** A syntax feature used by a file. */
+export interface EsFeatureUsage {
+  /** Name of the feature, e.g. `ES2015ArrowFunctions`. */
+  feature: string
+  /** The ES version which introduced the feature, e.g. `es2015`. */
+  esVersion: string
+  /** Start offset of the code which uses the feature. */
+  start: number
+  /** End offset of the code which uses the feature. */
+  end: number
+}
+
 /** TypeScript Isolated Declarations for Standalone DTS Emit */
 export declare function isolatedDeclaration(filename: string, sourceText: string, options?: IsolatedDeclarationsOptions | undefined | null): IsolatedDeclarationsResult
 
+/**
+ * TypeScript Isolated Declarations for many files at once.
+ *
+ * The files are processed in parallel. Results are in the same order as `files`.
+ */
+export declare function isolatedDeclarationBatch(files: Array<IsolatedDeclarationsFile>, options?: IsolatedDeclarationsOptions | undefined | null): Array<IsolatedDeclarationsResult>
+
+/** A source file for batch isolated declarations emit. */
+export interface IsolatedDeclarationsFile {
+  filename: string
+  sourceText: string
+}
+
 export interface IsolatedDeclarationsOptions {
   /**
    * Do not emit declarations for code that has an @internal annotation in its JSDoc comment.
@@ -30,13 +96,18 @@ export interface IsolatedDeclarationsOptions {
    * See <https://www.typescriptlang.org/tsconfig/#stripInternal>
    */
   stripInternal?: boolean
+  /**
+   * Generate a source map of the declarations.
+   *
+   * Default: `false`
+   */
   sourcemap?: boolean
 }
 
 export interface IsolatedDeclarationsResult {
   code: string
   map?: SourceMap
-  errors: Array<string>
+  errors: Array<Diagnostic>
 }
 
 /**
@@ -134,6 +205,16 @@ export interface JsxOptions {
   refresh?: boolean | ReactRefreshOptions
 }
 
+/** Time spent in a transform plugin. */
+export interface PluginProfile {
+  /** Name of the plugin, e.g. `typescript` or `es2020`. */
+  name: string
+  /** Total time spent in the plugin, in milliseconds. */
+  duration: number
+  /** Number of visitor methods of the plugin which were called. */
+  calls: number
+}
+
 export interface ReactRefreshOptions {
   /**
    * Specify the identifier of the refresh registration variable.
@@ -189,6 +270,19 @@ export interface TransformOptions {
    * options.
    */
   cwd?: string
+  /**
+   * An expression which evaluates to the file name of the module, e.g.
+   * `import.meta.url` or `__filename`, emitted instead of the path of the
+   * file by transforms which reference it, such as JSX development mode.
+   *
+   * Keeps the output independent of the machine it is built on.
+   */
+  filenameExpression?: string
+  /**
+   * An expression which evaluates to the id of the module, e.g.
+   * `module.id`, used to prefix the components registered by React Refresh.
+   */
+  moduleIdExpression?: string
   /**
    * Enable source map generation.
    *
@@ -207,6 +301,52 @@ export interface TransformOptions {
   define?: Record<string, string>
   /** Inject Plugin */
   inject?: Record<string, string | [string, string]>
+  /**
+   * Hoist `jest.mock()` calls above the imports, like
+   * `babel-plugin-jest-hoist`, for using Oxc as a Jest transformer.
+   *
+   * @default false
+   */
+  jestHoist?: boolean
+  /**
+   * Keep the `name` of classes which a transform would otherwise change,
+   * e.g. an anonymous class initializing a class field, by defining it with
+   * `Object.defineProperty`.
+   *
+   * @default false
+   */
+  keepClassNames?: boolean
+  /**
+   * Measure the time spent in each transform plugin, returned in
+   * {@link TransformResult#profile}.
+   *
+   * @default false
+   */
+  profile?: boolean
+  /**
+   * Options for the files matching some glob patterns, e.g. a different JSX
+   * import source for a subdirectory.
+   *
+   * Each matching override replaces the options it sets. Later overrides
+   * take precedence over earlier ones.
+   */
+  overrides?: Array<TransformOptionsOverride>
+}
+
+/** Options for the files matching {@link TransformOptionsOverride#files}. */
+export interface TransformOptionsOverride {
+  /**
+   * Glob patterns of the files to apply the options to, e.g. `src/components/**`.
+   *
+   * Patterns are matched against the file name relative to
+   * {@link TransformOptions#cwd}, or the file name as given when it is
+   * not inside `cwd`.
+   */
+  files: Array<string>
+  /** Replaces {@link TransformOptions#typescript}. */
+  typescript?: TypeScriptOptions
+  /** Replaces {@link TransformOptions#jsx}. */
+  jsx?: JsxOptions
 }
 
 export interface TransformResult {
@@ -246,7 +386,13 @@ export interface TransformResult {
    * transformed code may still be available even if there are errors in this
    * list.
    */
-  errors: Array<string>
+  errors: Array<Diagnostic>
+  /**
+   * Time spent in each transform plugin.
+   *
+   * This will be set if {@link TransformOptions#profile} is `true`.
+   */
+  profile?: Array<PluginProfile>
 }
 
 export interface TypeScriptOptions {
@@ -255,6 +401,13 @@ export interface TypeScriptOptions {
   onlyRemoveTypeImports?: boolean
   allowNamespaces?: boolean
   allowDeclareFields?: boolean
+  /**
+   * Emit enums in the compact form of `tsc`, `(function (E) { ... })(E || (E = {}))`,
+   * instead of the form of Babel.
+   *
+   * @default false
+   */
+  compactEnums?: boolean
   /**
    * Also generate a `.d.ts` declaration file for TypeScript files.
    *
@@ -361,5 +361,7 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
+module.exports.esFeatures = nativeBinding.esFeatures
 module.exports.isolatedDeclaration = nativeBinding.isolatedDeclaration
+module.exports.isolatedDeclarationBatch = nativeBinding.isolatedDeclarationBatch
 module.exports.transform = nativeBinding.transform
@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use napi_derive::napi;
+
+use oxc::{
+    allocator::Allocator,
+    napi::{
+        diagnostic::Diagnostic,
+        es_features::{EsFeatureUsage, EsFeaturesResult},
+    },
+    parser::Parser,
+    span::SourceType,
+    transformer::ESFeatureAnalyzer,
+};
+
+/// List the syntax features used by a file, and the ES version they require.
+///
+/// Useful for checking that code meets its claimed browser support without being transformed.
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn es_features(filename: String, source_text: String) -> EsFeaturesResult {
+    let source_path = Path::new(&filename);
+    let source_type = SourceType::from_path(source_path).unwrap_or_default();
+    let allocator = Allocator::default();
+
+    let parser_ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let ret = ESFeatureAnalyzer::new(&allocator).build(&parser_ret.program);
+
+    let features = ret.features.iter().map(EsFeatureUsage::from).collect();
+    let es_target = ret.es_target().to_string();
+
+    let errors = parser_ret.errors.into_iter().chain(ret.errors).collect();
+    let errors = Diagnostic::from_diagnostics(source_path, source_type, &source_text, errors);
+
+    EsFeaturesResult { features, es_target, errors }
+}
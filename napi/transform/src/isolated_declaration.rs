@@ -1,21 +1,23 @@
 use std::path::Path;
 
 use napi_derive::napi;
+use rayon::prelude::*;
 
 use oxc::{
     allocator::Allocator,
     codegen::{CodeGenerator, CodegenOptions},
     isolated_declarations::IsolatedDeclarations,
     napi::{
-        isolated_declarations::{IsolatedDeclarationsOptions, IsolatedDeclarationsResult},
+        diagnostic::Diagnostic,
+        isolated_declarations::{
+            IsolatedDeclarationsFile, IsolatedDeclarationsOptions, IsolatedDeclarationsResult,
+        },
         source_map::SourceMap,
     },
     parser::Parser,
     span::SourceType,
 };
 
-use crate::errors::wrap_diagnostics;
-
 /// TypeScript Isolated Declarations for Standalone DTS Emit
 #[allow(clippy::needless_pass_by_value)]
 #[napi]
@@ -24,30 +26,47 @@ pub fn isolated_declaration(
     source_text: String,
     options: Option<IsolatedDeclarationsOptions>,
 ) -> IsolatedDeclarationsResult {
-    let source_path = Path::new(&filename);
+    emit(&filename, &source_text, options.unwrap_or_default())
+}
+
+/// TypeScript Isolated Declarations for many files at once.
+///
+/// The files are processed in parallel. Results are in the same order as `files`.
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn isolated_declaration_batch(
+    files: Vec<IsolatedDeclarationsFile>,
+    options: Option<IsolatedDeclarationsOptions>,
+) -> Vec<IsolatedDeclarationsResult> {
+    let options = options.unwrap_or_default();
+    files.par_iter().map(|file| emit(&file.filename, &file.source_text, options)).collect()
+}
+
+fn emit(
+    filename: &str,
+    source_text: &str,
+    options: IsolatedDeclarationsOptions,
+) -> IsolatedDeclarationsResult {
+    let source_path = Path::new(filename);
     let source_type = SourceType::from_path(source_path).unwrap_or_default().with_typescript(true);
     let allocator = Allocator::default();
-    let options = options.unwrap_or_default();
 
-    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
 
-    let transformed_ret = IsolatedDeclarations::new(
-        &allocator,
-        oxc::isolated_declarations::IsolatedDeclarationsOptions {
-            strip_internal: options.strip_internal.unwrap_or(false),
-        },
-    )
-    .build(&ret.program);
+    let transformed_ret = IsolatedDeclarations::new(&allocator, options.into()).build(&ret.program);
 
     let codegen_ret = CodeGenerator::new()
         .with_options(CodegenOptions {
-            source_map_path: Some(source_path.to_path_buf()),
+            source_map_path: options
+                .sourcemap
+                .unwrap_or_default()
+                .then(|| source_path.to_path_buf()),
             ..CodegenOptions::default()
         })
         .build(&transformed_ret.program);
 
     let errors = ret.errors.into_iter().chain(transformed_ret.errors).collect();
-    let errors = wrap_diagnostics(source_path, source_type, &source_text, errors);
+    let errors = Diagnostic::from_diagnostics(source_path, source_type, source_text, errors);
 
     IsolatedDeclarationsResult {
         code: codegen_ret.code,
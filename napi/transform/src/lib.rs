@@ -1,6 +1,7 @@
-mod errors;
+pub use oxc::napi::{diagnostic, es_features, isolated_declarations, transform};
 
-pub use oxc::napi::{isolated_declarations, transform};
+mod es_feature;
+pub use es_feature::*;
 
 mod isolated_declaration;
 pub use isolated_declaration::*;
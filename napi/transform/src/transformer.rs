@@ -1,23 +1,26 @@
-use std::path::Path;
+use std::{ops::ControlFlow, path::Path};
 
+use globset::{Glob, GlobSetBuilder};
 use napi::Either;
 use napi_derive::napi;
 
 use oxc::{
+    ast::ast::Program,
     codegen::CodegenReturn,
     diagnostics::OxcDiagnostic,
     isolated_declarations::IsolatedDeclarationsOptions,
     napi::{
+        diagnostic::Diagnostic,
         source_map::SourceMap,
-        transform::{TransformOptions, TransformResult},
+        transform::{PluginProfile, TransformOptions, TransformResult},
     },
     span::SourceType,
-    transformer::{InjectGlobalVariablesConfig, InjectImport, ReplaceGlobalDefinesConfig},
+    transformer::{
+        InjectGlobalVariablesConfig, InjectImport, ReplaceGlobalDefinesConfig, TransformerReturn,
+    },
     CompilerInterface,
 };
 
-use crate::errors::wrap_diagnostics;
-
 #[derive(Default)]
 struct Compiler {
     transform_options: oxc::transformer::TransformOptions,
@@ -33,14 +36,24 @@ struct Compiler {
 
     define: Option<ReplaceGlobalDefinesConfig>,
     inject: Option<InjectGlobalVariablesConfig>,
+    jest_hoist: bool,
 
     errors: Vec<OxcDiagnostic>,
+
+    profile: Option<Vec<PluginProfile>>,
 }
 
 impl Compiler {
-    fn new(options: Option<TransformOptions>) -> Result<Self, Vec<OxcDiagnostic>> {
+    fn new(
+        options: Option<TransformOptions>,
+        source_path: &Path,
+    ) -> Result<Self, Vec<OxcDiagnostic>> {
         let mut options = options;
 
+        if let Some(options) = options.as_mut() {
+            apply_overrides(options, source_path)?;
+        }
+
         let isolated_declaration_options = options
             .as_ref()
             .and_then(|o| o.typescript.as_ref())
@@ -48,6 +61,7 @@ impl Compiler {
             .map(oxc::isolated_declarations::IsolatedDeclarationsOptions::from);
 
         let sourcemap = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
+        let jest_hoist = options.as_ref().and_then(|o| o.jest_hoist).unwrap_or_default();
 
         let define = options
             .as_mut()
@@ -97,11 +111,48 @@ impl Compiler {
             declaration_map: None,
             define,
             inject,
+            jest_hoist,
             errors: vec![],
+            profile: None,
         })
     }
 }
 
+/// Apply the overrides of `options` whose patterns match `source_path`, in order.
+fn apply_overrides(
+    options: &mut TransformOptions,
+    source_path: &Path,
+) -> Result<(), Vec<OxcDiagnostic>> {
+    let Some(overrides) = options.overrides.take() else {
+        return Ok(());
+    };
+    let path = options
+        .cwd
+        .as_deref()
+        .and_then(|cwd| source_path.strip_prefix(cwd).ok())
+        .unwrap_or(source_path);
+    let invalid_pattern = |err: globset::Error| {
+        vec![OxcDiagnostic::error(format!("Invalid override pattern: {err}"))]
+    };
+
+    for r#override in overrides {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &r#override.files {
+            builder.add(Glob::new(pattern).map_err(invalid_pattern)?);
+        }
+        if !builder.build().map_err(invalid_pattern)?.is_match(path) {
+            continue;
+        }
+        if let Some(typescript) = r#override.typescript {
+            options.typescript = Some(typescript);
+        }
+        if let Some(jsx) = r#override.jsx {
+            options.jsx = Some(jsx);
+        }
+    }
+    Ok(())
+}
+
 impl CompilerInterface for Compiler {
     fn handle_errors(&mut self, errors: Vec<OxcDiagnostic>) {
         self.errors.extend(errors);
@@ -127,6 +178,22 @@ impl CompilerInterface for Compiler {
         self.inject.clone()
     }
 
+    fn jest_hoist(&self) -> bool {
+        self.jest_hoist
+    }
+
+    fn after_transform(
+        &mut self,
+        _program: &mut Program<'_>,
+        transformer_return: &mut TransformerReturn,
+    ) -> ControlFlow<()> {
+        self.profile = transformer_return
+            .profile
+            .take()
+            .map(|profile| profile.plugins.into_iter().map(PluginProfile::from).collect());
+        ControlFlow::Continue(())
+    }
+
     fn after_codegen(&mut self, ret: CodegenReturn) {
         self.printed = ret.code;
         self.printed_sourcemap = ret.map.map(SourceMap::from);
@@ -164,7 +231,9 @@ pub fn transform(
         Some("tsx") => SourceType::tsx(),
         Some(lang) => {
             return TransformResult {
-                errors: vec![format!("Incorrect lang '{lang}'")],
+                errors: vec![Diagnostic::from(OxcDiagnostic::error(format!(
+                    "Incorrect lang '{lang}'"
+                )))],
                 ..Default::default()
             }
         }
@@ -180,11 +249,16 @@ pub fn transform(
         }
     };
 
-    let mut compiler = match Compiler::new(options) {
+    let mut compiler = match Compiler::new(options, source_path) {
         Ok(compiler) => compiler,
         Err(errors) => {
             return TransformResult {
-                errors: wrap_diagnostics(source_path, source_type, &source_text, errors),
+                errors: Diagnostic::from_diagnostics(
+                    source_path,
+                    source_type,
+                    &source_text,
+                    errors,
+                ),
                 ..Default::default()
             }
         }
@@ -197,6 +271,12 @@ pub fn transform(
         map: compiler.printed_sourcemap,
         declaration: compiler.declaration,
         declaration_map: compiler.declaration_map,
-        errors: wrap_diagnostics(source_path, source_type, &source_text, compiler.errors),
+        errors: Diagnostic::from_diagnostics(
+            source_path,
+            source_type,
+            &source_text,
+            compiler.errors,
+        ),
+        profile: compiler.profile,
     }
 }
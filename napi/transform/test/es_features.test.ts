@@ -0,0 +1,24 @@
+import { assert, describe, it } from 'vitest';
+
+import oxc from './index';
+
+describe('es features', () => {
+  it('lists the features used by a file', () => {
+    const ret = oxc.esFeatures('test.js', 'const a = () => a ?? b');
+    assert.deepEqual(ret, {
+      features: [
+        { feature: 'ES2015BlockScoping', esVersion: 'es2015', start: 0, end: 22 },
+        { feature: 'ES2015ArrowFunctions', esVersion: 'es2015', start: 10, end: 22 },
+        { feature: 'ES2020NullishCoalescingOperator', esVersion: 'es2020', start: 16, end: 22 },
+      ],
+      esTarget: 'es2020',
+      errors: [],
+    });
+  });
+
+  it('reports es5 when no features are used', () => {
+    const ret = oxc.esFeatures('test.js', 'var a = function () {}');
+    assert.deepEqual(ret.features, []);
+    assert.equal(ret.esTarget, 'es5');
+  });
+});
@@ -37,4 +37,22 @@ describe('isolated declaration', () => {
       errors: [],
     });
   });
+
+  it('omits the source map by default', () => {
+    const ret = oxc.isolatedDeclaration('test.ts', 'export const a = 1;');
+    assert.equal(ret.code, 'export declare const a = 1;\n');
+    assert.isUndefined(ret.map);
+  });
+
+  it('emits many files in one call', () => {
+    const ret = oxc.isolatedDeclarationBatch([
+      { filename: 'a.ts', sourceText: 'export function a(): number { return 1; }' },
+      { filename: 'b.ts', sourceText: 'export function b() { return 1; }' },
+    ], { sourcemap: true });
+    assert.equal(ret.length, 2);
+    assert.equal(ret[0].code, 'export declare function a(): number;\n');
+    assert.deepEqual(ret[0].map?.sources, ['a.ts']);
+    assert.deepEqual(ret[0].errors, []);
+    assert.equal(ret[1].errors.length, 1);
+  });
 });
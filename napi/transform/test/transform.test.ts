@@ -134,3 +134,29 @@ describe('inject plugin', () => {
     assert.equal(ret.code, 'import $inject_Object_assign from "foo";\nlet _ = $inject_Object_assign;\n');
   });
 });
+
+describe('overrides', () => {
+  const code = 'export const App = () => <div />;';
+  const options = {
+    jsx: { importSource: 'react' },
+    overrides: [{ files: ['src/preact/**'], jsx: { importSource: 'preact' } }],
+  };
+
+  it('applies the options of matching overrides', () => {
+    const ret = oxc.transform('src/preact/app.jsx', code, options);
+    assert(ret.code.includes('from "preact/jsx-runtime"'));
+  });
+
+  it('keeps the options for other files', () => {
+    const ret = oxc.transform('src/app.jsx', code, options);
+    assert(ret.code.includes('from "react/jsx-runtime"'));
+  });
+
+  it('reports invalid patterns', () => {
+    const ret = oxc.transform('src/app.jsx', code, { overrides: [{ files: ['src/[a'] }] });
+    assert.equal(ret.errors.length, 1);
+    assert.equal(ret.errors[0].severity, 'Error');
+    assert.deepEqual(ret.errors[0].labels, []);
+    assert.isUndefined(ret.errors[0].codeframe);
+  });
+});
@@ -907,8 +907,11 @@ export interface ImportDeclaration extends Span {
   source: StringLiteral;
   withClause: WithClause | null;
   importKind: ImportOrExportKind;
+  phase: ImportPhase | null;
 }
 
+export type ImportPhase = 'source' | 'defer';
+
 export type ImportDeclarationSpecifier = ImportSpecifier | ImportDefaultSpecifier | ImportNamespaceSpecifier;
 
 export interface ImportSpecifier extends Span {
@@ -25,7 +25,7 @@ define_generator!(VisitGenerator);
 impl Generator for VisitGenerator {
     fn generate(&mut self, schema: &Schema) -> Output {
         Output::Rust {
-            path: output_path(crate::AST_CRATE, "visit.rs"),
+            path: output_path(crate::AST_VISIT_CRATE, "visit.rs"),
             tokens: generate_visit(false, schema),
         }
     }
@@ -38,7 +38,7 @@ define_generator!(VisitMutGenerator);
 impl Generator for VisitMutGenerator {
     fn generate(&mut self, schema: &Schema) -> Output {
         Output::Rust {
-            path: output_path(crate::AST_CRATE, "visit_mut.rs"),
+            path: output_path(crate::AST_VISIT_CRATE, "visit_mut.rs"),
             tokens: generate_visit(true, schema),
         }
     }
@@ -94,8 +94,8 @@ fn generate_visit(is_mut: bool, schema: &Schema) -> TokenStream {
         use oxc_syntax::scope::{ScopeFlags, ScopeId};
 
         ///@@line_break
-        use crate::ast::*;
-        use crate::ast_kind::#ast_kind_type;
+        use oxc_ast::ast::*;
+        use oxc_ast::#ast_kind_type;
 
         ///@@line_break
         use #walk_mod::*;
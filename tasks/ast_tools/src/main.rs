@@ -45,6 +45,7 @@ static SOURCE_PATHS: &[&str] = &[
 ];
 
 const AST_CRATE: &str = "crates/oxc_ast";
+const AST_VISIT_CRATE: &str = "crates/oxc_ast_visit";
 const TYPESCRIPT_PACKAGE: &str = "npm/oxc-types";
 const GITHUB_WATCH_LIST_PATH: &str = ".github/.generated_ast_watch_list.yml";
 const SCHEMA_PATH: &str = "schema.json";
@@ -0,0 +1,29 @@
+use oxc_allocator::Allocator;
+use oxc_benchmark::{
+    criterion_group, criterion_main,
+    pipeline::{run_pipeline, PipelineOptions},
+    BenchmarkId, Criterion,
+};
+use oxc_tasks_common::TestFiles;
+
+fn bench_pipeline(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("pipeline");
+
+    for file in TestFiles::complicated_one(1).files() {
+        for options in PipelineOptions::matrix() {
+            let id = BenchmarkId::new(options.to_string(), &file.file_name);
+            let mut allocator = Allocator::default();
+            group.bench_function(id, |b| {
+                b.iter_with_large_drop(|| {
+                    allocator.reset();
+                    run_pipeline(&allocator, &file.file_name, &file.source_text, options)
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(pipeline, bench_pipeline);
+criterion_main!(pipeline);
@@ -2,6 +2,9 @@ use std::alloc::{GlobalAlloc, Layout, System};
 
 pub use criterion::*;
 
+#[cfg(feature = "pipeline")]
+pub mod pipeline;
+
 #[global_allocator]
 static GLOBAL: NeverGrowInPlaceAllocator = NeverGrowInPlaceAllocator;
 
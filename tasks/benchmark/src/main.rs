@@ -0,0 +1,65 @@
+#![allow(clippy::print_stdout)]
+//! Run every corpus file under each [`PipelineOptions`] and print a comparison table.
+//!
+//! Run with `cargo benchmark`.
+
+use std::time::{Duration, Instant};
+
+use oxc_allocator::Allocator;
+use oxc_benchmark::pipeline::{run_pipeline, PipelineOptions};
+use oxc_tasks_common::TestFiles;
+
+const ITERATIONS: usize = 10;
+
+fn main() {
+    let files = TestFiles::complicated();
+    let matrix = PipelineOptions::matrix();
+
+    // Header
+    let mut header = String::from("| Options |");
+    let mut separator = String::from("| :-- |");
+    for file in files.files() {
+        header.push_str(&format!(" {} |", file.file_name));
+        separator.push_str(" --: |");
+    }
+    println!("{header}");
+    println!("{separator}");
+
+    let baselines = files
+        .files()
+        .iter()
+        .map(|file| measure(&file.file_name, &file.source_text, PipelineOptions::BASELINE))
+        .collect::<Vec<_>>();
+
+    for options in matrix {
+        let mut row = format!("| {options} |");
+        for (file, baseline) in files.files().iter().zip(&baselines) {
+            let duration = if options == PipelineOptions::BASELINE {
+                *baseline
+            } else {
+                measure(&file.file_name, &file.source_text, options)
+            };
+            let ratio = duration.as_secs_f64() / baseline.as_secs_f64();
+            row.push_str(&format!(" {:.2}ms ({ratio:.2}x) |", duration.as_secs_f64() * 1000.0));
+        }
+        println!("{row}");
+    }
+}
+
+/// Median duration of [`ITERATIONS`] runs, after one warmup run.
+fn measure(file_name: &str, source_text: &str, options: PipelineOptions) -> Duration {
+    let mut allocator = Allocator::default();
+    run_pipeline(&allocator, file_name, source_text, options);
+    let mut durations = (0..ITERATIONS)
+        .map(|_| {
+            allocator.reset();
+            let now = Instant::now();
+            let ret = run_pipeline(&allocator, file_name, source_text, options);
+            let duration = now.elapsed();
+            drop(ret);
+            duration
+        })
+        .collect::<Vec<_>>();
+    durations.sort_unstable();
+    durations[ITERATIONS / 2]
+}
@@ -0,0 +1,100 @@
+//! Option matrix for benchmarking the whole pipeline.
+//!
+//! Each file is run through parse -> transform -> minify -> codegen once per [`PipelineOptions`],
+//! so performance work can be evaluated per configuration rather than a single default path.
+
+use std::{fmt, path::Path, path::PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions, CodegenReturn};
+use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
+use oxc_parser::{Parser, ParserReturn};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+
+/// Targets exercised by the matrix, from most to least transforms.
+pub const TARGETS: [&str; 3] = ["es5", "es2018", "esnext"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipelineOptions {
+    pub target: &'static str,
+    pub minify: bool,
+    pub sourcemap: bool,
+}
+
+impl PipelineOptions {
+    /// The configuration other configurations are compared against.
+    pub const BASELINE: Self = Self { target: "esnext", minify: false, sourcemap: false };
+
+    /// All combinations of [`TARGETS`], minify on/off and sourcemaps on/off.
+    pub fn matrix() -> Vec<Self> {
+        let mut matrix = vec![];
+        for target in TARGETS {
+            for minify in [false, true] {
+                for sourcemap in [false, true] {
+                    matrix.push(Self { target, minify, sourcemap });
+                }
+            }
+        }
+        matrix
+    }
+}
+
+impl fmt::Display for PipelineOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.target)?;
+        if self.minify {
+            f.write_str("+minify")?;
+        }
+        if self.sourcemap {
+            f.write_str("+sourcemap")?;
+        }
+        Ok(())
+    }
+}
+
+/// Run the whole pipeline on `source_text`.
+///
+/// # Panics
+///
+/// * `file_name` does not have a JavaScript or TypeScript extension.
+/// * `options.target` is not a valid target.
+pub fn run_pipeline(
+    allocator: &Allocator,
+    file_name: &str,
+    source_text: &str,
+    options: PipelineOptions,
+) -> CodegenReturn {
+    let source_path = Path::new(file_name);
+    let source_type = SourceType::from_path(source_path).unwrap();
+
+    let ParserReturn { mut program, .. } = Parser::new(allocator, source_text, source_type).parse();
+
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let transform_options = TransformOptions::from_target(options.target).unwrap();
+    Transformer::new(allocator, source_path, &transform_options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+
+    let mangler = options.minify.then(|| {
+        let options = MinifierOptions {
+            mangle: true,
+            compress: CompressOptions::default(),
+            ..MinifierOptions::default()
+        };
+        Minifier::new(options).build(allocator, &mut program).mangler
+    });
+
+    CodeGenerator::new()
+        .with_options(CodegenOptions {
+            minify: options.minify,
+            source_map_path: options.sourcemap.then(|| PathBuf::from(file_name)),
+            ..CodegenOptions::default()
+        })
+        .with_mangler(mangler.flatten())
+        .build(&program)
+}
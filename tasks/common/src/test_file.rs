@@ -50,6 +50,37 @@ impl TestFiles {
         }
     }
 
+    /// The libraries of `tasks/common/libs.txt` tagged with `tag`.
+    pub fn by_tag(tag: TestFileTag) -> Self {
+        let files =
+            Self::libs().filter(|(_, tags)| tags.contains(&tag)).map(|(url, _)| TestFile::new(url));
+        Self { files: files.collect() }
+    }
+
+    /// The libraries of `tasks/common/libs.txt` whose size falls into `size`.
+    ///
+    /// All libraries are downloaded to measure them.
+    pub fn by_size(size: TestFileSize) -> Self {
+        let files = Self::libs()
+            .map(|(url, _)| TestFile::new(url))
+            .filter(|file| TestFileSize::of(&file.source_text) == size);
+        Self { files: files.collect() }
+    }
+
+    /// # Panics
+    /// Unknown tag in `libs.txt`
+    fn libs() -> impl Iterator<Item = (&'static str, Vec<TestFileTag>)> {
+        include_str!("../libs.txt")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let mut parts = line.split_whitespace();
+                let url = parts.next().unwrap();
+                let tags = parts.map(|tag| tag.parse().unwrap()).collect();
+                (url, tags)
+            })
+    }
+
     pub fn complicated() -> Self {
         let files = Self::complicated_urls().into_iter().map(TestFile::new).collect();
         Self { files }
@@ -77,6 +108,54 @@ impl TestFiles {
     }
 }
 
+/// Category of a library in `tasks/common/libs.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestFileTag {
+    /// UI frameworks and libraries, e.g. React, Vue.
+    Framework,
+    /// Minified output of a bundler.
+    Minified,
+    /// TypeScript syntax.
+    TypeScript,
+    /// Over 2MB.
+    Huge,
+}
+
+impl FromStr for TestFileTag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "framework" => Ok(Self::Framework),
+            "minified" => Ok(Self::Minified),
+            "typescript" => Ok(Self::TypeScript),
+            "huge" => Ok(Self::Huge),
+            _ => Err(format!("unknown test file tag `{s}`")),
+        }
+    }
+}
+
+/// Size bucket of a test file, see [`TestFiles::by_size`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestFileSize {
+    /// Under 100KB.
+    Small,
+    /// Under 1MB.
+    Medium,
+    /// 1MB and over.
+    Large,
+}
+
+impl TestFileSize {
+    pub fn of(source_text: &str) -> Self {
+        match source_text.len() {
+            0..=99_999 => Self::Small,
+            100_000..=999_999 => Self::Medium,
+            _ => Self::Large,
+        }
+    }
+}
+
 pub struct TestFile {
     pub url: String,
     pub file_name: String,
@@ -95,8 +95,12 @@ pub fn run() -> Result<(), io::Error> {
 
 fn minify_twice(file: &TestFile) -> String {
     let source_type = SourceType::from_path(&file.file_name).unwrap();
-    let options = MinifierOptions { mangle: true, compress: CompressOptions::default() };
-    let source_text1 = minify(&file.source_text, source_type, options);
+    let options = MinifierOptions {
+        mangle: true,
+        compress: CompressOptions::default(),
+        ..MinifierOptions::default()
+    };
+    let source_text1 = minify(&file.source_text, source_type, options.clone());
     let source_text2 = minify(&source_text1, source_type, options);
     assert!(source_text1 == source_text2, "Minification failed for {}", &file.file_name);
     source_text2
@@ -3,10 +3,10 @@
 use std::{fs, path::Path, str::FromStr};
 
 use oxc_allocator::Allocator;
-use oxc_ast::{
-    ast::{Argument, ArrayExpressionElement, CallExpression, Expression, ObjectPropertyKind},
-    VisitMut,
+use oxc_ast::ast::{
+    Argument, ArrayExpressionElement, CallExpression, Expression, ObjectPropertyKind,
 };
+use oxc_ast_visit::VisitMut;
 use oxc_parser::Parser;
 use oxc_prettier::{ArrowParens, EndOfLine, PrettierOptions, QuoteProps, TrailingComma};
 use oxc_span::{GetSpan, SourceType};
@@ -6,15 +6,13 @@ use std::{
 
 use convert_case::{Case, Casing};
 use oxc_allocator::Allocator;
-use oxc_ast::{
-    ast::{
-        Argument, ArrayExpressionElement, CallExpression, ExportDefaultDeclarationKind, Expression,
-        ExpressionStatement, ObjectExpression, ObjectProperty, ObjectPropertyKind, Program,
-        PropertyKey, Statement, StaticMemberExpression, StringLiteral, TaggedTemplateExpression,
-        TemplateLiteral,
-    },
-    Visit,
+use oxc_ast::ast::{
+    Argument, ArrayExpressionElement, CallExpression, ExportDefaultDeclarationKind, Expression,
+    ExpressionStatement, ObjectExpression, ObjectProperty, ObjectPropertyKind, Program,
+    PropertyKey, Statement, StaticMemberExpression, StringLiteral, TaggedTemplateExpression,
+    TemplateLiteral,
 };
+use oxc_ast_visit::Visit;
 use oxc_parser::Parser;
 use oxc_span::{GetSpan, SourceType, Span};
 use rustc_hash::FxHashMap;
@@ -66,6 +64,9 @@ const PROMISE_TEST_PATH: &str =
 const VITEST_TEST_PATH: &str =
     "https://raw.githubusercontent.com/veritem/eslint-plugin-vitest/main/tests";
 
+const REGEXP_TEST_PATH: &str =
+    "https://raw.githubusercontent.com/ota-meshi/eslint-plugin-regexp/master/tests/lib/rules";
+
 struct TestCase {
     source_text: String,
     code: Option<String>,
@@ -602,6 +603,7 @@ pub enum RuleKind {
     Promise,
     Vitest,
     Security,
+    Regexp,
 }
 
 impl RuleKind {
@@ -622,6 +624,7 @@ impl RuleKind {
             "promise" => Self::Promise,
             "vitest" => Self::Vitest,
             "security" => Self::Security,
+            "regexp" => Self::Regexp,
             _ => Self::ESLint,
         }
     }
@@ -646,6 +649,7 @@ impl Display for RuleKind {
             Self::Promise => write!(f, "eslint-plugin-promise"),
             Self::Vitest => write!(f, "eslint-plugin-vitest"),
             Self::Security => write!(f, "security"),
+            Self::Regexp => write!(f, "eslint-plugin-regexp"),
         }
     }
 }
@@ -675,6 +679,7 @@ fn main() {
         RuleKind::TreeShaking => format!("{TREE_SHAKING_PATH}/{kebab_rule_name}.test.ts"),
         RuleKind::Promise => format!("{PROMISE_TEST_PATH}/{kebab_rule_name}.js"),
         RuleKind::Vitest => format!("{VITEST_TEST_PATH}/{kebab_rule_name}.test.ts"),
+        RuleKind::Regexp => format!("{REGEXP_TEST_PATH}/{kebab_rule_name}.ts"),
         RuleKind::Oxc | RuleKind::Security => String::new(),
     };
     let language = match rule_kind {
@@ -800,6 +805,7 @@ fn add_rules_entry(ctx: &Context, rule_kind: RuleKind) -> Result<(), Box<dyn std
         RuleKind::Vitest => "vitest",
         RuleKind::Node => "node",
         RuleKind::Security => "security",
+        RuleKind::Regexp => "regexp",
     };
     let mod_def = format!("mod {mod_name}");
     let Some(mod_start) = rules.find(&mod_def) else {
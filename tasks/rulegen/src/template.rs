@@ -46,6 +46,7 @@ impl<'a> Template<'a> {
             RuleKind::Promise => Path::new("crates/oxc_linter/src/rules/promise"),
             RuleKind::Vitest => Path::new("crates/oxc_linter/src/rules/vitest"),
             RuleKind::Security => Path::new("crates/oxc_linter/src/rules/security"),
+            RuleKind::Regexp => Path::new("crates/oxc_linter/src/rules/regexp"),
         };
 
         std::fs::create_dir_all(path)?;
@@ -95,7 +95,8 @@ use indexmap::IndexMap;
 use rustc_hash::FxHasher;
 
 use oxc_allocator::{Allocator, CloneIn};
-use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{walk, Visit};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::{ScopeTree, SemanticBuilder, SymbolTable};
 use oxc_span::CompactStr;
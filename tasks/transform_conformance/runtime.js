@@ -0,0 +1,82 @@
+// Executes the `exec.js` tests transformed by oxc, like `@babel/helper-transform-fixture-test-runner`
+// does, and responds with the error of the tests which fail.
+//
+// Started by `cargo run -p oxc_transform_conformance -- --exec`.
+import { createServer } from 'node:http';
+import { createRequire } from 'node:module';
+import process from 'node:process';
+import vm from 'node:vm';
+
+import { JestAsymmetricMatchers, JestChaiExpect, JestExtend } from '@vitest/expect';
+import * as chai from 'chai';
+
+chai.use(JestExtend);
+chai.use(JestChaiExpect);
+chai.use(JestAsymmetricMatchers);
+
+const require = createRequire(import.meta.url);
+
+async function runExecTest({ code = '', isModule = false }) {
+  const context = vm.createContext({
+    expect: chai.expect,
+    console,
+    setTimeout,
+    clearTimeout,
+    setImmediate,
+    clearImmediate,
+  });
+
+  // The code of a test is a function, which may return a promise.
+  let exec;
+  if (isModule) {
+    const module = new vm.SourceTextModule(code, { context });
+    await module.link(linker);
+    await module.evaluate();
+    exec = module.namespace.default;
+  } else {
+    exec = new vm.Script(code).runInContext(context);
+  }
+  await exec();
+}
+
+// Modules imported by the tests, e.g. the helpers of `@babel/runtime`, are loaded by Node.js.
+async function linker(specifier, referencingModule) {
+  const namespace = await import(require.resolve(specifier));
+  const names = Object.keys(namespace);
+  return new vm.SyntheticModule(names, function() {
+    for (const name of names) {
+      this.setExport(name, namespace[name]);
+    }
+  }, { context: referencingModule.context });
+}
+
+const server = createServer((req, res) => {
+  // Checked by the test runner before sending tests.
+  if (req.method === 'GET') {
+    return res.end();
+  }
+  if (req.method !== 'POST') {
+    res.statusCode = 404;
+    return res.end('Not Found');
+  }
+  let body = '';
+  req.on('data', chunk => {
+    body += chunk.toString();
+  });
+  req.on('end', async () => {
+    try {
+      await runExecTest(JSON.parse(body));
+    } catch (err) {
+      return res.end(String(err));
+    }
+    res.end();
+  });
+});
+
+process.on('unhandledRejection', () => {
+  // Don't exit when a test does this
+});
+
+server.timeout = 3000;
+
+server.listen(32056, () => {});
@@ -2,7 +2,7 @@ use std::{mem, ops::ControlFlow, path::Path};
 
 use oxc::{
     ast::ast::Program,
-    codegen::{CodegenOptions, CodegenReturn},
+    codegen::{CodegenOptions, CodegenReturn, CommentMode},
     diagnostics::OxcDiagnostic,
     span::SourceType,
     transformer::{TransformOptions, TransformerReturn},
@@ -23,7 +23,7 @@ impl CompilerInterface for Driver {
     }
 
     fn codegen_options(&self) -> Option<CodegenOptions> {
-        Some(CodegenOptions { comments: false, ..CodegenOptions::default() })
+        Some(CodegenOptions { comments: CommentMode::None, ..CodegenOptions::default() })
     }
 
     fn check_semantic_error(&self) -> bool {
@@ -1,11 +1,7 @@
 #![allow(clippy::print_stdout, clippy::print_stderr)]
 
 mod constants;
-use std::{
-    fs,
-    path::{Path, PathBuf},
-    process::Command,
-};
+use std::path::{Path, PathBuf};
 
 use constants::PLUGINS;
 use indexmap::IndexMap;
@@ -14,6 +10,7 @@ use test_case::TestCaseKind;
 use walkdir::WalkDir;
 
 mod driver;
+mod runtime;
 mod test_case;
 
 #[test]
@@ -54,10 +51,6 @@ fn oxc_test_root() -> PathBuf {
     conformance_root().join("tests")
 }
 
-fn fixture_root() -> PathBuf {
-    conformance_root().join("fixtures")
-}
-
 const CONFORMANCE_SNAPSHOT: &str = "babel.snap.md";
 const OXC_CONFORMANCE_SNAPSHOT: &str = "oxc.snap.md";
 const EXEC_SNAPSHOT: &str = "babel_exec.snap.md";
@@ -82,6 +75,7 @@ impl TestRunner {
 
     /// # Panics
     pub fn run(self) {
+        let runtime = self.options.exec.then(runtime::start_runtime);
         for (root, snapshot, exec_snapshot) in &[
             (packages_root(), CONFORMANCE_SNAPSHOT, EXEC_SNAPSHOT),
             (oxc_test_root(), OXC_CONFORMANCE_SNAPSHOT, OXC_EXEC_SNAPSHOT),
@@ -91,13 +85,12 @@ impl TestRunner {
             self.generate_snapshot(root, SnapshotOption::new(transform_paths, snapshot));
 
             if self.options.exec && !exec_files.is_empty() {
-                let fixture_root = fixture_root();
-                let _ = fs::remove_dir_all(&fixture_root);
-                let _ = fs::create_dir(&fixture_root);
                 self.generate_snapshot(root, SnapshotOption::new(exec_files, exec_snapshot));
-                self.run_vitest(&SnapshotOption::new(IndexMap::default(), exec_snapshot));
             }
         }
+        if let Some(mut runtime) = runtime {
+            let _ = runtime.kill();
+        }
     }
 
     fn glob_files(
@@ -197,29 +190,4 @@ impl TestRunner {
             self.snapshot.save(&dest, &snapshot);
         }
     }
-
-    fn run_vitest(&self, option: &SnapshotOption) {
-        let version = String::from("node: ")
-            + &String::from_utf8(Command::new("node").arg("--version").output().unwrap().stdout)
-                .unwrap();
-        let output = Command::new("node")
-            .current_dir(conformance_root())
-            .env("NO_COLOR", "1")
-            .args([
-                "--run",
-                "vitest",
-                "--",
-                "run",
-                "--reporter=basic",
-                "--exclude=\"\"",
-                "--no-color",
-                "./fixtures",
-            ])
-            .output()
-            .unwrap();
-        let content = if output.stderr.is_empty() { output.stdout } else { output.stderr };
-        let output = String::from_utf8(content).unwrap();
-        let output = version + &output;
-        self.snapshot.save(&option.dest, &output);
-    }
 }
@@ -0,0 +1,51 @@
+use std::{
+    process::{Child, Command},
+    thread,
+    time::Duration,
+};
+
+use oxc_tasks_common::agent;
+use serde_json::json;
+
+use crate::conformance_root;
+
+const RUNTIME_URL: &str = "http://localhost:32056";
+
+/// Start `runtime.js`, which executes the transformed `exec.js` tests, and wait until it accepts
+/// requests.
+///
+/// # Panics
+pub fn start_runtime() -> Child {
+    let path = conformance_root().join("runtime.js");
+    let mut runtime = Command::new("node")
+        .current_dir(conformance_root())
+        .arg("--experimental-vm-modules")
+        .arg(path)
+        .spawn()
+        .expect("Run runtime.js failed");
+    for _ in 0..50 {
+        if agent().get(RUNTIME_URL).call().is_ok() {
+            return runtime;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    let _ = runtime.kill();
+    panic!("runtime.js did not start, Node.js 22 or later is required");
+}
+
+/// Execute the code of a test, which evaluates to a function, or exports it as default if
+/// `is_module`. Returns the error thrown by the test, if any.
+pub fn run_exec_test(code: &str, is_module: bool) -> Result<(), String> {
+    let output = agent()
+        .post(RUNTIME_URL)
+        .timeout(Duration::from_secs(4))
+        .send_json(json!({ "code": code, "isModule": is_module }))
+        .map_err(|err| err.to_string())?
+        .into_string()
+        .map_err(|err| err.to_string())?;
+    if output.is_empty() {
+        Ok(())
+    } else {
+        Err(output)
+    }
+}
@@ -7,18 +7,19 @@ use cow_utils::CowUtils;
 use oxc::parser::ParseOptions;
 use oxc::{
     allocator::Allocator,
-    codegen::{CodeGenerator, CodegenOptions},
+    codegen::{CodeGenerator, CodegenOptions, CommentMode},
     diagnostics::{Error, NamedSource, OxcDiagnostic},
     parser::Parser,
     span::{SourceType, VALID_EXTENSIONS},
     transformer::{BabelOptions, HelperLoaderMode, TransformOptions},
 };
-use oxc_tasks_common::{normalize_path, print_diff_in_terminal, project_root};
+use oxc_tasks_common::{print_diff_in_terminal, project_root};
 
 use crate::{
     constants::{PLUGINS_NOT_SUPPORTED_YET, SKIP_TESTS},
     driver::Driver,
-    fixture_root, oxc_test_root, packages_root,
+    packages_root,
+    runtime::run_exec_test,
 };
 
 #[derive(Debug)]
@@ -308,7 +309,7 @@ impl TestCase for ConformanceTestCase {
 
                     CodeGenerator::new()
                         .with_options(CodegenOptions {
-                            comments: false,
+                            comments: CommentMode::None,
                             ..CodegenOptions::default()
                         })
                         .build(&ret.program)
@@ -383,23 +384,13 @@ pub struct ExecTestCase {
 }
 
 impl ExecTestCase {
-    fn write_to_test_files(&self, content: &str) {
-        let unprefixed_path = self
-            .path
-            .strip_prefix(packages_root())
-            .or_else(|_| self.path.strip_prefix(oxc_test_root()))
-            .unwrap();
-        let new_file_name: String =
-            normalize_path(unprefixed_path).split('/').collect::<Vec<&str>>().join("-");
-
-        let mut target_path = fixture_root().join(new_file_name);
-        target_path.set_extension("test.js");
-        let content = Self::template(content);
-        fs::write(&target_path, content).unwrap();
-    }
-
-    fn template(code: &str) -> String {
-        // Move all the import statements to top level.
+    /// Wrap the transformed code of a test in a function, as the code of `exec.js` tests runs in
+    /// a function and may `return` a promise.
+    ///
+    /// Import statements are moved to the top level, and the function is exported as default.
+    /// Returns whether the code is a module, tests without imports run as scripts, not in strict
+    /// mode.
+    fn template(code: &str) -> (String, bool) {
         let mut codes = vec![];
         let mut imports = vec![];
 
@@ -412,15 +403,12 @@ impl ExecTestCase {
         }
 
         let code = codes.join("\n");
-        let imports = imports.join("\n");
-
-        format!(
-            r#"import {{expect, test}} from 'vitest';
-{imports}
-test("exec", () => {{
-{code}
-}})"#
-        )
+        if imports.is_empty() {
+            (format!("(function exec() {{\n{code}\n}})"), false)
+        } else {
+            let imports = imports.join("\n");
+            (format!("{imports}\nexport default function exec() {{\n{code}\n}}"), true)
+        }
     }
 }
 
@@ -455,15 +443,36 @@ impl TestCase for ExecTestCase {
         }
 
         let result = match self.transform(&self.path) {
-            Ok(mut driver) => driver.printed(),
+            Ok(mut driver) => {
+                let errors = driver.errors();
+                if !errors.is_empty() {
+                    if filtered {
+                        println!("Transform Errors:\n{errors:?}\n");
+                    }
+                    self.errors.extend(errors);
+                    return;
+                }
+                driver.printed()
+            }
             Err(error) => {
                 if filtered {
                     println!("Transform Errors:\n{error:?}\n",);
                 }
+                self.errors.push(error);
                 return;
             }
         };
-        self.write_to_test_files(&result);
+
+        let (code, is_module) = Self::template(&result);
+        if filtered {
+            println!("Transformed:\n{code}\n");
+        }
+        if let Err(error) = run_exec_test(&code, is_module) {
+            if filtered {
+                println!("Exec Errors:\n{error}\n");
+            }
+            self.errors.push(OxcDiagnostic::error(error));
+        }
     }
 }
 
@@ -1,8 +1,17 @@
 // Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
 #![allow(non_snake_case)]
 #![allow(clippy::needless_pass_by_value)]
+// Ids are serialized as `u32`, which is the size of `usize` in wasm32.
+#![allow(clippy::cast_possible_truncation)]
 
-use oxc::{allocator::Allocator, parser::Parser, span::SourceType};
+use oxc::{
+    allocator::Allocator,
+    cfg::{graph::visit::EdgeRef, ControlFlowGraph},
+    index::Idx,
+    parser::Parser,
+    semantic::{NodeId, Semantic, SemanticBuilder},
+    span::{GetSpan, SourceType, Span},
+};
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
@@ -23,6 +32,15 @@ pub struct ParserOptions {
     /// "module" and "jsx" will be inferred from `sourceFilename`.
     #[tsify(optional)]
     pub source_filename: Option<String>,
+
+    /// Return the scope tree, symbol table and references as `scopes`, `symbols` and
+    /// `references`.
+    #[tsify(optional)]
+    pub semantic: Option<bool>,
+
+    /// Return the control flow graph as `cfg`.
+    #[tsify(optional)]
+    pub cfg: Option<bool>,
 }
 
 #[derive(Default, Tsify)]
@@ -35,6 +53,26 @@ pub struct ParseResult {
     #[wasm_bindgen(readonly, skip_typescript)]
     #[tsify(type = "Diagnostic[]")]
     pub errors: Vec<JsValue>,
+
+    /// Set if the `semantic` option is enabled, indexed by scope id.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Scope[] | undefined")]
+    pub scopes: JsValue,
+
+    /// Set if the `semantic` option is enabled, indexed by symbol id.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Symbol[] | undefined")]
+    pub symbols: JsValue,
+
+    /// Set if the `semantic` option is enabled, indexed by reference id.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "Reference[] | undefined")]
+    pub references: JsValue,
+
+    /// Set if the `cfg` option is enabled.
+    #[wasm_bindgen(readonly, skip_typescript)]
+    #[tsify(type = "ControlFlowGraph | undefined")]
+    pub cfg: JsValue,
 }
 
 #[derive(Debug, Default, Serialize, Tsify)]
@@ -45,6 +83,70 @@ pub struct Diagnostic {
     pub message: String,
 }
 
+#[derive(Debug, Serialize, Tsify)]
+pub struct Scope {
+    /// `null` for the root scope.
+    pub parent: Option<u32>,
+    pub flags: Vec<&'static str>,
+    /// Span of the node which creates the scope.
+    pub start: u32,
+    pub end: u32,
+    /// Symbols declared in the scope.
+    pub bindings: Vec<u32>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+pub struct Symbol {
+    pub name: String,
+    pub flags: Vec<&'static str>,
+    pub scope: u32,
+    /// Span of the declared identifier.
+    pub start: u32,
+    pub end: u32,
+    pub references: Vec<u32>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+pub struct Reference {
+    /// `null` if the reference is not resolved, e.g. a global variable.
+    pub symbol: Option<u32>,
+    pub flags: Vec<&'static str>,
+    /// Span of the identifier.
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(rename = "ControlFlowGraph")]
+pub struct ControlFlowGraphData {
+    /// Basic blocks, indexed by the ids used in `edges`.
+    pub blocks: Vec<BasicBlock>,
+    pub edges: Vec<Edge>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+pub struct BasicBlock {
+    pub instructions: Vec<Instruction>,
+    pub unreachable: bool,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+pub struct Instruction {
+    /// e.g. `Statement`, `Condition` or `Return(ImplicitUndefined)`.
+    pub kind: String,
+    /// Span of the node of the instruction, if any.
+    pub start: Option<u32>,
+    pub end: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+pub struct Edge {
+    pub from: u32,
+    pub to: u32,
+    /// e.g. `Normal`, `Jump`, `Backedge` or `Error(Explicit)`.
+    pub kind: String,
+}
+
 /// # Errors
 ///
 /// * wasm bindgen serialization failed
@@ -104,5 +206,118 @@ pub fn parse_sync(
             .collect::<Vec<JsValue>>()
     };
 
-    Ok(ParseResult { program, errors })
+    let mut result = ParseResult { program, errors, ..ParseResult::default() };
+
+    let semantic = options.semantic.unwrap_or_default();
+    let cfg = options.cfg.unwrap_or_default();
+    if semantic || cfg {
+        let semantic_ret = SemanticBuilder::new().with_cfg(cfg).build(&ret.program);
+        if semantic {
+            result.scopes = serialize_scopes(&semantic_ret.semantic).serialize(&serializer)?;
+            result.symbols = serialize_symbols(&semantic_ret.semantic).serialize(&serializer)?;
+            result.references =
+                serialize_references(&semantic_ret.semantic).serialize(&serializer)?;
+        }
+        if let Some(cfg) = semantic_ret.semantic.cfg() {
+            result.cfg = serialize_cfg(cfg, &semantic_ret.semantic).serialize(&serializer)?;
+        }
+    }
+
+    Ok(result)
+}
+
+fn node_span(semantic: &Semantic, node_id: NodeId) -> Span {
+    semantic.nodes().get_node(node_id).kind().span()
+}
+
+fn serialize_scopes(semantic: &Semantic) -> Vec<Scope> {
+    let scopes = semantic.scopes();
+    scopes
+        .descendants_from_root()
+        .map(|scope_id| {
+            let span = node_span(semantic, scopes.get_node_id(scope_id));
+            Scope {
+                parent: scopes.get_parent_id(scope_id).map(|id| id.index() as u32),
+                flags: scopes.get_flags(scope_id).iter_names().map(|(name, _)| name).collect(),
+                start: span.start,
+                end: span.end,
+                bindings: scopes
+                    .iter_bindings_in(scope_id)
+                    .map(|symbol_id| symbol_id.index() as u32)
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn serialize_symbols(semantic: &Semantic) -> Vec<Symbol> {
+    let symbols = semantic.symbols();
+    symbols
+        .symbol_ids()
+        .map(|symbol_id| {
+            let span = symbols.get_span(symbol_id);
+            Symbol {
+                name: symbols.get_name(symbol_id).to_string(),
+                flags: symbols.get_flags(symbol_id).iter_names().map(|(name, _)| name).collect(),
+                scope: symbols.get_scope_id(symbol_id).index() as u32,
+                start: span.start,
+                end: span.end,
+                references: symbols
+                    .get_resolved_reference_ids(symbol_id)
+                    .iter()
+                    .map(|reference_id| reference_id.index() as u32)
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn serialize_references(semantic: &Semantic) -> Vec<Reference> {
+    semantic
+        .symbols()
+        .references
+        .iter()
+        .map(|reference| {
+            let span = node_span(semantic, reference.node_id());
+            Reference {
+                symbol: reference.symbol_id().map(|id| id.index() as u32),
+                flags: reference.flags().iter_names().map(|(name, _)| name).collect(),
+                start: span.start,
+                end: span.end,
+            }
+        })
+        .collect()
+}
+
+fn serialize_cfg(cfg: &ControlFlowGraph, semantic: &Semantic) -> ControlFlowGraphData {
+    let blocks = cfg
+        .graph()
+        .node_weights()
+        .map(|block_id| {
+            let block = &cfg.basic_blocks[*block_id];
+            let instructions = block
+                .instructions()
+                .iter()
+                .map(|instruction| {
+                    let span = instruction.node_id.map(|node_id| node_span(semantic, node_id));
+                    Instruction {
+                        kind: format!("{:?}", instruction.kind),
+                        start: span.map(|span| span.start),
+                        end: span.map(|span| span.end),
+                    }
+                })
+                .collect();
+            BasicBlock { instructions, unreachable: block.is_unreachable() }
+        })
+        .collect();
+    let edges = cfg
+        .graph()
+        .edge_references()
+        .map(|edge| Edge {
+            from: edge.source().index() as u32,
+            to: edge.target().index() as u32,
+            kind: format!("{:?}", edge.weight()),
+        })
+        .collect();
+    ControlFlowGraphData { blocks, edges }
 }
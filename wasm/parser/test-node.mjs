@@ -5,3 +5,13 @@ const code = 'let foo';
 const result = parseSync(code, { sourceFilename: 'test.ts' });
 assert(result.errors.length === 0);
 assert(result.program.body.length === 1);
+
+const semantic = parseSync('let foo = 1; if (foo) { foo++; }', { semantic: true, cfg: true });
+assert(semantic.scopes.length === 2);
+assert(semantic.scopes[1].parent === 0);
+assert(semantic.symbols.length === 1);
+assert(semantic.symbols[0].name === 'foo');
+assert(semantic.symbols[0].references.length === 2);
+assert(semantic.references[semantic.symbols[0].references[0]].symbol === 0);
+assert(semantic.cfg.blocks.length > 0);
+assert(semantic.cfg.edges.some((edge) => edge.kind === 'Jump'));